@@ -1,4 +1,5 @@
 use std::io::Read;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use tracing_subscriber::{fmt, EnvFilter};
@@ -17,19 +18,55 @@ async fn main() -> Result<()> {
     let mut args = std::env::args().skip(1);
     match args.next().as_deref() {
         Some("query") | Some("--oneshot") => {
-            let mut max_results: Option<usize> = None;
-            let mut json_output = false;
+            let mut output = docs_mcp::QueryOutput::default();
 
             let mut positionals = Vec::new();
             let mut pending = args.collect::<Vec<_>>().into_iter();
             while let Some(arg) = pending.next() {
                 match arg.as_str() {
-                    "--json" => json_output = true,
+                    "--json" => output.format = docs_mcp::QueryFormat::Json,
+                    "--format" | "-o" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        output.format = value.parse()?;
+                    }
+                    "--fields" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        output.fields.extend(
+                            value
+                                .split(',')
+                                .map(str::trim)
+                                .filter(|field| !field.is_empty())
+                                .map(str::to_string),
+                        );
+                    }
+                    "--provider" | "-p" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        output.provider = Some(value);
+                    }
+                    "--technology" | "-t" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        output.technology = Some(value);
+                    }
+                    "--platform" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        output.platform = Some(value);
+                    }
+                    "--no-beta" => output.exclude_beta = true,
                     "--max-results" | "--maxResults" | "-n" => {
                         let value = pending
                             .next()
                             .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
-                        max_results = Some(value.parse()?);
+                        output.max_results = Some(value.parse()?);
                     }
                     _ => positionals.push(arg),
                 }
@@ -43,22 +80,456 @@ async fn main() -> Result<()> {
                 let trimmed = buf.trim();
                 if trimmed.is_empty() {
                     anyhow::bail!(
-                        "missing query string (usage: docs-mcp-cli query [--json] [--max-results N] \"...\")"
+                        "missing query string (usage: docs-mcp-cli query [--provider NAME] [--technology NAME] [--platform NAME] [--no-beta] [--format md|json|yaml|plain] [--fields a,b] [--max-results N] \"...\")"
                     );
                 }
                 trimmed.to_string()
             };
 
-            let response = docs_mcp::oneshot_query(&query, max_results).await?;
+            println!("{}", docs_mcp::render_query(&query, &output).await?);
+            Ok(())
+        }
+        Some("cache") => run_cache_command(args.collect()).await,
+        Some("bundle") => run_bundle_command(args.collect()).await,
+        Some("warm") => run_warm_command(args.collect()).await,
+        Some("feedback") => run_feedback_command(args.collect()).await,
+        Some("providers") => run_providers_command(args.collect()).await,
+        Some("serve") => run_serve_command(args.collect()).await,
+        Some("bench") => run_bench_command(args.collect()).await,
+        Some("index") => run_index_command(args.collect()).await,
+        Some("get") => run_get_command(args.collect()).await,
+        _ => docs_mcp::run_server().await,
+    }
+}
+
+async fn run_cache_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str = "usage: docs-mcp-cli cache prune [--older-than-days N] [--provider NAME]... [--dry-run] [--json]\n       docs-mcp-cli cache export <FILE.tar.gz> [--json]\n       docs-mcp-cli cache import <FILE.tar.gz> [--json]";
+
+    let mut pending = args.into_iter();
+    match pending.next().as_deref() {
+        Some("prune") => {
+            let mut older_than_days: Option<u64> = None;
+            let mut providers = Vec::new();
+            let mut dry_run = false;
+            let mut json_output = false;
+
+            while let Some(arg) = pending.next() {
+                match arg.as_str() {
+                    "--older-than-days" | "--days" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        older_than_days = Some(value.parse()?);
+                    }
+                    "--provider" | "-p" => {
+                        let value = pending
+                            .next()
+                            .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                        providers.extend(value.split(',').map(|name| name.trim().to_string()));
+                    }
+                    "--dry-run" => dry_run = true,
+                    "--json" => json_output = true,
+                    other => anyhow::bail!("unknown argument '{other}' ({USAGE})"),
+                }
+            }
+
+            let report = docs_mcp::prune_cache(older_than_days, &providers, dry_run).await?;
             if json_output {
-                println!("{}", serde_json::to_string_pretty(&response)?);
+                println!("{}", serde_json::to_string_pretty(&report)?);
             } else {
-                for item in response.content {
-                    println!("{}", item.text);
+                for line in report.summary_lines() {
+                    println!("{line}");
                 }
             }
             Ok(())
         }
-        _ => docs_mcp::run_server().await,
+        Some(command @ ("export" | "import")) => {
+            let mut archive: Option<PathBuf> = None;
+            let mut json_output = false;
+            for arg in pending {
+                match arg.as_str() {
+                    "--json" => json_output = true,
+                    other if other.starts_with('-') => anyhow::bail!("unknown argument '{other}' ({USAGE})"),
+                    _ if archive.is_some() => anyhow::bail!("unexpected argument '{arg}' ({USAGE})"),
+                    _ => archive = Some(PathBuf::from(arg)),
+                }
+            }
+            let archive = archive.ok_or_else(|| anyhow::anyhow!(USAGE))?;
+
+            let (manifest, verb) = if command == "export" {
+                (docs_mcp::export_cache(&archive).await?, "Exported")
+            } else {
+                (docs_mcp::import_cache(&archive).await?, "Imported")
+            };
+            let headline = format!("{verb} {}", archive.display());
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&manifest)?);
+            } else {
+                for line in manifest.summary_lines(&headline) {
+                    println!("{line}");
+                }
+            }
+            Ok(())
+        }
+        _ => anyhow::bail!(USAGE),
+    }
+}
+
+async fn run_bundle_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str = "usage: docs-mcp-cli bundle export <FILE.tar.zst> [--provider NAME]... [--framework NAME]... [--json]\n       docs-mcp-cli bundle import <FILE.tar.zst> [--provider NAME]... [--framework NAME]... [--json]";
+
+    let mut pending = args.into_iter();
+    let command = match pending.next().as_deref() {
+        Some(command @ ("export" | "import")) => command.to_string(),
+        _ => anyhow::bail!(USAGE),
+    };
+    let mut archive: Option<PathBuf> = None;
+    let mut providers = Vec::new();
+    let mut frameworks = Vec::new();
+    let mut json_output = false;
+    while let Some(arg) = pending.next() {
+        match arg.as_str() {
+            "--provider" | "-p" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                providers.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--framework" | "-f" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                frameworks.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--json" => json_output = true,
+            other if other.starts_with('-') => anyhow::bail!("unknown argument '{other}' ({USAGE})"),
+            _ if archive.is_some() => anyhow::bail!("unexpected argument '{arg}' ({USAGE})"),
+            _ => archive = Some(PathBuf::from(arg)),
+        }
+    }
+    let archive = archive.ok_or_else(|| anyhow::anyhow!(USAGE))?;
+
+    let (manifest, verb) = if command == "export" {
+        (docs_mcp::export_bundle(&archive, &providers, &frameworks).await?, "Exported")
+    } else {
+        (docs_mcp::import_bundle(&archive, &providers, &frameworks).await?, "Imported")
+    };
+    let headline = format!("{verb} {}", archive.display());
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&manifest)?);
+    } else {
+        for line in manifest.summary_lines(&headline) {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+async fn run_feedback_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str = "usage: docs-mcp-cli feedback list [--json]\n       docs-mcp-cli feedback export <FILE.jsonl>";
+
+    let mut pending = args.into_iter();
+    match pending.next().as_deref() {
+        Some("list") => {
+            let mut json_output = false;
+            for arg in pending {
+                match arg.as_str() {
+                    "--json" => json_output = true,
+                    other => anyhow::bail!("unknown argument '{other}' ({USAGE})"),
+                }
+            }
+
+            let entries = docs_mcp::list_feedback().await?;
+            if json_output {
+                println!("{}", serde_json::to_string_pretty(&entries)?);
+            } else if entries.is_empty() {
+                println!("No feedback recorded yet.");
+            } else {
+                for entry in entries {
+                    for line in entry.summary_lines() {
+                        println!("{line}");
+                    }
+                }
+            }
+            Ok(())
+        }
+        Some("export") => {
+            let output = match (pending.next(), pending.next()) {
+                (Some(path), None) if !path.starts_with('-') => PathBuf::from(path),
+                _ => anyhow::bail!(USAGE),
+            };
+            let count = docs_mcp::export_feedback(&output).await?;
+            println!("Exported {count} feedback record(s) to {}", output.display());
+            Ok(())
+        }
+        _ => anyhow::bail!(USAGE),
+    }
+}
+
+async fn run_providers_command(args: Vec<String>) -> Result<()> {
+    let mut json_output = false;
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json_output = true,
+            other => anyhow::bail!("unknown argument '{other}' (usage: docs-mcp-cli providers [--json])"),
+        }
+    }
+
+    let report = docs_mcp::providers().await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for line in report.summary_lines() {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+async fn run_serve_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str = "usage: docs-mcp-cli serve [--http ADDR] [--allow-origin ORIGIN]... [--auth-token TOKEN]";
+    let mut bind = None;
+    let mut settings = docs_mcp::HttpSettings::default();
+
+    let mut pending = args.into_iter();
+    while let Some(arg) = pending.next() {
+        match arg.as_str() {
+            "--http" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                bind = Some(
+                    value
+                        .parse()
+                        .map_err(|_| anyhow::anyhow!("invalid address '{value}' (expected e.g. 127.0.0.1:8080)"))?,
+                );
+            }
+            "--allow-origin" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                settings
+                    .allowed_origins
+                    .extend(value.split(',').map(|origin| origin.trim().to_string()));
+            }
+            "--auth-token" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                settings.auth_token = Some(
+                    docs_mcp::ApiToken::new(value)
+                        .ok_or_else(|| anyhow::anyhow!("{arg} must not be empty"))?,
+                );
+            }
+            other => anyhow::bail!("unknown argument '{other}'\n{USAGE}"),
+        }
+    }
+
+    match bind {
+        Some(bind) => {
+            settings.bind = bind;
+            docs_mcp::run_http_server(settings).await
+        }
+        None if settings.allowed_origins.is_empty() && settings.auth_token.is_none() => {
+            docs_mcp::run_server().await
+        }
+        None => anyhow::bail!("--allow-origin and --auth-token require --http\n{USAGE}"),
+    }
+}
+
+async fn run_get_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str =
+        "usage: docs-mcp-cli get <PATH|URL> [--provider NAME] [--technology NAME] [--json]";
+    let mut path = None;
+    let mut provider = None;
+    let mut technology = None;
+    let mut json_output = false;
+
+    let mut pending = args.into_iter();
+    while let Some(arg) = pending.next() {
+        match arg.as_str() {
+            "--provider" | "-p" => {
+                provider = Some(
+                    pending
+                        .next()
+                        .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?,
+                );
+            }
+            "--technology" | "-t" => {
+                technology = Some(
+                    pending
+                        .next()
+                        .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?,
+                );
+            }
+            "--json" => json_output = true,
+            other if other.starts_with("--") => anyhow::bail!("unknown argument '{other}'\n{USAGE}"),
+            _ if path.is_none() => path = Some(arg),
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+    let Some(path) = path else {
+        anyhow::bail!(USAGE);
+    };
+
+    let response =
+        docs_mcp::get_document(&path, provider.as_deref(), technology.as_deref()).await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&response)?);
+    } else {
+        for item in response.content {
+            println!("{}", item.text);
+        }
+    }
+    Ok(())
+}
+
+async fn run_index_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str = "usage: docs-mcp-cli index build [--provider apple|rust]... [--framework NAME]... [--crate NAME]... [--json]";
+    let mut args = args.into_iter();
+    if args.next().as_deref() != Some("build") {
+        anyhow::bail!(USAGE);
+    }
+
+    let mut providers = Vec::new();
+    let mut frameworks = Vec::new();
+    let mut crates = Vec::new();
+    let mut json_output = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--provider" | "-p" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                providers.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--framework" | "-f" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                frameworks.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--crate" | "-c" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                crates.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--json" => json_output = true,
+            other => anyhow::bail!("unknown argument '{other}'\n{USAGE}"),
+        }
+    }
+
+    let report = docs_mcp::build_index(&providers, frameworks, crates).await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for line in report.summary_lines() {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+async fn run_bench_command(args: Vec<String>) -> Result<()> {
+    const USAGE: &str =
+        "usage: docs-mcp-cli bench --queries <FILE> [--passes N] [--max-results N] [--json]";
+    let mut queries_file: Option<PathBuf> = None;
+    let mut options = docs_mcp::BenchOptions {
+        passes: 1,
+        ..Default::default()
+    };
+    let mut json_output = false;
+
+    let mut pending = args.into_iter();
+    while let Some(arg) = pending.next() {
+        match arg.as_str() {
+            "--queries" | "-q" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                queries_file = Some(PathBuf::from(value));
+            }
+            "--passes" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                options.passes = value.parse()?;
+            }
+            "--max-results" | "-n" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                options.max_results = Some(value.parse()?);
+            }
+            "--json" => json_output = true,
+            other => anyhow::bail!("unknown argument '{other}'\n{USAGE}"),
+        }
+    }
+
+    let Some(queries_file) = queries_file else {
+        anyhow::bail!(USAGE);
+    };
+    let text = std::fs::read_to_string(&queries_file)
+        .map_err(|error| anyhow::anyhow!("read {}: {error}", queries_file.display()))?;
+    options.queries = docs_mcp::parse_bench_queries(&text);
+    if options.queries.is_empty() {
+        anyhow::bail!("{} contains no queries", queries_file.display());
+    }
+
+    let report = docs_mcp::bench(options).await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for line in report.summary_lines() {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+async fn run_warm_command(args: Vec<String>) -> Result<()> {
+    let mut options = docs_mcp::WarmOptions {
+        max_documents: docs_mcp::DEFAULT_WARM_DOCUMENTS,
+        ..Default::default()
+    };
+    let mut json_output = false;
+
+    let mut pending = args.into_iter();
+    while let Some(arg) = pending.next() {
+        match arg.as_str() {
+            "--framework" | "-f" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                options.frameworks.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--crate" | "-c" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                options.crates.extend(value.split(',').map(|name| name.trim().to_string()));
+            }
+            "--max-documents" => {
+                let value = pending
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} requires a value"))?;
+                options.max_documents = value.parse()?;
+            }
+            "--json" => json_output = true,
+            other => anyhow::bail!(
+                "unknown argument '{other}' (usage: docs-mcp-cli warm [--framework NAME]... [--crate NAME]... [--max-documents N] [--json])"
+            ),
+        }
+    }
+
+    let report = docs_mcp::warm(options).await?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for line in report.summary_lines() {
+            println!("{line}");
+        }
     }
+    Ok(())
 }
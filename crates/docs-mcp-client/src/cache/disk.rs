@@ -1,19 +1,40 @@
 use std::{
     fs::create_dir_all,
     path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
 };
 
 use anyhow::{anyhow, Context, Result};
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tokio::{fs, task};
 use tracing::debug;
 
-use crate::types::CacheEntry;
+use crate::types::{CacheEntry, CacheValidators};
 use super::stats::CacheStats;
 use time::OffsetDateTime;
 
 /// Default maximum cache size: 500MB
-const DEFAULT_MAX_SIZE_BYTES: u64 = 500 * 1024 * 1024;
+pub const DEFAULT_MAX_SIZE_BYTES: u64 = 500 * 1024 * 1024;
+
+static DEFAULT_MAX_SIZE: AtomicU64 = AtomicU64::new(DEFAULT_MAX_SIZE_BYTES);
+
+/// Change the size budget of caches created afterwards with [`DiskCache::new`].
+pub fn set_default_max_size(bytes: u64) {
+    DEFAULT_MAX_SIZE.store(bytes, Ordering::Relaxed);
+}
+
+/// Size budget each cache created with [`DiskCache::new`] gets.
+pub fn default_max_size() -> u64 {
+    DEFAULT_MAX_SIZE.load(Ordering::Relaxed)
+}
+
+/// Subdirectory of a cache root holding server logs rather than cache entries.
+pub const LOGS_DIR: &str = "logs";
+/// Subdirectory of a cache root holding persisted server state rather than cache entries.
+pub const STATE_DIR: &str = "state";
+/// Subdirectories that are never measured, pruned, evicted or exported with the cache.
+pub const RESERVED_DIRS: [&str; 2] = [LOGS_DIR, STATE_DIR];
 
 #[derive(Debug)]
 pub struct DiskCache {
@@ -24,7 +45,7 @@ pub struct DiskCache {
 
 impl DiskCache {
     pub fn new<P: Into<PathBuf>>(root: P) -> Self {
-        Self::with_max_size(root, DEFAULT_MAX_SIZE_BYTES)
+        Self::with_max_size(root, default_max_size())
     }
 
     pub fn with_max_size<P: Into<PathBuf>>(root: P, max_size_bytes: u64) -> Self {
@@ -49,6 +70,9 @@ impl DiskCache {
             .await
             .with_context(|| format!("failed to read cache file {path:?}"))?;
 
+        // Bump mtime so eviction treats this entry as recently used
+        touch(&path).await;
+
         let bytes_read = data.len() as u64;
 
         let entry =
@@ -60,6 +84,7 @@ impl DiskCache {
                             value,
                             stored_at: OffsetDateTime::UNIX_EPOCH,
                             last_accessed: OffsetDateTime::now_utc(),
+                            validators: None,
                         })
                         .map_err(|legacy_err| {
                             anyhow!(
@@ -80,6 +105,19 @@ impl DiskCache {
     }
 
     pub async fn store<T>(&self, file_name: &str, value: T) -> Result<()>
+    where
+        T: Serialize + Send + 'static,
+    {
+        self.store_with_validators(file_name, value, None).await
+    }
+
+    /// Store `value` along with the HTTP validators of the response it came from.
+    pub async fn store_with_validators<T>(
+        &self,
+        file_name: &str,
+        value: T,
+        validators: Option<CacheValidators>,
+    ) -> Result<()>
     where
         T: Serialize + Send + 'static,
     {
@@ -95,6 +133,7 @@ impl DiskCache {
             value,
             stored_at: now,
             last_accessed: now,
+            validators: validators.filter(|validators| !validators.is_empty()),
         };
 
         let payload = task::spawn_blocking(move || serde_json::to_vec(&entry)).await??;
@@ -111,68 +150,53 @@ impl DiskCache {
         Ok(())
     }
 
+    /// When `file_name` was last written, without counting as a cache access.
+    pub async fn stored_at(&self, file_name: &str) -> Result<Option<OffsetDateTime>> {
+        let path = self.root.join(file_name);
+        let data = match fs::read(&path).await {
+            Ok(data) => data,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let entry = task::spawn_blocking(move || {
+            serde_json::from_slice::<CacheEntry<serde::de::IgnoredAny>>(&data)
+        })
+        .await?;
+        // Legacy entries without an envelope are treated as arbitrarily old
+        Ok(Some(entry.map_or(OffsetDateTime::UNIX_EPOCH, |entry| entry.stored_at)))
+    }
+
     /// Get a reference to the cache statistics
     pub fn stats(&self) -> &CacheStats {
         &self.stats
     }
 
-    /// Evict least recently accessed entries if cache exceeds size limit
-    /// Uses file modification time (mtime) as a proxy for last access time
-    async fn evict_if_needed(&self) -> Result<()> {
-        use std::collections::BTreeMap;
-        use std::time::SystemTime;
-
-        // Calculate current cache size and collect entries with their metadata
-        // BTreeMap keeps entries sorted by modification time (oldest first)
-        let mut entries: BTreeMap<SystemTime, (String, u64)> = BTreeMap::new();
-        let mut total_size: u64 = 0;
+    /// Maximum number of bytes kept on disk before eviction kicks in
+    pub fn max_size_bytes(&self) -> u64 {
+        self.max_size_bytes
+    }
 
-        let mut read_dir = fs::read_dir(&self.root).await?;
-        while let Some(entry) = read_dir.next_entry().await? {
-            let path = entry.path();
-            if path.is_file() {
-                if let Ok(metadata) = fs::metadata(&path).await {
-                    let file_size = metadata.len();
-                    total_size += file_size;
-
-                    let file_name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("")
-                        .to_string();
-
-                    // Use file modification time as proxy for last access
-                    let modified_time = metadata
-                        .modified()
-                        .unwrap_or(SystemTime::UNIX_EPOCH);
-
-                    // Handle potential collisions by finding an available slot
-                    let mut key = modified_time;
-                    let mut nanos_offset = 1;
-                    while entries.contains_key(&key) {
-                        key = modified_time + std::time::Duration::from_nanos(nanos_offset);
-                        nanos_offset += 1;
-                    }
-
-                    entries.insert(key, (file_name, file_size));
-                }
-            }
-        }
+    /// Evict least recently used entries if cache exceeds size limit.
+    /// File modification time tracks last use: `store` writes it and `load` bumps it.
+    async fn evict_if_needed(&self) -> Result<()> {
+        let (mut entries, mut total_size) = collect_files(&self.root).await?;
 
         // If under limit, no eviction needed
         if total_size <= self.max_size_bytes {
             return Ok(());
         }
 
-        // Evict oldest entries (by modification time) until under limit
+        // Oldest access first
+        entries.sort_by_key(|(_, modified, _)| *modified);
+
+        // Evict least recently used entries until under limit
         let mut evicted_count = 0;
-        for (_, (file_name, file_size)) in entries.iter() {
+        for (file_path, _, file_size) in &entries {
             if total_size <= self.max_size_bytes {
                 break;
             }
 
-            let file_path = self.root.join(file_name);
-            if let Ok(()) = fs::remove_file(&file_path).await {
+            if let Ok(()) = fs::remove_file(file_path).await {
                 total_size -= file_size;
                 evicted_count += 1;
                 debug!(
@@ -192,6 +216,113 @@ impl DiskCache {
     }
 }
 
+/// Totals reported by [`prune_dir`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneSummary {
+    pub files_removed: u64,
+    pub bytes_reclaimed: u64,
+}
+
+/// Delete cache files under `root` that haven't been used for `max_age`
+/// (every file when `max_age` is `None`). With `dry_run`, only report what
+/// would be removed.
+pub async fn prune_dir(
+    root: &Path,
+    max_age: Option<std::time::Duration>,
+    dry_run: bool,
+) -> Result<PruneSummary> {
+    let (entries, _) = collect_files(root).await?;
+    // `Some(None)` means the age reaches before the epoch, so nothing qualifies
+    let cutoff = max_age.map(|age| SystemTime::now().checked_sub(age));
+
+    let mut summary = PruneSummary::default();
+    for (path, modified, size) in entries {
+        let recently_used = match cutoff {
+            None => false,
+            Some(None) => true,
+            Some(Some(cutoff)) => modified > cutoff,
+        };
+        if recently_used {
+            continue;
+        }
+        if !dry_run {
+            if let Err(error) = fs::remove_file(&path).await {
+                debug!(target: "docs_mcp_cache", file = ?path, error = %error, "failed to prune cache entry");
+                continue;
+            }
+        }
+        summary.files_removed += 1;
+        summary.bytes_reclaimed += size;
+    }
+
+    Ok(summary)
+}
+
+/// File count and total size of a cache directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirUsage {
+    pub files: u64,
+    pub bytes: u64,
+}
+
+/// Measure the cache files under `root`; a missing directory counts as empty.
+pub async fn dir_usage(root: &Path) -> Result<DirUsage> {
+    let (entries, bytes) = collect_files(root).await?;
+    Ok(DirUsage {
+        files: entries.len() as u64,
+        bytes,
+    })
+}
+
+/// Recursively list cache files as `(path, modified, size)` along with their total size
+async fn collect_files(root: &Path) -> Result<(Vec<(PathBuf, SystemTime, u64)>, u64)> {
+    let mut entries = Vec::new();
+    let mut total_size = 0;
+    let mut pending = vec![root.to_path_buf()];
+    let reserved: Vec<PathBuf> = RESERVED_DIRS.iter().map(|name| root.join(name)).collect();
+
+    while let Some(dir) = pending.pop() {
+        let mut read_dir = match fs::read_dir(&dir).await {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error.into()),
+        };
+        while let Some(entry) = read_dir.next_entry().await? {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if !reserved.contains(&entry.path()) {
+                    pending.push(entry.path());
+                }
+            } else if metadata.is_file() {
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                total_size += metadata.len();
+                entries.push((entry.path(), modified, metadata.len()));
+            }
+        }
+    }
+
+    Ok((entries, total_size))
+}
+
+/// Update a file's modification time to now; failures only cost LRU accuracy
+async fn touch(path: &Path) {
+    let path = path.to_path_buf();
+    let result = task::spawn_blocking(move || {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(SystemTime::now()))
+    })
+    .await;
+    if let Ok(Err(error)) = result {
+        debug!(target: "docs_mcp_cache", error = %error, "failed to update cache access time");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +344,42 @@ mod tests {
         assert_eq!(entry.value["hello"], "world");
     }
 
+    #[tokio::test]
+    async fn round_trip_persists_validators() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::new(dir.path());
+        let validators = CacheValidators {
+            etag: Some("\"abc\"".to_string()),
+            last_modified: None,
+        };
+
+        cache
+            .store_with_validators("example.json", json!({"hello": "world"}), Some(validators.clone()))
+            .await
+            .unwrap();
+        let entry: CacheEntry<serde_json::Value> = cache.load("example.json").await.unwrap().unwrap();
+        assert_eq!(entry.validators, Some(validators));
+
+        cache
+            .store_with_validators("empty.json", json!({}), Some(CacheValidators::default()))
+            .await
+            .unwrap();
+        let entry: CacheEntry<serde_json::Value> = cache.load("empty.json").await.unwrap().unwrap();
+        assert_eq!(entry.validators, None);
+    }
+
+    #[tokio::test]
+    async fn stored_at_does_not_count_as_access() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::new(dir.path());
+        assert!(cache.stored_at("missing.json").await.unwrap().is_none());
+
+        cache.store("example.json", json!({"hello": "world"})).await.unwrap();
+        let stored_at = cache.stored_at("example.json").await.unwrap().unwrap();
+        assert!(OffsetDateTime::now_utc() - stored_at < time::Duration::minutes(1));
+        assert_eq!(cache.stats().snapshot().hits, 0);
+    }
+
     #[tokio::test]
     async fn tracks_cache_hits() {
         let dir = tempdir().expect("tempdir");
@@ -324,6 +491,95 @@ mod tests {
         assert!(newest.is_some(), "Newest file should not be evicted");
     }
 
+    #[tokio::test]
+    async fn load_refreshes_recency_for_lru() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::with_max_size(dir.path(), 2048);
+
+        cache.store("first.json", json!({"data": "x".repeat(800)})).await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        cache.store("second.json", json!({"data": "x".repeat(800)})).await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        // Reading the oldest entry makes it the most recently used
+        let _: Option<CacheEntry<serde_json::Value>> = cache.load("first.json").await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        cache.store("third.json", json!({"data": "x".repeat(800)})).await.unwrap();
+
+        assert!(dir.path().join("first.json").exists(), "recently read entry should survive");
+        assert!(!dir.path().join("second.json").exists(), "least recently used entry should be evicted");
+        assert!(dir.path().join("third.json").exists());
+    }
+
+    #[tokio::test]
+    async fn evicts_entries_in_subdirectories() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::with_max_size(dir.path(), 1024);
+
+        cache
+            .store("documentation/swiftui/old.json", json!({"data": "x".repeat(800)}))
+            .await
+            .unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        cache.store("new.json", json!({"data": "x".repeat(800)})).await.unwrap();
+
+        assert!(!dir.path().join("documentation/swiftui/old.json").exists());
+        assert!(dir.path().join("new.json").exists());
+    }
+
+    #[tokio::test]
+    async fn prune_dir_removes_only_stale_entries() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::new(dir.path());
+        cache.store("stale.json", json!({"data": 1})).await.unwrap();
+        cache.store("nested/fresh.json", json!({"data": 2})).await.unwrap();
+
+        let stale = std::fs::OpenOptions::new()
+            .write(true)
+            .open(dir.path().join("stale.json"))
+            .unwrap();
+        stale
+            .set_modified(SystemTime::now() - std::time::Duration::from_secs(10 * 86_400))
+            .unwrap();
+
+        let max_age = Some(std::time::Duration::from_secs(7 * 86_400));
+        let preview = prune_dir(dir.path(), max_age, true).await.unwrap();
+        assert_eq!(preview.files_removed, 1);
+        assert!(dir.path().join("stale.json").exists(), "dry run must not delete");
+
+        let summary = prune_dir(dir.path(), max_age, false).await.unwrap();
+        assert_eq!(summary, preview);
+        assert!(!dir.path().join("stale.json").exists());
+        assert!(dir.path().join("nested/fresh.json").exists());
+
+        std::fs::create_dir_all(dir.path().join(LOGS_DIR)).unwrap();
+        std::fs::write(dir.path().join(LOGS_DIR).join("telemetry.jsonl"), "{}\n").unwrap();
+        let all = prune_dir(dir.path(), None, false).await.unwrap();
+        assert_eq!(all.files_removed, 1);
+        assert!(dir.path().join(LOGS_DIR).join("telemetry.jsonl").exists(), "logs are not cache entries");
+
+        std::fs::create_dir_all(dir.path().join(STATE_DIR)).unwrap();
+        std::fs::write(dir.path().join(STATE_DIR).join("session.json"), "{}").unwrap();
+        prune_dir(dir.path(), None, false).await.unwrap();
+        assert!(dir.path().join(STATE_DIR).join("session.json").exists(), "state is not a cache entry");
+    }
+
+    #[tokio::test]
+    async fn dir_usage_counts_nested_files() {
+        let dir = tempdir().expect("tempdir");
+        let cache = DiskCache::new(dir.path());
+        cache.store("a.json", json!({"data": 1})).await.unwrap();
+        cache.store("nested/b.json", json!({"data": 2})).await.unwrap();
+
+        let usage = dir_usage(dir.path()).await.unwrap();
+        assert_eq!(usage.files, 2);
+        assert!(usage.bytes > 0);
+
+        let missing = dir_usage(&dir.path().join("missing")).await.unwrap();
+        assert_eq!(missing, DirUsage::default());
+    }
+
     #[tokio::test]
     async fn tracks_entry_count() {
         let dir = tempdir().expect("tempdir");
@@ -0,0 +1,190 @@
+use std::{future::Future, path::PathBuf};
+
+use anyhow::{ensure, Result};
+use sha2::{Digest, Sha256};
+
+use super::disk::DiskCache;
+use super::stats::CacheStats;
+
+/// Subdirectory of a cache root holding embedding vectors.
+pub const EMBEDDINGS_DIR: &str = "embeddings";
+
+/// Persistent embedding vectors keyed by a SHA-256 hash of the embedded text.
+///
+/// Vectors are namespaced by model, so switching models never serves vectors from another
+/// embedding space. Re-indexing unchanged documents after a restart is then a disk read per
+/// document instead of a call to the embedding model.
+#[derive(Debug)]
+pub struct EmbeddingCache {
+    disk: DiskCache,
+    model: String,
+}
+
+impl EmbeddingCache {
+    /// Cache vectors produced by `model` under `<root>/embeddings/<model>/`.
+    pub fn new<P: Into<PathBuf>>(root: P, model: &str) -> Self {
+        Self::with_max_size(root, model, super::disk::default_max_size())
+    }
+
+    pub fn with_max_size<P: Into<PathBuf>>(root: P, model: &str, max_size_bytes: u64) -> Self {
+        let model = model_slug(model);
+        let root = root.into().join(EMBEDDINGS_DIR).join(&model);
+        Self {
+            disk: DiskCache::with_max_size(root, max_size_bytes),
+            model,
+        }
+    }
+
+    /// The model namespace vectors are stored under.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Hex SHA-256 of `text`, the key vectors are stored under.
+    pub fn content_hash(text: &str) -> String {
+        Sha256::digest(text.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// The cached vector for `text`, if it was embedded before.
+    pub async fn get(&self, text: &str) -> Result<Option<Vec<f32>>> {
+        let entry = self.disk.load::<Vec<f32>>(&entry_file(&Self::content_hash(text))).await?;
+        Ok(entry.map(|entry| entry.value))
+    }
+
+    pub async fn insert(&self, text: &str, vector: Vec<f32>) -> Result<()> {
+        ensure!(!vector.is_empty(), "refusing to cache an empty embedding");
+        self.disk.store(&entry_file(&Self::content_hash(text)), vector).await
+    }
+
+    /// The cached vector for `text`, or the one `compute` produces, which is then cached.
+    pub async fn get_or_compute<F, Fut>(&self, text: &str, compute: F) -> Result<Vec<f32>>
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: Future<Output = Result<Vec<f32>>>,
+    {
+        if let Some(vector) = self.get(text).await? {
+            return Ok(vector);
+        }
+        let vector = compute(text.to_string()).await?;
+        self.insert(text, vector.clone()).await?;
+        Ok(vector)
+    }
+
+    /// Vectors for `texts` in order, calling `compute` once with only the texts not cached yet.
+    pub async fn get_or_compute_batch<F, Fut>(&self, texts: &[&str], compute: F) -> Result<Vec<Vec<f32>>>
+    where
+        F: FnOnce(Vec<String>) -> Fut,
+        Fut: Future<Output = Result<Vec<Vec<f32>>>>,
+    {
+        let mut vectors = Vec::with_capacity(texts.len());
+        let mut missing = Vec::new();
+        for (index, text) in texts.iter().enumerate() {
+            let cached = self.get(text).await?;
+            if cached.is_none() {
+                missing.push(index);
+            }
+            vectors.push(cached);
+        }
+
+        if !missing.is_empty() {
+            let computed = compute(missing.iter().map(|&index| texts[index].to_string()).collect()).await?;
+            ensure!(
+                computed.len() == missing.len(),
+                "embedding model returned {} vectors for {} texts",
+                computed.len(),
+                missing.len()
+            );
+            for (index, vector) in missing.into_iter().zip(computed) {
+                self.insert(texts[index], vector.clone()).await?;
+                vectors[index] = Some(vector);
+            }
+        }
+
+        Ok(vectors.into_iter().flatten().collect())
+    }
+
+    pub fn stats(&self) -> &CacheStats {
+        self.disk.stats()
+    }
+}
+
+/// Spread entries over 256 subdirectories by hash prefix to keep directories small.
+fn entry_file(hash: &str) -> String {
+    format!("{}/{hash}.json", &hash[..2])
+}
+
+/// Model names like `BAAI/bge-small-en-v1.5` as a single path component.
+fn model_slug(model: &str) -> String {
+    model
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn reuses_vectors_for_unchanged_content() {
+        let dir = tempdir().expect("tempdir");
+        let cache = EmbeddingCache::new(dir.path(), "BAAI/bge-small-en-v1.5");
+        assert_eq!(cache.model(), "BAAI_bge-small-en-v1.5");
+
+        let calls = AtomicUsize::new(0);
+        let embed = |text: String| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(vec![text.len() as f32, 1.0]) }
+        };
+        assert_eq!(cache.get_or_compute("NavigationStack", embed).await.unwrap(), [15.0, 1.0]);
+        assert_eq!(cache.get_or_compute("NavigationStack", embed).await.unwrap(), [15.0, 1.0]);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.stats().snapshot().hits, 1);
+
+        // A restarted process finds the vector on disk
+        let restarted = EmbeddingCache::new(dir.path(), "BAAI/bge-small-en-v1.5");
+        assert_eq!(restarted.get_or_compute("NavigationStack", embed).await.unwrap(), [15.0, 1.0]);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(restarted.stats().snapshot().hits, 1);
+        // Other models and changed content are misses
+        let other_model = EmbeddingCache::new(dir.path(), "all-MiniLM-L6-v2");
+        assert_eq!(other_model.get("NavigationStack").await.unwrap(), None);
+        assert_eq!(restarted.get("NavigationStack ").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn batch_computes_only_missing_texts() {
+        let dir = tempdir().expect("tempdir");
+        let cache = EmbeddingCache::new(dir.path(), "test-model");
+        cache.insert("b", vec![2.0]).await.unwrap();
+
+        let vectors = cache
+            .get_or_compute_batch(&["a", "b", "c"], |texts| async move {
+                assert_eq!(texts, ["a", "c"]);
+                Ok(vec![vec![1.0], vec![3.0]])
+            })
+            .await
+            .unwrap();
+        assert_eq!(vectors, [vec![1.0], vec![2.0], vec![3.0]]);
+
+        let short = cache
+            .get_or_compute_batch(&["d", "e"], |_| async { Ok(vec![vec![4.0]]) })
+            .await;
+        assert!(short.is_err());
+        cache.insert("f", Vec::new()).await.unwrap_err();
+    }
+
+    #[test]
+    fn hashes_content() {
+        assert_eq!(
+            EmbeddingCache::content_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
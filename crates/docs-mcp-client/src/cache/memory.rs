@@ -65,6 +65,7 @@ impl<T: Clone> MemoryCache<T> {
             value,
             stored_at: now,
             last_accessed: now,
+            validators: None,
         };
         self.entries.insert(key.into(), entry);
         self.stats.set_entry_count(self.entries.len());
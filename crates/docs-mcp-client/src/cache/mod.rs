@@ -1,7 +1,9 @@
 pub mod disk;
+pub mod embedding;
 pub mod memory;
 pub mod stats;
 
-pub use disk::DiskCache;
+pub use disk::{dir_usage, prune_dir, DirUsage, DiskCache, PruneSummary};
+pub use embedding::EmbeddingCache;
 pub use memory::MemoryCache;
 pub use stats::CombinedCacheStats;
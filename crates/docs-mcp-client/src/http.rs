@@ -0,0 +1,276 @@
+//! Shared construction of the HTTP clients used by every documentation provider.
+//!
+//! Network settings such as the proxy and TLS trust are process-wide: they are
+//! applied with [`set_network_settings`] before clients are built, and every
+//! client is created through [`client_builder`] so none of them can miss a setting.
+//!
+//! [`probe`], [`post_json`] and [`get_text`] are one-off requests made outside the
+//! providers' retry and rate-limit machinery.
+
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use reqwest::{Certificate, Client, ClientBuilder, NoProxy, Proxy};
+use tracing::warn;
+
+use crate::timeouts::HttpTimeouts;
+
+/// Which proxy outbound requests go through.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ProxyMode {
+    /// Use `HTTP_PROXY`/`HTTPS_PROXY`/`ALL_PROXY`, honoring `NO_PROXY`.
+    #[default]
+    System,
+    /// Connect directly, ignoring proxy environment variables.
+    Disabled,
+    /// Send every request through `url` except hosts matched by `no_proxy`
+    /// (same comma-separated syntax as `NO_PROXY`).
+    Explicit { url: String, no_proxy: Option<String> },
+}
+
+/// TLS implementation used for HTTPS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TlsBackend {
+    /// rustls with the bundled Mozilla root store.
+    #[default]
+    Rustls,
+    /// The platform TLS library and its trust store; needs the `native-tls` feature.
+    Native,
+}
+
+impl TlsBackend {
+    /// Parse `"rustls"` or `"native"`/`"native-tls"`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rustls" => Some(Self::Rustls),
+            "native" | "native-tls" | "native_tls" => Some(Self::Native),
+            _ => None,
+        }
+    }
+}
+
+/// How HTTPS connections are established and which roots are trusted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsSettings {
+    pub backend: TlsBackend,
+    /// PEM files whose certificates are trusted in addition to the built-in roots,
+    /// e.g. a corporate CA used for TLS interception.
+    pub extra_root_certificates: Vec<PathBuf>,
+}
+
+/// Process-wide network settings shared by every documentation client.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkSettings {
+    pub proxy: ProxyMode,
+    pub tls: TlsSettings,
+}
+
+static SETTINGS: RwLock<Option<NetworkSettings>> = RwLock::new(None);
+
+/// Replace the network settings used by clients built afterwards.
+pub fn set_network_settings(settings: NetworkSettings) {
+    *SETTINGS.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(settings);
+}
+
+/// The current process-wide network settings.
+pub fn network_settings() -> NetworkSettings {
+    SETTINGS
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+        .unwrap_or_default()
+}
+
+/// A client builder with `timeouts` and the process-wide network settings applied.
+pub fn client_builder(timeouts: HttpTimeouts) -> ClientBuilder {
+    client_builder_with(timeouts, &network_settings())
+}
+
+/// A client builder with `timeouts` and explicit network settings applied.
+pub fn client_builder_with(timeouts: HttpTimeouts, settings: &NetworkSettings) -> ClientBuilder {
+    let builder = timeouts.apply(Client::builder());
+    let builder = apply_proxy(builder, &settings.proxy);
+    apply_tls(builder, &settings.tls)
+}
+
+/// Send a `HEAD` request to `url` and return the status the server answered with.
+///
+/// Meant for reachability checks.
+pub async fn probe(url: &str, timeouts: HttpTimeouts) -> anyhow::Result<u16> {
+    crate::offline::ensure_online(url)?;
+    let client = client_builder(timeouts).user_agent("AppleDocsMCP/1.0").build()?;
+    let response = client.head(url).send().await?;
+    Ok(response.status().as_u16())
+}
+
+/// `POST` `body` as JSON to `url`, optionally with a bearer token, failing on non-2xx answers.
+///
+/// The token is only ever sent over `https://`; other URLs are refused when one is given.
+pub async fn post_json(
+    url: &str,
+    body: &serde_json::Value,
+    bearer_token: Option<&str>,
+    timeouts: HttpTimeouts,
+) -> anyhow::Result<u16> {
+    if bearer_token.is_some() && !is_https(url) {
+        anyhow::bail!("refusing to send a bearer token to {url}; use an https:// URL");
+    }
+    crate::offline::ensure_online(url)?;
+    let client = client_builder(timeouts).user_agent("AppleDocsMCP/1.0").build()?;
+    let mut request = client.post(url).json(body);
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        let detail = response.text().await.unwrap_or_default();
+        anyhow::bail!("{url} answered HTTP {}: {}", status.as_u16(), detail.trim());
+    }
+    Ok(status.as_u16())
+}
+
+/// `GET` `url` and return the body as text, failing on non-2xx answers, on URLs that are not
+/// `https://` and on bodies larger than `max_bytes`.
+pub async fn get_text(url: &str, timeouts: HttpTimeouts, max_bytes: usize) -> anyhow::Result<String> {
+    if !is_https(url) {
+        anyhow::bail!("refusing to download {url}; use an https:// URL");
+    }
+    crate::offline::ensure_online(url)?;
+    let client = client_builder(timeouts).user_agent("AppleDocsMCP/1.0").build()?;
+    let mut response = client.get(url).send().await?;
+    let status = response.status();
+    if !status.is_success() {
+        anyhow::bail!("{url} answered HTTP {}", status.as_u16());
+    }
+    let too_large = || anyhow::anyhow!("{url} is larger than {max_bytes} bytes");
+    if response.content_length().is_some_and(|length| length > max_bytes as u64) {
+        return Err(too_large());
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Whether `url` uses the `https` scheme.
+fn is_https(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| url.scheme() == "https")
+}
+
+fn apply_proxy(builder: ClientBuilder, proxy: &ProxyMode) -> ClientBuilder {
+    match proxy {
+        // reqwest reads the proxy environment variables unless told otherwise
+        ProxyMode::System => builder,
+        ProxyMode::Disabled => builder.no_proxy(),
+        ProxyMode::Explicit { url, no_proxy } => match Proxy::all(url) {
+            Ok(proxy) => {
+                let no_proxy = no_proxy.as_deref().and_then(NoProxy::from_string);
+                builder.proxy(proxy.no_proxy(no_proxy))
+            }
+            Err(error) => {
+                warn!(proxy = %url, error = %error, "invalid proxy URL; falling back to system proxy settings");
+                builder
+            }
+        },
+    }
+}
+
+fn apply_tls(builder: ClientBuilder, tls: &TlsSettings) -> ClientBuilder {
+    let mut builder = match tls.backend {
+        TlsBackend::Rustls => builder.use_rustls_tls(),
+        #[cfg(feature = "native-tls")]
+        TlsBackend::Native => builder.use_native_tls(),
+        #[cfg(not(feature = "native-tls"))]
+        TlsBackend::Native => {
+            warn!("built without the `native-tls` feature; using rustls");
+            builder.use_rustls_tls()
+        }
+    };
+
+    for path in &tls.extra_root_certificates {
+        match load_certificates(path) {
+            Ok(certificates) => {
+                for certificate in certificates {
+                    builder = builder.add_root_certificate(certificate);
+                }
+            }
+            Err(error) => {
+                warn!(path = %path.display(), error = %error, "skipping unreadable root certificate");
+            }
+        }
+    }
+    builder
+}
+
+fn load_certificates(path: &Path) -> anyhow::Result<Vec<Certificate>> {
+    let pem = std::fs::read(path)?;
+    let certificates = Certificate::from_pem_bundle(&pem)?;
+    anyhow::ensure!(!certificates.is_empty(), "no PEM certificates found");
+    Ok(certificates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_clients_for_every_proxy_mode() {
+        let modes = [
+            ProxyMode::System,
+            ProxyMode::Disabled,
+            ProxyMode::Explicit {
+                url: "http://proxy.corp.example:3128".to_string(),
+                no_proxy: Some("localhost,.internal.example".to_string()),
+            },
+            ProxyMode::Explicit {
+                url: "not a url".to_string(),
+                no_proxy: None,
+            },
+        ];
+        for mode in modes {
+            let builder = apply_proxy(Client::builder(), &mode);
+            assert!(builder.build().is_ok(), "{mode:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn credentials_and_downloads_require_https() {
+        let body = serde_json::json!({});
+        let error = post_json("http://hooks.example.com/feedback", &body, Some("secret"), HttpTimeouts::default())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("refusing to send a bearer token"), "{error}");
+        let error = get_text("http://packs.example.com/team.yaml", HttpTimeouts::default(), 1024)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("use an https:// URL"), "{error}");
+        assert!(is_https("https://hooks.example.com/feedback"));
+        assert!(!is_https("HTTP://hooks.example.com/feedback"));
+    }
+
+    #[test]
+    fn unreadable_certificates_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let not_pem = dir.path().join("corp-ca.pem");
+        std::fs::write(&not_pem, "not a certificate").unwrap();
+
+        let tls = TlsSettings {
+            backend: TlsBackend::Rustls,
+            extra_root_certificates: vec![not_pem.clone(), dir.path().join("missing.pem")],
+        };
+        assert!(load_certificates(&not_pem).is_err());
+        assert!(apply_tls(Client::builder(), &tls).build().is_ok());
+    }
+
+    #[test]
+    fn parses_backend_names() {
+        assert_eq!(TlsBackend::from_name("RUSTLS"), Some(TlsBackend::Rustls));
+        assert_eq!(TlsBackend::from_name("native-tls"), Some(TlsBackend::Native));
+        assert_eq!(TlsBackend::from_name("openssl"), None);
+    }
+}
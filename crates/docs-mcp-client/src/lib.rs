@@ -1,23 +1,35 @@
 pub mod cache;
+pub mod http;
+pub mod limiter;
+pub mod offline;
+pub mod rate_limit;
+pub mod retry;
+pub mod stale;
+pub mod timeouts;
 pub mod types;
 
 // Re-export commonly used cache types
 pub use cache::CombinedCacheStats;
 
-use std::{path::PathBuf, time::Duration as StdDuration};
+use std::path::PathBuf;
 
 use anyhow::{anyhow, Context, Result};
 use cache::{DiskCache, MemoryCache};
+use dashmap::DashMap;
 use directories::ProjectDirs;
+use reqwest::header::{HeaderMap, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
 use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use std::collections::HashMap;
 use thiserror::Error;
-use time::Duration;
+use time::{Duration, OffsetDateTime};
 use tokio::sync::Mutex;
 use tracing::{debug, instrument, warn};
 
-use crate::types::{FrameworkData, SymbolData, Technology};
+use crate::http::NetworkSettings;
+use crate::retry::SendWithRetry;
+use crate::timeouts::HttpTimeouts;
+use crate::types::{CacheValidators, FrameworkData, SymbolData, Technology, TutorialPage};
 
 const BASE_URL: &str = "https://developer.apple.com/tutorials/data";
 const TECHNOLOGIES_KEY: &str = "technologies";
@@ -30,12 +42,20 @@ pub enum ClientError {
     Status(StatusCode),
     #[error("cache miss")]
     CacheMiss,
+    #[error("offline mode: {0} is not cached; run `docs-mcp-cli warm` or `docs-mcp-cli cache import` first")]
+    Offline(String),
 }
 
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     pub cache_dir: PathBuf,
     pub memory_cache_ttl: Duration,
+    /// Disk cache size budget; least recently used entries are evicted beyond it.
+    pub max_cache_size_bytes: u64,
+    /// Connect and read timeouts for requests to Apple.
+    pub timeouts: HttpTimeouts,
+    /// Proxy and TLS settings; defaults to the process-wide [`http::network_settings`].
+    pub network: NetworkSettings,
 }
 
 impl Default for ClientConfig {
@@ -46,6 +66,9 @@ impl Default for ClientConfig {
         Self {
             cache_dir: project_dirs.cache_dir().to_path_buf(),
             memory_cache_ttl: Duration::minutes(10),
+            max_cache_size_bytes: cache::disk::DEFAULT_MAX_SIZE_BYTES,
+            timeouts: HttpTimeouts::APPLE,
+            network: http::network_settings(),
         }
     }
 }
@@ -57,6 +80,8 @@ pub struct AppleDocsClient {
     technologies_lock: Mutex<()>,
     frameworks_lock: Mutex<()>,
     memory_cache: MemoryCache<Vec<u8>>,
+    /// How often each framework has been requested, for background refresh.
+    framework_usage: DashMap<String, u64>,
     config: ClientConfig,
 }
 
@@ -68,9 +93,8 @@ impl Default for AppleDocsClient {
 
 impl AppleDocsClient {
     pub fn with_config(config: ClientConfig) -> Self {
-        let http = Client::builder()
+        let http = http::client_builder_with(config.timeouts, &config.network)
             .user_agent("AppleDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(15))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -83,13 +107,14 @@ impl AppleDocsClient {
             );
         }
 
-        let disk_cache = DiskCache::new(&config.cache_dir);
+        let disk_cache = DiskCache::with_max_size(&config.cache_dir, config.max_cache_size_bytes);
         Self {
             http,
             disk_cache,
             technologies_lock: Mutex::new(()),
             frameworks_lock: Mutex::new(()),
             memory_cache: MemoryCache::new(config.memory_cache_ttl),
+            framework_usage: DashMap::new(),
             config,
         }
     }
@@ -105,6 +130,7 @@ impl AppleDocsClient {
 
     #[instrument(name = "docs_mcp_client.get_framework", skip(self))]
     pub async fn get_framework(&self, framework: &str) -> Result<FrameworkData> {
+        *self.framework_usage.entry(framework.to_string()).or_default() += 1;
         let file_name = format!("{}.json", framework);
         if let Some(entry) = self.disk_cache.load::<FrameworkData>(&file_name).await? {
             debug!(framework, "framework served from disk cache");
@@ -117,21 +143,24 @@ impl AppleDocsClient {
             return Ok(entry.value);
         }
 
-        let data: FrameworkData = self
-            .fetch_json(&format!("documentation/{framework}.json"))
+        let (data, validators) = self
+            .fetch_json::<FrameworkData>(&format!("documentation/{framework}.json"))
+            .await?;
+        self.disk_cache
+            .store_with_validators(&file_name, data.clone(), Some(validators))
             .await?;
-        self.disk_cache.store(&file_name, data.clone()).await?;
         Ok(data)
     }
 
+    /// Re-download a framework, skipping the body when the server reports it unchanged.
     #[instrument(name = "docs_mcp_client.refresh_framework", skip(self))]
     pub async fn refresh_framework(&self, framework: &str) -> Result<FrameworkData> {
-        let data: FrameworkData = self
-            .fetch_json(&format!("documentation/{framework}.json"))
-            .await?;
-        let file_name = format!("{}.json", framework);
-        self.disk_cache.store(&file_name, data.clone()).await?;
-        Ok(data)
+        self.revalidate(
+            &format!("{}.json", framework),
+            &format!("documentation/{framework}.json"),
+            |value| serde_json::from_value(value).context("failed to parse framework payload"),
+        )
+        .await
     }
 
     #[instrument(name = "docs_mcp_client.get_symbol", skip(self))]
@@ -142,6 +171,13 @@ impl AppleDocsClient {
         Ok(symbol)
     }
 
+    /// A tutorial or tutorial collection, e.g. `tutorials/swiftui/creating-and-combining-views`.
+    #[instrument(name = "docs_mcp_client.get_tutorial", skip(self))]
+    pub async fn get_tutorial(&self, path: &str) -> Result<TutorialPage> {
+        let value = self.load_document(path).await?;
+        TutorialPage::from_value(&value).ok_or_else(|| anyhow!("{path} is not a tutorial page"))
+    }
+
     #[instrument(name = "docs_mcp_client.get_technologies", skip(self))]
     pub async fn get_technologies(&self) -> Result<HashMap<String, Technology>> {
         let file_name = format!("{TECHNOLOGIES_KEY}.json");
@@ -164,25 +200,93 @@ impl AppleDocsClient {
             }
         }
 
-        let value: Value = self
-            .fetch_json("documentation/technologies.json")
+        let (value, validators) = self
+            .fetch_json::<Value>("documentation/technologies.json")
             .await
             .context("failed to fetch technologies payload")?;
         let (parsed, _) = Self::extract_technologies(value)?;
-        self.disk_cache.store(&file_name, parsed.clone()).await?;
+        self.disk_cache
+            .store_with_validators(&file_name, parsed.clone(), Some(validators))
+            .await?;
         Ok(parsed)
     }
 
+    /// Re-download the technologies index, skipping the body when the server reports it unchanged.
     pub async fn refresh_technologies(&self) -> Result<HashMap<String, Technology>> {
-        let value: Value = self
-            .fetch_json("documentation/technologies.json")
-            .await
-            .context("failed to download technologies payload")?;
-        let (data, _) = Self::extract_technologies(value)?;
-        self.disk_cache
-            .store(&format!("{TECHNOLOGIES_KEY}.json"), data.clone())
-            .await?;
-        Ok(data)
+        self.revalidate(
+            &format!("{TECHNOLOGIES_KEY}.json"),
+            "documentation/technologies.json",
+            |value| Self::extract_technologies(value).map(|(data, _)| data),
+        )
+        .await
+        .context("failed to download technologies payload")
+    }
+
+    /// Conditionally re-fetch `path` using the validators stored with `file_name`.
+    /// A `304 Not Modified` keeps the cached value and only renews its timestamp;
+    /// if the request fails, the cached value is served stale (see [`stale`]).
+    async fn revalidate<T>(
+        &self,
+        file_name: &str,
+        path: &str,
+        parse: impl FnOnce(Value) -> Result<T>,
+    ) -> Result<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Clone + Send + 'static,
+    {
+        // An unreadable entry is simply refetched in full
+        let cached = self.disk_cache.load::<T>(file_name).await.ok().flatten();
+        let previous = cached.as_ref().and_then(|entry| entry.validators.as_ref());
+
+        let fetched = match self.fetch_conditional::<Value>(path, previous).await {
+            Ok(fetched) => fetched,
+            Err(error) => match cached {
+                Some(entry) => {
+                    stale::record(path, &error);
+                    return Ok(entry.value);
+                }
+                None => return Err(error),
+            },
+        };
+
+        match fetched {
+            Fetched::NotModified => {
+                let entry = cached.ok_or_else(|| anyhow!("server returned 304 for uncached {path}"))?;
+                debug!(path, "cached payload still current");
+                self.disk_cache
+                    .store_with_validators(file_name, entry.value.clone(), entry.validators)
+                    .await?;
+                Ok(entry.value)
+            }
+            Fetched::Fresh { value, validators } => {
+                let data = parse(value)?;
+                self.disk_cache
+                    .store_with_validators(file_name, data.clone(), Some(validators))
+                    .await?;
+                Ok(data)
+            }
+        }
+    }
+
+    /// Frameworks requested so far, most used first.
+    pub fn frequent_frameworks(&self, limit: usize) -> Vec<String> {
+        let mut usage: Vec<(String, u64)> = self
+            .framework_usage
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        usage.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        usage.into_iter().take(limit).map(|(name, _)| name).collect()
+    }
+
+    /// When the cached copy of `framework` was downloaded, if it is cached.
+    pub async fn framework_stored_at(&self, framework: &str) -> Result<Option<OffsetDateTime>> {
+        self.disk_cache.stored_at(&format!("{}.json", framework)).await
+    }
+
+    /// When the cached technologies index was downloaded, if it is cached.
+    pub async fn technologies_stored_at(&self) -> Result<Option<OffsetDateTime>> {
+        self.disk_cache.stored_at(&format!("{TECHNOLOGIES_KEY}.json")).await
     }
 
     pub fn clear_memory_cache(&self) {
@@ -207,12 +311,15 @@ impl AppleDocsClient {
             return Ok(entry.value);
         }
 
-        let data: Value = self.fetch_json(&format!("{clean}.json")).await?;
-        self.disk_cache.store(&file_name, data.clone()).await?;
+        let (data, validators) = self.fetch_json::<Value>(&format!("{clean}.json")).await?;
+        self.disk_cache
+            .store_with_validators(&file_name, data.clone(), Some(validators))
+            .await?;
         Ok(data)
     }
 
-    async fn fetch_json<T>(&self, path: &str) -> Result<T>
+    /// Fetch `path` along with its response validators (empty when served from memory).
+    async fn fetch_json<T>(&self, path: &str) -> Result<(T, CacheValidators)>
     where
         T: serde::de::DeserializeOwned,
     {
@@ -221,20 +328,46 @@ impl AppleDocsClient {
         if let Some(bytes) = self.memory_cache.get_with_size(&url, |v| v.len()) {
             let value = serde_json::from_slice(&bytes)
                 .with_context(|| format!("failed to parse cached json for {url}"))?;
-            return Ok(value);
+            return Ok((value, CacheValidators::default()));
+        }
+
+        match self.fetch_conditional(path, None).await? {
+            Fetched::Fresh { value, validators } => Ok((value, validators)),
+            Fetched::NotModified => Err(anyhow!("unexpected 304 for unconditional request to {url}")),
+        }
+    }
+
+    /// Fetch `path`, sending `If-None-Match`/`If-Modified-Since` from `previous` when present.
+    async fn fetch_conditional<T>(&self, path: &str, previous: Option<&CacheValidators>) -> Result<Fetched<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = format!("{BASE_URL}/{path}");
+
+        offline::ensure_online(&url)?;
+        let mut request = self.http.get(&url);
+        if let Some(previous) = previous {
+            if let Some(etag) = &previous.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &previous.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
         }
 
-        let response = self
-            .http
-            .get(&url)
-            .send()
+        let response = request
+            .send_with_retry()
             .await
             .map_err(|err| ClientError::Http(err.to_string()))?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(Fetched::NotModified);
+        }
         if !response.status().is_success() {
             warn!(status = %response.status(), url, "Apple docs request failed");
             return Err(ClientError::Status(response.status()).into());
         }
 
+        let validators = validators_from_headers(response.headers());
         let bytes = response
             .bytes()
             .await
@@ -243,7 +376,7 @@ impl AppleDocsClient {
 
         let value = serde_json::from_slice::<T>(&bytes)
             .with_context(|| format!("failed to parse json from {url}"))?;
-        Ok(value)
+        Ok(Fetched::Fresh { value, validators })
     }
 
     fn extract_technologies(value: Value) -> Result<(HashMap<String, Technology>, bool)> {
@@ -276,13 +409,77 @@ impl AppleDocsClient {
     }
 }
 
+/// Outcome of a conditional request.
+enum Fetched<T> {
+    NotModified,
+    Fresh { value: T, validators: CacheValidators },
+}
+
+fn validators_from_headers(headers: &HeaderMap) -> CacheValidators {
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    CacheValidators {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn captures_response_validators() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"v1\""));
+        headers.insert(LAST_MODIFIED, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+
+        let validators = validators_from_headers(&headers);
+        assert_eq!(validators.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(validators.last_modified.as_deref(), Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert!(validators_from_headers(&HeaderMap::new()).is_empty());
+    }
 
     #[tokio::test]
     async fn defaults_provide_cache_dir() {
         let client = AppleDocsClient::new();
         assert!(client.cache_dir().exists());
     }
+
+    #[tokio::test]
+    async fn failed_revalidation_serves_cached_copy() {
+        // A proxy on a closed local port makes every fetch fail without touching the
+        // network or process-wide settings
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let dir = tempfile::tempdir().unwrap();
+        let client = AppleDocsClient::with_config(ClientConfig {
+            cache_dir: dir.path().to_path_buf(),
+            network: NetworkSettings {
+                proxy: http::ProxyMode::Explicit {
+                    url: format!("http://127.0.0.1:{port}"),
+                    no_proxy: None,
+                },
+                ..NetworkSettings::default()
+            },
+            ..ClientConfig::default()
+        });
+        client
+            .disk_cache
+            .store("technologies.json", HashMap::<String, Technology>::new())
+            .await
+            .unwrap();
+
+        let (refreshed, notes) = stale::track(client.refresh_technologies()).await;
+        let (uncached, _) = stale::track(client.refresh_framework("swiftui")).await;
+
+        assert!(refreshed.unwrap().is_empty());
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].resource, "documentation/technologies.json");
+        assert!(uncached.is_err());
+    }
 }
@@ -0,0 +1,82 @@
+//! Process-wide cap on simultaneous outbound requests.
+//!
+//! Every documentation client shares one semaphore, so federated searches and
+//! index expansions queue behind each other instead of opening dozens of
+//! connections at once and tripping upstream rate limits.
+
+use std::sync::{Arc, RwLock};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Default number of requests allowed in flight across all providers.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+static LIMITER: RwLock<Option<(usize, Arc<Semaphore>)>> = RwLock::new(None);
+
+/// Change the number of requests allowed in flight; `0` is treated as `1`.
+///
+/// Requests already holding a permit finish normally; the new limit applies to
+/// requests started afterwards.
+pub fn set_max_concurrent_requests(limit: usize) {
+    let limit = limit.max(1);
+    let mut limiter = LIMITER.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if limiter.as_ref().is_some_and(|(current, _)| *current == limit) {
+        return;
+    }
+    *limiter = Some((limit, Arc::new(Semaphore::new(limit))));
+}
+
+/// Number of requests currently allowed in flight.
+pub fn max_concurrent_requests() -> usize {
+    semaphore().0
+}
+
+/// Wait for a free slot; the request may proceed while the permit is held.
+pub async fn acquire() -> OwnedSemaphorePermit {
+    semaphore()
+        .1
+        .acquire_owned()
+        .await
+        .expect("outbound request semaphore is never closed")
+}
+
+fn semaphore() -> (usize, Arc<Semaphore>) {
+    if let Some((limit, semaphore)) = LIMITER
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        return (*limit, semaphore.clone());
+    }
+    let mut limiter = LIMITER.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let (limit, semaphore) = limiter.get_or_insert_with(|| {
+        (
+            DEFAULT_MAX_CONCURRENT_REQUESTS,
+            Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENT_REQUESTS)),
+        )
+    });
+    (*limit, semaphore.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn limits_requests_in_flight() {
+        set_max_concurrent_requests(2);
+        assert_eq!(max_concurrent_requests(), 2);
+
+        let first = acquire().await;
+        let _second = acquire().await;
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(20), acquire())
+                .await
+                .is_err(),
+            "third request waits for a free slot"
+        );
+
+        drop(first);
+        let _third = acquire().await;
+    }
+}
@@ -0,0 +1,44 @@
+//! Process-wide strict offline switch shared by every documentation client.
+//!
+//! When enabled, clients answer only from their caches and every outbound
+//! request fails immediately with [`ClientError::Offline`] instead of waiting
+//! on a network timeout.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::ClientError;
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable strict offline mode.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Whether strict offline mode is enabled.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Call before any outbound request; fails with [`ClientError::Offline`] in offline mode.
+pub fn ensure_online(url: &str) -> Result<(), ClientError> {
+    if is_offline() {
+        return Err(ClientError::Offline(url.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_requests_only_when_offline() {
+        set_offline(true);
+        let err = ensure_online("https://example.com/doc.json").unwrap_err();
+        assert!(err.to_string().contains("not cached"));
+
+        set_offline(false);
+        assert!(ensure_online("https://example.com/doc.json").is_ok());
+    }
+}
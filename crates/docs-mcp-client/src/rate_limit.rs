@@ -0,0 +1,131 @@
+//! Client-side token-bucket rate limiting per upstream host.
+//!
+//! Each host (`developer.apple.com`, `raw.githubusercontent.com`, ...) gets its
+//! own bucket. Requests beyond the bucket's capacity are queued until a token
+//! frees up instead of being sent and rejected with `429 Too Many Requests`.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Token-bucket parameters applied to every host.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Sustained requests per second per host; `0` disables rate limiting.
+    pub requests_per_second: f64,
+    /// Requests a host may receive back to back before pacing kicks in.
+    pub burst: u32,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 5.0,
+            burst: 10,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Negative when requests are queued waiting for tokens.
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+static LIMIT: RwLock<Option<RateLimit>> = RwLock::new(None);
+static BUCKETS: Mutex<Option<HashMap<String, Bucket>>> = Mutex::new(None);
+
+/// Replace the per-host rate limit; existing buckets start over.
+pub fn set_rate_limit(limit: RateLimit) {
+    *LIMIT.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(limit);
+    *BUCKETS.lock().unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+}
+
+/// The current per-host rate limit.
+pub fn rate_limit() -> RateLimit {
+    LIMIT
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .unwrap_or_default()
+}
+
+/// Wait until a request to `host` fits within its rate limit.
+pub async fn throttle(host: &str) {
+    let wait = reserve(host, rate_limit(), Instant::now());
+    if !wait.is_zero() {
+        debug!(host, wait_ms = wait.as_millis(), "rate limit reached; queueing request");
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Take a token for `host`, returning how long the caller must wait for it.
+fn reserve(host: &str, limit: RateLimit, now: Instant) -> Duration {
+    if limit.requests_per_second <= 0.0 {
+        return Duration::ZERO;
+    }
+    let capacity = f64::from(limit.burst.max(1));
+
+    let mut buckets = BUCKETS.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let bucket = buckets
+        .get_or_insert_with(HashMap::new)
+        .entry(host.to_string())
+        .or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+
+    let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+    bucket.tokens = (bucket.tokens + elapsed * limit.requests_per_second).min(capacity);
+    bucket.refilled_at = now;
+
+    // Reserving ahead of time queues callers in arrival order
+    bucket.tokens -= 1.0;
+    if bucket.tokens >= 0.0 {
+        Duration::ZERO
+    } else {
+        Duration::from_secs_f64(-bucket.tokens / limit.requests_per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paces_requests_after_burst() {
+        let limit = RateLimit {
+            requests_per_second: 2.0,
+            burst: 2,
+        };
+        let start = Instant::now();
+        let host = "paces.example";
+
+        assert_eq!(reserve(host, limit, start), Duration::ZERO);
+        assert_eq!(reserve(host, limit, start), Duration::ZERO);
+        assert_eq!(reserve(host, limit, start), Duration::from_millis(500));
+        assert_eq!(reserve(host, limit, start), Duration::from_millis(1000));
+
+        // Other hosts have their own bucket
+        assert_eq!(reserve("other.example", limit, start), Duration::ZERO);
+
+        // Tokens refill over time
+        let later = start + Duration::from_secs(3);
+        assert_eq!(reserve(host, limit, later), Duration::ZERO);
+    }
+
+    #[test]
+    fn zero_rate_disables_limiting() {
+        let limit = RateLimit {
+            requests_per_second: 0.0,
+            burst: 1,
+        };
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert_eq!(reserve("disabled.example", limit, now), Duration::ZERO);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Retry with jittered exponential backoff for outbound documentation requests.
+//!
+//! Transient failures — timeouts, connection resets, `429 Too Many Requests`
+//! and `5xx` responses — are retried according to a process-wide
+//! [`RetryPolicy`]; everything else is returned to the caller unchanged. Each
+//! attempt first waits for the host's [`rate_limit`] and then holds a
+//! [`limiter`] permit, which is released while backing off.
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Request, RequestBuilder, Response, StatusCode};
+use tracing::debug;
+
+use crate::{limiter, rate_limit};
+
+/// How transient request failures are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Additional attempts after the first; `0` disables retries.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled on every further attempt.
+    pub base_delay: Duration,
+    /// Upper bound for a single delay, including `Retry-After` hints.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff before retry number `attempt` (starting at 0): half fixed, half random.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        let capped = exponential.min(self.max_delay);
+        let half = capped / 2;
+        half + half.mul_f64(jitter())
+    }
+}
+
+static POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+/// Replace the process-wide retry policy.
+pub fn set_retry_policy(policy: RetryPolicy) {
+    *POLICY.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(policy);
+}
+
+/// The current process-wide retry policy.
+pub fn retry_policy() -> RetryPolicy {
+    POLICY
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .unwrap_or_default()
+}
+
+/// `RequestBuilder::send` with retries for transient failures.
+pub trait SendWithRetry {
+    fn send_with_retry(self) -> impl Future<Output = reqwest::Result<Response>> + Send;
+}
+
+impl SendWithRetry for RequestBuilder {
+    fn send_with_retry(self) -> impl Future<Output = reqwest::Result<Response>> + Send {
+        send_with_policy(self, retry_policy())
+    }
+}
+
+async fn send_with_policy(request: RequestBuilder, policy: RetryPolicy) -> reqwest::Result<Response> {
+    let (client, request) = request.build_split();
+    let request = request?;
+    let host = request.url().host_str().unwrap_or_default().to_string();
+
+    let mut attempt = 0;
+    loop {
+        // Requests with streaming bodies can't be replayed; send those once
+        let Some(retry_request) = (attempt < policy.max_retries)
+            .then(|| request.try_clone())
+            .flatten()
+        else {
+            return execute(&client, &host, request).await;
+        };
+
+        let delay = match execute(&client, &host, retry_request).await {
+            Ok(response) if is_retryable_status(response.status()) => {
+                let hinted = retry_after(&response).map(|hint| hint.min(policy.max_delay));
+                debug!(status = %response.status(), url = %response.url(), attempt, "retrying request");
+                hinted.unwrap_or_else(|| policy.backoff(attempt))
+            }
+            Err(error) if is_retryable_error(&error) => {
+                debug!(error = %error, attempt, "retrying request");
+                policy.backoff(attempt)
+            }
+            outcome => return outcome,
+        };
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// A single attempt, paced by the host's rate limit and the global concurrency cap.
+async fn execute(client: &Client, host: &str, request: Request) -> reqwest::Result<Response> {
+    rate_limit::throttle(host).await;
+    let _permit = limiter::acquire().await;
+    client.execute(request).await
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_timeout() || error.is_connect()
+}
+
+/// `Retry-After` given in seconds (HTTP dates are ignored in favour of backoff).
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Uniform value in `[0, 1)` from the std hasher's per-instance random keys.
+fn jitter() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+        for attempt in 0..8 {
+            let ceiling = Duration::from_millis(100 * (1 << attempt)).min(policy.max_delay);
+            let delay = policy.backoff(attempt);
+            assert!(delay >= ceiling / 2 && delay <= ceiling, "attempt {attempt}: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn retries_only_transient_statuses() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::NOT_MODIFIED));
+    }
+
+    #[test]
+    fn jitter_is_a_fraction() {
+        for _ in 0..100 {
+            let value = jitter();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
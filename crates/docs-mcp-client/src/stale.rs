@@ -0,0 +1,77 @@
+//! Notes about payloads served from an outdated cache entry.
+//!
+//! When re-fetching a cached payload fails, clients fall back to the cached copy
+//! and [`record`] it instead of returning the error. Callers that want to tell
+//! the user run their work inside [`track`] and get back every note recorded
+//! along the way; outside of [`track`] the fallback is only logged.
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use serde::Serialize;
+use tracing::warn;
+
+/// Reason attached to responses that include a stale payload.
+pub const STALE_REASON: &str = "served stale due to network error";
+
+tokio::task_local! {
+    static NOTES: RefCell<Vec<StaleNote>>;
+}
+
+/// A cached payload that was served because refreshing it failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StaleNote {
+    /// The cache entry or upstream path that could not be refreshed.
+    pub resource: String,
+    /// Why the refresh failed.
+    pub error: String,
+}
+
+/// Note that `resource` was served from cache because fetching it failed with `error`.
+pub fn record(resource: impl Into<String>, error: &anyhow::Error) {
+    let note = StaleNote {
+        resource: resource.into(),
+        error: format!("{error:#}"),
+    };
+    warn!(resource = %note.resource, error = %note.error, "fetch failed; {STALE_REASON}");
+    // Not tracking is fine: the warning above is all we can do then
+    let _ = NOTES.try_with(|notes| notes.borrow_mut().push(note));
+}
+
+/// Run `future`, collecting every [`record`]ed note along the way.
+pub async fn track<F: Future>(future: F) -> (F::Output, Vec<StaleNote>) {
+    NOTES
+        .scope(RefCell::new(Vec::new()), async move {
+            let output = future.await;
+            let notes = NOTES.with(RefCell::take);
+            (output, notes)
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn collects_notes_within_scope_only() {
+        record("outside", &anyhow::anyhow!("ignored"));
+
+        let (value, notes) = track(async {
+            record("documentation/swiftui.json", &anyhow::anyhow!("connection reset"));
+            42
+        })
+        .await;
+        assert_eq!(value, 42);
+        assert_eq!(
+            notes,
+            vec![StaleNote {
+                resource: "documentation/swiftui.json".to_string(),
+                error: "connection reset".to_string(),
+            }]
+        );
+
+        let ((), notes) = track(async {}).await;
+        assert!(notes.is_empty());
+    }
+}
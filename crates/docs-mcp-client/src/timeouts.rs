@@ -0,0 +1,39 @@
+//! Connect and read timeouts for documentation HTTP clients.
+
+use std::time::Duration;
+
+use reqwest::ClientBuilder;
+
+/// Timeouts applied to a documentation client's HTTP requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpTimeouts {
+    /// Time allowed to establish a connection.
+    pub connect: Duration,
+    /// Time allowed for a whole request, from sending it to reading the last byte.
+    pub read: Duration,
+}
+
+impl HttpTimeouts {
+    /// Apple's CDN is fast; a slow Apple response usually means it is stuck.
+    pub const APPLE: Self = Self::new(10, 15);
+    /// Provider hosts (GitHub raw, docs.rs, MDN, ...) can be slow to produce large pages.
+    pub const PROVIDER: Self = Self::new(10, 30);
+
+    const fn new(connect_secs: u64, read_secs: u64) -> Self {
+        Self {
+            connect: Duration::from_secs(connect_secs),
+            read: Duration::from_secs(read_secs),
+        }
+    }
+
+    /// Configure `builder` with these timeouts.
+    pub fn apply(self, builder: ClientBuilder) -> ClientBuilder {
+        builder.connect_timeout(self.connect).timeout(self.read)
+    }
+}
+
+impl Default for HttpTimeouts {
+    fn default() -> Self {
+        Self::PROVIDER
+    }
+}
@@ -1,9 +1,13 @@
 pub mod models;
+pub mod tutorials;
+pub mod variants;
 
 pub use models::{
-    CacheEntry, FrameworkData, FrameworkMetadata, PlatformInfo, ReferenceData, RichText,
+    CacheEntry, CacheValidators, FrameworkData, FrameworkMetadata, PlatformInfo, ReferenceData, RichText,
     SearchResult, SymbolData, SymbolMetadata, Technology, TopicData, TopicMetadata, TopicSection,
 };
+pub use tutorials::{TutorialCode, TutorialPage, TutorialStep};
+pub use variants::{apply_language_variant, SymbolLanguage};
 
 pub fn extract_text(segments: &[RichText]) -> String {
     segments
@@ -33,3 +37,75 @@ pub fn format_platforms(platforms: &[PlatformInfo]) -> String {
         .collect::<Vec<_>>()
         .join(", ")
 }
+
+/// Canonical form of an Apple platform name, so `visionOS`, `xrOS` and `vision os` compare equal.
+pub fn normalize_platform(name: &str) -> String {
+    let compact: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    match compact.as_str() {
+        "xros" | "vision" | "applevision" => "visionos".to_string(),
+        "catalyst" | "uikitformac" => "maccatalyst".to_string(),
+        "mac" | "osx" | "macosx" => "macos".to_string(),
+        "watch" => "watchos".to_string(),
+        "tv" | "appletv" => "tvos".to_string(),
+        "ipad" => "ipados".to_string(),
+        _ => compact,
+    }
+}
+
+/// Whether `platforms` lists `platform`. An empty list means availability is unknown, which
+/// is treated as available.
+pub fn available_on(platforms: &[PlatformInfo], platform: &str) -> bool {
+    let wanted = normalize_platform(platform);
+    platforms.is_empty()
+        || platforms
+            .iter()
+            .any(|info| normalize_platform(&info.name) == wanted)
+}
+
+/// Whether any listed platform ships the API in beta.
+pub fn has_beta(platforms: &[PlatformInfo]) -> bool {
+    platforms.iter().any(|platform| platform.beta)
+}
+
+/// Whether the API is only available in beta releases, on every listed platform.
+pub fn beta_only(platforms: &[PlatformInfo]) -> bool {
+    !platforms.is_empty() && platforms.iter().all(|platform| platform.beta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn platform(name: &str) -> PlatformInfo {
+        PlatformInfo {
+            name: name.to_string(),
+            introduced_at: None,
+            beta: false,
+        }
+    }
+
+    #[test]
+    fn matches_platform_aliases() {
+        let platforms = vec![platform("iOS"), platform("Mac Catalyst"), platform("visionOS")];
+        assert!(available_on(&platforms, "xrOS"));
+        assert!(available_on(&platforms, "catalyst"));
+        assert!(available_on(&platforms, "ios"));
+        assert!(!available_on(&platforms, "iPadOS"));
+        assert!(!available_on(&platforms, "watchOS"));
+        assert!(available_on(&[], "tvOS"));
+    }
+
+    #[test]
+    fn classifies_beta_availability() {
+        let mut visionos = platform("visionOS");
+        visionos.beta = true;
+        assert!(beta_only(std::slice::from_ref(&visionos)));
+        assert!(has_beta(&[platform("iOS"), visionos.clone()]));
+        assert!(!beta_only(&[platform("iOS"), visionos]));
+        assert!(!beta_only(&[]));
+    }
+}
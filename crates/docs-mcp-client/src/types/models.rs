@@ -68,6 +68,22 @@ pub struct SymbolData {
     pub references: HashMap<String, ReferenceData>,
     #[serde(default, rename = "topicSections")]
     pub topic_sections: Vec<TopicSection>,
+    #[serde(default, rename = "relationshipsSections")]
+    pub relationships_sections: Vec<RelationshipsSection>,
+    /// Default implementations of a protocol requirement, grouped by extension.
+    #[serde(default, rename = "defaultImplementationsSections")]
+    pub default_implementations_sections: Vec<TopicSection>,
+}
+
+/// A relationship group such as "Conforms To" or "Conforming Types".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipsSection {
+    /// `conformsTo`, `conformingTypes`, `inheritsFrom`, `inheritedBy`, ...
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    pub title: String,
+    #[serde(default)]
+    pub identifiers: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,6 +127,24 @@ pub struct CacheEntry<T> {
     pub stored_at: OffsetDateTime,
     #[serde(default = "OffsetDateTime::now_utc")]
     pub last_accessed: OffsetDateTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validators: Option<CacheValidators>,
+}
+
+/// HTTP validators captured from the response that produced a cache entry,
+/// replayed as `If-None-Match`/`If-Modified-Since` when revalidating it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheValidators {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,419 @@
+//! Apple tutorial pages (`/tutorials/...`).
+//!
+//! Tutorials are DocC render nodes too, but instead of `primaryContentSections` they carry
+//! `sections`: a hero, then tasks made of steps whose code lives in `file` references. Each
+//! file reference highlights the lines that changed since the previous step.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// A tutorial (`project`) or the table of contents of a tutorial collection (`overview`).
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialPage {
+    pub title: String,
+    /// `project` for a single tutorial, `overview` for a collection.
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chapter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_minutes: Option<u64>,
+    pub intro: String,
+    /// Tasks of a tutorial, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<TutorialSection>,
+    /// Chapters of a collection, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub chapters: Vec<TutorialChapter>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialSection {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    pub overview: String,
+    pub steps: Vec<TutorialStep>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialStep {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TutorialCode>,
+}
+
+/// The state of one source file after a step.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialCode {
+    pub file_name: String,
+    pub syntax: String,
+    pub lines: Vec<String>,
+    /// 1-based lines added or changed by the step.
+    pub highlights: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialChapter {
+    pub name: String,
+    pub summary: String,
+    pub tutorials: Vec<TutorialLink>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TutorialLink {
+    pub title: String,
+    pub path: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_time: Option<String>,
+}
+
+impl TutorialPage {
+    /// Parse a tutorial render node; `None` for any other kind of page.
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let kind = value
+            .pointer("/metadata/role")
+            .or_else(|| value.get("kind"))
+            .and_then(Value::as_str)?;
+        if kind != "project" && kind != "overview" {
+            return None;
+        }
+        let sections = value.get("sections")?.as_array()?;
+        let empty = Map::new();
+        let references = value.get("references").and_then(Value::as_object).unwrap_or(&empty);
+
+        let mut page = TutorialPage {
+            title: value
+                .pointer("/metadata/title")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            kind: kind.to_string(),
+            ..Default::default()
+        };
+        for section in sections {
+            match section.get("kind").and_then(Value::as_str) {
+                Some("hero") => {
+                    if page.title.is_empty() {
+                        page.title = string_field(section, "title");
+                    }
+                    page.chapter = section.get("chapter").and_then(Value::as_str).map(str::to_string);
+                    page.estimated_minutes =
+                        section.get("estimatedTimeInMinutes").and_then(Value::as_u64);
+                    page.intro = blocks_text(section.get("content"), references);
+                }
+                Some("tasks") => {
+                    let tasks = section.get("tasks").and_then(Value::as_array).into_iter().flatten();
+                    page.sections.extend(tasks.map(|task| parse_task(task, references)));
+                }
+                Some("volume") => {
+                    let chapters = section.get("chapters").and_then(Value::as_array).into_iter().flatten();
+                    page.chapters
+                        .extend(chapters.map(|chapter| parse_chapter(chapter, references)));
+                }
+                _ => {}
+            }
+        }
+        Some(page)
+    }
+
+    pub fn step_count(&self) -> usize {
+        self.sections.iter().map(|section| section.steps.len()).sum()
+    }
+}
+
+impl TutorialCode {
+    /// The step's changes as a unified-diff style listing: highlighted lines are marked `+`
+    /// and shown with `context` unchanged lines around them. A file without highlights is new
+    /// in this step and is listed in full.
+    pub fn diff(&self, context: usize) -> String {
+        if self.highlights.is_empty() {
+            return self.lines.iter().map(|line| format!("+{line}")).collect::<Vec<_>>().join("\n");
+        }
+        let changed = |index: usize| self.highlights.contains(&(index + 1));
+        let visible = |index: usize| {
+            let start = index.saturating_sub(context);
+            let end = (index + context).min(self.lines.len().saturating_sub(1));
+            (start..=end).any(changed)
+        };
+        let mut out = Vec::new();
+        let mut previous: Option<usize> = None;
+        for (index, line) in self.lines.iter().enumerate() {
+            if !visible(index) {
+                continue;
+            }
+            if previous.map_or(true, |previous| previous + 1 != index) {
+                out.push(format!("@@ line {} @@", index + 1));
+            }
+            out.push(format!("{}{line}", if changed(index) { '+' } else { ' ' }));
+            previous = Some(index);
+        }
+        out.join("\n")
+    }
+}
+
+fn parse_task(task: &Value, references: &Map<String, Value>) -> TutorialSection {
+    let overview = task
+        .get("contentSection")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|section| blocks_text(section.get("content"), references))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let steps = task
+        .get("stepsSection")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|step| step.get("type").and_then(Value::as_str) == Some("step"))
+        .map(|step| TutorialStep {
+            text: blocks_text(step.get("content"), references),
+            caption: Some(blocks_text(step.get("caption"), references)).filter(|text| !text.is_empty()),
+            code: step
+                .get("code")
+                .and_then(Value::as_str)
+                .and_then(|identifier| references.get(identifier))
+                .map(parse_code),
+        })
+        .collect();
+    TutorialSection {
+        title: string_field(task, "title"),
+        anchor: task.get("anchor").and_then(Value::as_str).map(str::to_string),
+        overview,
+        steps,
+    }
+}
+
+fn parse_code(file: &Value) -> TutorialCode {
+    TutorialCode {
+        file_name: string_field(file, "fileName"),
+        syntax: file
+            .get("syntax")
+            .or_else(|| file.get("fileType"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        lines: file
+            .get("content")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|line| line.as_str().unwrap_or_default().to_string())
+            .collect(),
+        highlights: file
+            .get("highlights")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|highlight| highlight.get("line").and_then(Value::as_u64))
+            .map(|line| line as usize)
+            .collect(),
+    }
+}
+
+fn parse_chapter(chapter: &Value, references: &Map<String, Value>) -> TutorialChapter {
+    let tutorials = chapter
+        .get("tutorials")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter_map(|identifier| {
+            let reference = references.get(identifier)?;
+            Some(TutorialLink {
+                title: string_field(reference, "title"),
+                path: reference
+                    .get("url")
+                    .and_then(Value::as_str)
+                    .unwrap_or(identifier)
+                    .to_string(),
+                summary: inline_text(reference.get("abstract"), references),
+                estimated_time: reference
+                    .get("estimatedTime")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            })
+        })
+        .collect();
+    TutorialChapter {
+        name: string_field(chapter, "name"),
+        summary: blocks_text(chapter.get("content"), references),
+        tutorials,
+    }
+}
+
+fn string_field(value: &Value, key: &str) -> String {
+    value.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// Plain text of block content (paragraphs, asides, lists), paragraphs separated by blank lines.
+fn blocks_text(blocks: Option<&Value>, references: &Map<String, Value>) -> String {
+    let mut paragraphs = Vec::new();
+    for block in blocks.and_then(Value::as_array).into_iter().flatten() {
+        match block.get("type").and_then(Value::as_str) {
+            Some("paragraph") => paragraphs.push(inline_text(block.get("inlineContent"), references)),
+            Some("aside") => paragraphs.push(blocks_text(block.get("content"), references)),
+            Some("unorderedList" | "orderedList") => {
+                let items = block.get("items").and_then(Value::as_array).into_iter().flatten();
+                paragraphs.extend(
+                    items.map(|item| format!("- {}", blocks_text(item.get("content"), references))),
+                );
+            }
+            _ => {}
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.trim().is_empty());
+    paragraphs.join("\n\n")
+}
+
+fn inline_text(inline: Option<&Value>, references: &Map<String, Value>) -> String {
+    let mut text = String::new();
+    for item in inline.and_then(Value::as_array).into_iter().flatten() {
+        match item.get("type").and_then(Value::as_str) {
+            Some("text") => text.push_str(item.get("text").and_then(Value::as_str).unwrap_or_default()),
+            Some("codeVoice") => {
+                text.push('`');
+                text.push_str(item.get("code").and_then(Value::as_str).unwrap_or_default());
+                text.push('`');
+            }
+            Some("reference") => {
+                let title = item
+                    .get("identifier")
+                    .and_then(Value::as_str)
+                    .and_then(|identifier| references.get(identifier))
+                    .and_then(|reference| reference.get("title"))
+                    .and_then(Value::as_str);
+                if let Some(title) = title {
+                    text.push_str(title);
+                }
+            }
+            _ => text.push_str(&inline_text(item.get("inlineContent"), references)),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn project() -> Value {
+        json!({
+            "kind": "project",
+            "metadata": {"title": "Creating and combining views", "role": "project"},
+            "sections": [
+                {
+                    "kind": "hero",
+                    "chapter": "SwiftUI essentials",
+                    "estimatedTimeInMinutes": 40,
+                    "content": [{"type": "paragraph", "inlineContent": [
+                        {"type": "text", "text": "Build "},
+                        {"type": "emphasis", "inlineContent": [{"type": "text", "text": "Landmarks"}]},
+                        {"type": "text", "text": "."}
+                    ]}]
+                },
+                {
+                    "kind": "tasks",
+                    "tasks": [{
+                        "title": "Customize the text view",
+                        "anchor": "Customize-the-text-view",
+                        "contentSection": [{"kind": "contentAndMedia", "content": [
+                            {"type": "paragraph", "inlineContent": [{"type": "text", "text": "Change the text."}]}
+                        ]}],
+                        "stepsSection": [
+                            {"type": "step", "content": [{"type": "paragraph", "inlineContent": [
+                                {"type": "text", "text": "Add a "},
+                                {"type": "codeVoice", "code": "font(_:)"},
+                                {"type": "text", "text": " modifier."}
+                            ]}], "caption": [], "code": "step-2.swift"},
+                            {"type": "paragraph", "inlineContent": [{"type": "text", "text": "Interstitial"}]}
+                        ]
+                    }]
+                }
+            ],
+            "references": {
+                "step-2.swift": {
+                    "type": "file",
+                    "fileName": "ContentView.swift",
+                    "syntax": "swift",
+                    "content": [
+                        "import SwiftUI",
+                        "",
+                        "struct ContentView: View {",
+                        "    var body: some View {",
+                        "        Text(\"Turtle Rock\")",
+                        "            .font(.title)",
+                        "    }",
+                        "}"
+                    ],
+                    "highlights": [{"line": 6}]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn parses_tutorial_steps_and_code() {
+        let page = TutorialPage::from_value(&project()).expect("tutorial");
+        assert_eq!(page.kind, "project");
+        assert_eq!(page.chapter.as_deref(), Some("SwiftUI essentials"));
+        assert_eq!(page.estimated_minutes, Some(40));
+        assert_eq!(page.intro, "Build Landmarks.");
+        assert_eq!(page.sections.len(), 1);
+        assert_eq!(page.sections[0].overview, "Change the text.");
+        assert_eq!(page.step_count(), 1);
+        let step = &page.sections[0].steps[0];
+        assert_eq!(step.text, "Add a `font(_:)` modifier.");
+        assert!(step.caption.is_none());
+        let code = step.code.as_ref().expect("code");
+        assert_eq!(code.file_name, "ContentView.swift");
+        assert_eq!(code.highlights, vec![6]);
+        assert_eq!(
+            code.diff(1),
+            "@@ line 5 @@\n         Text(\"Turtle Rock\")\n+            .font(.title)\n     }"
+        );
+
+        assert!(TutorialPage::from_value(&json!({"metadata": {"role": "symbol"}, "sections": []})).is_none());
+    }
+
+    #[test]
+    fn parses_collection_chapters() {
+        let overview = json!({
+            "kind": "overview",
+            "metadata": {"title": "Introducing SwiftUI", "role": "overview"},
+            "sections": [
+                {"kind": "hero", "content": [], "estimatedTimeInMinutes": 270},
+                {"kind": "volume", "chapters": [{
+                    "name": "SwiftUI essentials",
+                    "content": [{"type": "paragraph", "inlineContent": [{"type": "text", "text": "Learn the basics."}]}],
+                    "tutorials": ["doc://tutorials/creating-and-combining-views"]
+                }]}
+            ],
+            "references": {
+                "doc://tutorials/creating-and-combining-views": {
+                    "title": "Creating and combining views",
+                    "url": "/tutorials/swiftui/creating-and-combining-views",
+                    "abstract": [{"type": "text", "text": "Build the Landmarks app."}],
+                    "estimatedTime": "40min"
+                }
+            }
+        });
+        let page = TutorialPage::from_value(&overview).expect("overview");
+        assert_eq!(page.chapters.len(), 1);
+        let link = &page.chapters[0].tutorials[0];
+        assert_eq!(link.path, "/tutorials/swiftui/creating-and-combining-views");
+        assert_eq!(link.summary, "Build the Landmarks app.");
+        assert_eq!(link.estimated_time.as_deref(), Some("40min"));
+    }
+}
@@ -0,0 +1,245 @@
+//! Source-language variants of Apple documentation pages.
+//!
+//! DocC pages are rendered for Swift; the Objective-C variant is described by
+//! `variantOverrides`, a list of JSON patches tagged with the interface language they apply to.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+/// Source language a symbol page is rendered in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymbolLanguage {
+    #[default]
+    Swift,
+    ObjectiveC,
+}
+
+impl SymbolLanguage {
+    /// The `interfaceLanguage` identifier DocC uses for this language.
+    pub fn interface_language(self) -> &'static str {
+        match self {
+            Self::Swift => "swift",
+            Self::ObjectiveC => "occ",
+        }
+    }
+
+    /// Info string for fenced code blocks.
+    pub fn code_fence(self) -> &'static str {
+        match self {
+            Self::Swift => "swift",
+            Self::ObjectiveC => "objc",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Swift => "Swift",
+            Self::ObjectiveC => "Objective-C",
+        }
+    }
+}
+
+impl fmt::Display for SymbolLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code_fence())
+    }
+}
+
+impl FromStr for SymbolLanguage {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "swift" => Ok(Self::Swift),
+            "objc" | "occ" | "objective-c" | "objectivec" => Ok(Self::ObjectiveC),
+            other => bail!("unknown language '{other}' (expected swift or objc)"),
+        }
+    }
+}
+
+/// Rewrite a DocC page in place so it describes `language`.
+///
+/// Applies the page's `variantOverrides` for that language (declarations, titles, availability)
+/// and drops declarations written for other languages. Swift pages are returned as served,
+/// apart from the declaration filtering.
+pub fn apply_language_variant(document: &mut Value, language: SymbolLanguage) {
+    let interface = language.interface_language();
+    if language != SymbolLanguage::Swift {
+        let patches: Vec<Value> = document
+            .get("variantOverrides")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|variant| applies_to(variant, interface))
+            .filter_map(|variant| variant.get("patch").and_then(Value::as_array))
+            .flatten()
+            .cloned()
+            .collect();
+        for operation in &patches {
+            apply_operation(document, operation);
+        }
+    }
+    if let Some(sections) = document
+        .get_mut("primaryContentSections")
+        .and_then(Value::as_array_mut)
+    {
+        for section in sections {
+            retain_declarations(section, interface);
+        }
+    }
+}
+
+fn applies_to(variant: &Value, interface: &str) -> bool {
+    variant
+        .get("traits")
+        .and_then(Value::as_array)
+        .is_some_and(|traits| {
+            traits
+                .iter()
+                .any(|trait_| trait_.get("interfaceLanguage").and_then(Value::as_str) == Some(interface))
+        })
+}
+
+/// Keep only declarations for `interface` when the section has any.
+fn retain_declarations(section: &mut Value, interface: &str) {
+    let Some(declarations) = section.get_mut("declarations").and_then(Value::as_array_mut) else {
+        return;
+    };
+    let matches = |declaration: &Value| {
+        declaration
+            .get("languages")
+            .and_then(Value::as_array)
+            .is_some_and(|languages| languages.iter().any(|language| language == interface))
+    };
+    if declarations.iter().any(matches) {
+        declarations.retain(matches);
+    }
+}
+
+/// Apply one JSON Patch (RFC 6902) `add`/`replace`/`remove` operation; others are ignored.
+fn apply_operation(document: &mut Value, operation: &Value) {
+    let (Some(op), Some(path)) = (
+        operation.get("op").and_then(Value::as_str),
+        operation.get("path").and_then(Value::as_str),
+    ) else {
+        return;
+    };
+    let Some((parent, last)) = path.rsplit_once('/') else {
+        return;
+    };
+    let key = unescape(last);
+    let Some(target) = document.pointer_mut(parent) else {
+        return;
+    };
+    let value = operation.get("value").cloned();
+    match (op, target) {
+        ("replace" | "add", Value::Object(map)) => {
+            if let Some(value) = value {
+                map.insert(key, value);
+            }
+        }
+        ("remove", Value::Object(map)) => {
+            map.remove(&key);
+        }
+        (op, Value::Array(items)) => {
+            let index = if key == "-" { Some(items.len()) } else { key.parse::<usize>().ok() };
+            let Some(index) = index else {
+                return;
+            };
+            match (op, value) {
+                ("replace", Some(value)) if index < items.len() => items[index] = value,
+                ("add", Some(value)) if index <= items.len() => items.insert(index, value),
+                ("remove", _) if index < items.len() => {
+                    items.remove(index);
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Decode a JSON Pointer reference token (`~1` is `/`, `~0` is `~`).
+fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn page() -> Value {
+        json!({
+            "metadata": {
+                "title": "init(frame:)",
+                "platforms": [{"name": "iOS", "introducedAt": "2.0"}]
+            },
+            "primaryContentSections": [{
+                "kind": "declarations",
+                "declarations": [{
+                    "languages": ["swift"],
+                    "tokens": [{"kind": "text", "text": "init(frame: CGRect)"}]
+                }]
+            }],
+            "variantOverrides": [{
+                "traits": [{"interfaceLanguage": "occ"}],
+                "patch": [
+                    {"op": "replace", "path": "/metadata/title", "value": "initWithFrame:"},
+                    {"op": "replace", "path": "/primaryContentSections/0/declarations/0/languages/0", "value": "occ"},
+                    {
+                        "op": "replace",
+                        "path": "/primaryContentSections/0/declarations/0/tokens",
+                        "value": [{"kind": "text", "text": "- (instancetype)initWithFrame:(CGRect)frame;"}]
+                    },
+                    {"op": "add", "path": "/metadata/platforms/-", "value": {"name": "Mac Catalyst", "introducedAt": "13.1"}}
+                ]
+            }]
+        })
+    }
+
+    #[test]
+    fn parses_languages() {
+        assert_eq!("ObjC".parse::<SymbolLanguage>().unwrap(), SymbolLanguage::ObjectiveC);
+        assert_eq!("swift".parse::<SymbolLanguage>().unwrap(), SymbolLanguage::Swift);
+        assert!("kotlin".parse::<SymbolLanguage>().is_err());
+    }
+
+    #[test]
+    fn applies_objective_c_overrides() {
+        let mut document = page();
+        apply_language_variant(&mut document, SymbolLanguage::ObjectiveC);
+        assert_eq!(document["metadata"]["title"], "initWithFrame:");
+        assert_eq!(document["metadata"]["platforms"][1]["name"], "Mac Catalyst");
+        let declaration = &document["primaryContentSections"][0]["declarations"][0];
+        assert_eq!(declaration["languages"][0], "occ");
+        assert_eq!(
+            declaration["tokens"][0]["text"],
+            "- (instancetype)initWithFrame:(CGRect)frame;"
+        );
+
+        let mut swift = page();
+        apply_language_variant(&mut swift, SymbolLanguage::Swift);
+        assert_eq!(swift, page());
+    }
+
+    #[test]
+    fn keeps_declarations_for_the_requested_language() {
+        let mut document = json!({
+            "primaryContentSections": [{
+                "kind": "declarations",
+                "declarations": [
+                    {"languages": ["swift"], "tokens": [{"text": "var frame: CGRect"}]},
+                    {"languages": ["occ"], "tokens": [{"text": "@property CGRect frame;"}]}
+                ]
+            }]
+        });
+        apply_language_variant(&mut document, SymbolLanguage::ObjectiveC);
+        let declarations = document["primaryContentSections"][0]["declarations"].as_array().unwrap();
+        assert_eq!(declarations.len(), 1);
+        assert_eq!(declarations[0]["languages"][0], "occ");
+    }
+}
@@ -1,16 +1,27 @@
+use std::collections::HashMap;
 use std::{path::PathBuf, sync::Arc};
 
 use anyhow::Result;
+use docs_mcp_client::http::NetworkSettings;
+use docs_mcp_client::rate_limit::RateLimit;
+use docs_mcp_client::retry::RetryPolicy;
+use docs_mcp_client::timeouts::HttpTimeouts;
 use docs_mcp_client::{AppleDocsClient, ClientConfig};
+use multi_provider_client::credentials::ApiToken;
+use multi_provider_client::types::ProviderType;
 
 pub mod markdown;
 pub mod services;
 pub mod state;
 pub mod tools;
 pub mod transport;
+use services::feedback::FeedbackWebhook;
+use services::refresh::RefreshSettings;
+use services::jsonl_log::LogRotation;
+use transport::http::HttpSettings;
 use state::AppContext;
 use time::OffsetDateTime;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Configuration inputs required to bootstrap the MCP server core.
 #[derive(Debug, Clone)]
@@ -21,11 +32,95 @@ pub struct ServerConfig {
     pub boot_timestamp: OffsetDateTime,
     /// How the server transports requests/responses.
     pub mode: ServerMode,
+    /// Default truncation limits applied when rendering documentation.
+    pub limits: ContentLimits,
+    /// Optional override for the disk cache size budget, in bytes.
+    pub cache_max_size_bytes: Option<u64>,
+    /// Optional override for the memory budget shared by provider clients, in bytes.
+    pub memory_cache_max_bytes: Option<u64>,
+    /// Serve exclusively from the disk cache and never make outbound requests.
+    pub offline: bool,
+    /// Background stale-while-revalidate settings; `None` disables the worker.
+    pub refresh: Option<RefreshSettings>,
+    /// Retry and backoff applied to every outbound documentation request.
+    pub retry: RetryPolicy,
+    /// Per-provider HTTP timeout overrides; providers not listed keep their defaults.
+    pub timeouts: HashMap<ProviderType, HttpTimeouts>,
+    /// Outbound requests allowed in flight at once, across all providers.
+    pub max_concurrent_requests: usize,
+    /// Token-bucket rate limit applied to each upstream host.
+    pub rate_limit: RateLimit,
+    /// How long federated calls wait before returning partial results.
+    pub federated_deadline: std::time::Duration,
+    /// Proxy and TLS settings shared by every provider's HTTP client.
+    pub network: NetworkSettings,
+    /// Hugging Face token for authenticated Hub requests and gated models.
+    pub huggingface_token: Option<ApiToken>,
+    /// GitHub token for providers that fetch from GitHub, lifting anonymous rate limits.
+    pub github_token: Option<ApiToken>,
+    /// Commit, tag or branch of the tonkeeper OpenAPI spec to use; `None` follows `master`.
+    pub ton_spec_ref: Option<String>,
+    /// Rotation of the opt-in JSONL telemetry log under the cache dir; `None` disables it.
+    pub telemetry_log: Option<LogRotation>,
+    /// Rotation of the opt-in JSONL request log under the cache dir; `None` disables it.
+    pub request_log: Option<LogRotation>,
+    /// Where `submit_feedback` also POSTs each record; `None` keeps feedback local only.
+    pub feedback_webhook: Option<FeedbackWebhook>,
+    /// Save the stdio session's active provider/technology under the cache dir and restore
+    /// it on startup.
+    pub persist_session: bool,
+    /// Directory of extra `how_do_i` recipe packs (`.json`/`.yaml`) loaded at startup.
+    pub recipe_dir: Option<PathBuf>,
+    /// Directory of extra knowledge packs (symbol tips and pitfalls, `.json`/`.yaml`) loaded
+    /// at startup.
+    pub knowledge_dir: Option<PathBuf>,
+    /// URL of a knowledge pack downloaded at startup; the last download is reused offline.
+    pub knowledge_url: Option<String>,
+    /// Rules file (`.yaml`/`.json`) that replaces or edits the built-in query intent patterns
+    /// and provider keywords at startup.
+    pub intent_rules: Option<PathBuf>,
+    /// Also register `discover_technologies`, `choose_technology` and `current_technology`
+    /// for workflows that pin a framework instead of relying on per-query auto-detection.
+    pub technology_tools: bool,
+    /// Register only these tools (by name), overriding the defaults and `technology_tools`.
+    pub enabled_tools: Option<Vec<String>>,
+    /// Tools never registered, applied after `enabled_tools`.
+    pub disabled_tools: Vec<String>,
+    /// Alternative tool names accepted by `tools/call`, mapped to the registered tool
+    /// (e.g. `search` → `query`). Registered names always take precedence.
+    pub tool_aliases: HashMap<String, String>,
+    /// Listener settings used when `mode` is [`ServerMode::Http`].
+    pub http: HttpSettings,
+}
+
+/// Character limits used when rendering documentation content.
+///
+/// A limit of `0` disables truncation for that field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentLimits {
+    /// Maximum length for summaries in non-detailed results.
+    pub summary: usize,
+    /// Maximum length for code samples.
+    pub code: usize,
+    /// Maximum length for full documentation content.
+    pub content: usize,
+}
+
+impl Default for ContentLimits {
+    fn default() -> Self {
+        Self {
+            summary: 300,
+            code: 2000,
+            content: 4000,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServerMode {
     Stdio,
+    /// Streamable HTTP on `ServerConfig::http`, with a session per `Mcp-Session-Id`.
+    Http,
     Headless,
 }
 
@@ -35,24 +130,132 @@ impl Default for ServerConfig {
             cache_dir: None,
             boot_timestamp: OffsetDateTime::now_utc(),
             mode: ServerMode::Stdio,
+            limits: ContentLimits::default(),
+            cache_max_size_bytes: None,
+            memory_cache_max_bytes: None,
+            offline: false,
+            refresh: Some(RefreshSettings::default()),
+            retry: RetryPolicy::default(),
+            timeouts: HashMap::new(),
+            max_concurrent_requests: docs_mcp_client::limiter::DEFAULT_MAX_CONCURRENT_REQUESTS,
+            rate_limit: RateLimit::default(),
+            federated_deadline: multi_provider_client::timeouts::DEFAULT_FEDERATED_DEADLINE,
+            network: NetworkSettings::default(),
+            huggingface_token: None,
+            github_token: None,
+            ton_spec_ref: None,
+            telemetry_log: None,
+            request_log: None,
+            feedback_webhook: None,
+            persist_session: true,
+            recipe_dir: None,
+            knowledge_dir: None,
+            knowledge_url: None,
+            intent_rules: None,
+            technology_tools: false,
+            enabled_tools: None,
+            disabled_tools: Vec::new(),
+            tool_aliases: HashMap::new(),
+            http: HttpSettings::default(),
         }
     }
 }
 
+/// Build the Apple documentation client described by `config`.
+///
+/// Also applies `config.offline`, `config.retry`, `config.timeouts`, `config.network`, the
+/// outbound concurrency and rate limits, the federated deadline, provider API tokens, the
+/// pinned TON spec, the shared memory budget, `config.recipe_dir`, `config.knowledge_dir` and
+/// `config.intent_rules`, which are process-wide and cover every provider client.
+pub fn build_client(config: &ServerConfig) -> AppleDocsClient {
+    docs_mcp_client::offline::set_offline(config.offline);
+    docs_mcp_client::http::set_network_settings(config.network.clone());
+    docs_mcp_client::retry::set_retry_policy(config.retry);
+    docs_mcp_client::limiter::set_max_concurrent_requests(config.max_concurrent_requests);
+    docs_mcp_client::rate_limit::set_rate_limit(config.rate_limit);
+    multi_provider_client::timeouts::set_federated_deadline(config.federated_deadline);
+    multi_provider_client::credentials::set_huggingface_token(config.huggingface_token.clone());
+    multi_provider_client::credentials::set_github_token(config.github_token.clone());
+    multi_provider_client::ton::set_spec_ref(config.ton_spec_ref.clone());
+    for (provider, timeouts) in &config.timeouts {
+        multi_provider_client::timeouts::set_timeouts(*provider, *timeouts);
+    }
+    if let Some(bytes) = config.cache_max_size_bytes {
+        docs_mcp_client::cache::disk::set_default_max_size(bytes);
+    }
+    if let Some(budget) = config.memory_cache_max_bytes {
+        multi_provider_client::memory::shared_memory_cache().set_budget(budget);
+    }
+    if let Some(dir) = &config.recipe_dir {
+        match services::knowledge::load_recipe_packs(dir) {
+            Ok(report) => {
+                for error in &report.errors {
+                    warn!(target: "docs_mcp_core", error = %error, "skipping recipe pack");
+                }
+                info!(
+                    target: "docs_mcp_core",
+                    files = report.files,
+                    recipes = report.recipes,
+                    "Loaded recipe packs"
+                );
+            }
+            Err(error) => {
+                warn!(target: "docs_mcp_core", error = %format!("{error:#}"), "failed to load recipe packs");
+            }
+        }
+    }
+    if let Some(dir) = &config.knowledge_dir {
+        match services::knowledge::load_knowledge_packs(dir) {
+            Ok(report) => {
+                for error in &report.errors {
+                    warn!(target: "docs_mcp_core", error = %error, "skipping knowledge pack");
+                }
+                info!(
+                    target: "docs_mcp_core",
+                    files = report.files,
+                    entries = report.entries,
+                    "Loaded knowledge packs"
+                );
+            }
+            Err(error) => {
+                warn!(target: "docs_mcp_core", error = %format!("{error:#}"), "failed to load knowledge packs");
+            }
+        }
+    }
+    if let Some(path) = &config.intent_rules {
+        match services::intent_rules::load_intent_rules(path) {
+            Ok(()) => info!(target: "docs_mcp_core", path = %path.display(), "Loaded intent rules"),
+            Err(error) => {
+                warn!(target: "docs_mcp_core", error = %format!("{error:#}"), "failed to load intent rules; using the built-in ones");
+            }
+        }
+    }
+
+    let defaults = ClientConfig::default();
+    AppleDocsClient::with_config(ClientConfig {
+        cache_dir: config.cache_dir.clone().unwrap_or(defaults.cache_dir),
+        max_cache_size_bytes: config
+            .cache_max_size_bytes
+            .unwrap_or(defaults.max_cache_size_bytes),
+        timeouts: multi_provider_client::timeouts::timeouts_for(ProviderType::Apple),
+        network: config.network.clone(),
+        ..defaults
+    })
+}
+
 /// Placeholder entry point for the core server runtime.
 ///
 /// Later phases will replace this stub with the full MCP event loop.
 pub async fn run(config: ServerConfig) -> Result<()> {
-    let client = match &config.cache_dir {
-        Some(dir) => AppleDocsClient::with_config(ClientConfig {
-            cache_dir: dir.clone(),
-            ..ClientConfig::default()
-        }),
-        None => AppleDocsClient::new(),
-    };
-
-    let context = Arc::new(AppContext::new(client));
+    let client = build_client(&config);
+
+    let context = Arc::new(AppContext::with_config(client, config.clone()));
     tools::register_tools(context.clone()).await;
+    services::session_store::restore_session(&context).await;
+    if let Some(url) = config.knowledge_url.clone() {
+        let cache_dir = context.client.cache_dir().to_path_buf();
+        tokio::spawn(async move { services::knowledge::sync_remote_knowledge_pack(&url, &cache_dir).await });
+    }
 
     debug!(
         target: "docs_mcp_core",
@@ -65,11 +268,23 @@ pub async fn run(config: ServerConfig) -> Result<()> {
         cache_dir = ?config.cache_dir,
         boot_timestamp = %config.boot_timestamp,
         mode = ?config.mode,
+        offline = config.offline,
         "Core server starting"
     );
 
     match config.mode {
-        ServerMode::Stdio => transport::serve_stdio(context).await?,
+        ServerMode::Stdio => {
+            if let Some(settings) = config.refresh {
+                services::refresh::spawn_refresh_worker(context.clone(), settings);
+            }
+            transport::serve_stdio(context).await?
+        }
+        ServerMode::Http => {
+            if let Some(settings) = config.refresh {
+                services::refresh::spawn_refresh_worker(context.clone(), settings);
+            }
+            transport::http::serve_http(context, config.http.clone()).await?
+        }
         ServerMode::Headless => {
             debug!(target: "docs_mcp_core", "Headless mode: skipping transport loop")
         }
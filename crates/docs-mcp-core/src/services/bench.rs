@@ -0,0 +1,309 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use docs_mcp_client::cache::stats::CacheStatsSnapshot;
+use multi_provider_client::memory::shared_memory_cache;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::state::AppContext;
+use crate::tools::telemetry_stats::percentile;
+
+/// Slowest runs listed in the report.
+const SLOWEST_RUNS: usize = 5;
+
+/// What `run_bench` replays.
+#[derive(Debug, Clone, Default)]
+pub struct BenchOptions {
+    pub queries: Vec<String>,
+    /// Times the whole list is replayed; later passes show the effect of warm caches.
+    pub passes: usize,
+    pub max_results: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchRun {
+    pub query: String,
+    pub pass: usize,
+    pub latency_ms: u64,
+    pub results: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencySummary {
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p95_ms: u64,
+    pub p99_ms: u64,
+    pub max_ms: u64,
+    pub mean_ms: u64,
+}
+
+impl LatencySummary {
+    fn from_latencies(mut latencies: Vec<u64>) -> Self {
+        latencies.sort_unstable();
+        let total: u64 = latencies.iter().sum();
+        Self {
+            p50_ms: percentile(&latencies, 50),
+            p90_ms: percentile(&latencies, 90),
+            p95_ms: percentile(&latencies, 95),
+            p99_ms: percentile(&latencies, 99),
+            max_ms: latencies.last().copied().unwrap_or_default(),
+            mean_ms: total.checked_div(latencies.len() as u64).unwrap_or_default(),
+        }
+    }
+}
+
+/// Cache lookups made during the benchmark.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheDelta {
+    pub hits: usize,
+    pub misses: usize,
+    pub hit_rate: f64,
+}
+
+impl CacheDelta {
+    fn between(before: &CacheStatsSnapshot, after: &CacheStatsSnapshot) -> Self {
+        let delta = CacheStatsSnapshot {
+            hits: after.hits.saturating_sub(before.hits),
+            misses: after.misses.saturating_sub(before.misses),
+            ..*after
+        };
+        Self {
+            hits: delta.hits,
+            misses: delta.misses,
+            hit_rate: delta.hit_rate(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchReport {
+    pub queries: usize,
+    pub passes: usize,
+    pub errors: usize,
+    pub latency: LatencySummary,
+    /// Latency of each pass, first (coldest) to last.
+    pub pass_latency: Vec<LatencySummary>,
+    /// Apple documentation cache (memory and disk).
+    pub documentation_cache: CacheDelta,
+    /// Shared in-memory cache of the other providers.
+    pub provider_cache: CacheDelta,
+    pub slowest: Vec<BenchRun>,
+}
+
+impl BenchReport {
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!(
+                "{} quer{} x {} pass(es), {} error(s)",
+                self.queries,
+                if self.queries == 1 { "y" } else { "ies" },
+                self.passes,
+                self.errors
+            ),
+            format!("Latency: {}", latency_line(&self.latency)),
+        ];
+        if self.pass_latency.len() > 1 {
+            for (index, pass) in self.pass_latency.iter().enumerate() {
+                lines.push(format!("  pass {}: {}", index + 1, latency_line(pass)));
+            }
+        }
+        lines.push(cache_line("Documentation cache", &self.documentation_cache));
+        lines.push(cache_line("Provider cache", &self.provider_cache));
+        if !self.slowest.is_empty() {
+            lines.push("Slowest:".to_string());
+            for run in &self.slowest {
+                let outcome = match &run.error {
+                    Some(error) => format!("error: {error}"),
+                    None => format!("{} result(s)", run.results),
+                };
+                lines.push(format!(
+                    "  {} ms  {} (pass {}, {outcome})",
+                    run.latency_ms, run.query, run.pass
+                ));
+            }
+        }
+        lines
+    }
+}
+
+fn latency_line(summary: &LatencySummary) -> String {
+    format!(
+        "p50 {} ms, p90 {} ms, p95 {} ms, p99 {} ms, max {} ms, mean {} ms",
+        summary.p50_ms,
+        summary.p90_ms,
+        summary.p95_ms,
+        summary.p99_ms,
+        summary.max_ms,
+        summary.mean_ms
+    )
+}
+
+fn cache_line(label: &str, delta: &CacheDelta) -> String {
+    format!(
+        "{label}: {} hit(s), {} miss(es), {:.1}% hit rate",
+        delta.hits, delta.misses, delta.hit_rate
+    )
+}
+
+/// Queries from a benchmark file: one per line, blank lines and `#` comments skipped.
+pub fn parse_queries(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Replay `options.queries` through the `query` tool, one at a time, and measure them.
+///
+/// Each query runs on fresh session state so provider detection is part of the timing, as
+/// it would be for a new client; caches are shared across runs.
+pub async fn run_bench(context: &AppContext, options: &BenchOptions) -> Result<BenchReport> {
+    let tool = context
+        .tools
+        .get("query")
+        .await
+        .context("query tool not registered")?;
+    let documentation_before = context.cache_stats().total();
+    let provider_before = shared_memory_cache().stats().snapshot();
+
+    let passes = options.passes.max(1);
+    let mut runs = Vec::with_capacity(options.queries.len() * passes);
+    for pass in 1..=passes {
+        for query in &options.queries {
+            let mut args = json!({ "query": query });
+            if let Some(max) = options.max_results {
+                args["maxResults"] = json!(max);
+            }
+            let started = Instant::now();
+            let outcome = (tool.handler)(Arc::new(context.detached()), args).await;
+            let latency_ms = started.elapsed().as_millis() as u64;
+            let (results, error) = match outcome {
+                Ok(response) => (
+                    response
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata["resultCount"].as_u64())
+                        .unwrap_or_default() as usize,
+                    None,
+                ),
+                Err(error) => (0, Some(error.to_string())),
+            };
+            runs.push(BenchRun {
+                query: query.clone(),
+                pass,
+                latency_ms,
+                results,
+                error,
+            });
+        }
+    }
+
+    Ok(build_report(
+        options.queries.len(),
+        passes,
+        runs,
+        CacheDelta::between(&documentation_before, &context.cache_stats().total()),
+        CacheDelta::between(&provider_before, &shared_memory_cache().stats().snapshot()),
+    ))
+}
+
+fn build_report(
+    queries: usize,
+    passes: usize,
+    mut runs: Vec<BenchRun>,
+    documentation_cache: CacheDelta,
+    provider_cache: CacheDelta,
+) -> BenchReport {
+    let latency = LatencySummary::from_latencies(runs.iter().map(|run| run.latency_ms).collect());
+    let pass_latency = (1..=passes)
+        .map(|pass| {
+            LatencySummary::from_latencies(
+                runs.iter()
+                    .filter(|run| run.pass == pass)
+                    .map(|run| run.latency_ms)
+                    .collect(),
+            )
+        })
+        .collect();
+    let errors = runs.iter().filter(|run| run.error.is_some()).count();
+    runs.sort_by_key(|run| std::cmp::Reverse(run.latency_ms));
+    runs.truncate(SLOWEST_RUNS);
+
+    BenchReport {
+        queries,
+        passes,
+        errors,
+        latency,
+        pass_latency,
+        documentation_cache,
+        provider_cache,
+        slowest: runs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(query: &str, pass: usize, latency_ms: u64) -> BenchRun {
+        BenchRun {
+            query: query.to_string(),
+            pass,
+            latency_ms,
+            results: 3,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn parses_query_files() {
+        let queries = parse_queries("# Apple\nSwiftUI List\n\n  tokio spawn  \n");
+        assert_eq!(queries, vec!["SwiftUI List", "tokio spawn"]);
+    }
+
+    #[test]
+    fn report_summarizes_passes_and_caches() {
+        let runs = vec![
+            run("SwiftUI List", 1, 900),
+            run("tokio spawn", 1, 300),
+            run("SwiftUI List", 2, 20),
+            run("tokio spawn", 2, 10),
+        ];
+        let before = CacheStatsSnapshot {
+            hits: 1,
+            misses: 4,
+            bytes_served: 0,
+            entry_count: 2,
+            evictions: 0,
+        };
+        let after = CacheStatsSnapshot {
+            hits: 4,
+            misses: 5,
+            ..before
+        };
+        let documentation = CacheDelta::between(&before, &after);
+        let report = build_report(2, 2, runs, documentation, CacheDelta::default());
+
+        assert_eq!(report.latency.p50_ms, 20);
+        assert_eq!(report.latency.max_ms, 900);
+        assert_eq!(report.pass_latency[1].max_ms, 20);
+        assert_eq!(report.documentation_cache.hits, 3);
+        assert_eq!(report.documentation_cache.hit_rate, 75.0);
+        assert_eq!(report.slowest[0].latency_ms, 900);
+
+        let lines = report.summary_lines().join("\n");
+        assert!(lines.contains("2 queries x 2 pass(es), 0 error(s)"));
+        assert!(lines.contains("pass 2: p50 10 ms"));
+        assert!(lines.contains("Documentation cache: 3 hit(s), 1 miss(es), 75.0% hit rate"));
+    }
+}
@@ -0,0 +1,268 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use docs_mcp_client::cache::{dir_usage, prune_dir, DirUsage, PruneSummary};
+use docs_mcp_client::AppleDocsClient;
+use multi_provider_client::{provider_cache_root, types::ProviderType};
+use serde::Serialize;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// On-disk cache directories for every provider.
+#[derive(Debug, Clone)]
+pub struct CacheLocations {
+    /// Apple documentation cache (owned by `AppleDocsClient`).
+    pub apple: PathBuf,
+    /// Shared root for the other providers' caches, one subdirectory each.
+    pub providers: Option<PathBuf>,
+}
+
+impl CacheLocations {
+    pub fn for_client(client: &AppleDocsClient) -> Self {
+        Self {
+            apple: client.cache_dir().clone(),
+            providers: provider_cache_root(),
+        }
+    }
+
+    /// Cache directory for `provider`, if one can be resolved.
+    pub fn dir_for(&self, provider: ProviderType) -> Option<PathBuf> {
+        match provider {
+            ProviderType::Apple => Some(self.apple.clone()),
+            other => self.providers.as_ref().map(|root| root.join(other.slug())),
+        }
+    }
+}
+
+/// What `prune_caches` should delete.
+#[derive(Debug, Clone, Default)]
+pub struct PruneOptions {
+    /// Remove entries not used for this many days.
+    pub older_than_days: Option<u64>,
+    /// Remove every entry belonging to these providers (e.g. disabled ones).
+    pub providers: Vec<ProviderType>,
+    /// Report what would be removed without deleting anything.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderPruneReport {
+    pub provider: ProviderType,
+    pub path: String,
+    #[serde(flatten)]
+    pub summary: PruneSummary,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneReport {
+    pub dry_run: bool,
+    pub files_removed: u64,
+    pub bytes_reclaimed: u64,
+    pub providers: Vec<ProviderPruneReport>,
+}
+
+impl PruneReport {
+    /// Plain-text summary suitable for tool output or the CLI.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let verb = if self.dry_run { "Would remove" } else { "Removed" };
+        let mut lines = vec![format!(
+            "{verb} {} cache file(s), reclaiming {}.",
+            self.files_removed,
+            format_bytes(self.bytes_reclaimed)
+        )];
+        for entry in &self.providers {
+            lines.push(format!(
+                "- {}: {} file(s), {} ({})",
+                entry.provider.name(),
+                entry.summary.files_removed,
+                format_bytes(entry.summary.bytes_reclaimed),
+                entry.path
+            ));
+        }
+        lines
+    }
+}
+
+/// Delete stale cache entries and/or whole provider caches, reporting reclaimed bytes.
+pub async fn prune_caches(locations: &CacheLocations, options: &PruneOptions) -> Result<PruneReport> {
+    if options.older_than_days.is_none() && options.providers.is_empty() {
+        bail!("Specify olderThanDays and/or providers to prune");
+    }
+
+    let max_age = options
+        .older_than_days
+        .map(|days| Duration::from_secs(days.saturating_mul(SECONDS_PER_DAY)));
+
+    let mut report = PruneReport {
+        dry_run: options.dry_run,
+        ..PruneReport::default()
+    };
+
+    for provider in ProviderType::ALL {
+        let purge_all = options.providers.contains(&provider);
+        if !purge_all && max_age.is_none() {
+            continue;
+        }
+        let Some(dir) = locations.dir_for(provider) else {
+            continue;
+        };
+
+        let age = if purge_all { None } else { max_age };
+        let summary = prune_dir(&dir, age, options.dry_run).await?;
+        if summary.files_removed == 0 {
+            continue;
+        }
+
+        report.files_removed += summary.files_removed;
+        report.bytes_reclaimed += summary.bytes_reclaimed;
+        report.providers.push(ProviderPruneReport {
+            provider,
+            path: dir.display().to_string(),
+            summary,
+        });
+    }
+
+    Ok(report)
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderCacheUsage {
+    pub provider: ProviderType,
+    pub path: String,
+    #[serde(flatten)]
+    pub usage: DirUsage,
+}
+
+/// On-disk size of every provider cache that currently holds files.
+pub async fn cache_usage(locations: &CacheLocations) -> Result<Vec<ProviderCacheUsage>> {
+    let mut report = Vec::new();
+    for provider in ProviderType::ALL {
+        let Some(dir) = locations.dir_for(provider) else {
+            continue;
+        };
+        let usage = dir_usage(&dir).await?;
+        if usage.files == 0 {
+            continue;
+        }
+        report.push(ProviderCacheUsage {
+            provider,
+            path: dir.display().to_string(),
+            usage,
+        });
+    }
+    Ok(report)
+}
+
+/// Resolve provider names (slugs or display names) into provider types.
+pub fn parse_providers(names: &[String]) -> Result<Vec<ProviderType>> {
+    names
+        .iter()
+        .map(|name| ProviderType::from_slug(name).ok_or_else(|| anyhow!("unknown provider '{name}'")))
+        .collect()
+}
+
+/// Human-readable byte count (e.g. `1.5 MB`).
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::cache::DiskCache;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn prunes_selected_provider_caches() {
+        let apple = tempdir().expect("tempdir");
+        let providers = tempdir().expect("tempdir");
+        let locations = CacheLocations {
+            apple: apple.path().to_path_buf(),
+            providers: Some(providers.path().to_path_buf()),
+        };
+
+        DiskCache::new(apple.path())
+            .store("SwiftUI.json", json!({"title": "SwiftUI"}))
+            .await
+            .unwrap();
+        DiskCache::new(providers.path().join("rust"))
+            .store("std.json", json!({"name": "std"}))
+            .await
+            .unwrap();
+
+        let report = prune_caches(
+            &locations,
+            &PruneOptions {
+                providers: vec![ProviderType::Rust],
+                ..PruneOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.files_removed, 1);
+        assert!(report.bytes_reclaimed > 0);
+        assert_eq!(report.providers[0].provider, ProviderType::Rust);
+        assert!(apple.path().join("SwiftUI.json").exists());
+        assert!(!providers.path().join("rust/std.json").exists());
+    }
+
+    #[tokio::test]
+    async fn reports_usage_for_populated_caches() {
+        let apple = tempdir().expect("tempdir");
+        let providers = tempdir().expect("tempdir");
+        let locations = CacheLocations {
+            apple: apple.path().to_path_buf(),
+            providers: Some(providers.path().to_path_buf()),
+        };
+
+        DiskCache::new(providers.path().join("mdn"))
+            .store("fetch.json", json!({"title": "fetch"}))
+            .await
+            .unwrap();
+
+        let usage = cache_usage(&locations).await.unwrap();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].provider, ProviderType::Mdn);
+        assert_eq!(usage[0].usage.files, 1);
+    }
+
+    #[tokio::test]
+    async fn requires_a_prune_criterion() {
+        let dir = tempdir().expect("tempdir");
+        let locations = CacheLocations {
+            apple: dir.path().to_path_buf(),
+            providers: None,
+        };
+        assert!(prune_caches(&locations, &PruneOptions::default()).await.is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_providers() {
+        let err = parse_providers(&["rust".to_string(), "nope".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("nope"));
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+    }
+}
@@ -1067,6 +1067,7 @@ mod tests {
         let client = AppleDocsClient::with_config(ClientConfig {
             cache_dir: cache_dir.path().to_path_buf(),
             memory_cache_ttl: Duration::minutes(5),
+            ..ClientConfig::default()
         });
         let context = AppContext::new(client);
         let sections = guidance_for(&context, "Text", "/documentation/swiftui/text")
@@ -0,0 +1,112 @@
+//! Complete documents behind truncated tool output, served as MCP resources.
+//!
+//! `query` cuts long content down to its limits; the full text of such a result is kept here
+//! and linked from the response, so capable clients can read it with `resources/read`.
+
+use std::collections::VecDeque;
+
+use multi_provider_client::types::ProviderType;
+
+use crate::state::ResourceLink;
+
+/// Scheme and authority of the resource URIs handed out for documents.
+pub const DOCUMENT_URI_PREFIX: &str = "docs-mcp://documents/";
+
+const MARKDOWN_MIME_TYPE: &str = "text/markdown";
+
+#[derive(Debug, Clone)]
+pub struct StoredDocument {
+    pub link: ResourceLink,
+    pub text: String,
+}
+
+/// The most recently linked documents of one session.
+#[derive(Default)]
+pub struct DocumentStore {
+    inner: std::sync::Mutex<VecDeque<StoredDocument>>,
+}
+
+impl DocumentStore {
+    /// Documents kept before the oldest one is dropped.
+    pub const MAX_DOCUMENTS: usize = 200;
+
+    /// Keep `text` as the full document at `path` of `provider`, replacing an older copy.
+    pub fn insert(&self, provider: ProviderType, path: &str, title: &str, text: String) -> ResourceLink {
+        let link = ResourceLink {
+            uri: document_uri(provider, path),
+            name: title.to_string(),
+            mime_type: MARKDOWN_MIME_TYPE.to_string(),
+            description: Some(format!("Full {} documentation for {title}", provider.name())),
+        };
+        let mut documents = self.lock();
+        documents.retain(|document| document.link.uri != link.uri);
+        documents.push_back(StoredDocument {
+            link: link.clone(),
+            text,
+        });
+        while documents.len() > Self::MAX_DOCUMENTS {
+            documents.pop_front();
+        }
+        link
+    }
+
+    pub fn get(&self, uri: &str) -> Option<StoredDocument> {
+        self.lock().iter().find(|document| document.link.uri == uri).cloned()
+    }
+
+    /// Links to the stored documents, newest first.
+    pub fn list(&self) -> Vec<ResourceLink> {
+        self.lock().iter().rev().map(|document| document.link.clone()).collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<StoredDocument>> {
+        self.inner.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// `docs-mcp://documents/<provider>/<path>`, with characters that would end the path escaped.
+pub fn document_uri(provider: ProviderType, path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.trim().trim_start_matches('/').chars() {
+        match c {
+            ' ' => escaped.push_str("%20"),
+            '#' => escaped.push_str("%23"),
+            '%' => escaped.push_str("%25"),
+            '?' => escaped.push_str("%3F"),
+            c => escaped.push(c),
+        }
+    }
+    format!("{DOCUMENT_URI_PREFIX}{}/{escaped}", provider.slug())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_latest_copy_of_recent_documents() {
+        let store = DocumentStore::default();
+        let link = store.insert(
+            ProviderType::Apple,
+            "/documentation/swiftui/navigationstack",
+            "NavigationStack",
+            "old".to_string(),
+        );
+        assert_eq!(link.uri, "docs-mcp://documents/apple/documentation/swiftui/navigationstack");
+        assert_eq!(link.mime_type, "text/markdown");
+        store.insert(ProviderType::Apple, "documentation/swiftui/navigationstack", "NavigationStack", "new".to_string());
+        assert_eq!(store.list().len(), 1);
+        assert_eq!(store.get(&link.uri).unwrap().text, "new");
+
+        for index in 0..DocumentStore::MAX_DOCUMENTS {
+            store.insert(ProviderType::Rust, &format!("std::doc{index}"), "doc", String::new());
+        }
+        assert!(store.get(&link.uri).is_none());
+        assert_eq!(store.list().len(), DocumentStore::MAX_DOCUMENTS);
+        assert_eq!(store.list()[0].uri, "docs-mcp://documents/rust/std::doc199");
+        assert_eq!(
+            document_uri(ProviderType::Mdn, "Web/API/Fetch API#usage"),
+            "docs-mcp://documents/mdn/Web/API/Fetch%20API%23usage"
+        );
+    }
+}
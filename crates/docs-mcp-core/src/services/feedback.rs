@@ -0,0 +1,354 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use docs_mcp_client::timeouts::HttpTimeouts;
+use multi_provider_client::credentials::ApiToken;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use tokio::io::AsyncWriteExt;
+
+/// Time allowed for forwarding one feedback record.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest issue title built from the feedback text.
+const ISSUE_TITLE_CHARS: usize = 80;
+
+/// Overrides the directory feedback records are written to (default `Feedback/`).
+pub const FEEDBACK_DIR_ENV: &str = "DOCSMCP_FEEDBACK_DIR";
+const DEFAULT_FEEDBACK_DIR: &str = "Feedback";
+
+/// Directory holding one `feedback_*.json` file per `submit_feedback` call.
+pub fn feedback_dir() -> PathBuf {
+    std::env::var_os(FEEDBACK_DIR_ENV).map_or_else(|| PathBuf::from(DEFAULT_FEEDBACK_DIR), PathBuf::from)
+}
+
+/// Where submitted feedback is forwarded in addition to being stored locally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedbackWebhook {
+    /// A generic webhook receiving the submitted fields of the record, or a GitHub
+    /// `https://api.github.com/repos/<owner>/<repo>/issues` endpoint.
+    pub url: String,
+    /// Sent as `Authorization: Bearer <token>`.
+    pub token: Option<ApiToken>,
+}
+
+impl FeedbackWebhook {
+    /// Whether `url` is the GitHub REST endpoint for creating issues.
+    pub fn is_github_issues(&self) -> bool {
+        let Some(path) = self.url.strip_prefix("https://api.github.com/repos/") else {
+            return false;
+        };
+        let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        matches!(segments.as_slice(), [owner, repo, "issues"] if !owner.is_empty() && !repo.is_empty())
+    }
+}
+
+/// Record fields that leave the machine: what the user submitted, plus when.
+///
+/// The environment and diagnostics sections (local user, host, working directory, telemetry,
+/// recent queries of every session) stay in the local copy only.
+const FORWARDED_FIELDS: &[&str] = &[
+    "schema_version",
+    "timestamp",
+    "client",
+    "rating",
+    "feedback",
+    "improvements",
+    "missing_docs",
+    "pain_points",
+    "failing_queries",
+    "provider",
+];
+
+/// POST a stored feedback `record` to `webhook`.
+///
+/// GitHub Issues endpoints get an issue built from the record; any other URL gets the
+/// [`FORWARDED_FIELDS`] of the record as JSON.
+pub async fn forward_feedback(webhook: &FeedbackWebhook, record: &Value) -> Result<()> {
+    if docs_mcp_client::offline::is_offline() {
+        bail!("offline mode is enabled; feedback was not forwarded");
+    }
+    let body = if webhook.is_github_issues() {
+        github_issue(record)
+    } else {
+        submitted_fields(record)
+    };
+    let timeouts = HttpTimeouts {
+        connect: WEBHOOK_TIMEOUT,
+        read: WEBHOOK_TIMEOUT,
+    };
+    let token = webhook.token.as_ref().map(ApiToken::expose);
+    docs_mcp_client::http::post_json(&webhook.url, &body, token, timeouts).await?;
+    Ok(())
+}
+
+/// `record` reduced to its [`FORWARDED_FIELDS`].
+fn submitted_fields(record: &Value) -> Value {
+    FORWARDED_FIELDS
+        .iter()
+        .filter_map(|&key| Some((key.to_string(), record.get(key)?.clone())))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// A GitHub "create issue" payload summarizing `record`.
+fn github_issue(record: &Value) -> Value {
+    let text = |key: &str| record.get(key).and_then(Value::as_str).unwrap_or_default();
+    let list = |key: &str| -> Vec<&str> {
+        record
+            .get(key)
+            .and_then(Value::as_array)
+            .map(|items| items.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default()
+    };
+
+    let feedback = text("feedback").trim();
+    let first_line = feedback.lines().next().unwrap_or_default();
+    let mut title: String = first_line.chars().take(ISSUE_TITLE_CHARS).collect();
+    if title.len() < first_line.len() {
+        title.push('…');
+    }
+
+    let mut body = vec![feedback.to_string(), String::new()];
+    if let Some(rating) = record.get("rating").and_then(Value::as_u64) {
+        body.push(format!("**Rating:** {rating}/5"));
+    }
+    if !text("provider").is_empty() {
+        body.push(format!("**Provider:** {}", text("provider")));
+    }
+    for (key, heading) in [
+        ("failing_queries", "Failing queries"),
+        ("missing_docs", "Missing docs"),
+        ("improvements", "Suggested improvements"),
+        ("pain_points", "Pain points"),
+    ] {
+        let items = list(key);
+        if items.is_empty() {
+            continue;
+        }
+        body.push(String::new());
+        body.push(format!("### {heading}"));
+        body.extend(items.iter().map(|item| format!("- {item}")));
+    }
+    body.push(String::new());
+    body.push(format!("_Submitted via docs-mcp `submit_feedback` at {}._", text("timestamp")));
+
+    serde_json::json!({
+        "title": format!("Feedback: {title}"),
+        "body": body.join("\n"),
+        "labels": ["feedback"],
+    })
+}
+
+/// The fields of a stored feedback record worth listing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedbackSummary {
+    #[serde(skip_deserializing)]
+    pub path: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    #[serde(default)]
+    pub rating: Option<u8>,
+    pub feedback: String,
+    #[serde(default)]
+    pub provider: Option<String>,
+    #[serde(default, alias = "failing_queries")]
+    pub failing_queries: Vec<String>,
+    #[serde(default, alias = "missing_docs")]
+    pub missing_docs: Vec<String>,
+}
+
+impl FeedbackSummary {
+    /// Plain-text rendering for the CLI.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let timestamp = self
+            .timestamp
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_default();
+        let mut headline = timestamp;
+        if let Some(rating) = self.rating {
+            headline.push_str(&format!(" ★{rating}"));
+        }
+        if let Some(provider) = &self.provider {
+            headline.push_str(&format!(" [{provider}]"));
+        }
+        let mut lines = vec![headline, format!("  {}", self.feedback.trim())];
+        for query in &self.failing_queries {
+            lines.push(format!("  failing query: {query}"));
+        }
+        for doc in &self.missing_docs {
+            lines.push(format!("  missing: {doc}"));
+        }
+        lines
+    }
+}
+
+/// Every feedback record in `dir`, newest first. A missing directory has no feedback.
+///
+/// Files that can't be parsed are skipped with a warning rather than failing the listing.
+pub async fn list_feedback(dir: &Path) -> Result<Vec<FeedbackSummary>> {
+    let mut summaries = Vec::new();
+    for (path, record) in read_records(dir).await? {
+        match serde_json::from_value::<FeedbackSummary>(record) {
+            Ok(mut summary) => {
+                summary.path = path.display().to_string();
+                summaries.push(summary);
+            }
+            Err(error) => {
+                tracing::warn!(target: "docs_mcp_core", path = %path.display(), error = %error, "skipping unreadable feedback record");
+            }
+        }
+    }
+    summaries.sort_by_key(|summary| std::cmp::Reverse(summary.timestamp));
+    Ok(summaries)
+}
+
+/// Write every feedback record in `dir` to `output` as JSON Lines, oldest first.
+///
+/// Returns the number of records exported.
+pub async fn export_feedback(dir: &Path, output: &Path) -> Result<usize> {
+    let records = read_records(dir).await?;
+    let mut file = tokio::fs::File::create(output)
+        .await
+        .with_context(|| format!("create {}", output.display()))?;
+    for (_, record) in &records {
+        let mut line = serde_json::to_vec(record).context("serialize feedback")?;
+        line.push(b'\n');
+        file.write_all(&line).await.context("write feedback export")?;
+    }
+    file.flush().await.context("flush feedback export")?;
+    Ok(records.len())
+}
+
+/// Parsed `feedback_*.json` files in `dir`, sorted by file name (and therefore by time).
+async fn read_records(dir: &Path) -> Result<Vec<(PathBuf, Value)>> {
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error).with_context(|| format!("read {}", dir.display())),
+    };
+
+    let mut paths = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let is_record = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("feedback_") && name.ends_with(".json"));
+        if is_record {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut records = Vec::with_capacity(paths.len());
+    for path in paths {
+        let bytes = tokio::fs::read(&path)
+            .await
+            .with_context(|| format!("read {}", path.display()))?;
+        match serde_json::from_slice(&bytes) {
+            Ok(record) => records.push((path, record)),
+            Err(error) => {
+                tracing::warn!(target: "docs_mcp_core", path = %path.display(), error = %error, "skipping malformed feedback file");
+            }
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn lists_and_exports_records() {
+        let dir = tempdir().expect("tempdir");
+        let older = json!({
+            "schema_version": 1,
+            "timestamp": "2025-01-01T00:00:00Z",
+            "feedback": "Missing AppKit docs",
+            "missing_docs": ["NSTableView"]
+        });
+        let newer = json!({
+            "schema_version": 1,
+            "timestamp": "2025-02-01T00:00:00Z",
+            "feedback": "tokio results empty",
+            "rating": 2,
+            "provider": "rust",
+            "failing_queries": ["tokio select"]
+        });
+        std::fs::write(dir.path().join("feedback_1_0_pid1.json"), older.to_string()).unwrap();
+        std::fs::write(dir.path().join("feedback_2_0_pid1.json"), newer.to_string()).unwrap();
+        std::fs::write(dir.path().join("feedback_3_0_pid1.json"), "not json").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let listed = list_feedback(dir.path()).await.expect("list");
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].feedback, "tokio results empty");
+        assert_eq!(listed[0].provider.as_deref(), Some("rust"));
+        assert_eq!(listed[0].failing_queries, vec!["tokio select".to_string()]);
+        assert_eq!(listed[1].missing_docs, vec!["NSTableView".to_string()]);
+
+        let output = dir.path().join("export.jsonl");
+        assert_eq!(export_feedback(dir.path(), &output).await.expect("export"), 2);
+        let exported = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<Value> = exported.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines, vec![older, newer]);
+    }
+
+    #[test]
+    fn detects_github_issue_endpoints() {
+        let webhook = |url: &str| FeedbackWebhook {
+            url: url.to_string(),
+            token: None,
+        };
+        assert!(webhook("https://api.github.com/repos/acme/docs/issues").is_github_issues());
+        assert!(!webhook("https://api.github.com/repos/acme/issues").is_github_issues());
+        assert!(!webhook("https://hooks.example.com/feedback").is_github_issues());
+    }
+
+    #[test]
+    fn builds_github_issue_from_record() {
+        let record = json!({
+            "timestamp": "2025-02-01T00:00:00Z",
+            "feedback": "tokio results are empty\nmore detail",
+            "rating": 2,
+            "provider": "rust",
+            "failing_queries": ["tokio select"],
+            "improvements": []
+        });
+        let issue = github_issue(&record);
+        assert_eq!(issue["title"], "Feedback: tokio results are empty");
+        let body = issue["body"].as_str().unwrap();
+        assert!(body.contains("**Provider:** rust"));
+        assert!(body.contains("### Failing queries\n- tokio select"));
+        assert!(!body.contains("Suggested improvements"));
+    }
+
+    #[test]
+    fn forwards_only_submitted_fields() {
+        let record = json!({
+            "schema_version": 1,
+            "timestamp": "2025-02-01T00:00:00Z",
+            "feedback": "tokio results are empty",
+            "failing_queries": ["tokio select"],
+            "environment": {"user": "alice", "cwd": "/home/alice/secret"},
+            "diagnostics": {"recentQueries": [{"query": "another session"}]}
+        });
+        let forwarded = submitted_fields(&record);
+        assert_eq!(forwarded["feedback"], "tokio results are empty");
+        assert_eq!(forwarded["failing_queries"][0], "tokio select");
+        assert!(forwarded.get("environment").is_none());
+        assert!(forwarded.get("diagnostics").is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_directory_has_no_feedback() {
+        let dir = tempdir().expect("tempdir");
+        assert!(list_feedback(&dir.path().join("absent")).await.unwrap().is_empty());
+    }
+}
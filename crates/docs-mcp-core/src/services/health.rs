@@ -0,0 +1,244 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use docs_mcp_client::timeouts::HttpTimeouts;
+use futures::future::join_all;
+use multi_provider_client::types::ProviderType;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::services::cache_maintenance::CacheLocations;
+use crate::state::AppContext;
+
+/// Time allowed for each provider's reachability probe.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const PROBE_FILE_NAME: &str = ".health-probe";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// Usable, but some providers can't be reached.
+    Degraded,
+    /// Caches can't be written or no provider is reachable.
+    Unhealthy,
+}
+
+impl HealthStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Degraded => "degraded",
+            Self::Unhealthy => "unhealthy",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheDirHealth {
+    pub path: String,
+    pub writable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderHealth {
+    pub provider: ProviderType,
+    pub url: &'static str,
+    /// `None` when the probe was skipped (offline mode).
+    pub reachable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    #[serde(with = "time::serde::rfc3339")]
+    pub checked_at: OffsetDateTime,
+    pub offline: bool,
+    pub cache_dirs: Vec<CacheDirHealth>,
+    pub providers: Vec<ProviderHealth>,
+}
+
+impl HealthReport {
+    /// Plain-text summary suitable for tool output or the CLI.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Status: {}", self.status.as_str()), String::new()];
+        lines.push("## Cache".to_string());
+        for dir in &self.cache_dirs {
+            match &dir.error {
+                None => lines.push(format!("- ✅ {} is writable", dir.path)),
+                Some(error) => lines.push(format!("- ❌ {} is not writable: {error}", dir.path)),
+            }
+        }
+        lines.push(String::new());
+        lines.push("## Providers".to_string());
+        for provider in &self.providers {
+            let name = provider.provider.name();
+            let line = match (provider.reachable, &provider.error) {
+                (None, _) => format!("- ⏸️ {name}: not probed (offline mode)"),
+                (Some(true), _) => format!(
+                    "- ✅ {name}: HTTP {} in {} ms",
+                    provider.status_code.unwrap_or_default(),
+                    provider.latency_ms.unwrap_or_default()
+                ),
+                (Some(false), Some(error)) => format!("- ❌ {name}: {error}"),
+                (Some(false), None) => format!(
+                    "- ❌ {name}: HTTP {}",
+                    provider.status_code.unwrap_or_default()
+                ),
+            };
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Check that the caches are writable and probe each of `providers` (all when empty).
+pub async fn check_health(context: &AppContext, providers: &[ProviderType]) -> HealthReport {
+    let locations = CacheLocations::for_client(&context.client);
+    let mut cache_dirs = vec![check_writable(&locations.apple).await];
+    if let Some(root) = &locations.providers {
+        cache_dirs.push(check_writable(root).await);
+    }
+
+    let offline = docs_mcp_client::offline::is_offline();
+    let providers = if providers.is_empty() {
+        ProviderType::ALL.to_vec()
+    } else {
+        providers.to_vec()
+    };
+    let providers = join_all(providers.into_iter().map(|provider| probe(provider, offline))).await;
+
+    HealthReport {
+        status: overall_status(&cache_dirs, &providers),
+        checked_at: OffsetDateTime::now_utc(),
+        offline,
+        cache_dirs,
+        providers,
+    }
+}
+
+async fn check_writable(dir: &Path) -> CacheDirHealth {
+    let probe = dir.join(PROBE_FILE_NAME);
+    let result = async {
+        tokio::fs::create_dir_all(dir).await?;
+        tokio::fs::write(&probe, b"ok").await?;
+        tokio::fs::remove_file(&probe).await
+    }
+    .await;
+    CacheDirHealth {
+        path: dir.display().to_string(),
+        writable: result.is_ok(),
+        error: result.err().map(|error| error.to_string()),
+    }
+}
+
+async fn probe(provider: ProviderType, offline: bool) -> ProviderHealth {
+    let url = provider.homepage();
+    let mut health = ProviderHealth {
+        provider,
+        url,
+        reachable: None,
+        status_code: None,
+        latency_ms: None,
+        error: None,
+    };
+    if offline {
+        return health;
+    }
+
+    let timeouts = HttpTimeouts {
+        connect: PROBE_TIMEOUT,
+        read: PROBE_TIMEOUT,
+    };
+    let started = Instant::now();
+    match docs_mcp_client::http::probe(url, timeouts).await {
+        Ok(status) => {
+            health.latency_ms = Some(started.elapsed().as_millis() as u64);
+            health.status_code = Some(status);
+            // Any answer short of a server error means the host is up
+            health.reachable = Some(status < 500);
+        }
+        Err(error) => {
+            health.reachable = Some(false);
+            health.error = Some(error.to_string());
+        }
+    }
+    health
+}
+
+fn overall_status(cache_dirs: &[CacheDirHealth], providers: &[ProviderHealth]) -> HealthStatus {
+    if cache_dirs.iter().any(|dir| !dir.writable) {
+        return HealthStatus::Unhealthy;
+    }
+    let probed: Vec<bool> = providers.iter().filter_map(|provider| provider.reachable).collect();
+    match probed.iter().filter(|reachable| !**reachable).count() {
+        0 => HealthStatus::Ok,
+        down if down == probed.len() => HealthStatus::Unhealthy,
+        _ => HealthStatus::Degraded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn provider(reachable: Option<bool>) -> ProviderHealth {
+        ProviderHealth {
+            provider: ProviderType::Rust,
+            url: ProviderType::Rust.homepage(),
+            reachable,
+            status_code: None,
+            latency_ms: None,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_writable_cache_dirs() {
+        let dir = tempdir().expect("tempdir");
+        let health = check_writable(&dir.path().join("nested")).await;
+        assert!(health.writable);
+        assert!(!dir.path().join("nested").join(PROBE_FILE_NAME).exists());
+
+        let file = dir.path().join("file");
+        std::fs::write(&file, "").unwrap();
+        let health = check_writable(&file).await;
+        assert!(!health.writable);
+        assert!(health.error.is_some());
+    }
+
+    #[test]
+    fn overall_status_reflects_failures() {
+        let writable = vec![CacheDirHealth {
+            path: "/cache".to_string(),
+            writable: true,
+            error: None,
+        }];
+        let up = provider(Some(true));
+        let down = provider(Some(false));
+        let skipped = provider(None);
+
+        assert_eq!(overall_status(&writable, &[up.clone(), skipped.clone()]), HealthStatus::Ok);
+        assert_eq!(overall_status(&writable, &[up.clone(), down.clone()]), HealthStatus::Degraded);
+        assert_eq!(overall_status(&writable, &[down.clone(), skipped]), HealthStatus::Unhealthy);
+
+        let read_only = vec![CacheDirHealth {
+            path: "/cache".to_string(),
+            writable: false,
+            error: Some("read-only file system".to_string()),
+        }];
+        assert_eq!(overall_status(&read_only, &[up]), HealthStatus::Unhealthy);
+    }
+}
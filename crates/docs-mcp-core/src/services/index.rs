@@ -0,0 +1,268 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use docs_mcp_client::types::Technology;
+use futures::stream::{self, StreamExt};
+use multi_provider_client::types::ProviderType;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::services::build_framework_index;
+use crate::state::{AppContext, FrameworkIndexEntry};
+
+/// Bumped whenever `FrameworkIndexEntry` or tokenization changes, invalidating stored indexes.
+const INDEX_SCHEMA_VERSION: u32 = 1;
+/// Stored indexes older than this are rebuilt in memory instead of trusted.
+const INDEX_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// Parallel framework downloads while building.
+const INDEX_CONCURRENCY: usize = 4;
+
+/// What `build_indexes` should precompute.
+#[derive(Debug, Clone, Default)]
+pub struct IndexOptions {
+    /// Providers to index; empty means Apple, plus Rust when crates are given.
+    pub providers: Vec<ProviderType>,
+    /// Apple frameworks by title or identifier; empty indexes every framework.
+    pub frameworks: Vec<String>,
+    /// Rust crates whose search indexes should be cached.
+    pub crates: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexTarget {
+    pub provider: ProviderType,
+    pub name: String,
+    pub entries: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexReport {
+    pub targets: Vec<IndexTarget>,
+}
+
+impl IndexReport {
+    pub fn summary_lines(&self) -> Vec<String> {
+        if self.targets.is_empty() {
+            return vec!["Nothing to index.".to_string()];
+        }
+        let built = self.targets.iter().filter(|target| target.error.is_none()).count();
+        let mut lines: Vec<String> = self
+            .targets
+            .iter()
+            .map(|target| match &target.error {
+                Some(error) => format!("- {} {}: failed ({error})", target.provider.name(), target.name),
+                None => format!(
+                    "- {} {}: {} entries",
+                    target.provider.name(),
+                    target.name,
+                    target.entries
+                ),
+            })
+            .collect();
+        lines.push(format!("Built {built} of {} index(es).", self.targets.len()));
+        lines
+    }
+}
+
+/// A framework index as stored under `<cache>/indexes/`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredIndex {
+    schema_version: u32,
+    identifier: String,
+    #[serde(with = "time::serde::rfc3339")]
+    built_at: OffsetDateTime,
+    entries: Vec<FrameworkIndexEntry>,
+}
+
+/// Precompute search indexes so interactive queries don't build them on first use.
+///
+/// Apple framework indexes are written to the cache directory and picked up by every later
+/// session; Rust crate search indexes go into the Rust provider's own disk cache.
+pub async fn build_indexes(context: &AppContext, options: &IndexOptions) -> Result<IndexReport> {
+    let mut providers = options.providers.clone();
+    if providers.is_empty() {
+        providers.push(ProviderType::Apple);
+        if !options.crates.is_empty() {
+            providers.push(ProviderType::Rust);
+        }
+    }
+
+    let mut report = IndexReport::default();
+    for provider in providers {
+        match provider {
+            ProviderType::Apple => {
+                let technologies = select_frameworks(context, &options.frameworks).await?;
+                let targets: Vec<IndexTarget> = stream::iter(technologies)
+                    .map(|technology| async move {
+                        let outcome = build_apple_index(context, &technology).await;
+                        target(ProviderType::Apple, &technology.title, outcome)
+                    })
+                    .buffer_unordered(INDEX_CONCURRENCY)
+                    .collect()
+                    .await;
+                report.targets.extend(targets);
+            }
+            ProviderType::Rust => {
+                if options.crates.is_empty() {
+                    bail!("Indexing Rust needs at least one crate (--crate NAME)");
+                }
+                for crate_name in &options.crates {
+                    let outcome = context.providers.rust.prefetch_search_index(crate_name).await;
+                    report.targets.push(target(ProviderType::Rust, crate_name, outcome));
+                }
+            }
+            other => bail!(
+                "{} has no prebuilt search index; supported providers are apple and rust",
+                other.name()
+            ),
+        }
+    }
+    report.targets.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(report)
+}
+
+async fn select_frameworks(context: &AppContext, wanted: &[String]) -> Result<Vec<Technology>> {
+    let technologies = context.client.get_technologies().await?;
+    let mut frameworks: Vec<Technology> = technologies.into_values().collect();
+    if wanted.is_empty() {
+        frameworks.retain(|technology| technology.identifier.contains("/documentation/"));
+        return Ok(frameworks);
+    }
+    wanted
+        .iter()
+        .map(|name| {
+            let name = name.to_lowercase();
+            frameworks
+                .iter()
+                .find(|technology| {
+                    technology.title.to_lowercase() == name
+                        || identifier_of(technology).is_some_and(|id| id.to_lowercase() == name)
+                })
+                .cloned()
+                .with_context(|| format!("Unknown Apple framework '{name}'"))
+        })
+        .collect()
+}
+
+async fn build_apple_index(context: &AppContext, technology: &Technology) -> Result<usize> {
+    let identifier = identifier_of(technology).context("Invalid technology identifier")?;
+    let framework = context.client.get_framework(identifier).await?;
+    let entries = build_framework_index(&framework);
+    store_index(&index_dir(context), &technology.identifier, &entries).await?;
+    Ok(entries.len())
+}
+
+fn target(provider: ProviderType, name: &str, outcome: Result<usize>) -> IndexTarget {
+    let (entries, error) = match outcome {
+        Ok(entries) => (entries, None),
+        Err(error) => (0, Some(format!("{error:#}"))),
+    };
+    IndexTarget {
+        provider,
+        name: name.to_string(),
+        entries,
+        error,
+    }
+}
+
+fn identifier_of(technology: &Technology) -> Option<&str> {
+    technology.identifier.rsplit('/').next()
+}
+
+fn index_dir(context: &AppContext) -> PathBuf {
+    context.client.cache_dir().join("indexes")
+}
+
+fn index_file(dir: &Path, technology_identifier: &str) -> PathBuf {
+    let name = technology_identifier
+        .rsplit('/')
+        .next()
+        .unwrap_or(technology_identifier)
+        .to_lowercase();
+    dir.join(format!("{name}.json"))
+}
+
+async fn store_index(dir: &Path, technology_identifier: &str, entries: &[FrameworkIndexEntry]) -> Result<()> {
+    tokio::fs::create_dir_all(dir)
+        .await
+        .with_context(|| format!("create {}", dir.display()))?;
+    let stored = StoredIndex {
+        schema_version: INDEX_SCHEMA_VERSION,
+        identifier: technology_identifier.to_string(),
+        built_at: OffsetDateTime::now_utc(),
+        entries: entries.to_vec(),
+    };
+    let path = index_file(dir, technology_identifier);
+    let bytes = serde_json::to_vec(&stored).context("serialize search index")?;
+    tokio::fs::write(&path, bytes)
+        .await
+        .with_context(|| format!("write {}", path.display()))
+}
+
+/// The prebuilt index of `technology_identifier`, unless it is missing, stale or outdated.
+pub(crate) async fn load_stored_index(
+    context: &AppContext,
+    technology_identifier: &str,
+) -> Option<Vec<FrameworkIndexEntry>> {
+    read_index(&index_dir(context), technology_identifier, OffsetDateTime::now_utc()).await
+}
+
+async fn read_index(
+    dir: &Path,
+    technology_identifier: &str,
+    now: OffsetDateTime,
+) -> Option<Vec<FrameworkIndexEntry>> {
+    let bytes = tokio::fs::read(index_file(dir, technology_identifier)).await.ok()?;
+    let stored: StoredIndex = serde_json::from_slice(&bytes).ok()?;
+    let fresh = now - stored.built_at < INDEX_MAX_AGE;
+    (stored.schema_version == INDEX_SCHEMA_VERSION
+        && stored.identifier == technology_identifier
+        && fresh)
+        .then_some(stored.entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::types::ReferenceData;
+    use tempfile::tempdir;
+
+    const SWIFTUI: &str = "doc://com.apple.documentation/documentation/SwiftUI";
+
+    fn entry(id: &str) -> FrameworkIndexEntry {
+        FrameworkIndexEntry {
+            id: id.to_string(),
+            tokens: vec!["list".to_string()],
+            reference: ReferenceData {
+                title: Some("List".to_string()),
+                kind: Some("struct".to_string()),
+                r#abstract: None,
+                platforms: None,
+                url: Some("/documentation/swiftui/list".to_string()),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn stored_indexes_round_trip_until_stale() {
+        let dir = tempdir().expect("tempdir");
+        store_index(dir.path(), SWIFTUI, &[entry("swiftui/list")]).await.unwrap();
+        assert!(dir.path().join("swiftui.json").exists());
+
+        let now = OffsetDateTime::now_utc();
+        let loaded = read_index(dir.path(), SWIFTUI, now).await.expect("stored index");
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].reference.url.as_deref(), Some("/documentation/swiftui/list"));
+
+        assert!(read_index(dir.path(), SWIFTUI, now + INDEX_MAX_AGE).await.is_none());
+        assert!(read_index(dir.path(), "doc://com.apple.documentation/documentation/UIKit", now)
+            .await
+            .is_none());
+    }
+}
@@ -0,0 +1,325 @@
+//! Data-driven rules the `query` tool uses to classify a query and pick its provider.
+//!
+//! The built-in rules live in `intent_rules.yaml`; `DOCSMCP_INTENT_RULES` points at a file
+//! with the same layout whose lists replace or edit the built-in ones at startup.
+
+use anyhow::{bail, Context, Result};
+use multi_provider_client::types::ProviderType;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+const BUILTIN_RULES: &str = include_str!("intent_rules.yaml");
+
+/// Keyword lists the `query` tool consults; rule files may only name these.
+pub const KEYWORD_LISTS: &[&str] = &[
+    "apple_frameworks", "apple", "apple_ml", "rust_crates", "rust", "telegram", "tdlib",
+    "mini_apps", "ton_exit_codes", "tlb", "ton", "cocoon", "web_framework_names", "css", "mdn",
+    "react", "nextjs", "nodejs", "express", "node_routing", "astro", "react_router",
+    "react_native", "typescript", "bun", "mlx", "mlx_lm", "huggingface", "hf_hub_api",
+    "quicknode_evm", "solana_docs", "solana_topic_words", "quicknode", "claude_agent_sdk",
+    "vertcoin_names", "vertcoin", "bitcoin", "litecoin", "cuda", "cuda_library_prefixes",
+    "cuda_kernels", "cuda_cublas", "cuda_cudnn", "cuda_cufft", "cuda_thrust", "cuda_cub",
+    "cuda_libraries", "cuda_gpu", "cuda_optimization",
+];
+
+static RULES: Lazy<RwLock<Arc<IntentRules>>> = Lazy::new(|| RwLock::new(Arc::new(IntentRules::builtin())));
+
+/// A rules file: every field is optional so overrides only mention what they change.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RulesFile {
+    #[serde(default)]
+    howto_patterns: Option<RuleList>,
+    #[serde(default)]
+    reference_patterns: Option<RuleList>,
+    #[serde(default)]
+    provider_names: HashMap<String, RuleList>,
+    /// `None` removes an alias
+    #[serde(default)]
+    aliases: HashMap<String, Option<String>>,
+    #[serde(default)]
+    keywords: HashMap<String, RuleList>,
+}
+
+/// A plain list replaces the current one; `{add, remove}` edits it.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RuleList {
+    Replace(Vec<String>),
+    Edit {
+        #[serde(default)]
+        add: Vec<String>,
+        #[serde(default)]
+        remove: Vec<String>,
+    },
+}
+
+impl RuleList {
+    fn apply(self, current: &mut Vec<String>, normalize: fn(&str) -> String) {
+        match self {
+            Self::Replace(values) => *current = values.iter().map(|value| normalize(value)).collect(),
+            Self::Edit { add, remove } => {
+                let remove: Vec<String> = remove.iter().map(|value| normalize(value)).collect();
+                current.retain(|value| !remove.contains(value));
+                for value in add.iter().map(|value| normalize(value)) {
+                    if !current.contains(&value) {
+                        current.push(value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct IntentRules {
+    howto_patterns: Vec<String>,
+    reference_patterns: Vec<String>,
+    howto: RegexSet,
+    reference: RegexSet,
+    provider_names: HashMap<ProviderType, Vec<String>>,
+    aliases: HashMap<String, String>,
+    /// Matches any alias as a whole word; `None` without aliases
+    alias_pattern: Option<Regex>,
+    keywords: HashMap<String, Vec<String>>,
+}
+
+impl IntentRules {
+    /// The rules shipped in `intent_rules.yaml`.
+    ///
+    /// # Panics
+    ///
+    /// If the embedded file is invalid, which the tests rule out.
+    #[must_use]
+    pub fn builtin() -> Self {
+        let empty = Self {
+            howto_patterns: Vec::new(),
+            reference_patterns: Vec::new(),
+            howto: RegexSet::empty(),
+            reference: RegexSet::empty(),
+            provider_names: HashMap::new(),
+            aliases: HashMap::new(),
+            alias_pattern: None,
+            keywords: HashMap::new(),
+        };
+        empty
+            .merged(BUILTIN_RULES)
+            .expect("built-in intent rules are valid")
+    }
+
+    /// These rules with the YAML (or JSON) rules file `text` applied on top.
+    pub fn merged(&self, text: &str) -> Result<Self> {
+        let file: RulesFile = serde_yaml::from_str(text).context("parse intent rules")?;
+        if let Some(unknown) = file.keywords.keys().find(|list| !KEYWORD_LISTS.contains(&list.as_str())) {
+            bail!("unknown keyword list '{unknown}'; expected one of: {}", KEYWORD_LISTS.join(", "));
+        }
+
+        let mut howto_patterns = self.howto_patterns.clone();
+        let mut reference_patterns = self.reference_patterns.clone();
+        let mut provider_names = self.provider_names.clone();
+        let mut aliases = self.aliases.clone();
+        let mut keywords = self.keywords.clone();
+        if let Some(list) = file.howto_patterns {
+            list.apply(&mut howto_patterns, str::to_string);
+        }
+        if let Some(list) = file.reference_patterns {
+            list.apply(&mut reference_patterns, str::to_string);
+        }
+        for (slug, list) in file.provider_names {
+            let provider = ProviderType::from_slug(&slug).with_context(|| format!("unknown provider '{slug}'"))?;
+            list.apply(provider_names.entry(provider).or_default(), |name| name.trim().to_lowercase());
+        }
+        for (alias, expansion) in file.aliases {
+            let alias = alias.trim().to_lowercase();
+            match expansion.map(|expansion| expansion.trim().to_lowercase()) {
+                Some(expansion) if !expansion.is_empty() => aliases.insert(alias, expansion),
+                _ => aliases.remove(&alias),
+            };
+        }
+        for (name, list) in file.keywords {
+            list.apply(keywords.entry(name).or_default(), |keyword| keyword.trim().to_lowercase());
+        }
+
+        Ok(Self {
+            howto: compile(&howto_patterns).context("compile howto_patterns")?,
+            reference: compile(&reference_patterns).context("compile reference_patterns")?,
+            alias_pattern: alias_pattern(&aliases).context("compile aliases")?,
+            howto_patterns,
+            reference_patterns,
+            provider_names,
+            aliases,
+            keywords,
+        })
+    }
+
+    /// Whether the trimmed query asks how to do something.
+    #[must_use]
+    pub fn is_howto(&self, query: &str) -> bool {
+        self.howto.is_match(query)
+    }
+
+    /// Whether the trimmed query asks what something is.
+    #[must_use]
+    pub fn is_reference(&self, query: &str) -> bool {
+        self.reference.is_match(query)
+    }
+
+    /// Lowercase names that mention `provider` outright, e.g. "transformers" for Hugging Face.
+    #[must_use]
+    pub fn provider_names(&self, provider: ProviderType) -> &[String] {
+        self.provider_names.get(&provider).map_or(&[], Vec::as_slice)
+    }
+
+    /// `query` with every alias replaced by its expansion, e.g. "vc lifecycle" as
+    /// "viewcontroller lifecycle". Code such as `std::env`, `<nav>` or `config.toml` is kept.
+    #[must_use]
+    pub fn expand_aliases(&self, query: &str) -> String {
+        let Some(pattern) = &self.alias_pattern else {
+            return query.to_string();
+        };
+        pattern
+            .replace_all(query, |captures: &regex::Captures| {
+                let matched = captures.get(0).expect("group 0 always matches");
+                let before = query[..matched.start()].chars().next_back();
+                let after = query[matched.end()..].chars().next();
+                let in_code = before.is_some_and(|c| matches!(c, '<' | ':' | '.' | '@' | '#' | '/'))
+                    || after.is_some_and(|c| matches!(c, '>' | ':' | '.' | '(' | '/'));
+                match self.aliases.get(&matched.as_str().to_lowercase()) {
+                    Some(expansion) if !in_code => expansion.clone(),
+                    _ => matched.as_str().to_string(),
+                }
+            })
+            .into_owned()
+    }
+
+    /// The lowercase keywords of one of the [`KEYWORD_LISTS`].
+    #[must_use]
+    pub fn keywords(&self, list: &str) -> &[String] {
+        debug_assert!(KEYWORD_LISTS.contains(&list), "unknown keyword list {list}");
+        self.keywords.get(list).map_or(&[], Vec::as_slice)
+    }
+}
+
+fn compile(patterns: &[String]) -> Result<RegexSet> {
+    Ok(RegexSetBuilder::new(patterns).case_insensitive(true).build()?)
+}
+
+/// One alternation of all aliases, longest first so `async/await` wins over an `async` alias.
+fn alias_pattern(aliases: &HashMap<String, String>) -> Result<Option<Regex>> {
+    let mut names: Vec<&String> = aliases.keys().filter(|alias| !alias.is_empty()).collect();
+    if names.is_empty() {
+        return Ok(None);
+    }
+    names.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let alternatives: Vec<String> = names
+        .iter()
+        .map(|alias| {
+            let start = if is_word(alias.chars().next()) { r"\b" } else { "" };
+            let end = if is_word(alias.chars().next_back()) { r"\b" } else { "" };
+            format!("{start}{}{end}", regex::escape(alias))
+        })
+        .collect();
+    let pattern = RegexBuilder::new(&alternatives.join("|")).case_insensitive(true).build()?;
+    Ok(Some(pattern))
+}
+
+/// The rules currently in effect.
+pub fn current() -> Arc<IntentRules> {
+    RULES
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Apply the rules file at `path` on top of the built-in rules, replacing rules loaded before.
+/// An invalid file leaves the current rules in place.
+pub fn load_intent_rules(path: &Path) -> Result<()> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+    let rules = IntentRules::builtin()
+        .merged(&text)
+        .with_context(|| format!("load intent rules {}", path.display()))?;
+    *RULES.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Arc::new(rules);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_rules_cover_every_list() {
+        let rules = IntentRules::builtin();
+        for list in KEYWORD_LISTS {
+            assert!(!rules.keywords(list).is_empty(), "{list} is empty");
+        }
+        assert!(rules.is_howto("How do I add a toolbar?"));
+        assert!(rules.is_howto("What's the best way to cache images"));
+        assert!(rules.is_reference("what is NavigationStack"));
+        assert!(!rules.is_howto("NavigationStack"));
+        assert!(rules.keywords("ton").iter().any(|keyword| keyword == "tonconnect"));
+        assert!(rules.provider_names(ProviderType::HuggingFace).iter().any(|name| name == "transformers"));
+    }
+
+    #[test]
+    fn expands_aliases_as_whole_words() {
+        let rules = IntentRules::builtin();
+        assert_eq!(rules.expand_aliases("vc lifecycle"), "viewcontroller lifecycle");
+        assert_eq!(rules.expand_aliases("swift async/await basics"), "swift concurrency basics");
+        assert_eq!(rules.expand_aliases("CD fetch request"), "coredata fetch request");
+        // Aliases inside longer words stay as they are
+        assert_eq!(rules.expand_aliases("navigationstack in a navbar"), "navigationstack in a navigationbar");
+        assert_eq!(rules.expand_aliases("cdn caching"), "cdn caching");
+        assert_eq!(rules.expand_aliases("std::env::var"), "std::env::var");
+        assert_eq!(rules.expand_aliases("<nav> element"), "<nav> element");
+        assert_eq!(rules.expand_aliases("cargo config.toml"), "cargo config.toml");
+
+        let rules = rules.merged("aliases: {vc: ~, rn: react native, tvc: UITableViewController}").unwrap();
+        assert_eq!(rules.expand_aliases("vc rn tvc"), "vc react native uitableviewcontroller");
+        assert_eq!(IntentRules::builtin().merged("aliases: {}").unwrap().expand_aliases("btn"), "button");
+    }
+
+    #[test]
+    fn overrides_replace_and_edit_lists() {
+        let rules = IntentRules::builtin()
+            .merged(
+                r"
+howto_patterns:
+  add: ['^cómo\s+', '^wie\s+(kann|mache)\s+ich']
+reference_patterns:
+  remove: ['^(explain|describe)']
+keywords:
+  ton:
+    add: [Jetton Wallet]
+    remove: [func]
+  node_routing: [router]
+provider_names:
+  hugging-face: {add: [HF Hub]}
+",
+            )
+            .unwrap();
+
+        assert!(rules.is_howto("Cómo crear una lista"));
+        assert!(rules.is_howto("Wie kann ich eine Liste sortieren"));
+        assert!(rules.is_howto("how to add a toolbar"));
+        assert!(!rules.is_reference("explain NavigationStack"));
+        assert!(rules.is_reference("what is NavigationStack"));
+
+        let ton = rules.keywords("ton");
+        assert!(ton.iter().any(|keyword| keyword == "jetton wallet"));
+        assert!(!ton.iter().any(|keyword| keyword == "func"));
+        assert!(ton.iter().any(|keyword| keyword == "tonconnect"));
+        assert_eq!(rules.keywords("node_routing"), ["router"]);
+        assert!(rules.provider_names(ProviderType::HuggingFace).iter().any(|name| name == "hf hub"));
+        IntentRules::builtin().merged("provider_names: {myspace: [tom]}").unwrap_err();
+
+        let unknown = IntentRules::builtin().merged("keywords: {rustlang: [cargo]}").unwrap_err();
+        assert!(unknown.to_string().contains("unknown keyword list 'rustlang'"));
+        IntentRules::builtin().merged("howto_patterns: ['^(unclosed']").unwrap_err();
+    }
+}
@@ -0,0 +1,206 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use docs_mcp_client::cache::disk::LOGS_DIR;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// Tool-call telemetry; rotated files get a `.1`, `.2`, ... suffix.
+pub const TELEMETRY_LOG_FILE: &str = "telemetry.jsonl";
+/// Opt-in log of every JSON-RPC request and its response size.
+pub const REQUEST_LOG_FILE: &str = "requests.jsonl";
+/// Default size at which the active log is rotated: 10MB
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Default number of rotated logs kept besides the active one.
+pub const DEFAULT_MAX_FILES: usize = 5;
+
+/// Rotation settings for an on-disk JSONL log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogRotation {
+    /// The active log is rotated once appending would grow it past this size.
+    pub max_file_bytes: u64,
+    /// Rotated logs kept; older ones are deleted.
+    pub max_files: usize,
+}
+
+impl Default for LogRotation {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            max_files: DEFAULT_MAX_FILES,
+        }
+    }
+}
+
+/// Append-only JSONL log that outlives the process, rotated by size.
+#[derive(Debug)]
+pub struct JsonlLog {
+    path: PathBuf,
+    settings: LogRotation,
+    write_lock: Mutex<()>,
+}
+
+impl JsonlLog {
+    /// A log named `file_name` in the `logs` directory under `cache_dir`.
+    pub fn in_cache_dir(cache_dir: &Path, file_name: &str, settings: LogRotation) -> Self {
+        Self::new(cache_dir.join(LOGS_DIR).join(file_name), settings)
+    }
+
+    pub fn new(path: PathBuf, settings: LogRotation) -> Self {
+        Self {
+            path,
+            settings,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `entry` as one JSON line, rotating the log first if it is full.
+    pub async fn append<T: Serialize>(&self, entry: &T) -> Result<()> {
+        let mut line = serde_json::to_vec(entry).context("serialize log entry")?;
+        line.push(b'\n');
+
+        let _guard = self.write_lock.lock().await;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("create {}", parent.display()))?;
+        }
+
+        let current = fs::metadata(&self.path).await.map_or(0, |metadata| metadata.len());
+        if current > 0 && current + line.len() as u64 > self.settings.max_file_bytes {
+            self.rotate().await?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("open {}", self.path.display()))?;
+        file.write_all(&line).await.context("write log entry")?;
+        file.flush().await.context("flush log")?;
+        Ok(())
+    }
+
+    /// Every entry still on disk, oldest first; unparseable lines are skipped.
+    pub async fn read_all<T: DeserializeOwned>(&self) -> Result<Vec<T>> {
+        let _guard = self.write_lock.lock().await;
+        let mut entries = Vec::new();
+        let paths = (1..=self.settings.max_files)
+            .rev()
+            .map(|index| self.rotated_path(index))
+            .chain(std::iter::once(self.path.clone()));
+        for path in paths {
+            let contents = match fs::read_to_string(&path).await {
+                Ok(contents) => contents,
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error).with_context(|| format!("read {}", path.display())),
+            };
+            entries.extend(contents.lines().filter_map(|line| serde_json::from_str(line).ok()));
+        }
+        Ok(entries)
+    }
+
+    /// Shift `<log>.N` to `.N+1`, dropping the oldest, and start a new active log.
+    async fn rotate(&self) -> Result<()> {
+        let max_files = self.settings.max_files;
+        if max_files == 0 {
+            return remove_if_exists(&self.path).await;
+        }
+
+        remove_if_exists(&self.rotated_path(max_files)).await?;
+        for index in (1..max_files).rev() {
+            rename_if_exists(&self.rotated_path(index), &self.rotated_path(index + 1)).await?;
+        }
+        rename_if_exists(&self.path, &self.rotated_path(1)).await
+    }
+
+    /// Path of the `index`-th most recent rotated log.
+    pub fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+}
+
+async fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path).await {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            Err(error).with_context(|| format!("remove {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+async fn rename_if_exists(from: &Path, to: &Path) -> Result<()> {
+    match fs::rename(from, to).await {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            Err(error).with_context(|| format!("rename {} -> {}", from.display(), to.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::TelemetryEntry;
+    use tempfile::tempdir;
+    use time::OffsetDateTime;
+
+    fn entry(tool: &str) -> TelemetryEntry {
+        TelemetryEntry {
+            tool: tool.to_string(),
+            timestamp: OffsetDateTime::now_utc(),
+            latency_ms: 12,
+            success: true,
+            metadata: None,
+            error: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn appends_json_lines() {
+        let dir = tempdir().expect("tempdir");
+        let log = JsonlLog::in_cache_dir(dir.path(), TELEMETRY_LOG_FILE, LogRotation::default());
+        log.append(&entry("query")).await.unwrap();
+        log.append(&entry("cache_stats")).await.unwrap();
+
+        let contents = std::fs::read_to_string(dir.path().join("logs/telemetry.jsonl")).unwrap();
+        let tools: Vec<String> = contents
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["tool"].to_string())
+            .collect();
+        assert_eq!(tools, vec!["\"query\"", "\"cache_stats\""]);
+    }
+
+    #[tokio::test]
+    async fn rotates_and_keeps_max_files() {
+        let dir = tempdir().expect("tempdir");
+        let settings = LogRotation {
+            max_file_bytes: 1,
+            max_files: 2,
+        };
+        let log = JsonlLog::new(dir.path().join("telemetry.jsonl"), settings);
+        for tool in ["a", "b", "c", "d"] {
+            log.append(&entry(tool)).await.unwrap();
+        }
+
+        let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+        assert!(read(log.path().to_path_buf()).contains("\"tool\":\"d\""));
+        assert!(read(log.rotated_path(1)).contains("\"tool\":\"c\""));
+        assert!(read(log.rotated_path(2)).contains("\"tool\":\"b\""));
+        assert!(!log.rotated_path(3).exists());
+
+        let entries: Vec<TelemetryEntry> = log.read_all().await.unwrap();
+        let tools: Vec<String> = entries.into_iter().map(|entry| entry.tool).collect();
+        assert_eq!(tools, vec!["b", "c", "d"]);
+    }
+}
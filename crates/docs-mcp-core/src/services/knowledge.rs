@@ -1,5 +1,12 @@
+use anyhow::{bail, Context, Result};
+use docs_mcp_client::timeouts::HttpTimeouts;
 use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use tracing::{info, warn};
 
 pub struct IntegrationLink {
     pub framework: &'static str,
@@ -19,6 +26,8 @@ pub struct KnowledgeEntry {
     pub quick_tip: Option<&'static str>,
     pub related: &'static [RelatedItem],
     pub integration: &'static [IntegrationLink],
+    /// Mistakes people commonly make with the symbol.
+    pub pitfalls: &'static [&'static str],
     pub snippet: Option<Snippet>,
 }
 
@@ -240,6 +249,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             quick_tip: Some("Pair with searchSuggestions(_:) and searchScopes(_:scopes:) to cover completions and scoped results."),
             related: &SWIFTUI_SEARCHABLE_RELATED,
             integration: &SWIFTUI_SEARCHABLE_INTEGRATIONS,
+            pitfalls: &[],
             snippet: Some(SWIFTUI_SEARCHABLE_SNIPPET),
         },
     );
@@ -251,6 +261,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             ),
             related: &SWIFTUI_TEXTFIELD_RELATED,
             integration: &SWIFTUI_TEXTFIELD_INTEGRATIONS,
+            pitfalls: &[],
             snippet: Some(SWIFTUI_TEXTFIELD_SNIPPET),
         },
     );
@@ -260,6 +271,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             quick_tip: Some("Adopt listStyle(_:) to align visuals with platform conventions."),
             related: &SWIFTUI_LIST_RELATED,
             integration: &SWIFTUI_LIST_INTEGRATIONS,
+            pitfalls: &[],
             snippet: Some(SWIFTUI_LIST_SNIPPET),
         },
     );
@@ -269,6 +281,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             quick_tip: Some("Combine with accessibilityHint(_:) to clarify the control’s result."),
             related: &SWIFTUI_ACCESSIBILITY_RELATED,
             integration: &[],
+            pitfalls: &[],
             snippet: Some(SWIFTUI_ACCESSIBILITY_SNIPPET),
         },
     );
@@ -302,6 +315,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "Bridge to macOS text controls in hybrid interfaces.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(SWIFTUI_TEXT_SNIPPET),
         },
     );
@@ -311,6 +325,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             quick_tip: Some("Use searchSuggestions(_:), searchScopes(_:scopes:), and tokens to shape the experience."),
             related: &SWIFTUI_SEARCH_TOPIC_RELATED,
             integration: &SWIFTUI_SEARCH_TOPIC_INTEGRATIONS,
+            pitfalls: &[],
             snippet: Some(SWIFTUI_SEARCH_TOPIC_SNIPPET),
         },
     );
@@ -320,6 +335,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
             quick_tip: Some("Adopt UITextFieldDelegate for validation and formatting."),
             related: &UIKIT_UITEXTFIELD_RELATED,
             integration: &UIKIT_UITEXTFIELD_INTEGRATIONS,
+            pitfalls: &[],
             snippet: Some(UIKIT_UITEXTFIELD_SNIPPET),
         },
     );
@@ -354,6 +370,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "Use when embedding SwiftUI in UIKit navigation hierarchies.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var path = NavigationPath()\n\nNavigationStack(path: $path) {\n    List(items) { item in\n        NavigationLink(value: item) {\n            Text(item.title)\n        }\n    }\n    .navigationDestination(for: Item.self) { item in\n        DetailView(item: item)\n    }\n}",
@@ -386,6 +403,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "Use for UIKit-based tab navigation.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var selectedTab = 0\n\nTabView(selection: $selectedTab) {\n    HomeView()\n        .tabItem { Label(\"Home\", systemImage: \"house\") }\n        .tag(0)\n    SettingsView()\n        .tabItem { Label(\"Settings\", systemImage: \"gear\") }\n        .tag(1)\n}",
@@ -418,6 +436,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit equivalent for wheel-style pickers.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var selection = \"Red\"\nlet colors = [\"Red\", \"Green\", \"Blue\"]\n\nPicker(\"Color\", selection: $selection) {\n    ForEach(colors, id: \\.self) { color in\n        Text(color).tag(color)\n    }\n}\n.pickerStyle(.menu)",
@@ -450,6 +469,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit sheet with detent support.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var showSettings = false\n\nButton(\"Settings\") { showSettings = true }\n.sheet(isPresented: $showSettings) {\n    SettingsView()\n        .presentationDetents([.medium, .large])\n        .presentationDragIndicator(.visible)\n}",
@@ -475,6 +495,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                 },
             ],
             integration: &[],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "AsyncImage(url: imageURL) { phase in\n    switch phase {\n    case .empty:\n        ProgressView()\n    case .success(let image):\n        image.resizable().aspectRatio(contentMode: .fit)\n    case .failure:\n        Image(systemName: \"photo\")\n            .foregroundStyle(.secondary)\n    @unknown default:\n        EmptyView()\n    }\n}",
@@ -507,6 +528,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit progress bar.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var progress = 0.5\n\nProgressView(value: progress) {\n    Text(\"Downloading...\")\n} currentValueLabel: {\n    Text(\"\\(Int(progress * 100))%\")\n}",
@@ -539,6 +561,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit grouped table style for settings.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "Form {\n    Section(\"Account\") {\n        TextField(\"Username\", text: $username)\n        SecureField(\"Password\", text: $password)\n    }\n    Section(\"Preferences\") {\n        Toggle(\"Notifications\", isOn: $notifications)\n        Picker(\"Theme\", selection: $theme) {\n            Text(\"Light\").tag(0)\n            Text(\"Dark\").tag(1)\n        }\n    }\n}",
@@ -566,6 +589,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit alert and action sheet presentations.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var showAlert = false\n\nButton(\"Delete\") { showAlert = true }\n.alert(\"Delete Item?\", isPresented: $showAlert) {\n    Button(\"Cancel\", role: .cancel) { }\n    Button(\"Delete\", role: .destructive) {\n        deleteItem()\n    }\n} message: {\n    Text(\"This action cannot be undone.\")\n}",
@@ -603,6 +627,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit gesture recognizer base class.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var offset = CGSize.zero\n\nCircle()\n    .fill(.blue)\n    .frame(width: 100, height: 100)\n    .offset(offset)\n    .gesture(\n        DragGesture()\n            .onChanged { value in\n                offset = value.translation\n            }\n            .onEnded { _ in\n                withAnimation { offset = .zero }\n            }\n    )",
@@ -635,6 +660,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit block-based animations.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@State private var isExpanded = false\n\nVStack {\n    Button(\"Toggle\") {\n        withAnimation(.spring(response: 0.3, dampingFraction: 0.7)) {\n            isExpanded.toggle()\n        }\n    }\n    if isExpanded {\n        Text(\"Expanded content\")\n            .transition(.move(edge: .top).combined(with: .opacity))\n    }\n}",
@@ -667,6 +693,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                     note: "UIKit hierarchical menu system.",
                 },
             ],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "Menu(\"Actions\") {\n    Button(\"Copy\", action: copyItem)\n    Button(\"Share\", action: shareItem)\n    Divider()\n    Button(\"Delete\", role: .destructive, action: deleteItem)\n}",
@@ -697,6 +724,7 @@ static KNOWLEDGE: Lazy<HashMap<&'static str, KnowledgeEntry>> = Lazy::new(|| {
                 },
             ],
             integration: &[],
+            pitfalls: &[],
             snippet: Some(Snippet {
                 language: "swift",
                 code: "@Observable\nclass UserSettings {\n    var username = \"\"\n    var notificationsEnabled = true\n}\n\nstruct SettingsView: View {\n    @Bindable var settings: UserSettings\n    \n    var body: some View {\n        Form {\n            TextField(\"Username\", text: $settings.username)\n            Toggle(\"Notifications\", isOn: $settings.notificationsEnabled)\n        }\n    }\n}",
@@ -2114,28 +2142,393 @@ static RECIPES: Lazy<Vec<RecipeDefinition>> = Lazy::new(|| {
     ]
 });
 
-pub fn lookup(technology: &str, symbol_title: &str) -> Option<&'static KnowledgeEntry> {
-    let key = format!(
+type ExternalKnowledge = Lazy<RwLock<HashMap<String, &'static KnowledgeEntry>>>;
+
+/// Entries from `DOCSMCP_KNOWLEDGE_DIR` packs; consulted first.
+static LOCAL_KNOWLEDGE: ExternalKnowledge = Lazy::new(|| RwLock::new(HashMap::new()));
+/// Entries from the `DOCSMCP_KNOWLEDGE_URL` pack; consulted before the built-in ones.
+static REMOTE_KNOWLEDGE: ExternalKnowledge = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Where the last downloaded remote pack is kept for offline restarts, under the cache dir.
+const REMOTE_PACK_CACHE: &str = "knowledge/remote-pack";
+const REMOTE_PACK_TIMEOUTS: HttpTimeouts = HttpTimeouts::PROVIDER;
+/// Downloaded packs larger than this are rejected.
+const MAX_REMOTE_PACK_BYTES: usize = 4 * 1024 * 1024;
+
+fn knowledge_key(technology: &str, symbol_title: &str) -> String {
+    format!(
         "{}::{}",
         technology.trim().to_lowercase(),
         symbol_title.trim().to_lowercase()
-    );
-    KNOWLEDGE.get(key.as_str())
+    )
+}
+
+pub fn lookup(technology: &str, symbol_title: &str) -> Option<&'static KnowledgeEntry> {
+    let key = knowledge_key(technology, symbol_title);
+    let external = |packs: &ExternalKnowledge| {
+        packs
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&key)
+            .copied()
+    };
+    external(&LOCAL_KNOWLEDGE)
+        .or_else(|| external(&REMOTE_KNOWLEDGE))
+        .or_else(|| KNOWLEDGE.get(key.as_str()))
+}
+
+/// Recipes loaded from external packs; consulted before the built-in ones.
+static EXTERNAL_RECIPES: RwLock<Vec<&'static RecipeDefinition>> = RwLock::new(Vec::new());
+
+/// Every recipe, external packs first so they can override built-in entries.
+fn all_recipes() -> Vec<&'static RecipeDefinition> {
+    let external = EXTERNAL_RECIPES
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    with_builtin_recipes(external)
+}
+
+/// `external` followed by the built-in recipes.
+fn with_builtin_recipes(external: Vec<&'static RecipeDefinition>) -> Vec<&'static RecipeDefinition> {
+    external.into_iter().chain(RECIPES.iter()).collect()
 }
 
 pub fn find_recipe(technology: &str, query: &str) -> Option<&'static RecipeDefinition> {
-    RECIPES
-        .iter()
-        .find(|recipe| recipe.matches(query, technology))
+    find_recipe_in(&all_recipes(), technology, query)
+}
+
+fn find_recipe_in(
+    recipes: &[&'static RecipeDefinition],
+    technology: &str,
+    query: &str,
+) -> Option<&'static RecipeDefinition> {
+    recipes.iter().copied().find(|recipe| recipe.matches(query, technology))
 }
 
 pub fn recipes_for(technology: &str) -> Vec<&'static RecipeDefinition> {
-    RECIPES
+    recipes_for_in(&all_recipes(), technology)
+}
+
+fn recipes_for_in(recipes: &[&'static RecipeDefinition], technology: &str) -> Vec<&'static RecipeDefinition> {
+    recipes
         .iter()
+        .copied()
         .filter(|recipe| recipe.technology.eq_ignore_ascii_case(technology))
         .collect()
 }
 
+/// A recipe as written in a pack file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RecipeSpec {
+    id: String,
+    technology: String,
+    title: String,
+    #[serde(default)]
+    summary: String,
+    keywords: Vec<String>,
+    steps: Vec<String>,
+    #[serde(default)]
+    references: Vec<ReferenceSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReferenceSpec {
+    title: String,
+    path: String,
+    #[serde(default)]
+    note: String,
+}
+
+/// A knowledge entry as written in a pack file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnowledgeSpec {
+    technology: String,
+    symbol: String,
+    #[serde(default)]
+    tip: Option<String>,
+    #[serde(default)]
+    pitfalls: Vec<String>,
+    #[serde(default)]
+    related: Vec<ReferenceSpec>,
+    #[serde(default)]
+    snippet: Option<SnippetSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SnippetSpec {
+    #[serde(default = "default_snippet_language")]
+    language: String,
+    code: String,
+    #[serde(default)]
+    caption: Option<String>,
+}
+
+fn default_snippet_language() -> String {
+    "swift".to_string()
+}
+
+/// A pack is either a list of recipes or an object with a `recipes` list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RecipePack {
+    Recipes(Vec<RecipeSpec>),
+    Wrapped { recipes: Vec<RecipeSpec> },
+}
+
+/// A knowledge pack is either a list of entries or an object with an `entries` list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KnowledgePack {
+    Entries(Vec<KnowledgeSpec>),
+    Wrapped { entries: Vec<KnowledgeSpec> },
+}
+
+/// Outcome of [`load_recipe_packs`].
+#[derive(Debug, Default)]
+pub struct RecipePackReport {
+    pub files: usize,
+    pub recipes: usize,
+    /// One message per pack file that could not be loaded.
+    pub errors: Vec<String>,
+}
+
+/// Load every `.json`, `.yaml` and `.yml` recipe pack in `dir`, replacing packs loaded before.
+///
+/// Packs are read once at startup, so their strings are leaked to share the `'static`
+/// lifetime of the built-in recipes. Files that fail to parse are reported and skipped.
+pub fn load_recipe_packs(dir: &Path) -> Result<RecipePackReport> {
+    let (report, loaded) = read_recipe_packs(dir)?;
+    *EXTERNAL_RECIPES
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = loaded;
+    Ok(report)
+}
+
+/// Parse the recipe packs in `dir` without installing them.
+fn read_recipe_packs(dir: &Path) -> Result<(RecipePackReport, Vec<&'static RecipeDefinition>)> {
+    let paths = pack_files(dir).with_context(|| format!("read recipe pack dir {}", dir.display()))?;
+
+    let mut report = RecipePackReport::default();
+    let mut loaded = Vec::new();
+    for path in paths {
+        match read_recipe_pack(&path) {
+            Ok(recipes) => {
+                report.files += 1;
+                loaded.extend(recipes.into_iter().map(leak_recipe));
+            }
+            Err(error) => report.errors.push(format!("{}: {error:#}", path.display())),
+        }
+    }
+    report.recipes = loaded.len();
+    Ok((report, loaded))
+}
+
+/// The `.json`, `.yaml` and `.yml` files in `dir`, sorted by name.
+fn pack_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| matches!(extension, "json" | "yaml" | "yml"))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parse a pack as JSON when `name` ends in `.json`, as YAML otherwise.
+fn parse_pack<T: DeserializeOwned>(name: &str, text: &str) -> Result<T> {
+    if name.ends_with(".json") {
+        serde_json::from_str(text).context("parse JSON pack")
+    } else {
+        serde_yaml::from_str(text).context("parse YAML pack")
+    }
+}
+
+fn read_recipe_pack(path: &Path) -> Result<Vec<RecipeSpec>> {
+    let text = std::fs::read_to_string(path).context("read pack")?;
+    let pack: RecipePack = parse_pack(&path.to_string_lossy(), &text)?;
+    let recipes = match pack {
+        RecipePack::Recipes(recipes) | RecipePack::Wrapped { recipes } => recipes,
+    };
+    for recipe in &recipes {
+        if recipe.keywords.iter().all(|keyword| keyword.trim().is_empty()) {
+            bail!("recipe '{}' needs at least one keyword", recipe.id);
+        }
+        if recipe.steps.is_empty() {
+            bail!("recipe '{}' needs at least one step", recipe.id);
+        }
+    }
+    Ok(recipes)
+}
+
+fn leak_recipe(spec: RecipeSpec) -> &'static RecipeDefinition {
+    Box::leak(Box::new(RecipeDefinition {
+        id: leak(spec.id),
+        technology: leak(spec.technology),
+        title: leak(spec.title),
+        summary: leak(spec.summary),
+        keywords: leak_all(spec.keywords),
+        steps: leak_all(spec.steps),
+        references: leak_references(spec.references),
+    }))
+}
+
+/// Outcome of [`load_knowledge_packs`].
+#[derive(Debug, Default)]
+pub struct KnowledgePackReport {
+    pub files: usize,
+    pub entries: usize,
+    pub errors: Vec<String>,
+}
+
+/// Load every `.json`, `.yaml` and `.yml` knowledge pack in `dir`, replacing packs loaded
+/// from a directory before.
+///
+/// Entries are keyed by technology and symbol title like the built-in ones; fields a pack
+/// leaves out fall back to the built-in entry for the same symbol. Files that fail to parse
+/// are reported and skipped.
+pub fn load_knowledge_packs(dir: &Path) -> Result<KnowledgePackReport> {
+    let paths = pack_files(dir).with_context(|| format!("read knowledge pack dir {}", dir.display()))?;
+
+    let mut report = KnowledgePackReport::default();
+    let mut loaded = HashMap::new();
+    for path in paths {
+        let entries = std::fs::read_to_string(&path)
+            .context("read pack")
+            .and_then(|text| parse_knowledge_pack(&path.to_string_lossy(), &text));
+        match entries {
+            Ok(entries) => {
+                report.files += 1;
+                loaded.extend(entries);
+            }
+            Err(error) => report.errors.push(format!("{}: {error:#}", path.display())),
+        }
+    }
+    report.entries = loaded.len();
+    *LOCAL_KNOWLEDGE
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = loaded;
+    Ok(report)
+}
+
+/// Install a knowledge pack downloaded from `source`, replacing the previous remote pack.
+/// Returns the number of entries.
+pub fn load_remote_knowledge_pack(source: &str, text: &str) -> Result<usize> {
+    let entries = parse_knowledge_pack(source, text)?;
+    let count = entries.len();
+    *REMOTE_KNOWLEDGE
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = entries;
+    Ok(count)
+}
+
+/// Download the knowledge pack at `url` and install it, keeping a copy under `cache_dir`.
+/// When the download fails (e.g. offline), the copy from the last successful download is
+/// used instead.
+pub async fn sync_remote_knowledge_pack(url: &str, cache_dir: &Path) {
+    let cached = cache_dir.join(REMOTE_PACK_CACHE);
+    let (text, source) = match docs_mcp_client::http::get_text(url, REMOTE_PACK_TIMEOUTS, MAX_REMOTE_PACK_BYTES).await {
+        Ok(text) => {
+            if let Some(parent) = cached.parent() {
+                let _ = tokio::fs::create_dir_all(parent).await;
+            }
+            if let Err(error) = tokio::fs::write(&cached, &text).await {
+                warn!(target: "docs_mcp_core", error = %error, "failed to cache knowledge pack");
+            }
+            (text, "download")
+        }
+        Err(error) => {
+            warn!(target: "docs_mcp_core", url, error = %format!("{error:#}"), "failed to download knowledge pack");
+            match tokio::fs::read_to_string(&cached).await {
+                Ok(text) => (text, "cache"),
+                Err(_) => return,
+            }
+        }
+    };
+
+    match load_remote_knowledge_pack(url, &text) {
+        Ok(entries) => info!(target: "docs_mcp_core", url, entries, source, "Loaded remote knowledge pack"),
+        Err(error) => {
+            warn!(target: "docs_mcp_core", url, error = %format!("{error:#}"), "skipping remote knowledge pack");
+        }
+    }
+}
+
+fn parse_knowledge_pack(name: &str, text: &str) -> Result<HashMap<String, &'static KnowledgeEntry>> {
+    let entries = match parse_pack(name, text)? {
+        KnowledgePack::Entries(entries) | KnowledgePack::Wrapped { entries } => entries,
+    };
+    for entry in &entries {
+        if entry.technology.trim().is_empty() || entry.symbol.trim().is_empty() {
+            bail!("knowledge entries need a technology and a symbol");
+        }
+        if entry.tip.is_none() && entry.pitfalls.is_empty() && entry.related.is_empty() && entry.snippet.is_none() {
+            bail!(
+                "knowledge entry '{}' needs a tip, pitfalls, related items or a snippet",
+                entry.symbol
+            );
+        }
+    }
+    Ok(entries
+        .into_iter()
+        .map(|spec| (knowledge_key(&spec.technology, &spec.symbol), leak_knowledge(spec)))
+        .collect())
+}
+
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+fn leak_all(values: Vec<String>) -> &'static [&'static str] {
+    Box::leak(values.into_iter().map(leak).collect::<Vec<_>>().into_boxed_slice())
+}
+
+fn leak_references(references: Vec<ReferenceSpec>) -> &'static [RelatedItem] {
+    let references: Vec<RelatedItem> = references
+        .into_iter()
+        .map(|reference| RelatedItem {
+            title: leak(reference.title),
+            path: leak(reference.path),
+            note: leak(reference.note),
+        })
+        .collect();
+    Box::leak(references.into_boxed_slice())
+}
+
+/// Leak a pack entry, filling the fields it leaves out from the built-in entry.
+fn leak_knowledge(spec: KnowledgeSpec) -> &'static KnowledgeEntry {
+    let builtin = KNOWLEDGE.get(knowledge_key(&spec.technology, &spec.symbol).as_str());
+    let related = if spec.related.is_empty() {
+        builtin.map_or(&[][..], |entry| entry.related)
+    } else {
+        leak_references(spec.related)
+    };
+    let pitfalls = if spec.pitfalls.is_empty() {
+        builtin.map_or(&[][..], |entry| entry.pitfalls)
+    } else {
+        leak_all(spec.pitfalls)
+    };
+    let snippet = spec.snippet.map(|snippet| Snippet {
+        language: leak(snippet.language),
+        code: leak(snippet.code),
+        caption: snippet.caption.map(leak),
+    });
+    Box::leak(Box::new(KnowledgeEntry {
+        quick_tip: spec.tip.map(leak).or_else(|| builtin.and_then(|entry| entry.quick_tip)),
+        related,
+        integration: builtin.map_or(&[][..], |entry| entry.integration),
+        pitfalls,
+        snippet: snippet.or_else(|| builtin.and_then(|entry| entry.snippet)),
+    }))
+}
+
 pub fn snippet(entry: &KnowledgeEntry) -> Option<Snippet> {
     entry.snippet
 }
@@ -2147,8 +2540,113 @@ pub fn related_items(entry: &KnowledgeEntry) -> &'static [RelatedItem] {
 pub fn integration_links(entry: &KnowledgeEntry) -> &'static [IntegrationLink] {
     entry.integration
 }
+
+pub fn pitfalls(entry: &KnowledgeEntry) -> &'static [&'static str] {
+    entry.pitfalls
+}
 const SWIFTUI_SEARCHABLE_SNIPPET: Snippet = Snippet {
         language: "swift",
         code: "List(filteredBooks) { book in\n    Text(book.title)\n}\n.searchable(text: $query, placement: .navigationBarDrawer, prompt: \"Search books\")",
         caption: Some("Attach `searchable` to filter list content reactively."),
     };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn loads_yaml_and_json_packs() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("team.yaml"),
+            r#"
+recipes:
+  - id: acme-networking
+    technology: SwiftUI
+    title: Call the Acme API client
+    summary: Use the shared networking layer.
+    keywords: ["acme api"]
+    steps:
+      - Inject AcmeClient through the environment.
+      - Call it from a .task modifier.
+    references:
+      - title: task(priority:_:)
+        path: /documentation/swiftui/view/task(priority:_:)
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("rust.json"),
+            r#"[{"id": "acme-errors", "technology": "tokio", "title": "Report errors", "keywords": ["acme errors"], "steps": ["Wrap with anyhow::Context."]}]"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("broken.yml"), "recipes: [{id: missing-fields}]").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        // Parsed without installing, so other tests keep seeing the built-in recipes only
+        let (report, loaded) = read_recipe_packs(dir.path()).expect("load");
+        assert_eq!(report.files, 2);
+        assert_eq!(report.recipes, 2);
+        assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
+
+        let recipes = with_builtin_recipes(loaded);
+        let recipe = find_recipe_in(&recipes, "swiftui", "how do I call the ACME API?").expect("external recipe");
+        assert_eq!(recipe.id, "acme-networking");
+        assert_eq!(recipe.references.len(), 1);
+        assert!(recipes_for_in(&recipes, "tokio").iter().any(|recipe| recipe.id == "acme-errors"));
+        // Built-in recipes are still available
+        assert!(find_recipe_in(&recipes, "swiftui", "add search suggestions").is_some());
+
+        assert!(read_recipe_packs(&dir.path().join("notes.txt")).is_err());
+    }
+
+    #[test]
+    fn loads_knowledge_packs() {
+        let dir = tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("swiftui.yaml"),
+            r"
+entries:
+  - technology: SwiftUI
+    symbol: NavigationStack
+    pitfalls:
+      - Don't nest a NavigationStack inside another one.
+  - technology: SwiftUI
+    symbol: ScrollViewReader
+    tip: Give rows stable ids so scrollTo(_:anchor:) can find them.
+    snippet:
+      code: ScrollViewReader { proxy in content }
+",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("broken.json"), r#"[{"technology": "SwiftUI", "symbol": "Text"}]"#).unwrap();
+
+        let report = load_knowledge_packs(dir.path()).expect("load");
+        assert_eq!(report.files, 1);
+        assert_eq!(report.entries, 2);
+        assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
+
+        // Fields the pack leaves out come from the built-in entry
+        let stack = lookup("swiftui", "NavigationStack").expect("merged entry");
+        assert_eq!(pitfalls(stack), ["Don't nest a NavigationStack inside another one."]);
+        assert!(stack.quick_tip.is_some_and(|tip| tip.contains("value-based navigation")));
+        assert!(!related_items(stack).is_empty());
+
+        let reader = lookup("SwiftUI", "ScrollViewReader").expect("new entry");
+        assert!(reader.quick_tip.is_some_and(|tip| tip.contains("stable ids")));
+        assert_eq!(snippet(reader).map(|snippet| snippet.language), Some("swift"));
+    }
+
+    #[test]
+    fn loads_remote_knowledge_pack() {
+        let pack = r#"{"entries": [{"technology": "tokio", "symbol": "spawn_blocking", "pitfalls": ["Blocking tasks can't be aborted."]}]}"#;
+        assert_eq!(load_remote_knowledge_pack("https://example.com/pack.json", pack).unwrap(), 1);
+        let entry = lookup("tokio", "spawn_blocking").expect("remote entry");
+        assert_eq!(pitfalls(entry), ["Blocking tasks can't be aborted."]);
+
+        load_remote_knowledge_pack("https://example.com/pack.yaml", "entries: [{symbol: x}]").unwrap_err();
+        // A failed load keeps the previous pack
+        assert!(lookup("tokio", "spawn_blocking").is_some());
+    }
+}
@@ -3,8 +3,22 @@ use docs_mcp_client::types::{FrameworkData, ReferenceData, SymbolData, Technolog
 
 use crate::state::{AppContext, FrameworkIndexEntry};
 
+pub mod bench;
+pub mod cache_maintenance;
 pub mod design_guidance;
+pub mod documents;
+pub mod feedback;
+pub mod health;
+pub mod index;
+pub mod intent_rules;
+pub mod jsonl_log;
 pub mod knowledge;
+pub mod providers;
+pub mod refresh;
+pub mod server_info;
+pub mod session_store;
+pub mod snapshot;
+pub mod warm;
 
 pub async fn load_active_framework(context: &AppContext) -> Result<FrameworkData> {
     let maybe_cached = context.state.framework_cache.read().await.clone();
@@ -44,6 +58,14 @@ pub async fn ensure_framework_index(context: &AppContext) -> Result<Vec<Framewor
         return Ok(index);
     }
 
+    let active = context.state.active_technology.read().await.clone();
+    if let Some(technology) = active {
+        if let Some(entries) = index::load_stored_index(context, &technology.identifier).await {
+            *context.state.framework_index.write().await = Some(entries.clone());
+            return Ok(entries);
+        }
+    }
+
     let framework = load_active_framework(context).await?;
     let entries = build_framework_index(&framework);
 
@@ -66,6 +88,16 @@ pub async fn ensure_global_framework_index(
         return Ok(index);
     }
 
+    if let Some(entries) = index::load_stored_index(context, &technology.identifier).await {
+        context
+            .state
+            .global_indexes
+            .write()
+            .await
+            .insert(technology.identifier.clone(), entries.clone());
+        return Ok(entries);
+    }
+
     let identifier = technology
         .identifier
         .split('/')
@@ -224,7 +256,7 @@ fn split_camel_case(token: &str) -> Vec<String> {
     pieces
 }
 
-fn normalize_reference_link(input: &str) -> String {
+pub(crate) fn normalize_reference_link(input: &str) -> String {
     let trimmed = input.trim();
     let without_doc = trimmed
         .strip_prefix("doc://com.apple.documentation/")
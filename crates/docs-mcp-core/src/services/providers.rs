@@ -0,0 +1,168 @@
+use multi_provider_client::{types::ProviderType, AllTechnologies};
+use serde::Serialize;
+
+use crate::services::cache_maintenance::CacheLocations;
+use crate::services::health::{check_health, ProviderHealth};
+use crate::state::AppContext;
+
+/// One provider's technologies, cache location and reachability.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderStatus {
+    pub provider: ProviderType,
+    pub name: &'static str,
+    /// `None` when the technology list could not be loaded.
+    pub technologies: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub technologies_error: Option<String>,
+    pub cache_dir: Option<String>,
+    /// `None` when the probe was skipped (offline mode).
+    pub reachable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub probe_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvidersReport {
+    pub offline: bool,
+    pub providers: Vec<ProviderStatus>,
+}
+
+impl ProvidersReport {
+    /// Plain-text table for the CLI.
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut lines = Vec::with_capacity(self.providers.len() + 1);
+        if self.offline {
+            lines.push(
+                "Offline mode: technologies come from the cache and hosts are not probed."
+                    .to_string(),
+            );
+        }
+        for status in &self.providers {
+            let technologies = match (status.technologies, &status.technologies_error) {
+                (Some(count), _) => format!("{count} technologies"),
+                (None, Some(error)) => format!("technologies unavailable ({error})"),
+                (None, None) => "technologies unavailable".to_string(),
+            };
+            let reachability = match (status.reachable, &status.probe_error) {
+                (None, _) => "not probed".to_string(),
+                (Some(true), _) => format!(
+                    "reachable (HTTP {}, {} ms)",
+                    status.status_code.unwrap_or_default(),
+                    status.latency_ms.unwrap_or_default()
+                ),
+                (Some(false), Some(error)) => format!("unreachable ({error})"),
+                (Some(false), None) => {
+                    format!("unreachable (HTTP {})", status.status_code.unwrap_or_default())
+                }
+            };
+            let icon = match (status.technologies.is_some(), status.reachable) {
+                (true, Some(true) | None) => "✅",
+                (false, Some(false)) => "❌",
+                _ => "⚠️",
+            };
+            lines.push(format!("{icon} {} [{}]", status.name, status.provider.slug()));
+            lines.push(format!("   {technologies}; {reachability}"));
+            lines.push(format!(
+                "   cache: {}",
+                status.cache_dir.as_deref().unwrap_or("unavailable")
+            ));
+        }
+        lines
+    }
+}
+
+/// Every provider with its technology count, cache directory and reachability.
+///
+/// Technologies are loaded with the federated deadline, so a slow provider is reported as
+/// an error rather than holding up the others.
+pub async fn list_providers(context: &AppContext) -> ProvidersReport {
+    let (technologies, health) = tokio::join!(
+        context.providers.get_all_technologies(),
+        check_health(context, &[])
+    );
+    build_report(
+        &technologies,
+        &health.providers,
+        &CacheLocations::for_client(&context.client),
+        health.offline,
+    )
+}
+
+fn build_report(
+    technologies: &AllTechnologies,
+    health: &[ProviderHealth],
+    locations: &CacheLocations,
+    offline: bool,
+) -> ProvidersReport {
+    let providers = ProviderType::ALL
+        .into_iter()
+        .map(|provider| {
+            let probe = health.iter().find(|probe| probe.provider == provider);
+            ProviderStatus {
+                provider,
+                name: provider.name(),
+                technologies: technologies.technologies.get(&provider).map(Vec::len),
+                technologies_error: technologies
+                    .errors
+                    .get(&provider)
+                    .map(|error| error.to_string()),
+                cache_dir: locations
+                    .dir_for(provider)
+                    .map(|dir| dir.display().to_string()),
+                reachable: probe.and_then(|probe| probe.reachable),
+                status_code: probe.and_then(|probe| probe.status_code),
+                latency_ms: probe.and_then(|probe| probe.latency_ms),
+                probe_error: probe.and_then(|probe| probe.error.clone()),
+            }
+        })
+        .collect();
+    ProvidersReport { offline, providers }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn report_combines_technologies_and_probes() {
+        let mut technologies = AllTechnologies::default();
+        technologies.record(ProviderType::Rust, Ok(Vec::new()));
+        technologies.record(ProviderType::Mdn, Err(anyhow::anyhow!("timed out after 8.0s")));
+        let health = vec![ProviderHealth {
+            provider: ProviderType::Rust,
+            url: ProviderType::Rust.homepage(),
+            reachable: Some(true),
+            status_code: Some(200),
+            latency_ms: Some(42),
+            error: None,
+        }];
+        let locations = CacheLocations {
+            apple: PathBuf::from("/cache/apple"),
+            providers: Some(PathBuf::from("/cache/providers")),
+        };
+
+        let report = build_report(&technologies, &health, &locations, false);
+        assert_eq!(report.providers.len(), ProviderType::ALL.len());
+
+        let rust = &report.providers[ProviderType::ALL
+            .iter()
+            .position(|provider| *provider == ProviderType::Rust)
+            .unwrap()];
+        assert_eq!(rust.technologies, Some(0));
+        assert_eq!(rust.reachable, Some(true));
+        assert_eq!(rust.cache_dir.as_deref(), Some("/cache/providers/rust"));
+
+        let lines = report.summary_lines().join("\n");
+        assert!(lines.contains("✅ Rust [rust]"));
+        assert!(lines.contains("reachable (HTTP 200, 42 ms)"));
+        assert!(lines.contains("technologies unavailable (timed out after 8.0s)"));
+        assert!(lines.contains("cache: /cache/apple"));
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use docs_mcp_client::stale::{self, StaleNote};
+use time::OffsetDateTime;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::state::AppContext;
+
+/// Default time between background refresh passes.
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// Default age after which a cached payload is revalidated.
+pub const DEFAULT_REFRESH_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+/// Most-used frameworks considered on each pass.
+const MAX_FRAMEWORKS_PER_PASS: usize = 8;
+
+/// Settings for the stale-while-revalidate worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshSettings {
+    /// Time between refresh passes.
+    pub interval: Duration,
+    /// Cached payloads older than this are revalidated.
+    pub refresh_after: Duration,
+}
+
+impl Default for RefreshSettings {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_REFRESH_INTERVAL,
+            refresh_after: DEFAULT_REFRESH_AFTER,
+        }
+    }
+}
+
+/// Totals for a single refresh pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RefreshSummary {
+    pub refreshed: usize,
+    pub failures: usize,
+}
+
+/// Spawn a task that keeps the technologies index and the most-used frameworks
+/// fresh in the background, so requests keep being served from disk.
+pub fn spawn_refresh_worker(context: Arc<AppContext>, settings: RefreshSettings) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(settings.interval).await;
+            if docs_mcp_client::offline::is_offline() {
+                continue;
+            }
+            let summary = refresh_stale_entries(&context, settings.refresh_after).await;
+            if summary.refreshed > 0 || summary.failures > 0 {
+                debug!(
+                    target: "docs_mcp_core",
+                    refreshed = summary.refreshed,
+                    failures = summary.failures,
+                    "background cache refresh finished"
+                );
+            }
+        }
+    })
+}
+
+/// Revalidate the technologies index and frequently used frameworks older than `refresh_after`.
+pub async fn refresh_stale_entries(context: &AppContext, refresh_after: Duration) -> RefreshSummary {
+    let client = &context.client;
+    let mut summary = RefreshSummary::default();
+
+    if is_stale(client.technologies_stored_at().await, refresh_after) {
+        let outcome = stale::track(client.refresh_technologies()).await;
+        record(&mut summary, "technologies", refreshed(outcome));
+    }
+
+    for framework in client.frequent_frameworks(MAX_FRAMEWORKS_PER_PASS) {
+        if is_stale(client.framework_stored_at(&framework).await, refresh_after) {
+            let outcome = stale::track(client.refresh_framework(&framework)).await;
+            record(&mut summary, &framework, refreshed(outcome));
+        }
+    }
+
+    summary
+}
+
+/// Only entries that exist on disk and have aged past `refresh_after` are refreshed.
+fn is_stale(stored_at: Result<Option<OffsetDateTime>>, refresh_after: Duration) -> bool {
+    match stored_at {
+        Ok(Some(stored_at)) => OffsetDateTime::now_utc() - stored_at >= refresh_after,
+        _ => false,
+    }
+}
+
+/// A refresh that fell back to the cached copy did not refresh anything.
+fn refreshed<T>((outcome, notes): (Result<T>, Vec<StaleNote>)) -> Result<()> {
+    match notes.into_iter().next() {
+        Some(note) => Err(anyhow::anyhow!(note.error)),
+        None => outcome.map(|_| ()),
+    }
+}
+
+fn record(summary: &mut RefreshSummary, name: &str, outcome: Result<()>) {
+    match outcome {
+        Ok(()) => summary.refreshed += 1,
+        Err(error) => {
+            warn!(target: "docs_mcp_core", entry = name, error = %error, "background refresh failed");
+            summary.failures += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_aged_entries_are_stale() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let now = OffsetDateTime::now_utc();
+        assert!(is_stale(Ok(Some(now - time::Duration::days(2))), day));
+        assert!(!is_stale(Ok(Some(now)), day));
+        assert!(!is_stale(Ok(None), day));
+        assert!(!is_stale(Err(anyhow::anyhow!("unreadable")), day));
+    }
+
+    #[test]
+    fn records_outcomes() {
+        let mut summary = RefreshSummary::default();
+        record(&mut summary, "SwiftUI", Ok(()));
+        record(&mut summary, "UIKit", Err(anyhow::anyhow!("boom")));
+        assert_eq!(summary, RefreshSummary { refreshed: 1, failures: 1 });
+    }
+
+    #[test]
+    fn stale_fallbacks_count_as_failures() {
+        let note = StaleNote {
+            resource: "documentation/uikit.json".to_string(),
+            error: "connection reset".to_string(),
+        };
+        assert!(refreshed((Ok::<_, anyhow::Error>(1), Vec::new())).is_ok());
+        assert!(refreshed((Ok::<_, anyhow::Error>(1), vec![note])).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+use std::time::Duration;
+
+use docs_mcp_client::http::ProxyMode;
+use multi_provider_client::memory::{memory_ttl, shared_memory_cache};
+use multi_provider_client::types::ProviderType;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::services::cache_maintenance::{format_bytes, CacheLocations};
+use crate::state::AppContext;
+use crate::ServerMode;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransportInfo {
+    /// `stdio`, `http` or `headless`.
+    pub mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bind: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_origins: Vec<String>,
+    pub auth_required: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderInfo {
+    pub provider: ProviderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_dir: Option<String>,
+    pub memory_ttl_secs: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshInfo {
+    pub interval_secs: u64,
+    pub refresh_after_secs: u64,
+}
+
+/// The effective configuration of a running server. Secrets are reported as set/unset only.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    pub version: &'static str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub boot_timestamp: OffsetDateTime,
+    pub uptime_secs: u64,
+    pub transport: TransportInfo,
+    pub offline: bool,
+    pub cache_dir: String,
+    pub cache_max_size_bytes: Option<u64>,
+    pub memory_budget_bytes: u64,
+    /// Background revalidation of cached payloads; `None` when disabled.
+    pub refresh: Option<RefreshInfo>,
+    pub providers: Vec<ProviderInfo>,
+    pub max_retries: u32,
+    pub max_concurrent_requests: usize,
+    pub requests_per_second: f64,
+    pub federated_deadline_ms: u64,
+    /// `system`, `disabled` or `explicit`; explicit proxy URLs may embed credentials.
+    pub proxy: &'static str,
+    pub huggingface_token: bool,
+    pub github_token: bool,
+    /// Pinned tonkeeper OpenAPI spec reference; `None` follows `master`.
+    pub ton_spec_ref: Option<String>,
+    pub telemetry_log: bool,
+    pub request_log: bool,
+    pub persist_session: bool,
+    pub tools: Vec<String>,
+    pub tool_aliases: Vec<(String, String)>,
+}
+
+impl ServerInfo {
+    pub fn summary_lines(&self) -> Vec<String> {
+        let mut transport = self.transport.mode.to_string();
+        if let Some(bind) = &self.transport.bind {
+            transport.push_str(&format!(" on {bind}"));
+            if self.transport.auth_required {
+                transport.push_str(" (bearer token required)");
+            }
+        }
+        let mut lines = vec![
+            "## Server".to_string(),
+            String::new(),
+            format!("- Version: {}", self.version),
+            format!(
+                "- Started: {} (up {})",
+                self.boot_timestamp,
+                format_duration(Duration::from_secs(self.uptime_secs))
+            ),
+            format!("- Transport: {transport}"),
+            format!("- Offline: {}", yes_no(self.offline)),
+            String::new(),
+            "## Cache".to_string(),
+            String::new(),
+            format!("- Directory: {}", self.cache_dir),
+            format!(
+                "- Disk budget: {}",
+                self.cache_max_size_bytes
+                    .map(format_bytes)
+                    .unwrap_or_else(|| "default".to_string())
+            ),
+            format!("- Provider memory budget: {}", format_bytes(self.memory_budget_bytes)),
+            match &self.refresh {
+                Some(refresh) => format!(
+                    "- Background refresh: every {}, revalidating entries older than {}",
+                    format_duration(Duration::from_secs(refresh.interval_secs)),
+                    format_duration(Duration::from_secs(refresh.refresh_after_secs))
+                ),
+                None => "- Background refresh: disabled".to_string(),
+            },
+            String::new(),
+            "## Providers".to_string(),
+            String::new(),
+        ];
+        for provider in &self.providers {
+            lines.push(format!(
+                "- {}: memory TTL {}, cache {}",
+                provider.provider.name(),
+                format_duration(Duration::from_secs(provider.memory_ttl_secs)),
+                provider.cache_dir.as_deref().unwrap_or("unavailable")
+            ));
+        }
+        lines.extend([
+            String::new(),
+            "## Network".to_string(),
+            String::new(),
+            format!("- Retries: {}", self.max_retries),
+            format!("- Concurrent requests: {}", self.max_concurrent_requests),
+            format!(
+                "- Rate limit: {}",
+                if self.requests_per_second > 0.0 {
+                    format!("{} request(s)/s per host", self.requests_per_second)
+                } else {
+                    "disabled".to_string()
+                }
+            ),
+            format!("- Federated deadline: {} ms", self.federated_deadline_ms),
+            format!("- Proxy: {}", self.proxy),
+            format!("- Hugging Face token: {}", set_unset(self.huggingface_token)),
+            format!("- GitHub token: {}", set_unset(self.github_token)),
+            format!("- TON spec: {}", self.ton_spec_ref.as_deref().unwrap_or("master (unpinned)")),
+            String::new(),
+            "## Session".to_string(),
+            String::new(),
+            format!("- Telemetry log: {}", yes_no(self.telemetry_log)),
+            format!("- Request log: {}", yes_no(self.request_log)),
+            format!("- Persisted session: {}", yes_no(self.persist_session)),
+            format!("- Tools: {}", self.tools.join(", ")),
+        ]);
+        if !self.tool_aliases.is_empty() {
+            let aliases: Vec<String> = self
+                .tool_aliases
+                .iter()
+                .map(|(alias, tool)| format!("{alias} → {tool}"))
+                .collect();
+            lines.push(format!("- Tool aliases: {}", aliases.join(", ")));
+        }
+        lines
+    }
+}
+
+/// Snapshot the configuration `context` is running with.
+pub async fn server_info(context: &AppContext) -> ServerInfo {
+    let config = &context.config;
+    let locations = CacheLocations::for_client(&context.client);
+    let uptime = OffsetDateTime::now_utc() - config.boot_timestamp;
+
+    let mut tools: Vec<String> = context
+        .tools
+        .definitions()
+        .await
+        .into_iter()
+        .map(|definition| definition.name)
+        .collect();
+    tools.sort();
+    let mut tool_aliases: Vec<(String, String)> = config
+        .tool_aliases
+        .iter()
+        .map(|(alias, tool)| (alias.clone(), tool.clone()))
+        .collect();
+    tool_aliases.sort();
+
+    ServerInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        boot_timestamp: config.boot_timestamp,
+        uptime_secs: uptime.whole_seconds().max(0) as u64,
+        transport: TransportInfo {
+            mode: match config.mode {
+                ServerMode::Stdio => "stdio",
+                ServerMode::Http => "http",
+                ServerMode::Headless => "headless",
+            },
+            bind: (config.mode == ServerMode::Http).then(|| config.http.bind.to_string()),
+            allowed_origins: config.http.allowed_origins.clone(),
+            auth_required: config.mode == ServerMode::Http && config.http.auth_token.is_some(),
+        },
+        offline: config.offline,
+        cache_dir: context.client.cache_dir().display().to_string(),
+        cache_max_size_bytes: config.cache_max_size_bytes,
+        memory_budget_bytes: shared_memory_cache().budget(),
+        refresh: config.refresh.map(|refresh| RefreshInfo {
+            interval_secs: refresh.interval.as_secs(),
+            refresh_after_secs: refresh.refresh_after.as_secs(),
+        }),
+        providers: ProviderType::ALL
+            .into_iter()
+            .map(|provider| ProviderInfo {
+                provider,
+                cache_dir: locations
+                    .dir_for(provider)
+                    .map(|dir| dir.display().to_string()),
+                memory_ttl_secs: memory_ttl(provider).whole_seconds().max(0) as u64,
+            })
+            .collect(),
+        max_retries: config.retry.max_retries,
+        max_concurrent_requests: config.max_concurrent_requests,
+        requests_per_second: config.rate_limit.requests_per_second,
+        federated_deadline_ms: config.federated_deadline.as_millis() as u64,
+        proxy: match config.network.proxy {
+            ProxyMode::System => "system",
+            ProxyMode::Disabled => "disabled",
+            ProxyMode::Explicit { .. } => "explicit",
+        },
+        huggingface_token: config.huggingface_token.is_some(),
+        github_token: config.github_token.is_some(),
+        ton_spec_ref: config.ton_spec_ref.clone(),
+        telemetry_log: config.telemetry_log.is_some(),
+        request_log: config.request_log.is_some(),
+        persist_session: config.persist_session,
+        tools,
+        tool_aliases,
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn set_unset(value: bool) -> &'static str {
+    if value {
+        "set"
+    } else {
+        "not set"
+    }
+}
+
+/// Coarse human-readable duration, e.g. `2d 3h`, `45m`, `12s`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{secs}s"),
+        (0, 0, _) => format!("{minutes}m"),
+        (0, _, 0) => format!("{hours}h"),
+        (0, _, _) => format!("{hours}h {minutes}m"),
+        (_, 0, _) => format!("{days}d"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServerConfig;
+    use docs_mcp_client::{AppleDocsClient, ClientConfig};
+    use multi_provider_client::credentials::ApiToken;
+    use tempfile::tempdir;
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_secs(12)), "12s");
+        assert_eq!(format_duration(Duration::from_secs(30 * 60)), "30m");
+        assert_eq!(format_duration(Duration::from_secs(24 * 60 * 60)), "1d");
+        assert_eq!(format_duration(Duration::from_secs(26 * 60 * 60 + 60)), "1d 2h");
+    }
+
+    #[tokio::test]
+    async fn reports_effective_configuration_without_secrets() {
+        let dir = tempdir().expect("tempdir");
+        let client = AppleDocsClient::with_config(ClientConfig {
+            cache_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let config = ServerConfig {
+            mode: ServerMode::Http,
+            offline: true,
+            refresh: None,
+            github_token: ApiToken::new("ghp_secret"),
+            http: crate::transport::http::HttpSettings {
+                auth_token: ApiToken::new("http-secret"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let context = AppContext::with_config(client, config);
+
+        let info = server_info(&context).await;
+        assert_eq!(info.transport.mode, "http");
+        assert!(info.transport.auth_required);
+        assert!(info.offline);
+        assert!(info.refresh.is_none());
+        assert_eq!(info.providers.len(), ProviderType::ALL.len());
+        let rust = info.providers.iter().find(|p| p.provider == ProviderType::Rust).unwrap();
+        assert_eq!(rust.memory_ttl_secs, 24 * 60 * 60);
+
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(!json.contains("secret"));
+        assert!(json.contains("\"githubToken\":true"));
+        let text = info.summary_lines().join("\n");
+        assert!(text.contains("- Transport: http on 127.0.0.1:8080 (bearer token required)"));
+        assert!(text.contains("- Background refresh: disabled"));
+    }
+}
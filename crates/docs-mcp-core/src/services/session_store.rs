@@ -0,0 +1,227 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::{Context, Result};
+use docs_mcp_client::cache::disk::STATE_DIR;
+use docs_mcp_client::types::Technology;
+use multi_provider_client::types::{ProviderType, UnifiedTechnology};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::state::{AppContext, ServerState};
+
+/// Prefix of the per-workspace session files under the `state` directory of the cache dir.
+const SESSION_FILE_PREFIX: &str = "session-";
+
+/// The parts of [`ServerState`] that survive a restart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSnapshot {
+    pub active_provider: ProviderType,
+    #[serde(default)]
+    pub active_technology: Option<Technology>,
+    #[serde(default)]
+    pub active_unified_technology: Option<UnifiedTechnology>,
+    #[serde(default)]
+    pub expanded_identifiers: Vec<String>,
+    /// Set by `choose_technology`; see [`ServerState::technology_pinned`].
+    #[serde(default)]
+    pub technology_pinned: bool,
+}
+
+impl SessionSnapshot {
+    pub async fn capture(state: &ServerState) -> Self {
+        let mut expanded_identifiers: Vec<String> =
+            state.expanded_identifiers.lock().await.iter().cloned().collect();
+        expanded_identifiers.sort();
+        Self {
+            active_provider: *state.active_provider.read().await,
+            active_technology: state.active_technology.read().await.clone(),
+            active_unified_technology: state.active_unified_technology.read().await.clone(),
+            expanded_identifiers,
+            technology_pinned: *state.technology_pinned.read().await,
+        }
+    }
+}
+
+/// Persists the stdio session's [`SessionSnapshot`], rewriting the file only when it changes.
+#[derive(Debug)]
+pub struct SessionStore {
+    path: PathBuf,
+    last_saved: Mutex<Option<Vec<u8>>>,
+}
+
+impl SessionStore {
+    /// The store of servers started in `workspace`, so servers running in different projects
+    /// at the same time keep separate selections.
+    pub fn in_cache_dir(cache_dir: &Path, workspace: &Path) -> Self {
+        let workspace = std::fs::canonicalize(workspace).unwrap_or_else(|_| workspace.to_path_buf());
+        let digest = Sha256::digest(workspace.as_os_str().as_encoded_bytes());
+        let id: String = digest[..8].iter().map(|byte| format!("{byte:02x}")).collect();
+        Self::new(cache_dir.join(STATE_DIR).join(format!("{SESSION_FILE_PREFIX}{id}.json")))
+    }
+
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            last_saved: Mutex::new(None),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The saved snapshot, or `None` when nothing was saved yet.
+    pub async fn load(&self) -> Result<Option<SessionSnapshot>> {
+        let bytes = match tokio::fs::read(&self.path).await {
+            Ok(bytes) => bytes,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error).with_context(|| format!("read {}", self.path.display())),
+        };
+        let snapshot = serde_json::from_slice(&bytes)
+            .with_context(|| format!("parse {}", self.path.display()))?;
+        *self.last_saved.lock().await = Some(bytes);
+        Ok(Some(snapshot))
+    }
+
+    pub async fn save(&self, snapshot: &SessionSnapshot) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(snapshot).context("serialize session state")?;
+        let mut last_saved = self.last_saved.lock().await;
+        if last_saved.as_deref() == Some(bytes.as_slice()) {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("create {}", parent.display()))?;
+        }
+        // Write then rename so a crash never leaves a truncated file behind; the temp name is
+        // unique so servers sharing the file never write into each other's temp file
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+        let tmp = self.path.with_extension(format!(
+            "json.{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::write(&tmp, &bytes)
+            .await
+            .with_context(|| format!("write {}", tmp.display()))?;
+        tokio::fs::rename(&tmp, &self.path)
+            .await
+            .with_context(|| format!("rename {} -> {}", tmp.display(), self.path.display()))?;
+        *last_saved = Some(bytes);
+        Ok(())
+    }
+}
+
+/// Restore the saved selection into `context.state`.
+///
+/// Expanded identifiers are re-expanded in the background, since the framework index they
+/// feed is not persisted.
+pub async fn restore_session(context: &AppContext) {
+    let Some(store) = &context.session_store else {
+        return;
+    };
+    let snapshot = match store.load().await {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => return,
+        Err(error) => {
+            warn!(target: "docs_mcp_core", error = %error, "ignoring unreadable session state");
+            return;
+        }
+    };
+
+    let state = &context.state;
+    *state.active_provider.write().await = snapshot.active_provider;
+    *state.active_technology.write().await = snapshot.active_technology;
+    *state.active_unified_technology.write().await = snapshot.active_unified_technology;
+    *state.technology_pinned.write().await = snapshot.technology_pinned;
+    tracing::info!(
+        target: "docs_mcp_core",
+        provider = snapshot.active_provider.slug(),
+        path = %store.path().display(),
+        "restored session state"
+    );
+
+    if snapshot.expanded_identifiers.is_empty() {
+        return;
+    }
+    let context = context.clone();
+    tokio::spawn(async move {
+        if let Err(error) = super::expand_identifiers(&context, &snapshot.expanded_identifiers).await {
+            warn!(target: "docs_mcp_core", error = %error, "failed to re-expand restored identifiers");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn round_trips_and_skips_unchanged_writes() {
+        let dir = tempdir().expect("tempdir");
+        let store = SessionStore::in_cache_dir(dir.path(), dir.path());
+        assert!(store.load().await.unwrap().is_none());
+
+        let state = ServerState::default();
+        *state.active_provider.write().await = ProviderType::Rust;
+        *state.technology_pinned.write().await = true;
+        state.expanded_identifiers.lock().await.insert("doc://b".to_string());
+        state.expanded_identifiers.lock().await.insert("doc://a".to_string());
+        store.save(&SessionSnapshot::capture(&state).await).await.unwrap();
+        assert!(store.path().starts_with(dir.path().join(STATE_DIR)));
+        assert!(store.path().exists());
+        let leftovers = std::fs::read_dir(dir.path().join(STATE_DIR)).unwrap().count();
+        assert_eq!(leftovers, 1, "temp file left behind");
+
+        // An unchanged snapshot is not rewritten
+        std::fs::remove_file(store.path()).unwrap();
+        store.save(&SessionSnapshot::capture(&state).await).await.unwrap();
+        assert!(!store.path().exists());
+
+        *state.active_provider.write().await = ProviderType::Mdn;
+        store.save(&SessionSnapshot::capture(&state).await).await.unwrap();
+        let loaded = SessionStore::in_cache_dir(dir.path(), dir.path()).load().await.unwrap().unwrap();
+        assert_eq!(loaded.active_provider, ProviderType::Mdn);
+        assert!(loaded.technology_pinned);
+        assert_eq!(loaded.expanded_identifiers, vec!["doc://a", "doc://b"]);
+    }
+
+    #[test]
+    fn workspaces_get_their_own_file() {
+        let cache = tempdir().expect("tempdir");
+        let (first, second) = (tempdir().expect("tempdir"), tempdir().expect("tempdir"));
+        let path = |workspace: &Path| SessionStore::in_cache_dir(cache.path(), workspace).path().to_path_buf();
+        assert_ne!(path(first.path()), path(second.path()));
+        assert_eq!(path(first.path()), path(&first.path().join(".")));
+    }
+
+    #[tokio::test]
+    async fn restores_into_context() {
+        let dir = tempdir().expect("tempdir");
+        let workspace = std::env::current_dir().unwrap();
+        let store = SessionStore::in_cache_dir(dir.path(), &workspace);
+        store
+            .save(&SessionSnapshot {
+                active_provider: ProviderType::Telegram,
+                technology_pinned: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let client = docs_mcp_client::AppleDocsClient::with_config(docs_mcp_client::ClientConfig {
+            cache_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        });
+        let context = AppContext::new(client);
+        restore_session(&context).await;
+        assert_eq!(*context.state.active_provider.read().await, ProviderType::Telegram);
+        assert!(*context.state.technology_pinned.read().await);
+    }
+}
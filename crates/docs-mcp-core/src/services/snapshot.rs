@@ -0,0 +1,467 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use docs_mcp_client::cache::disk::RESERVED_DIRS;
+use docs_mcp_client::cache::DirUsage;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression as GzLevel;
+use multi_provider_client::types::ProviderType;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::services::cache_maintenance::{format_bytes, CacheLocations};
+
+const MANIFEST_NAME: &str = "manifest.json";
+const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+/// Apple's technology list, kept in framework-filtered snapshots so the frameworks resolve.
+const APPLE_TECHNOLOGIES_FILE: &str = "technologies.json";
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const ZSTD_LEVEL: i32 = 10;
+
+/// Which caches `export_snapshot` packs.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotOptions {
+    /// Providers to include; empty includes every provider.
+    pub providers: Vec<ProviderType>,
+    /// Apple frameworks (e.g. `swiftui`) to include; empty includes the whole Apple cache.
+    pub frameworks: Vec<String>,
+}
+
+impl SnapshotOptions {
+    fn includes(&self, provider: ProviderType) -> bool {
+        self.providers.is_empty() || self.providers.contains(&provider)
+    }
+
+    /// Whether `relative` (a path inside `provider`'s cache dir) belongs in the snapshot.
+    fn includes_file(&self, provider: ProviderType, relative: &Path) -> bool {
+        if provider != ProviderType::Apple || self.frameworks.is_empty() {
+            return true;
+        }
+        let name = relative.to_string_lossy().replace('\\', "/").to_lowercase();
+        name == APPLE_TECHNOLOGIES_FILE
+            || self.frameworks.iter().any(|framework| {
+                let framework = framework.trim().to_lowercase();
+                name == format!("{framework}.json")
+                    || name == format!("indexes/{framework}.json")
+                    || name == format!("documentation__{framework}.json")
+                    || name.starts_with(&format!("documentation__{framework}__"))
+            })
+    }
+}
+
+/// Archive compression, chosen from the file extension on export and sniffed on import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    fn for_archive(archive: &Path) -> Self {
+        let name = archive.to_string_lossy().to_lowercase();
+        if name.ends_with(".zst") || name.ends_with(".tzst") {
+            Self::Zstd
+        } else {
+            Self::Gzip
+        }
+    }
+}
+
+/// Per-provider contents of a snapshot archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotProvider {
+    pub provider: ProviderType,
+    #[serde(flatten)]
+    pub usage: DirUsage,
+}
+
+/// Manifest stored at the root of every snapshot archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    pub schema_version: u32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub server_version: String,
+    pub providers: Vec<SnapshotProvider>,
+}
+
+impl SnapshotManifest {
+    /// Plain-text summary for the CLI, headed by `headline` (e.g. `Exported snapshot.tar.gz`).
+    pub fn summary_lines(&self, headline: &str) -> Vec<String> {
+        let files: u64 = self.providers.iter().map(|entry| entry.usage.files).sum();
+        let bytes: u64 = self.providers.iter().map(|entry| entry.usage.bytes).sum();
+        let mut lines = vec![format!(
+            "{headline}: {files} cache file(s), {} (created {}).",
+            format_bytes(bytes),
+            self.created_at.date()
+        )];
+        for entry in &self.providers {
+            lines.push(format!(
+                "- {}: {} file(s), {}",
+                entry.provider.name(),
+                entry.usage.files,
+                format_bytes(entry.usage.bytes)
+            ));
+        }
+        lines
+    }
+}
+
+/// Pack every provider cache into a single gzip-compressed tar archive at `archive`.
+pub async fn export_snapshot(locations: &CacheLocations, archive: &Path) -> Result<SnapshotManifest> {
+    export_snapshot_with(locations, archive, &SnapshotOptions::default()).await
+}
+
+/// Pack the caches selected by `options` into a tar archive at `archive`, compressed with
+/// zstd when the name ends in `.zst`/`.tzst` and with gzip otherwise.
+pub async fn export_snapshot_with(
+    locations: &CacheLocations,
+    archive: &Path,
+    options: &SnapshotOptions,
+) -> Result<SnapshotManifest> {
+    let mut sources = Vec::new();
+    let mut providers = Vec::new();
+    for provider in ProviderType::ALL {
+        if !options.includes(provider) {
+            continue;
+        }
+        let Some(dir) = locations.dir_for(provider) else {
+            continue;
+        };
+        let files: Vec<PathBuf> = {
+            let dir = dir.clone();
+            tokio::task::spawn_blocking(move || list_files(&dir))
+                .await
+                .context("snapshot export task panicked")??
+        }
+        .into_iter()
+        .filter(|relative| options.includes_file(provider, relative))
+        .collect();
+        if files.is_empty() {
+            continue;
+        }
+        let usage = selected_usage(&dir, &files);
+        sources.push((provider, dir, files));
+        providers.push(SnapshotProvider { provider, usage });
+    }
+
+    let manifest = SnapshotManifest {
+        schema_version: SNAPSHOT_SCHEMA_VERSION,
+        created_at: OffsetDateTime::now_utc(),
+        server_version: env!("CARGO_PKG_VERSION").to_string(),
+        providers,
+    };
+
+    let archive = archive.to_path_buf();
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest).context("serialize snapshot manifest")?;
+    tokio::task::spawn_blocking(move || write_archive(&archive, &manifest_bytes, &sources))
+        .await
+        .context("snapshot export task panicked")??;
+
+    Ok(manifest)
+}
+
+/// Unpack a snapshot produced by [`export_snapshot`] into the local provider caches.
+/// Existing entries with the same key are overwritten; others are kept.
+pub async fn import_snapshot(locations: &CacheLocations, archive: &Path) -> Result<SnapshotManifest> {
+    import_snapshot_with(locations, archive, &SnapshotOptions::default()).await
+}
+
+/// Like [`import_snapshot`], unpacking only the caches selected by `options`.
+pub async fn import_snapshot_with(
+    locations: &CacheLocations,
+    archive: &Path,
+    options: &SnapshotOptions,
+) -> Result<SnapshotManifest> {
+    let locations = locations.clone();
+    let archive = archive.to_path_buf();
+    let selection = options.clone();
+    let mut manifest =
+        tokio::task::spawn_blocking(move || read_archive(&locations, &archive, &selection))
+            .await
+            .context("snapshot import task panicked")??;
+    manifest.providers.retain(|entry| options.includes(entry.provider));
+    Ok(manifest)
+}
+
+/// Files under `dir`, relative to it, skipping the reserved log/state directories.
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        let entries = match std::fs::read_dir(dir.join(&relative)) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error).with_context(|| format!("read {}", dir.display())),
+        };
+        for entry in entries {
+            let entry = entry.with_context(|| format!("read {}", dir.display()))?;
+            let name = entry.file_name();
+            if relative.as_os_str().is_empty() && RESERVED_DIRS.iter().any(|reserved| name == *reserved) {
+                continue;
+            }
+            let path = relative.join(&name);
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn selected_usage(dir: &Path, files: &[PathBuf]) -> DirUsage {
+    let bytes = files
+        .iter()
+        .filter_map(|relative| std::fs::metadata(dir.join(relative)).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    DirUsage {
+        files: files.len() as u64,
+        bytes,
+    }
+}
+
+fn write_archive(
+    archive: &Path,
+    manifest: &[u8],
+    sources: &[(ProviderType, PathBuf, Vec<PathBuf>)],
+) -> Result<()> {
+    if let Some(parent) = archive.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    let file = File::create(archive).with_context(|| format!("create snapshot {}", archive.display()))?;
+    match Compression::for_archive(archive) {
+        Compression::Gzip => {
+            let builder = tar::Builder::new(GzEncoder::new(file, GzLevel::default()));
+            append_entries(builder, manifest, sources)?
+                .finish()
+                .context("flush snapshot archive")?;
+        }
+        Compression::Zstd => {
+            let encoder = zstd::Encoder::new(file, ZSTD_LEVEL).context("start zstd stream")?;
+            append_entries(tar::Builder::new(encoder), manifest, sources)?
+                .finish()
+                .context("flush snapshot archive")?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the manifest and every source file, returning the underlying compressor.
+fn append_entries<W: Write>(
+    mut builder: tar::Builder<W>,
+    manifest: &[u8],
+    sources: &[(ProviderType, PathBuf, Vec<PathBuf>)],
+) -> Result<W> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(OffsetDateTime::now_utc().unix_timestamp().try_into().unwrap_or(0));
+    header.set_cksum();
+    builder
+        .append_data(&mut header, MANIFEST_NAME, manifest)
+        .context("write snapshot manifest")?;
+
+    for (provider, dir, files) in sources {
+        for relative in files {
+            let source = dir.join(relative);
+            builder
+                .append_path_with_name(&source, Path::new(provider.slug()).join(relative))
+                .with_context(|| format!("archive {}", source.display()))?;
+        }
+    }
+
+    builder.into_inner().context("finish snapshot archive")
+}
+
+fn read_archive(
+    locations: &CacheLocations,
+    archive: &Path,
+    options: &SnapshotOptions,
+) -> Result<SnapshotManifest> {
+    let file = File::open(archive).with_context(|| format!("open snapshot {}", archive.display()))?;
+    let mut file = BufReader::new(file);
+    let is_zstd = file.fill_buf().context("read snapshot archive")?.starts_with(&ZSTD_MAGIC);
+    let decoder: Box<dyn Read> = if is_zstd {
+        Box::new(zstd::Decoder::with_buffer(file).context("start zstd stream")?)
+    } else {
+        Box::new(GzDecoder::new(file))
+    };
+    let mut reader = tar::Archive::new(decoder);
+    let mut manifest: Option<SnapshotManifest> = None;
+
+    for entry in reader.entries().context("read snapshot archive")? {
+        let mut entry = entry.context("read snapshot entry")?;
+        let path = entry.path().context("invalid snapshot entry path")?.into_owned();
+
+        if path == Path::new(MANIFEST_NAME) {
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).context("read snapshot manifest")?;
+            let parsed: SnapshotManifest = serde_json::from_slice(&bytes).context("parse snapshot manifest")?;
+            if parsed.schema_version > SNAPSHOT_SCHEMA_VERSION {
+                bail!(
+                    "snapshot schema version {} is newer than supported ({SNAPSHOT_SCHEMA_VERSION})",
+                    parsed.schema_version
+                );
+            }
+            manifest = Some(parsed);
+            continue;
+        }
+
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let Some((provider, relative)) = split_entry_path(&path) else {
+            bail!("unexpected snapshot entry {}", path.display());
+        };
+        if !options.includes(provider) || !options.includes_file(provider, &relative) {
+            continue;
+        }
+        let Some(dir) = locations.dir_for(provider) else {
+            continue;
+        };
+        let target = dir.join(relative);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+        }
+        entry
+            .unpack(&target)
+            .with_context(|| format!("unpack {}", target.display()))?;
+    }
+
+    manifest.with_context(|| format!("{} is not a docs-mcp snapshot (missing manifest)", archive.display()))
+}
+
+/// Split `<provider-slug>/<relative path>`, rejecting anything that could escape the cache dir.
+fn split_entry_path(path: &Path) -> Option<(ProviderType, PathBuf)> {
+    let mut components = path.components();
+    let provider = match components.next()? {
+        Component::Normal(slug) => ProviderType::from_slug(slug.to_str()?)?,
+        _ => return None,
+    };
+    let relative: PathBuf = components
+        .map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    (!relative.as_os_str().is_empty()).then_some((provider, relative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::cache::disk::LOGS_DIR;
+    use docs_mcp_client::cache::DiskCache;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    fn locations(root: &Path) -> CacheLocations {
+        CacheLocations {
+            apple: root.join("apple"),
+            providers: Some(root.join("providers")),
+        }
+    }
+
+    #[tokio::test]
+    async fn round_trips_provider_caches() {
+        let source = tempdir().expect("tempdir");
+        let target = tempdir().expect("tempdir");
+        let archive = source.path().join("out/snapshot.tar.gz");
+
+        let from = locations(source.path());
+        DiskCache::new(&from.apple)
+            .store("SwiftUI.json", json!({"title": "SwiftUI"}))
+            .await
+            .unwrap();
+        DiskCache::new(from.dir_for(ProviderType::Rust).unwrap())
+            .store("crates/tokio.json", json!({"name": "tokio"}))
+            .await
+            .unwrap();
+        std::fs::create_dir_all(from.apple.join(LOGS_DIR)).unwrap();
+        std::fs::write(from.apple.join(LOGS_DIR).join("telemetry.jsonl"), "{}\n").unwrap();
+
+        let exported = export_snapshot(&from, &archive).await.unwrap();
+        assert_eq!(exported.providers.len(), 2);
+
+        let to = locations(target.path());
+        let imported = import_snapshot(&to, &archive).await.unwrap();
+        assert_eq!(imported.providers.len(), 2);
+        assert!(to.apple.join("SwiftUI.json").exists());
+        assert!(to.dir_for(ProviderType::Rust).unwrap().join("crates/tokio.json").exists());
+        assert!(!to.apple.join(LOGS_DIR).exists(), "logs stay out of snapshots");
+    }
+
+    #[tokio::test]
+    async fn zstd_bundles_keep_only_selected_frameworks() {
+        let source = tempdir().expect("tempdir");
+        let target = tempdir().expect("tempdir");
+        let archive = source.path().join("bundle.tar.zst");
+
+        let from = locations(source.path());
+        let apple = DiskCache::new(&from.apple);
+        for key in [
+            "technologies.json",
+            "SwiftUI.json",
+            "documentation__swiftui__list.json",
+            "UIKit.json",
+        ] {
+            apple.store(key, json!({"key": key})).await.unwrap();
+        }
+        DiskCache::new(from.dir_for(ProviderType::Rust).unwrap())
+            .store("crates/tokio.json", json!({"name": "tokio"}))
+            .await
+            .unwrap();
+
+        let options = SnapshotOptions {
+            providers: vec![ProviderType::Apple],
+            frameworks: vec!["swiftui".to_string()],
+        };
+        let exported = export_snapshot_with(&from, &archive, &options).await.unwrap();
+        assert_eq!(exported.providers.len(), 1);
+        assert_eq!(exported.providers[0].usage.files, 3);
+
+        let bytes = std::fs::read(&archive).unwrap();
+        assert!(bytes.starts_with(&ZSTD_MAGIC));
+
+        let to = locations(target.path());
+        import_snapshot(&to, &archive).await.unwrap();
+        assert!(to.apple.join("technologies.json").exists());
+        assert!(to.apple.join("documentation__swiftui__list.json").exists());
+        assert!(!to.apple.join("UIKit.json").exists());
+        assert!(!to.dir_for(ProviderType::Rust).unwrap().exists());
+    }
+
+    #[tokio::test]
+    async fn rejects_archives_without_manifest() {
+        let dir = tempdir().expect("tempdir");
+        let archive = dir.path().join("empty.tar.gz");
+        let file = File::create(&archive).unwrap();
+        tar::Builder::new(GzEncoder::new(file, GzLevel::default()))
+            .into_inner()
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        let err = import_snapshot(&locations(dir.path()), &archive).await.unwrap_err();
+        assert!(err.to_string().contains("missing manifest"));
+    }
+
+    #[test]
+    fn entry_paths_cannot_escape() {
+        assert!(split_entry_path(Path::new("rust/../../etc/passwd")).is_none());
+        assert!(split_entry_path(Path::new("unknown/file.json")).is_none());
+        assert_eq!(
+            split_entry_path(Path::new("mdn/a/b.json")),
+            Some((ProviderType::Mdn, PathBuf::from("a/b.json")))
+        );
+    }
+}
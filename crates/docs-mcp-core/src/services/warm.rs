@@ -0,0 +1,223 @@
+use anyhow::{Context, Result};
+use futures::stream::{self, StreamExt};
+use multi_provider_client::types::ProviderType;
+use serde::Serialize;
+
+use crate::services::normalize_reference_link;
+use crate::state::AppContext;
+
+/// Default number of top-level symbol documents fetched per framework/crate.
+pub const DEFAULT_MAX_DOCUMENTS: usize = 50;
+/// Parallel document downloads per target.
+const WARM_CONCURRENCY: usize = 4;
+
+/// What `warm_caches` should download.
+#[derive(Debug, Clone, Default)]
+pub struct WarmOptions {
+    /// Apple frameworks by title or identifier (e.g. `swiftui`).
+    pub frameworks: Vec<String>,
+    /// Rust crates (e.g. `tokio`).
+    pub crates: Vec<String>,
+    /// Top-level symbol documents to prefetch per target.
+    pub max_documents: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmTarget {
+    pub provider: ProviderType,
+    pub name: String,
+    pub documents: usize,
+    pub failures: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmReport {
+    pub targets: Vec<WarmTarget>,
+}
+
+impl WarmReport {
+    pub fn summary_lines(&self) -> Vec<String> {
+        if self.targets.is_empty() {
+            return vec!["Nothing to warm. Pass --framework and/or --crate.".to_string()];
+        }
+        self.targets
+            .iter()
+            .map(|target| match &target.error {
+                Some(error) => format!("- {} {}: failed ({error})", target.provider.name(), target.name),
+                None => format!(
+                    "- {} {}: cached index + {} document(s){}",
+                    target.provider.name(),
+                    target.name,
+                    target.documents,
+                    if target.failures > 0 {
+                        format!(", {} failed", target.failures)
+                    } else {
+                        String::new()
+                    }
+                ),
+            })
+            .collect()
+    }
+}
+
+/// Pre-download framework/crate indexes and their top-level documents into the disk cache.
+pub async fn warm_caches(context: &AppContext, options: &WarmOptions) -> Result<WarmReport> {
+    let mut report = WarmReport::default();
+
+    for framework in &options.frameworks {
+        let target = match warm_apple_framework(context, framework, options.max_documents).await {
+            Ok((documents, failures)) => warm_target(ProviderType::Apple, framework, documents, failures),
+            Err(error) => failed_target(ProviderType::Apple, framework, &error),
+        };
+        report.targets.push(target);
+    }
+
+    for crate_name in &options.crates {
+        let target = match warm_rust_crate(context, crate_name, options.max_documents).await {
+            Ok((documents, failures)) => warm_target(ProviderType::Rust, crate_name, documents, failures),
+            Err(error) => failed_target(ProviderType::Rust, crate_name, &error),
+        };
+        report.targets.push(target);
+    }
+
+    Ok(report)
+}
+
+async fn warm_apple_framework(
+    context: &AppContext,
+    framework: &str,
+    max_documents: usize,
+) -> Result<(usize, usize)> {
+    let wanted = framework.to_lowercase();
+    let technologies = context.client.get_technologies().await?;
+    let technology = technologies
+        .values()
+        .find(|tech| {
+            tech.title.to_lowercase() == wanted
+                || tech
+                    .identifier
+                    .rsplit('/')
+                    .next()
+                    .is_some_and(|id| id.to_lowercase() == wanted)
+        })
+        .with_context(|| format!("Unknown Apple framework '{framework}'"))?;
+
+    let identifier = technology
+        .identifier
+        .rsplit('/')
+        .next()
+        .context("Invalid technology identifier")?;
+    let data = context.client.get_framework(identifier).await?;
+
+    let mut paths: Vec<String> = Vec::new();
+    for id in data.topic_sections.iter().flat_map(|section| section.identifiers.iter()) {
+        let path = normalize_reference_link(id);
+        if path.starts_with("documentation/") && !paths.contains(&path) {
+            paths.push(path);
+        }
+        if paths.len() >= max_documents {
+            break;
+        }
+    }
+
+    Ok(fetch_all(paths, |path| async move { context.client.load_document(&path).await.map(|_| ()) }).await)
+}
+
+async fn warm_rust_crate(
+    context: &AppContext,
+    crate_name: &str,
+    max_documents: usize,
+) -> Result<(usize, usize)> {
+    let category = context.providers.rust.get_category(crate_name).await?;
+    let paths: Vec<String> = category
+        .items
+        .into_iter()
+        .take(max_documents)
+        .map(|item| item.path)
+        .collect();
+
+    Ok(fetch_all(paths, |path| async move { context.providers.rust.get_item(&path).await.map(|_| ()) }).await)
+}
+
+/// Run `fetch` over `paths` with bounded concurrency, returning (successes, failures).
+async fn fetch_all<F, Fut>(paths: Vec<String>, fetch: F) -> (usize, usize)
+where
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    let outcomes: Vec<bool> = stream::iter(paths)
+        .map(|path| {
+            let fut = fetch(path.clone());
+            async move {
+                match fut.await {
+                    Ok(()) => true,
+                    Err(error) => {
+                        tracing::debug!(path = %path, error = %error, "warm fetch failed");
+                        false
+                    }
+                }
+            }
+        })
+        .buffer_unordered(WARM_CONCURRENCY)
+        .collect()
+        .await;
+
+    let documents = outcomes.iter().filter(|ok| **ok).count();
+    (documents, outcomes.len() - documents)
+}
+
+fn warm_target(provider: ProviderType, name: &str, documents: usize, failures: usize) -> WarmTarget {
+    WarmTarget {
+        provider,
+        name: name.to_string(),
+        documents,
+        failures,
+        error: None,
+    }
+}
+
+fn failed_target(provider: ProviderType, name: &str, error: &anyhow::Error) -> WarmTarget {
+    WarmTarget {
+        provider,
+        name: name.to_string(),
+        documents: 0,
+        failures: 0,
+        error: Some(format!("{error:#}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn fetch_all_counts_successes_and_failures() {
+        let paths = vec!["ok/a".to_string(), "bad/b".to_string(), "ok/c".to_string()];
+        let (documents, failures) = fetch_all(paths, |path| async move {
+            if path.starts_with("ok") {
+                Ok(())
+            } else {
+                anyhow::bail!("boom")
+            }
+        })
+        .await;
+        assert_eq!((documents, failures), (2, 1));
+    }
+
+    #[test]
+    fn summary_reports_failures() {
+        let report = WarmReport {
+            targets: vec![
+                warm_target(ProviderType::Rust, "tokio", 10, 2),
+                failed_target(ProviderType::Apple, "nope", &anyhow::anyhow!("Unknown Apple framework 'nope'")),
+            ],
+        };
+        let lines = report.summary_lines();
+        assert_eq!(lines[0], "- Rust tokio: cached index + 10 document(s), 2 failed");
+        assert!(lines[1].contains("failed (Unknown Apple framework 'nope')"));
+    }
+}
@@ -1,10 +1,11 @@
 use std::{
     collections::{HashMap, HashSet},
     sync::Arc,
+    time::{Duration, Instant},
 };
 
 use docs_mcp_client::{
-    types::{FrameworkData, ReferenceData, SymbolData, Technology},
+    types::{available_on, FrameworkData, ReferenceData, SymbolData, Technology},
     AppleDocsClient,
 };
 use futures::future::BoxFuture;
@@ -12,32 +13,107 @@ use multi_provider_client::{
     types::{ProviderType, UnifiedTechnology},
     ProviderClients,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use time::OffsetDateTime;
 use tokio::sync::{Mutex, RwLock};
+use tracing::warn;
 
 use crate::services::design_guidance::DesignSection;
+use crate::services::documents::DocumentStore;
+use crate::services::jsonl_log::{JsonlLog, REQUEST_LOG_FILE, TELEMETRY_LOG_FILE};
+use crate::services::session_store::{SessionSnapshot, SessionStore};
+use crate::ServerConfig;
 
 #[derive(Clone)]
 pub struct AppContext {
     pub client: Arc<AppleDocsClient>,
     pub providers: Arc<ProviderClients>,
+    /// State of the session this context serves; see [`AppContext::for_session`].
     pub state: Arc<ServerState>,
+    /// Per-session state for transports that serve several MCP clients at once.
+    pub sessions: Arc<SessionRegistry>,
+    /// Full documents linked from this session's truncated tool output, readable as MCP
+    /// resources.
+    pub documents: Arc<DocumentStore>,
     pub tools: Arc<ToolRegistry>,
+    pub config: Arc<ServerConfig>,
+    /// On-disk telemetry log; `None` when disabled.
+    pub telemetry_log: Option<Arc<JsonlLog>>,
+    /// On-disk JSON-RPC request log; `None` unless enabled.
+    pub request_log: Option<Arc<JsonlLog>>,
+    /// Where the stdio session's state is persisted; `None` when disabled.
+    pub session_store: Option<Arc<SessionStore>>,
 }
 
 impl AppContext {
     pub fn new(client: AppleDocsClient) -> Self {
+        Self::with_config(client, ServerConfig::default())
+    }
+
+    pub fn with_config(client: AppleDocsClient, config: ServerConfig) -> Self {
+        let open = |file_name, settings| Arc::new(JsonlLog::in_cache_dir(client.cache_dir(), file_name, settings));
+        let telemetry_log = config.telemetry_log.map(|settings| open(TELEMETRY_LOG_FILE, settings));
+        let request_log = config.request_log.map(|settings| open(REQUEST_LOG_FILE, settings));
+        let session_store = config.persist_session.then(|| {
+            let workspace = std::env::current_dir().unwrap_or_default();
+            Arc::new(SessionStore::in_cache_dir(client.cache_dir(), &workspace))
+        });
         Self {
             client: Arc::new(client),
             providers: Arc::new(ProviderClients::new()),
             state: Arc::new(ServerState::default()),
+            sessions: Arc::new(SessionRegistry::default()),
+            documents: Arc::new(DocumentStore::default()),
             tools: Arc::new(ToolRegistry::default()),
+            config: Arc::new(config),
+            telemetry_log,
+            request_log,
+            session_store,
         }
     }
 
+    /// Open a new MCP session with empty state and return its id; `None` when
+    /// [`SessionRegistry::MAX_SESSIONS`] sessions are still in use.
+    pub fn start_session(&self) -> Option<String> {
+        self.sessions.create()
+    }
+
+    /// This context with the [`ServerState`] and documents of MCP session `session_id`, so one
+    /// client's active provider, technology and linked documents never leak into another's.
+    /// `None` when the id was not issued by [`AppContext::start_session`] or the session has
+    /// ended.
+    pub fn for_session(&self, session_id: &str) -> Option<Self> {
+        let (state, documents) = self.sessions.session(session_id)?;
+        Some(Self {
+            state,
+            documents,
+            ..self.clone()
+        })
+    }
+
+    /// This context with empty state of its own, for work that must not change (or race on)
+    /// the caller's active provider and technology. Documents it links still belong to the
+    /// caller's session.
+    pub fn detached(&self) -> Self {
+        Self {
+            state: Arc::new(ServerState::default()),
+            ..self.clone()
+        }
+    }
+
+    /// Drop the state of `session_id`; returns whether it existed.
+    pub fn end_session(&self, session_id: &str) -> bool {
+        self.sessions.remove(session_id)
+    }
+
+    /// Keep `entry` in memory for this session and append it to the telemetry log.
     pub async fn record_telemetry(&self, entry: TelemetryEntry) {
+        if let Some(log) = &self.telemetry_log {
+            if let Err(error) = log.append(&entry).await {
+                warn!(target: "docs_mcp_core", error = %error, "failed to write telemetry log");
+            }
+        }
         let mut guard = self.state.telemetry_log.lock().await;
         guard.push(entry);
         const MAX_ENTRIES: usize = 200;
@@ -51,12 +127,112 @@ impl AppContext {
         self.state.telemetry_log.lock().await.clone()
     }
 
+    /// Save this context's session state, if persistence is enabled.
+    pub async fn persist_session(&self) {
+        if let Some(store) = &self.session_store {
+            if let Err(error) = store.save(&SessionSnapshot::capture(&self.state).await).await {
+                warn!(target: "docs_mcp_core", error = %error, "failed to persist session state");
+            }
+        }
+    }
+
+    /// Append `entry` to the request log, if it is enabled.
+    pub async fn record_request(&self, entry: &RequestLogEntry) {
+        if let Some(log) = &self.request_log {
+            if let Err(error) = log.append(entry).await {
+                warn!(target: "docs_mcp_core", error = %error, "failed to write request log");
+            }
+        }
+    }
+
     /// Get current cache statistics from the client
     pub fn cache_stats(&self) -> docs_mcp_client::CombinedCacheStats {
         self.client.cache_stats()
     }
 }
 
+/// [`ServerState`] per MCP session id.
+///
+/// The stdio transport serves a single client and uses [`AppContext::state`] directly.
+#[derive(Default)]
+pub struct SessionRegistry {
+    inner: std::sync::Mutex<HashMap<String, SessionSlot>>,
+}
+
+struct SessionSlot {
+    state: Arc<ServerState>,
+    documents: Arc<DocumentStore>,
+    last_used: Instant,
+}
+
+impl SessionSlot {
+    /// Idle past [`SessionRegistry::IDLE_TIMEOUT`] with no request holding its state.
+    fn is_idle(&self, now: Instant) -> bool {
+        now.duration_since(self.last_used) >= SessionRegistry::IDLE_TIMEOUT && Arc::strong_count(&self.state) == 1
+    }
+}
+
+impl SessionRegistry {
+    /// Sessions kept at once; new sessions are refused while this many are in use.
+    pub const MAX_SESSIONS: usize = 256;
+    /// How long a session goes without requests before a new session may replace it.
+    pub const IDLE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+    /// Register a session with empty state under a fresh random id and return the id.
+    ///
+    /// When the registry is full, idle sessions are dropped to make room; `None` when none is
+    /// idle, so a burst of new clients can never end sessions that are still in use.
+    pub fn create(&self) -> Option<String> {
+        self.create_at(Instant::now())
+    }
+
+    fn create_at(&self, now: Instant) -> Option<String> {
+        let mut sessions = self.inner.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if sessions.len() >= Self::MAX_SESSIONS {
+            sessions.retain(|_, slot| !slot.is_idle(now));
+            if sessions.len() >= Self::MAX_SESSIONS {
+                return None;
+            }
+        }
+        let session_id = new_session_id();
+        sessions.insert(
+            session_id.clone(),
+            SessionSlot {
+                state: Arc::new(ServerState::default()),
+                documents: Arc::new(DocumentStore::default()),
+                last_used: now,
+            },
+        );
+        Some(session_id)
+    }
+
+    /// The state and documents of `session_id`; `None` unless it came from
+    /// [`SessionRegistry::create`] and is still live.
+    pub fn session(&self, session_id: &str) -> Option<(Arc<ServerState>, Arc<DocumentStore>)> {
+        let mut sessions = self.inner.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let slot = sessions.get_mut(session_id)?;
+        slot.last_used = Instant::now();
+        Some((slot.state.clone(), slot.documents.clone()))
+    }
+
+    /// Forget `session_id`; returns whether it existed.
+    pub fn remove(&self, session_id: &str) -> bool {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(session_id)
+            .is_some()
+    }
+}
+
+/// An unguessable session id: 128 bits from the operating system's CSPRNG, hex encoded.
+fn new_session_id() -> String {
+    let mut bytes = [0u8; 16];
+    // The OS generator only fails on platforms that cannot serve sessions safely anyway
+    getrandom::getrandom(&mut bytes).expect("operating system randomness");
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
 /// Multi-provider aware context for unified documentation access
 #[derive(Clone)]
 pub struct MultiProviderContext {
@@ -174,15 +350,48 @@ pub struct ServerState {
     /// Pre-cached design guidance for the active technology
     /// Maps design guidance slug (e.g., "design/human-interface-guidelines/buttons") to sections
     pub design_guidance_cache: RwLock<HashMap<String, Arc<DesignSection>>>,
+    /// Set by `choose_technology`: `query` keeps the active technology instead of
+    /// auto-detecting one per query.
+    pub technology_pinned: RwLock<bool>,
+    /// MCP protocol version agreed on at `initialize`; `None` before it.
+    pub protocol_version: RwLock<Option<String>>,
 }
 
-#[derive(Clone)]
+impl ServerState {
+    /// Forget the active provider and technology along with everything derived from them,
+    /// as if the session had just started. Cross-framework indexes and telemetry are kept.
+    pub async fn reset(&self) {
+        *self.active_provider.write().await = ProviderType::default();
+        self.active_technology.write().await.take();
+        self.active_unified_technology.write().await.take();
+        self.framework_cache.write().await.take();
+        self.framework_index.write().await.take();
+        self.expanded_identifiers.lock().await.clear();
+        self.last_symbol.write().await.take();
+        self.last_discovery.write().await.take();
+        self.design_guidance_cache.write().await.clear();
+        *self.technology_pinned.write().await = false;
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FrameworkIndexEntry {
     pub id: String,
     pub tokens: Vec<String>,
     pub reference: ReferenceData,
 }
 
+impl FrameworkIndexEntry {
+    /// Whether the symbol is listed for `platform` (e.g. `visionOS`); symbols without
+    /// availability data are kept.
+    pub fn available_on(&self, platform: &str) -> bool {
+        self.reference
+            .platforms
+            .as_deref()
+            .map_or(true, |platforms| available_on(platforms, platform))
+    }
+}
+
 #[derive(Clone)]
 pub struct DiscoverySnapshot {
     pub query: Option<String>,
@@ -199,7 +408,7 @@ pub struct SearchQueryLog {
     pub timestamp: Option<OffsetDateTime>,
 }
 
-#[derive(Clone, Serialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TelemetryEntry {
     pub tool: String,
     #[serde(with = "time::serde::rfc3339")]
@@ -212,6 +421,24 @@ pub struct TelemetryEntry {
     pub error: Option<String>,
 }
 
+/// One JSON-RPC exchange in the request log. Arguments are anonymized before logging.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestLogEntry {
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Value>,
+    pub latency_ms: u64,
+    /// Size of the serialized response in bytes; `0` for notifications.
+    pub result_bytes: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+}
+
 #[derive(Clone, Serialize)]
 pub struct ToolDefinition {
     pub name: String,
@@ -240,7 +467,33 @@ pub struct ToolResponse {
 #[derive(Clone, Serialize)]
 pub struct ToolContent {
     pub r#type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub text: String,
+    /// Target of a `resource_link` block
+    #[serde(flatten)]
+    pub resource: Option<ResourceLink>,
+}
+
+impl ToolContent {
+    /// A `resource_link` block pointing at a resource clients can read on demand.
+    pub fn resource_link(link: ResourceLink) -> Self {
+        Self {
+            r#type: "resource_link".to_string(),
+            text: String::new(),
+            resource: Some(link),
+        }
+    }
+}
+
+/// An MCP resource as listed by `resources/list` and linked from tool output.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceLink {
+    pub uri: String,
+    pub name: String,
+    #[serde(rename = "mimeType")]
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 pub type ToolFuture = BoxFuture<'static, anyhow::Result<ToolResponse>>;
@@ -285,3 +538,60 @@ impl ToolResponse {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::AppleDocsClient;
+
+    #[tokio::test]
+    async fn sessions_do_not_share_state() {
+        let context = AppContext::new(AppleDocsClient::new());
+        let (a, b) = (context.start_session().unwrap(), context.start_session().unwrap());
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 32);
+        let first = context.for_session(&a).unwrap();
+        let second = context.for_session(&b).unwrap();
+
+        *first.state.active_provider.write().await = ProviderType::Rust;
+        first.state.expanded_identifiers.lock().await.insert("doc://x".to_string());
+
+        assert_eq!(*second.state.active_provider.read().await, ProviderType::Apple);
+        assert!(second.state.expanded_identifiers.lock().await.is_empty());
+        assert_eq!(*context.state.active_provider.read().await, ProviderType::Apple);
+        assert_eq!(
+            *context.for_session(&a).unwrap().state.active_provider.read().await,
+            ProviderType::Rust
+        );
+
+        assert!(context.end_session(&a));
+        assert!(!context.end_session(&a));
+        assert!(context.for_session(&a).is_none());
+        assert!(context.for_session("made-up").is_none());
+    }
+
+    #[test]
+    fn full_registry_only_replaces_idle_sessions() {
+        let registry = SessionRegistry::default();
+        let ids: Vec<String> = (0..SessionRegistry::MAX_SESSIONS)
+            .map(|_| registry.create().unwrap())
+            .collect();
+        assert!(registry.create().is_none());
+
+        // Idle sessions make room, unless a request still holds their state
+        let (held, _) = registry.session(&ids[1]).unwrap();
+        let later = Instant::now() + SessionRegistry::IDLE_TIMEOUT;
+        {
+            let mut sessions = registry.inner.lock().unwrap();
+            for id in &ids[2..] {
+                sessions.get_mut(id).unwrap().last_used = later;
+            }
+        }
+        assert!(registry.create_at(later).is_some());
+        assert!(!registry.remove(&ids[0]));
+        assert!(registry.create_at(later).is_none());
+        drop(held);
+        assert!(registry.create_at(later).is_some());
+        assert!(!registry.remove(&ids[1]));
+    }
+}
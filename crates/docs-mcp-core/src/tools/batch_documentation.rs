@@ -1,32 +1,39 @@
 //! Batch documentation tool optimized for programmatic calling.
 //!
 //! This tool fetches documentation for multiple symbols in a single call,
-//! returning compact summaries suitable for aggregation in code.
+//! e.g. every related API listed by a prior `query`, returning summaries,
+//! declarations and full content suitable for aggregation in code.
 
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use futures::future::join_all;
+use multi_provider_client::telegram::{MINI_APPS_TECHNOLOGY, TDLIB_TECHNOLOGY};
 use multi_provider_client::types::ProviderType;
 use serde::Deserialize;
 use serde_json::json;
 
 use crate::{
     state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
-    tools::{parse_args, text_response, wrap_handler},
+    tools::{parse_args, query, text_response, wrap_handler},
 };
 
-/// Code execution caller identifier for programmatic tool calling.
-const CODE_EXECUTION_CALLER: &str = "code_execution_20250825";
-
 /// Maximum number of paths allowed per batch request.
 const MAX_BATCH_SIZE: usize = 10;
 
+/// Every field a result can carry, also the default selection.
+const ALL_FIELDS: [&str; 6] = ["title", "summary", "platforms", "kind", "declaration", "content"];
+
 #[derive(Debug, Deserialize)]
 struct Args {
     /// Array of symbol paths to fetch (max 10)
     paths: Vec<String>,
     /// Which fields to include in response
     fields: Option<Vec<String>>,
+    /// Provider slug; defaults to the provider of the last query
+    provider: Option<String>,
+    /// Framework or crate the paths belong to; defaults to the last query's technology
+    technology: Option<String>,
 }
 
 /// Result for a single symbol in the batch
@@ -42,6 +49,10 @@ struct BatchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     kind: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    declaration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
 }
 
@@ -49,10 +60,10 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
     (
         ToolDefinition {
             name: "batch_documentation".to_string(),
-            description: "Retrieve documentation for multiple symbols in a single call. \
-                         Returns compact summaries optimized for batch processing. \
-                         Use when comparing multiple APIs or gathering information across symbols. \
-                         Designed for programmatic orchestration - call once, process results in code."
+            description: "Retrieve documentation for multiple symbols in a single call, e.g. the \
+                         related APIs listed by a prior `query`. Paths resolve against the provider and \
+                         technology of the last query unless `provider`/`technology` are given. \
+                         Returns summaries, declarations and full content as JSON for batch processing."
                 .to_string(),
             input_schema: json!({
                 "type": "object",
@@ -68,11 +79,20 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                         "type": "array",
                         "items": {
                             "type": "string",
-                            "enum": ["title", "summary", "platforms", "kind"]
+                            "enum": ALL_FIELDS
                         },
-                        "description": "Which fields to include (default: all). Options: title, summary, platforms, kind"
+                        "description": "Which fields to include (default: all). Options: title, summary, platforms, kind, declaration, content"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "description": "Provider of the paths (apple, rust, telegram, ton, cocoon); defaults to the provider of the last query"
+                    },
+                    "technology": {
+                        "type": "string",
+                        "description": "Framework or crate the paths belong to (e.g. 'swiftui', 'tokio'); defaults to the technology of the last query"
                     }
-                }
+                },
+                "additionalProperties": false
             }),
             input_examples: Some(vec![
                 // Simple: fetch multiple symbols
@@ -81,9 +101,10 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"paths": ["NavigationStack", "TabView", "NavigationSplitView"], "fields": ["summary", "platforms"]}),
                 // Comparing similar APIs
                 json!({"paths": ["List", "LazyVStack", "ScrollView"], "fields": ["summary", "kind"]}),
+                // Explicit provider and technology, independent of earlier queries
+                json!({"paths": ["tokio::spawn", "tokio::select"], "provider": "rust", "technology": "tokio"}),
             ]),
-            // Primary use case is programmatic calling - designed for code orchestration
-            allowed_callers: Some(vec![CODE_EXECUTION_CALLER.to_string()]),
+            allowed_callers: None,
         },
         wrap_handler(|context, value| async move {
             let args: Args = parse_args(value)?;
@@ -105,83 +126,59 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
         );
     }
 
-    // Get active provider
-    let provider = *context.state.active_provider.read().await;
-
-    // Get active technology based on provider
-    let active = match provider {
-        ProviderType::Apple => {
-            context
-                .state
-                .active_technology
-                .read()
-                .await
-                .clone()
-                .context("No technology selected. Use `choose_technology` first.")?
-        }
-        _ => {
-            // For non-Apple providers, use active_unified_technology
-            let unified = context
-                .state
-                .active_unified_technology
-                .read()
-                .await
-                .clone()
-                .context("No technology selected. Use `choose_technology` first.")?;
-
-            docs_mcp_client::types::Technology {
-                identifier: unified.identifier,
-                title: unified.title,
-                r#abstract: vec![],
-                kind: String::new(),
-                role: String::new(),
-                url: String::new(),
-            }
-        }
+    let provider = match &args.provider {
+        Some(name) => ProviderType::from_slug(name).ok_or_else(|| anyhow!("unknown provider '{name}'"))?,
+        None => *context.state.active_provider.read().await,
     };
-
+    let technology = resolve_technology(&context, provider, args.technology.as_deref()).await;
     // Determine which fields to include
-    let fields = args.fields.unwrap_or_else(|| {
-        vec![
-            "title".to_string(),
-            "summary".to_string(),
-            "platforms".to_string(),
-            "kind".to_string(),
-        ]
+    let fields = args
+        .fields
+        .unwrap_or_else(|| ALL_FIELDS.iter().map(|field| field.to_string()).collect());
+    if let Some(unknown) = fields.iter().find(|field| !ALL_FIELDS.contains(&field.as_str())) {
+        anyhow::bail!("unknown field '{unknown}'; expected one of {}", ALL_FIELDS.join(", "));
+    }
+    let include = |name: &str| fields.iter().any(|field| field == name);
+    let detailed = include("declaration") || include("content");
+
+    // Fetch documentation for every path concurrently; the batch is small
+    let fetches = args.paths.iter().map(|path| {
+        let context = &context;
+        let technology = technology.as_ref();
+        async move {
+            let identifier = technology.map(|technology| technology.identifier.as_str());
+            match provider {
+                ProviderType::Apple => fetch_apple_info(context, identifier, path).await,
+                ProviderType::Telegram => fetch_telegram_info(context, identifier, path).await,
+                ProviderType::TON => fetch_ton_info(context, path).await,
+                ProviderType::Cocoon => fetch_cocoon_info(context, identifier, path).await,
+                ProviderType::Rust => fetch_rust_info(context, identifier, path, detailed).await,
+                // MDN, WebFrameworks, Mlx, HuggingFace, QuickNode, ClaudeAgentSdk, Vertcoin, Cuda, and Solana not supported in batch documentation
+                ProviderType::Mdn | ProviderType::WebFrameworks | ProviderType::Mlx | ProviderType::HuggingFace
+                | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Bitcoind | ProviderType::Cuda
+                | ProviderType::Solana => {
+                    Err(anyhow!("Provider {} does not support batch documentation", provider.name()))
+                }
+            }
+        }
     });
-    let include_title = fields.iter().any(|f| f == "title");
-    let include_summary = fields.iter().any(|f| f == "summary");
-    let include_platforms = fields.iter().any(|f| f == "platforms");
-    let include_kind = fields.iter().any(|f| f == "kind");
+    let fetched = join_all(fetches).await;
 
-    // Fetch documentation for each path
     let mut results: Vec<BatchResult> = Vec::with_capacity(args.paths.len());
     let mut success_count = 0;
     let mut error_count = 0;
-
-    for path in &args.paths {
-        let fetch_result = match provider {
-            ProviderType::Apple => fetch_apple_info(&context, &active.identifier, path).await,
-            ProviderType::Telegram => fetch_telegram_info(&context, path).await,
-            ProviderType::TON => fetch_ton_info(&context, path).await,
-            ProviderType::Cocoon => fetch_cocoon_info(&context, &active.identifier, path).await,
-            ProviderType::Rust => fetch_rust_info(&context, &active.identifier, path).await,
-            // MDN, WebFrameworks, Mlx, HuggingFace, QuickNode, ClaudeAgentSdk, Vertcoin, and Cuda not supported in batch documentation
-            ProviderType::Mdn | ProviderType::WebFrameworks | ProviderType::Mlx | ProviderType::HuggingFace
-            | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Vertcoin | ProviderType::Cuda => {
-                Err(anyhow::anyhow!("Provider {} does not support batch documentation", provider.name()))
-            }
-        };
-
+    for (path, fetch_result) in args.paths.iter().zip(fetched) {
         match fetch_result {
             Ok(info) => {
                 success_count += 1;
                 results.push(BatchResult {
                     path: path.clone(),
-                    title: if include_title { info.title } else { None },
-                    summary: if include_summary { info.summary } else { None },
-                    platforms: if include_platforms { info.platforms } else { None },
-                    kind: if include_kind { info.kind } else { None },
+                    title: info.title.filter(|_| include("title")),
+                    summary: info.summary.filter(|_| include("summary")),
+                    platforms: info.platforms.filter(|_| include("platforms")),
+                    kind: info.kind.filter(|_| include("kind")),
+                    declaration: info.declaration.filter(|_| include("declaration")),
+                    content: info.content.filter(|_| include("content")),
                     error: None,
                 });
             }
@@ -193,7 +190,9 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
                     summary: None,
                     platforms: None,
                     kind: None,
-                    error: Some(e.to_string()),
+                    declaration: None,
+                    content: None,
+                    error: Some(format!("{e:#}")),
                 });
             }
         }
@@ -201,7 +200,8 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
 
     // Build response as JSON for easy programmatic parsing
     let response_json = json!({
-        "technology": active.title,
+        "provider": provider.slug(),
+        "technology": technology.as_ref().map(|technology| technology.title.clone()),
         "requested": args.paths.len(),
         "succeeded": success_count,
         "failed": error_count,
@@ -218,24 +218,78 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
     Ok(text_response([serde_json::to_string_pretty(&response_json)?]).with_metadata(metadata))
 }
 
-/// Compact symbol information for batch responses
+/// The technology paths are resolved against.
+struct BatchTechnology {
+    identifier: String,
+    title: String,
+}
+
+/// An explicit `technology` argument wins over the one selected by the last query.
+async fn resolve_technology(
+    context: &AppContext,
+    provider: ProviderType,
+    requested: Option<&str>,
+) -> Option<BatchTechnology> {
+    if let Some(name) = requested.map(str::trim).filter(|name| !name.is_empty()) {
+        let identifier = match provider {
+            ProviderType::Apple => format!("doc://com.apple.documentation/documentation/{}", name.to_lowercase()),
+            ProviderType::Rust => format!("rust:{}", name.strip_prefix("rust:").unwrap_or(name)),
+            _ => name.to_string(),
+        };
+        return Some(BatchTechnology {
+            identifier,
+            title: name.to_string(),
+        });
+    }
+
+    if provider == ProviderType::Apple {
+        if let Some(technology) = context.state.active_technology.read().await.clone() {
+            return Some(BatchTechnology {
+                identifier: technology.identifier,
+                title: technology.title,
+            });
+        }
+    }
+    context
+        .state
+        .active_unified_technology
+        .read()
+        .await
+        .clone()
+        .filter(|technology| technology.provider == provider)
+        .map(|technology| BatchTechnology {
+            identifier: technology.identifier,
+            title: technology.title,
+        })
+}
+
+/// Symbol information for batch responses
+#[derive(Default)]
 struct SymbolInfo {
     title: Option<String>,
     summary: Option<String>,
     platforms: Option<Vec<String>>,
     kind: Option<String>,
+    declaration: Option<String>,
+    content: Option<String>,
 }
 
+fn non_empty(text: &str) -> Option<String> {
+    (!text.trim().is_empty()).then(|| text.to_string())
+}
+
+const NO_TECHNOLOGY: &str = "No technology selected. Run `query` first or pass `technology`.";
+
 /// Fetch Apple documentation info for a given path
 async fn fetch_apple_info(
     context: &Arc<AppContext>,
-    technology_id: &str,
+    technology_id: Option<&str>,
     path: &str,
 ) -> Result<SymbolInfo> {
     use docs_mcp_client::types::extract_text;
 
     // Normalize the path
-    let normalized = normalize_apple_path(technology_id, path);
+    let normalized = normalize_apple_path(technology_id, path).context(NO_TECHNOLOGY)?;
 
     // Try to fetch the symbol data
     let symbol = context
@@ -249,7 +303,7 @@ async fn fetch_apple_info(
         .metadata
         .title
         .clone()
-        .or_else(|| path.split('/').last().map(|s| s.to_string()));
+        .or_else(|| path.split('/').next_back().map(|s| s.to_string()));
 
     // Extract summary from abstract
     let summary = {
@@ -283,28 +337,73 @@ async fn fetch_apple_info(
         summary,
         platforms,
         kind,
+        declaration: query::extract_declaration(&symbol),
+        content: query::extract_full_content(&symbol),
     })
 }
 
-/// Fetch Telegram Bot API info for a given path
-async fn fetch_telegram_info(context: &Arc<AppContext>, path: &str) -> Result<SymbolInfo> {
-    let item = context
-        .providers
-        .telegram
-        .get_item(path)
-        .await
-        .with_context(|| format!("Failed to fetch Telegram docs for '{}'", path))?;
+/// Fetch Telegram Bot API, Mini Apps or TDLib info for a given path
+async fn fetch_telegram_info(context: &Arc<AppContext>, identifier: Option<&str>, path: &str) -> Result<SymbolInfo> {
+    let identifier = identifier.unwrap_or_default();
+    let telegram = &context.providers.telegram;
+    let item = match identifier {
+        MINI_APPS_TECHNOLOGY => telegram.get_mini_app_item(path).await,
+        TDLIB_TECHNOLOGY => telegram.get_tdlib_item(path).await,
+        _ => telegram.get_item(path).await,
+    }
+    .with_context(|| format!("Failed to fetch Telegram docs for '{}'", path))?;
+
+    let fields: Vec<String> = item
+        .fields
+        .iter()
+        .map(|field| {
+            let requirement = if field.required { "required" } else { "optional" };
+            format!("- {} ({}, {requirement}): {}", field.name, field.types.join(" | "), field.description)
+        })
+        .collect();
+    let content = if fields.is_empty() {
+        non_empty(&item.description)
+    } else {
+        Some(format!("{}\n\n{}", item.description, fields.join("\n")))
+    };
 
     Ok(SymbolInfo {
         title: Some(item.name.clone()),
         summary: Some(item.description.clone()),
-        platforms: Some(vec!["Telegram Bot API".to_string()]),
+        platforms: Some(vec![query::telegram_platform(identifier).to_string()]),
         kind: Some(item.kind.clone()),
+        declaration: item
+            .returns
+            .as_ref()
+            .map(|returns| format!("{}(…) -> {}", item.name, returns.join(" | "))),
+        content,
     })
 }
 
 /// Fetch TON API info for a given path
 async fn fetch_ton_info(context: &Arc<AppContext>, path: &str) -> Result<SymbolInfo> {
+    if let Some(exit_code) = context.providers.ton.get_exit_code(path) {
+        return Ok(SymbolInfo {
+            title: Some(format!("Exit code {}: {}", exit_code.code, exit_code.name)),
+            summary: Some(exit_code.description.clone()),
+            platforms: Some(vec!["TON".to_string()]),
+            kind: Some(exit_code.phase.clone()),
+            declaration: None,
+            content: Some(exit_code.causes.join("\n")),
+        });
+    }
+
+    if let Some(schema) = context.providers.ton.get_tlb_schema(path) {
+        return Ok(SymbolInfo {
+            title: Some(schema.name.clone()),
+            summary: Some(schema.description.clone()),
+            platforms: Some(vec!["TON TL-B".to_string()]),
+            kind: Some("TL-B schema".to_string()),
+            declaration: Some(schema.tlb.clone()),
+            content: Some(schema.standard.clone()),
+        });
+    }
+
     let endpoint = context
         .providers
         .ton
@@ -317,37 +416,38 @@ async fn fetch_ton_info(context: &Arc<AppContext>, path: &str) -> Result<SymbolI
         summary: endpoint.summary.clone().or(endpoint.description.clone()),
         platforms: Some(vec!["TON API".to_string()]),
         kind: Some(format!("{} endpoint", endpoint.method)),
+        declaration: Some(format!("{} {}", endpoint.method.to_uppercase(), endpoint.path)),
+        content: endpoint.description.clone(),
     })
 }
 
 /// Fetch Cocoon documentation info for a given path
 async fn fetch_cocoon_info(
     context: &Arc<AppContext>,
-    section_id: &str,
+    section_id: Option<&str>,
     path: &str,
 ) -> Result<SymbolInfo> {
-    // Try to find the document in the section
-    if let Ok(section) = context.providers.cocoon.get_section(section_id).await {
-        if let Some(doc) = section.documents.iter().find(|d| {
-            d.path.eq_ignore_ascii_case(path)
-                || d.title.to_lowercase().contains(&path.to_lowercase())
-        }) {
-            return Ok(SymbolInfo {
-                title: Some(doc.title.clone()),
-                summary: Some(doc.summary.clone()),
-                platforms: Some(vec!["Cocoon".to_string()]),
-                kind: Some("document".to_string()),
-            });
+    // Resolve a title to the document's path within the section
+    let mut document_path = path.to_string();
+    if let Some(section_id) = section_id {
+        if let Ok(section) = context.providers.cocoon.get_section(section_id).await {
+            if let Some(doc) = section.documents.iter().find(|d| {
+                d.path.eq_ignore_ascii_case(path)
+                    || d.title.to_lowercase().contains(&path.to_lowercase())
+            }) {
+                document_path = doc.path.clone();
+            }
         }
     }
 
-    // Try to get the full document
-    if let Ok(doc) = context.providers.cocoon.get_document(path).await {
+    if let Ok(doc) = context.providers.cocoon.get_document(&document_path).await {
         return Ok(SymbolInfo {
             title: Some(doc.title.clone()),
             summary: Some(doc.summary.clone()),
             platforms: Some(vec!["Cocoon".to_string()]),
             kind: Some("document".to_string()),
+            declaration: None,
+            content: non_empty(&doc.content),
         });
     }
 
@@ -358,23 +458,36 @@ async fn fetch_cocoon_info(
 /// Uses minimal fetch for batch operations to avoid slow HTTP requests
 async fn fetch_rust_info(
     context: &Arc<AppContext>,
-    technology_id: &str,
+    technology_id: Option<&str>,
     path: &str,
+    detailed: bool,
 ) -> Result<SymbolInfo> {
-    // Extract crate name from technology identifier (e.g., "rust:std" -> "std")
-    let crate_name = technology_id.strip_prefix("rust:").unwrap_or(technology_id);
+    // Extract crate name from technology identifier (e.g., "rust:std" -> "std"); a full
+    // `crate::path` names its own crate
+    let crate_name = match technology_id {
+        Some(id) => id.strip_prefix("rust:").unwrap_or(id),
+        None => path.split("::").next().filter(|_| path.contains("::")).context(NO_TECHNOLOGY)?,
+    };
+    let full_path = if path.contains("::") {
+        path.to_string()
+    } else {
+        format!("{crate_name}::{path}")
+    };
 
-    // Try to get the item (minimal version for batch operations)
-    if let Ok(item) = context.providers.rust.get_item_minimal(path).await {
+    // Only pay for the full page when declarations or content were asked for
+    let item = if detailed {
+        context.providers.rust.get_item(&full_path).await
+    } else {
+        context.providers.rust.get_item_minimal(&full_path).await
+    };
+    if let Ok(item) = item {
         return Ok(SymbolInfo {
             title: Some(item.name.clone()),
-            summary: if item.summary.is_empty() {
-                None
-            } else {
-                Some(item.summary.clone())
-            },
+            summary: non_empty(&item.summary),
             platforms: Some(vec![format!("Rust ({} v{})", item.crate_name, item.crate_version)]),
             kind: Some(format!("{:?}", item.kind)),
+            declaration: item.declaration.clone(),
+            content: item.documentation.clone(),
         });
     }
 
@@ -383,13 +496,10 @@ async fn fetch_rust_info(
         if let Some(item) = results.first() {
             return Ok(SymbolInfo {
                 title: Some(item.name.clone()),
-                summary: if item.summary.is_empty() {
-                    None
-                } else {
-                    Some(item.summary.clone())
-                },
+                summary: non_empty(&item.summary),
                 platforms: Some(vec![format!("Rust ({} v{})", item.crate_name, item.crate_version)]),
                 kind: Some(format!("{:?}", item.kind)),
+                ..Default::default()
             });
         }
     }
@@ -398,24 +508,53 @@ async fn fetch_rust_info(
 }
 
 /// Normalize a symbol path for the Apple documentation API
-fn normalize_apple_path(technology_id: &str, path: &str) -> String {
+///
+/// Relative paths need a technology; `None` when there is none.
+fn normalize_apple_path(technology_id: Option<&str>, path: &str) -> Option<String> {
     // Strip doc:// prefix if present
     let path = path
         .strip_prefix("doc://com.apple.documentation/")
-        .unwrap_or(path);
+        .unwrap_or(path)
+        .trim_start_matches('/');
 
     // If already starts with documentation/, use as-is
     if path.starts_with("documentation/") || path.starts_with("design/") {
-        return path.to_string();
+        return Some(path.to_string());
     }
 
     // Extract technology name from identifier
-    let tech_name = technology_id
-        .split('/')
-        .last()
-        .unwrap_or("swiftui")
-        .to_lowercase();
+    let tech_name = technology_id?.split('/').next_back()?.to_lowercase();
 
     // Build full path
-    format!("documentation/{}/{}", tech_name, path.to_lowercase())
+    Some(format!("documentation/{}/{}", tech_name, path.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_apple_paths() {
+        let swiftui = Some("doc://com.apple.documentation/documentation/swiftui");
+        assert_eq!(
+            normalize_apple_path(swiftui, "NavigationStack").as_deref(),
+            Some("documentation/swiftui/navigationstack")
+        );
+        assert_eq!(
+            normalize_apple_path(None, "doc://com.apple.documentation/documentation/uikit/uiview").as_deref(),
+            Some("documentation/uikit/uiview")
+        );
+        assert_eq!(normalize_apple_path(None, "NavigationStack"), None);
+    }
+
+    #[tokio::test]
+    async fn explicit_technology_wins_over_active_one() {
+        let context = AppContext::new(docs_mcp_client::AppleDocsClient::new());
+        assert!(resolve_technology(&context, ProviderType::Rust, None).await.is_none());
+
+        let technology = resolve_technology(&context, ProviderType::Rust, Some("tokio")).await.unwrap();
+        assert_eq!(technology.identifier, "rust:tokio");
+        let technology = resolve_technology(&context, ProviderType::Apple, Some("SwiftUI")).await.unwrap();
+        assert_eq!(technology.identifier, "doc://com.apple.documentation/documentation/swiftui");
+    }
 }
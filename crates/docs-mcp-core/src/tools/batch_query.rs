@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{parse_args, query, text_response, wrap_handler};
+
+/// Most queries accepted in one call.
+const MAX_QUERIES: usize = 10;
+/// Queries run at once; the rest wait for a slot.
+const MAX_CONCURRENT_QUERIES: usize = 4;
+/// Default per-query result count, lower than `query`'s to keep batches readable.
+const DEFAULT_MAX_RESULTS: usize = 5;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Args {
+    queries: Vec<String>,
+    max_results: Option<usize>,
+    max_summary_length: Option<usize>,
+    max_code_length: Option<usize>,
+    max_content_length: Option<usize>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "batch_query".to_string(),
+        description: "Run several documentation queries in one call (up to 10, executed concurrently) and get the results grouped per query. Each query behaves like the `query` tool, auto-detecting its own provider.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["queries"],
+            "properties": {
+                "queries": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "minItems": 1,
+                    "maxItems": MAX_QUERIES,
+                    "description": "Natural language queries, e.g. ['SwiftUI List selection', 'Rust tokio spawn']"
+                },
+                "maxResults": {
+                    "type": "number",
+                    "description": "Maximum results per query (default: 5, max: 20)"
+                },
+                "maxSummaryLength": {
+                    "type": "number",
+                    "description": "Maximum characters per summary. Use 0 to disable truncation."
+                },
+                "maxCodeLength": {
+                    "type": "number",
+                    "description": "Maximum characters per code sample. Use 0 to disable truncation."
+                },
+                "maxContentLength": {
+                    "type": "number",
+                    "description": "Maximum characters of full documentation content. Use 0 to disable truncation."
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({"queries": ["SwiftUI NavigationStack", "SwiftData @Model macro"]}),
+            json!({"queries": ["Rust tokio spawn", "React useEffect cleanup", "MDN fetch API"], "maxResults": 3}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, value: Value) -> Result<ToolResponse> {
+    let args: Args = parse_args(value)?;
+    let queries: Vec<String> = args
+        .queries
+        .iter()
+        .map(|query| query.trim().to_string())
+        .filter(|query| !query.is_empty())
+        .collect();
+    if queries.is_empty() {
+        bail!("queries must contain at least one non-empty query");
+    }
+    if queries.len() > MAX_QUERIES {
+        bail!("at most {MAX_QUERIES} queries are allowed per batch (got {})", queries.len());
+    }
+
+    let mut shared = json!({"maxResults": args.max_results.unwrap_or(DEFAULT_MAX_RESULTS)});
+    for (key, value) in [
+        ("maxSummaryLength", args.max_summary_length),
+        ("maxCodeLength", args.max_code_length),
+        ("maxContentLength", args.max_content_length),
+    ] {
+        if let Some(value) = value {
+            shared[key] = json!(value);
+        }
+    }
+
+    // Each query detects its own provider, so it gets a state of its own instead of
+    // racing the others (and the caller) on the active technology
+    let outcomes: Vec<(String, Result<ToolResponse>)> = stream::iter(queries)
+        .map(|query| {
+            let context = Arc::new(context.detached());
+            let mut arguments = shared.clone();
+            arguments["query"] = json!(query);
+            async move { (query, query::run_query(context, arguments).await) }
+        })
+        .buffered(MAX_CONCURRENT_QUERIES)
+        .collect()
+        .await;
+
+    let failures = outcomes.iter().filter(|(_, outcome)| outcome.is_err()).count();
+    let mut lines = vec![format!(
+        "# Batch results: {} quer{}",
+        outcomes.len(),
+        if outcomes.len() == 1 { "y" } else { "ies" }
+    )];
+    let mut results = Vec::with_capacity(outcomes.len());
+    for (index, (query, outcome)) in outcomes.into_iter().enumerate() {
+        lines.push(String::new());
+        lines.push(format!("## {}. {query}", index + 1));
+        lines.push(String::new());
+        match outcome {
+            Ok(response) => {
+                lines.extend(response.content.into_iter().map(|content| content.text));
+                results.push(json!({"query": query, "success": true, "metadata": response.metadata}));
+            }
+            Err(error) => {
+                lines.push(format!("Query failed: {error:#}"));
+                results.push(json!({"query": query, "success": false, "error": format!("{error:#}")}));
+            }
+        }
+    }
+
+    Ok(text_response(lines).with_metadata(json!({
+        "queries": results.len(),
+        "failures": failures,
+        "results": results,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::AppleDocsClient;
+
+    #[tokio::test]
+    async fn rejects_empty_and_oversized_batches() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let Err(error) = handle(context.clone(), json!({"queries": ["  "]})).await else {
+            panic!("blank queries are rejected");
+        };
+        assert!(error.to_string().contains("at least one"));
+
+        let queries: Vec<String> = (0..=MAX_QUERIES).map(|index| format!("query {index}")).collect();
+        let Err(error) = handle(context, json!({"queries": queries})).await else {
+            panic!("oversized batches are rejected");
+        };
+        assert!(error.to_string().contains("at most"));
+    }
+}
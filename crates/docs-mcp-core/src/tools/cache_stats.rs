@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use docs_mcp_client::cache::stats::CacheStatsSnapshot;
+use multi_provider_client::memory::shared_memory_cache;
+use serde_json::json;
+
+use crate::services::cache_maintenance::{cache_usage, format_bytes, CacheLocations};
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{text_response, wrap_handler};
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "cache_stats".to_string(),
+        description: "Maintenance: report documentation cache hit/miss rates and entry counts for this session, shared provider memory usage, and on-disk usage per provider.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![json!({})]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, _value: serde_json::Value) -> Result<ToolResponse> {
+    let stats = context.cache_stats();
+    let provider_memory = shared_memory_cache();
+    let locations = CacheLocations::for_client(&context.client);
+    let usage = cache_usage(&locations).await?;
+
+    let mut lines = vec![
+        "## Cache Statistics".to_string(),
+        String::new(),
+        stats_line("Memory", &stats.memory),
+        stats_line("Disk", &stats.disk),
+        stats_line("Total", &stats.total()),
+        stats_line("Providers (memory)", &provider_memory.stats().snapshot()),
+        format!(
+            "- Provider memory usage: {} of {} budget",
+            format_bytes(provider_memory.total_bytes()),
+            format_bytes(provider_memory.budget())
+        ),
+        String::new(),
+        "## Disk Usage".to_string(),
+        String::new(),
+    ];
+
+    let total_bytes: u64 = usage.iter().map(|entry| entry.usage.bytes).sum();
+    if usage.is_empty() {
+        lines.push("No cached files on disk.".to_string());
+    } else {
+        for entry in &usage {
+            lines.push(format!(
+                "- {}: {} file(s), {} ({})",
+                entry.provider.name(),
+                entry.usage.files,
+                format_bytes(entry.usage.bytes),
+                entry.path
+            ));
+        }
+        lines.push(format!("- Total: {}", format_bytes(total_bytes)));
+    }
+
+    Ok(text_response(lines).with_metadata(json!({
+        "memory": snapshot_json(&stats.memory),
+        "disk": snapshot_json(&stats.disk),
+        "total": snapshot_json(&stats.total()),
+        "providerMemory": {
+            "stats": snapshot_json(&provider_memory.stats().snapshot()),
+            "bytes": provider_memory.total_bytes(),
+            "budgetBytes": provider_memory.budget(),
+            "byProvider": provider_memory
+                .usage_by_provider()
+                .into_iter()
+                .map(|(provider, bytes)| json!({"provider": provider.slug(), "bytes": bytes}))
+                .collect::<Vec<_>>(),
+        },
+        "diskUsage": usage,
+        "diskBytes": total_bytes,
+    })))
+}
+
+fn stats_line(label: &str, snapshot: &CacheStatsSnapshot) -> String {
+    format!(
+        "- {label}: {} hit(s), {} miss(es), {:.1}% hit rate, {} entries, {} eviction(s), {} served",
+        snapshot.hits,
+        snapshot.misses,
+        snapshot.hit_rate(),
+        snapshot.entry_count,
+        snapshot.evictions,
+        format_bytes(snapshot.bytes_served)
+    )
+}
+
+fn snapshot_json(snapshot: &CacheStatsSnapshot) -> serde_json::Value {
+    json!({
+        "hits": snapshot.hits,
+        "misses": snapshot.misses,
+        "hitRate": snapshot.hit_rate(),
+        "entryCount": snapshot.entry_count,
+        "evictions": snapshot.evictions,
+        "bytesServed": snapshot.bytes_served,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_line_reports_hit_rate() {
+        let snapshot = CacheStatsSnapshot {
+            hits: 3,
+            misses: 1,
+            bytes_served: 2048,
+            entry_count: 4,
+            evictions: 0,
+        };
+        assert_eq!(
+            stats_line("Memory", &snapshot),
+            "- Memory: 3 hit(s), 1 miss(es), 75.0% hit rate, 4 entries, 0 eviction(s), 2.0 KB served"
+        );
+        assert_eq!(snapshot_json(&snapshot)["hitRate"], 75.0);
+    }
+}
@@ -0,0 +1,49 @@
+//! Release selection shared by `telegram_changelog` and `sdk_changelog`.
+
+/// How a changelog's releases are versioned
+pub(super) struct ReleaseVersions<R, K> {
+    /// Version of a release, e.g. "7.10"
+    pub version: fn(&R) -> &str,
+    /// Ordering key of a version
+    pub key: fn(&str) -> K,
+    /// Whether a version falls under a pattern such as "7.10" or "7.x"
+    pub matches: fn(&str, &str) -> bool,
+}
+
+/// Releases for a version pattern, a `(from, to]` range, or the latest release.
+///
+/// `releases` are newest first. A release line such as "7.x" as the upper bound includes
+/// every release on that line.
+pub(super) fn select_releases<'a, R, K: Ord>(
+    releases: &'a [R],
+    versions: &ReleaseVersions<R, K>,
+    version: Option<&str>,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Vec<&'a R> {
+    if let Some(version) = version {
+        return releases
+            .iter()
+            .filter(|release| (versions.matches)((versions.version)(release), version))
+            .collect();
+    }
+    if from.is_none() && to.is_none() {
+        return releases.iter().take(1).collect();
+    }
+
+    let lower = from.map(versions.key);
+    releases
+        .iter()
+        .filter(|release| {
+            let release = (versions.version)(release);
+            let key = (versions.key)(release);
+            lower.as_ref().map_or(true, |lower| key > *lower)
+                && to.map_or(true, |to| key <= (versions.key)(to) || (versions.matches)(release, to))
+        })
+        .collect()
+}
+
+/// A string argument without surrounding whitespace, or `None` when it is blank
+pub(super) fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
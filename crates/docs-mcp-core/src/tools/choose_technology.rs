@@ -23,7 +23,7 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
     (
         ToolDefinition {
             name: "choose_technology".to_string(),
-            description: "Select the framework/technology to scope all subsequent searches. Supports Apple (SwiftUI, UIKit), Telegram (methods, types), TON (accounts, nft), Cocoon (architecture, smart-contracts), and Rust (std, serde, tokio)."
+            description: "Pin the framework/technology that scopes all subsequent searches; `query` stops auto-detecting the technology until `reset_state`. Supports Apple (SwiftUI, UIKit), Telegram (methods, types), TON (accounts, nft), Cocoon (architecture, smart-contracts), and Rust (std, serde, tokio)."
                 .to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
@@ -61,7 +61,9 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
         },
         wrap_handler(|context, value| async move {
             let args: Args = parse_args(value)?;
-            handle(context, args).await
+            let response = handle(context.clone(), args).await?;
+            *context.state.technology_pinned.write().await = true;
+            Ok(response)
         }),
     )
 }
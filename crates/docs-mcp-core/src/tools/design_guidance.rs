@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use docs_mcp_client::types::{extract_text, SymbolData};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    markdown,
+    services::design_guidance::{self, DesignSection},
+    state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
+    tools::{parse_args, query, text_response, wrap_handler},
+};
+
+const DEFAULT_FRAMEWORK: &str = "swiftui";
+const HIG_BASE_URL: &str = "https://developer.apple.com";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Args {
+    /// Component or pattern name ("Button", "tab bar") or a documentation path
+    component: String,
+    /// Framework the component belongs to (default `swiftui`)
+    framework: Option<String>,
+    /// Also fetch the component's API summary and declaration (default true)
+    include_api: Option<bool>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "design_guidance".to_string(),
+        description: "Apple Human Interface Guidelines for a UI component or pattern, next to the component's API summary. \
+                      Pass a SwiftUI/UIKit type (\"NavigationStack\", \"Toggle\"), a pattern (\"search field\", \"tab bar\") \
+                      or a documentation path.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "required": ["component"],
+            "properties": {
+                "component": {
+                    "type": "string",
+                    "description": "Component or pattern, e.g. \"Button\", \"List\", \"search field\", or a path like /documentation/swiftui/picker"
+                },
+                "framework": {
+                    "type": "string",
+                    "description": "Framework of the component when a name is given (default swiftui), e.g. uikit or appkit"
+                },
+                "includeApi": {
+                    "type": "boolean",
+                    "description": "Also include the API summary and declaration (default true)"
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({"component": "Toggle"}),
+            json!({"component": "tab bar"}),
+            json!({"component": "UIButton", "framework": "uikit", "includeApi": false}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (
+        definition,
+        wrap_handler(|context, value| async move {
+            let args: Args = parse_args(value)?;
+            handle(context, args).await
+        }),
+    )
+}
+
+async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
+    let component = args.component.trim();
+    if component.is_empty() {
+        bail!("`component` must not be empty");
+    }
+    let framework = args
+        .framework
+        .as_deref()
+        .map(str::trim)
+        .filter(|framework| !framework.is_empty())
+        .unwrap_or(DEFAULT_FRAMEWORK);
+    let path = component_path(component, framework);
+
+    let sections = design_guidance::guidance_for(context.as_ref(), component, &path).await?;
+    let symbol = if args.include_api.unwrap_or(true) {
+        context.client.get_symbol(path.trim_start_matches('/')).await.ok()
+    } else {
+        None
+    };
+
+    let lines = render(component, &path, symbol.as_ref(), &sections);
+    Ok(text_response(lines).with_metadata(json!({
+        "component": component,
+        "path": path,
+        "apiFound": symbol.is_some(),
+        "designSections": sections.iter().map(|section| &section.slug).collect::<Vec<_>>(),
+    })))
+}
+
+/// Documentation path for `component`, which may already be a path.
+fn component_path(component: &str, framework: &str) -> String {
+    let trimmed = component
+        .strip_prefix("doc://com.apple.documentation")
+        .unwrap_or(component)
+        .trim_start_matches('/');
+    if trimmed.starts_with("documentation/") || trimmed.starts_with("design/") {
+        return format!("/{}", trimmed.to_ascii_lowercase());
+    }
+    let name: String = trimmed
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect();
+    format!(
+        "/documentation/{}/{}",
+        framework.to_ascii_lowercase(),
+        name.to_ascii_lowercase()
+    )
+}
+
+fn render(
+    component: &str,
+    path: &str,
+    symbol: Option<&SymbolData>,
+    sections: &[DesignSection],
+) -> Vec<String> {
+    let mut lines = vec![
+        markdown::header(1, &format!("🎨 Design Guidance: {component}")),
+        String::new(),
+    ];
+
+    if let Some(symbol) = symbol {
+        let title = symbol.metadata.title.as_deref().unwrap_or(component);
+        lines.push(markdown::header(2, &format!("API: {title}")));
+        let summary = extract_text(&symbol.r#abstract);
+        if !summary.trim().is_empty() {
+            lines.push(summary);
+        }
+        if let Some(declaration) = query::extract_declaration(symbol) {
+            lines.push(String::new());
+            lines.push("```swift".to_string());
+            lines.push(declaration);
+            lines.push("```".to_string());
+        }
+        lines.push(format!("Documentation: {HIG_BASE_URL}{path}"));
+        lines.push(String::new());
+    }
+
+    if sections.is_empty() {
+        lines.push(format!(
+            "No Human Interface Guidelines are mapped to `{component}`. Try a component name such as \
+             `Button`, `List` or `NavigationStack`, or a pattern such as `search field`."
+        ));
+        return lines;
+    }
+
+    lines.push(markdown::header(2, "Human Interface Guidelines"));
+    for section in sections {
+        lines.push(String::new());
+        lines.push(markdown::header(3, &section.title));
+        for bullet in &section.bullets {
+            lines.push(format!("• **{}:** {}", bullet.category, bullet.text));
+        }
+        lines.push(format!("Full guidelines: {HIG_BASE_URL}{}", section.url));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::design_guidance::DesignBullet;
+
+    #[test]
+    fn resolves_component_paths() {
+        assert_eq!(component_path("Toggle", "swiftui"), "/documentation/swiftui/toggle");
+        assert_eq!(component_path("tab bar", "UIKit"), "/documentation/uikit/tabbar");
+        assert_eq!(
+            component_path("/documentation/SwiftUI/Picker", "uikit"),
+            "/documentation/swiftui/picker"
+        );
+        assert_eq!(
+            component_path("doc://com.apple.documentation/documentation/appkit/nsbutton", "swiftui"),
+            "/documentation/appkit/nsbutton"
+        );
+    }
+
+    #[test]
+    fn renders_guidance_sections() {
+        let sections = vec![DesignSection {
+            slug: "design/human-interface-guidelines/toggles".to_string(),
+            url: "/design/human-interface-guidelines/toggles".to_string(),
+            title: "Toggles".to_string(),
+            summary: None,
+            bullets: vec![DesignBullet {
+                category: "Best practice",
+                text: "Use a toggle for binary settings.".to_string(),
+            }],
+        }];
+        let lines = render("Toggle", "/documentation/swiftui/toggle", None, &sections).join("\n");
+        assert!(lines.contains("## Human Interface Guidelines"));
+        assert!(lines.contains("• **Best practice:** Use a toggle for binary settings."));
+        assert!(lines.contains(
+            "Full guidelines: https://developer.apple.com/design/human-interface-guidelines/toggles"
+        ));
+
+        let empty = render("Widget", "/documentation/swiftui/widget", None, &[]).join("\n");
+        assert!(empty.contains("No Human Interface Guidelines are mapped to `Widget`"));
+    }
+}
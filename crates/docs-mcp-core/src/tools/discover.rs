@@ -3,6 +3,7 @@ use std::{collections::HashMap, sync::Arc};
 use anyhow::Result;
 use docs_mcp_client::types::extract_text;
 use multi_provider_client::types::{ProviderType, TechnologyKind, UnifiedTechnology};
+use multi_provider_client::AllTechnologies;
 use once_cell::sync::Lazy;
 use serde::Deserialize;
 use serde_json::json;
@@ -250,33 +251,38 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
         unified_techs.extend(filtered_apple);
     }
 
-    // Telegram technologies
+    // Other providers; failures are reported as degraded instead of failing the listing
+    let mut loaded = AllTechnologies::default();
     if provider_filter == "all" || provider_filter == "telegram" {
-        if let Ok(telegram_techs) = context.providers.telegram.get_technologies().await {
-            unified_techs.extend(telegram_techs.into_iter().map(UnifiedTechnology::from_telegram));
-        }
+        let outcome = context.providers.telegram.get_technologies().await;
+        loaded.record(
+            ProviderType::Telegram,
+            outcome.map(|techs| techs.into_iter().map(UnifiedTechnology::from_telegram).collect()),
+        );
     }
-
-    // TON technologies
     if provider_filter == "all" || provider_filter == "ton" {
-        if let Ok(ton_techs) = context.providers.ton.get_technologies().await {
-            unified_techs.extend(ton_techs.into_iter().map(UnifiedTechnology::from_ton));
-        }
+        let outcome = context.providers.ton.get_technologies().await;
+        loaded.record(
+            ProviderType::TON,
+            outcome.map(|techs| techs.into_iter().map(UnifiedTechnology::from_ton).collect()),
+        );
     }
-
-    // Cocoon technologies
     if provider_filter == "all" || provider_filter == "cocoon" {
-        if let Ok(cocoon_techs) = context.providers.cocoon.get_technologies().await {
-            unified_techs.extend(cocoon_techs.into_iter().map(UnifiedTechnology::from_cocoon));
-        }
+        let outcome = context.providers.cocoon.get_technologies().await;
+        loaded.record(
+            ProviderType::Cocoon,
+            outcome.map(|techs| techs.into_iter().map(UnifiedTechnology::from_cocoon).collect()),
+        );
     }
-
-    // Rust technologies
     if provider_filter == "all" || provider_filter == "rust" {
-        if let Ok(rust_techs) = context.providers.rust.get_technologies().await {
-            unified_techs.extend(rust_techs.into_iter().map(UnifiedTechnology::from_rust));
-        }
+        let outcome = context.providers.rust.get_technologies().await;
+        loaded.record(
+            ProviderType::Rust,
+            outcome.map(|techs| techs.into_iter().map(UnifiedTechnology::from_rust).collect()),
+        );
     }
+    let degraded = loaded.degraded_providers();
+    unified_techs.extend(loaded.technologies.into_values().flatten());
 
     // Apply query filter
     if let Some(query) = &args.query {
@@ -345,6 +351,18 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
         String::new(),
     ];
 
+    if !degraded.is_empty() {
+        lines.push(format!(
+            "⚠️ **Degraded providers:** {} (results from these providers are missing)",
+            degraded
+                .iter()
+                .map(|provider| format!("{} — {}", provider.name(), loaded.errors[provider]))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ));
+        lines.push(String::new());
+    }
+
     // Show available providers hint when no filter applied
     if args.query.is_none() && provider_filter == "all" {
         lines.push("*Available providers: apple (iOS/macOS), telegram (Bot API), ton (Blockchain), cocoon (Confidential Computing), rust (Rust std & crates)*".to_string());
@@ -383,10 +401,11 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
             TechnologyKind::WebFramework => " [Framework]",
             TechnologyKind::MlxFramework => " [ML]",
             TechnologyKind::HfLibrary => " [AI]",
-            TechnologyKind::QuickNodeApi => " [Solana]",
+            TechnologyKind::QuickNodeApi => " [RPC]",
             TechnologyKind::AgentSdkLibrary => " [SDK]",
-            TechnologyKind::VertcoinApi => " [VTC]",
+            TechnologyKind::BitcoindApi => " [bitcoind]",
             TechnologyKind::CudaApi => " [GPU]",
+            TechnologyKind::SolanaDocs => " [Solana]",
         };
         title_line.push_str(kind_badge);
 
@@ -431,7 +450,14 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
             "ton": ton_count,
             "cocoon": cocoon_count,
             "rust": rust_count,
-        }
+        },
+        "degradedProviders": degraded
+            .iter()
+            .map(|provider| json!({
+                "provider": provider.slug(),
+                "error": loaded.errors[provider].to_string(),
+            }))
+            .collect::<Vec<_>>(),
     });
 
     Ok(text_response(lines).with_metadata(metadata))
@@ -449,10 +475,11 @@ fn provider_display_name(provider: &ProviderType) -> &'static str {
         ProviderType::WebFrameworks => "⚛️ Web Frameworks",
         ProviderType::Mlx => "🧠 MLX",
         ProviderType::HuggingFace => "🤗 Hugging Face",
-        ProviderType::QuickNode => "⚡ QuickNode Solana",
+        ProviderType::QuickNode => "⚡ QuickNode",
         ProviderType::ClaudeAgentSdk => "🤖 Claude Agent SDK",
-        ProviderType::Vertcoin => "💚 Vertcoin",
+        ProviderType::Bitcoind => "💚 Bitcoin / Litecoin / Vertcoin",
         ProviderType::Cuda => "🎮 CUDA",
+        ProviderType::Solana => "◎ Solana",
     }
 }
 
@@ -471,7 +498,8 @@ fn provider_sort_order(provider: &ProviderType) -> u8 {
         ProviderType::HuggingFace => 9,
         ProviderType::QuickNode => 10,
         ProviderType::ClaudeAgentSdk => 11,
-        ProviderType::Vertcoin => 12,
+        ProviderType::Bitcoind => 12,
+        ProviderType::Solana => 13,
     }
 }
 
@@ -500,8 +528,9 @@ fn get_unified_relevance_score(tech: &UnifiedTechnology, query: &Option<String>)
             TechnologyKind::HfLibrary => 44,
             TechnologyKind::QuickNodeApi => 42,
             TechnologyKind::AgentSdkLibrary => 43,
-            TechnologyKind::VertcoinApi => 41,
+            TechnologyKind::BitcoindApi => 41,
             TechnologyKind::CudaApi => 49, // High score for CUDA/GPU programming
+            TechnologyKind::SolanaDocs => 42,
         }
     };
 
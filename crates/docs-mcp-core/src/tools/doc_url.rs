@@ -0,0 +1,242 @@
+//! Documentation links pasted from a browser, resolved to the provider, technology and path
+//! the tools address pages by.
+//!
+//! Recognizes developer.apple.com, docs.rs, doc.rust-lang.org and MDN Web Docs pages.
+
+use multi_provider_client::mdn::MdnCategory;
+use multi_provider_client::rust::STD_CRATES;
+use multi_provider_client::types::ProviderType;
+
+use super::query::CARGO_BOOK_TECHNOLOGY;
+
+/// Rustdoc anchors naming a member of the page's item, e.g. `#method.push`
+const MEMBER_ANCHORS: &[&str] = &[
+    "method",
+    "tymethod",
+    "variant",
+    "structfield",
+    "associatedtype",
+    "associatedconstant",
+];
+
+/// The documentation page a URL points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DocUrl {
+    pub provider: ProviderType,
+    /// Technology identifier to select, e.g. `rust:tokio@1.35.0`
+    pub technology: String,
+    /// Path within the technology as `get_documentation` takes it
+    pub path: String,
+}
+
+/// Resolve an `http(s)` documentation URL; `None` for anything else, including other sites.
+pub(crate) fn parse_doc_url(input: &str) -> Option<DocUrl> {
+    let input = input.trim();
+    let rest = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))?;
+    if rest.chars().any(char::is_whitespace) {
+        return None;
+    }
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.to_ascii_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+
+    // The query string never matters; the fragment can name a Rust member or Cargo Book section
+    let (path, fragment) = match path.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment).filter(|fragment| !fragment.is_empty())),
+        None => (path, None),
+    };
+    let path = path.split('?').next().unwrap_or_default().trim_matches('/');
+
+    match host {
+        "developer.apple.com" => apple_url(path),
+        "docs.rs" => docs_rs_url(path, fragment),
+        "doc.rust-lang.org" => rust_lang_url(path, fragment),
+        "developer.mozilla.org" => mdn_url(path),
+        _ => None,
+    }
+}
+
+/// `documentation/swiftui/navigationstack`, `tutorials/...` or `design/...`
+fn apple_url(path: &str) -> Option<DocUrl> {
+    let mut segments = path.split('/');
+    let framework = match segments.next()? {
+        "documentation" => segments.next().filter(|framework| !framework.is_empty())?,
+        "tutorials" | "design" => "swiftui",
+        _ => return None,
+    };
+    Some(DocUrl {
+        provider: ProviderType::Apple,
+        technology: format!("doc://com.apple.documentation/documentation/{}", framework.to_lowercase()),
+        path: path.to_string(),
+    })
+}
+
+/// `<crate>/<version>/<lib>/<module>/struct.Name.html`, `crate/<crate>/<version>` or `<crate>`
+fn docs_rs_url(path: &str, fragment: Option<&str>) -> Option<DocUrl> {
+    let mut segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    if segments.first() == Some(&"crate") {
+        segments.remove(0);
+        segments.truncate(2);
+    }
+    let (&crate_name, rest) = segments.split_first()?;
+    let technology = match rest.first().filter(|version| !matches!(**version, "latest" | "*")) {
+        Some(version) => format!("rust:{crate_name}@{version}"),
+        None => format!("rust:{crate_name}"),
+    };
+    let path = rustdoc_path(rest.get(1..).unwrap_or_default(), fragment)
+        .unwrap_or_else(|| crate_name.replace('-', "_"));
+    Some(DocUrl {
+        provider: ProviderType::Rust,
+        technology,
+        path,
+    })
+}
+
+/// Standard library items, compiler error codes and Cargo Book pages, on any release channel
+fn rust_lang_url(path: &str, fragment: Option<&str>) -> Option<DocUrl> {
+    let mut segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+    let channel = segments.first().is_some_and(|first| {
+        matches!(*first, "stable" | "beta" | "nightly") || first.starts_with(|c: char| c.is_ascii_digit())
+    });
+    if channel {
+        segments.remove(0);
+    }
+
+    let rust = |technology: &str, path: String| {
+        Some(DocUrl {
+            provider: ProviderType::Rust,
+            technology: technology.to_string(),
+            path,
+        })
+    };
+    match segments.as_slice() {
+        ["error_codes", page] if page.starts_with('E') => {
+            rust("rust:std", page.trim_end_matches(".html").to_string())
+        }
+        ["error-index.html"] => rust("rust:std", fragment?.to_string()),
+        ["cargo", pages @ ..] if !pages.is_empty() => {
+            let page = pages.join("/");
+            let path = match fragment {
+                Some(fragment) => format!("{page}#{fragment}"),
+                None => page,
+            };
+            rust(CARGO_BOOK_TECHNOLOGY, path)
+        }
+        [crate_name, ..] if STD_CRATES.iter().any(|(name, _)| name == crate_name) => {
+            rust(&format!("rust:{crate_name}"), rustdoc_path(&segments, fragment)?)
+        }
+        _ => None,
+    }
+}
+
+/// `["tokio", "sync", "struct.Mutex.html"]` with anchor `method.lock` as `tokio::sync::Mutex::lock`
+fn rustdoc_path(segments: &[&str], fragment: Option<&str>) -> Option<String> {
+    let (&last, modules) = segments.split_last()?;
+    let mut parts = modules.to_vec();
+    match last.strip_suffix(".html") {
+        Some("index") => {}
+        Some(file) => parts.push(file.split_once('.').map_or(file, |(_, name)| name)),
+        None => parts.push(last),
+    }
+    if parts.is_empty() {
+        return None;
+    }
+    let member = fragment
+        .and_then(|fragment| fragment.split_once('.'))
+        .filter(|(kind, _)| MEMBER_ANCHORS.contains(kind));
+    if let Some((_, name)) = member {
+        parts.push(name);
+    }
+    Some(parts.join("::"))
+}
+
+/// `<locale>/docs/Web/API/fetch`
+fn mdn_url(path: &str) -> Option<DocUrl> {
+    let (_locale, slug) = path.split_once("/docs/")?;
+    if slug.is_empty() {
+        return None;
+    }
+    let technology = match MdnCategory::from_slug(slug) {
+        MdnCategory::JavaScript => "mdn:javascript",
+        MdnCategory::WebApi => "mdn:webapi",
+        MdnCategory::Css => "mdn:css",
+        MdnCategory::Html => "mdn:html",
+    };
+    Some(DocUrl {
+        provider: ProviderType::Mdn,
+        technology: technology.to_string(),
+        path: slug.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(url: &str) -> (ProviderType, String, String) {
+        let doc = parse_doc_url(url).unwrap_or_else(|| panic!("{url} did not resolve"));
+        (doc.provider, doc.technology, doc.path)
+    }
+
+    #[test]
+    fn resolves_apple_urls() {
+        assert_eq!(
+            resolved("https://developer.apple.com/documentation/SwiftUI/NavigationStack?language=objc"),
+            (
+                ProviderType::Apple,
+                "doc://com.apple.documentation/documentation/swiftui".to_string(),
+                "documentation/SwiftUI/NavigationStack".to_string()
+            )
+        );
+        assert_eq!(
+            resolved("https://developer.apple.com/design/human-interface-guidelines/buttons").2,
+            "design/human-interface-guidelines/buttons"
+        );
+        assert_eq!(parse_doc_url("https://developer.apple.com/videos/play/wwdc2024/10001"), None);
+    }
+
+    #[test]
+    fn resolves_rust_urls() {
+        assert_eq!(
+            resolved("https://docs.rs/tokio/1.35.0/tokio/sync/struct.Mutex.html#method.lock"),
+            (ProviderType::Rust, "rust:tokio@1.35.0".to_string(), "tokio::sync::Mutex::lock".to_string())
+        );
+        assert_eq!(
+            resolved("https://docs.rs/serde/latest/serde/trait.Deserialize.html"),
+            (ProviderType::Rust, "rust:serde".to_string(), "serde::Deserialize".to_string())
+        );
+        assert_eq!(resolved("https://docs.rs/async-trait").2, "async_trait");
+        assert_eq!(resolved("https://docs.rs/crate/tokio/latest").2, "tokio");
+        assert_eq!(resolved("https://docs.rs/tokio/latest/tokio/sync/index.html").2, "tokio::sync");
+
+        assert_eq!(
+            resolved("https://doc.rust-lang.org/std/vec/struct.Vec.html#method.push"),
+            (ProviderType::Rust, "rust:std".to_string(), "std::vec::Vec::push".to_string())
+        );
+        assert_eq!(resolved("https://doc.rust-lang.org/nightly/core/option/enum.Option.html").2, "core::option::Option");
+        assert_eq!(resolved("https://doc.rust-lang.org/error_codes/E0502.html").2, "E0502");
+        assert_eq!(
+            resolved("https://doc.rust-lang.org/cargo/reference/profiles.html#lto"),
+            (ProviderType::Rust, CARGO_BOOK_TECHNOLOGY.to_string(), "reference/profiles.html#lto".to_string())
+        );
+        assert_eq!(parse_doc_url("https://doc.rust-lang.org/book/ch04-01-what-is-ownership.html"), None);
+    }
+
+    #[test]
+    fn resolves_mdn_urls() {
+        assert_eq!(
+            resolved("https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API#concepts_and_usage"),
+            (ProviderType::Mdn, "mdn:webapi".to_string(), "Web/API/Fetch_API".to_string())
+        );
+        assert_eq!(resolved("https://developer.mozilla.org/de/docs/Web/CSS/display").1, "mdn:css");
+    }
+
+    #[test]
+    fn ignores_other_input() {
+        assert_eq!(parse_doc_url("docs.rs/parking_lot Mutex"), None);
+        assert_eq!(parse_doc_url("https://example.com/documentation/swiftui"), None);
+        assert_eq!(parse_doc_url("NavigationStack"), None);
+    }
+}
@@ -1,10 +1,15 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use docs_mcp_client::types::{
-    extract_text, format_platforms, PlatformInfo, ReferenceData, SymbolData, TopicData,
-    TopicSection,
+    apply_language_variant, extract_text, format_platforms, PlatformInfo, ReferenceData,
+    SymbolData, SymbolLanguage, TopicData, TopicSection, TutorialPage,
 };
+use multi_provider_client::mdn::{browser_support_table, MdnArticle};
+use multi_provider_client::telegram::{MiniAppSection, MINI_APPS_TECHNOLOGY, TDLIB_TECHNOLOGY};
 use multi_provider_client::types::ProviderType;
 use serde::Deserialize;
 use serde_json::{json, Value};
@@ -13,12 +18,18 @@ use crate::{
     markdown,
     services::{design_guidance, knowledge},
     state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
-    tools::{parse_args, text_response, wrap_handler},
+    tools::{doc_url, parse_args, query, text_response, wrap_handler},
 };
 
 #[derive(Debug, Deserialize)]
 struct Args {
     path: String,
+    /// Source language of Apple symbol pages: `swift` (default) or `objc`
+    #[serde(default)]
+    language: Option<String>,
+    /// Rust only: return the item's source code instead of its documentation
+    #[serde(default)]
+    source: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +45,12 @@ struct RenderOutput {
     metadata: Value,
 }
 
+/// Changed lines shown per tutorial step before the listing is cut short.
+const MAX_TUTORIAL_DIFF_LINES: usize = 40;
+
+/// Implementing types listed for a Rust trait.
+const MAX_RUST_IMPLEMENTORS: usize = 30;
+
 /// Code execution caller identifier for programmatic tool calling.
 const CODE_EXECUTION_CALLER: &str = "code_execution_20250825";
 
@@ -50,7 +67,19 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 "type": "object",
                 "required": ["path"],
                 "properties": {
-                    "path": {"type": "string", "description": "Symbol path or relative name"}
+                    "path": {
+                        "type": "string",
+                        "description": "Symbol path or relative name, or a developer.apple.com, docs.rs, doc.rust-lang.org or MDN URL"
+                    },
+                    "language": {
+                        "type": "string",
+                        "enum": ["swift", "objc"],
+                        "description": "Apple only: show the Objective-C declaration, title and availability instead of Swift (default swift)"
+                    },
+                    "source": {
+                        "type": "boolean",
+                        "description": "Rust only: return the item's implementation from its docs.rs source page instead of its documentation"
+                    }
                 }
             }),
             // Examples showing various path formats accepted by the tool
@@ -63,8 +92,16 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"path": "documentation/swiftui/navigationstack"}),
                 // Design guidance / HIG content
                 json!({"path": "design/human-interface-guidelines/buttons"}),
+                // Tutorial with its steps and code changes
+                json!({"path": "tutorials/swiftui/creating-and-combining-views"}),
                 // Path with doc:// prefix (automatically stripped)
                 json!({"path": "doc://com.apple.documentation/documentation/swiftui/text"}),
+                // Documentation URL pasted from a browser (selects the provider and crate)
+                json!({"path": "https://docs.rs/tokio/latest/tokio/sync/struct.Mutex.html"}),
+                // Objective-C declaration of a UIKit symbol
+                json!({"path": "documentation/uikit/uiview/init(frame:)", "language": "objc"}),
+                // Implementation of a Rust item (with a Rust crate selected)
+                json!({"path": "std::vec::Vec::push", "source": true}),
             ]),
             // Enable programmatic calling for batch documentation fetching.
             // Allows Claude to write code that fetches multiple symbols and compares them,
@@ -78,9 +115,75 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
     )
 }
 
-async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
+/// Fetch one document by path or documentation URL without selecting a technology first.
+///
+/// URLs and Apple paths select their technology themselves (`documentation/swiftui/...`); other
+/// providers need `technology`.
+pub async fn fetch_document(
+    context: Arc<AppContext>,
+    path: &str,
+    provider: Option<ProviderType>,
+    technology: Option<&str>,
+) -> Result<ToolResponse> {
+    let args = Args {
+        path: path.to_string(),
+        language: None,
+        source: false,
+    };
+    if doc_url::parse_doc_url(path).is_some() {
+        return handle(context, args).await;
+    }
+    let path = strip_web_prefix(path);
+    let provider = provider.unwrap_or_default();
+    let technology = match (technology, provider) {
+        (Some(technology), _) => technology.to_string(),
+        (None, ProviderType::Apple) => path_framework(path).unwrap_or("swiftui").to_string(),
+        (None, _) => bail!("Pass a technology to fetch {} documentation", provider.name()),
+    };
+    query::select_technology(&context, provider, &query::technology_identifier(provider, &technology)?)
+        .await?;
+    handle(
+        context,
+        Args {
+            path: path.to_string(),
+            language: None,
+            source: false,
+        },
+    )
+    .await
+}
+
+/// `path` without a developer.apple.com origin.
+fn strip_web_prefix(path: &str) -> &str {
+    let trimmed = path.trim();
+    ["https://developer.apple.com", "http://developer.apple.com", "developer.apple.com"]
+        .iter()
+        .find_map(|origin| trimmed.strip_prefix(origin))
+        .unwrap_or(trimmed)
+}
+
+/// Framework a `documentation/<framework>/...` path belongs to.
+fn path_framework(path: &str) -> Option<&str> {
+    let path = path
+        .strip_prefix("doc://com.apple.documentation/")
+        .unwrap_or(path)
+        .trim_start_matches('/');
+    let mut segments = path.strip_prefix("documentation/")?.split(['/', '#', '?']);
+    segments.next().filter(|framework| !framework.is_empty())
+}
+
+async fn handle(context: Arc<AppContext>, mut args: Args) -> Result<ToolResponse> {
+    // A pasted documentation URL selects its provider and technology
+    if let Some(url) = doc_url::parse_doc_url(&args.path) {
+        query::select_technology(&context, url.provider, &url.technology).await?;
+        args.path = url.path;
+    }
+
     // Dispatch based on provider type
     let provider = *context.state.active_provider.read().await;
+    if args.source && provider != ProviderType::Rust {
+        bail!("Source code is only available for Rust items (active provider: {})", provider.name());
+    }
 
     match provider {
         ProviderType::Apple => {
@@ -95,7 +198,8 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
         }
         ProviderType::Telegram | ProviderType::TON | ProviderType::Cocoon | ProviderType::Rust
         | ProviderType::Mdn | ProviderType::WebFrameworks | ProviderType::Mlx | ProviderType::HuggingFace
-        | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Vertcoin | ProviderType::Cuda => {
+        | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Bitcoind | ProviderType::Cuda
+        | ProviderType::Solana => {
             // For non-Apple providers, use active_unified_technology
             let unified = context
                 .state
@@ -120,8 +224,10 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
                 ProviderType::TON => handle_ton(&context, &active, &args).await,
                 ProviderType::Cocoon => handle_cocoon(&context, &active, &args).await,
                 ProviderType::Rust => handle_rust(&context, &active, &args).await,
-                // Mlx, HuggingFace, QuickNode, ClaudeAgentSdk, Vertcoin, and Cuda use the unified query tool
-                ProviderType::Mlx | ProviderType::HuggingFace | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Vertcoin | ProviderType::Cuda => {
+                ProviderType::Mdn => handle_mdn(&context, &args).await,
+                // WebFrameworks, Mlx, HuggingFace, QuickNode, ClaudeAgentSdk, Vertcoin, Cuda, and Solana use the unified query tool
+                ProviderType::WebFrameworks | ProviderType::Mlx | ProviderType::HuggingFace | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Bitcoind | ProviderType::Cuda
+                | ProviderType::Solana => {
                     anyhow::bail!("Use the `query` tool for {} documentation", provider.name())
                 }
                 _ => unreachable!(),
@@ -141,6 +247,12 @@ async fn handle_apple(
         .next_back()
         .context("Invalid technology identifier")?;
 
+    let language = args
+        .language
+        .as_deref()
+        .map(str::parse::<SymbolLanguage>)
+        .transpose()?
+        .unwrap_or_default();
     let normalized = normalize_path(&args.path, identifier);
     let fallback = fallback_path(&args.path);
     let paths = if normalized == fallback {
@@ -152,7 +264,12 @@ async fn handle_apple(
 
     for path in paths {
         match context.client.load_document(&path).await {
-            Ok(value) => {
+            Ok(mut value) => {
+                if let Some(tutorial) = TutorialPage::from_value(&value) {
+                    let render = build_tutorial_response(&path, &tutorial);
+                    return Ok(text_response(render.lines).with_metadata(render.metadata));
+                }
+                apply_language_variant(&mut value, language);
                 if let Ok(symbol) = serde_json::from_value::<SymbolData>(value.clone()) {
                     *context.state.last_symbol.write().await = Some(symbol.clone());
                     let symbol_title = symbol
@@ -165,7 +282,8 @@ async fn handle_apple(
                         design_guidance::guidance_for(context, &symbol_title, &symbol_path)
                             .await
                             .unwrap_or_default();
-                    let render = build_symbol_response(&active.title, &symbol, &design_sections);
+                    let render =
+                        build_symbol_response(&active.title, &symbol, language, &design_sections);
                     return Ok(text_response(render.lines).with_metadata(render.metadata));
                 }
 
@@ -216,13 +334,20 @@ async fn handle_telegram(
     args: &Args,
 ) -> Result<ToolResponse> {
     let path = args.path.trim();
+    let telegram = &context.providers.telegram;
+    let provider_label = query::telegram_platform(&active.identifier);
 
     // Try to get item by name (searching through all items)
-    if let Ok(item) = context.providers.telegram.get_item(path).await {
+    let item = match active.identifier.as_str() {
+        MINI_APPS_TECHNOLOGY => telegram.get_mini_app_item(path).await,
+        TDLIB_TECHNOLOGY => telegram.get_tdlib_item(path).await,
+        _ => telegram.get_item(path).await,
+    };
+    if let Ok(item) = item {
         let mut lines = vec![
             markdown::header(1, &item.name),
             String::new(),
-            markdown::bold("Provider", "Telegram Bot API"),
+            markdown::bold("Provider", provider_label),
             markdown::bold("Type", &item.kind),
         ];
 
@@ -249,8 +374,21 @@ async fn handle_telegram(
             lines.push(returns.join(", "));
         }
 
+        if !item.subtype_of.is_empty() {
+            lines.push(String::new());
+            lines.push(markdown::header(2, "Subtype Of"));
+            lines.push(item.subtype_of.join(", "));
+        }
+
+        if !item.subtypes.is_empty() {
+            lines.push(String::new());
+            lines.push(markdown::header(2, "Subtypes"));
+            lines.push(item.subtypes.join(", "));
+        }
+
         let metadata = json!({
             "provider": "telegram",
+            "technology": active.identifier,
             "kind": item.kind,
             "name": item.name,
             "fieldCount": item.fields.len(),
@@ -260,12 +398,20 @@ async fn handle_telegram(
     }
 
     // Fallback: search for the item
-    if let Ok(results) = context.providers.telegram.search(path).await {
+    let results = match active.identifier.as_str() {
+        MINI_APPS_TECHNOLOGY => telegram
+            .search_mini_apps(path)
+            .await
+            .map(|sections| sections.iter().map(MiniAppSection::to_item).collect()),
+        TDLIB_TECHNOLOGY => telegram.search_tdlib(path).await,
+        _ => telegram.search(path).await,
+    };
+    if let Ok(results) = results {
         if let Some(item) = results.first() {
             let mut lines = vec![
                 markdown::header(1, &item.name),
                 String::new(),
-                markdown::bold("Provider", "Telegram Bot API"),
+                markdown::bold("Provider", provider_label),
                 markdown::bold("Type", &item.kind),
             ];
 
@@ -283,7 +429,7 @@ async fn handle_telegram(
         }
     }
 
-    Err(anyhow!("Documentation not found for '{}' in Telegram Bot API. Technology: {}", path, active.title))
+    Err(anyhow!("Documentation not found for '{}' in {}. Technology: {}", path, provider_label, active.title))
 }
 
 async fn handle_ton(
@@ -293,6 +439,79 @@ async fn handle_ton(
 ) -> Result<ToolResponse> {
     let path = args.path.trim();
 
+    if let Some(exit_code) = context.providers.ton.get_exit_code(path) {
+        let mut lines = vec![
+            markdown::header(1, &format!("Exit code {}: {}", exit_code.code, exit_code.name)),
+            String::new(),
+            markdown::bold("Provider", "TON"),
+            markdown::bold("Raised in", &exit_code.phase),
+            String::new(),
+            exit_code.description.clone(),
+        ];
+
+        if !exit_code.causes.is_empty() {
+            lines.push(String::new());
+            lines.push(markdown::header(2, "Common Causes"));
+            for cause in &exit_code.causes {
+                lines.push(format!("• {cause}"));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(format!("Reference: {}", exit_code.url));
+
+        let metadata = json!({
+            "provider": "ton",
+            "kind": "exitCode",
+            "code": exit_code.code,
+            "phase": exit_code.phase,
+        });
+
+        return Ok(text_response(lines).with_metadata(metadata));
+    }
+
+    // TL-B schemas are looked up by id or op code
+    if let Some(schema) = context.providers.ton.get_tlb_schema(path) {
+        let mut lines = vec![
+            markdown::header(1, &schema.name),
+            String::new(),
+            markdown::bold("Provider", "TON TL-B"),
+            markdown::bold("Standard", &schema.standard),
+        ];
+        if let Some(op_code) = &schema.op_code {
+            lines.push(markdown::bold("Op code", op_code));
+        }
+
+        lines.push(String::new());
+        lines.push(schema.description.clone());
+        lines.push(String::new());
+        lines.push("```tlb".to_string());
+        lines.push(schema.tlb.clone());
+        lines.push("```".to_string());
+
+        if !schema.fields.is_empty() {
+            lines.push(String::new());
+            lines.push(markdown::header(2, "Fields"));
+            for field in &schema.fields {
+                let layout = field.description.as_deref().unwrap_or("");
+                lines.push(format!("• **{}** `{}` — {}", field.name, field.param_type, layout));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(format!("Specification: {}", schema.url));
+
+        let metadata = json!({
+            "provider": "ton",
+            "kind": "tlbSchema",
+            "id": schema.id,
+            "opCode": schema.op_code,
+            "fieldCount": schema.fields.len(),
+        });
+
+        return Ok(text_response(lines).with_metadata(metadata));
+    }
+
     // Try to get endpoint by operation ID
     if let Ok(endpoint) = context.providers.ton.get_endpoint(path).await {
         let mut lines = vec![
@@ -458,6 +677,88 @@ async fn handle_cocoon(
     Err(anyhow!("Documentation not found for '{}' in Cocoon. Technology: {}", path, active.title))
 }
 
+/// An MDN article by slug, e.g. `Web/API/Fetch_API`.
+async fn handle_mdn(context: &Arc<AppContext>, args: &Args) -> Result<ToolResponse> {
+    let slug = args.path.trim().trim_matches('/');
+    let article = context
+        .providers
+        .mdn
+        .get_article(slug)
+        .await
+        .with_context(|| format!("Documentation not found for '{slug}' in MDN Web Docs"))?;
+    Ok(build_mdn_response(&article))
+}
+
+fn build_mdn_response(article: &MdnArticle) -> ToolResponse {
+    let mut lines = vec![
+        markdown::header(1, &article.title),
+        String::new(),
+        markdown::bold("Provider", &format!("MDN Web Docs ({})", article.category)),
+    ];
+    if !article.summary.is_empty() {
+        lines.push(String::new());
+        lines.push(article.summary.clone());
+    }
+
+    if let Some(syntax) = article.syntax.as_deref().filter(|syntax| !syntax.trim().is_empty()) {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Syntax"));
+        lines.push(format!("```js\n{syntax}\n```"));
+    }
+
+    if !article.parameters.is_empty() {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Parameters"));
+        for parameter in &article.parameters {
+            let optional = if parameter.optional { " (optional)" } else { "" };
+            lines.push(format!("• **{}**{optional} — {}", parameter.name, parameter.description));
+        }
+    }
+    if let Some(returns) = &article.return_value {
+        lines.push(String::new());
+        lines.push(markdown::bold("Returns", returns));
+    }
+
+    if !article.members.is_empty() {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Members"));
+        for member in &article.members {
+            lines.push(format!("• **{}** ({}) — {}", member.name, member.label(), member.description));
+        }
+    }
+
+    let example = article
+        .examples
+        .iter()
+        .max_by_key(|example| (example.is_runnable, example.code.len()));
+    if let Some(example) = example {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Example"));
+        lines.push(format!("```{}\n{}\n```", example.language, example.code));
+    }
+
+    if let Some(table) = browser_support_table(&article.browser_support) {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Browser Support"));
+        lines.push(table);
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Documentation:** {}", article.url));
+
+    let metadata = json!({
+        "provider": "mdn",
+        "category": article.category.as_str(),
+        "slug": article.slug,
+        "title": article.title,
+        "url": article.url,
+        "parameterCount": article.parameters.len(),
+        "memberCount": article.members.len(),
+        "hasExample": example.is_some(),
+    });
+    text_response(lines).with_metadata(metadata)
+}
+
 async fn handle_rust(
     context: &Arc<AppContext>,
     active: &docs_mcp_client::types::Technology,
@@ -471,6 +772,30 @@ async fn handle_rust(
         .strip_prefix("rust:")
         .unwrap_or(&active.identifier);
 
+    // Paths into the active crate follow its pinned version (`rust:tokio@1.35`)
+    let pinned = pin_crate_version(path, crate_name);
+    let path = pinned.as_str();
+
+    if args.source {
+        return handle_rust_source(context, crate_name, path).await;
+    }
+
+    // The Cargo Book is addressed by page (`reference/profiles.html#lto`) or topic name
+    if active.identifier == query::CARGO_BOOK_TECHNOLOGY {
+        let topic = multi_provider_client::rust::cargo_book::find_cargo_topic(path)
+            .with_context(|| format!("No Cargo Book topic matches '{path}'"))?;
+        let section = context.providers.rust.get_cargo_section(&topic).await?;
+        return Ok(build_cargo_book_response(&topic, &section));
+    }
+
+    // Compiler error codes (`E0502`) have their own explanations
+    if let Some(code) = multi_provider_client::rust::find_error_code(path) {
+        if code.eq_ignore_ascii_case(path) {
+            let error = context.providers.rust.get_error_code(&code).await?;
+            return Ok(build_rust_error_code_response(&error));
+        }
+    }
+
     // Try to get the item documentation
     if let Ok(item) = context.providers.rust.get_item(path).await {
         return Ok(build_rust_response(&item));
@@ -494,6 +819,133 @@ async fn handle_rust(
     ))
 }
 
+fn build_cargo_book_response(
+    topic: &multi_provider_client::rust::CargoTopic,
+    section: &multi_provider_client::rust::CargoBookSection,
+) -> ToolResponse {
+    let lines = vec![
+        markdown::header(1, &section.title),
+        String::new(),
+        markdown::bold("Provider", "Cargo Book"),
+        markdown::bold("Path", &format!("`{}`", topic.path)),
+        String::new(),
+        section.content.clone(),
+        String::new(),
+        format!("**Documentation:** {}", section.url),
+    ];
+    let metadata = json!({
+        "provider": "rust",
+        "technology": query::CARGO_BOOK_TECHNOLOGY,
+        "path": topic.path,
+        "url": section.url,
+        "hasExample": section.example.is_some(),
+    });
+    text_response(lines).with_metadata(metadata)
+}
+
+fn build_rust_error_code_response(error: &multi_provider_client::rust::RustErrorCode) -> ToolResponse {
+    let lines = vec![
+        markdown::header(1, &format!("Error {}", error.code)),
+        String::new(),
+        markdown::bold("Provider", "Rust compiler error index"),
+        String::new(),
+        error.explanation.clone(),
+        String::new(),
+        format!("**Documentation:** {}", error.url),
+    ];
+    let metadata = json!({
+        "provider": "rust",
+        "errorCode": error.code,
+        "exampleCount": error.examples.len(),
+        "url": error.url,
+    });
+    text_response(lines).with_metadata(metadata)
+}
+
+/// The source code of a Rust item, looked up like its documentation.
+async fn handle_rust_source(
+    context: &Arc<AppContext>,
+    crate_name: &str,
+    path: &str,
+) -> Result<ToolResponse> {
+    let source = match context.providers.rust.get_source(path).await {
+        Ok(source) => source,
+        Err(error) => {
+            let results = context.providers.rust.search(crate_name, path).await.unwrap_or_default();
+            match results.first() {
+                Some(item) if item.path != path => context.providers.rust.get_source(&item.path).await?,
+                _ => return Err(error),
+            }
+        }
+    };
+    Ok(build_rust_source_response(&source))
+}
+
+fn build_rust_source_response(source: &multi_provider_client::rust::RustSource) -> ToolResponse {
+    let mut lines = vec![
+        markdown::header(1, &format!("Source: {}", source.path)),
+        String::new(),
+        markdown::bold("Crate", &format!("{} v{}", source.crate_name, source.crate_version)),
+        markdown::bold(
+            "File",
+            &format!("`{}` (lines {}-{})", source.file, source.start_line, source.end_line),
+        ),
+        String::new(),
+        format!("```rust\n{}\n```", source.code),
+    ];
+    if source.truncated {
+        lines.push(format!(
+            "*Listing cut off at line {}; the full item continues in the source file.*",
+            source.end_line
+        ));
+    }
+    lines.push(String::new());
+    lines.push(format!("**Source:** {}", source.url));
+
+    let metadata = json!({
+        "provider": "rust",
+        "source": true,
+        "crate": source.crate_name,
+        "version": source.crate_version,
+        "path": source.path,
+        "file": source.file,
+        "startLine": source.start_line,
+        "endLine": source.end_line,
+        "truncated": source.truncated,
+        "url": source.url,
+    });
+    text_response(lines).with_metadata(metadata)
+}
+
+/// `path` with its crate replaced by `spec` when `spec` pins a version of that same crate.
+fn pin_crate_version(path: &str, spec: &str) -> String {
+    let (name, version) = multi_provider_client::rust::split_crate_spec(spec);
+    if version.is_none() {
+        return path.to_string();
+    }
+    let rest = path
+        .strip_prefix(name)
+        .filter(|rest| rest.is_empty() || rest.starts_with("::") || rest.starts_with('/'));
+    match rest {
+        Some(rest) => format!("{spec}{rest}"),
+        None => path.to_string(),
+    }
+}
+
+/// `` `new` · `with_capacity` ``, with a count of the rest beyond `limit`.
+fn rust_method_names(methods: &[String], limit: usize) -> String {
+    let mut names = methods
+        .iter()
+        .take(limit)
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(" · ");
+    if methods.len() > limit {
+        names.push_str(&format!(" · +{} more", methods.len() - limit));
+    }
+    names
+}
+
 fn build_rust_response(item: &multi_provider_client::rust::RustItem) -> ToolResponse {
     let mut lines = vec![
         markdown::header(1, &item.name),
@@ -507,6 +959,17 @@ fn build_rust_response(item: &multi_provider_client::rust::RustItem) -> ToolResp
         lines.push(markdown::bold("Path", &format!("`{}`", item.path)));
     }
 
+    if !item.required_features.is_empty() {
+        lines.push(markdown::bold(
+            "Requires Cargo features",
+            &crate::tools::query::feature_list(&item.required_features),
+        ));
+    }
+
+    if !item.platforms.is_empty() {
+        lines.push(markdown::bold("Platforms", &format!("{} only", item.platforms.join(" or "))));
+    }
+
     // Declaration/Signature
     if let Some(decl) = &item.declaration {
         lines.push(String::new());
@@ -589,6 +1052,38 @@ fn build_rust_response(item: &multi_provider_client::rust::RustItem) -> ToolResp
         }
     }
 
+    // Inherent impl blocks
+    let inherent: Vec<_> = item
+        .impl_blocks
+        .iter()
+        .filter(|block| block.trait_name.is_none())
+        .collect();
+    if !inherent.is_empty() {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Implementations"));
+        for block in inherent.iter().take(10) {
+            lines.push(format!("• `{}`", trim_with_ellipsis(&block.header, 100)));
+            if !block.methods.is_empty() {
+                lines.push(format!("  {}", rust_method_names(&block.methods, 12)));
+            }
+        }
+    }
+
+    // Implementors (for traits)
+    if !item.implementors.is_empty() {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Implementors"));
+        for block in item.implementors.iter().take(MAX_RUST_IMPLEMENTORS) {
+            lines.push(format!("• `{}`", block.self_type));
+        }
+        if item.implementors.len() > MAX_RUST_IMPLEMENTORS {
+            lines.push(format!(
+                "*... and {} more implementors*",
+                item.implementors.len() - MAX_RUST_IMPLEMENTORS
+            ));
+        }
+    }
+
     // Trait Implementations
     if !item.impl_traits.is_empty() {
         lines.push(String::new());
@@ -638,12 +1133,22 @@ fn build_rust_response(item: &multi_provider_client::rust::RustItem) -> ToolResp
         "crate": item.crate_name,
         "version": item.crate_version,
         "path": item.path,
+        "requiredFeatures": item.required_features,
+        "platforms": item.platforms,
         "isDetailed": item.is_detailed,
         "hasDeclaration": item.declaration.is_some(),
         "hasDocumentation": item.documentation.is_some(),
         "exampleCount": item.examples.len(),
         "methodCount": item.methods.len(),
         "traitImplCount": item.impl_traits.len(),
+        "implBlockCount": item.impl_blocks.len(),
+        "implementorCount": item.implementors.len(),
+        "implementors": item
+            .implementors
+            .iter()
+            .take(MAX_RUST_IMPLEMENTORS)
+            .map(|block| &block.self_type)
+            .collect::<Vec<_>>(),
         "associatedTypeCount": item.associated_types.len(),
     });
 
@@ -701,6 +1206,7 @@ fn normalize_path(path: &str, identifier: &str) -> String {
     if without_prefix.starts_with("design/")
         || without_prefix.starts_with("Design/")
         || without_prefix.starts_with("human-interface-guidelines/")
+        || without_prefix.starts_with("tutorials/")
     {
         return without_prefix.to_ascii_lowercase();
     }
@@ -724,6 +1230,7 @@ fn fallback_path(path: &str) -> String {
     if trimmed.starts_with("design/")
         || trimmed.starts_with("Design/")
         || trimmed.starts_with("human-interface-guidelines/")
+        || trimmed.starts_with("tutorials/")
     {
         trimmed.to_ascii_lowercase()
     } else if trimmed.starts_with("documentation/") {
@@ -733,6 +1240,93 @@ fn fallback_path(path: &str) -> String {
     }
 }
 
+fn build_tutorial_response(path: &str, tutorial: &TutorialPage) -> RenderOutput {
+    let title = if tutorial.title.is_empty() { path } else { &tutorial.title };
+    let mut lines = vec![
+        markdown::header(1, title),
+        String::new(),
+        markdown::bold(
+            "Type",
+            if tutorial.kind == "overview" { "Tutorial collection" } else { "Tutorial" },
+        ),
+    ];
+    if let Some(chapter) = &tutorial.chapter {
+        lines.push(markdown::bold("Chapter", chapter));
+    }
+    if let Some(minutes) = tutorial.estimated_minutes {
+        lines.push(markdown::bold("Estimated time", &format!("{minutes} min")));
+    }
+    lines.push(String::new());
+    lines.extend(tutorial_body(tutorial));
+
+    let metadata = json!({
+        "kind": "tutorial",
+        "tutorialKind": tutorial.kind,
+        "path": path,
+        "sectionCount": tutorial.sections.len(),
+        "stepCount": tutorial.step_count(),
+        "chapterCount": tutorial.chapters.len(),
+        "estimatedMinutes": tutorial.estimated_minutes,
+    });
+    RenderOutput { lines, metadata }
+}
+
+/// Intro, sections with numbered steps and their code changes, or a collection's chapters.
+pub(crate) fn tutorial_body(tutorial: &TutorialPage) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !tutorial.intro.is_empty() {
+        lines.push(tutorial.intro.clone());
+    }
+
+    for (number, section) in tutorial.sections.iter().enumerate() {
+        lines.push(String::new());
+        lines.push(markdown::header(2, &format!("Section {}: {}", number + 1, section.title)));
+        if !section.overview.is_empty() {
+            lines.push(section.overview.clone());
+        }
+        for (step_number, step) in section.steps.iter().enumerate() {
+            lines.push(String::new());
+            lines.push(format!("{}. {}", step_number + 1, step.text));
+            if let Some(caption) = &step.caption {
+                lines.push(format!("   _{caption}_"));
+            }
+            if let Some(code) = &step.code {
+                let diff = code.diff(2);
+                let mut diff_lines: Vec<&str> = diff.lines().collect();
+                let hidden = diff_lines.len().saturating_sub(MAX_TUTORIAL_DIFF_LINES);
+                diff_lines.truncate(MAX_TUTORIAL_DIFF_LINES);
+                lines.push(format!("   `{}`", code.file_name));
+                lines.push(format!("```diff\n{}\n```", diff_lines.join("\n")));
+                if hidden > 0 {
+                    lines.push(format!("   *... and {hidden} more lines*"));
+                }
+            }
+        }
+    }
+
+    for chapter in &tutorial.chapters {
+        lines.push(String::new());
+        lines.push(markdown::header(2, &chapter.name));
+        if !chapter.summary.is_empty() {
+            lines.push(chapter.summary.clone());
+        }
+        for link in &chapter.tutorials {
+            let time = link
+                .estimated_time
+                .as_ref()
+                .map(|time| format!(" ({time})"))
+                .unwrap_or_default();
+            lines.push(format!(
+                "• **{}**{time} — {} (`get_documentation {{ \"path\": \"{}\" }}`)",
+                link.title,
+                trim_with_ellipsis(&link.summary, 100),
+                link.path
+            ));
+        }
+    }
+    lines
+}
+
 fn build_topic_response(
     technology_title: &str,
     path: &str,
@@ -861,6 +1455,7 @@ fn build_topic_response(
 fn build_symbol_response(
     technology_title: &str,
     symbol: &SymbolData,
+    language: SymbolLanguage,
     design_sections: &[design_guidance::DesignSection],
 ) -> RenderOutput {
     let title = symbol
@@ -877,8 +1472,10 @@ fn build_symbol_response(
     let description = extract_text(&symbol.r#abstract);
     let knowledge_entry = knowledge::lookup(technology_title, &title);
     let quick_tip = knowledge_entry.and_then(|entry| entry.quick_tip);
+    // Curated snippets are Swift; don't pair them with another language's declaration
     let snippet_from_knowledge =
         knowledge_entry
+            .filter(|_| language == SymbolLanguage::Swift)
             .and_then(knowledge::snippet)
             .map(|snippet| CodeSnippet {
                 language: snippet.language.to_string(),
@@ -887,6 +1484,7 @@ fn build_symbol_response(
             });
     let snippet = snippet_from_knowledge.or_else(|| extract_symbol_snippet(symbol));
     let relationships = extract_relationships(symbol);
+    let relationship_groups = extract_relationship_groups(symbol);
     let parameters = extract_parameters(symbol);
     let summary = build_symbol_summary(
         symbol,
@@ -909,6 +1507,16 @@ fn build_symbol_response(
         markdown::bold("Type", &kind),
         markdown::bold("Platforms", &platforms),
     ];
+    if language != SymbolLanguage::Swift {
+        lines.push(markdown::bold("Language", language.name()));
+    }
+
+    let declaration = query::extract_declaration(symbol);
+    if let Some(declaration) = &declaration {
+        lines.push(String::new());
+        lines.push(markdown::header(2, "Declaration"));
+        lines.push(format!("```{}\n{}\n```", language.code_fence(), declaration.trim_end()));
+    }
 
     if !summary.is_empty() {
         lines.push(String::new());
@@ -967,6 +1575,12 @@ fn build_symbol_response(
                 ));
             }
         }
+        let pitfalls = knowledge::pitfalls(entry);
+        if !pitfalls.is_empty() {
+            lines.push(String::new());
+            lines.push(markdown::header(2, "Pitfalls"));
+            lines.extend(pitfalls.iter().map(|pitfall| format!("• {pitfall}")));
+        }
         true
     } else {
         false
@@ -1010,14 +1624,25 @@ fn build_symbol_response(
         }
     }
 
+    for group in &relationship_groups {
+        lines.push(String::new());
+        lines.push(markdown::header(2, &group.title));
+        for rel in group.entries.iter().take(MAX_RELATIONSHIP_ENTRIES) {
+            lines.push(relationship_line(rel));
+        }
+        if group.total > MAX_RELATIONSHIP_ENTRIES {
+            lines.push(format!(
+                "*... and {} more*",
+                group.total - MAX_RELATIONSHIP_ENTRIES
+            ));
+        }
+    }
+
     if !relationships.is_empty() {
         lines.push(String::new());
         lines.push(markdown::header(2, "Relationships"));
         for rel in &relationships {
-            lines.push(format!(
-                "• **{}** — {} (`get_documentation {{ \"path\": \"{}\" }}`)",
-                rel.title, rel.summary, rel.path
-            ));
+            lines.push(relationship_line(rel));
         }
     }
 
@@ -1035,14 +1660,26 @@ fn build_symbol_response(
 
     let metadata = json!({
         "kind": "symbol",
+        "language": language.code_fence(),
+        "hasDeclaration": declaration.is_some(),
         "designSections": design_sections.len(),
         "topicSections": symbol.topic_sections.len(),
         "hasSnippet": snippet.is_some(),
         "hasKnowledge": has_knowledge,
         "hasQuickTip": quick_tip.is_some(),
+        "pitfallCount": knowledge_entry.map_or(0, |entry| knowledge::pitfalls(entry).len()),
         "platformCount": symbol.metadata.platforms.len(),
         "sampleReferences": count_symbol_sample_references(symbol),
         "relationshipCount": relationships.len(),
+        "relationshipSections": relationship_groups
+            .iter()
+            .map(|group| json!({ "type": group.kind, "title": group.title, "count": group.total }))
+            .collect::<Vec<_>>(),
+        "conformingTypeCount": relationship_groups
+            .iter()
+            .filter(|group| group.kind == "conformingTypes")
+            .map(|group| group.total)
+            .sum::<usize>(),
         "parameterCount": parameters.len(),
         "summaryCount": summary_count,
         "hasSampleSummary": has_sample_summary,
@@ -1051,6 +1688,22 @@ fn build_symbol_response(
     RenderOutput { lines, metadata }
 }
 
+fn relationship_line(rel: &RelationshipEntry) -> String {
+    if rel.summary.is_empty() {
+        format!(
+            "• **{}** (`get_documentation {{ \"path\": \"{}\" }}`)",
+            rel.title, rel.path
+        )
+    } else {
+        format!(
+            "• **{}** — {} (`get_documentation {{ \"path\": \"{}\" }}`)",
+            rel.title,
+            trim_with_ellipsis(&rel.summary, 100),
+            rel.path
+        )
+    }
+}
+
 fn trim_with_ellipsis(text: &str, max: usize) -> String {
     if text.len() <= max {
         text.to_string()
@@ -1125,6 +1778,24 @@ fn build_symbol_summary(
         summary.push(format!("• Related types: {highlights}"));
     }
 
+    if let Some(conforming) = symbol
+        .relationships_sections
+        .iter()
+        .find(|section| section.kind == "conformingTypes" && !section.identifiers.is_empty())
+    {
+        let highlights = conforming
+            .identifiers
+            .iter()
+            .filter_map(|identifier| symbol.references.get(identifier)?.title.clone())
+            .take(3)
+            .collect::<Vec<_>>()
+            .join(" · ");
+        summary.push(format!(
+            "• Conforming types ({}): {highlights}",
+            conforming.identifiers.len()
+        ));
+    }
+
     if !parameters.is_empty() {
         let highlights = parameters
             .iter()
@@ -1436,6 +2107,72 @@ struct RelationshipEntry {
     summary: String,
 }
 
+/// Entries listed per relationship group; protocols like `View` have hundreds of conformers.
+const MAX_RELATIONSHIP_ENTRIES: usize = 20;
+
+/// A "Conforms To", "Conforming Types", "Inherits From" or default-implementations group.
+struct RelationshipGroup {
+    kind: String,
+    title: String,
+    entries: Vec<RelationshipEntry>,
+    /// Identifiers in the section, including ones without a resolvable reference.
+    total: usize,
+}
+
+fn extract_relationship_groups(symbol: &SymbolData) -> Vec<RelationshipGroup> {
+    let relationships = symbol
+        .relationships_sections
+        .iter()
+        .map(|section| (section.kind.as_str(), &section.title, &section.identifiers));
+    // Protocols list their extension defaults as a topic section; requirements carry
+    // `defaultImplementationsSections` instead.
+    let defaults = symbol
+        .default_implementations_sections
+        .iter()
+        .chain(
+            symbol
+                .topic_sections
+                .iter()
+                .filter(|section| section.title.eq_ignore_ascii_case("Default Implementations")),
+        )
+        .map(|section| ("defaultImplementations", &section.title, &section.identifiers));
+    relationships
+        .chain(defaults)
+        .filter(|(_, _, identifiers)| !identifiers.is_empty())
+        .map(|(kind, title, identifiers)| RelationshipGroup {
+            kind: kind.to_string(),
+            title: title.clone(),
+            entries: identifiers
+                .iter()
+                .filter_map(|identifier| relationship_entry(&symbol.references, identifier))
+                .collect(),
+            total: identifiers.len(),
+        })
+        .collect()
+}
+
+fn relationship_entry(
+    references: &HashMap<String, ReferenceData>,
+    identifier: &str,
+) -> Option<RelationshipEntry> {
+    let reference = references.get(identifier)?;
+    Some(RelationshipEntry {
+        title: reference
+            .title
+            .clone()
+            .unwrap_or_else(|| identifier.to_string()),
+        path: reference
+            .url
+            .clone()
+            .unwrap_or_else(|| identifier.to_string()),
+        summary: reference
+            .r#abstract
+            .as_ref()
+            .map(|segments| extract_text(segments))
+            .unwrap_or_default(),
+    })
+}
+
 #[derive(Clone)]
 struct ParameterEntry {
     name: String,
@@ -1631,6 +2368,216 @@ mod tests {
     use serde_json::json;
     use std::collections::HashMap;
 
+    #[test]
+    fn rust_traits_list_implementors() {
+        use multi_provider_client::rust::{RustImplBlock, RustItem, RustItemKind};
+
+        let mut item = RustItem::empty("Deserialize", "serde");
+        item.kind = RustItemKind::Trait;
+        item.implementors = ["bool", "String", "Vec<T>"]
+            .iter()
+            .map(|ty| RustImplBlock {
+                header: format!("impl<'de> Deserialize<'de> for {ty}"),
+                trait_name: Some("Deserialize<'de>".to_string()),
+                self_type: ty.to_string(),
+                methods: vec!["deserialize".to_string()],
+            })
+            .collect();
+        let response = build_rust_response(&item);
+        let text = &response.content[0].text;
+        assert!(text.contains("## Implementors\n• `bool`\n• `String`\n• `Vec<T>`"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["implementorCount"], 3);
+        assert_eq!(metadata["implementors"][2], "Vec<T>");
+    }
+
+    #[test]
+    fn rust_source_is_rendered_as_a_listing() {
+        use multi_provider_client::rust::{RustItem, RustSource};
+
+        let mut item = RustItem::empty("push", "alloc");
+        item.path = "std::vec::Vec::push".to_string();
+        let lines: Vec<String> = ["pub fn push(&mut self, value: T) {", "    // ...", "}"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        let url = "https://doc.rust-lang.org/src/alloc/vec/mod.rs.html#1-3";
+        let source = RustSource::from_lines(&item, url, &lines, 1, 3, 2);
+        let response = build_rust_source_response(&source);
+        let text = &response.content[0].text;
+        assert!(text.contains("**File:** `alloc/vec/mod.rs` (lines 1-2)"));
+        assert!(text.contains("```rust\npub fn push(&mut self, value: T) {\n    // ...\n```"));
+        assert!(text.contains("Listing cut off at line 2"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["truncated"], true);
+    }
+
+    #[test]
+    fn rust_paths_follow_the_pinned_crate_version() {
+        assert_eq!(pin_crate_version("tokio::sync::mpsc", "tokio@1.35"), "tokio@1.35::sync::mpsc");
+        assert_eq!(pin_crate_version("tokio/sync", "tokio@1.35"), "tokio@1.35/sync");
+        assert_eq!(pin_crate_version("tokio_util::codec", "tokio@1.35"), "tokio_util::codec");
+        assert_eq!(pin_crate_version("tokio::spawn", "tokio"), "tokio::spawn");
+    }
+
+    #[test]
+    fn mdn_response_renders_article() {
+        let article: MdnArticle = serde_json::from_value(json!({
+            "slug": "Web/API/Window/fetch",
+            "title": "Window: fetch() method",
+            "summary": "Starts fetching a resource from the network.",
+            "category": "WebApi",
+            "url": "https://developer.mozilla.org/en-US/docs/Web/API/Window/fetch",
+            "examples": [
+                {"code": "fetch(url)", "language": "js", "description": null, "is_runnable": false},
+                {"code": "const response = await fetch(url);", "language": "js", "description": null, "is_runnable": true}
+            ],
+            "syntax": "fetch(resource, options)",
+            "parameters": [{"name": "options", "description": "Request settings.", "param_type": null, "optional": true}],
+            "return_value": "A Promise that resolves to a Response object.",
+            "browser_compat": null,
+            "content": null
+        }))
+        .unwrap();
+
+        let response = build_mdn_response(&article);
+        let text = &response.content[0].text;
+        assert!(text.contains("**Provider:** MDN Web Docs (Web API)"), "{text}");
+        assert!(text.contains("• **options** (optional) — Request settings."));
+        assert!(text.contains("```js\nconst response = await fetch(url);\n```"));
+        assert!(text.contains("**Documentation:** https://developer.mozilla.org/en-US/docs/Web/API/Window/fetch"));
+        assert_eq!(response.metadata.unwrap()["parameterCount"], 1);
+    }
+
+    #[test]
+    fn resolves_framework_from_paths_and_urls() {
+        assert_eq!(
+            strip_web_prefix("https://developer.apple.com/documentation/swiftui/navigationstack"),
+            "/documentation/swiftui/navigationstack"
+        );
+        assert_eq!(path_framework("documentation/swiftui/navigationstack"), Some("swiftui"));
+        assert_eq!(path_framework("/documentation/uikit"), Some("uikit"));
+        assert_eq!(
+            path_framework("doc://com.apple.documentation/documentation/appkit/nsview"),
+            Some("appkit")
+        );
+        assert_eq!(path_framework("design/human-interface-guidelines/buttons"), None);
+        assert_eq!(path_framework("Button"), None);
+    }
+
+    #[test]
+    fn symbol_response_renders_objective_c_declarations() {
+        let mut document = json!({
+            "abstract": [{"type": "text", "text": "Initializes a view with a frame."}],
+            "metadata": {"title": "init(frame:)", "symbolKind": "init", "platforms": []},
+            "references": {},
+            "primaryContentSections": [{
+                "kind": "declarations",
+                "declarations": [{"languages": ["swift"], "tokens": [{"text": "init(frame: CGRect)"}]}]
+            }],
+            "variantOverrides": [{
+                "traits": [{"interfaceLanguage": "occ"}],
+                "patch": [
+                    {"op": "replace", "path": "/metadata/title", "value": "initWithFrame:"},
+                    {
+                        "op": "replace",
+                        "path": "/primaryContentSections/0/declarations/0",
+                        "value": {"languages": ["occ"], "tokens": [{"text": "- (instancetype)initWithFrame:(CGRect)frame;"}]}
+                    }
+                ]
+            }]
+        });
+        apply_language_variant(&mut document, SymbolLanguage::ObjectiveC);
+        let symbol: SymbolData = serde_json::from_value(document).unwrap();
+
+        let render = build_symbol_response("UIKit", &symbol, SymbolLanguage::ObjectiveC, &[]);
+        let text = render.lines.join("\n");
+        assert!(text.starts_with("# initWithFrame:"));
+        assert!(text.contains("**Language:** Objective-C"));
+        assert!(text.contains("```objc\n- (instancetype)initWithFrame:(CGRect)frame;\n```"));
+        assert_eq!(render.metadata["language"], "objc");
+    }
+
+    #[test]
+    fn symbol_response_lists_conforming_types_and_default_implementations() {
+        let symbol: SymbolData = serde_json::from_value(json!({
+            "abstract": [{"type": "text", "text": "A type that represents part of your app's UI."}],
+            "metadata": {"title": "View", "symbolKind": "protocol", "platforms": []},
+            "primaryContentSections": [],
+            "references": {
+                "doc://swiftui/Text": {
+                    "title": "Text",
+                    "url": "/documentation/swiftui/text",
+                    "abstract": [{"type": "text", "text": "A view that displays text."}]
+                },
+                "doc://swiftui/Button": {"title": "Button", "url": "/documentation/swiftui/button"},
+                "doc://swiftui/View-Implementations": {
+                    "title": "View Implementations",
+                    "url": "/documentation/swiftui/view-implementations"
+                }
+            },
+            "topicSections": [{
+                "title": "Default Implementations",
+                "identifiers": ["doc://swiftui/View-Implementations"]
+            }],
+            "relationshipsSections": [
+                {
+                    "type": "conformingTypes",
+                    "title": "Conforming Types",
+                    "kind": "relationships",
+                    "identifiers": ["doc://swiftui/Text", "doc://swiftui/Button", "doc://external/Missing"]
+                },
+                {"type": "inheritedBy", "title": "Inherited By", "identifiers": []}
+            ]
+        }))
+        .unwrap();
+
+        let render = build_symbol_response("SwiftUI", &symbol, SymbolLanguage::Swift, &[]);
+        let text = render.lines.join("\n");
+        assert!(text.contains("## Conforming Types\n• **Text** — A view that displays text."));
+        assert!(text.contains("• **Button** (`get_documentation { \"path\": \"/documentation/swiftui/button\" }`)"));
+        assert!(text.contains("## Default Implementations\n• **View Implementations**"));
+        assert!(!text.contains("Inherited By"));
+        assert!(text.contains("• Conforming types (3): Text · Button"));
+        assert_eq!(render.metadata["conformingTypeCount"], 3);
+        assert_eq!(render.metadata["relationshipSections"][1]["type"], "defaultImplementations");
+    }
+
+    #[test]
+    fn tutorial_response_numbers_steps_and_shows_code_changes() {
+        let tutorial = TutorialPage::from_value(&json!({
+            "kind": "project",
+            "metadata": {"title": "Creating and combining views", "role": "project"},
+            "sections": [
+                {"kind": "hero", "chapter": "SwiftUI essentials", "estimatedTimeInMinutes": 40, "content": []},
+                {"kind": "tasks", "tasks": [{
+                    "title": "Customize the text view",
+                    "stepsSection": [{
+                        "type": "step",
+                        "content": [{"type": "paragraph", "inlineContent": [{"type": "text", "text": "Change the font."}]}],
+                        "code": "step.swift"
+                    }]
+                }]}
+            ],
+            "references": {"step.swift": {
+                "fileName": "ContentView.swift",
+                "syntax": "swift",
+                "content": ["Text(\"Turtle Rock\")", "    .font(.title)"],
+                "highlights": [{"line": 2}]
+            }}
+        }))
+        .unwrap();
+
+        let render =
+            build_tutorial_response("tutorials/swiftui/creating-and-combining-views", &tutorial);
+        let text = render.lines.join("\n");
+        assert!(text.contains("**Chapter:** SwiftUI essentials"));
+        assert!(text.contains("## Section 1: Customize the text view\n\n1. Change the font."));
+        assert!(text.contains("```diff\n@@ line 1 @@\n Text(\"Turtle Rock\")\n+    .font(.title)\n```"));
+        assert_eq!(render.metadata["stepCount"], 1);
+        assert_eq!(render.metadata["kind"], "tutorial");
+    }
+
     fn sample_symbol() -> SymbolData {
         let mut references = HashMap::new();
         references.insert(
@@ -1679,6 +2626,8 @@ mod tests {
                     title: "Configure Appearance".to_string(),
                 },
             ],
+            relationships_sections: Vec::new(),
+            default_implementations_sections: Vec::new(),
         }
     }
 
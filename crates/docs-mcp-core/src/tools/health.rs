@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::services::cache_maintenance::parse_providers;
+use crate::services::health::check_health;
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{parse_args, text_response, wrap_handler};
+
+#[derive(Debug, Deserialize)]
+struct Args {
+    #[serde(default)]
+    providers: Vec<String>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "health".to_string(),
+        description: "Maintenance: check that the cache directories are writable and that each documentation provider is reachable. Reports ok/degraded/unhealthy with per-provider details.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "providers": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Only probe these providers (e.g. 'apple', 'rust'); all by default."
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![json!({}), json!({"providers": ["apple", "rust"]})]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, value: serde_json::Value) -> Result<ToolResponse> {
+    let args: Args = parse_args(value)?;
+    let report = check_health(&context, &parse_providers(&args.providers)?).await;
+    Ok(text_response(report.summary_lines()).with_metadata(serde_json::to_value(&report)?))
+}
@@ -71,11 +71,12 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
                 .await
                 .clone()
                 .map(|t| t.title)
-                .context("No technology selected. Use `choose_technology` before requesting a recipe.")?
+                .context("No technology selected. Run `query` for the technology before requesting a recipe.")?
         }
         ProviderType::Telegram | ProviderType::TON | ProviderType::Cocoon | ProviderType::Rust
         | ProviderType::Mdn | ProviderType::WebFrameworks | ProviderType::Mlx | ProviderType::HuggingFace
-        | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Vertcoin | ProviderType::Cuda => {
+        | ProviderType::QuickNode | ProviderType::ClaudeAgentSdk | ProviderType::Bitcoind | ProviderType::Cuda
+        | ProviderType::Solana => {
             context
                 .state
                 .active_unified_technology
@@ -83,7 +84,7 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
                 .await
                 .clone()
                 .map(|t| t.title)
-                .context("No technology selected. Use `choose_technology` before requesting a recipe.")?
+                .context("No technology selected. Run `query` for the technology before requesting a recipe.")?
         }
     };
     let task_trimmed = args.task.trim().to_string();
@@ -178,7 +179,7 @@ async fn build_fallback_recipe(
                 .unwrap_or(false)
         })
         .collect();
-    related_queries.sort_by(|a, b| b.matches.cmp(&a.matches));
+    related_queries.sort_by_key(|entry| std::cmp::Reverse(entry.matches));
     let top_query = related_queries.first()?;
 
     let knowledge_matches = knowledge::lookup(technology, task)
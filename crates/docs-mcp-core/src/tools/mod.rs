@@ -1,23 +1,96 @@
 use std::sync::Arc;
 
 use anyhow::{anyhow, Result};
+use docs_mcp_client::stale::{self, StaleNote, STALE_REASON};
+use serde_json::json;
+use tracing::warn;
 
 use crate::state::{AppContext, ToolContent, ToolEntry, ToolHandler, ToolResponse};
 
+mod batch_documentation;
+mod batch_query;
+mod cache_stats;
+mod changelog;
+mod choose_technology;
 mod current_technology;
+mod design_guidance;
 mod discover;
+mod doc_url;
 mod get_documentation;
+mod health;
+mod how_do_i;
+mod prune_cache;
 mod query;
+mod refresh_spec;
+mod reset_state;
+mod sdk_changelog;
 mod search_symbols;
+mod server_info;
 mod submit_feedback;
+mod telegram_changelog;
+pub(crate) mod telemetry_stats;
 
+/// Register the tools selected by the server config.
+///
+/// `enabled_tools` replaces the default set (which includes the technology tools only when
+/// `technology_tools` is set, and never the admin tools that delete data, such as
+/// `prune_cache`); `disabled_tools` then removes tools from whichever set applies.
 pub async fn register_tools(context: Arc<AppContext>) {
-    // Register only the unified query tool
-    // Other tools are kept in the codebase for reference but not exposed via MCP
-    let tools = [
+    let defaults = vec![
         query::definition(),
+        batch_query::definition(),
+        batch_documentation::definition(),
+        how_do_i::definition(),
+        design_guidance::definition(),
+        telegram_changelog::definition(),
+        sdk_changelog::definition(),
+        search_symbols::definition(),
         submit_feedback::definition(),
+        refresh_spec::definition(),
+        cache_stats::definition(),
+        telemetry_stats::definition(),
+        health::definition(),
+        server_info::definition(),
+        reset_state::definition(),
     ];
+    let technology_tools = [
+        discover::definition(),
+        choose_technology::definition(),
+        current_technology::definition(),
+    ];
+    let admin_tools = [prune_cache::definition()];
+
+    let config = &context.config;
+    let known: Vec<String> = defaults
+        .iter()
+        .chain(&technology_tools)
+        .chain(&admin_tools)
+        .map(|(definition, _)| definition.name.clone())
+        .collect();
+    for name in config.enabled_tools.iter().flatten().chain(&config.disabled_tools) {
+        if !known.contains(name) {
+            warn!(target: "docs_mcp_core", tool = %name, "ignoring unknown tool in tool configuration");
+        }
+    }
+
+    let tools = defaults
+        .into_iter()
+        .map(|tool| (tool, true))
+        .chain(
+            technology_tools
+                .into_iter()
+                .map(|tool| (tool, config.technology_tools)),
+        )
+        .chain(admin_tools.into_iter().map(|tool| (tool, false)))
+        .filter(|((definition, _), default)| {
+            let name = &definition.name;
+            let enabled = match &config.enabled_tools {
+                Some(names) => names.contains(name),
+                None => *default,
+            };
+            enabled && !config.disabled_tools.contains(name)
+        })
+        .map(|(tool, _)| tool);
 
     let registry = context.tools.clone();
 
@@ -35,6 +108,7 @@ pub(crate) fn text_response(lines: impl IntoIterator<Item = String>) -> ToolResp
         content: vec![ToolContent {
             r#type: "text".to_string(),
             text: lines.into_iter().collect::<Vec<_>>().join("\n"),
+            resource: None,
         }],
         metadata: None,
     }
@@ -48,15 +122,120 @@ where
     Arc::new(move |context, value| {
         let ctx = context.clone();
         let fut = handler(ctx, value);
-        Box::pin(fut)
+        Box::pin(async move {
+            let (result, notes) = stale::track(fut).await;
+            result.map(|response| mark_stale(response, &notes))
+        })
     })
 }
 
+/// Flag responses built from cached payloads that could not be refreshed.
+fn mark_stale(mut response: ToolResponse, notes: &[StaleNote]) -> ToolResponse {
+    if notes.is_empty() {
+        return response;
+    }
+    let metadata = response.metadata.get_or_insert_with(|| json!({}));
+    if let Some(object) = metadata.as_object_mut() {
+        object.insert("stale".to_string(), json!(true));
+        object.insert("staleReason".to_string(), json!(STALE_REASON));
+        object.insert("staleResources".to_string(), json!(notes));
+    }
+    response
+}
+
 pub(crate) fn parse_args<T: serde::de::DeserializeOwned>(value: serde_json::Value) -> Result<T> {
     serde_json::from_value(value).map_err(|error| anyhow!("invalid arguments: {error}"))
 }
 
 pub use current_technology::definition as current_technology_definition;
 pub use discover::definition as discover_technologies_definition;
-pub use get_documentation::definition as get_documentation_definition;
+pub use get_documentation::{definition as get_documentation_definition, fetch_document};
+pub use query::RESULT_FIELDS as QUERY_RESULT_FIELDS;
 pub use search_symbols::definition as search_symbols_definition;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServerConfig;
+    use docs_mcp_client::AppleDocsClient;
+
+    #[tokio::test]
+    async fn technology_tools_are_opt_in() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        register_tools(context.clone()).await;
+        assert!(context.tools.get("query").await.is_some());
+        assert!(context.tools.get("choose_technology").await.is_none());
+
+        let config = ServerConfig {
+            technology_tools: true,
+            ..Default::default()
+        };
+        let context = Arc::new(AppContext::with_config(AppleDocsClient::new(), config));
+        register_tools(context.clone()).await;
+        for name in ["discover_technologies", "choose_technology", "current_technology"] {
+            assert!(context.tools.get(name).await.is_some(), "{name} not registered");
+        }
+    }
+
+    #[tokio::test]
+    async fn tool_configuration_selects_tools() {
+        let config = ServerConfig {
+            enabled_tools: Some(vec!["query".to_string(), "choose_technology".to_string()]),
+            ..Default::default()
+        };
+        let context = Arc::new(AppContext::with_config(AppleDocsClient::new(), config));
+        register_tools(context.clone()).await;
+        let mut names: Vec<String> = context
+            .tools
+            .definitions()
+            .await
+            .into_iter()
+            .map(|definition| definition.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["choose_technology", "query"]);
+
+        let config = ServerConfig {
+            disabled_tools: vec!["health".to_string(), "submit_feedback".to_string()],
+            ..Default::default()
+        };
+        let context = Arc::new(AppContext::with_config(AppleDocsClient::new(), config));
+        register_tools(context.clone()).await;
+        assert!(context.tools.get("query").await.is_some());
+        assert!(context.tools.get("health").await.is_none());
+        assert!(context.tools.get("submit_feedback").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn admin_tools_need_enabled_tools() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        register_tools(context.clone()).await;
+        assert!(context.tools.get("prune_cache").await.is_none());
+
+        let config = ServerConfig {
+            enabled_tools: Some(vec!["query".to_string(), "prune_cache".to_string()]),
+            ..Default::default()
+        };
+        let context = Arc::new(AppContext::with_config(AppleDocsClient::new(), config));
+        register_tools(context.clone()).await;
+        assert!(context.tools.get("prune_cache").await.is_some());
+    }
+
+    #[test]
+    fn stale_notes_are_added_to_metadata() {
+        let fresh = mark_stale(text_response(["ok".to_string()]), &[]);
+        assert!(fresh.metadata.is_none());
+
+        let note = StaleNote {
+            resource: "documentation/swiftui.json".to_string(),
+            error: "connection reset".to_string(),
+        };
+        let mut response = text_response(["ok".to_string()]);
+        response.metadata = Some(json!({"provider": "apple"}));
+        let metadata = mark_stale(response, &[note]).metadata.unwrap();
+        assert_eq!(metadata["provider"], "apple");
+        assert_eq!(metadata["stale"], true);
+        assert_eq!(metadata["staleReason"], STALE_REASON);
+        assert_eq!(metadata["staleResources"][0]["resource"], "documentation/swiftui.json");
+    }
+}
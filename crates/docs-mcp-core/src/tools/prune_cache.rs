@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::services::cache_maintenance::{parse_providers, prune_caches, CacheLocations, PruneOptions};
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{parse_args, text_response, wrap_handler};
+
+#[derive(Debug, Deserialize)]
+struct Args {
+    #[serde(rename = "olderThanDays")]
+    older_than_days: Option<u64>,
+    #[serde(default)]
+    providers: Vec<String>,
+    #[serde(default, rename = "dryRun")]
+    dry_run: bool,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "prune_cache".to_string(),
+        description: "Maintenance: delete cached documentation not used in N days and/or every cache entry for the given providers. Reports reclaimed bytes; use dryRun to preview.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "olderThanDays": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Remove entries not used for this many days."
+                },
+                "providers": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Remove all cached entries for these providers (e.g. 'rust', 'huggingface')."
+                },
+                "dryRun": {
+                    "type": "boolean",
+                    "description": "Report what would be removed without deleting anything."
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({"olderThanDays": 30}),
+            json!({"providers": ["cuda", "bitcoind"], "dryRun": true}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, value: serde_json::Value) -> Result<ToolResponse> {
+    let args: Args = parse_args(value)?;
+    let options = PruneOptions {
+        older_than_days: args.older_than_days,
+        providers: parse_providers(&args.providers)?,
+        dry_run: args.dry_run,
+    };
+
+    let report = prune_caches(&CacheLocations::for_client(&context.client), &options).await?;
+    Ok(text_response(report.summary_lines()).with_metadata(serde_json::to_value(&report)?))
+}
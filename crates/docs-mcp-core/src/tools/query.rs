@@ -7,38 +7,115 @@
 //! 4. Fetches detailed documentation for top matches
 //! 5. Returns structured context ready for AI consumption
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use docs_mcp_client::types::{
+    apply_language_variant, available_on, beta_only, has_beta, SymbolLanguage,
+};
+use multi_provider_client::claude_agent_sdk::changelog as agent_sdk_changelog;
+use multi_provider_client::huggingface::{model_reference, HfModelCard};
+use multi_provider_client::bitcoind::{method_path, parse_category_identifier as parse_bitcoind_category, BitcoindCoin};
+use multi_provider_client::mdn::{
+    browser_support_table, is_html_element_slug, MdnBrowserSupport, MdnCategory, MdnHtmlAttribute,
+    MdnMemberKind,
+};
+use multi_provider_client::rust::{cargo_book, find_error_code, is_cargo_query};
+use multi_provider_client::solana::SolanaDocSource;
+use multi_provider_client::quicknode::{category_title, parse_category_identifier, QuickNodeChain};
+use multi_provider_client::telegram::{MiniAppSection, MINI_APPS_TECHNOLOGY, TDLIB_TECHNOLOGY};
+use multi_provider_client::ton::{types::TonSearchResult, EXIT_CODES_TECHNOLOGY, TLB_TECHNOLOGY};
 use multi_provider_client::types::{ProviderType, UnifiedTechnology};
+use multi_provider_client::web_frameworks::types::{node_docs_base, NODE_RELEASE_LINES};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
     markdown,
-    services::{ensure_framework_index, knowledge},
-    state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
-    tools::{parse_args, text_response, wrap_handler},
+    services::{ensure_framework_index, intent_rules::{self, IntentRules}, knowledge, normalize_reference_link},
+    state::{AppContext, ToolContent, ToolDefinition, ToolHandler, ToolResponse},
+    tools::{doc_url, get_documentation, parse_args, text_response, wrap_handler},
+    ContentLimits,
 };
 
 /// Maximum number of search results to include in the response
 const MAX_SEARCH_RESULTS: usize = 10;
 /// Maximum number of detailed documentation entries to fetch (with full content)
 const MAX_DETAILED_DOCS: usize = 5;
-/// Maximum length for summaries in non-detailed results
-const MAX_SUMMARY_LENGTH: usize = 300;
-/// Maximum length for code samples
-const MAX_CODE_LENGTH: usize = 2000;
-/// Maximum length for full documentation content
-const MAX_CONTENT_LENGTH: usize = 4000;
+/// Implementing types named in a Rust trait result
+const MAX_LISTED_IMPLEMENTORS: usize = 12;
 
 #[derive(Debug, Deserialize)]
 struct Args {
     query: String,
     #[serde(rename = "maxResults")]
     max_results: Option<usize>,
+    /// Overrides for the server's truncation limits (0 disables truncation)
+    #[serde(rename = "maxSummaryLength")]
+    max_summary_length: Option<usize>,
+    #[serde(rename = "maxCodeLength")]
+    max_code_length: Option<usize>,
+    #[serde(rename = "maxContentLength")]
+    max_content_length: Option<usize>,
+    #[serde(default)]
+    format: OutputFormat,
+    /// Provider slug that replaces auto-detection (e.g. `rust`, `mdn`)
+    provider: Option<String>,
+    /// Technology within the provider (e.g. `swiftui`, `tokio`) that replaces auto-detection
+    technology: Option<String>,
+    /// Source language of Apple declarations: `swift` (default) or `objc`
+    language: Option<String>,
+    /// Only return Apple symbols available on this platform (e.g. `visionOS`), or Rust items
+    /// not cfg-gated away from it (e.g. `windows`)
+    platform: Option<String>,
+    /// `false` hides beta-only Apple APIs and ranks partially beta ones last
+    #[serde(rename = "includeBeta", default = "default_include_beta")]
+    include_beta: bool,
+}
+
+fn default_include_beta() -> bool {
+    true
+}
+
+/// Rendering style for the tool's text output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OutputFormat {
+    /// Markdown document with headers and fenced code (default)
+    #[default]
+    Markdown,
+    /// Machine-readable JSON document of the structured results
+    Json,
+    /// Plain text without markdown syntax
+    Plain,
+}
+
+impl Args {
+    /// Resolve truncation limits, preferring per-call overrides over server defaults
+    fn limits(&self, defaults: ContentLimits) -> ContentLimits {
+        ContentLimits {
+            summary: self.max_summary_length.unwrap_or(defaults.summary),
+            code: self.max_code_length.unwrap_or(defaults.code),
+            content: self.max_content_length.unwrap_or(defaults.content),
+        }
+    }
+
+    /// Formatting arguments that a documentation URL query cannot honour
+    fn url_mode_conflicts(&self) -> Vec<&'static str> {
+        [
+            ("format", self.format != OutputFormat::Markdown),
+            ("maxSummaryLength", self.max_summary_length.is_some()),
+            ("maxCodeLength", self.max_code_length.is_some()),
+            ("maxContentLength", self.max_content_length.is_some()),
+            ("language", self.language.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect()
+    }
 }
 
 /// Parsed intent from the user's query
@@ -54,6 +131,14 @@ struct QueryIntent {
     keywords: Vec<String>,
     /// Type of query (how-to, reference, search)
     query_type: QueryType,
+    /// Source language for Apple declarations and titles
+    language: SymbolLanguage,
+    /// Platform results must be available on (Apple availability, Rust cfg gates)
+    platform: Option<String>,
+    /// Keep beta-only Apple APIs in the results
+    include_beta: bool,
+    /// Another provider the query names, with the technology to search there
+    secondary: Option<(ProviderType, String)>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -66,82 +151,124 @@ enum QueryType {
     Search,
 }
 
+/// Every key a result in the `json` format can have; optional ones are left out when empty.
+pub const RESULT_FIELDS: &[&str] = &[
+    "title",
+    "kind",
+    "path",
+    "url",
+    "score",
+    "summary",
+    "platforms",
+    "codeSample",
+    "codeLanguage",
+    "relatedApis",
+    "fullContent",
+    "declaration",
+    "parameters",
+    "beta",
+    "features",
+    "browserSupport",
+    "provider",
+];
+
 /// Structured documentation result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 struct DocResult {
     title: String,
     kind: String,
     path: String,
+    /// Canonical public URL for citing the source
+    url: Option<String>,
+    /// Provider relevance score, when the provider exposes one
+    score: Option<f64>,
     summary: String,
     platforms: Option<String>,
     code_sample: Option<String>,
+    /// Language of the code sample as reported by the provider
+    code_language: Option<String>,
     related_apis: Vec<String>,
     /// Full documentation content (for detailed results)
     full_content: Option<String>,
     /// Declaration/signature
     declaration: Option<String>,
     /// Parameters or properties
+    #[serde(serialize_with = "serialize_parameters")]
     parameters: Vec<(String, String)>,
+    /// Only available in beta releases on at least one platform (Apple)
+    beta: bool,
+    /// Cargo features that must be enabled to use the item (Rust)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    features: Vec<String>,
+    /// Support in the major browsers (MDN)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    browser_support: Vec<MdnBrowserSupport>,
+    /// Provider the result came from, set when results of two providers are merged
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "serialize_provider")]
+    provider: Option<ProviderType>,
 }
 
-/// Technology detection patterns
-static APPLE_FRAMEWORKS: Lazy<Vec<(&'static str, &'static str)>> = Lazy::new(|| {
-    vec![
-        ("swiftui", "doc://com.apple.documentation/documentation/swiftui"),
-        ("uikit", "doc://com.apple.documentation/documentation/uikit"),
-        ("foundation", "doc://com.apple.documentation/documentation/foundation"),
-        ("combine", "doc://com.apple.documentation/documentation/combine"),
-        ("coredata", "doc://com.apple.documentation/documentation/coredata"),
-        ("cloudkit", "doc://com.apple.documentation/documentation/cloudkit"),
-        ("mapkit", "doc://com.apple.documentation/documentation/mapkit"),
-        ("avfoundation", "doc://com.apple.documentation/documentation/avfoundation"),
-        ("webkit", "doc://com.apple.documentation/documentation/webkit"),
-        ("corelocation", "doc://com.apple.documentation/documentation/corelocation"),
-        ("usernotifications", "doc://com.apple.documentation/documentation/usernotifications"),
-        ("swift", "doc://com.apple.documentation/documentation/swift"),
-        ("appkit", "doc://com.apple.documentation/documentation/appkit"),
-        ("realitykit", "doc://com.apple.documentation/documentation/realitykit"),
-        ("arkit", "doc://com.apple.documentation/documentation/arkit"),
-        ("metal", "doc://com.apple.documentation/documentation/metal"),
-        ("spritekit", "doc://com.apple.documentation/documentation/spritekit"),
-        ("scenekit", "doc://com.apple.documentation/documentation/scenekit"),
-        ("healthkit", "doc://com.apple.documentation/documentation/healthkit"),
-        ("storekit", "doc://com.apple.documentation/documentation/storekit"),
-        ("gamekit", "doc://com.apple.documentation/documentation/gamekit"),
-        ("passkit", "doc://com.apple.documentation/documentation/passkit"),
-        ("photokit", "doc://com.apple.documentation/documentation/photokit"),
-        ("musickit", "doc://com.apple.documentation/documentation/musickit"),
-        ("carplay", "doc://com.apple.documentation/documentation/carplay"),
-        ("widgetkit", "doc://com.apple.documentation/documentation/widgetkit"),
-        ("activitykit", "doc://com.apple.documentation/documentation/activitykit"),
-        ("appintents", "doc://com.apple.documentation/documentation/appintents"),
-        ("charts", "doc://com.apple.documentation/documentation/charts"),
-        ("observation", "doc://com.apple.documentation/documentation/observation"),
-        ("swiftdata", "doc://com.apple.documentation/documentation/swiftdata"),
-        // ML/AI frameworks
-        ("coreml", "doc://com.apple.documentation/documentation/coreml"),
-        ("createml", "doc://com.apple.documentation/documentation/createml"),
-        ("vision", "doc://com.apple.documentation/documentation/vision"),
-        ("naturallanguage", "doc://com.apple.documentation/documentation/naturallanguage"),
-        ("speech", "doc://com.apple.documentation/documentation/speech"),
-        ("soundanalysis", "doc://com.apple.documentation/documentation/soundanalysis"),
-        ("visionkit", "doc://com.apple.documentation/documentation/visionkit"),
-        ("accelerate", "doc://com.apple.documentation/documentation/accelerate"),
-        ("mlcompute", "doc://com.apple.documentation/documentation/mlcompute"),
-        ("metalperformanceshaders", "doc://com.apple.documentation/documentation/metalperformanceshaders"),
-        ("metalperformanceshadersgraph", "doc://com.apple.documentation/documentation/metalperformanceshadersgraph"),
-    ]
-});
+fn serialize_provider<S: serde::Serializer>(
+    provider: &Option<ProviderType>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(provider.map_or("", |provider| provider.name()))
+}
+
+fn serialize_parameters<S: serde::Serializer>(
+    parameters: &[(String, String)],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    use serde::ser::SerializeSeq;
+    let mut seq = serializer.serialize_seq(Some(parameters.len()))?;
+    for (name, description) in parameters {
+        seq.serialize_element(&json!({ "name": name, "description": description }))?;
+    }
+    seq.end()
+}
 
-/// Rust crate detection patterns
-static RUST_CRATES: Lazy<Vec<&'static str>> = Lazy::new(|| {
+/// CUDA keyword lists naming a documentation section, in the order they are checked
+const CUDA_SECTIONS: &[(&str, &str)] = &[
+    ("cuda_kernels", "cuda:kernels"),
+    ("cuda_cublas", "cuda:cublas"),
+    ("cuda_cudnn", "cuda:cudnn"),
+    ("cuda_cufft", "cuda:cufft"),
+    ("cuda_thrust", "cuda:thrust"),
+    ("cuda_cub", "cuda:cub"),
+    ("cuda_libraries", "cuda:libraries"),
+    ("cuda_gpu", "cuda:gpu"),
+    ("cuda_optimization", "cuda:optimization"),
+];
+
+/// Words in Rust queries that name concepts rather than crates, never looked up on crates.io
+static RUST_GENERIC_TERMS: Lazy<Vec<&'static str>> = Lazy::new(|| {
     vec![
-        "std", "core", "alloc", "tokio", "serde", "reqwest", "axum", "actix",
-        "diesel", "sqlx", "rocket", "clap", "tracing", "anyhow", "thiserror",
-        "async-std", "hyper", "warp", "tonic", "prost", "futures", "rayon",
+        "rust", "cargo", "crate", "crates", "docs", "rs", "std", "core", "alloc", "async",
+        "await", "trait", "traits", "struct", "enum", "fn", "function", "impl", "macro",
+        "macros", "error", "errors", "vec", "string", "iterator", "iter", "closure", "lifetime",
+        "borrow", "ownership", "thread", "threads", "mutex", "channel", "file", "read", "write",
+        "example", "examples", "type", "types", "generic", "generics", "module", "pattern",
+        "match", "option", "result", "box", "rc", "arc", "map", "test", "tests", "unsafe",
     ]
 });
-
+/// Technology for the Cargo Book (manifest keys, profiles, subcommands) rather than a crate's API
+pub(crate) const CARGO_BOOK_TECHNOLOGY: &str = "rust:cargo";
+/// Technology for MDN's CSS reference (properties, selectors, at-rules)
+pub(crate) const MDN_CSS_TECHNOLOGY: &str = "mdn:css";
+/// Web API interface members listed per result; `Element` has well over a hundred
+const MAX_LISTED_MEMBERS: usize = 40;
+/// Technology for MDN's HTML element reference
+pub(crate) const MDN_HTML_TECHNOLOGY: &str = "mdn:html";
+/// crates.io lookups made for one query before falling back to std
+const MAX_CRATE_LOOKUPS: usize = 2;
+
+/// A crate pinned to a version, e.g. `tokio@1.35` (as in `tokio@1.35/sync/mpsc`)
+static RUST_VERSIONED_CRATE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b([a-z][a-z0-9_-]*)@([0-9][0-9a-z.+-]*[0-9a-z])").unwrap());
+/// A Node.js release line, e.g. `nodejs@20`, `node 22` or `Node.js v18`
+static NODE_RELEASE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bnode(?:\.?js)?(?:@|\s*v?)(\d{2})(?:\.x)?\b").unwrap());
 static RUST_DOCS_RS_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?:https?://)?docs\.rs/([a-zA-Z0-9_-]+)").unwrap());
 static RUST_CRATE_HINT_RE: Lazy<Regex> =
@@ -151,284 +278,37 @@ static RUST_PATH_CRATE_RE: Lazy<Regex> =
 static RUST_TOKEN_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\b[a-zA-Z][a-zA-Z0-9_-]*\b").unwrap());
 
-/// Telegram-related keywords
-static TELEGRAM_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "telegram", "bot", "sendmessage", "getme", "getupdates", "webhook",
-        "inline", "callback", "chat", "chatmember", "botcommand",
-    ]
-});
-
-/// TON-related keywords (expanded for comprehensive coverage)
-static TON_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        // Core TON terms
-        "ton", "toncoin", "tonapi", "tonkeeper", "tonconnect",
-        // Smart contract languages
-        "tact", "func", "tolk", "fift",
-        // Data structures
-        "cell", "slice", "builder", "boc", "bagofcells",
-        // Tokens and standards
-        "jetton", "tep74", "tep62", "tep85", "sbt",
-        // TVM (TON Virtual Machine)
-        "tvm", "opcode", "continuation", "gasless",
-        // Wallet operations
-        "wallet", "v3r2", "v4r2", "walletv5", "seqno",
-        // Transactions and messages
-        "sendmessage", "rawmessage", "internalMessage", "externalmessage",
-        // API operations
-        "getaccount", "gettransactions", "getbalance", "getstate",
-        // Security terms
-        "replayattack", "acceptmessage", "throwunless",
-        // Development tools
-        "blueprint", "sandbox", "emulator",
-        // Network terms
-        "masterchain", "workchain", "shardchain",
-        // Common operations
-        "deployer", "staking", "validator",
-    ]
-});
-
-/// MDN Web Docs keywords (JavaScript, Web APIs, TypeScript)
-static MDN_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "javascript", "js", "ecmascript", "typescript", "ts", "dom", "fetch",
-        "promise", "async", "await", "array", "object", "function", "class",
-        "map", "set", "weakmap", "weakset", "proxy", "reflect", "symbol",
-        "iterator", "generator", "module", "import", "export", "json",
-        "localstorage", "sessionstorage", "indexeddb", "webworker", "serviceworker",
-        "websocket", "xmlhttprequest", "formdata", "url", "urlsearchparams",
-        "blob", "file", "filereader", "canvas", "webgl", "audio", "video",
-        "geolocation", "notification", "clipboard", "intersectionobserver",
-        "mutationobserver", "resizeobserver", "customelement", "shadowdom",
-        "template", "slot", "eventlistener", "addeventlistener", "queryselector",
-        "mdn", "web", "browser", "html", "css",
-    ]
-});
-
-/// React keywords
-static REACT_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "react", "jsx", "tsx", "hook", "usestate", "useeffect", "usecontext",
-        "usereducer", "usecallback", "usememo", "useref", "uselayouteffect",
-        "useimperativehandle", "usedebugvalue", "usetransition", "usedeferredvalue",
-        "useid", "usesyncexternalstore", "useinsertioneffect", "component",
-        "props", "children", "fragment", "suspense", "lazy", "memo", "forwardref",
-        "createcontext", "createref", "strictmode", "profiler", "reactdom",
-        "createroot", "hydrateroot", "flushsync", "createportal",
-    ]
-});
-
-/// Next.js keywords
-static NEXTJS_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "nextjs", "next", "approuter", "pagesrouter", "servercomponent",
-        "clientcomponent", "serveraction", "getserversideprops", "getstaticprops",
-        "getstaticpaths", "incrementalstaticregeneration", "isr", "middleware",
-        "nextimage", "nextlink", "nextscript", "nexthead", "userouter",
-        "usepathname", "usesearchparams", "useparams", "notfound", "redirect",
-        "generatemetadata", "generatestaticparams", "routehandler", "apiRoute",
-        "layout", "page", "loading", "error", "notfound", "template",
-    ]
-});
-
-/// Node.js keywords
-static NODEJS_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "nodejs", "node", "fs", "path", "http", "https", "crypto", "stream",
-        "buffer", "events", "util", "os", "child_process", "cluster", "worker_threads",
-        "readline", "repl", "net", "dgram", "dns", "tls", "zlib", "assert",
-        "querystring", "string_decoder", "timers", "tty", "v8", "vm", "process",
-        "console", "require", "module", "exports", "global", "dirname", "filename",
-    ]
-});
-
-/// Bun runtime keywords
-static BUN_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        // Core runtime
-        "bun", "bunjs", "bun.sh", "bunfile", "bunfig", "bunfig.toml",
-        // Bun-specific APIs
-        "bun.serve", "bun.file", "bun.write", "bun.spawn", "bun.spawnSync",
-        "bun.build", "bun.password", "bun.hash", "bun.sleep", "bun.sleepSync",
-        "bun.env", "bun.gc", "bun.which", "bun.peek", "bun.inspect",
-        "bun.nanoseconds", "bun.listen", "bun.connect", "bun.udpsocket",
-        "bun.transpiler", "bun.deepEquals", "bun.escapeHTML", "bun.pathToFileURL",
-        "bun.fileURLToPath", "bun.resolveSync", "bun.resolve", "bun.version",
-        "bun.cryptohasher", "bun.arraybuffersink", "bun.openineditor",
-        // Server/networking
-        "serverwebsocket", "websocket pubsub", "bun http server",
-        // SQLite
-        "bun:sqlite", "bun sqlite",
-        // FFI
-        "bun:ffi", "bun ffi", "dlopen",
-        // CLI commands
-        "bunx", "bun install", "bun add", "bun remove", "bun update",
-        "bun run", "bun test", "bun build", "bun create", "bun init",
-        "bun link", "bun pm", "bun upgrade", "bun repl",
-        // Testing
-        "bun:test", "bun test runner",
-        // Package manager
-        "bun.lockb", "bun lockfile", "bun workspaces", "bun cache",
-        // Configuration
-        "bunfig runtime", "bunfig install", "bunfig test",
-        // Comparison keywords (when users ask about Bun specifically)
-        "bun vs node", "bun vs deno", "bun runtime", "bun typescript",
-        "bun jsx", "bun hot reload", "bun watch",
-    ]
-});
-
-/// MLX (Apple Silicon ML) keywords
-static MLX_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "mlx", "mlxarray", "mlxswift", "mlx-swift", "apple silicon", "unified memory",
-        "mlxnn", "mlx.nn", "mlx.core", "mlx.optimizers", "mlx_lm",
-        // Core operations
-        "matmul", "conv2d", "softmax", "relu", "gelu", "layernorm", "rmsnorm",
-        // Optimizers
-        "adamw",
-        // Compilation
-        "jit", "compile", "eval", "valueandgrad",
-        // LLM specific
-        "kvcache", "rope", "rotary",
-    ]
-});
-
-/// Hugging Face keywords
-static HUGGINGFACE_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "huggingface", "hugging face", "hf", "transformers", "automodel", "autotokenizer",
-        "pipeline", "trainer", "from_pretrained", "push_to_hub",
-        // Model families
-        "llama", "mistral", "gemma", "phi", "qwen", "falcon", "codellama", "starcoder",
-        "bert", "gpt2", "t5", "whisper", "clip", "stable diffusion",
-        // Swift transformers
-        "swift-transformers", "swifttransformers",
-        // Libraries
-        "tokenizers", "datasets", "diffusers", "peft", "accelerate", "trl",
-        // Tasks
-        "text-generation", "text-classification", "token-classification", "question-answering",
-        "summarization", "translation", "conversational", "fill-mask",
-    ]
-});
-
-/// QuickNode / Solana keywords
-static QUICKNODE_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        "quicknode", "solana", "spl", "lamports", "pubkey",
-        // HTTP methods
-        "getaccountinfo", "getbalance", "getblock", "getblockheight", "gettransaction",
-        "sendtransaction", "simulatetransaction", "getlatestblockhash", "getslot",
-        "getsignaturestatuses", "getsignaturesforaddress", "gettokenaccountbalance",
-        "gettokenaccountsbyowner", "getprogramaccounts", "getmultipleaccounts",
-        "requestairdrop", "getepochinfo", "getvoteaccounts", "getclusterNodes",
-        // WebSocket methods
-        "accountsubscribe", "programsubscribe", "logssubscribe", "slotsubscribe",
-        "blocksubscribe", "signaturesubscribe", "rootsubscribe",
-        // Marketplace add-ons
-        "jito", "metaplex", "das", "yellowstone", "geyser", "grpc",
-        // General Solana terms
-        "devnet", "mainnet", "testnet", "anchor", "serum", "raydium", "jupiter",
-    ]
-});
-
-/// Claude Agent SDK keywords
-static CLAUDE_AGENT_SDK_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        // SDK names
-        "claude agent sdk", "claude-agent-sdk", "agent sdk", "claudeagentsdk",
-        "claude code sdk", "claude sdk",
-        // Core API (TypeScript: ClaudeClient, Python: ClaudeSDKClient)
-        "claudeclient", "claudesdkclient", "claudeagentoptions", "claudecodeoptions",
-        // Key functions
-        "query", "mcp", "mcpservers",
-        // Hooks
-        "pretooluse", "posttooluse", "onmessage",
-        // Configuration
-        "systemprompt", "system_prompt", "maxturns", "max_turns",
-        "allowedtools", "allowed_tools", "permissionmode", "permission_mode",
-        // Python specific
-        "@tool", "create_sdk_mcp_server", "cli_path",
-        // Messages
-        "assistantmessage", "usermessage", "systemmessage", "resultmessage",
-        // Content blocks
-        "textblock", "tooluseblock", "toolresultblock",
-    ]
-});
-
-/// Vertcoin blockchain keywords
-static VERTCOIN_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        // Core identifiers
-        "vertcoin", "vtc", "verthash",
-        // Mining related
-        "verthash.dat", "verthash miner", "verthashminer", "one click miner", "ocm",
-        "gpu mining", "asic resistance", "asic resistant",
-        // Network/blockchain
-        "vertcoin-cli", "vertcoind", "vertcoin-qt", "vertcoin core",
-        // RPC methods (common ones)
-        "getblockchaininfo", "getmininginfo", "getnetworkhashps", "getblocktemplate",
-        "getbalance", "getnewaddress", "sendtoaddress", "listtransactions",
-        "getpeerinfo", "getconnectioncount", "getnetworkinfo",
-        // Wallet
-        "vertcoin wallet", "vtc wallet", "vtcwallet",
-        // Specifications
-        "kimoto gravity well", "kgw", "2.5 minute", "84 million", "segwit",
-        // P2Pool
-        "p2pool", "vertcoin p2pool",
-        // Explorers
-        "vtc explorer", "vertcoin explorer", "insight vtc",
-    ]
-});
-
-/// CUDA GPU programming keywords
-static CUDA_KEYWORDS: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    vec![
-        // Core CUDA identifiers
-        "cuda", "nvcc", "nvidia cuda", "cuda toolkit",
-        // Runtime API functions
-        "cudamalloc", "cudafree", "cudamemcpy", "cudamemcpyasync",
-        "cudamallocmanaged", "cudamallochost", "cudafreehost",
-        "cudamemset", "cudadevicesynchronize", "cudalaunchkernel",
-        "cudagetdevicecount", "cudasetdevice", "cudagetdeviceproperties",
-        // Kernel programming
-        "__global__", "__device__", "__host__", "__shared__", "__constant__",
-        "threadidx", "blockidx", "blockdim", "griddim",
-        "__syncthreads", "__syncwarp", "warpsize",
-        // Atomic operations
-        "atomicadd", "atomiccas", "atomicexch", "atomicmin", "atomicmax",
-        // Warp operations
-        "__shfl_sync", "__ballot_sync", "warp shuffle", "warp primitive",
-        // Streams and events
-        "cudastreamcreate", "cudastreamdestroy", "cudastreamsynchronize",
-        "cudaeventcreate", "cudaeventelapsedtime", "cudaeventrecord",
-        // Libraries
-        "cublas", "cudnn", "cufft", "curand", "nccl",
-        "cublassgemm", "cublasdgemm", "cublashgemm",
-        "cudnnconvolutionforward", "cudnnbatchnormalization",
-        // GPU specs
-        "rtx 3070", "rtx 4090", "rtx3070", "rtx4090",
-        "compute capability", "cuda cores", "tensor cores", "sm",
-        "ampere", "ada lovelace", "ga104", "ad102",
-        // Memory types
-        "global memory", "shared memory", "constant memory", "texture memory",
-        "unified memory", "pinned memory", "device memory",
-        // Optimization
-        "memory coalescing", "bank conflict", "occupancy", "warp divergence",
-        "grid stride loop", "kernel fusion", "tensor core",
-        // General
-        "gpu kernel", "cuda kernel", "gpu programming", "parallel computing",
-    ]
-});
-
-/// How-to query patterns
-static HOWTO_PATTERNS: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)^(how\s+(do\s+i|to|can\s+i)|what'?s?\s+the\s+(best\s+)?way\s+to|implement|create|make|build|add|show\s+me\s+how)").unwrap()
-});
-
-/// Reference query patterns
-static REFERENCE_PATTERNS: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(?i)^(what\s+is|explain|describe|tell\s+me\s+about|documentation\s+for|docs\s+for|api\s+for)").unwrap()
+/// HTML elements recognised in "dialog element" and `<dialog>` queries
+const HTML_ELEMENTS: &[&str] = &[
+    "a", "abbr", "address", "area", "article", "aside", "audio", "b", "base", "bdi", "bdo",
+    "blockquote", "body", "br", "button", "canvas", "caption", "cite", "code", "col", "colgroup",
+    "data", "datalist", "dd", "del", "details", "dfn", "dialog", "div", "dl", "dt", "em", "embed",
+    "fieldset", "figcaption", "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6",
+    "head", "header", "hgroup", "hr", "html", "i", "iframe", "img", "input", "ins", "kbd", "label",
+    "legend", "li", "link", "main", "map", "mark", "menu", "meta", "meter", "nav", "noscript",
+    "object", "ol", "optgroup", "option", "output", "p", "picture", "pre", "progress", "q", "rp",
+    "rt", "ruby", "s", "samp", "script", "search", "section", "select", "slot", "small", "source",
+    "span", "strong", "style", "sub", "summary", "sup", "table", "tbody", "td", "template",
+    "textarea", "tfoot", "th", "thead", "time", "title", "tr", "track", "u", "ul", "var", "video",
+    "wbr",
+];
+
+/// `<dialog>` or `<input type="date">` as written in a query
+static HTML_TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([a-z][a-z0-9]*)[\s>/]").unwrap());
+/// "dialog element", "details tag"
+static HTML_ELEMENT_WORD_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b([a-z][a-z0-9]*)\s+(?:element|tag)s?\b").unwrap());
+/// `input type=datetime-local`, `<input type="checkbox">`
+static HTML_INPUT_TYPE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\binput\s+type\s*=\s*["']?([a-z][a-z-]*)"#).unwrap());
+
+/// CSS tokens kept whole for MDN search: `@media`, `::before`, `:nth-child()`, `grid-template-columns`
+static CSS_TOKEN_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"@[a-z][a-z-]*|::?[a-z][a-z-]*(?:\(\))?|[a-z]+(?:-[a-z]+)+").unwrap());
+
+/// A generic utility type applied to a type argument, e.g. `Partial<User>` or `Omit<T, "id">`
+static TS_UTILITY_TYPE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:partial|required|readonly|record|pick|omit|exclude|extract|parameters|awaited)\s*<").unwrap()
 });
 
 pub fn definition() -> (ToolDefinition, ToolHandler) {
@@ -439,7 +319,7 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 "Complete documentation retrieval in a single call. Returns full documentation \
                  content, code examples, declarations, and parameters—no follow-up calls needed. \
                  Auto-detects provider (Apple, Rust, Telegram, TON, Cocoon, MDN, React, Next.js, \
-                 Node.js, MLX, Hugging Face, QuickNode, Claude Agent SDK, Vertcoin, CUDA) from your query. \
+                 Node.js, MLX, Hugging Face, QuickNode, Claude Agent SDK, Bitcoin/Litecoin/Vertcoin, CUDA, Solana) from your query. \
                  Top 5 results include complete documentation; remaining results include summaries. \
                  Use natural language: 'SwiftUI NavigationStack', 'Rust tokio spawn', 'CUDA cudaMalloc', 'RTX 4090 specs'."
                     .to_string(),
@@ -449,11 +329,49 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 "properties": {
                     "query": {
                         "type": "string",
-                        "description": "Natural language query. Include technology name for best results (e.g., 'SwiftUI List selection', 'Rust HashMap', 'Telegram Bot API webhooks')"
+                        "description": "Natural language query. Include technology name for best results (e.g., 'SwiftUI List selection', 'Rust HashMap', 'Telegram Bot API webhooks'). A developer.apple.com, docs.rs, doc.rust-lang.org or MDN URL returns that page; format, language and the length limits are rejected with one"
                     },
                     "maxResults": {
                         "type": "number",
                         "description": "Maximum results to return (default: 10, max: 20). Top 5 get full documentation."
+                    },
+                    "maxSummaryLength": {
+                        "type": "number",
+                        "description": "Maximum characters per summary (default: 300). Use 0 to disable truncation."
+                    },
+                    "maxCodeLength": {
+                        "type": "number",
+                        "description": "Maximum characters per code sample (default: 2000). Use 0 to disable truncation."
+                    },
+                    "maxContentLength": {
+                        "type": "number",
+                        "description": "Maximum characters of full documentation content (default: 4000). Use 0 to disable truncation."
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "json", "plain"],
+                        "description": "Output format: 'markdown' (default), 'json' for structured results, or 'plain' text without markdown."
+                    },
+                    "provider": {
+                        "type": "string",
+                        "description": "Skip provider auto-detection and search this provider (e.g. 'apple', 'rust', 'telegram', 'mdn')"
+                    },
+                    "technology": {
+                        "type": "string",
+                        "description": "Skip technology auto-detection: an Apple framework ('swiftui'), a Rust crate ('tokio') or a provider technology id ('webfw:nextjs', or 'webfw:nodejs@20' for a Node.js release line)"
+                    },
+                    "language": {
+                        "type": "string",
+                        "enum": ["swift", "objc"],
+                        "description": "Apple only: return Objective-C declarations, titles and availability instead of Swift (default swift)"
+                    },
+                    "platform": {
+                        "type": "string",
+                        "description": "Apple and Rust: drop symbols that aren't available on this platform (e.g. 'iOS', 'macOS', 'visionOS'; for Rust std 'linux', 'windows', 'unix')"
+                    },
+                    "includeBeta": {
+                        "type": "boolean",
+                        "description": "Apple only: set to false to hide APIs that are beta on every platform and rank partially beta APIs last (default true)"
                     }
                 }
             }),
@@ -463,6 +381,8 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"query": "Rust tokio spawn async task"}),
                 json!({"query": "Rust std HashMap insert"}),
                 json!({"query": "Telegram Bot API sendMessage"}),
+                json!({"query": "TDLib updateAuthorizationState"}),
+                json!({"query": "Telegram Mini App validate initData"}),
                 json!({"query": "how to implement CoreData fetch requests"}),
                 // TON blockchain examples
                 json!({"query": "TON Tact smart contract"}),
@@ -470,10 +390,14 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"query": "TON security best practices"}),
                 json!({"query": "TON FunC recv_internal"}),
                 json!({"query": "TON wallet v5"}),
+                json!({"query": "TON jetton transfer TL-B"}),
+                json!({"query": "ton exit code 37"}),
                 json!({"query": "TVM gas costs"}),
                 // Other providers
                 json!({"query": "Solana getAccountInfo"}),
                 json!({"query": "QuickNode getBalance"}),
+                json!({"query": "QuickNode Ethereum eth_getLogs"}),
+                json!({"query": "Solana program derived address seeds"}),
                 json!({"query": "Claude Agent SDK query function typescript"}),
                 json!({"query": "agent sdk python ClaudeSDKClient"}),
                 json!({"query": "Claude Agent SDK hooks PreToolUse"}),
@@ -482,65 +406,303 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"query": "Bun SQLite database"}),
                 json!({"query": "Bun.spawn child process"}),
                 json!({"query": "bun test runner expect"}),
+                json!({"query": "Express router middleware"}),
+                json!({"query": "express req.params route parameters"}),
+                json!({"query": "Astro getStaticPaths dynamic routes"}),
+                json!({"query": "Remix loader useLoaderData"}),
+                json!({"query": "nodejs@20 test runner mock"}),
+                json!({"query": "React Native FlatList performance"}),
+                json!({"query": "react native turbomodule codegen"}),
+                json!({"query": "TypeScript Partial<T> utility type"}),
+                json!({"query": "tsconfig moduleResolution bundler"}),
+                json!({"query": "Node.js 18 fetch"}),
                 json!({"query": "Vertcoin getblockchaininfo"}),
                 json!({"query": "Verthash mining algorithm"}),
                 json!({"query": "vertcoin-cli sendtoaddress"}),
                 json!({"query": "Vertcoin RPC getbalance"}),
+                json!({"query": "bitcoin-cli getdeploymentinfo"}),
+                json!({"query": "Litecoin MWEB address"}),
                 json!({"query": "CUDA cudaMalloc memory allocation"}),
                 json!({"query": "CUDA __shared__ memory example"}),
+                json!({"query": "cudnnSetTensor4dDescriptor NHWC"}),
+                json!({"query": "cufftPlanMany batched FFT"}),
                 json!({"query": "CUDA kernel __global__ function"}),
                 json!({"query": "RTX 4090 specifications CUDA"}),
                 json!({"query": "cuBLAS matrix multiplication"}),
                 json!({"query": "CUDA memory coalescing optimization"}),
+                json!({"query": "Rust tokio spawn", "format": "json"}),
+                json!({"query": "spawn blocking task", "provider": "rust", "technology": "tokio"}),
+                json!({"query": "Rust std symlink", "platform": "windows"}),
+                json!({"query": "UIKit UIView animateWithDuration", "language": "objc"}),
+                json!({"query": "SwiftUI immersive space", "platform": "visionOS"}),
+                json!({"query": "SwiftUI toolbar", "platform": "iOS", "includeBeta": false}),
             ]),
             allowed_callers: None,
         },
-        wrap_handler(|context, value| async move {
-            let args: Args = parse_args(value)?;
-            handle(context, args).await
-        }),
+        wrap_handler(run_query),
     )
 }
 
+/// Run one `query` tool call with raw JSON arguments.
+pub(crate) async fn run_query(context: Arc<AppContext>, value: serde_json::Value) -> Result<ToolResponse> {
+    let args: Args = parse_args(value)?;
+    handle(context, args).await
+}
+
 async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
-    let max_results = args.max_results.unwrap_or(MAX_SEARCH_RESULTS).min(20);
+    // A pasted documentation link is answered with that page
+    if doc_url::parse_doc_url(&args.query).is_some() {
+        let conflicts = args.url_mode_conflicts();
+        if !conflicts.is_empty() {
+            bail!(
+                "{} can't be applied to a documentation URL; pass the URL to get_documentation or drop them",
+                conflicts.join(", ")
+            );
+        }
+        return get_documentation::fetch_document(context, &args.query, None, None).await;
+    }
 
-    // Step 1: Parse the query to extract intent
-    let intent = parse_query_intent(&args.query);
+    let max_results = args.max_results.unwrap_or(MAX_SEARCH_RESULTS).min(20);
+    let limits = args.limits(context.config.limits);
 
-    // Step 2: Ensure we have the right technology selected
-    let (provider, technology) = resolve_technology(&context, &intent).await?;
+    // Step 1: Parse the query to extract intent, then apply explicit overrides
+    let mut intent = parse_query_intent(&args.query);
+    if let Some(language) = &args.language {
+        intent.language = language.parse()?;
+    }
+    intent.platform = args.platform.clone().filter(|platform| !platform.trim().is_empty());
+    intent.include_beta = args.include_beta;
+    let explicit = apply_overrides(&context, &mut intent, args.provider.as_deref(), args.technology.as_deref())
+        .await?;
+    if !explicit {
+        resolve_unknown_crate(&context, &mut intent).await;
+    }
 
-    // Step 3: Execute the appropriate search strategy based on intent
-    let results = match intent.query_type {
-        QueryType::HowTo => execute_howto_query(&context, &intent, max_results).await?,
-        QueryType::Reference => execute_reference_query(&context, &intent, max_results).await?,
-        QueryType::Search => execute_search_query(&context, &intent, max_results).await?,
+    // A second provider named in the query is searched alongside the primary one, on state of
+    // its own so the session keeps the primary provider's technology
+    let secondary_target = match intent.secondary.clone() {
+        Some((other, tech_id))
+            if !explicit
+                && intent.provider.is_some_and(|provider| provider != other)
+                && !*context.state.technology_pinned.read().await =>
+        {
+            Some((other, tech_id))
+        }
+        _ => None,
+    };
+    let detached = Arc::new(context.detached());
+    let secondary_intent = intent.clone();
+    let secondary = async {
+        let (other, tech_id) = secondary_target?;
+        search_secondary(&detached, &secondary_intent, other, &tech_id, max_results).await
     };
+    let primary = async {
+        // Step 2: Ensure we have the right technology selected
+        let (provider, technology) = resolve_technology(&context, &intent, explicit).await?;
+        // Overrides or the session may have settled on another provider than detection did
+        intent.keywords = query_keywords(&intent.raw_query, provider == ProviderType::Apple);
+
+        // Step 3: Execute the appropriate search strategy based on intent
+        let results = execute_query(&context, &intent, max_results).await?;
+        anyhow::Ok((provider, technology, results))
+    };
+    let (primary, secondary) = tokio::join!(primary, secondary);
+    let (provider, technology, mut results) = primary?;
+    if let Some((other, other_results)) = secondary {
+        results = interleave(label(results, provider), label(other_results, other), max_results);
+    }
+
+    if results.is_empty() && docs_mcp_client::offline::is_offline() {
+        return Ok(offline_miss_response(&intent, &provider, &technology));
+    }
 
     // Step 4: Build structured response
-    build_response(&intent, &provider, &technology, &results)
+    let response = build_response(&intent, &provider, &technology, &results, &limits, args.format)?;
+    Ok(link_full_documents(&context, &provider, response, &results, &limits))
+}
+
+/// Keep the complete text of results cut short by `limits` and link it as MCP resources.
+fn link_full_documents(
+    context: &AppContext,
+    provider: &ProviderType,
+    mut response: ToolResponse,
+    results: &[DocResult],
+    limits: &ContentLimits,
+) -> ToolResponse {
+    let mut uris = Vec::new();
+    for result in results.iter().filter(|result| is_truncated(result, limits)) {
+        let provider = result.provider.unwrap_or(*provider);
+        let link = context
+            .documents
+            .insert(provider, &result.path, &result.title, full_document(&provider, result));
+        uris.push(link.uri.clone());
+        response.content.push(ToolContent::resource_link(link));
+    }
+    if let Some(metadata) = response.metadata.as_mut().filter(|_| !uris.is_empty()) {
+        metadata["fullDocuments"] = json!(uris);
+    }
+    response
+}
+
+/// Whether rendering `result` under `limits` drops part of its content or code sample.
+fn is_truncated(result: &DocResult, limits: &ContentLimits) -> bool {
+    let cut = |text: &str, max: usize| max != 0 && text.len() > max;
+    let content = match &result.full_content {
+        Some(content) => cut(content, limits.content),
+        None => cut(&result.summary, limits.summary),
+    };
+    content || result.code_sample.as_deref().is_some_and(|code| cut(code, limits.code))
+}
+
+/// `result` as a markdown document without any truncation.
+fn full_document(provider: &ProviderType, result: &DocResult) -> String {
+    let mut lines = vec![markdown::header(1, &result.title), String::new()];
+    if let Some(url) = &result.url {
+        lines.push(format!("**Source:** {url}"));
+        lines.push(String::new());
+    }
+    if let Some(declaration) = &result.declaration {
+        lines.push(format!("```{}\n{declaration}\n```", sample_language(provider, result)));
+        lines.push(String::new());
+    }
+    let content = result.full_content.as_deref().unwrap_or(&result.summary);
+    lines.push(tag_code_fences(content, &sample_language(provider, result)));
+    if let Some(code) = &result.code_sample {
+        lines.push(String::new());
+        lines.push("**Example:**".to_string());
+        lines.push(format!("```{}\n{code}\n```", sample_language(provider, result)));
+    }
+    lines.join("\n")
+}
+
+async fn execute_query(
+    context: &Arc<AppContext>,
+    intent: &QueryIntent,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    match intent.query_type {
+        QueryType::HowTo => execute_howto_query(context, intent, max_results).await,
+        QueryType::Reference => execute_reference_query(context, intent, max_results).await,
+        QueryType::Search => execute_search_query(context, intent, max_results).await,
+    }
+}
+
+/// Search `tech_id` of the second provider a query names; failures only drop its results.
+///
+/// `context` should be [`AppContext::detached`], since this selects `tech_id` in its state.
+async fn search_secondary(
+    context: &Arc<AppContext>,
+    intent: &QueryIntent,
+    provider: ProviderType,
+    tech_id: &str,
+    max_results: usize,
+) -> Option<(ProviderType, Vec<DocResult>)> {
+    let intent = QueryIntent {
+        provider: Some(provider),
+        technology: Some(tech_id.to_string()),
+        keywords: query_keywords(&intent.raw_query, provider == ProviderType::Apple),
+        ..intent.clone()
+    };
+    let results = match select_technology(context, provider, tech_id).await {
+        Ok(_) => execute_query(context, &intent, max_results).await,
+        Err(error) => Err(error),
+    };
+    match results {
+        Ok(results) if !results.is_empty() => Some((provider, results)),
+        Ok(_) => None,
+        Err(error) => {
+            tracing::debug!(%error, provider = provider.name(), "secondary provider search failed");
+            None
+        }
+    }
+}
+
+fn label(results: Vec<DocResult>, provider: ProviderType) -> Vec<DocResult> {
+    results
+        .into_iter()
+        .map(|result| DocResult { provider: Some(provider), ..result })
+        .collect()
+}
+
+/// Alternate between two ranked lists, keeping each list's order, up to `max_results`.
+fn interleave(first: Vec<DocResult>, second: Vec<DocResult>, max_results: usize) -> Vec<DocResult> {
+    let mut merged = Vec::with_capacity(max_results.min(first.len() + second.len()));
+    let mut first = first.into_iter();
+    let mut second = second.into_iter();
+    while merged.len() < max_results {
+        match (first.next(), second.next()) {
+            (None, None) => break,
+            (a, b) => merged.extend(a.into_iter().chain(b)),
+        }
+    }
+    merged.truncate(max_results);
+    merged
+}
+
+/// Names of the providers behind `results`, the primary `provider` first.
+fn provider_names(provider: &ProviderType, results: &[DocResult]) -> Vec<&'static str> {
+    let mut names = vec![provider.name()];
+    for name in results.iter().filter_map(|result| result.provider).map(|provider| provider.name()) {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// "Apple + Hugging Face" when results from two providers were merged.
+fn providers_label(provider: &ProviderType, results: &[DocResult]) -> String {
+    provider_names(provider, results).join(" + ")
+}
+
+/// Explain an empty result in offline mode instead of reporting "no matches".
+fn offline_miss_response(intent: &QueryIntent, provider: &ProviderType, technology: &str) -> ToolResponse {
+    text_response([
+        format!("# {}", intent.raw_query),
+        String::new(),
+        format!("Offline mode: no cached {} documentation matches this query.", provider.name()),
+        String::new(),
+        "Run `docs-mcp-cli warm` on a connected machine (then `docs-mcp-cli cache export`/`cache import`), or unset `DOCSMCP_OFFLINE`.".to_string(),
+    ])
+    .with_metadata(json!({
+        "query": intent.raw_query,
+        "provider": provider.name(),
+        "technology": technology,
+        "resultCount": 0,
+        "offline": true,
+    }))
 }
 
 /// Parse the user's query to extract intent, provider, technology, and keywords
 fn parse_query_intent(query: &str) -> QueryIntent {
     let query_lower = query.to_lowercase();
     let query_trimmed = query.trim();
+    let rules = intent_rules::current();
 
     // Detect query type
-    let query_type = if HOWTO_PATTERNS.is_match(query_trimmed) {
+    let query_type = if rules.is_howto(query_trimmed) {
         QueryType::HowTo
-    } else if REFERENCE_PATTERNS.is_match(query_trimmed) {
+    } else if rules.is_reference(query_trimmed) {
         QueryType::Reference
     } else {
         QueryType::Search
     };
 
     // Detect provider and technology
-    let (provider, technology) = detect_provider_and_technology(query_trimmed, &query_lower);
-
-    // Extract keywords (remove common stop words and query prefixes)
-    let keywords = extract_keywords(&query_lower);
+    let (mut provider, mut technology) = detect_provider_and_technology(&rules, query_trimmed, &query_lower);
+    let secondary = provider.and_then(|primary| detect_secondary_provider(query_trimmed, &query_lower, primary));
+
+    // Aliases are Apple shorthand ("CD persistent container"): they may name the Apple framework of a
+    // query no other provider claimed, but never reroute one
+    if matches!(provider, None | Some(ProviderType::Apple)) {
+        let expanded = rules.expand_aliases(&query_lower);
+        if let (Some(ProviderType::Apple), Some(apple_technology)) =
+            detect_provider_and_technology(&rules, query_trimmed, &expanded)
+        {
+            (provider, technology) = (Some(ProviderType::Apple), Some(apple_technology));
+        }
+    }
+    let keywords = query_keywords(query, provider == Some(ProviderType::Apple));
 
     QueryIntent {
         raw_query: query.to_string(),
@@ -548,7 +710,61 @@ fn parse_query_intent(query: &str) -> QueryIntent {
         technology,
         keywords,
         query_type,
+        language: SymbolLanguage::default(),
+        platform: None,
+        include_beta: true,
+        secondary,
+    }
+}
+
+/// Keywords of `query` (common stop words and query prefixes removed). A pinned version is
+/// part of the technology, not something to search for.
+///
+/// With `expand_aliases`, shorthand such as "vc" is searched as the words Apple's
+/// documentation uses; only queries routed to Apple ask for that.
+fn query_keywords(query: &str, expand_aliases: bool) -> Vec<String> {
+    let mut query = query.to_lowercase();
+    if expand_aliases {
+        query = intent_rules::current().expand_aliases(&query);
+    }
+    let unpinned = RUST_VERSIONED_CRATE_RE.replace_all(&query, "$1");
+    extract_keywords(&NODE_RELEASE_RE.replace_all(&unpinned, "node"))
+}
+
+/// Another provider named outright in the query besides `primary`, with its technology.
+///
+/// The technology is detected again from the query with the primary provider's names
+/// removed, so "transformers pipeline swift" searches `hf:transformers` next to SwiftUI.
+fn detect_secondary_provider(
+    raw_query: &str,
+    query: &str,
+    primary: ProviderType,
+) -> Option<(ProviderType, String)> {
+    let rules = intent_rules::current();
+    let secondary = ProviderType::ALL.into_iter().find(|provider| {
+        *provider != primary && rules.provider_names(*provider).iter().any(|name| keyword_matches(query, name))
+    })?;
+
+    let primary_names = rules.provider_names(primary);
+    let mut remaining = query
+        .split_whitespace()
+        .filter(|word| !primary_names.iter().any(|name| name == word))
+        .collect::<Vec<_>>()
+        .join(" ");
+    for name in primary_names.iter().filter(|name| name.contains(' ')) {
+        remaining = remaining.replace(name.as_str(), " ");
     }
+    let remaining_raw = raw_query
+        .split_whitespace()
+        .filter(|word| !primary_names.contains(&word.to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let technology = match detect_provider_and_technology(&rules, &remaining_raw, &remaining) {
+        (Some(provider), Some(technology)) if provider == secondary => technology,
+        _ => default_technology(secondary).to_string(),
+    };
+    Some((secondary, technology))
 }
 
 /// Check if a word exists as a whole word in the query (not as a substring of another word)
@@ -578,7 +794,56 @@ fn keyword_matches(query: &str, keyword: &str) -> bool {
     contains_word(query, keyword)
 }
 
+/// Like [`keyword_matches`], but a single-word keyword may also begin a longer word, as
+/// "cublas" does in `cublasGemmEx`.
+fn keyword_starts_word(query: &str, keyword: &str) -> bool {
+    if keyword.contains(|c: char| !c.is_alphanumeric() && c != '_') {
+        return query.contains(keyword);
+    }
+    query
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| word.starts_with(keyword))
+}
+
+/// Node.js release line named in the query, if its docs can be pinned
+fn node_release(query: &str) -> Option<u32> {
+    NODE_RELEASE_RE
+        .captures_iter(query)
+        .filter_map(|caps| caps[1].parse().ok())
+        .find(|line| NODE_RELEASE_LINES.contains(line))
+}
+
+/// Split a web framework technology id into the framework and a pinned Node.js release line,
+/// e.g. `webfw:nodejs@20` -> `("nodejs", Some(20))`
+fn web_framework_technology(tech_id: &str) -> (&str, Option<u32>) {
+    let framework = tech_id.strip_prefix("webfw:").unwrap_or(tech_id);
+    match framework.split_once('@') {
+        Some((name, line)) => (
+            name,
+            line.parse().ok().filter(|line| NODE_RELEASE_LINES.contains(line)),
+        ),
+        None => (framework, None),
+    }
+}
+
+/// MLX technology for a query: Swift, mlx-lm (LLM generation, quantization, LoRA),
+/// mlx-examples, or the Python API
+fn mlx_technology(query: &str) -> &'static str {
+    if query.contains("swift") || query.contains("ios") || query.contains("macos") {
+        "mlx:swift"
+    } else if intent_rules::current().keywords("mlx_lm").iter().any(|keyword| keyword_matches(query, keyword)) {
+        "mlx:lm"
+    } else if query.contains("mlx-examples") || contains_word(query, "example") || contains_word(query, "examples") {
+        "mlx:examples"
+    } else {
+        "mlx:python"
+    }
+}
+
 fn detect_rust_crate_hint(raw_query: &str, query: &str) -> Option<String> {
+    if let Some(caps) = RUST_VERSIONED_CRATE_RE.captures(query) {
+        return Some(format!("{}@{}", &caps[1], &caps[2]));
+    }
     if let Some(caps) = RUST_DOCS_RS_RE.captures(query) {
         return Some(caps[1].to_string());
     }
@@ -592,7 +857,8 @@ fn detect_rust_crate_hint(raw_query: &str, query: &str) -> Option<String> {
                 return None;
             }
             let candidate = candidate.to_lowercase();
-            if !matches!(candidate.as_str(), "self" | "super" | "crate") {
+            // `thrust::` and `cub::` are CUDA C++ namespaces, not crates
+            if !matches!(candidate.as_str(), "self" | "super" | "crate" | "thrust" | "cub") {
                 return Some(candidate);
             }
         }
@@ -621,6 +887,46 @@ fn detect_rust_crate_token(query: &str) -> Option<String> {
     None
 }
 
+/// Words of a general Rust query that could be the name of a crate outside the `rust_crates` keyword list.
+///
+/// Type names are CamelCase (`HashMap`), so only words written in lower case qualify.
+fn unknown_crate_candidates(intent: &QueryIntent) -> Vec<String> {
+    RUST_TOKEN_RE
+        .find_iter(&intent.raw_query)
+        .map(|token| token.as_str())
+        .filter(|token| !token.chars().any(|c| c.is_ascii_uppercase()))
+        .filter(|token| intent.keywords.iter().any(|keyword| keyword == token))
+        .filter(|token| !RUST_GENERIC_TERMS.contains(token))
+        .map(str::to_string)
+        .take(MAX_CRATE_LOOKUPS)
+        .collect()
+}
+
+/// Point a general Rust query (`rust:std`) at a crate it names that isn't in the `rust_crates` keyword list,
+/// if crates.io knows it, so "Rust bevy ECS query" searches bevy's docs on docs.rs.
+async fn resolve_unknown_crate(context: &AppContext, intent: &mut QueryIntent) {
+    if intent.provider != Some(ProviderType::Rust)
+        || intent.technology.as_deref() != Some("rust:std")
+        || find_error_code(&intent.raw_query).is_some()
+    {
+        return;
+    }
+    for candidate in unknown_crate_candidates(intent) {
+        match context.providers.rust.resolve_crate(&candidate).await {
+            Ok(Some(resolved)) => {
+                intent.technology = Some(format!("rust:{}", resolved.name));
+                return;
+            }
+            Ok(None) => {}
+            Err(error) => {
+                // Offline or crates.io unreachable: std is still a useful answer
+                tracing::debug!(%error, candidate, "crates.io lookup failed");
+                return;
+            }
+        }
+    }
+}
+
 fn detect_claude_agent_sdk_signal(query: &str) -> bool {
     keyword_matches(query, "@tool")
         || keyword_matches(query, "cli_path")
@@ -633,19 +939,49 @@ fn detect_claude_agent_sdk_signal(query: &str) -> bool {
         || contains_word(query, "claudeclient")
 }
 
-/// Detect the provider and technology from the query
-fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<ProviderType>, Option<String>) {
+/// Detect the provider and technology from the query using the keyword lists of `rules`
+fn detect_provider_and_technology(
+    rules: &IntentRules,
+    raw_query: &str,
+    query: &str,
+) -> (Option<ProviderType>, Option<String>) {
+
+    // Compiler output such as `error[E0502]: cannot borrow ...` is always about Rust
+    if find_error_code(raw_query).is_some() {
+        return (Some(ProviderType::Rust), Some("rust:std".to_string()));
+    }
+
+    // Build configuration: Cargo.toml keys, profiles, `cargo <subcommand>`
+    if is_cargo_query(raw_query) {
+        return (Some(ProviderType::Rust), Some(CARGO_BOOK_TECHNOLOGY.to_string()));
+    }
+
+    // React Native before Apple: "react native ios" is about React Native, not UIKit
+    if rules.keywords("react_native").iter().any(|keyword| keyword_matches(query, keyword)) {
+        return (Some(ProviderType::WebFrameworks), Some("webfw:react-native".to_string()));
+    }
+
+    // MLX and swift-transformers name themselves; their questions mention Swift and Apple
+    // Silicon without being about Apple's frameworks
+    if contains_word(query, "mlx") || query.contains("mlx-swift") || query.contains("ml-explore") {
+        return (Some(ProviderType::Mlx), Some(mlx_technology(query).to_string()));
+    }
+    if query.contains("swift-transformers") {
+        return (Some(ProviderType::HuggingFace), Some("hf:swift-transformers".to_string()));
+    }
+
+    let matches_any = |list: &str| rules.keywords(list).iter().any(|keyword| keyword_matches(query, keyword));
+
     // Check for Apple frameworks first (most common case)
-    for (name, identifier) in APPLE_FRAMEWORKS.iter() {
-        if contains_word(query, name) {
-            return (Some(ProviderType::Apple), Some(identifier.to_string()));
-        }
+    if let Some(name) = rules.keywords("apple_frameworks").iter().find(|name| keyword_matches(query, name)) {
+        return (
+            Some(ProviderType::Apple),
+            Some(format!("doc://com.apple.documentation/documentation/{name}")),
+        );
     }
 
     // Check for iOS/macOS/Swift-related keywords that imply Apple
-    if contains_word(query, "ios") || contains_word(query, "macos") || contains_word(query, "swift")
-        || contains_word(query, "xcode") || contains_word(query, "apple")
-    {
+    if matches_any("apple") {
         // Default to SwiftUI if no specific framework detected
         return (
             Some(ProviderType::Apple),
@@ -654,13 +990,7 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
     }
 
     // Check for ML/AI-related keywords that imply Apple CoreML
-    if query.contains("machine learning") || query.contains("neural network")
-        || query.contains("ml model") || query.contains("model inference")
-        || query.contains("bnns") || query.contains("image classification")
-        || query.contains("object detection") || query.contains("text recognition")
-        || query.contains("face detection") || query.contains("pose estimation")
-        || query.contains("sentiment analysis") || query.contains("language model")
-    {
+    if matches_any("apple_ml") {
         // Default to CoreML for general ML queries
         return (
             Some(ProviderType::Apple),
@@ -668,18 +998,31 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
         );
     }
 
+    // A pinned Node.js release line, before `<crate>@<version>` reads `nodejs@20` as a Rust crate
+    if let Some(release) = node_release(query) {
+        return (Some(ProviderType::WebFrameworks), Some(format!("webfw:nodejs@{release}")));
+    }
+
     // Check for Rust crate hints like `docs.rs/<crate>`, `crate <name>`, or `<crate>::...`
     if let Some(crate_name) = detect_rust_crate_hint(raw_query, query) {
         return (Some(ProviderType::Rust), Some(format!("rust:{crate_name}")));
     }
 
+    // Scripting against the Hub over HTTP rather than through a library
+    if matches_any("hf_hub_api") {
+        return (Some(ProviderType::HuggingFace), Some("hf:hub-api".to_string()));
+    }
+
+    // Hub models such as `Qwen/Qwen2.5-7B-Instruct` or `Llama-3.1-8B-Instruct` get their model card
+    if model_reference(raw_query).is_some() {
+        return (Some(ProviderType::HuggingFace), Some("hf:models".to_string()));
+    }
+
     // Check for Rust crates
-    for crate_name in RUST_CRATES.iter() {
-        if contains_word(query, crate_name) {
+    for crate_name in rules.keywords("rust_crates").iter() {
+        if keyword_matches(query, crate_name) {
             // Avoid false positives for extremely common words unless the query is clearly Rust-related.
-            if matches!(*crate_name, "std" | "core" | "alloc")
-                && !(contains_word(query, "rust") || contains_word(query, "cargo") || query.contains("::"))
-            {
+            if matches!(crate_name.as_str(), "std" | "core" | "alloc") && !(matches_any("rust") || query.contains("::")) {
                 continue;
             }
             return (Some(ProviderType::Rust), Some(format!("rust:{}", crate_name)));
@@ -692,77 +1035,131 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
     }
 
     // Check for general Rust queries (no specific crate detected)
-    if contains_word(query, "rust") || contains_word(query, "cargo") {
+    if matches_any("rust") {
         return (Some(ProviderType::Rust), Some("rust:std".to_string()));
     }
 
-    // Check for Vertcoin keywords (before TON/QuickNode since all are blockchain-related)
-    for keyword in VERTCOIN_KEYWORDS.iter() {
-        if keyword_matches(query, keyword) {
-            // Determine category based on query content
-            let tech = if query.contains("mining") || query.contains("verthash") || query.contains("hashrate") || query.contains("getblocktemplate") {
-                "vertcoin:mining"
-            } else if query.contains("wallet") || query.contains("balance") || query.contains("send") || query.contains("address") {
-                "vertcoin:wallet"
-            } else if query.contains("spec") || query.contains("segwit") || query.contains("block time") || query.contains("supply") {
-                "vertcoin:specs"
-            } else {
-                // Default to blockchain RPC
-                "vertcoin:blockchain"
-            };
-            return (Some(ProviderType::Vertcoin), Some(tech.to_string()));
-        }
+    // Solana concepts (accounts, PDAs, CPI, rent) before QuickNode's Solana RPC methods
+    if matches_any("solana_docs")
+        || (contains_word(query, "solana") && rules.keywords("solana_topic_words").iter().any(|word| contains_word(query, word)))
+    {
+        let source = if query.contains("how to") || query.contains("how do") || query.contains("cookbook")
+            || query.contains("recipe") || query.contains("example")
+        {
+            SolanaDocSource::Cookbook
+        } else {
+            SolanaDocSource::Docs
+        };
+        return (Some(ProviderType::Solana), Some(source.identifier().to_string()));
     }
 
-    // Check for CUDA keywords (GPU programming)
-    for keyword in CUDA_KEYWORDS.iter() {
-        if keyword_matches(query, keyword) {
-            // Determine category based on query content
-            let tech = if query.contains("kernel") || query.contains("__global__") || query.contains("__device__") || query.contains("__shared__") {
-                "cuda:kernels"
-            } else if query.contains("cublas") || query.contains("cudnn") || query.contains("cufft") || query.contains("curand") || query.contains("nccl") {
-                "cuda:libraries"
-            } else if query.contains("rtx") || query.contains("3070") || query.contains("4090") || query.contains("spec") || query.contains("compute capability") {
-                "cuda:gpu"
-            } else if query.contains("coalescing") || query.contains("occupancy") || query.contains("optimization") || query.contains("performance") {
-                "cuda:optimization"
-            } else {
-                // Default to runtime API
-                "cuda:runtime"
-            };
-            return (Some(ProviderType::Cuda), Some(tech.to_string()));
-        }
+    // QuickNode EVM chains before Vertcoin, whose RPC names (`getbalance`) also appear in `eth_getBalance`
+    if matches_any("quicknode_evm") {
+        let chain = if contains_word(query, "polygon") || contains_word(query, "matic") || query.contains("bor_") {
+            QuickNodeChain::Polygon
+        } else if query.contains("base chain") || query.contains("base mainnet") || query.contains("base sepolia")
+            || contains_word(query, "base")
+        {
+            QuickNodeChain::Base
+        } else {
+            QuickNodeChain::Ethereum
+        };
+        let category = if query.contains("subscribe") || query.contains("websocket") {
+            "websocket"
+        } else if query.contains("debug_") {
+            "debug"
+        } else if query.contains("trace_") && chain == QuickNodeChain::Ethereum {
+            "trace"
+        } else {
+            "http"
+        };
+        return (Some(ProviderType::QuickNode), Some(format!("quicknode:{}:{category}", chain.slug())));
     }
 
-    // Check for Telegram keywords
-    for keyword in TELEGRAM_KEYWORDS.iter() {
+    // Check for bitcoind coins (before TON/QuickNode since all are blockchain-related). Vertcoin's
+    // own names win, then Litecoin and Bitcoin; bare RPC names like `getbalance` stay with Vertcoin.
+    let coin = if matches_any("vertcoin_names") {
+        Some(BitcoindCoin::Vertcoin)
+    } else if matches_any("litecoin") {
+        Some(BitcoindCoin::Litecoin)
+    } else if matches_any("bitcoin") {
+        Some(BitcoindCoin::Bitcoin)
+    } else if matches_any("vertcoin") {
+        Some(BitcoindCoin::Vertcoin)
+    } else {
+        None
+    };
+    if let Some(coin) = coin {
+        // Determine category based on query content
+        let pow = coin.pow().to_lowercase();
+        let category = if query.contains("mining") || query.contains(pow.as_str()) || query.contains("hashrate") || query.contains("getblocktemplate") {
+            "mining"
+        } else if query.contains("wallet") || query.contains("balance") || query.contains("send") || query.contains("address") {
+            "wallet"
+        } else if query.contains("spec") || query.contains("segwit") || query.contains("block time") || query.contains("supply")
+            || query.contains("mweb") || query.contains("taproot")
+        {
+            "specs"
+        } else {
+            // Default to blockchain RPC
+            "blockchain"
+        };
+        return (Some(ProviderType::Bitcoind), Some(format!("{}:{category}", coin.slug())));
+    }
+
+    // Check for CUDA keywords (GPU programming)
+    // cuBLAS, cuDNN and cuFFT identifiers (cublasGemmEx, cudnnTensorDescriptor_t, cufftPlanMany)
+    let starts_any = |list: &str| rules.keywords(list).iter().any(|keyword| keyword_starts_word(query, keyword));
+    if starts_any("cuda_library_prefixes") || matches_any("cuda") {
+        // Default to the runtime API
+        let section = CUDA_SECTIONS
+            .iter()
+            .find(|(list, _)| starts_any(list))
+            .map_or("cuda:runtime", |(_, technology)| technology);
+        return (Some(ProviderType::Cuda), Some(section.to_string()));
+    }
+
+    // Check for Mini Apps and TDLib before the Bot API
+    if matches_any("mini_apps") {
+        return (Some(ProviderType::Telegram), Some(MINI_APPS_TECHNOLOGY.to_string()));
+    }
+    if matches_any("tdlib") {
+        return (Some(ProviderType::Telegram), Some(TDLIB_TECHNOLOGY.to_string()));
+    }
+
+    // Check for Telegram keywords
+    for keyword in rules.keywords("telegram").iter() {
         if contains_word(query, keyword) {
             return (Some(ProviderType::Telegram), Some("telegram:methods".to_string()));
         }
     }
 
+    if matches_any("ton_exit_codes") {
+        return (Some(ProviderType::TON), Some(EXIT_CODES_TECHNOLOGY.to_string()));
+    }
+    if matches_any("tlb") {
+        return (Some(ProviderType::TON), Some(TLB_TECHNOLOGY.to_string()));
+    }
+
     // Check for TON keywords (use word boundary to avoid "button" matching "ton")
-    for keyword in TON_KEYWORDS.iter() {
+    for keyword in rules.keywords("ton").iter() {
         if contains_word(query, keyword) {
+            if query.contains("exit code") || contains_word(query, "exitcode") {
+                return (Some(ProviderType::TON), Some(EXIT_CODES_TECHNOLOGY.to_string()));
+            }
+            // Questions about how a message is serialized go to the TL-B schemas
+            if ["schema", "layout", "serialization", "serialize"].iter().any(|word| contains_word(query, word)) {
+                return (Some(ProviderType::TON), Some(TLB_TECHNOLOGY.to_string()));
+            }
             return (Some(ProviderType::TON), Some("ton:accounts".to_string()));
         }
     }
 
     // Check for Cocoon keywords
-    if contains_word(query, "cocoon") || query.contains("confidential computing") || contains_word(query, "tdx") {
+    if matches_any("cocoon") {
         return (Some(ProviderType::Cocoon), Some("cocoon:architecture".to_string()));
     }
 
-    // Check for MLX keywords (Apple Silicon ML) before generic JS/Node matches like "module"
-    if contains_word(query, "mlx") || query.contains("mlx-swift") || query.contains("ml-explore") {
-        let tech = if query.contains("swift") || query.contains("ios") || query.contains("macos") {
-            "mlx:swift"
-        } else {
-            "mlx:python"
-        };
-        return (Some(ProviderType::Mlx), Some(tech.to_string()));
-    }
-
     // Check for Claude Agent SDK signals before Node.js keywords like "path"
     if detect_claude_agent_sdk_signal(query) {
         let tech = if query.contains("python")
@@ -776,49 +1173,77 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
         return (Some(ProviderType::ClaudeAgentSdk), Some(tech.to_string()));
     }
 
+    // Check for HTML elements and CSS before the web frameworks, whose keywords include
+    // "layout" and "template", unless the question names a framework ("css modules in next.js")
+    let names_framework = matches_any("web_framework_names");
+    if !names_framework && html_element_query(query).is_some() {
+        return (Some(ProviderType::Mdn), Some(MDN_HTML_TECHNOLOGY.to_string()));
+    }
+    if !names_framework && matches_any("css") {
+        return (Some(ProviderType::Mdn), Some(MDN_CSS_TECHNOLOGY.to_string()));
+    }
+
+    // TypeScript before the web frameworks (whose keywords include "module" and "error") and MDN
+    if !names_framework
+        && (matches_any("typescript") || TS_UTILITY_TYPE_RE.is_match(query))
+    {
+        return (Some(ProviderType::WebFrameworks), Some("webfw:typescript".to_string()));
+    }
+
+    // Check for Express before React and Next.js, whose keywords include "middleware" and
+    // "next"; Node.js routing questions belong here too
+    let names_node = contains_word(query, "node") || contains_word(query, "nodejs");
+    if matches_any("express")
+        || (names_node && rules.keywords("node_routing").iter().any(|keyword| contains_word(query, keyword)))
+    {
+        return (Some(ProviderType::WebFrameworks), Some("webfw:express".to_string()));
+    }
+
+    // Check for Astro and React Router before React, since "react router" names React
+    if matches_any("astro") {
+        return (Some(ProviderType::WebFrameworks), Some("webfw:astro".to_string()));
+    }
+    if matches_any("react_router") {
+        return (Some(ProviderType::WebFrameworks), Some("webfw:react-router".to_string()));
+    }
+
     // Check for React keywords (before general MDN keywords since React uses JS)
-    for keyword in REACT_KEYWORDS.iter() {
+    for keyword in rules.keywords("react").iter() {
         if contains_word(query, keyword) {
             return (Some(ProviderType::WebFrameworks), Some("webfw:react".to_string()));
         }
     }
 
     // Check for Next.js keywords
-    for keyword in NEXTJS_KEYWORDS.iter() {
+    for keyword in rules.keywords("nextjs").iter() {
         if contains_word(query, keyword) {
             return (Some(ProviderType::WebFrameworks), Some("webfw:nextjs".to_string()));
         }
     }
 
     // Check for Bun keywords (before Node.js since Bun is more specific)
-    for keyword in BUN_KEYWORDS.iter() {
+    for keyword in rules.keywords("bun").iter() {
         if keyword_matches(query, keyword) {
             return (Some(ProviderType::WebFrameworks), Some("webfw:bun".to_string()));
         }
     }
 
     // Check for Node.js keywords
-    for keyword in NODEJS_KEYWORDS.iter() {
+    for keyword in rules.keywords("nodejs").iter() {
         if contains_word(query, keyword) {
             return (Some(ProviderType::WebFrameworks), Some("webfw:nodejs".to_string()));
         }
     }
 
     // Check for MLX keywords (Apple Silicon ML)
-    for keyword in MLX_KEYWORDS.iter() {
+    for keyword in rules.keywords("mlx").iter() {
         if keyword_matches(query, keyword) {
-            // Determine if Swift or Python based on context
-            let tech = if query.contains("swift") || query.contains("ios") || query.contains("macos") {
-                "mlx:swift"
-            } else {
-                "mlx:python"
-            };
-            return (Some(ProviderType::Mlx), Some(tech.to_string()));
+            return (Some(ProviderType::Mlx), Some(mlx_technology(query).to_string()));
         }
     }
 
     // Check for Hugging Face keywords
-    for keyword in HUGGINGFACE_KEYWORDS.iter() {
+    for keyword in rules.keywords("huggingface").iter() {
         if keyword_matches(query, keyword) {
             // Determine if Swift Transformers or Python Transformers
             let tech = if query.contains("swift") {
@@ -831,7 +1256,7 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
     }
 
     // Check for QuickNode/Solana keywords
-    for keyword in QUICKNODE_KEYWORDS.iter() {
+    for keyword in rules.keywords("quicknode").iter() {
         if keyword_matches(query, keyword) {
             // Determine category based on query content
             let tech = if query.contains("websocket") || query.contains("subscribe") {
@@ -846,9 +1271,9 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
     }
 
     // Check for Claude Agent SDK keywords (before MDN since SDK uses JavaScript/TypeScript)
-    for keyword in CLAUDE_AGENT_SDK_KEYWORDS.iter() {
+    for keyword in rules.keywords("claude_agent_sdk").iter() {
         if keyword_matches(query, keyword) {
-            if matches!(*keyword, "query" | "mcp" | "mcpservers") && !detect_claude_agent_sdk_signal(query) {
+            if matches!(keyword.as_str(), "query" | "mcp" | "mcpservers") && !detect_claude_agent_sdk_signal(query) {
                 continue;
             }
             // Determine language based on query content
@@ -865,7 +1290,7 @@ fn detect_provider_and_technology(raw_query: &str, query: &str) -> (Option<Provi
     }
 
     // Check for MDN/JavaScript keywords
-    for keyword in MDN_KEYWORDS.iter() {
+    for keyword in rules.keywords("mdn").iter() {
         if contains_word(query, keyword) {
             return (Some(ProviderType::Mdn), Some("mdn:javascript".to_string()));
         }
@@ -904,279 +1329,96 @@ fn extract_keywords(query: &str) -> Vec<String> {
         .collect()
 }
 
+/// Replace the detected provider/technology with explicit `provider`/`technology` arguments.
+///
+/// A provider without a technology keeps the detected technology when detection agreed on
+/// the provider, and otherwise uses the provider's default. A technology without a provider
+/// belongs to the detected provider, or the active one when nothing was detected. Returns
+/// whether anything changed.
+async fn apply_overrides(
+    context: &AppContext,
+    intent: &mut QueryIntent,
+    provider: Option<&str>,
+    technology: Option<&str>,
+) -> Result<bool> {
+    let provider = match provider.map(str::trim).filter(|slug| !slug.is_empty()) {
+        Some(slug) => {
+            Some(ProviderType::from_slug(slug).with_context(|| format!("Unknown provider \"{slug}\""))?)
+        }
+        None => None,
+    };
+    let technology = technology.map(str::trim).filter(|technology| !technology.is_empty());
+    let provider = match (provider, technology) {
+        (None, None) => return Ok(false),
+        (Some(provider), _) => provider,
+        (None, Some(_)) => match intent.provider {
+            Some(detected) => detected,
+            None => *context.state.active_provider.read().await,
+        },
+    };
+
+    let technology = match technology {
+        Some(technology) => technology_identifier(provider, technology)?,
+        None => match (&intent.provider, &intent.technology) {
+            (Some(detected), Some(technology)) if *detected == provider => technology.clone(),
+            _ => default_technology(provider).to_string(),
+        },
+    };
+    intent.provider = Some(provider);
+    intent.technology = Some(technology);
+    Ok(true)
+}
+
+/// Identifier `select_technology` expects for a `technology` argument.
+///
+/// Fails for blank names and names ending in `/`, which leave no technology to select.
+pub(crate) fn technology_identifier(provider: ProviderType, technology: &str) -> Result<String> {
+    let technology = technology.trim();
+    if technology.is_empty() || technology.ends_with('/') {
+        bail!("\"{technology}\" does not name a technology");
+    }
+    Ok(match provider {
+        ProviderType::Apple if !technology.starts_with("doc://") => format!(
+            "doc://com.apple.documentation/documentation/{}",
+            technology.to_lowercase()
+        ),
+        ProviderType::Rust if !technology.starts_with("rust:") => format!("rust:{technology}"),
+        _ => technology.to_string(),
+    })
+}
+
+/// Technology searched when only a provider is given.
+fn default_technology(provider: ProviderType) -> &'static str {
+    match provider {
+        ProviderType::Apple => "doc://com.apple.documentation/documentation/swiftui",
+        ProviderType::Rust => "rust:std",
+        ProviderType::Telegram => "telegram:methods",
+        ProviderType::TON => "ton:accounts",
+        ProviderType::Cocoon => "cocoon:architecture",
+        ProviderType::Mdn => "mdn:javascript",
+        ProviderType::WebFrameworks => "webfw:react",
+        ProviderType::Mlx => "mlx:python",
+        ProviderType::HuggingFace => "hf:transformers",
+        ProviderType::QuickNode => "quicknode:solana:http",
+        ProviderType::ClaudeAgentSdk => "agent-sdk:typescript",
+        ProviderType::Bitcoind => "vertcoin:blockchain",
+        ProviderType::Cuda => "cuda:runtime",
+        ProviderType::Solana => "solana:core",
+    }
+}
+
 /// Resolve and set the appropriate technology based on intent
 async fn resolve_technology(
     context: &Arc<AppContext>,
     intent: &QueryIntent,
+    explicit: bool,
 ) -> Result<(ProviderType, String)> {
-    // If we detected a specific provider/technology, set it
-    if let (Some(provider), Some(tech_id)) = (&intent.provider, &intent.technology) {
-        // Set the active provider
-        *context.state.active_provider.write().await = *provider;
-
-        match provider {
-            ProviderType::Apple => {
-                // Clear cached framework data to force reload for new technology
-                *context.state.framework_cache.write().await = None;
-                *context.state.framework_index.write().await = None;
-
-                // Find and set the Apple technology
-                let technologies = context.client.get_technologies().await?;
-                if let Some(tech) = technologies.get(tech_id) {
-                    *context.state.active_technology.write().await = Some(tech.clone());
-                    return Ok((*provider, tech.title.clone()));
-                }
-                // Fallback: create a basic technology object
-                let title = tech_id
-                    .split('/')
-                    .next_back()
-                    .unwrap_or("Unknown")
-                    .to_string();
-                let capitalized = title
-                    .chars()
-                    .next()
-                    .map(|c| c.to_uppercase().to_string())
-                    .unwrap_or_default()
-                    + &title[1..];
-                let fallback_tech = docs_mcp_client::types::Technology {
-                    identifier: tech_id.clone(),
-                    title: capitalized.clone(),
-                    r#abstract: vec![],
-                    kind: "symbol".to_string(),
-                    role: "collection".to_string(),
-                    url: format!("https://developer.apple.com/documentation/{}", title),
-                };
-                *context.state.active_technology.write().await = Some(fallback_tech);
-                Ok((*provider, capitalized))
-            }
-            ProviderType::Rust => {
-                let crate_name = tech_id.strip_prefix("rust:").unwrap_or("std");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: format!("Rust {}", crate_name),
-                    description: format!("Rust {} crate documentation", crate_name),
-                    provider: ProviderType::Rust,
-                    url: Some(format!("https://docs.rs/{}", crate_name)),
-                    kind: multi_provider_client::types::TechnologyKind::RustCrate,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, format!("Rust {}", crate_name)))
-            }
-            ProviderType::Telegram => {
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: "Telegram Bot API".to_string(),
-                    description: "Telegram Bot API methods and types".to_string(),
-                    provider: ProviderType::Telegram,
-                    url: Some("https://core.telegram.org/bots/api".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::ApiCategory,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, "Telegram Bot API".to_string()))
-            }
-            ProviderType::TON => {
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: "TON API".to_string(),
-                    description: "TON blockchain API".to_string(),
-                    provider: ProviderType::TON,
-                    url: Some("https://tonapi.io/docs".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::BlockchainApi,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, "TON API".to_string()))
-            }
-            ProviderType::Cocoon => {
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: "Cocoon".to_string(),
-                    description: "Cocoon confidential computing".to_string(),
-                    provider: ProviderType::Cocoon,
-                    url: Some("https://cocoon.dev/docs".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::DocSection,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, "Cocoon".to_string()))
-            }
-            ProviderType::Mdn => {
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: "MDN Web Docs".to_string(),
-                    description: "JavaScript, Web APIs, and TypeScript documentation".to_string(),
-                    provider: ProviderType::Mdn,
-                    url: Some("https://developer.mozilla.org".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::MdnCategory,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, "MDN Web Docs".to_string()))
-            }
-            ProviderType::WebFrameworks => {
-                // Parse framework from tech_id (e.g., "webfw:react" -> "React")
-                let framework_name = tech_id
-                    .strip_prefix("webfw:")
-                    .map(|f| match f {
-                        "react" => "React",
-                        "nextjs" => "Next.js",
-                        "nodejs" => "Node.js",
-                        "bun" => "Bun",
-                        _ => "React",
-                    })
-                    .unwrap_or("React");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: framework_name.to_string(),
-                    description: format!("{} documentation", framework_name),
-                    provider: ProviderType::WebFrameworks,
-                    url: Some(match framework_name {
-                        "React" => "https://react.dev".to_string(),
-                        "Next.js" => "https://nextjs.org/docs".to_string(),
-                        "Node.js" => "https://nodejs.org/api".to_string(),
-                        "Bun" => "https://bun.sh/docs".to_string(),
-                        _ => "https://react.dev".to_string(),
-                    }),
-                    kind: multi_provider_client::types::TechnologyKind::WebFramework,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, framework_name.to_string()))
-            }
-            ProviderType::Mlx => {
-                // Parse language from tech_id (e.g., "mlx:swift" -> "MLX Swift")
-                let lang_name = tech_id
-                    .strip_prefix("mlx:")
-                    .map(|l| match l {
-                        "swift" => "MLX Swift",
-                        "python" => "MLX Python",
-                        _ => "MLX Swift",
-                    })
-                    .unwrap_or("MLX Swift");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: lang_name.to_string(),
-                    description: format!("{} - Machine learning on Apple Silicon", lang_name),
-                    provider: ProviderType::Mlx,
-                    url: Some("https://ml-explore.github.io/mlx-swift/documentation/mlx".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::MlxFramework,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, lang_name.to_string()))
-            }
-            ProviderType::HuggingFace => {
-                // Parse technology from tech_id (e.g., "hf:transformers" -> "Transformers")
-                let tech_name = tech_id
-                    .strip_prefix("hf:")
-                    .map(|t| match t {
-                        "transformers" => "Transformers",
-                        "swift-transformers" => "Swift Transformers",
-                        "models" => "Models",
-                        _ => "Transformers",
-                    })
-                    .unwrap_or("Transformers");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: tech_name.to_string(),
-                    description: format!("Hugging Face {} documentation", tech_name),
-                    provider: ProviderType::HuggingFace,
-                    url: Some("https://huggingface.co/docs/transformers".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::HfLibrary,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, tech_name.to_string()))
-            }
-            ProviderType::QuickNode => {
-                // Parse category from tech_id (e.g., "quicknode:solana:http" -> "Solana HTTP Methods")
-                let category_name = tech_id
-                    .strip_prefix("quicknode:solana:")
-                    .map(|c| match c {
-                        "http" => "Solana HTTP Methods",
-                        "websocket" => "Solana WebSocket Methods",
-                        "marketplace" => "Solana Marketplace Add-ons",
-                        _ => "Solana HTTP Methods",
-                    })
-                    .unwrap_or("Solana HTTP Methods");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: category_name.to_string(),
-                    description: format!("QuickNode {} documentation", category_name),
-                    provider: ProviderType::QuickNode,
-                    url: Some("https://www.quicknode.com/docs/solana".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::QuickNodeApi,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, category_name.to_string()))
-            }
-            ProviderType::ClaudeAgentSdk => {
-                // Parse language from tech_id (e.g., "agent-sdk:typescript" -> "Claude Agent SDK (TypeScript)")
-                let lang_name = tech_id
-                    .strip_prefix("agent-sdk:")
-                    .map(|l| match l {
-                        "typescript" => "Claude Agent SDK (TypeScript)",
-                        "python" => "Claude Agent SDK (Python)",
-                        _ => "Claude Agent SDK (TypeScript)",
-                    })
-                    .unwrap_or("Claude Agent SDK (TypeScript)");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: lang_name.to_string(),
-                    description: "Build AI agents with Claude Code capabilities".to_string(),
-                    provider: ProviderType::ClaudeAgentSdk,
-                    url: Some("https://docs.anthropic.com/en/docs/agents-and-tools/claude-agent-sdk".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::AgentSdkLibrary,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, lang_name.to_string()))
-            }
-            ProviderType::Vertcoin => {
-                // Parse category from tech_id (e.g., "vertcoin:blockchain" -> "Vertcoin (Blockchain RPC)")
-                let category_name = tech_id
-                    .strip_prefix("vertcoin:")
-                    .map(|c| match c {
-                        "blockchain" => "Vertcoin (Blockchain RPC)",
-                        "wallet" => "Vertcoin (Wallet)",
-                        "mining" => "Vertcoin (Verthash Mining)",
-                        "network" => "Vertcoin (Network)",
-                        "specs" => "Vertcoin (Specifications)",
-                        _ => "Vertcoin (Blockchain RPC)",
-                    })
-                    .unwrap_or("Vertcoin (Blockchain RPC)");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: category_name.to_string(),
-                    description: "Vertcoin blockchain RPC and Verthash mining documentation".to_string(),
-                    provider: ProviderType::Vertcoin,
-                    url: Some("https://github.com/vertcoin-project/vertcoin-core".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::VertcoinApi,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, category_name.to_string()))
-            }
-            ProviderType::Cuda => {
-                // Parse category from tech_id (e.g., "cuda:runtime" -> "CUDA Runtime API")
-                let category_name = tech_id
-                    .strip_prefix("cuda:")
-                    .map(|c| match c {
-                        "runtime" => "CUDA Runtime API",
-                        "kernels" => "CUDA Kernel Programming",
-                        "libraries" => "CUDA Libraries",
-                        "gpu" => "GPU Specifications (RTX 3070/4090)",
-                        "optimization" => "CUDA Optimization",
-                        "memory" => "CUDA Memory Management",
-                        "stream" => "CUDA Streams",
-                        "event" => "CUDA Events",
-                        _ => "CUDA Runtime API",
-                    })
-                    .unwrap_or("CUDA Runtime API");
-                let unified = UnifiedTechnology {
-                    identifier: tech_id.clone(),
-                    title: category_name.to_string(),
-                    description: "CUDA GPU programming for RTX 3070 and RTX 4090".to_string(),
-                    provider: ProviderType::Cuda,
-                    url: Some("https://docs.nvidia.com/cuda".to_string()),
-                    kind: multi_provider_client::types::TechnologyKind::CudaApi,
-                };
-                *context.state.active_unified_technology.write().await = Some(unified);
-                Ok((*provider, category_name.to_string()))
-            }
-        }
+    // If we detected a specific provider/technology, set it unless `choose_technology` pinned
+    // one; explicit arguments always win
+    let pinned = *context.state.technology_pinned.read().await && !explicit;
+    if let (false, Some(provider), Some(tech_id)) = (pinned, &intent.provider, &intent.technology) {
+        let title = select_technology(context, *provider, tech_id).await?;
+        Ok((*provider, title))
     } else {
         // No provider detected - check if there's an active technology, otherwise default to Apple/SwiftUI
         let current_provider = *context.state.active_provider.read().await;
@@ -1235,6 +1477,394 @@ async fn resolve_technology(
     }
 }
 
+/// Apple technology for `tech_id` when it is missing from the technologies index.
+fn fallback_technology(tech_id: &str) -> docs_mcp_client::types::Technology {
+    let name = tech_id.rsplit('/').find(|segment| !segment.is_empty()).unwrap_or("Unknown");
+    let mut chars = name.chars();
+    let title = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default();
+    docs_mcp_client::types::Technology {
+        identifier: tech_id.to_string(),
+        title,
+        r#abstract: vec![],
+        kind: "symbol".to_string(),
+        role: "collection".to_string(),
+        url: format!("https://developer.apple.com/documentation/{name}"),
+    }
+}
+
+/// Make `tech_id` of `provider` the active technology of `context`; returns its title.
+pub(crate) async fn select_technology(
+    context: &AppContext,
+    provider: ProviderType,
+    tech_id: &str,
+) -> Result<String> {
+    // Set the active provider
+    *context.state.active_provider.write().await = provider;
+
+    match provider {
+        ProviderType::Apple => {
+            // Clear cached framework data to force reload for new technology
+            *context.state.framework_cache.write().await = None;
+            *context.state.framework_index.write().await = None;
+
+            // Find and set the Apple technology
+            let technologies = context.client.get_technologies().await?;
+            if let Some(tech) = technologies.get(tech_id) {
+                *context.state.active_technology.write().await = Some(tech.clone());
+                return Ok(tech.title.clone());
+            }
+            // Fallback: create a basic technology object
+            let fallback_tech = fallback_technology(tech_id);
+            let capitalized = fallback_tech.title.clone();
+            *context.state.active_technology.write().await = Some(fallback_tech);
+            Ok(capitalized)
+        }
+        ProviderType::Rust if tech_id == CARGO_BOOK_TECHNOLOGY => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "Cargo Book".to_string(),
+                description: "Cargo manifest format, profiles, workspaces, configuration and commands"
+                    .to_string(),
+                provider: ProviderType::Rust,
+                url: Some("https://doc.rust-lang.org/cargo/".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::RustCrate,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("Cargo Book".to_string())
+        }
+        ProviderType::Rust => {
+            let crate_name = tech_id.strip_prefix("rust:").unwrap_or("std");
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: format!("Rust {}", crate_name),
+                description: format!("Rust {} crate documentation", crate_name),
+                provider: ProviderType::Rust,
+                url: Some(format!("https://docs.rs/{}", crate_name)),
+                kind: multi_provider_client::types::TechnologyKind::RustCrate,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(format!("Rust {}", crate_name))
+        }
+        ProviderType::Telegram if tech_id == MINI_APPS_TECHNOLOGY => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "Telegram Mini Apps".to_string(),
+                description: "Telegram Mini Apps WebApp JavaScript API, themes and init data validation".to_string(),
+                provider: ProviderType::Telegram,
+                url: Some("https://core.telegram.org/bots/webapps".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::ApiCategory,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("Telegram Mini Apps".to_string())
+        }
+        ProviderType::Telegram if tech_id == TDLIB_TECHNOLOGY => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "TDLib".to_string(),
+                description: "TDLib classes, objects and functions for building Telegram clients".to_string(),
+                provider: ProviderType::Telegram,
+                url: Some("https://core.telegram.org/tdlib/docs/td__api_8h.html".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::ApiCategory,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("TDLib".to_string())
+        }
+        ProviderType::Telegram => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "Telegram Bot API".to_string(),
+                description: "Telegram Bot API methods and types".to_string(),
+                provider: ProviderType::Telegram,
+                url: Some("https://core.telegram.org/bots/api".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::ApiCategory,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("Telegram Bot API".to_string())
+        }
+        ProviderType::TON if tech_id == EXIT_CODES_TECHNOLOGY => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "TON Exit Codes".to_string(),
+                description: "TVM compute and action phase exit codes and common contract error codes".to_string(),
+                provider: ProviderType::TON,
+                url: Some("https://docs.ton.org/v3/documentation/tvm/tvm-exit-codes".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::BlockchainApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("TON Exit Codes".to_string())
+        }
+        ProviderType::TON if tech_id == TLB_TECHNOLOGY => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "TON TL-B Schemas".to_string(),
+                description: "TL-B layouts of jetton, NFT and wallet messages".to_string(),
+                provider: ProviderType::TON,
+                url: Some("https://github.com/ton-blockchain/TEPs".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::BlockchainApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("TON TL-B Schemas".to_string())
+        }
+        ProviderType::TON => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "TON API".to_string(),
+                description: "TON blockchain API".to_string(),
+                provider: ProviderType::TON,
+                url: Some("https://tonapi.io/docs".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::BlockchainApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("TON API".to_string())
+        }
+        ProviderType::Cocoon => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "Cocoon".to_string(),
+                description: "Cocoon confidential computing".to_string(),
+                provider: ProviderType::Cocoon,
+                url: Some("https://cocoon.dev/docs".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::DocSection,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("Cocoon".to_string())
+        }
+        ProviderType::Mdn if tech_id == MDN_CSS_TECHNOLOGY || tech_id == MDN_HTML_TECHNOLOGY => {
+            let (title, description, section) = if tech_id == MDN_CSS_TECHNOLOGY {
+                ("MDN CSS Reference", "CSS properties, selectors, at-rules, functions and data types", "CSS")
+            } else {
+                ("MDN HTML Reference", "HTML elements, their attributes and accessibility", "HTML")
+            };
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: title.to_string(),
+                description: description.to_string(),
+                provider: ProviderType::Mdn,
+                url: Some(format!("https://developer.mozilla.org/en-US/docs/Web/{section}")),
+                kind: multi_provider_client::types::TechnologyKind::MdnCategory,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(title.to_string())
+        }
+        ProviderType::Mdn => {
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: "MDN Web Docs".to_string(),
+                description: "JavaScript, Web APIs, and TypeScript documentation".to_string(),
+                provider: ProviderType::Mdn,
+                url: Some("https://developer.mozilla.org".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::MdnCategory,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok("MDN Web Docs".to_string())
+        }
+        ProviderType::WebFrameworks => {
+            // Parse framework from tech_id (e.g., "webfw:react" -> "React", "webfw:nodejs@20" -> "Node.js 20")
+            let (framework_id, release) = web_framework_technology(tech_id);
+            let framework_name = match framework_id {
+                "react" => "React",
+                "nextjs" => "Next.js",
+                "nodejs" => "Node.js",
+                "bun" => "Bun",
+                "express" => "Express",
+                "astro" => "Astro",
+                "react-router" | "remix" => "React Router",
+                "react-native" => "React Native",
+                "typescript" => "TypeScript",
+                _ => "React",
+            };
+            let title = release.map_or_else(|| framework_name.to_string(), |line| format!("{framework_name} {line}"));
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: title.clone(),
+                description: format!("{} documentation", title),
+                provider: ProviderType::WebFrameworks,
+                url: Some(match framework_name {
+                    "React" => "https://react.dev".to_string(),
+                    "Next.js" => "https://nextjs.org/docs".to_string(),
+                    "Node.js" => node_docs_base(release),
+                    "Bun" => "https://bun.sh/docs".to_string(),
+                    "Express" => "https://expressjs.com".to_string(),
+                    "Astro" => "https://docs.astro.build".to_string(),
+                    "React Router" => "https://reactrouter.com".to_string(),
+                    "React Native" => "https://reactnative.dev/docs".to_string(),
+                    "TypeScript" => "https://www.typescriptlang.org/docs".to_string(),
+                    _ => "https://react.dev".to_string(),
+                }),
+                kind: multi_provider_client::types::TechnologyKind::WebFramework,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(title)
+        }
+        ProviderType::Mlx => {
+            // Parse language from tech_id (e.g., "mlx:swift" -> "MLX Swift")
+            let lang_name = tech_id
+                .strip_prefix("mlx:")
+                .map(|l| match l {
+                    "swift" => "MLX Swift",
+                    "python" => "MLX Python",
+                    "lm" => "mlx-lm",
+                    "examples" => "MLX Examples",
+                    _ => "MLX Swift",
+                })
+                .unwrap_or("MLX Swift");
+            let url = match tech_id.strip_prefix("mlx:") {
+                Some("python") => "https://ml-explore.github.io/mlx/build/html",
+                Some("lm") => "https://github.com/ml-explore/mlx-lm",
+                Some("examples") => "https://github.com/ml-explore/mlx-examples",
+                _ => "https://ml-explore.github.io/mlx-swift/documentation/mlx",
+            };
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: lang_name.to_string(),
+                description: format!("{} - Machine learning on Apple Silicon", lang_name),
+                provider: ProviderType::Mlx,
+                url: Some(url.to_string()),
+                kind: multi_provider_client::types::TechnologyKind::MlxFramework,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(lang_name.to_string())
+        }
+        ProviderType::HuggingFace => {
+            // Parse technology from tech_id (e.g., "hf:transformers" -> "Transformers")
+            let tech_name = tech_id
+                .strip_prefix("hf:")
+                .map(|t| match t {
+                    "transformers" => "Transformers",
+                    "swift-transformers" => "Swift Transformers",
+                    "models" => "Models",
+                    "hub-api" => "Hub HTTP API",
+                    _ => "Transformers",
+                })
+                .unwrap_or("Transformers");
+            let url = if tech_id == "hf:hub-api" {
+                multi_provider_client::huggingface::hub_api::HUB_API_DOCS_URL
+            } else {
+                "https://huggingface.co/docs/transformers"
+            };
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: tech_name.to_string(),
+                description: format!("Hugging Face {} documentation", tech_name),
+                provider: ProviderType::HuggingFace,
+                url: Some(url.to_string()),
+                kind: multi_provider_client::types::TechnologyKind::HfLibrary,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(tech_name.to_string())
+        }
+        ProviderType::QuickNode => {
+            // Parse chain and category from tech_id (e.g., "quicknode:ethereum:debug" -> "Ethereum Debug Methods")
+            let (chain, category) = parse_category_identifier(tech_id).unwrap_or((QuickNodeChain::Solana, "http"));
+            let category_name = category_title(chain, category);
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: category_name.clone(),
+                description: format!("QuickNode {} documentation", category_name),
+                provider: ProviderType::QuickNode,
+                url: Some(chain.docs_url()),
+                kind: multi_provider_client::types::TechnologyKind::QuickNodeApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(category_name.to_string())
+        }
+        ProviderType::ClaudeAgentSdk => {
+            // Parse language from tech_id (e.g., "agent-sdk:typescript" -> "Claude Agent SDK (TypeScript)")
+            let lang_name = tech_id
+                .strip_prefix("agent-sdk:")
+                .map(|l| match l {
+                    "typescript" => "Claude Agent SDK (TypeScript)",
+                    "python" => "Claude Agent SDK (Python)",
+                    _ => "Claude Agent SDK (TypeScript)",
+                })
+                .unwrap_or("Claude Agent SDK (TypeScript)");
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: lang_name.to_string(),
+                description: "Build AI agents with Claude Code capabilities".to_string(),
+                provider: ProviderType::ClaudeAgentSdk,
+                url: Some("https://docs.anthropic.com/en/docs/agents-and-tools/claude-agent-sdk".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::AgentSdkLibrary,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(lang_name.to_string())
+        }
+        ProviderType::Bitcoind => {
+            // Parse coin and category from tech_id (e.g., "litecoin:mining" -> "Litecoin (Scrypt Mining)")
+            let (coin, category) = parse_bitcoind_category(tech_id).unwrap_or((BitcoindCoin::Vertcoin, "blockchain"));
+            let category_name = match category {
+                "wallet" => format!("{} (Wallet)", coin.name()),
+                "mining" => format!("{} ({} Mining)", coin.name(), coin.pow()),
+                "network" => format!("{} (Network)", coin.name()),
+                "specs" => format!("{} (Specifications)", coin.name()),
+                _ => format!("{} (Blockchain RPC)", coin.name()),
+            };
+            let unified = UnifiedTechnology {
+                identifier: format!("{}:{category}", coin.slug()),
+                title: category_name.clone(),
+                description: format!("{} node RPC and {} mining documentation", coin.name(), coin.pow()),
+                provider: ProviderType::Bitcoind,
+                url: Some(format!("https://github.com/{}", coin.repository())),
+                kind: multi_provider_client::types::TechnologyKind::BitcoindApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(category_name)
+        }
+        ProviderType::Cuda => {
+            // Parse category from tech_id (e.g., "cuda:runtime" -> "CUDA Runtime API")
+            let category_name = tech_id
+                .strip_prefix("cuda:")
+                .map(|c| match c {
+                    "runtime" => "CUDA Runtime API",
+                    "kernels" => "CUDA Kernel Programming",
+                    "libraries" => "CUDA Libraries",
+                    "cublas" => "cuBLAS",
+                    "cudnn" => "cuDNN",
+                    "cufft" => "cuFFT",
+                    "thrust" => "Thrust",
+                    "cub" => "CUB",
+                    "gpu" => "GPU Specifications (RTX 3070/4090)",
+                    "optimization" => "CUDA Optimization",
+                    "memory" => "CUDA Memory Management",
+                    "stream" => "CUDA Streams",
+                    "event" => "CUDA Events",
+                    _ => "CUDA Runtime API",
+                })
+                .unwrap_or("CUDA Runtime API");
+            let unified = UnifiedTechnology {
+                identifier: tech_id.to_string(),
+                title: category_name.to_string(),
+                description: "CUDA GPU programming for RTX 3070 and RTX 4090".to_string(),
+                provider: ProviderType::Cuda,
+                url: Some("https://docs.nvidia.com/cuda".to_string()),
+                kind: multi_provider_client::types::TechnologyKind::CudaApi,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(category_name.to_string())
+        }
+        ProviderType::Solana => {
+            let source = SolanaDocSource::from_identifier(tech_id).unwrap_or(SolanaDocSource::Docs);
+            let category_name = match source {
+                SolanaDocSource::Docs => "Solana Core Concepts",
+                SolanaDocSource::Cookbook => "Solana Cookbook",
+            };
+            let unified = UnifiedTechnology {
+                identifier: source.identifier().to_string(),
+                title: category_name.to_string(),
+                description: format!("{} documentation", source.name()),
+                provider: ProviderType::Solana,
+                url: Some(source.base_url().to_string()),
+                kind: multi_provider_client::types::TechnologyKind::SolanaDocs,
+            };
+            *context.state.active_unified_technology.write().await = Some(unified);
+            Ok(category_name.to_string())
+        }
+    }
+}
+
 /// Execute a how-to query - focuses on recipes and guided steps
 async fn execute_howto_query(
     context: &Arc<AppContext>,
@@ -1253,6 +1883,10 @@ async fn execute_howto_query(
             if let Some(tip) = entry.quick_tip {
                 result.summary = format!("{}\n\n**Tip:** {}", result.summary, tip);
             }
+            let pitfalls = knowledge::pitfalls(entry);
+            if !pitfalls.is_empty() {
+                result.summary = format!("{}\n\n**Pitfalls:** {}", result.summary, pitfalls.join(" "));
+            }
         }
     }
 
@@ -1285,7 +1919,7 @@ async fn execute_search_query(
         // Rust but not crate names that might be search terms
         "rust", "crate", "cargo",
         // Telegram but not "bot" as that might be a search term
-        "telegram",
+        "telegram", "tdlib",
         // TON blockchain but not "wallet" as that's a search term
         "ton", "blockchain", "tonapi",
         // Cocoon
@@ -1294,12 +1928,14 @@ async fn execute_search_query(
         "mlx", "mlxswift",
         // Bun runtime provider name
         "bun", "bunjs",
+        // Express, Astro, Remix and TypeScript provider names
+        "express", "expressjs", "astro", "astrojs", "remix", "typescript",
         // Hugging Face but not model names that might be search terms
         "huggingface", "hf", "transformers",
         // Claude Agent SDK provider names only - keep class names like "claudesdkclient", "claudeclient"
         "claude", "agent", "sdk", "claudeagentsdk",
-        // Vertcoin provider names
-        "vertcoin", "vtc", "verthash",
+        // bitcoind provider and coin names
+        "vertcoin", "vtc", "verthash", "bitcoin", "btc", "litecoin", "ltc",
     ];
 
     let search_keywords: Vec<&str> = intent
@@ -1317,19 +1953,75 @@ async fn execute_search_query(
     };
 
     match provider {
-        ProviderType::Apple => search_apple(context, &search_query, max_results).await,
+        ProviderType::Apple if is_tutorial_query(intent) => {
+            match search_apple_tutorials(context, &search_query, max_results).await {
+                Ok(results) if !results.is_empty() => Ok(results),
+                _ => search_apple(context, &search_query, intent, max_results).await,
+            }
+        }
+        ProviderType::Apple => search_apple(context, &search_query, intent, max_results).await,
+        ProviderType::Rust if find_error_code(&intent.raw_query).is_some() => {
+            match search_rust_error_code(context, &intent.raw_query).await {
+                Ok(results) => Ok(results),
+                Err(_) => search_rust(context, intent, &search_query, max_results).await,
+            }
+        }
+        ProviderType::Rust if intent.technology.as_deref() == Some(CARGO_BOOK_TECHNOLOGY) => {
+            search_cargo_book(context, &intent.raw_query, max_results).await
+        }
         ProviderType::Rust => search_rust(context, intent, &search_query, max_results).await,
+        ProviderType::Telegram if intent.technology.as_deref() == Some(MINI_APPS_TECHNOLOGY) => {
+            search_mini_apps(context, &search_query, max_results).await
+        }
+        ProviderType::Telegram if intent.technology.as_deref() == Some(TDLIB_TECHNOLOGY) => {
+            search_tdlib(context, &search_query, max_results).await
+        }
         ProviderType::Telegram => search_telegram(context, &search_query, max_results).await,
+        ProviderType::TON if intent.technology.as_deref() == Some(EXIT_CODES_TECHNOLOGY) => {
+            // The raw query keeps negative and hex codes that keyword extraction drops
+            let items = context.providers.ton.search_exit_codes(&intent.raw_query);
+            Ok(items.into_iter().take(max_results).map(ton_result).collect())
+        }
+        ProviderType::TON if intent.technology.as_deref() == Some(TLB_TECHNOLOGY) => {
+            let items = context.providers.ton.search_tlb_schemas(&search_query.to_lowercase());
+            Ok(items.into_iter().take(max_results).map(ton_result).collect())
+        }
         ProviderType::TON => search_ton(context, &search_query, max_results).await,
         ProviderType::Cocoon => search_cocoon(context, &search_query, max_results).await,
-        ProviderType::Mdn => search_mdn(context, &search_query, max_results).await,
+        ProviderType::Mdn if intent.technology.as_deref() == Some(MDN_CSS_TECHNOLOGY) => {
+            let css_query = css_search_query(&intent.raw_query, &intent.keywords);
+            search_mdn(context, &css_query, Some(MdnCategory::Css), max_results).await
+        }
+        ProviderType::Mdn if intent.technology.as_deref() == Some(MDN_HTML_TECHNOLOGY) => {
+            let html_query = html_search_query(&intent.raw_query, &search_query);
+            search_mdn(context, &html_query, Some(MdnCategory::Html), max_results).await
+        }
+        ProviderType::Mdn => search_mdn(context, &search_query, None, max_results).await,
         ProviderType::WebFrameworks => search_web_frameworks(context, intent, &search_query, max_results).await,
         ProviderType::Mlx => search_mlx(context, intent, &search_query, max_results).await,
         ProviderType::HuggingFace => search_huggingface(context, intent, &search_query, max_results).await,
-        ProviderType::QuickNode => search_quicknode(context, &search_query, max_results).await,
+        ProviderType::QuickNode => {
+            let chain = intent
+                .technology
+                .as_deref()
+                .and_then(parse_category_identifier)
+                .map(|(chain, _)| chain);
+            search_quicknode(context, &search_query, chain, max_results).await
+        }
         ProviderType::ClaudeAgentSdk => search_claude_agent_sdk(context, intent, &search_query, max_results).await,
-        ProviderType::Vertcoin => search_vertcoin(context, &search_query, max_results).await,
+        ProviderType::Bitcoind => {
+            let coin = intent
+                .technology
+                .as_deref()
+                .and_then(parse_bitcoind_category)
+                .map_or(BitcoindCoin::Vertcoin, |(coin, _)| coin);
+            search_bitcoind(context, &search_query, coin, max_results).await
+        }
         ProviderType::Cuda => search_cuda(context, &search_query, max_results).await,
+        ProviderType::Solana => {
+            let source = intent.technology.as_deref().and_then(SolanaDocSource::from_identifier);
+            search_solana(context, &search_query, source, max_results).await
+        }
     }
 }
 
@@ -1363,6 +2055,7 @@ static SEARCH_SYNONYMS: Lazy<std::collections::HashMap<&'static str, Vec<&'stati
 async fn search_apple(
     context: &Arc<AppContext>,
     query: &str,
+    intent: &QueryIntent,
     max_results: usize,
 ) -> Result<Vec<DocResult>> {
     use docs_mcp_client::types::extract_text;
@@ -1376,8 +2069,16 @@ async fn search_apple(
         .clone()
         .context("No Apple technology selected")?;
 
-    // Load the framework index
+    let language = intent.language;
+    let platform = intent.platform.as_deref();
+    let keep = |entry: &crate::state::FrameworkIndexEntry| {
+        platform.map_or(true, |platform| entry.available_on(platform))
+            && (intent.include_beta || !entry.reference.platforms.as_deref().is_some_and(beta_only))
+    };
+
+    // Load the framework index, keeping only symbols available on the requested platform
     let mut index = ensure_framework_index(context).await?;
+    index.retain(|entry| keep(entry));
 
     // Build search terms with synonym expansion
     let query_lower = query.to_lowercase();
@@ -1445,7 +2146,8 @@ async fn search_apple(
         })
         .collect();
 
-    matches.sort_by(|a, b| b.0.cmp(&a.0));
+    matches.sort_by_key(|m| std::cmp::Reverse(m.0));
+    dedupe_matches(&mut matches);
 
     // If no good symbol matches found (only articles/collections), expand the index with symbols from topic sections
     let has_symbol_matches = matches.iter().take(5).any(|(_, entry)| {
@@ -1464,6 +2166,7 @@ async fn search_apple(
             .collect();
         if !identifiers.is_empty() {
             index = expand_identifiers(context, &identifiers).await?;
+            index.retain(|entry| keep(entry));
 
             // Re-search with expanded index
             matches = index
@@ -1516,12 +2219,13 @@ async fn search_apple(
                 })
                 .collect();
 
-            matches.sort_by(|a, b| b.0.cmp(&a.0));
+            matches.sort_by_key(|m| std::cmp::Reverse(m.0));
+            dedupe_matches(&mut matches);
         }
     }
 
     let mut results = Vec::new();
-    for (_, entry) in matches.into_iter().take(max_results) {
+    for (score, entry) in matches.into_iter().take(max_results) {
         let title = entry
             .reference
             .title
@@ -1548,27 +2252,61 @@ async fn search_apple(
             .platforms
             .as_ref()
             .map(|p| docs_mcp_client::types::format_platforms(p));
+        let beta = entry.reference.platforms.as_deref().is_some_and(has_beta);
+
+        let url = Some(apple_web_url(&path));
 
         results.push(DocResult {
             title,
             kind,
             path,
+            url,
+            score: Some(f64::from(score)),
             summary,
             platforms,
             code_sample: None,
+            code_language: None,
             related_apis: Vec::new(),
             full_content: None,
             declaration: None,
             parameters: Vec::new(),
+            beta,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
     // Fetch detailed docs for top results (with full content)
+    let mut unavailable = Vec::new();
     for result in results.iter_mut().take(MAX_DETAILED_DOCS) {
-        if let Ok(doc) = context.client.load_document(&result.path).await {
+        if let Ok(mut doc) = context.client.load_document(&result.path).await {
+            apply_language_variant(&mut doc, language);
             if let Ok(symbol) = serde_json::from_value::<docs_mcp_client::types::SymbolData>(doc.clone()) {
+                // The page has fuller availability than the index; drop what it rules out
+                let page_platforms = &symbol.metadata.platforms;
+                if platform.is_some_and(|platform| !available_on(page_platforms, platform))
+                    || (!intent.include_beta && beta_only(page_platforms))
+                {
+                    unavailable.push(result.path.clone());
+                    continue;
+                }
+                if !page_platforms.is_empty() {
+                    result.beta = has_beta(page_platforms);
+                }
+                // Index titles and availability are Swift's; take the variant's own
+                if language != SymbolLanguage::Swift {
+                    if let Some(title) = &symbol.metadata.title {
+                        result.title = title.clone();
+                    }
+                    if !symbol.metadata.platforms.is_empty() {
+                        result.platforms = Some(docs_mcp_client::types::format_platforms(&symbol.metadata.platforms));
+                    }
+                }
+
                 // Extract code sample if available
                 result.code_sample = extract_code_sample(&symbol);
+                result.code_language = result.code_sample.as_ref().map(|_| "swift".to_string());
 
                 // Extract declaration/signature
                 result.declaration = extract_declaration(&symbol);
@@ -1591,9 +2329,122 @@ async fn search_apple(
         }
     }
 
+    results.retain(|result| !unavailable.contains(&result.path));
+    if !intent.include_beta {
+        // Stable: beta results keep their relative order behind the others
+        results.sort_by_key(|result| result.beta);
+    }
+    Ok(results)
+}
+
+fn is_tutorial_query(intent: &QueryIntent) -> bool {
+    intent
+        .keywords
+        .iter()
+        .any(|keyword| matches!(keyword.as_str(), "tutorial" | "tutorials"))
+}
+
+/// Search the active framework's tutorial collection (`/tutorials/<framework>`) and expand the
+/// best match into its sections, steps and code changes.
+async fn search_apple_tutorials(
+    context: &Arc<AppContext>,
+    query: &str,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    let technology = context
+        .state
+        .active_technology
+        .read()
+        .await
+        .clone()
+        .context("No Apple technology selected")?;
+    let framework = technology
+        .identifier
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let collection = context.client.get_tutorial(&format!("tutorials/{framework}")).await?;
+
+    let query_lower = query.to_lowercase();
+    let terms: Vec<&str> = query_lower
+        .split_whitespace()
+        .filter(|term| !matches!(*term, "tutorial" | "tutorials"))
+        .collect();
+    let mut matches: Vec<_> = collection
+        .chapters
+        .iter()
+        .flat_map(|chapter| chapter.tutorials.iter().map(move |link| (chapter.name.as_str(), link)))
+        .filter_map(|(chapter, link)| {
+            let (title, summary) = (link.title.to_lowercase(), link.summary.to_lowercase());
+            let chapter_lower = chapter.to_lowercase();
+            let score = terms
+                .iter()
+                .map(|term| {
+                    15 * i32::from(title.contains(term))
+                        + 5 * i32::from(summary.contains(term))
+                        + 3 * i32::from(chapter_lower.contains(term))
+                })
+                .sum::<i32>();
+            // A bare "<framework> tutorial" lists the whole collection in order
+            (terms.is_empty() || score > 0).then_some((score.max(1), chapter, link))
+        })
+        .collect();
+    matches.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+    matches.truncate(max_results);
+
+    let mut results = Vec::new();
+    for (index, (score, chapter, link)) in matches.into_iter().enumerate() {
+        let mut result = DocResult {
+            title: link.title.clone(),
+            kind: "tutorial".to_string(),
+            path: link.path.clone(),
+            url: Some(apple_web_url(&link.path)),
+            score: Some(f64::from(score)),
+            summary: link.summary.clone(),
+            platforms: None,
+            code_sample: None,
+            code_language: None,
+            related_apis: vec![chapter.to_string()],
+            full_content: None,
+            declaration: None,
+            parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
+        };
+        if index == 0 {
+            if let Ok(tutorial) = context.client.get_tutorial(&link.path).await {
+                let body = crate::tools::get_documentation::tutorial_body(&tutorial);
+                result.full_content = Some(body.join("\n"));
+                result.related_apis = tutorial
+                    .sections
+                    .iter()
+                    .map(|section| section.title.clone())
+                    .collect();
+            }
+        }
+        results.push(result);
+    }
     Ok(results)
 }
 
+/// Drop entries that point at the same page under different identifiers
+/// (e.g. `doc://…/documentation/SwiftUI/List` vs `documentation/swiftui/list`),
+/// keeping the highest-scored occurrence. Expects `matches` sorted by score.
+fn dedupe_matches(matches: &mut Vec<(i32, &crate::state::FrameworkIndexEntry)>) {
+    let mut seen = std::collections::HashSet::new();
+    matches.retain(|(_, entry)| seen.insert(canonical_entry_key(entry)));
+}
+
+fn canonical_entry_key(entry: &crate::state::FrameworkIndexEntry) -> String {
+    let raw = entry.reference.url.as_deref().unwrap_or(&entry.id);
+    let normalized = normalize_reference_link(raw);
+    let key = if normalized.is_empty() { raw } else { normalized.as_str() };
+    key.trim_end_matches('/').to_lowercase()
+}
+
 /// Search Rust documentation
 async fn search_rust(
     context: &Arc<AppContext>,
@@ -1615,32 +2466,48 @@ async fn search_rust(
         }
     };
 
+    let platform = intent.platform.as_deref();
     let mut results: Vec<DocResult> = items
         .into_iter()
+        .filter(|item| platform.map_or(true, |platform| item.available_on(platform)))
         .take(max_results)
         .map(|item| DocResult {
             title: item.name,
             kind: format!("{:?}", item.kind),
             path: item.path.clone(),
+            url: non_empty_url(item.url),
+            score: None,
             summary: item.summary,
-            platforms: Some(format!("{} v{}", item.crate_name, item.crate_version)),
+            platforms: Some(rust_availability(&item.crate_name, &item.crate_version, &item.platforms)),
             code_sample: None,
+            code_language: None,
             related_apis: Vec::new(),
             full_content: None,
             declaration: None,
             parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         })
         .collect();
 
+    // Pages carry the exact cfg gates; drop items they show are unavailable on the platform
+    let mut unavailable = HashSet::new();
     for result in results.iter_mut().take(MAX_DETAILED_DOCS) {
         let Ok(item) = context.providers.rust.get_item(&result.path).await else {
             continue;
         };
+        if platform.is_some_and(|platform| !item.available_on(platform)) {
+            unavailable.insert(result.path.clone());
+            continue;
+        }
+        result.platforms = Some(rust_availability(&item.crate_name, &item.crate_version, &item.platforms));
 
         result.full_content = item
             .documentation
             .as_deref()
-            .map(|text| trim_text(text, MAX_CONTENT_LENGTH))
+            .map(str::to_string)
             .or_else(|| {
                 if item.summary.is_empty() {
                     None
@@ -1659,6 +2526,7 @@ async fn search_rust(
             .iter()
             .max_by_key(|ex| ex.code.len())
             .map(|ex| ex.code.clone());
+        result.code_language = result.code_sample.as_ref().map(|_| "rust".to_string());
 
         result.related_apis = item
             .methods
@@ -1666,11 +2534,112 @@ async fn search_rust(
             .take(8)
             .map(|method| method.name.clone())
             .collect();
+        result.features = item.required_features.clone();
+
+        // A trait is best described by what implements it
+        if !item.implementors.is_empty() {
+            let types: Vec<String> = item
+                .implementors
+                .iter()
+                .take(MAX_LISTED_IMPLEMENTORS)
+                .map(|block| format!("`{}`", block.self_type))
+                .collect();
+            let more = item.implementors.len().saturating_sub(MAX_LISTED_IMPLEMENTORS);
+            let mut line = format!("Implemented by: {}", types.join(", "));
+            if more > 0 {
+                line.push_str(&format!(" and {more} more"));
+            }
+            let content = result.full_content.get_or_insert_with(String::new);
+            if !content.is_empty() {
+                content.push_str("\n\n");
+            }
+            content.push_str(&line);
+        }
+    }
+    results.retain(|result| !unavailable.contains(&result.path));
+
+    Ok(results)
+}
+
+/// Crate and version of a Rust item, with its cfg gate: "std vlatest · Unix only"
+pub(crate) fn rust_availability(crate_name: &str, version: &str, platforms: &[String]) -> String {
+    let mut availability = format!("{crate_name} v{version}");
+    if !platforms.is_empty() {
+        availability.push_str(" · ");
+        availability.push_str(&platforms.join(" or "));
+        availability.push_str(" only");
     }
+    availability
+}
 
+/// Search the Cargo Book's catalogued topics and read the best matches
+async fn search_cargo_book(
+    context: &Arc<AppContext>,
+    query: &str,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    let mut results = Vec::new();
+    for (index, topic) in cargo_book::search_cargo_topics(query, max_results).into_iter().enumerate() {
+        let mut result = DocResult {
+            title: topic.title.clone(),
+            kind: "cargo book".to_string(),
+            path: topic.path.clone(),
+            url: Some(topic.url()),
+            score: None,
+            summary: String::new(),
+            platforms: Some("Cargo".to_string()),
+            code_sample: None,
+            code_language: None,
+            related_apis: Vec::new(),
+            full_content: None,
+            declaration: None,
+            parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
+        };
+        if index < MAX_DETAILED_DOCS {
+            match context.providers.rust.get_cargo_section(&topic).await {
+                Ok(section) => {
+                    result.summary = section.summary;
+                    result.full_content = Some(section.content);
+                    result.code_sample = section.example;
+                    result.code_language = section.example_language;
+                }
+                Err(error) => tracing::debug!(path = %topic.path, %error, "Cargo Book section unavailable"),
+            }
+        }
+        results.push(result);
+    }
     Ok(results)
 }
 
+/// Explain the rustc error code in `query` from the compiler error index
+async fn search_rust_error_code(context: &Arc<AppContext>, query: &str) -> Result<Vec<DocResult>> {
+    let error = context.providers.rust.get_error_code(query).await?;
+    let example = error.erroneous_example().or_else(|| error.fixed_example());
+    Ok(vec![DocResult {
+        title: error.code.clone(),
+        kind: "error code".to_string(),
+        path: error.code.clone(),
+        url: Some(error.url.clone()),
+        score: None,
+        summary: error.summary.clone(),
+        platforms: Some("rustc".to_string()),
+        code_sample: example.map(|example| example.code.clone()),
+        code_language: example.map(|_| "rust".to_string()),
+        related_apis: Vec::new(),
+        full_content: Some(error.explanation.clone()),
+        declaration: None,
+        parameters: Vec::new(),
+        beta: false,
+        features: Vec::new(),
+        browser_support: Vec::new(),
+        provider: None,
+    }])
+}
+
 /// Search Telegram Bot API
 async fn search_telegram(
     context: &Arc<AppContext>,
@@ -1690,6 +2659,7 @@ async fn search_telegram(
         .take(max_results)
         .map(|item| {
             let path = item.name.clone();
+            let url = Some(telegram_web_url(&item.name));
             let parameters: Vec<(String, String)> = item
                 .fields
                 .iter()
@@ -1699,13 +2669,20 @@ async fn search_telegram(
                 title: item.name,
                 kind: item.kind,
                 path,
+                url,
+                score: None,
                 summary: item.description.clone(),
                 platforms: Some("Telegram Bot API".to_string()),
                 code_sample: None,
+                code_language: None,
                 related_apis: item.fields.iter().take(8).map(|f| f.name.clone()).collect(),
                 full_content: Some(item.description),
                 declaration: None,
                 parameters,
+                beta: false,
+                features: Vec::new(),
+                browser_support: Vec::new(),
+                provider: None,
             }
         })
         .collect();
@@ -1713,66 +2690,98 @@ async fn search_telegram(
     Ok(results)
 }
 
-/// Search TON documentation (API, security patterns, docs articles)
-async fn search_ton(
+/// Search the Telegram Mini Apps platform docs
+async fn search_mini_apps(
     context: &Arc<AppContext>,
     query: &str,
     max_results: usize,
 ) -> Result<Vec<DocResult>> {
-    // Use the unified search_all method that searches API, security patterns, and documentation
-    let items = match context.providers.ton.search_all(query).await {
-        Ok(items) => items,
+    let sections = match context.providers.telegram.search_mini_apps(query).await {
+        Ok(sections) => sections,
         Err(e) => {
-            tracing::warn!(error = %e, "TON search failed, returning empty results");
+            tracing::warn!(error = %e, "Telegram Mini Apps search failed, returning empty results");
             return Ok(Vec::new());
         }
     };
 
-    let results = items
+    let results = sections
         .into_iter()
         .take(max_results)
-        .map(|item| {
-            let code_sample = item
-                .code_examples
+        .map(|section: MiniAppSection| {
+            let parameters: Vec<(String, String)> = section
+                .fields
                 .iter()
-                .max_by_key(|ex| (ex.is_complete as usize, ex.code.len()))
-                .map(|ex| ex.code.clone());
-
-            // Determine the kind based on result type
-            let kind = item.result_type.name().to_string();
-
-            // Build related APIs from code examples descriptions
-            let related_apis: Vec<String> = item.code_examples.iter()
-                .filter_map(|ex| ex.description.clone())
-                .take(5)
+                .map(|f| (f.name.clone(), format!("{}: {}", f.type_name, f.description)))
                 .collect();
+            DocResult {
+                title: section.title.clone(),
+                kind: section.kind().to_string(),
+                path: section.title.clone(),
+                url: Some(section.url()),
+                score: None,
+                summary: section.summary.clone(),
+                platforms: Some(telegram_platform(MINI_APPS_TECHNOLOGY).to_string()),
+                code_language: section.code_sample.as_ref().map(|_| "javascript".to_string()),
+                code_sample: section.code_sample,
+                related_apis: section.fields.iter().take(8).map(|f| f.name.clone()).collect(),
+                full_content: Some(section.content),
+                declaration: None,
+                parameters,
+                beta: false,
+                features: Vec::new(),
+                browser_support: Vec::new(),
+                provider: None,
+            }
+        })
+        .collect();
 
-            // Format full content with code examples for detailed results
-            let full_content = if item.code_examples.is_empty() {
-                item.description.clone()
-            } else {
-                let mut content = item.description.clone();
-                for ex in &item.code_examples {
-                    if let Some(desc) = &ex.description {
-                        content.push_str(&format!("\n\n**{}**:\n```{}\n{}\n```", desc, ex.language, ex.code));
-                    } else {
-                        content.push_str(&format!("\n\n```{}\n{}\n```", ex.language, ex.code));
-                    }
-                }
-                content
-            };
+    Ok(results)
+}
+
+/// Search TDLib classes, objects and functions
+async fn search_tdlib(
+    context: &Arc<AppContext>,
+    query: &str,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    let items = match context.providers.telegram.search_tdlib(query).await {
+        Ok(items) => items,
+        Err(e) => {
+            tracing::warn!(error = %e, "TDLib search failed, returning empty results");
+            return Ok(Vec::new());
+        }
+    };
 
+    let results = items
+        .into_iter()
+        .take(max_results)
+        .map(|item| {
+            let parameters: Vec<(String, String)> = item
+                .fields
+                .iter()
+                .map(|f| (f.name.clone(), format!("{}: {}", f.types.join(" | "), f.description)))
+                .collect();
+            let mut related_apis: Vec<String> = item.returns.clone().unwrap_or_default();
+            related_apis.extend(item.subtype_of.iter().cloned());
+            related_apis.extend(item.subtypes.iter().take(8).cloned());
             DocResult {
-                title: item.title.clone(),
-                kind,
-                path: item.id.clone(),
+                title: item.name.clone(),
+                kind: item.kind,
+                path: item.name,
+                url: Some(item.href),
+                score: None,
                 summary: item.description.clone(),
-                platforms: Some(format!("TON ({})", item.source.name())),
-                code_sample,
+                platforms: Some(telegram_platform(TDLIB_TECHNOLOGY).to_string()),
+                code_sample: None,
+                code_language: None,
                 related_apis,
-                full_content: Some(full_content),
+                full_content: Some(item.description),
                 declaration: None,
-                parameters: vec![],
+                parameters,
+                beta: false,
+                features: Vec::new(),
+                browser_support: Vec::new(),
+                provider: None,
             }
         })
         .collect();
@@ -1780,6 +2789,77 @@ async fn search_ton(
     Ok(results)
 }
 
+/// Search TON documentation (API, security patterns, docs articles)
+async fn search_ton(
+    context: &Arc<AppContext>,
+    query: &str,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    // Use the unified search_all method that searches API, security patterns, and documentation
+    let items = match context.providers.ton.search_all(query).await {
+        Ok(items) => items,
+        Err(e) => {
+            tracing::warn!(error = %e, "TON search failed, returning empty results");
+            return Ok(Vec::new());
+        }
+    };
+
+    Ok(items.into_iter().take(max_results).map(ton_result).collect())
+}
+
+fn ton_result(item: TonSearchResult) -> DocResult {
+    let best_example = item
+        .code_examples
+        .iter()
+        .max_by_key(|ex| (ex.is_complete as usize, ex.code.len()));
+    let code_sample = best_example.map(|ex| ex.code.clone());
+    let code_language = best_example.map(|ex| ex.language.clone());
+
+    // Determine the kind based on result type
+    let kind = item.result_type.name().to_string();
+
+    // Build related APIs from code examples descriptions
+    let related_apis: Vec<String> = item.code_examples.iter()
+        .filter_map(|ex| ex.description.clone())
+        .take(5)
+        .collect();
+
+    // Format full content with code examples for detailed results
+    let full_content = if item.code_examples.is_empty() {
+        item.description.clone()
+    } else {
+        let mut content = item.description.clone();
+        for ex in &item.code_examples {
+            if let Some(desc) = &ex.description {
+                content.push_str(&format!("\n\n**{}**:\n```{}\n{}\n```", desc, ex.language, ex.code));
+            } else {
+                content.push_str(&format!("\n\n```{}\n{}\n```", ex.language, ex.code));
+            }
+        }
+        content
+    };
+
+    DocResult {
+        title: item.title.clone(),
+        kind,
+        path: item.id.clone(),
+        url: non_empty_url(item.url),
+        score: Some(f64::from(item.score)),
+        summary: item.description.clone(),
+        platforms: Some(format!("TON ({})", item.source.name())),
+        code_sample,
+        code_language,
+        related_apis,
+        full_content: Some(full_content),
+        declaration: None,
+        parameters: vec![],
+        beta: false,
+        features: Vec::new(),
+        browser_support: Vec::new(),
+        provider: None,
+    }
+}
+
 /// Search Cocoon documentation
 async fn search_cocoon(
     context: &Arc<AppContext>,
@@ -1793,6 +2873,11 @@ async fn search_cocoon(
         .search(query)
         .await
         .unwrap_or_default();
+    // Results say which upstream commit the cached docs come from
+    let platforms = match context.providers.cocoon.snapshot().await {
+        Ok(Some(snapshot)) => format!("Cocoon ({})", snapshot.label()),
+        _ => "Cocoon".to_string(),
+    };
 
     // Fetch full content for top results
     let mut results = Vec::new();
@@ -1814,13 +2899,20 @@ async fn search_cocoon(
             title: doc.title,
             kind: "Document".to_string(),
             path: doc.path,
+            url: non_empty_url(doc.url),
+            score: None,
             summary: doc.summary,
-            platforms: Some("Cocoon".to_string()),
+            platforms: Some(platforms.clone()),
             code_sample: None,
+            code_language: None,
             related_apis: Vec::new(),
             full_content,
             declaration: None,
             parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -1831,72 +2923,214 @@ async fn search_cocoon(
 async fn search_mdn(
     context: &Arc<AppContext>,
     query: &str,
+    category: Option<MdnCategory>,
     max_results: usize,
 ) -> Result<Vec<DocResult>> {
-    let items = match context.providers.mdn.search(query).await {
+    let mut items = match context.providers.mdn.search(query).await {
         Ok(items) => items,
         Err(e) => {
             tracing::warn!(error = %e, "MDN search failed, returning empty results");
             return Ok(Vec::new());
         }
     };
+    // CSS and HTML questions want the reference pages, not the DOM interfaces of the same name
+    if let Some(category) = category.filter(|category| items.iter().any(|item| item.category == *category)) {
+        items.retain(|item| item.category == category);
+        items.sort_by_key(|item| item.css_kind.is_none() && !is_html_element_slug(&item.slug));
+    }
 
-    let mut results = Vec::new();
-    for item in items.into_iter().take(max_results) {
-        // Fetch full article for top results
-        let (full_content, code_sample, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
-            match context.providers.mdn.get_article(&item.slug).await {
-                Ok(article) => {
-                    let code = article
-                        .examples
-                        .iter()
-                        .max_by_key(|ex| (ex.is_runnable as usize, ex.code.len()))
-                        .map(|ex| ex.code.clone());
-                    let params: Vec<(String, String)> = article
-                        .parameters
-                        .iter()
-                        .map(|p| (p.name.clone(), p.description.clone()))
-                        .collect();
-                    let content = article
-                        .content
-                        .as_deref()
-                        .map(str::trim)
-                        .filter(|text| !text.is_empty())
-                        .map(|text| trim_text(text, MAX_CONTENT_LENGTH))
-                        .or_else(|| {
-                            if article.summary.is_empty() {
-                                None
-                            } else {
-                                Some(article.summary.clone())
-                            }
-                        });
-                    let decl = article.syntax.filter(|text| !text.trim().is_empty());
-                    (content, code, decl, params)
-                }
-                Err(_) => (None, None, None, Vec::new()),
+    let mut results: Vec<DocResult> = items
+        .into_iter()
+        .take(max_results)
+        .map(|item| DocResult {
+            title: item.title,
+            kind: match item.css_kind {
+                Some(kind) => kind.as_str(),
+                None if is_html_element_slug(&item.slug) => "HTML element",
+                None => "Article",
             }
+            .to_string(),
+            path: item.slug,
+            url: non_empty_url(item.url),
+            score: None,
+            summary: item.summary,
+            platforms: Some(format!("MDN Web Docs ({})", item.category)),
+            code_sample: None,
+            code_language: None,
+            related_apis: Vec::new(),
+            full_content: None,
+            declaration: None,
+            parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
+        })
+        .collect();
+
+    // Fetch full articles for top results
+    for result in results.iter_mut().take(MAX_DETAILED_DOCS) {
+        let Ok(article) = context.providers.mdn.get_article(&result.path).await else {
+            continue;
+        };
+
+        let example = article
+            .examples
+            .iter()
+            .max_by_key(|ex| (ex.is_runnable as usize, ex.code.len()));
+        result.code_sample = example.map(|ex| ex.code.clone());
+        result.code_language = example.map(|ex| ex.language.clone());
+
+        // Interfaces are described by their members, elements by their attributes
+        result.parameters = if !article.members.is_empty() {
+            article
+                .members
+                .iter()
+                .take(MAX_LISTED_MEMBERS)
+                .map(|member| (member.name.clone(), format!("{} — {}", member.label(), member.description)))
+                .collect()
+        } else if !article.attributes.is_empty() {
+            article.attributes.iter().map(attribute_parameter).collect()
         } else {
-            (None, None, None, Vec::new())
+            article
+                .parameters
+                .iter()
+                .map(|p| (p.name.clone(), p.description.clone()))
+                .collect()
         };
+        result.related_apis = article
+            .members
+            .iter()
+            .filter(|member| matches!(member.kind, MdnMemberKind::Method | MdnMemberKind::StaticMethod | MdnMemberKind::Event))
+            .map(|member| member.name.clone())
+            .collect();
 
-        results.push(DocResult {
-            title: item.title.clone(),
-            kind: "Article".to_string(),
-            path: item.slug.clone(),
-            summary: item.summary.clone(),
-            platforms: Some(format!("MDN Web Docs ({})", item.category)),
-            code_sample,
-            related_apis: Vec::new(),
-            full_content,
-            declaration,
-            parameters,
-        });
+        let content = article
+            .content
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                if article.summary.is_empty() {
+                    None
+                } else {
+                    Some(article.summary.clone())
+                }
+            });
+        let content = match article.formal_syntax.as_deref() {
+            Some(grammar) => Some(format!(
+                "{}Formal syntax:\n```css\n{grammar}\n```",
+                content.map(|text| format!("{text}\n\n")).unwrap_or_default()
+            )),
+            None => content,
+        };
+        result.full_content = with_accessibility_notes(content, &article.accessibility_notes);
+        result.declaration = article.syntax.filter(|text| !text.trim().is_empty());
+        result.browser_support = article.browser_support;
+        if !article.members.is_empty() && result.kind == "Article" {
+            result.kind = "Interface".to_string();
+        }
     }
 
     Ok(results)
 }
 
-/// Search Web Frameworks documentation (React, Next.js, Node.js)
+/// What a result's parameters are: an interface's members, an element's attributes
+fn parameters_heading(kind: &str) -> &'static str {
+    match kind {
+        "Interface" => "Members",
+        "HTML element" => "Attributes",
+        _ => "Parameters",
+    }
+}
+
+/// An HTML attribute as a result parameter, flagged when deprecated or experimental
+fn attribute_parameter(attribute: &MdnHtmlAttribute) -> (String, String) {
+    let mut name = attribute.name.clone();
+    if attribute.deprecated {
+        name.push_str(" (deprecated)");
+    } else if attribute.experimental {
+        name.push_str(" (experimental)");
+    }
+    (name, attribute.description.clone())
+}
+
+/// Insert an element's accessibility notes after the first paragraph of its overview, where
+/// the content limit will not cut them
+fn with_accessibility_notes(content: Option<String>, notes: &[String]) -> Option<String> {
+    if notes.is_empty() {
+        return content;
+    }
+    let mut block = "Accessibility:".to_string();
+    for note in notes {
+        block.push_str("\n- ");
+        block.push_str(note);
+    }
+    Some(match content {
+        Some(text) => match text.split_once("\n\n") {
+            Some((intro, rest)) => format!("{intro}\n\n{block}\n\n{rest}"),
+            None => format!("{text}\n\n{block}"),
+        },
+        None => block,
+    })
+}
+
+/// The element a query asks about and, for `<input>`, its type:
+/// "dialog element" is `("dialog", None)`, "input type=datetime-local" is
+/// `("input", Some("datetime-local"))`
+fn html_element_query(query: &str) -> Option<(String, Option<String>)> {
+    let query = query.to_lowercase();
+    if let Some(captures) = HTML_INPUT_TYPE_RE.captures(&query) {
+        return Some(("input".to_string(), Some(captures[1].to_string())));
+    }
+    // Written out, one-letter names are articles ("create a tag"), not elements
+    let tags = HTML_TAG_RE.captures_iter(&query).map(|captures| captures[1].to_string());
+    let words = HTML_ELEMENT_WORD_RE
+        .captures_iter(&query)
+        .map(|captures| captures[1].to_string())
+        .filter(|name| name.len() > 1);
+    tags.chain(words)
+        .find(|name| HTML_ELEMENTS.contains(&name.as_str()))
+        .map(|name| (name, None))
+}
+
+/// MDN search terms for an HTML question: the element (and input type) first, then the keywords
+fn html_search_query(raw_query: &str, search_query: &str) -> String {
+    match html_element_query(raw_query) {
+        Some((element, Some(input_type))) => format!("html <{element}> type {input_type}"),
+        Some((element, None)) => {
+            let rest: Vec<&str> = search_query
+                .split_whitespace()
+                .filter(|word| !matches!(*word, "element" | "elements" | "tag" | "tags" | "html") && *word != element)
+                .collect();
+            format!("html <{element}> {}", rest.join(" ")).trim_end().to_string()
+        }
+        None => format!("html {search_query}"),
+    }
+}
+
+/// MDN search terms for a CSS question: CSS tokens such as `@media` or `grid-template-columns`
+/// whole, the remaining keywords, and "css" so the reference outranks same-named Web APIs
+fn css_search_query(raw_query: &str, keywords: &[String]) -> String {
+    let lower = raw_query.to_lowercase();
+    let mut terms: Vec<String> = vec!["css".to_string()];
+    for token in CSS_TOKEN_RE.find_iter(&lower) {
+        let token = token.as_str().to_string();
+        if !terms.contains(&token) {
+            terms.push(token);
+        }
+    }
+    for keyword in keywords {
+        if !terms.iter().any(|term| term.contains(keyword.as_str())) {
+            terms.push(keyword.clone());
+        }
+    }
+    terms.join(" ")
+}
+
+/// Search Web Frameworks documentation (React, React Native, Next.js, Node.js, Bun, Express, Astro,
+/// React Router, TypeScript)
 async fn search_web_frameworks(
     context: &Arc<AppContext>,
     intent: &QueryIntent,
@@ -1905,21 +3139,31 @@ async fn search_web_frameworks(
 ) -> Result<Vec<DocResult>> {
     use multi_provider_client::web_frameworks::types::WebFramework;
 
-    // Determine which framework to search based on the technology identifier
-    let framework = intent
+    // Determine which framework to search based on the technology identifier; Node.js may be
+    // pinned to a release line ("webfw:nodejs@20")
+    let (framework_id, release) = intent
         .technology
-        .as_ref()
-        .and_then(|t| t.strip_prefix("webfw:"))
-        .map(|f| match f {
-            "react" => WebFramework::React,
-            "nextjs" => WebFramework::NextJs,
-            "nodejs" => WebFramework::NodeJs,
-            "bun" => WebFramework::Bun,
-            _ => WebFramework::React,
-        })
-        .unwrap_or(WebFramework::React);
+        .as_deref()
+        .map_or(("react", None), web_framework_technology);
+    let framework = match framework_id {
+        "react" => WebFramework::React,
+        "nextjs" => WebFramework::NextJs,
+        "nodejs" => WebFramework::NodeJs,
+        "bun" => WebFramework::Bun,
+        "express" => WebFramework::Express,
+        "astro" => WebFramework::Astro,
+        "react-router" | "remix" => WebFramework::ReactRouter,
+        "react-native" => WebFramework::ReactNative,
+        "typescript" => WebFramework::TypeScript,
+        _ => WebFramework::React,
+    };
 
-    let items = match context.providers.web_frameworks.search(framework, query).await {
+    let search = if framework == WebFramework::NodeJs {
+        context.providers.web_frameworks.search_nodejs_release(release, query).await
+    } else {
+        context.providers.web_frameworks.search(framework, query).await
+    };
+    let items = match search {
         Ok(items) => items,
         Err(e) => {
             tracing::warn!(error = %e, "Web Frameworks search failed, returning empty results");
@@ -1932,43 +3176,54 @@ async fn search_web_frameworks(
         WebFramework::NextJs => "Next.js",
         WebFramework::NodeJs => "Node.js",
         WebFramework::Bun => "Bun",
+        WebFramework::Express => "Express",
+        WebFramework::Astro => "Astro",
+        WebFramework::ReactRouter => "React Router",
+        WebFramework::ReactNative => "React Native",
+        WebFramework::TypeScript => "TypeScript",
     };
+    let platform = release.map_or_else(|| framework_name.to_string(), |line| format!("{framework_name} {line}"));
 
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
         // Fetch full article for top results
-        let (full_content, code_sample) = if results.len() < MAX_DETAILED_DOCS {
+        let (full_content, code_sample, code_language) = if results.len() < MAX_DETAILED_DOCS {
             match context.providers.web_frameworks.get_article(framework, &item.slug).await {
                 Ok(article) => {
-                    let code = article
-                        .examples
-                        .iter()
-                        .max_by_key(|e| e.quality_score())
-                        .map(|e| e.code.clone());
+                    let example = article.examples.iter().max_by_key(|e| e.quality_score());
+                    let code = example.map(|e| e.code.clone());
+                    let lang = example.map(|e| e.language.clone());
                     let content = if !article.content.is_empty() {
-                        Some(trim_text(&article.content, MAX_CONTENT_LENGTH))
+                        Some(article.content.clone())
                     } else {
                         None
                     };
-                    (content, code)
+                    (content, code, lang)
                 }
-                Err(_) => (None, None),
+                Err(_) => (None, None, None),
             }
         } else {
-            (None, None)
+            (None, None, None)
         };
 
         results.push(DocResult {
             title: item.title.clone(),
             kind: item.category.clone().unwrap_or_else(|| "Article".to_string()),
             path: item.slug.clone(),
+            url: non_empty_url(item.url),
+            score: None,
             summary: item.description.clone(),
-            platforms: Some(framework_name.to_string()),
+            platforms: Some(platform.clone()),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration: None,
             parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -1991,7 +3246,7 @@ async fn search_mlx(
         .and_then(|t| t.strip_prefix("mlx:"))
         .and_then(|l| match l {
             "swift" => Some(MlxLanguage::Swift),
-            "python" => Some(MlxLanguage::Python),
+            "python" | "lm" | "examples" => Some(MlxLanguage::Python),
             _ => None,
         });
 
@@ -2006,34 +3261,42 @@ async fn search_mlx(
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
         // Fetch full article for top results
-        let (full_content, code_sample, declaration) = if results.len() < MAX_DETAILED_DOCS {
+        let (full_content, code_sample, code_language, declaration) = if results.len() < MAX_DETAILED_DOCS {
             match context.providers.mlx.get_article(&item.path, item.language).await {
                 Ok(article) => {
                     let code = article.examples.first().map(|e| e.code.clone());
+                    let lang = article.examples.first().map(|e| e.language.clone());
                     let content = if !article.content.is_empty() {
-                        Some(trim_text(&article.content, MAX_CONTENT_LENGTH))
+                        Some(article.content.clone())
                     } else {
                         None
                     };
-                    (content, code, article.declaration)
+                    (content, code, lang, article.declaration)
                 }
-                Err(_) => (None, None, None),
+                Err(_) => (None, None, None, None),
             }
         } else {
-            (None, None, None)
+            (None, None, None, None)
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
             path: item.path.clone(),
+            url: non_empty_url(item.url),
+            score: Some(f64::from(item.score)),
             summary: item.description.clone(),
             platforms: Some(format!("MLX {}", item.language)),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration,
             parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -2058,26 +3321,37 @@ async fn search_huggingface(
             "swift-transformers" => Some(HfTechnologyKind::SwiftTransformers),
             "transformers" => Some(HfTechnologyKind::Transformers),
             "models" => Some(HfTechnologyKind::Models),
+            "hub-api" => Some(HfTechnologyKind::HubApi),
             _ => None,
         });
 
+    let mut results = Vec::new();
+
+    // A named Hub model leads with its card: architecture, context length, usage
+    if let Some(model) = model_reference(&intent.raw_query) {
+        match context.providers.huggingface.get_model_card(&model).await {
+            Ok(card) => results.push(model_card_result(card)),
+            Err(e) => tracing::warn!(error = %e, model, "Hugging Face model card lookup failed"),
+        }
+    }
+
     let items = match context.providers.huggingface.search(query, technology).await {
         Ok(items) => items,
         Err(e) => {
-            tracing::warn!(error = %e, "Hugging Face search failed, returning empty results");
-            return Ok(Vec::new());
+            tracing::warn!(error = %e, "Hugging Face search failed");
+            return Ok(results);
         }
     };
 
-    let mut results = Vec::new();
-    for item in items.into_iter().take(max_results) {
+    for item in items.into_iter().take(max_results.saturating_sub(results.len())) {
         // Fetch full article for top results
-        let (full_content, code_sample, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
+        let (full_content, code_sample, code_language, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
             match context.providers.huggingface.get_article(&item.path, item.technology).await {
                 Ok(article) => {
                     let code = article.examples.first().map(|e| e.code.clone());
+                    let lang = article.examples.first().map(|e| e.language.clone());
                     let content = if !article.content.is_empty() {
-                        Some(trim_text(&article.content, MAX_CONTENT_LENGTH))
+                        Some(article.content.clone())
                     } else {
                         None
                     };
@@ -2086,38 +3360,73 @@ async fn search_huggingface(
                         .iter()
                         .map(|p| (p.name.clone(), p.description.clone()))
                         .collect();
-                    (content, code, article.declaration, params)
+                    (content, code, lang, article.declaration, params)
                 }
-                Err(_) => (None, None, None, Vec::new()),
+                Err(_) => (None, None, None, None, Vec::new()),
             }
         } else {
-            (None, None, None, Vec::new())
+            (None, None, None, None, Vec::new())
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
             path: item.path.clone(),
+            url: non_empty_url(item.url),
+            score: Some(f64::from(item.score)),
             summary: item.description.clone(),
             platforms: Some(format!("Hugging Face {}", item.technology)),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration,
             parameters,
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
     Ok(results)
 }
 
-/// Search QuickNode Solana documentation
+fn model_card_result(card: HfModelCard) -> DocResult {
+    let mut content = card.summary.clone().map(|summary| vec![summary, String::new()]).unwrap_or_default();
+    content.extend(card.facts());
+    DocResult {
+        title: card.model_id.clone(),
+        kind: "model card".to_string(),
+        path: format!("models/{}", card.model_id),
+        url: non_empty_url(card.url.clone()),
+        score: None,
+        summary: card
+            .summary
+            .clone()
+            .unwrap_or_else(|| format!("{} on the Hugging Face Hub", card.model_id)),
+        platforms: Some("Hugging Face models".to_string()),
+        code_sample: card.usage.as_ref().map(|usage| usage.code.clone()),
+        code_language: card.usage.as_ref().map(|usage| usage.language.clone()),
+        related_apis: card.architectures.clone(),
+        full_content: Some(content.join("\n")),
+        declaration: None,
+        parameters: Vec::new(),
+        beta: false,
+        features: Vec::new(),
+        browser_support: Vec::new(),
+        provider: None,
+    }
+}
+
+/// Search QuickNode RPC documentation of one chain
 async fn search_quicknode(
     context: &Arc<AppContext>,
     query: &str,
+    chain: Option<QuickNodeChain>,
     max_results: usize,
 ) -> Result<Vec<DocResult>> {
-    let items = match context.providers.quicknode.search(query).await {
+    let items = match context.providers.quicknode.search_in(query, chain).await {
         Ok(items) => items,
         Err(e) => {
             tracing::warn!(error = %e, "QuickNode search failed, returning empty results");
@@ -2128,10 +3437,11 @@ async fn search_quicknode(
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
         // Fetch full method documentation for top results
-        let (full_content, code_sample, parameters) = if results.len() < MAX_DETAILED_DOCS {
-            match context.providers.quicknode.get_method(&item.name).await {
+        let (full_content, code_sample, code_language, parameters) = if results.len() < MAX_DETAILED_DOCS {
+            match context.providers.quicknode.get_method(&item.path()).await {
                 Ok(method) => {
                     let code = method.examples.first().map(|e| e.code.clone());
+                    let lang = method.examples.first().map(|e| e.language.clone());
                     let params: Vec<(String, String)> = method
                         .parameters
                         .iter()
@@ -2142,25 +3452,32 @@ async fn search_quicknode(
                     } else {
                         None
                     };
-                    (content, code, params)
+                    (content, code, lang, params)
                 }
-                Err(_) => (Some(item.description.clone()), None, Vec::new()),
+                Err(_) => (Some(item.description.clone()), None, None, Vec::new()),
             }
         } else {
-            (None, None, Vec::new())
+            (None, None, None, Vec::new())
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
-            path: item.name,
+            path: item.path(),
+            url: non_empty_url(item.url),
+            score: None,
             summary: item.description.clone(),
-            platforms: Some("QuickNode Solana".to_string()),
+            platforms: Some(format!("QuickNode {}", item.chain.title())),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration: None,
             parameters,
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -2195,10 +3512,37 @@ async fn search_claude_agent_sdk(
         }
     };
 
+    // Release notes date each option, e.g. "PreToolUse" since 0.0.20
+    let mut changelogs = Vec::new();
+    for language in [AgentSdkLanguage::TypeScript, AgentSdkLanguage::Python] {
+        if items.iter().take(max_results).any(|item| item.language == language) {
+            match context.providers.claude_agent_sdk.get_changelog(language).await {
+                Ok(releases) => changelogs.push((language, releases)),
+                Err(e) => tracing::debug!(error = %e, %language, "Claude Agent SDK changelog unavailable"),
+            }
+        }
+    }
+    let asked_version = sdk_version_in_query(&intent.raw_query);
+
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
+        let since = changelogs
+            .iter()
+            .find(|(language, _)| *language == item.language)
+            .and_then(|(_, releases)| agent_sdk_changelog::introduced_in(releases, &item.name));
+        let platforms = match since {
+            Some(release) => format!("Claude Agent SDK ({}) {}+", item.language, release.version),
+            None => format!("Claude Agent SDK ({})", item.language),
+        };
+        let mut summary = item.description.clone();
+        if let (Some(release), Some(asked)) = (since, asked_version.as_deref()) {
+            if agent_sdk_changelog::version_key(&release.version) > agent_sdk_changelog::version_key(asked) {
+                summary.push_str(&format!(" (not available in {asked}; added in {})", release.version));
+            }
+        }
+
         // Fetch full article for top results
-        let (full_content, code_sample, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
+        let (full_content, code_sample, code_language, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
             match context
                 .providers
                 .claude_agent_sdk
@@ -2207,8 +3551,9 @@ async fn search_claude_agent_sdk(
             {
                 Ok(article) => {
                     let code = article.examples.first().map(|e| e.code.clone());
+                    let lang = article.examples.first().map(|e| e.language.clone());
                     let content = if !article.content.is_empty() {
-                        Some(trim_text(&article.content, MAX_CONTENT_LENGTH))
+                        Some(article.content.clone())
                     } else {
                         None
                     };
@@ -2217,41 +3562,61 @@ async fn search_claude_agent_sdk(
                         .iter()
                         .map(|p| (p.name.clone(), p.description.clone()))
                         .collect();
-                    (content, code, article.declaration, params)
+                    (content, code, lang, article.declaration, params)
                 }
-                Err(_) => (Some(item.description.clone()), None, None, Vec::new()),
+                Err(_) => (Some(item.description.clone()), None, None, None, Vec::new()),
             }
         } else {
-            (None, None, None, Vec::new())
+            (None, None, None, None, Vec::new())
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
             path: item.path.clone(),
-            summary: item.description.clone(),
-            platforms: Some(format!("Claude Agent SDK ({})", item.language)),
+            url: non_empty_url(item.url),
+            score: Some(f64::from(item.score)),
+            summary,
+            platforms: Some(platforms),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration,
             parameters,
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
     Ok(results)
 }
 
-/// Search Vertcoin blockchain documentation
-async fn search_vertcoin(
+/// An SDK version named in a query, e.g. "0.1.5" in "hooks in agent sdk v0.1.5"
+fn sdk_version_in_query(raw_query: &str) -> Option<String> {
+    raw_query.split_whitespace().find_map(|word| {
+        let word = word.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+        let version = word.trim_start_matches(['v', 'V']);
+        // "Python 3.10" is not an SDK version; "0.1.5" and "v0.1" are
+        let explicit = version.len() < word.len() || version.matches('.').count() == 2;
+        let parts = agent_sdk_changelog::parse_version(version)?;
+        (explicit && parts.len() > 1).then(|| version.to_string())
+    })
+}
+
+/// Search Bitcoin, Litecoin or Vertcoin node RPC documentation
+async fn search_bitcoind(
     context: &Arc<AppContext>,
     query: &str,
+    coin: BitcoindCoin,
     max_results: usize,
 ) -> Result<Vec<DocResult>> {
-    let items = match context.providers.vertcoin.search(query).await {
+    let items = match context.providers.bitcoind.search(query, coin).await {
         Ok(items) => items,
         Err(e) => {
-            tracing::warn!(error = %e, "Vertcoin search failed, returning empty results");
+            tracing::warn!(error = %e, coin = coin.slug(), "bitcoind search failed, returning empty results");
             return Ok(Vec::new());
         }
     };
@@ -2259,10 +3624,11 @@ async fn search_vertcoin(
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
         // Fetch full method documentation for top results
-        let (full_content, code_sample, parameters) = if results.len() < MAX_DETAILED_DOCS {
-            match context.providers.vertcoin.get_method(&item.name).await {
+        let (full_content, code_sample, code_language, parameters) = if results.len() < MAX_DETAILED_DOCS {
+            match context.providers.bitcoind.get_method(&method_path(coin, &item.name)).await {
                 Ok(method) => {
                     let code = method.examples.first().map(|e| e.code.clone());
+                    let lang = method.examples.first().map(|e| e.language.clone());
                     let params: Vec<(String, String)> = method
                         .parameters
                         .iter()
@@ -2273,25 +3639,32 @@ async fn search_vertcoin(
                     } else {
                         None
                     };
-                    (content, code, params)
+                    (content, code, lang, params)
                 }
-                Err(_) => (Some(item.description.clone()), None, Vec::new()),
+                Err(_) => (Some(item.description.clone()), None, None, Vec::new()),
             }
         } else {
-            (None, None, Vec::new())
+            (None, None, None, Vec::new())
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
-            path: item.name,
+            path: method_path(coin, &item.name),
+            url: non_empty_url(item.url),
+            score: None,
             summary: item.description.clone(),
-            platforms: Some("Vertcoin / Verthash".to_string()),
+            platforms: Some(format!("{} / {}", coin.name(), coin.pow())),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
             declaration: None,
             parameters,
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -2315,10 +3688,11 @@ async fn search_cuda(
     let mut results = Vec::new();
     for item in items.into_iter().take(max_results) {
         // Fetch full method documentation for top results
-        let (full_content, code_sample, parameters) = if results.len() < MAX_DETAILED_DOCS {
+        let (full_content, code_sample, code_language, declaration, parameters) = if results.len() < MAX_DETAILED_DOCS {
             match context.providers.cuda.get_method(&item.name).await {
                 Ok(method) => {
                     let code = method.examples.first().map(|e| e.code.clone());
+                    let lang = method.examples.first().map(|e| e.language.clone());
                     let params: Vec<(String, String)> = method
                         .parameters
                         .iter()
@@ -2329,25 +3703,83 @@ async fn search_cuda(
                     } else {
                         None
                     };
-                    (content, code, params)
+                    (content, code, lang, method.declaration, params)
                 }
-                Err(_) => (Some(item.description.clone()), None, Vec::new()),
+                Err(_) => (Some(item.description.clone()), None, None, None, Vec::new()),
             }
         } else {
-            (None, None, Vec::new())
+            (None, None, None, item.declaration, Vec::new())
         };
 
         results.push(DocResult {
             title: item.name.clone(),
             kind: item.kind.to_string(),
             path: item.name,
+            url: non_empty_url(item.url),
+            score: None,
             summary: item.description.clone(),
             platforms: Some("CUDA / NVIDIA GPU".to_string()),
             code_sample,
+            code_language,
             related_apis: Vec::new(),
             full_content,
-            declaration: None,
+            declaration,
             parameters,
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Search Solana core concepts and cookbook recipes
+async fn search_solana(
+    context: &Arc<AppContext>,
+    query: &str,
+    source: Option<SolanaDocSource>,
+    max_results: usize,
+) -> Result<Vec<DocResult>> {
+    let items = context.providers.solana.search(query, source).await?;
+
+    let mut results = Vec::new();
+    for item in items.into_iter().take(max_results) {
+        // Top results are refreshed from their pages; the rest use the embedded copy
+        let article = if results.len() < MAX_DETAILED_DOCS {
+            context.providers.solana.get_article(&item.id).await.unwrap_or(item)
+        } else {
+            item
+        };
+
+        let example = article.code_examples.first();
+        let mut full_content = article.content.clone();
+        for ex in &article.code_examples {
+            match &ex.description {
+                Some(desc) => full_content.push_str(&format!("\n\n**{}**:\n```{}\n{}\n```", desc, ex.language, ex.code)),
+                None => full_content.push_str(&format!("\n\n```{}\n{}\n```", ex.language, ex.code)),
+            }
+        }
+
+        results.push(DocResult {
+            title: article.title.clone(),
+            kind: article.topic.clone(),
+            path: article.id.clone(),
+            url: non_empty_url(article.url.clone()),
+            score: None,
+            summary: article.description.clone(),
+            platforms: Some(article.source.name().to_string()),
+            code_sample: example.map(|ex| ex.code.clone()),
+            code_language: example.map(|ex| ex.language.clone()),
+            related_apis: Vec::new(),
+            full_content: Some(full_content),
+            declaration: None,
+            parameters: Vec::new(),
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
         });
     }
 
@@ -2415,7 +3847,7 @@ fn extract_code_from_value(value: &serde_json::Value) -> Option<String> {
 }
 
 /// Extract declaration/signature from Apple symbol data
-fn extract_declaration(symbol: &docs_mcp_client::types::SymbolData) -> Option<String> {
+pub(crate) fn extract_declaration(symbol: &docs_mcp_client::types::SymbolData) -> Option<String> {
     // Look for declaration in primary content sections
     for section in &symbol.primary_content_sections {
         if let Some(decl) = extract_declaration_from_value(section) {
@@ -2539,7 +3971,7 @@ fn extract_parameters_from_value(value: &serde_json::Value) -> Option<Vec<(Strin
 }
 
 /// Extract full documentation content from Apple symbol data
-fn extract_full_content(symbol: &docs_mcp_client::types::SymbolData) -> Option<String> {
+pub(crate) fn extract_full_content(symbol: &docs_mcp_client::types::SymbolData) -> Option<String> {
     use docs_mcp_client::types::extract_text;
 
     let mut content_parts = Vec::new();
@@ -2634,12 +4066,114 @@ fn build_response(
     provider: &ProviderType,
     technology: &str,
     results: &[DocResult],
+    limits: &ContentLimits,
+    format: OutputFormat,
 ) -> Result<ToolResponse> {
+    let lines = match format {
+        OutputFormat::Markdown => render_markdown(intent, provider, technology, results, limits),
+        OutputFormat::Plain => render_plain(intent, provider, technology, results, limits),
+        OutputFormat::Json => vec![render_json(intent, provider, technology, results, limits)?],
+    };
+
+    let relevance = relevance_scores(results);
+    let result_metadata: Vec<serde_json::Value> = results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            json!({
+                "rank": i + 1,
+                "title": result.title,
+                "kind": result.kind,
+                "path": result.path,
+                "url": result.url,
+                "score": result.score,
+                "relevance": relevance[i],
+                "detailed": is_detailed(i, result),
+                "hasCodeSample": result.code_sample.is_some(),
+                "beta": result.beta,
+                "features": result.features,
+                "browserSupport": result.browser_support,
+                "provider": result.provider.map(|provider| provider.name()),
+            })
+        })
+        .collect();
+
+    let metadata = json!({
+        "query": intent.raw_query,
+        "provider": provider.name(),
+        "providers": provider_names(provider, results),
+        "technology": technology,
+        "queryType": format!("{:?}", intent.query_type),
+        "language": (*provider == ProviderType::Apple).then(|| intent.language.code_fence()),
+        "platform": intent.platform,
+        "includeBeta": intent.include_beta,
+        "betaCount": results.iter().filter(|result| result.beta).count(),
+        "keywords": intent.keywords,
+        "resultCount": results.len(),
+        "detailedCount": results.iter().enumerate().filter(|(i, r)| is_detailed(*i, r)).count(),
+        "paths": results.iter().map(|r| r.path.as_str()).collect::<Vec<_>>(),
+        "topRelevance": relevance.first().copied().flatten(),
+        "results": result_metadata,
+        "hasCodeSamples": results.iter().any(|r| r.code_sample.is_some()),
+        "hasFullContent": results.iter().any(|r| r.full_content.is_some()),
+        "sources": results.iter().filter_map(|r| r.url.as_deref()).collect::<Vec<_>>(),
+        "format": format!("{:?}", format).to_lowercase(),
+    });
+
+    Ok(text_response(lines).with_metadata(metadata))
+}
+
+/// Provider score that maps to a relevance of 0.5
+const RELEVANCE_MIDPOINT: f64 = 10.0;
+
+/// Relevance in 0–1 per result; `None` when the provider doesn't score its results.
+///
+/// Scores are calibrated on a fixed curve rather than against the best score in the
+/// result set, so a weak top hit still reads as weak.
+fn relevance_scores(results: &[DocResult]) -> Vec<Option<f64>> {
+    results
+        .iter()
+        .map(|result| {
+            result.score.map(|score| {
+                let score = score.max(0.0);
+                (score / (score + RELEVANCE_MIDPOINT) * 100.0).round() / 100.0
+            })
+        })
+        .collect()
+}
+
+/// Cargo feature names as inline code, e.g. `` `sync`, `rt` ``.
+pub(crate) fn feature_list(features: &[String]) -> String {
+    features
+        .iter()
+        .map(|feature| format!("`{feature}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether a result is rendered with full detail (declaration, parameters)
+fn is_detailed(index: usize, result: &DocResult) -> bool {
+    index < MAX_DETAILED_DOCS
+        && (result.full_content.is_some()
+            || result.declaration.is_some()
+            || result.code_sample.is_some()
+            || !result.parameters.is_empty())
+}
+
+/// Render results as a markdown document
+fn render_markdown(
+    intent: &QueryIntent,
+    provider: &ProviderType,
+    technology: &str,
+    results: &[DocResult],
+    limits: &ContentLimits,
+) -> Vec<String> {
+    let relevance = relevance_scores(results);
     let mut lines = vec![
         markdown::header(1, &format!("📚 Documentation: {}", intent.raw_query)),
         String::new(),
         format!("**Provider:** {} | **Technology:** {} | **Results:** {}",
-            provider.name(), technology, results.len()),
+            providers_label(provider, results), technology, results.len()),
     ];
 
     if results.is_empty() {
@@ -2651,26 +4185,50 @@ fn build_response(
         lines.push(markdown::header(2, "Documentation"));
 
         for (i, result) in results.iter().enumerate() {
-            let is_detailed = i < MAX_DETAILED_DOCS
-                && (result.full_content.is_some()
-                    || result.declaration.is_some()
-                    || result.code_sample.is_some()
-                    || !result.parameters.is_empty());
+            let is_detailed = is_detailed(i, result);
+            let provider = result.provider.as_ref().unwrap_or(provider);
 
             lines.push(String::new());
-            lines.push(format!("### {}. {} `{}`", i + 1, result.title, result.kind));
+            let beta_badge = if result.beta { " 🧪 Beta" } else { "" };
+            let provider_badge = result.provider.map(|provider| format!(" · {}", provider.name())).unwrap_or_default();
+            lines.push(format!("### {}. {} `{}`{beta_badge}{provider_badge}", i + 1, result.title, result.kind));
 
             if let Some(platforms) = &result.platforms {
                 lines.push(format!("**Availability:** {}", platforms));
             }
 
-            // Declaration/signature for detailed results
-            if is_detailed {
+            if !result.features.is_empty() {
+                lines.push(format!(
+                    "**Requires Cargo features:** {} (enable in `Cargo.toml`)",
+                    feature_list(&result.features)
+                ));
+            }
+
+            if let Some(url) = &result.url {
+                lines.push(format!("**Source:** {}", url));
+            }
+
+            if let Some(relevance) = relevance[i] {
+                lines.push(format!("**Relevance:** {relevance:.2}"));
+            }
+
+            if let Some(table) = browser_support_table(&result.browser_support) {
+                lines.push(String::new());
+                lines.push("**Browser support:**".to_string());
+                lines.push(table);
+            }
+
+            // Declaration/signature for detailed results
+            if is_detailed {
                 if let Some(decl) = &result.declaration {
                     lines.push(String::new());
                     lines.push("**Declaration:**".to_string());
                     // Determine code language based on provider/platform
-                    let code_lang = detect_code_language(provider, result.platforms.as_deref());
+                    let code_lang = if *provider == ProviderType::Apple {
+                        intent.language.code_fence()
+                    } else {
+                        detect_code_language(provider, result.platforms.as_deref())
+                    };
                     lines.push(format!("```{}\n{}\n```", code_lang, decl));
                 }
             }
@@ -2679,16 +4237,19 @@ fn build_response(
             if let Some(content) = &result.full_content {
                 lines.push(String::new());
                 lines.push("**Overview:**".to_string());
-                lines.push(trim_text(content, MAX_CONTENT_LENGTH));
+                lines.push(tag_code_fences(
+                    &trim_text(content, limits.content),
+                    &sample_language(provider, result),
+                ));
             } else if !result.summary.is_empty() {
                 lines.push(String::new());
-                lines.push(trim_text(&result.summary, MAX_SUMMARY_LENGTH));
+                lines.push(trim_text(&result.summary, limits.summary));
             }
 
             // Parameters for detailed results
             if is_detailed && !result.parameters.is_empty() {
                 lines.push(String::new());
-                lines.push("**Parameters:**".to_string());
+                lines.push(format!("**{}:**", parameters_heading(&result.kind)));
                 for (name, desc) in &result.parameters {
                     if desc.is_empty() {
                         lines.push(format!("- `{}`", name));
@@ -2702,9 +4263,8 @@ fn build_response(
             if let Some(code) = &result.code_sample {
                 lines.push(String::new());
                 lines.push("**Example:**".to_string());
-                // Determine code language based on provider/platform
-                let code_lang = detect_code_language(provider, result.platforms.as_deref());
-                lines.push(format!("```{}\n{}\n```", code_lang, trim_text(code, MAX_CODE_LENGTH)));
+                let code_lang = sample_language(provider, result);
+                lines.push(format!("```{}\n{}\n```", code_lang, trim_text(code, limits.code)));
             }
 
             // Related APIs
@@ -2724,22 +4284,125 @@ fn build_response(
         lines.push("• Try 'how to...' queries for implementation guidance".to_string());
     }
 
-    let metadata = json!({
+    lines
+}
+
+/// Render results as plain text with no markdown syntax
+fn render_plain(
+    intent: &QueryIntent,
+    provider: &ProviderType,
+    technology: &str,
+    results: &[DocResult],
+    limits: &ContentLimits,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("Documentation: {}", intent.raw_query),
+        format!(
+            "Provider: {} | Technology: {} | Results: {}",
+            providers_label(provider, results),
+            technology,
+            results.len()
+        ),
+    ];
+
+    if results.is_empty() {
+        lines.push(String::new());
+        lines.push("No results found. Try different keywords or a more specific query.".to_string());
+        return lines;
+    }
+
+    let relevance = relevance_scores(results);
+    for (i, result) in results.iter().enumerate() {
+        lines.push(String::new());
+        lines.push(format!(
+            "{}. {} ({}){}{}{}",
+            i + 1,
+            result.title,
+            result.kind,
+            if result.beta { " [beta]" } else { "" },
+            result.provider.map(|provider| format!(" [{}]", provider.name())).unwrap_or_default(),
+            relevance[i].map(|relevance| format!(" relevance {relevance:.2}")).unwrap_or_default()
+        ));
+        if !result.path.is_empty() {
+            lines.push(format!("Path: {}", result.path));
+        }
+        if let Some(url) = &result.url {
+            lines.push(format!("Source: {}", url));
+        }
+        if let Some(platforms) = &result.platforms {
+            lines.push(format!("Availability: {}", platforms));
+        }
+        if !result.features.is_empty() {
+            lines.push(format!("Requires features: {}", result.features.join(", ")));
+        }
+        if !result.browser_support.is_empty() {
+            let browsers: Vec<String> = result
+                .browser_support
+                .iter()
+                .map(|support| format!("{} {}", support.browser, support.version_label()))
+                .collect();
+            lines.push(format!("Browser support: {}", browsers.join(" · ")));
+        }
+        if let Some(decl) = &result.declaration {
+            lines.push(format!("Declaration: {}", decl));
+        }
+        if let Some(content) = &result.full_content {
+            lines.push(trim_text(content, limits.content));
+        } else if !result.summary.is_empty() {
+            lines.push(trim_text(&result.summary, limits.summary));
+        }
+        for (name, desc) in &result.parameters {
+            if desc.is_empty() {
+                lines.push(format!("  - {}", name));
+            } else {
+                lines.push(format!("  - {}: {}", name, desc));
+            }
+        }
+        if let Some(code) = &result.code_sample {
+            lines.push("Example:".to_string());
+            lines.push(trim_text(code, limits.code));
+        }
+        if !result.related_apis.is_empty() {
+            lines.push(format!("Related: {}", result.related_apis.join(", ")));
+        }
+    }
+
+    lines
+}
+
+/// Render results as a JSON document of DocResult fields
+fn render_json(
+    intent: &QueryIntent,
+    provider: &ProviderType,
+    technology: &str,
+    results: &[DocResult],
+    limits: &ContentLimits,
+) -> Result<String> {
+    let results: Vec<DocResult> = results
+        .iter()
+        .map(|result| DocResult {
+            summary: trim_text(&result.summary, limits.summary),
+            code_sample: result.code_sample.as_deref().map(|code| trim_text(code, limits.code)),
+            full_content: result
+                .full_content
+                .as_deref()
+                .map(|content| trim_text(content, limits.content)),
+            ..result.clone()
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&json!({
         "query": intent.raw_query,
-        "provider": provider.name(),
+        "provider": providers_label(provider, &results),
         "technology": technology,
-        "queryType": format!("{:?}", intent.query_type),
-        "keywords": intent.keywords,
-        "resultCount": results.len(),
-        "hasCodeSamples": results.iter().any(|r| r.code_sample.is_some()),
-        "hasFullContent": results.iter().any(|r| r.full_content.is_some()),
-    });
-
-    Ok(text_response(lines).with_metadata(metadata))
+        "results": results,
+    }))
+    .context("Failed to serialize query results")
 }
 
+/// Truncate `text` to at most `max` bytes on a char boundary; `0` means no limit
 fn trim_text(text: &str, max: usize) -> String {
-    if text.len() <= max {
+    if max == 0 || text.len() <= max {
         text.to_string()
     } else {
         let mut end = max;
@@ -2750,13 +4413,101 @@ fn trim_text(text: &str, max: usize) -> String {
     }
 }
 
+/// Build the public developer.apple.com URL for a documentation path
+fn apple_web_url(path: &str) -> String {
+    let trimmed = path
+        .trim()
+        .strip_prefix("doc://com.apple.documentation/")
+        .unwrap_or(path.trim())
+        .trim_start_matches('/');
+    format!("https://developer.apple.com/{}", trimmed.to_lowercase())
+}
+
+/// Display name of a Telegram technology: the Bot API, Mini Apps or TDLib
+pub(crate) fn telegram_platform(identifier: &str) -> &'static str {
+    match identifier {
+        MINI_APPS_TECHNOLOGY => "Telegram Mini Apps",
+        TDLIB_TECHNOLOGY => "TDLib",
+        _ => "Telegram Bot API",
+    }
+}
+
+/// Build the Bot API anchor URL for a Telegram method or type
+fn telegram_web_url(name: &str) -> String {
+    format!("https://core.telegram.org/bots/api#{}", name.to_lowercase())
+}
+
+fn non_empty_url(url: String) -> Option<String> {
+    if url.trim().is_empty() {
+        None
+    } else {
+        Some(url)
+    }
+}
+
+/// Resolve the fence language for a result's code sample, preferring the
+/// language reported by the provider over the provider-wide default
+fn sample_language(provider: &ProviderType, result: &DocResult) -> String {
+    result
+        .code_language
+        .as_deref()
+        .and_then(normalize_fence_language)
+        .unwrap_or_else(|| detect_code_language(provider, result.platforms.as_deref()).to_string())
+}
+
+/// Normalize provider language labels into common highlighter identifiers
+fn normalize_fence_language(language: &str) -> Option<String> {
+    let lower = language.trim().to_lowercase();
+    let normalized = match lower.as_str() {
+        "" | "text" | "plain" | "plaintext" => return None,
+        "js" | "jsx" | "node" => "javascript",
+        "ts" | "tsx" => "typescript",
+        "py" | "python3" => "python",
+        "sh" | "shell" | "console" | "zsh" => "bash",
+        "fc" => "func",
+        "cu" | "cuda-c" | "cuda c++" => "cuda",
+        "objective-c" | "objc" => "objectivec",
+        other => other,
+    };
+    Some(normalized.to_string())
+}
+
+/// Tag untagged code fences in rendered content with `default_lang` and close
+/// any fence left open by truncation
+fn tag_code_fences(content: &str, default_lang: &str) -> String {
+    let mut in_fence = false;
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            if !in_fence && info.trim().is_empty() {
+                let indent = &line[..line.len() - trimmed.len()];
+                lines.push(format!("{indent}```{default_lang}"));
+            } else {
+                lines.push(line.to_string());
+            }
+            in_fence = !in_fence;
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    if in_fence {
+        lines.push("```".to_string());
+    }
+    lines.join("\n")
+}
+
 /// Detect the appropriate code language for syntax highlighting based on provider and platform
 fn detect_code_language(provider: &ProviderType, platforms: Option<&str>) -> &'static str {
     match provider {
         ProviderType::Apple => "swift",
         ProviderType::Rust => "rust",
         ProviderType::Telegram | ProviderType::TON => "json",
-        ProviderType::Mdn => "javascript",
+        ProviderType::Mdn => match platforms {
+            Some(p) if p.ends_with("(CSS)") => "css",
+            Some(p) if p.ends_with("(HTML)") => "html",
+            _ => "javascript",
+        },
         ProviderType::WebFrameworks => {
             // Check platform for hints
             if let Some(p) = platforms {
@@ -2799,8 +4550,9 @@ fn detect_code_language(provider: &ProviderType, platforms: Option<&str>) -> &'s
             "typescript"
         }
         ProviderType::Cocoon => "text",
-        ProviderType::Vertcoin => "bash",
+        ProviderType::Bitcoind => "bash",
         ProviderType::Cuda => "cuda",
+        ProviderType::Solana => "typescript",
     }
 }
 
@@ -2808,6 +4560,21 @@ fn detect_code_language(provider: &ProviderType, platforms: Option<&str>) -> &'s
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_url_mode_rejects_formatting_arguments() {
+        let args: Args = parse_args(json!({"query": "https://developer.apple.com/documentation/swiftui/view"})).unwrap();
+        assert!(args.url_mode_conflicts().is_empty());
+
+        let args: Args = parse_args(json!({
+            "query": "https://developer.apple.com/documentation/swiftui/view",
+            "format": "json",
+            "maxCodeLength": 200,
+            "language": "objc",
+        }))
+        .unwrap();
+        assert_eq!(args.url_mode_conflicts(), ["format", "maxCodeLength", "language"]);
+    }
+
     #[test]
     fn test_parse_howto_intent() {
         let intent = parse_query_intent("how to use SwiftUI NavigationStack");
@@ -2837,6 +4604,147 @@ mod tests {
         assert!(intent.technology.as_ref().unwrap().contains("tokio"));
     }
 
+    #[test]
+    fn test_detect_secondary_provider() {
+        let intent = parse_query_intent("transformers pipeline swift");
+        assert_eq!(intent.provider, Some(ProviderType::Apple));
+        assert_eq!(
+            intent.secondary,
+            Some((ProviderType::HuggingFace, "hf:transformers".to_string()))
+        );
+
+        assert_eq!(parse_query_intent("SwiftUI NavigationStack").secondary, None);
+        // Platform names alone do not pull in Apple next to React Native
+        assert_eq!(parse_query_intent("react native ios navigation").secondary, None);
+        // Nor do words other providers share with Apple
+        for query in [
+            "mlx unified memory on apple silicon",
+            "swift-transformers tokenizer",
+            "mlx swift array operations",
+            "rust serde serialize bitcoin amounts",
+        ] {
+            assert_eq!(parse_query_intent(query).secondary, None, "{query}");
+        }
+    }
+
+    #[test]
+    fn test_interleave_labels_providers() {
+        let titled = |title: &str| DocResult { title: title.to_string(), ..sample_result() };
+        let apple = label(vec![titled("a1"), titled("a2"), titled("a3")], ProviderType::Apple);
+        let hf = label(vec![titled("h1")], ProviderType::HuggingFace);
+
+        let merged = interleave(apple, hf, 3);
+        let titles: Vec<&str> = merged.iter().map(|result| result.title.as_str()).collect();
+        assert_eq!(titles, ["a1", "h1", "a2"]);
+        assert_eq!(providers_label(&ProviderType::Apple, &merged), "Apple + Hugging Face");
+
+        let intent = parse_query_intent("transformers pipeline swift");
+        let response = build_response(
+            &intent,
+            &ProviderType::Apple,
+            "SwiftUI",
+            &merged,
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        let text = &response.content[0].text;
+        assert!(text.contains("**Provider:** Apple + Hugging Face"), "{text}");
+        assert!(text.contains("### 2. h1 `struct` · Hugging Face"), "{text}");
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["providers"], json!(["Apple", "Hugging Face"]));
+        assert_eq!(metadata["results"][1]["provider"], "Hugging Face");
+    }
+
+    #[tokio::test]
+    async fn test_pinned_technology_skips_detection() {
+        let context = Arc::new(AppContext::new(docs_mcp_client::AppleDocsClient::new()));
+        select_technology(&context, ProviderType::Rust, "rust:serde").await.unwrap();
+        *context.state.technology_pinned.write().await = true;
+
+        let intent = parse_query_intent("tokio spawn async task");
+        let (provider, title) = resolve_technology(&context, &intent, false).await.unwrap();
+        assert_eq!(provider, ProviderType::Rust);
+        assert_eq!(title, "Rust serde");
+
+        *context.state.technology_pinned.write().await = false;
+        let (_, title) = resolve_technology(&context, &intent, false).await.unwrap();
+        assert!(title.contains("tokio"), "{title}");
+    }
+
+    #[tokio::test]
+    async fn test_secondary_provider_keeps_session_on_primary() {
+        let context = Arc::new(AppContext::new(docs_mcp_client::AppleDocsClient::new()));
+        let intent = parse_query_intent("bitcoin getblockchaininfo cuda");
+        assert_eq!(intent.provider, Some(ProviderType::Bitcoind));
+        assert_eq!(intent.secondary.as_ref().map(|(provider, _)| *provider), Some(ProviderType::Cuda));
+
+        let response = run_query(context.clone(), json!({"query": intent.raw_query, "format": "json"}))
+            .await
+            .unwrap();
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["providers"], json!([ProviderType::Bitcoind.name(), "CUDA"]));
+        assert_eq!(*context.state.active_provider.read().await, ProviderType::Bitcoind);
+    }
+
+    #[tokio::test]
+    async fn test_explicit_provider_and_technology_override_detection() {
+        let context = Arc::new(AppContext::new(docs_mcp_client::AppleDocsClient::new()));
+
+        let mut intent = parse_query_intent("tokio spawn async task");
+        assert!(apply_overrides(&context, &mut intent, Some("rust"), Some("async-std")).await.unwrap());
+        assert_eq!(intent.technology.as_deref(), Some("rust:async-std"));
+
+        let mut intent = parse_query_intent("Array map");
+        apply_overrides(&context, &mut intent, Some("telegram"), None).await.unwrap();
+        assert_eq!(intent.provider, Some(ProviderType::Telegram));
+        assert_eq!(intent.technology.as_deref(), Some("telegram:methods"));
+
+        // Explicit arguments win over a pinned technology
+        select_technology(&context, ProviderType::Rust, "rust:serde").await.unwrap();
+        *context.state.technology_pinned.write().await = true;
+        let (provider, title) = resolve_technology(&context, &intent, true).await.unwrap();
+        assert_eq!((provider, title.as_str()), (ProviderType::Telegram, "Telegram Bot API"));
+
+        // A technology alone belongs to the provider the query was routed to
+        let mut intent = parse_query_intent("tokio spawn async task");
+        assert!(apply_overrides(&context, &mut intent, None, Some("tokio")).await.unwrap());
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+        assert_eq!(intent.technology.as_deref(), Some("rust:tokio"));
+
+        let mut intent = parse_query_intent("spawn");
+        assert!(!apply_overrides(&context, &mut intent, None, Some(" ")).await.unwrap());
+        assert!(apply_overrides(&context, &mut intent, Some("cobol"), None).await.is_err());
+        assert!(apply_overrides(&context, &mut intent, Some("apple"), Some("swiftui/")).await.is_err());
+    }
+
+    #[test]
+    fn test_fallback_technology_title_is_char_safe() {
+        let technology = fallback_technology("doc://com.apple.documentation/documentation/élan");
+        assert_eq!(technology.title, "Élan");
+        assert_eq!(technology.url, "https://developer.apple.com/documentation/élan");
+        assert_eq!(fallback_technology("doc://com.apple.documentation/documentation/swiftui/").title, "Swiftui");
+        assert!(technology_identifier(ProviderType::Apple, "swiftui/").is_err());
+        assert!(technology_identifier(ProviderType::Apple, "  ").is_err());
+    }
+
+    #[test]
+    fn test_keyword_overrides_change_apple_and_rust_detection() {
+        let detect = |rules: &IntentRules, query: &str| {
+            detect_provider_and_technology(rules, query, &query.to_lowercase())
+        };
+        let builtin = IntentRules::builtin();
+        assert_eq!(detect(&builtin, "vision pipeline huggingface").0, Some(ProviderType::Apple));
+        assert_eq!(detect(&builtin, "bevy ecs cargo").1.as_deref(), Some("rust:std"));
+
+        let rules = builtin
+            .merged("keywords: {apple_frameworks: {remove: [vision]}, rust_crates: {add: [bevy]}}")
+            .unwrap();
+        assert_eq!(detect(&rules, "vision pipeline huggingface").0, Some(ProviderType::HuggingFace));
+        assert_eq!(detect(&rules, "bevy ecs cargo").1.as_deref(), Some("rust:bevy"));
+        assert_eq!(detect(&rules, "SwiftUI List").1.as_deref(), Some("doc://com.apple.documentation/documentation/swiftui"));
+    }
+
     #[test]
     fn test_detect_rust_crate_from_token() {
         let intent = parse_query_intent("Rust async_trait");
@@ -2844,6 +4752,102 @@ mod tests {
         assert_eq!(intent.technology.as_deref(), Some("rust:async_trait"));
     }
 
+    #[test]
+    fn test_unknown_crate_candidates_skip_types_and_concepts() {
+        let intent = parse_query_intent("Rust bevy ECS query");
+        assert_eq!(intent.technology.as_deref(), Some("rust:std"));
+        assert_eq!(unknown_crate_candidates(&intent), vec!["bevy", "query"]);
+
+        let intent = parse_query_intent("Rust HashMap async iterator");
+        assert!(unknown_crate_candidates(&intent).is_empty());
+    }
+
+    #[test]
+    fn test_detect_pinned_rust_crate_version() {
+        let intent = parse_query_intent("tokio@1.35/sync/mpsc channel");
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+        assert_eq!(intent.technology.as_deref(), Some("rust:tokio@1.35"));
+        assert_eq!(intent.keywords, vec!["tokio", "sync", "mpsc", "channel"]);
+    }
+
+    #[test]
+    fn test_detect_rustc_error_codes() {
+        let intent = parse_query_intent(
+            "error[E0502]: cannot borrow `v` as mutable because it is also borrowed as immutable",
+        );
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+        assert_eq!(intent.technology.as_deref(), Some("rust:std"));
+
+        let intent = parse_query_intent("what does e0382 mean");
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+        assert_eq!(find_error_code(&intent.raw_query).as_deref(), Some("E0382"));
+    }
+
+    #[test]
+    fn test_detect_cargo_book_queries() {
+        for query in ["How do I enable LTO in Cargo.toml?", "cargo add serde --features derive"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Rust), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(CARGO_BOOK_TECHNOLOGY), "{query}");
+        }
+        let intent = parse_query_intent("rust cargo HashMap");
+        assert_eq!(intent.technology.as_deref(), Some("rust:std"));
+    }
+
+    #[test]
+    fn test_detect_css_queries() {
+        for query in ["css grid template areas", "@media prefers-color-scheme", "li:nth-child odd rows", "justify-content vs align-items"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Mdn), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(MDN_CSS_TECHNOLOGY), "{query}");
+        }
+        let intent = parse_query_intent("javascript promise all");
+        assert_eq!(intent.technology.as_deref(), Some("mdn:javascript"));
+
+        let intent = parse_query_intent("@container query with grid-template-columns");
+        assert_eq!(
+            css_search_query(&intent.raw_query, &intent.keywords),
+            "css @container grid-template-columns query"
+        );
+    }
+
+    #[test]
+    fn test_detect_html_element_queries() {
+        for query in ["dialog element", "input type=datetime-local", "how to style <details> open state", "accessible button tag"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Mdn), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(MDN_HTML_TECHNOLOGY), "{query}");
+        }
+        let intent = parse_query_intent("react <Suspense> fallback");
+        assert_eq!(intent.provider, Some(ProviderType::WebFrameworks));
+
+        assert_eq!(
+            html_element_query(r#"<input type="checkbox"> indeterminate"#),
+            Some(("input".to_string(), Some("checkbox".to_string())))
+        );
+        assert_eq!(html_element_query("ui element spacing"), None);
+        assert_eq!(html_element_query("create a tag for the release"), None);
+        assert_eq!(html_search_query("input type=datetime-local", "input type datetime local"), "html <input> type datetime-local");
+        assert_eq!(html_search_query("dialog element close", "dialog element close"), "html <dialog> close");
+    }
+
+    #[test]
+    fn test_with_accessibility_notes() {
+        let notes = vec!["Implicit ARIA role: dialog".to_string()];
+        assert_eq!(
+            with_accessibility_notes(Some("Intro.\n\nDetails.".to_string()), &notes).as_deref(),
+            Some("Intro.\n\nAccessibility:\n- Implicit ARIA role: dialog\n\nDetails.")
+        );
+        assert_eq!(with_accessibility_notes(None, &[]), None);
+    }
+
+    #[test]
+    fn test_rust_availability() {
+        assert_eq!(rust_availability("std", "latest", &[]), "std vlatest");
+        let platforms = vec!["Linux".to_string(), "Android".to_string()];
+        assert_eq!(rust_availability("std", "latest", &platforms), "std vlatest · Linux or Android only");
+    }
+
     #[test]
     fn test_detect_rust_docs_rs_crate() {
         let intent = parse_query_intent("docs.rs/parking_lot Mutex");
@@ -2851,6 +4855,287 @@ mod tests {
         assert_eq!(intent.technology.as_deref(), Some("rust:parking_lot"));
     }
 
+    #[test]
+    fn test_detect_express_queries() {
+        for query in [
+            "express router middleware",
+            "Express.js res.json status code",
+            "error handling middleware that calls next(err) in express",
+            "node.js routing with route parameters",
+            "req.params in route handler",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::WebFrameworks), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some("webfw:express"), "{query}");
+        }
+
+        let intent = parse_query_intent("Next.js middleware");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nextjs"));
+        let intent = parse_query_intent("Node.js fs readFile");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nodejs"));
+    }
+
+    #[test]
+    fn test_detect_astro_and_react_router_queries() {
+        for query in ["Astro getStaticPaths dynamic routes", "getCollection content collections", "client:visible island"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.technology.as_deref(), Some("webfw:astro"), "{query}");
+        }
+        for query in ["Remix loader useLoaderData", "react router v7 data loading", "useFetcher form submit", "clientLoader cache"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.technology.as_deref(), Some("webfw:react-router"), "{query}");
+        }
+
+        let intent = parse_query_intent("React useState hook");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:react"));
+    }
+
+    #[test]
+    fn test_detect_node_release() {
+        let intent = parse_query_intent("nodejs@20 fs readFile");
+        assert_eq!(intent.provider, Some(ProviderType::WebFrameworks));
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nodejs@20"));
+        assert!(!intent.keywords.iter().any(|k| k.contains("20")), "{:?}", intent.keywords);
+
+        let intent = parse_query_intent("Node.js v18 fetch");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nodejs@18"));
+        let intent = parse_query_intent("node 22 test runner");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nodejs@22"));
+
+        // Unsupported lines use the current docs; crate pins still go to Rust
+        let intent = parse_query_intent("node 16 fs readFile");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:nodejs"));
+        let intent = parse_query_intent("tokio@1.35 mpsc channel");
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+
+        assert_eq!(web_framework_technology("webfw:nodejs@20"), ("nodejs", Some(20)));
+        assert_eq!(web_framework_technology("webfw:nodejs@12"), ("nodejs", None));
+        assert_eq!(web_framework_technology("webfw:react"), ("react", None));
+    }
+
+    #[test]
+    fn test_detect_react_native_queries() {
+        for query in [
+            "React Native FlatList performance",
+            "flatlist onEndReached",
+            "turbomodule codegen spec",
+            "react native ios push notifications",
+            "react-native StyleSheet.create",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::WebFrameworks), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some("webfw:react-native"), "{query}");
+        }
+
+        let intent = parse_query_intent("react useEffect cleanup");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:react"));
+    }
+
+    #[test]
+    fn test_detect_typescript_queries() {
+        for query in [
+            "TypeScript generics constraints",
+            "tsconfig moduleResolution bundler",
+            "Omit<User, \"id\"> example",
+            "typescript module resolution error",
+            "strictNullChecks undefined",
+            "discriminated union exhaustive switch",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::WebFrameworks), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some("webfw:typescript"), "{query}");
+        }
+
+        // Framework questions that mention TypeScript stay with the framework
+        let intent = parse_query_intent("bun typescript");
+        assert_eq!(intent.technology.as_deref(), Some("webfw:bun"));
+        let intent = parse_query_intent("javascript promise all");
+        assert_eq!(intent.technology.as_deref(), Some("mdn:javascript"));
+    }
+
+    #[test]
+    fn test_detect_tdlib_queries() {
+        for query in ["TDLib sendMessage", "td_api getChats", "updateAuthorizationState handling", "tdjson receive"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Telegram), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(TDLIB_TECHNOLOGY), "{query}");
+        }
+
+        let intent = parse_query_intent("telegram bot sendMessage");
+        assert_eq!(intent.technology.as_deref(), Some("telegram:methods"));
+    }
+
+    #[test]
+    fn test_detect_mini_apps_queries() {
+        for query in [
+            "validate initData on the server",
+            "Telegram WebApp themeParams",
+            "mini app MainButton",
+            "web_app_data keyboard button",
+            "HapticFeedback impactOccurred",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Telegram), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(MINI_APPS_TECHNOLOGY), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_detect_tlb_queries() {
+        for query in [
+            "jetton transfer TL-B",
+            "nft transfer tlb schema",
+            "TON wallet v4 message layout",
+            "how to serialize a jetton burn message",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::TON), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(TLB_TECHNOLOGY), "{query}");
+        }
+
+        let intent = parse_query_intent("TON get account balance");
+        assert_eq!(intent.technology.as_deref(), Some("ton:accounts"));
+    }
+
+    #[test]
+    fn test_detect_exit_code_queries() {
+        for query in ["ton exit code 37", "TVM exit code -14", "jetton wallet exit code 705", "compute phase exit code 9"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::TON), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(EXIT_CODES_TECHNOLOGY), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_detect_quicknode_evm_queries() {
+        for (query, technology) in [
+            ("ethereum eth_call", "quicknode:ethereum:http"),
+            ("eth_getLogs filter topics", "quicknode:ethereum:http"),
+            ("debug_traceTransaction callTracer", "quicknode:ethereum:debug"),
+            ("ethereum trace_block", "quicknode:ethereum:trace"),
+            ("polygon eth_getBalance", "quicknode:polygon:http"),
+            ("base chain debug_traceCall", "quicknode:base:debug"),
+            ("evm eth_subscribe logs", "quicknode:ethereum:websocket"),
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::QuickNode), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(technology), "{query}");
+        }
+
+        let intent = parse_query_intent("solana getAccountInfo");
+        assert_eq!(intent.technology.as_deref(), Some("quicknode:solana:http"));
+    }
+
+    #[test]
+    fn test_detect_bitcoind_coin_queries() {
+        for (query, technology) in [
+            ("Vertcoin getblockchaininfo", "vertcoin:blockchain"),
+            ("verthash mining setup", "vertcoin:mining"),
+            ("getbalance", "vertcoin:wallet"),
+            ("bitcoin-cli getbalance", "bitcoin:wallet"),
+            ("bitcoin core getblockchaininfo", "bitcoin:blockchain"),
+            ("btc taproot", "bitcoin:specs"),
+            ("litecoin mweb", "litecoin:specs"),
+            ("litecoin scrypt mining", "litecoin:mining"),
+            ("vertcoin vs bitcoin block time", "vertcoin:specs"),
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Bitcoind), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(technology), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_detect_cuda_library_queries() {
+        for (query, technology) in [
+            ("cublasGemmEx tensor cores", "cuda:cublas"),
+            ("cudnnSetTensor4dDescriptor NHWC", "cuda:cudnn"),
+            ("cufftPlanMany batched", "cuda:cufft"),
+            ("cuda curand generator", "cuda:libraries"),
+            ("thrust::device_vector transform", "cuda:thrust"),
+            ("thrust inclusive_scan", "cuda:thrust"),
+            ("cub::DeviceReduce::Sum temp storage", "cuda:cub"),
+            ("cuda cub block reduce", "cuda:cub"),
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Cuda), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(technology), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_detect_solana_docs_queries() {
+        for (query, technology) in [
+            ("solana rent exemption", "solana:core"),
+            ("program derived address bump", "solana:core"),
+            ("solana cpi invoke_signed", "solana:core"),
+            ("how to create a pda account on solana", "solana:cookbook"),
+            ("solana cookbook send sol", "solana:cookbook"),
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Solana), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(technology), "{query}");
+        }
+
+        let intent = parse_query_intent("solana getProgramAccounts");
+        assert_eq!(intent.provider, Some(ProviderType::QuickNode));
+    }
+
+    #[test]
+    fn test_detect_hugging_face_model_cards() {
+        for query in [
+            "Qwen2.5-7B-Instruct usage",
+            "meta-llama/Llama-3.1-8B-Instruct context length",
+            "https://huggingface.co/google/gemma-2-9b-it",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::HuggingFace), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some("hf:models"), "{query}");
+        }
+
+        let intent = parse_query_intent("transformers AutoTokenizer padding");
+        assert_eq!(intent.technology.as_deref(), Some("hf:transformers"));
+    }
+
+    #[test]
+    fn test_sdk_version_in_query() {
+        assert_eq!(sdk_version_in_query("hooks in agent sdk v0.1.0").as_deref(), Some("0.1.0"));
+        assert_eq!(sdk_version_in_query("claude-agent-sdk 0.1.5 options?").as_deref(), Some("0.1.5"));
+        assert_eq!(sdk_version_in_query("agent sdk on Python 3.10"), None);
+        assert_eq!(sdk_version_in_query("agent sdk hooks"), None);
+    }
+
+    #[test]
+    fn test_detect_hugging_face_hub_api() {
+        for query in [
+            "Hugging Face API search models curl",
+            "hub api download file",
+            "GET https://huggingface.co/api/models/Qwen/Qwen2.5-7B-Instruct",
+            "create hf inference endpoint",
+            "router.huggingface.co chat completions",
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::HuggingFace), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some("hf:hub-api"), "{query}");
+        }
+    }
+
+    #[test]
+    fn test_detect_mlx_lm_queries() {
+        for (query, technology) in [
+            ("mlx_lm generate with a sampler", "mlx:lm"),
+            ("mlx-lm lora fine-tuning", "mlx:lm"),
+            ("quantize llama to 4-bit with mlx", "mlx:lm"),
+            ("mlx-examples whisper", "mlx:examples"),
+            ("mlx training loop example", "mlx:examples"),
+            ("mlx.core.array reshape", "mlx:python"),
+        ] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.provider, Some(ProviderType::Mlx), "{query}");
+            assert_eq!(intent.technology.as_deref(), Some(technology), "{query}");
+        }
+    }
+
     #[test]
     fn test_detect_mlx_before_node_module() {
         let intent = parse_query_intent("MLX nn module");
@@ -2869,6 +5154,45 @@ mod tests {
         assert_eq!(intent.provider, Some(ProviderType::Telegram));
     }
 
+    #[test]
+    fn test_aliases_expand_for_apple_queries() {
+        let intent = parse_query_intent("CD persistent container");
+        assert_eq!(intent.provider, Some(ProviderType::Apple));
+        assert_eq!(
+            intent.technology.as_deref(),
+            Some("doc://com.apple.documentation/documentation/coredata")
+        );
+        assert!(intent.keywords.contains(&"coredata".to_string()));
+        let intent = parse_query_intent("uikit present a vc modally");
+        assert!(intent.keywords.contains(&"viewcontroller".to_string()));
+        assert!(!intent.keywords.contains(&"vc".to_string()));
+        // Nothing routes the query yet, so the session's provider decides later
+        assert!(parse_query_intent("present a vc modally").keywords.contains(&"vc".to_string()));
+        assert!(query_keywords("present a vc modally", true).contains(&"viewcontroller".to_string()));
+    }
+
+    #[test]
+    fn test_aliases_leave_other_providers_alone() {
+        let intent = parse_query_intent("nav element");
+        assert_eq!(intent.provider, Some(ProviderType::Mdn));
+        assert_eq!(intent.technology.as_deref(), Some(MDN_HTML_TECHNOLOGY));
+        assert!(intent.keywords.contains(&"nav".to_string()));
+        assert_eq!(parse_query_intent("img element lazy loading").provider, Some(ProviderType::Mdn));
+
+        for query in ["cargo env", "cargo config"] {
+            let intent = parse_query_intent(query);
+            assert_eq!(intent.technology.as_deref(), Some(CARGO_BOOK_TECHNOLOGY), "{query}");
+            assert!(!intent.keywords.iter().any(|k| k.starts_with("environment") || k.starts_with("configuration")), "{query}");
+        }
+
+        let intent = parse_query_intent("rust async/await tokio");
+        assert_eq!(intent.provider, Some(ProviderType::Rust));
+        assert!(!intent.keywords.contains(&"concurrency".to_string()));
+        let intent = parse_query_intent("javascript async/await");
+        assert_eq!(intent.provider, Some(ProviderType::Mdn));
+        assert!(!intent.keywords.contains(&"concurrency".to_string()));
+    }
+
     #[test]
     fn test_extract_keywords() {
         let keywords = extract_keywords("how to use swiftui navigationstack with binding");
@@ -2881,6 +5205,366 @@ mod tests {
         assert!(!keywords.contains(&"use".to_string()));
     }
 
+    #[test]
+    fn test_trim_text_zero_disables_truncation() {
+        let text = "a".repeat(50);
+        assert_eq!(trim_text(&text, 0), text);
+        assert_eq!(trim_text(&text, 10), format!("{}...", "a".repeat(10)));
+    }
+
+    #[test]
+    fn test_args_limits_override_defaults() {
+        let args: Args = serde_json::from_value(json!({
+            "query": "SwiftUI List",
+            "maxCodeLength": 0
+        }))
+        .unwrap();
+        let limits = args.limits(ContentLimits::default());
+        assert_eq!(limits.code, 0);
+        assert_eq!(limits.summary, ContentLimits::default().summary);
+        assert_eq!(limits.content, ContentLimits::default().content);
+    }
+
+    fn sample_result() -> DocResult {
+        DocResult {
+            title: "NavigationStack".to_string(),
+            kind: "struct".to_string(),
+            path: "documentation/swiftui/navigationstack".to_string(),
+            url: Some(apple_web_url("documentation/SwiftUI/NavigationStack")),
+            score: None,
+            summary: "A view that displays a root view.".to_string(),
+            platforms: Some("iOS 16.0+".to_string()),
+            code_sample: Some("NavigationStack { Text(\"Hi\") }".to_string()),
+            code_language: None,
+            related_apis: vec![],
+            full_content: None,
+            declaration: Some("struct NavigationStack".to_string()),
+            parameters: vec![("path".to_string(), "The navigation path.".to_string())],
+            beta: false,
+            features: Vec::new(),
+            browser_support: Vec::new(),
+            provider: None,
+        }
+    }
+
+    #[test]
+    fn test_result_fields_match_serialized_results() {
+        let result = DocResult {
+            features: vec!["rt".to_string()],
+            browser_support: vec![MdnBrowserSupport {
+                browser: "Firefox".to_string(),
+                version_added: Some("88".to_string()),
+                prefix: None,
+                alternative_name: None,
+                partial: false,
+                flagged: false,
+                note: None,
+            }],
+            provider: Some(ProviderType::Rust),
+            ..sample_result()
+        };
+        let value = serde_json::to_value(result).unwrap();
+        let mut keys: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        let mut expected = RESULT_FIELDS.to_vec();
+        keys.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn test_truncated_results_link_full_documents() {
+        let context = AppContext::new(docs_mcp_client::AppleDocsClient::new());
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let mut long = sample_result();
+        long.full_content = Some("Navigation ".repeat(100));
+        let limits = ContentLimits { content: 200, ..ContentLimits::default() };
+        let results = [long, DocResult { title: "List".to_string(), ..sample_result() }];
+
+        let response = build_response(&intent, &ProviderType::Apple, "SwiftUI", &results, &limits, OutputFormat::Markdown)
+            .unwrap();
+        let response = link_full_documents(&context, &ProviderType::Apple, response, &results, &limits);
+        assert_eq!(response.content.len(), 2);
+        let link = response.content[1].resource.as_ref().unwrap();
+        assert_eq!(response.content[1].r#type, "resource_link");
+        assert_eq!(link.uri, "docs-mcp://documents/apple/documentation/swiftui/navigationstack");
+        assert_eq!(response.metadata.unwrap()["fullDocuments"], json!([link.uri]));
+
+        let document = context.documents.get(&link.uri).unwrap();
+        assert!(document.text.contains(&"Navigation ".repeat(100).trim_end().to_string()));
+        assert!(document.text.contains("```swift\nNavigationStack { Text(\"Hi\") }\n```"), "{}", document.text);
+    }
+
+    #[test]
+    fn test_build_response_json_format() {
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let response = build_response(
+            &intent,
+            &ProviderType::Apple,
+            "SwiftUI",
+            &[sample_result()],
+            &ContentLimits::default(),
+            OutputFormat::Json,
+        )
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&response.content[0].text).unwrap();
+        assert_eq!(parsed["results"][0]["title"], "NavigationStack");
+        assert_eq!(parsed["results"][0]["codeSample"], "NavigationStack { Text(\"Hi\") }");
+        assert_eq!(parsed["results"][0]["parameters"][0]["name"], "path");
+        assert_eq!(
+            parsed["results"][0]["url"],
+            "https://developer.apple.com/documentation/swiftui/navigationstack"
+        );
+        assert_eq!(
+            response.metadata.as_ref().unwrap()["sources"][0],
+            "https://developer.apple.com/documentation/swiftui/navigationstack"
+        );
+    }
+
+    #[test]
+    fn test_build_response_structured_metadata() {
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let mut summary_only = sample_result();
+        summary_only.title = "NavigationSplitView".to_string();
+        summary_only.code_sample = None;
+        summary_only.declaration = None;
+        summary_only.parameters.clear();
+        summary_only.score = Some(12.0);
+        let response = build_response(
+            &intent,
+            &ProviderType::Apple,
+            "SwiftUI",
+            &[sample_result(), summary_only],
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["provider"], "Apple");
+        assert_eq!(metadata["resultCount"], 2);
+        assert_eq!(metadata["detailedCount"], 1);
+        assert_eq!(metadata["paths"][0], "documentation/swiftui/navigationstack");
+        assert_eq!(metadata["results"][1]["rank"], 2);
+        assert_eq!(metadata["results"][1]["score"], 12.0);
+        assert_eq!(metadata["results"][1]["detailed"], false);
+        assert_eq!(metadata["results"][1]["relevance"], 0.55);
+        assert!(metadata["results"][0]["relevance"].is_null());
+    }
+
+    #[test]
+    fn test_beta_results_are_flagged() {
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let mut beta = sample_result();
+        beta.beta = true;
+        beta.platforms = Some("iOS 26.0 (Beta)".to_string());
+        let response = build_response(
+            &intent,
+            &ProviderType::Apple,
+            "SwiftUI",
+            &[beta],
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        assert!(response.content[0].text.contains("### 1. NavigationStack `struct` 🧪 Beta"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["betaCount"], 1);
+        assert_eq!(metadata["results"][0]["beta"], true);
+    }
+
+    #[test]
+    fn test_feature_gated_results_name_their_features() {
+        let intent = parse_query_intent("tokio mpsc channel");
+        let mut gated = sample_result();
+        gated.features = vec!["sync".to_string()];
+        let response = build_response(
+            &intent,
+            &ProviderType::Rust,
+            "tokio",
+            &[gated],
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        assert!(response.content[0]
+            .text
+            .contains("**Requires Cargo features:** `sync` (enable in `Cargo.toml`)"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["results"][0]["features"][0], "sync");
+    }
+
+    #[test]
+    fn test_web_results_include_browser_support() {
+        let intent = parse_query_intent("css aspect-ratio");
+        let mut result = sample_result();
+        result.browser_support = ["Chrome", "Firefox", "Safari"]
+            .iter()
+            .zip(["88", "89", "15"])
+            .map(|(browser, version)| MdnBrowserSupport {
+                browser: browser.to_string(),
+                version_added: Some(version.to_string()),
+                prefix: None,
+                alternative_name: None,
+                partial: false,
+                flagged: false,
+                note: None,
+            })
+            .collect();
+        let response = build_response(
+            &intent,
+            &ProviderType::Mdn,
+            "MDN CSS Reference",
+            std::slice::from_ref(&result),
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        assert!(response.content[0]
+            .text
+            .contains("**Browser support:**\n| Chrome | Firefox | Safari |\n| --- | --- | --- |\n| 88 | 89 | 15 |"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["results"][0]["browserSupport"][2]["versionAdded"], "15");
+
+        let plain = build_response(
+            &intent,
+            &ProviderType::Mdn,
+            "MDN CSS Reference",
+            &[result],
+            &ContentLimits::default(),
+            OutputFormat::Plain,
+        )
+        .unwrap();
+        assert!(plain.content[0].text.contains("Browser support: Chrome 88 · Firefox 89 · Safari 15"));
+    }
+
+    #[test]
+    fn test_interface_members_are_listed_as_members() {
+        let intent = parse_query_intent("IntersectionObserver");
+        let mut result = sample_result();
+        result.kind = "Interface".to_string();
+        result.parameters = vec![(
+            "IntersectionObserver.root".to_string(),
+            "read-only property — The Element or Document whose bounds are used.".to_string(),
+        )];
+        result.related_apis = vec!["IntersectionObserver.observe()".to_string()];
+        let response = build_response(
+            &intent,
+            &ProviderType::Mdn,
+            "MDN Web Docs",
+            &[result],
+            &ContentLimits::default(),
+            OutputFormat::Markdown,
+        )
+        .unwrap();
+        let text = &response.content[0].text;
+        assert!(text.contains(
+            "**Members:**\n- `IntersectionObserver.root`: read-only property — The Element or Document whose bounds are used."
+        ));
+        assert!(text.contains("**Related:** IntersectionObserver.observe()"));
+    }
+
+    #[test]
+    fn test_offline_miss_response_explains_cache_miss() {
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let response = offline_miss_response(&intent, &ProviderType::Apple, "SwiftUI");
+        assert!(response.content[0].text.contains("Offline mode: no cached Apple documentation"));
+        assert!(response.content[0].text.contains("docs-mcp-cli warm"));
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["offline"], true);
+        assert_eq!(metadata["resultCount"], 0);
+    }
+
+    #[test]
+    fn test_relevance_scores_are_calibrated() {
+        let mut high = sample_result();
+        high.score = Some(40.0);
+        let mut low = sample_result();
+        low.score = Some(10.0);
+        assert_eq!(relevance_scores(&[high, low.clone()]), vec![Some(0.8), Some(0.5)]);
+        // A lone weak hit is not promoted to full relevance
+        assert_eq!(relevance_scores(&[low]), vec![Some(0.5)]);
+
+        let unscored = vec![sample_result(), sample_result()];
+        assert_eq!(relevance_scores(&unscored), vec![None, None]);
+    }
+
+    #[test]
+    fn test_tag_code_fences_adds_language_and_closes() {
+        let content = "Intro\n```\nlet x = 1;\n```\n\n```tact\ncontract A {}\n```\n```\ntruncated";
+        let tagged = tag_code_fences(content, "rust");
+        assert!(tagged.contains("```rust\nlet x = 1;\n```"));
+        assert!(tagged.contains("```tact\ncontract A {}\n```"));
+        assert!(tagged.ends_with("```rust\ntruncated\n```"));
+    }
+
+    #[test]
+    fn test_sample_language_prefers_provider_label() {
+        let mut result = sample_result();
+        result.code_language = Some("FC".to_string());
+        assert_eq!(sample_language(&ProviderType::TON, &result), "func");
+        result.code_language = None;
+        assert_eq!(sample_language(&ProviderType::TON, &result), "json");
+    }
+
+    #[test]
+    fn test_dedupe_matches_by_canonical_path() {
+        use crate::state::FrameworkIndexEntry;
+        use docs_mcp_client::types::ReferenceData;
+
+        let entry = |id: &str, url: Option<&str>| FrameworkIndexEntry {
+            id: id.to_string(),
+            tokens: Vec::new(),
+            reference: ReferenceData {
+                title: Some("List".to_string()),
+                kind: Some("struct".to_string()),
+                r#abstract: None,
+                platforms: None,
+                url: url.map(str::to_string),
+            },
+        };
+        let by_identifier = entry("doc://com.apple.documentation/documentation/SwiftUI/List", None);
+        let by_path = entry("list", Some("documentation/swiftui/list"));
+        let other = entry("form", Some("documentation/swiftui/form"));
+
+        let mut matches = vec![(40, &by_identifier), (30, &by_path), (20, &other)];
+        dedupe_matches(&mut matches);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].0, 40);
+        assert_eq!(matches[1].1.id, "form");
+    }
+
+    #[test]
+    fn test_canonical_urls() {
+        assert_eq!(
+            apple_web_url("doc://com.apple.documentation/documentation/SwiftUI/List"),
+            "https://developer.apple.com/documentation/swiftui/list"
+        );
+        assert_eq!(
+            telegram_web_url("sendMessage"),
+            "https://core.telegram.org/bots/api#sendmessage"
+        );
+        assert_eq!(non_empty_url(String::new()), None);
+    }
+
+    #[test]
+    fn test_build_response_plain_format_has_no_markdown() {
+        let intent = parse_query_intent("SwiftUI NavigationStack");
+        let response = build_response(
+            &intent,
+            &ProviderType::Apple,
+            "SwiftUI",
+            &[sample_result()],
+            &ContentLimits::default(),
+            OutputFormat::Plain,
+        )
+        .unwrap();
+        let text = &response.content[0].text;
+        assert!(text.contains("1. NavigationStack (struct)\n"));
+        assert!(!text.contains("relevance"));
+        assert!(!text.contains("```"));
+        assert!(!text.contains("**"));
+        assert!(!text.contains('#'));
+    }
+
     #[test]
     fn test_extract_keywords_strips_macro_bang() {
         let keywords = extract_keywords("how to use tokio::select!");
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use multi_provider_client::cocoon::types::CocoonSnapshot;
+use multi_provider_client::ton::types::TonSpecStatus;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    markdown,
+    state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
+    tools::{parse_args, text_response, wrap_handler},
+};
+
+#[derive(Debug, Deserialize)]
+struct Args {
+    /// Report the cached spec without downloading it again
+    #[serde(default, rename = "statusOnly")]
+    status_only: bool,
+    /// Source to refresh: "ton" (default) or "cocoon"
+    #[serde(default)]
+    provider: Option<String>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "refresh_spec".to_string(),
+        description: "Maintenance: re-download the TON API (tonkeeper OpenAPI) spec, which is otherwise cached \
+                      indefinitely, and report its version. The spec follows master unless DOCSMCP_TON_SPEC_REF pins \
+                      a commit, tag or branch; use statusOnly to inspect the cached copy. With provider \"cocoon\", \
+                      re-download the Cocoon docs at the latest upstream commit instead (they are otherwise checked \
+                      for upstream changes once a day)."
+            .to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "statusOnly": {
+                    "type": "boolean",
+                    "description": "Report the cached spec without downloading it again."
+                },
+                "provider": {
+                    "type": "string",
+                    "enum": ["ton", "cocoon"],
+                    "description": "Which source to refresh (default: ton)."
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({}),
+            json!({"statusOnly": true}),
+            json!({"provider": "cocoon"}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (
+        definition,
+        wrap_handler(|context, value| async move {
+            let args: Args = parse_args(value)?;
+            handle(context, args).await
+        }),
+    )
+}
+
+async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
+    match args.provider.as_deref().map(str::trim).map(str::to_lowercase).as_deref() {
+        None | Some("" | "ton") => {}
+        Some("cocoon") => return handle_cocoon(&context, args.status_only).await,
+        Some(other) => bail!("Unknown provider '{other}'; refresh_spec supports \"ton\" and \"cocoon\""),
+    }
+
+    let ton = &context.providers.ton;
+    let (status, refreshed) = if args.status_only {
+        (ton.spec_status().await?, false)
+    } else {
+        (Some(ton.refresh_spec().await?), true)
+    };
+
+    let lines = render(status.as_ref(), refreshed);
+    Ok(text_response(lines).with_metadata(json!({
+        "provider": "ton",
+        "refreshed": refreshed,
+        "spec": status,
+    })))
+}
+
+async fn handle_cocoon(context: &AppContext, status_only: bool) -> Result<ToolResponse> {
+    let cocoon = &context.providers.cocoon;
+    let (snapshot, refreshed) = if status_only {
+        (cocoon.snapshot().await?, false)
+    } else {
+        (Some(cocoon.refresh().await?), true)
+    };
+
+    let lines = render_cocoon(snapshot.as_ref(), refreshed);
+    Ok(text_response(lines).with_metadata(json!({
+        "provider": "cocoon",
+        "refreshed": refreshed,
+        "snapshot": snapshot,
+    })))
+}
+
+fn render_cocoon(snapshot: Option<&CocoonSnapshot>, refreshed: bool) -> Vec<String> {
+    let mut lines = vec![markdown::header(1, "🔄 Cocoon Docs"), String::new()];
+    let Some(snapshot) = snapshot else {
+        lines.push("No Cocoon docs snapshot is recorded yet; run refresh_spec with provider \"cocoon\" to download them.".to_string());
+        return lines;
+    };
+
+    if refreshed {
+        lines.push("Downloaded the docs again and replaced the cached copies.".to_string());
+        lines.push(String::new());
+    }
+    lines.extend([
+        markdown::bold("Snapshot", &snapshot.label()),
+        markdown::bold("Commit", &snapshot.commit),
+        markdown::bold("Documents", &snapshot.document_count.to_string()),
+        markdown::bold("Fetched", &snapshot.fetched_at.to_string()),
+        markdown::bold("Last checked", &snapshot.checked_at.to_string()),
+        markdown::bold("Source", "https://github.com/TelegramMessenger/cocoon/tree/master/docs"),
+    ]);
+    lines
+}
+
+fn render(status: Option<&TonSpecStatus>, refreshed: bool) -> Vec<String> {
+    let mut lines = vec![markdown::header(1, "🔄 TON API Spec"), String::new()];
+    let Some(status) = status else {
+        lines.push("No TON API spec is cached yet; run refresh_spec without statusOnly to download it.".to_string());
+        return lines;
+    };
+
+    if refreshed {
+        lines.push("Downloaded the spec again and replaced the cached copy.".to_string());
+        lines.push(String::new());
+    }
+    let reference = if status.pinned {
+        format!("{} (pinned)", status.reference)
+    } else {
+        format!("{} (unpinned; set DOCSMCP_TON_SPEC_REF to pin a commit)", status.reference)
+    };
+    lines.extend([
+        markdown::bold("Reference", &reference),
+        markdown::bold("Version", &status.version),
+        markdown::bold("Endpoints", &status.endpoint_count.to_string()),
+        markdown::bold("Fetched", &status.fetched_at.to_string()),
+        markdown::bold("Source", &status.url),
+    ]);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn renders_pinned_and_missing_specs() {
+        let status = TonSpecStatus {
+            reference: "v2.1.0".to_string(),
+            pinned: true,
+            url: "https://raw.githubusercontent.com/tonkeeper/opentonapi/v2.1.0/api/openapi.yml".to_string(),
+            version: "2.0.0".to_string(),
+            endpoint_count: 120,
+            fetched_at: OffsetDateTime::UNIX_EPOCH,
+        };
+
+        let text = render(Some(&status), true).join("\n");
+        assert!(text.contains("Downloaded the spec again"));
+        assert!(text.contains("**Reference:** v2.1.0 (pinned)"));
+        assert!(text.contains("**Endpoints:** 120"));
+
+        let text = render(None, false).join("\n");
+        assert!(text.contains("No TON API spec is cached yet"));
+    }
+
+    #[test]
+    fn renders_cocoon_snapshot() {
+        let snapshot = CocoonSnapshot {
+            commit: "1a2b3c4d5e6f".to_string(),
+            committed_at: Some("2025-11-28T09:15:00Z".to_string()),
+            fetched_at: OffsetDateTime::UNIX_EPOCH,
+            checked_at: OffsetDateTime::UNIX_EPOCH,
+            document_count: 12,
+        };
+
+        let text = render_cocoon(Some(&snapshot), true).join("\n");
+        assert!(text.contains("Downloaded the docs again"));
+        assert!(text.contains("**Snapshot:** docs as of 2025-11-28 (commit 1a2b3c4)"));
+        assert!(text.contains("**Documents:** 12"));
+
+        let text = render_cocoon(None, false).join("\n");
+        assert!(text.contains("No Cocoon docs snapshot is recorded yet"));
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{text_response, wrap_handler};
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "reset_state".to_string(),
+        description: "Maintenance: clear the active provider and technology, the loaded framework and its search index, and expanded identifiers. Use it when a query was routed to the wrong provider or framework.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![json!({})]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, _value: serde_json::Value) -> Result<ToolResponse> {
+    let state = &context.state;
+    let previous_provider = *state.active_provider.read().await;
+    let unified_title = state
+        .active_unified_technology
+        .read()
+        .await
+        .as_ref()
+        .map(|technology| technology.title.clone());
+    let apple_title = state
+        .active_technology
+        .read()
+        .await
+        .as_ref()
+        .map(|technology| technology.title.clone());
+    let previous_technology = unified_title.or(apple_title);
+    let expanded = state.expanded_identifiers.lock().await.len();
+
+    state.reset().await;
+
+    let mut lines = vec!["## State Reset".to_string(), String::new()];
+    lines.push(match &previous_technology {
+        Some(title) => format!("- Cleared active technology: {title} ({})", previous_provider.name()),
+        None => format!("- No technology was selected (provider was {})", previous_provider.name()),
+    });
+    lines.push(format!("- Cleared {expanded} expanded identifier(s) and the framework index"));
+    lines.push("- The next query detects its provider from scratch.".to_string());
+
+    Ok(text_response(lines).with_metadata(json!({
+        "previousProvider": previous_provider.slug(),
+        "previousTechnology": previous_technology,
+        "expandedIdentifiersCleared": expanded,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::AppleDocsClient;
+    use multi_provider_client::types::ProviderType;
+
+    #[tokio::test]
+    async fn clears_selection() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        *context.state.active_provider.write().await = ProviderType::Rust;
+        context.state.expanded_identifiers.lock().await.insert("doc://a".to_string());
+        context.state.framework_index.write().await.replace(Vec::new());
+
+        let response = handle(context.clone(), json!({})).await.unwrap();
+        let metadata = response.metadata.unwrap();
+        assert_eq!(metadata["previousProvider"], "rust");
+        assert_eq!(metadata["expandedIdentifiersCleared"], 1);
+
+        assert_eq!(*context.state.active_provider.read().await, ProviderType::Apple);
+        assert!(context.state.expanded_identifiers.lock().await.is_empty());
+        assert!(context.state.framework_index.read().await.is_none());
+    }
+}
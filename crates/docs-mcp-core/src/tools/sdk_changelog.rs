@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use multi_provider_client::claude_agent_sdk::changelog::{introduced_in, parse_version, version_key, version_matches};
+use multi_provider_client::claude_agent_sdk::{AgentSdkLanguage, AgentSdkRelease};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    markdown,
+    state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
+    tools::{
+        changelog::{non_empty, select_releases, ReleaseVersions},
+        parse_args, text_response, wrap_handler,
+    },
+};
+
+const SDK_VERSIONS: ReleaseVersions<AgentSdkRelease, (u32, u32, u32)> = ReleaseVersions {
+    version: release_version,
+    key: version_key,
+    matches: version_matches,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Args {
+    /// Release or release line, e.g. "0.1.5" or "0.1.x"
+    version: Option<String>,
+    /// Exclusive lower bound of a range, e.g. "0.1.0"
+    from: Option<String>,
+    /// Last SDK release to include; a line such as "0.1.x" includes all of it
+    to: Option<String>,
+    /// "typescript" or "python" (default: both)
+    language: Option<String>,
+    /// Option, hook or type to date, e.g. "PreToolUse" or "max_turns"
+    option: Option<String>,
+}
+
+/// One SDK's releases selected for display
+struct SdkHistory {
+    language: AgentSdkLanguage,
+    latest: Option<String>,
+    selected: Vec<AgentSdkRelease>,
+    introduced: Option<AgentSdkRelease>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "sdk_changelog".to_string(),
+        description: "Claude Agent SDK release notes for TypeScript and Python, and the SDK version that \
+                      introduced an option, hook or type. Pass a release (\"0.1.5\"), a release line (\"0.1.x\"), \
+                      a from/to range or an option name; with no arguments the latest release of each SDK is shown."
+            .to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "version": {
+                    "type": "string",
+                    "description": "SDK release or release line, e.g. \"0.1.5\" or \"0.1.x\""
+                },
+                "from": {
+                    "type": "string",
+                    "description": "Show what changed after this version, e.g. \"0.1.0\""
+                },
+                "to": {
+                    "type": "string",
+                    "description": "Last version to include (default: latest)"
+                },
+                "language": {
+                    "type": "string",
+                    "enum": ["typescript", "python"],
+                    "description": "Which SDK (default: both)"
+                },
+                "option": {
+                    "type": "string",
+                    "description": "Option, hook or type to find the introducing release of, e.g. \"PreToolUse\""
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({}),
+            json!({"version": "0.1.x", "language": "python"}),
+            json!({"option": "SessionStart"}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (
+        definition,
+        wrap_handler(|context, value| async move {
+            let args: Args = parse_args(value)?;
+            handle(context, args).await
+        }),
+    )
+}
+
+async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
+    let version = non_empty(args.version.as_deref());
+    let from = non_empty(args.from.as_deref());
+    let to = non_empty(args.to.as_deref());
+    let option = non_empty(args.option.as_deref());
+    for value in [version, from, to].into_iter().flatten() {
+        if parse_version(value).is_none() {
+            bail!("'{value}' is not an SDK version; expected e.g. \"0.1.5\" or \"0.1.x\"");
+        }
+    }
+    let languages = match non_empty(args.language.as_deref()).map(str::to_lowercase).as_deref() {
+        None => vec![AgentSdkLanguage::TypeScript, AgentSdkLanguage::Python],
+        Some("typescript" | "ts" | "javascript" | "js") => vec![AgentSdkLanguage::TypeScript],
+        Some("python" | "py") => vec![AgentSdkLanguage::Python],
+        Some(other) => bail!("Unknown SDK language '{other}'; expected \"typescript\" or \"python\""),
+    };
+
+    let mut histories = Vec::new();
+    let mut failures = Vec::new();
+    for language in languages {
+        match context.providers.claude_agent_sdk.get_changelog(language).await {
+            Ok(releases) => {
+                let introduced = option.and_then(|option| introduced_in(&releases, option)).cloned();
+                // An option lookup on its own shows the release that introduced it
+                let selected = match (&introduced, version.or(from).or(to)) {
+                    (Some(release), None) => vec![release.clone()],
+                    _ => select_releases(&releases, &SDK_VERSIONS, version, from, to).into_iter().cloned().collect(),
+                };
+                histories.push(SdkHistory {
+                    language,
+                    latest: releases.first().map(|release| release.version.clone()),
+                    selected,
+                    introduced,
+                });
+            }
+            Err(e) => failures.push(format!("{language}: {e}")),
+        }
+    }
+    if histories.is_empty() {
+        bail!("Claude Agent SDK changelog unavailable ({})", failures.join("; "));
+    }
+    if histories.iter().all(|history| history.selected.is_empty()) {
+        bail!("No Claude Agent SDK releases match the requested versions");
+    }
+
+    let lines = render(&histories, option);
+    Ok(text_response(lines).with_metadata(json!({
+        "provider": "claude_agent_sdk",
+        "sdks": histories.iter().map(|history| json!({
+            "language": history.language.to_string(),
+            "latest": history.latest,
+            "releases": history.selected.iter().map(|release| &release.version).collect::<Vec<_>>(),
+            "introducedIn": history.introduced.as_ref().map(|release| &release.version),
+        })).collect::<Vec<_>>(),
+        "unavailable": failures,
+    })))
+}
+
+fn release_version(release: &AgentSdkRelease) -> &str {
+    &release.version
+}
+
+fn render(histories: &[SdkHistory], option: Option<&str>) -> Vec<String> {
+    let mut lines = vec![markdown::header(1, "📜 Claude Agent SDK Changelog"), String::new()];
+
+    if let Some(option) = option {
+        for history in histories {
+            lines.push(match &history.introduced {
+                Some(release) => format!("• `{option}` was introduced in the {} SDK {}", history.language, release.version),
+                None => format!("• `{option}` is not mentioned in the {} SDK release notes", history.language),
+            });
+        }
+        lines.push(String::new());
+    }
+
+    for history in histories {
+        if history.selected.is_empty() {
+            continue;
+        }
+        let latest = history.latest.as_deref().map(|latest| format!(" (latest {latest})")).unwrap_or_default();
+        lines.push(markdown::header(2, &format!("{} SDK{latest}", history.language)));
+        for release in &history.selected {
+            let date = release.date.as_deref().map(|date| format!(" — {date}")).unwrap_or_default();
+            lines.push(markdown::header(3, &format!("{}{date}", release.version)));
+            for change in &release.changes {
+                lines.push(format!("• {change}"));
+            }
+            lines.push(format!("Release notes: {}", release.url));
+            lines.push(String::new());
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(version: &str) -> AgentSdkRelease {
+        AgentSdkRelease {
+            version: version.to_string(),
+            date: None,
+            url: String::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    fn versions(selected: &[&AgentSdkRelease]) -> Vec<String> {
+        selected.iter().map(|release| release.version.clone()).collect()
+    }
+
+    #[test]
+    fn selects_releases() {
+        let releases: Vec<_> = ["0.2.0", "0.1.10", "0.1.9", "0.1.0", "0.0.20"].into_iter().map(release).collect();
+
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, None, None, None)), ["0.2.0"]);
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, Some("0.1.x"), None, None)), ["0.1.10", "0.1.9", "0.1.0"]);
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, Some("v0.1.9"), None, None)), ["0.1.9"]);
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, None, Some("0.1.0"), Some("0.1.10"))), ["0.1.10", "0.1.9"]);
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, None, Some("0.1.9"), None)), ["0.2.0", "0.1.10"]);
+        assert_eq!(versions(&select_releases(&releases, &SDK_VERSIONS, None, None, Some("0.1.x"))), ["0.1.10", "0.1.9", "0.1.0", "0.0.20"]);
+    }
+
+    #[test]
+    fn renders_option_and_releases() {
+        let mut added = release("0.1.3");
+        added.changes.push("Added `SessionStart` hook events".to_string());
+        let histories = [
+            SdkHistory {
+                language: AgentSdkLanguage::Python,
+                latest: Some("0.1.6".to_string()),
+                selected: vec![added.clone()],
+                introduced: Some(added),
+            },
+            SdkHistory {
+                language: AgentSdkLanguage::TypeScript,
+                latest: Some("0.1.30".to_string()),
+                selected: Vec::new(),
+                introduced: None,
+            },
+        ];
+
+        let text = render(&histories, Some("SessionStart")).join("\n");
+        assert!(text.contains("• `SessionStart` was introduced in the Python SDK 0.1.3"));
+        assert!(text.contains("• `SessionStart` is not mentioned in the TypeScript SDK release notes"));
+        assert!(text.contains("## Python SDK (latest 0.1.6)"));
+        assert!(text.contains("### 0.1.3"));
+        assert!(!text.contains("## TypeScript SDK"));
+    }
+}
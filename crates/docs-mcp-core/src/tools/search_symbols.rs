@@ -6,7 +6,7 @@ use std::{
 
 use anyhow::{bail, Context, Result};
 use docs_mcp_client::types::{
-    extract_text, format_platforms, FrameworkData, PlatformInfo, ReferenceData, Technology,
+    available_on, extract_text, format_platforms, FrameworkData, PlatformInfo, ReferenceData, Technology,
 };
 use multi_provider_client::types::ProviderType;
 use once_cell::sync::Lazy;
@@ -23,7 +23,7 @@ use crate::{
     state::{
         AppContext, FrameworkIndexEntry, SearchQueryLog, ToolDefinition, ToolHandler, ToolResponse,
     },
-    tools::{parse_args, text_response, wrap_handler},
+    tools::{parse_args, query, text_response, wrap_handler},
 };
 use futures::future;
 use time::OffsetDateTime;
@@ -40,6 +40,10 @@ struct Args {
     #[serde(rename = "symbolType")]
     symbol_type: Option<String>,
     scope: Option<String>,
+    /// Provider slug; defaults to the provider of the last query
+    provider: Option<String>,
+    /// Framework, crate or category to search; defaults to the last query's technology
+    technology: Option<String>,
 }
 
 #[derive(Clone)]
@@ -273,7 +277,9 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
         ToolDefinition {
             name: "search_symbols".to_string(),
             description:
-                "Search symbols within the selected technology or across all Apple documentation. \
+                "Precise symbol search without the query tool's auto-detection: pass `provider` and \
+                 `technology` to pick exactly what is searched (defaults to the last query's \
+                 selection), or set `scope` to \"global\" to search all Apple documentation. \
                  Supports batch processing: call multiple times with different queries in code, \
                  then aggregate results. Ideal for comparing APIs across frameworks or finding \
                  related symbols programmatically."
@@ -284,12 +290,24 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 "properties": {
                     "query": {"type": "string"},
                     "maxResults": {"type": "number"},
-                    "platform": {"type": "string"},
+                    "platform": {
+                        "type": "string",
+                        "description": "Only symbols available on this platform (e.g. 'iOS', 'visionOS', 'Mac Catalyst')"
+                    },
                     "symbolType": {"type": "string"},
                     "scope": {
                         "type": "string",
                         "enum": ["technology", "global"],
                         "description": "Set to \"global\" to search every technology instead of only the active one"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ProviderType::ALL.iter().map(ProviderType::slug).collect::<Vec<_>>(),
+                        "description": "Provider to search; defaults to the provider of the last query"
+                    },
+                    "technology": {
+                        "type": "string",
+                        "description": "Framework, crate or category identifier (e.g. \"SwiftUI\", \"tokio\", \"mlx:swift\"); defaults to the last query's technology"
                     }
                 }
             }),
@@ -305,6 +323,8 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 json!({"query": "animation", "symbolType": "struct", "maxResults": 10}),
                 // Global search: find symbols across all Apple frameworks
                 json!({"query": "URLSession", "scope": "global", "maxResults": 20}),
+                // Explicit provider and crate: skip auto-detection entirely
+                json!({"query": "spawn", "provider": "rust", "technology": "tokio", "symbolType": "fn"}),
             ]),
             // Enable programmatic calling for batch search operations.
             // Allows Claude to write code that searches multiple queries and aggregates results,
@@ -321,8 +341,40 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
 async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
     let scope = args.scope.as_deref().unwrap_or("technology").to_lowercase();
 
-    // Check the active provider to dispatch to the appropriate search
-    let provider = *context.state.active_provider.read().await;
+    // An explicit provider/technology wins over the active one
+    let active_provider = *context.state.active_provider.read().await;
+    let provider = match args.provider.as_deref() {
+        Some(slug) => ProviderType::from_slug(slug)
+            .with_context(|| format!("Unknown provider \"{slug}\""))?,
+        None => active_provider,
+    };
+    let technology = args
+        .technology
+        .as_deref()
+        .map(str::trim)
+        .filter(|technology| !technology.is_empty());
+    let context = match technology {
+        // Search a throwaway state so the session's selection is left untouched
+        Some(technology) => {
+            let detached = Arc::new(context.detached());
+            query::select_technology(&detached, provider, &query::technology_identifier(provider, technology)?)
+                .await?;
+            detached
+        }
+        None if provider != active_provider => {
+            if provider != ProviderType::Apple || scope != "global" {
+                bail!(
+                    "Pass `technology` to search {} symbols; the active provider is {}.",
+                    provider.name(),
+                    active_provider.name()
+                );
+            }
+            let detached = Arc::new(context.detached());
+            *detached.state.active_provider.write().await = provider;
+            detached
+        }
+        None => context,
+    };
 
     match provider {
         ProviderType::Rust => search_rust(context, args).await,
@@ -336,6 +388,11 @@ async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
     }
 }
 
+/// Whether `kind` satisfies the optional `symbolType` filter.
+fn kind_matches(filter: Option<&str>, kind: &str) -> bool {
+    filter.map_or(true, |filter| kind.eq_ignore_ascii_case(filter.trim()))
+}
+
 async fn search_active_technology(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
     let technology = context
         .state
@@ -343,7 +400,7 @@ async fn search_active_technology(context: Arc<AppContext>, args: Args) -> Resul
         .read()
         .await
         .clone()
-        .context("No technology selected. Use `choose_technology` first.")?;
+        .context("No technology selected. Run `query` first or pass `technology`.")?;
 
     let mut index = ensure_framework_index(&context).await?;
     let max_results = args.max_results.unwrap_or(20).max(1);
@@ -870,18 +927,7 @@ fn collect_matches(
         }
 
         if let Some(platform) = &args.platform {
-            let lower = platform.to_lowercase();
-            let matches_platform = entry
-                .reference
-                .platforms
-                .as_ref()
-                .map(|platforms| {
-                    platforms
-                        .iter()
-                        .any(|info| info.name.to_lowercase().contains(&lower))
-                })
-                .unwrap_or(true);
-            if !matches_platform {
+            if !entry.available_on(platform) {
                 continue;
             }
         }
@@ -1400,7 +1446,13 @@ async fn perform_fallback_search(
     args: &Args,
     max_results: usize,
 ) -> Result<Vec<FallbackResult>> {
-    let framework = load_active_framework(context).await?;
+    let mut framework = load_active_framework(context).await?;
+    if let Some(platform) = &args.platform {
+        let defaults = framework.metadata.platforms.clone();
+        framework.references.retain(|_, reference| {
+            available_on(reference.platforms.as_deref().unwrap_or(&defaults), platform)
+        });
+    }
     let mut results = hierarchical_fallback(&framework, args, max_results);
     if results.is_empty() {
         results = regex_fallback(&framework, args, max_results)?;
@@ -1601,7 +1653,7 @@ async fn search_rust(context: Arc<AppContext>, args: Args) -> Result<ToolRespons
         .read()
         .await
         .clone()
-        .context("No Rust technology selected. Use `choose_technology` first.")?;
+        .context("No Rust technology selected. Run `query` first or pass `technology`.")?;
 
     let crate_name = technology
         .identifier
@@ -1611,11 +1663,14 @@ async fn search_rust(context: Arc<AppContext>, args: Args) -> Result<ToolRespons
     let max_results = args.max_results.unwrap_or(20).max(1);
 
     // Search within the crate
-    let results = context
+    let results: Vec<_> = context
         .providers
         .rust
         .search(crate_name, &args.query)
-        .await?;
+        .await?
+        .into_iter()
+        .filter(|item| kind_matches(args.symbol_type.as_deref(), item.kind.as_str()))
+        .collect();
 
     let match_count = results.len().min(max_results);
 
@@ -1674,7 +1729,7 @@ async fn search_unified_provider(
         .read()
         .await
         .clone()
-        .context("No technology selected. Use `choose_technology` first.")?;
+        .context("No technology selected. Run `query` first or pass `technology`.")?;
 
     let max_results = args.max_results.unwrap_or(20).max(1);
     let query_lower = args.query.to_lowercase();
@@ -1690,7 +1745,14 @@ async fn search_unified_provider(
     // For non-Rust providers, use the unified framework data for search
     match provider {
         ProviderType::Telegram => {
-            let results = context.providers.telegram.search(&args.query).await?;
+            let results: Vec<_> = context
+                .providers
+                .telegram
+                .search(&args.query)
+                .await?
+                .into_iter()
+                .filter(|item| kind_matches(args.symbol_type.as_deref(), &item.kind))
+                .collect();
             let match_count = results.len().min(max_results);
             lines.push(markdown::bold("Matches", &match_count.to_string()));
             lines.push(String::new());
@@ -1831,6 +1893,36 @@ async fn search_unified_provider(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_technology_identifier_accepts_names() {
+        assert_eq!(
+            query::technology_identifier(ProviderType::Apple, "SwiftUI").unwrap(),
+            "doc://com.apple.documentation/documentation/swiftui"
+        );
+        assert_eq!(query::technology_identifier(ProviderType::Rust, "tokio").unwrap(), "rust:tokio");
+        assert_eq!(query::technology_identifier(ProviderType::Rust, "rust:serde").unwrap(), "rust:serde");
+        assert_eq!(query::technology_identifier(ProviderType::Mlx, "mlx:swift").unwrap(), "mlx:swift");
+    }
+
+    #[test]
+    fn test_kind_matches_filter() {
+        assert!(kind_matches(None, "struct"));
+        assert!(kind_matches(Some("Fn"), "fn"));
+        assert!(!kind_matches(Some("struct"), "fn"));
+    }
+
+    #[tokio::test]
+    async fn test_explicit_provider_requires_technology() {
+        let context = Arc::new(AppContext::new(docs_mcp_client::AppleDocsClient::new()));
+        let args: Args =
+            serde_json::from_value(json!({"query": "spawn", "provider": "rust"})).unwrap();
+        let Err(error) = handle(context.clone(), args).await else {
+            panic!("expected an error");
+        };
+        assert!(error.to_string().contains("Pass `technology`"));
+        assert_eq!(*context.state.active_provider.read().await, ProviderType::Apple);
+    }
+
     #[test]
     fn test_proximity_bonus_adjacent_tokens() {
         // Adjacent tokens (distance = 1) should get +5 bonus
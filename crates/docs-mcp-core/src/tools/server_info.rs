@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::services::server_info::server_info;
+use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{text_response, wrap_handler};
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "server_info".to_string(),
+        description: "Maintenance: report the server's effective configuration — version, uptime, transport, cache directories, memory TTLs per provider, refresh, network limits and registered tools. Use it to explain why behavior differs between machines.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![json!({})]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, _value: serde_json::Value) -> Result<ToolResponse> {
+    let info = server_info(&context).await;
+    Ok(text_response(info.summary_lines()).with_metadata(serde_json::to_value(&info)?))
+}
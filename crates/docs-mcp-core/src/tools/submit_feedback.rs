@@ -7,11 +7,12 @@ use serde_json::json;
 use time::OffsetDateTime;
 use tokio::io::AsyncWriteExt;
 
+use multi_provider_client::types::ProviderType;
+
+use crate::services::feedback::{feedback_dir, forward_feedback};
 use crate::state::{AppContext, ToolDefinition, ToolHandler, ToolResponse};
 use crate::tools::{parse_args, text_response, wrap_handler};
 
-const FEEDBACK_DIR_ENV: &str = "DOCSMCP_FEEDBACK_DIR";
-
 #[derive(Debug, Deserialize)]
 struct Args {
     /// Free-form feedback: what worked, what didn’t, what you wish existed.
@@ -27,6 +28,12 @@ struct Args {
     /// What slowed you down (latency, irrelevant results, formatting, etc.).
     #[serde(default, rename = "painPoints")]
     pain_points: Vec<String>,
+    /// Queries that returned nothing useful, verbatim.
+    #[serde(default, rename = "failingQueries")]
+    failing_queries: Vec<String>,
+    /// Provider the feedback is about (slug or display name).
+    #[serde(default)]
+    provider: Option<String>,
     /// Optional client/agent metadata to help reproduce issues.
     #[serde(default)]
     client: Option<ClientInfo>,
@@ -58,6 +65,9 @@ struct FeedbackRecord {
     improvements: Vec<String>,
     missing_docs: Vec<String>,
     pain_points: Vec<String>,
+    failing_queries: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider: Option<String>,
     environment: serde_json::Value,
     diagnostics: serde_json::Value,
 }
@@ -71,7 +81,7 @@ struct ServerInfo {
 pub fn definition() -> (ToolDefinition, ToolHandler) {
     let definition = ToolDefinition {
         name: "submit_feedback".to_string(),
-        description: "Submit feedback about docs-mcp (what worked, what’s missing, how to improve). Writes a structured JSON record into the `Feedback/` folder.".to_string(),
+        description: "Submit feedback about docs-mcp (what worked, what’s missing, how to improve). Writes a structured JSON record into the `Feedback/` folder; list or export them with `docs-mcp-cli feedback`.".to_string(),
         input_schema: json!({
             "type": "object",
             "properties": {
@@ -100,6 +110,15 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                     "items": {"type": "string"},
                     "description": "What slowed you down (latency, irrelevant results, formatting, etc.)."
                 },
+                "failingQueries": {
+                    "type": "array",
+                    "items": {"type": "string"},
+                    "description": "Queries that returned nothing useful, verbatim."
+                },
+                "provider": {
+                    "type": "string",
+                    "description": "Provider the feedback is about (e.g. apple, rust, mdn)."
+                },
                 "client": {
                     "type": "object",
                     "description": "Optional metadata about the calling agent/client.",
@@ -126,6 +145,8 @@ pub fn definition() -> (ToolDefinition, ToolHandler) {
                 "improvements": ["Add fuzzy matching for typos", "Expose provider in output header"],
                 "missingDocs": ["UIKit UITableViewDiffableDataSource", "AppKit NSAttributedString paragraphStyle"],
                 "painPoints": ["Sometimes top docs are too long; would like a shorter 'key points' section first"],
+                "failingQueries": ["NSTextView ruler"],
+                "provider": "apple",
                 "client": {"agentName": "Codex CLI", "model": "gpt-5.2-codex", "reasoning": "xhigh"}
             })
         ]),
@@ -140,15 +161,34 @@ async fn handle_submit_feedback(context: Arc<AppContext>, value: serde_json::Val
     let args: Args = parse_args(value)?;
     validate_args(&args)?;
 
-    let saved_path = write_feedback(&context, args).await?;
-    Ok(text_response([format!(
+    let record = serde_json::to_value(build_record(&context, args).await?).context("serialize feedback")?;
+    let saved_path = write_feedback(&record).await?;
+    let mut lines = vec![format!(
         "Saved feedback to {}. Thank you — this directly guides what we improve next.",
         saved_path.display()
-    )])
-    .with_metadata(json!({
+    )];
+    let mut metadata = json!({
         "savedPath": saved_path.display().to_string(),
         "schemaVersion": 1
-    })))
+    });
+
+    if let Some(webhook) = &context.config.feedback_webhook {
+        // The local copy is already saved, so a failed forward is reported rather than raised
+        match forward_feedback(webhook, &record).await {
+            Ok(()) => {
+                lines.push("Forwarded to the configured feedback webhook.".to_string());
+                metadata["forwarded"] = json!(true);
+            }
+            Err(error) => {
+                tracing::warn!(target: "docs_mcp_core", error = %error, "failed to forward feedback");
+                lines.push(format!("Could not forward feedback to the webhook: {error:#}"));
+                metadata["forwarded"] = json!(false);
+                metadata["forwardError"] = json!(format!("{error:#}"));
+            }
+        }
+    }
+
+    Ok(text_response(lines).with_metadata(metadata))
 }
 
 fn validate_args(args: &Args) -> Result<()> {
@@ -160,11 +200,16 @@ fn validate_args(args: &Args) -> Result<()> {
     if args.feedback.trim().is_empty() {
         return Err(anyhow!("feedback must be a non-empty string"));
     }
+    if let Some(provider) = &args.provider {
+        if ProviderType::from_slug(provider).is_none() {
+            return Err(anyhow!("unknown provider '{provider}'"));
+        }
+    }
     Ok(())
 }
 
-async fn write_feedback(context: &Arc<AppContext>, args: Args) -> Result<PathBuf> {
-    let dir = resolve_feedback_dir()?;
+async fn write_feedback(record: &serde_json::Value) -> Result<PathBuf> {
+    let dir = feedback_dir();
     tokio::fs::create_dir_all(&dir)
         .await
         .with_context(|| format!("create feedback dir {}", dir.display()))?;
@@ -180,8 +225,7 @@ async fn write_feedback(context: &Arc<AppContext>, args: Args) -> Result<PathBuf
     let final_path = dir.join(file_name);
     let tmp_path = dir.join(format!(".{}.tmp", final_path.file_name().unwrap().to_string_lossy()));
 
-    let record = build_record(context, args).await?;
-    let bytes = serde_json::to_vec_pretty(&record).context("serialize feedback")?;
+    let bytes = serde_json::to_vec_pretty(record).context("serialize feedback")?;
 
     let mut file = tokio::fs::File::create(&tmp_path)
         .await
@@ -198,13 +242,6 @@ async fn write_feedback(context: &Arc<AppContext>, args: Args) -> Result<PathBuf
     Ok(final_path)
 }
 
-fn resolve_feedback_dir() -> Result<PathBuf> {
-    match std::env::var_os(FEEDBACK_DIR_ENV) {
-        Some(value) => Ok(PathBuf::from(value)),
-        None => Ok(PathBuf::from("Feedback")),
-    }
-}
-
 async fn build_record(context: &Arc<AppContext>, args: Args) -> Result<FeedbackRecord> {
     let telemetry = context.telemetry_snapshot().await;
 
@@ -246,6 +283,13 @@ async fn build_record(context: &Arc<AppContext>, args: Args) -> Result<FeedbackR
         improvements: args.improvements,
         missing_docs: args.missing_docs,
         pain_points: args.pain_points,
+        failing_queries: args.failing_queries,
+        // Stored as the slug so listings group consistently
+        provider: args
+            .provider
+            .as_deref()
+            .and_then(ProviderType::from_slug)
+            .map(|provider| provider.slug().to_string()),
         environment,
         diagnostics,
     })
@@ -254,6 +298,7 @@ async fn build_record(context: &Arc<AppContext>, args: Args) -> Result<FeedbackR
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::feedback::{list_feedback, FEEDBACK_DIR_ENV};
     use docs_mcp_client::AppleDocsClient;
     use tempfile::tempdir;
 
@@ -269,6 +314,8 @@ mod tests {
             improvements: vec!["Improve ranking".to_string()],
             missing_docs: vec![],
             pain_points: vec![],
+            failing_queries: vec!["tokio select biased".to_string()],
+            provider: Some("Rust".to_string()),
             client: Some(ClientInfo {
                 agent_name: Some("test".to_string()),
                 agent_version: None,
@@ -278,13 +325,19 @@ mod tests {
             }),
         };
 
-        let path = write_feedback(&context, args).await.expect("write");
+        let record = serde_json::to_value(build_record(&context, args).await.expect("record")).unwrap();
+        let path = write_feedback(&record).await.expect("write");
         assert!(path.exists(), "expected feedback file to exist");
 
         let bytes = tokio::fs::read(&path).await.expect("read");
         let parsed: serde_json::Value = serde_json::from_slice(&bytes).expect("json");
         assert_eq!(parsed.get("schema_version").and_then(|v| v.as_u64()), Some(1));
         assert_eq!(parsed.get("feedback").and_then(|v| v.as_str()), Some("Hello"));
+
+        let listed = list_feedback(dir.path()).await.expect("list");
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].provider.as_deref(), Some("rust"));
+        assert_eq!(listed[0].failing_queries, vec!["tokio select biased".to_string()]);
     }
 
     #[test]
@@ -295,6 +348,8 @@ mod tests {
             improvements: vec![],
             missing_docs: vec![],
             pain_points: vec![],
+            failing_queries: vec![],
+            provider: None,
             client: None,
         };
         let err = validate_args(&args).unwrap_err().to_string();
@@ -309,9 +364,27 @@ mod tests {
             improvements: vec![],
             missing_docs: vec![],
             pain_points: vec![],
+            failing_queries: vec![],
+            provider: None,
             client: None,
         };
         let err = validate_args(&args).unwrap_err().to_string();
         assert!(err.contains("non-empty"));
     }
+
+    #[test]
+    fn provider_validation_rejects_unknown() {
+        let args = Args {
+            feedback: "Hi".to_string(),
+            rating: None,
+            improvements: vec![],
+            missing_docs: vec![],
+            pain_points: vec![],
+            failing_queries: vec![],
+            provider: Some("cobol".to_string()),
+            client: None,
+        };
+        let err = validate_args(&args).unwrap_err().to_string();
+        assert!(err.contains("unknown provider"));
+    }
 }
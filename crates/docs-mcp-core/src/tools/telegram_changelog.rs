@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use multi_provider_client::telegram::changelog::{parse_version, version_key, version_matches, TelegramItemChange};
+use multi_provider_client::telegram::{TelegramRelease, TelegramSpecDiff};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::{
+    markdown,
+    state::{AppContext, ToolDefinition, ToolHandler, ToolResponse},
+    tools::{
+        changelog::{non_empty, select_releases, ReleaseVersions},
+        parse_args, text_response, wrap_handler,
+    },
+};
+
+/// Changes listed per field-level diff section before the rest are summarised
+const MAX_DIFF_ITEMS: usize = 40;
+
+const BOT_API_VERSIONS: ReleaseVersions<TelegramRelease, (u32, u32)> = ReleaseVersions {
+    version: release_version,
+    key: version_key,
+    matches: version_matches,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Args {
+    /// Release or release line, e.g. "7.10" or "7.x"
+    version: Option<String>,
+    /// Exclusive lower bound of a range, e.g. "7.0"
+    from: Option<String>,
+    /// Last Bot API version to include, e.g. "7.5" (default: latest)
+    to: Option<String>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "telegram_changelog".to_string(),
+        description: "Telegram Bot API release notes and the methods, types and fields that changed between versions. \
+                      Pass a release (\"7.10\"), a release line (\"7.x\") or a from/to range; with no arguments the \
+                      latest release is shown.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "version": {
+                    "type": "string",
+                    "description": "Bot API release or release line, e.g. \"7.10\" or \"7.x\""
+                },
+                "from": {
+                    "type": "string",
+                    "description": "Show what changed after this version, e.g. \"7.0\""
+                },
+                "to": {
+                    "type": "string",
+                    "description": "Last version to include (default: latest)"
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![
+            json!({}),
+            json!({"version": "7.x"}),
+            json!({"from": "7.0", "to": "7.5"}),
+        ]),
+        allowed_callers: None,
+    };
+
+    (
+        definition,
+        wrap_handler(|context, value| async move {
+            let args: Args = parse_args(value)?;
+            handle(context, args).await
+        }),
+    )
+}
+
+async fn handle(context: Arc<AppContext>, args: Args) -> Result<ToolResponse> {
+    let version = non_empty(args.version.as_deref());
+    let from = non_empty(args.from.as_deref());
+    let to = non_empty(args.to.as_deref());
+    for value in [version, from, to].into_iter().flatten() {
+        if parse_version(value).is_none() {
+            bail!("'{value}' is not a Bot API version; expected e.g. \"7.10\" or \"7.x\"");
+        }
+    }
+
+    let telegram = &context.providers.telegram;
+    let releases = telegram.get_changelog().await?;
+    let selected = select_releases(&releases, &BOT_API_VERSIONS, version, from, to);
+    if selected.is_empty() {
+        let latest = releases.first().map_or("unknown", |release| release.version.as_str());
+        bail!("No Bot API releases match the requested versions (latest is {latest})");
+    }
+
+    // Diff from the release before the oldest selected one to the newest selected one
+    let oldest = selected.last().map(|release| release.version.as_str()).unwrap_or_default();
+    let newest = selected.first().map(|release| release.version.as_str()).unwrap_or_default();
+    let diff_from = from.map(str::to_string).or_else(|| previous_release(&releases, oldest));
+    let tracked = telegram.tracked_versions().await.unwrap_or_default();
+    let unsaved = diff_from
+        .as_deref()
+        .map(|diff_from| unsaved_versions(&[diff_from, newest], &tracked))
+        .unwrap_or_default();
+    let diff = match &diff_from {
+        Some(diff_from) if unsaved.is_empty() => {
+            telegram.diff_versions(diff_from, Some(newest)).await.unwrap_or_else(|e| {
+                tracing::debug!(error = %e, "Telegram spec diff unavailable");
+                None
+            })
+        }
+        _ => None,
+    };
+
+    let lines = render(&selected, diff.as_ref(), &tracked, &unsaved);
+    Ok(text_response(lines).with_metadata(json!({
+        "provider": "telegram",
+        "releases": selected.iter().map(|release| &release.version).collect::<Vec<_>>(),
+        "diff": diff.as_ref().map(|diff| json!({
+            "from": diff.from,
+            "to": diff.to,
+            "addedMethods": diff.added_methods.len(),
+            "addedTypes": diff.added_types.len(),
+            "changedMethods": diff.changed_methods.len(),
+            "changedTypes": diff.changed_types.len(),
+        })),
+        "trackedVersions": tracked,
+        "unsavedVersions": unsaved,
+    })))
+}
+
+fn release_version(release: &TelegramRelease) -> &str {
+    &release.version
+}
+
+/// The `versions` without a local spec snapshot among `tracked`
+fn unsaved_versions<'a>(versions: &[&'a str], tracked: &[String]) -> Vec<&'a str> {
+    let mut unsaved: Vec<&str> = versions
+        .iter()
+        .copied()
+        .filter(|version| !tracked.iter().any(|saved| version_matches(saved, version)))
+        .collect();
+    unsaved.dedup();
+    unsaved
+}
+
+/// The release published just before `version`
+fn previous_release(releases: &[TelegramRelease], version: &str) -> Option<String> {
+    let key = version_key(version);
+    releases
+        .iter()
+        .filter(|release| version_key(&release.version) < key)
+        .max_by_key(|release| version_key(&release.version))
+        .map(|release| release.version.clone())
+}
+
+fn render(
+    releases: &[&TelegramRelease],
+    diff: Option<&TelegramSpecDiff>,
+    tracked: &[String],
+    unsaved: &[&str],
+) -> Vec<String> {
+    let mut lines = vec![markdown::header(1, "📜 Telegram Bot API Changelog"), String::new()];
+
+    for release in releases {
+        lines.push(markdown::header(2, &format!("Bot API {} — {}", release.version, release.date)));
+        for change in &release.changes {
+            lines.push(format!("• {change}"));
+        }
+        lines.push(format!("Release notes: {}", release.url));
+        lines.push(String::new());
+    }
+
+    match diff {
+        Some(diff) => {
+            lines.push(markdown::header(2, &format!("Spec Diff: {} → {}", diff.from, diff.to)));
+            if diff.is_empty() {
+                lines.push("No methods, types or fields changed between these snapshots.".to_string());
+            }
+            push_names(&mut lines, "Added methods", &diff.added_methods);
+            push_names(&mut lines, "Removed methods", &diff.removed_methods);
+            push_changes(&mut lines, "Changed methods", &diff.changed_methods);
+            push_names(&mut lines, "Added types", &diff.added_types);
+            push_names(&mut lines, "Removed types", &diff.removed_types);
+            push_changes(&mut lines, "Changed types", &diff.changed_types);
+        }
+        None if !unsaved.is_empty() => {
+            let versions = unsaved.iter().map(|version| format!("Bot API {version}")).collect::<Vec<_>>();
+            let tracked = if tracked.is_empty() { "none yet".to_string() } else { tracked.join(", ") };
+            lines.push(format!(
+                "No field-level diff: {} {} never saved locally. Snapshots are kept as new Bot API versions \
+                 are fetched; saved so far: {tracked}.",
+                versions.join(" and "),
+                if versions.len() == 1 { "was" } else { "were" },
+            ));
+        }
+        None => {
+            let tracked = if tracked.is_empty() { "none yet".to_string() } else { tracked.join(", ") };
+            lines.push(format!(
+                "A field-level diff needs spec snapshots of both versions. Snapshots are kept as new \
+                 Bot API versions are fetched; tracked so far: {tracked}."
+            ));
+        }
+    }
+
+    lines
+}
+
+fn push_names(lines: &mut Vec<String>, title: &str, names: &[String]) {
+    if names.is_empty() {
+        return;
+    }
+    lines.push(String::new());
+    lines.push(markdown::header(3, &format!("{title} ({})", names.len())));
+    lines.extend(names.iter().take(MAX_DIFF_ITEMS).map(|name| format!("• `{name}`")));
+    if names.len() > MAX_DIFF_ITEMS {
+        lines.push(format!("…and {} more", names.len() - MAX_DIFF_ITEMS));
+    }
+}
+
+fn push_changes(lines: &mut Vec<String>, title: &str, changes: &[TelegramItemChange]) {
+    if changes.is_empty() {
+        return;
+    }
+    lines.push(String::new());
+    lines.push(markdown::header(3, &format!("{title} ({})", changes.len())));
+    for change in changes.iter().take(MAX_DIFF_ITEMS) {
+        let mut parts = Vec::new();
+        if !change.added_fields.is_empty() {
+            parts.push(format!("+ {}", change.added_fields.join(", ")));
+        }
+        if !change.removed_fields.is_empty() {
+            parts.push(format!("− {}", change.removed_fields.join(", ")));
+        }
+        lines.push(format!("• `{}`: {}", change.name, parts.join("; ")));
+    }
+    if changes.len() > MAX_DIFF_ITEMS {
+        lines.push(format!("…and {} more", changes.len() - MAX_DIFF_ITEMS));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(version: &str) -> TelegramRelease {
+        TelegramRelease {
+            version: version.to_string(),
+            date: String::new(),
+            url: String::new(),
+            changes: Vec::new(),
+        }
+    }
+
+    fn versions(selected: &[&TelegramRelease]) -> Vec<String> {
+        selected.iter().map(|release| release.version.clone()).collect()
+    }
+
+    #[test]
+    fn selects_releases() {
+        let releases: Vec<_> = ["8.0", "7.10", "7.9", "7.1", "7.0", "6.9"].into_iter().map(release).collect();
+
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, None, None, None)), ["8.0"]);
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, Some("7.x"), None, None)), ["7.10", "7.9", "7.1", "7.0"]);
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, Some("7.1"), None, None)), ["7.1"]);
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, None, Some("7.1"), Some("7.10"))), ["7.10", "7.9"]);
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, None, Some("7.9"), None)), ["8.0", "7.10"]);
+        assert_eq!(versions(&select_releases(&releases, &BOT_API_VERSIONS, None, None, Some("6.x"))), ["6.9"]);
+
+        assert_eq!(previous_release(&releases, "7.0").as_deref(), Some("6.9"));
+        assert_eq!(previous_release(&releases, "6.9"), None);
+    }
+
+    #[test]
+    fn renders_diff_and_missing_snapshots() {
+        let mut latest = release("7.10");
+        latest.changes.push("Added the class PaidMediaPurchased.".to_string());
+        let diff = TelegramSpecDiff {
+            from: "Bot API 7.9".to_string(),
+            to: "Bot API 7.10".to_string(),
+            added_types: vec!["PaidMediaPurchased".to_string()],
+            ..TelegramSpecDiff::default()
+        };
+
+        let text = render(&[&latest], Some(&diff), &[], &[]).join("\n");
+        assert!(text.contains("## Bot API 7.10"));
+        assert!(text.contains("• Added the class PaidMediaPurchased."));
+        assert!(text.contains("## Spec Diff: Bot API 7.9 → Bot API 7.10"));
+        assert!(text.contains("### Added types (1)"));
+
+        let tracked = ["Bot API 7.10".to_string()];
+        let text = render(&[&latest], None, &tracked, &[]).join("\n");
+        assert!(text.contains("tracked so far: Bot API 7.10"));
+
+        let unsaved = unsaved_versions(&["7.9", "7.10"], &tracked);
+        assert_eq!(unsaved, ["7.9"]);
+        let text = render(&[&latest], None, &tracked, &unsaved).join("\n");
+        assert!(text.contains("No field-level diff: Bot API 7.9 was never saved locally"), "{text}");
+        assert!(!text.contains("Spec Diff"));
+    }
+}
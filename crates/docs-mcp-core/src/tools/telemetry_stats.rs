@@ -0,0 +1,239 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::state::{AppContext, TelemetryEntry, ToolDefinition, ToolHandler, ToolResponse};
+use crate::tools::{parse_args, text_response, wrap_handler};
+
+const DEFAULT_TOP_QUERIES: usize = 10;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Args {
+    /// `session` (default) or `all` to include the on-disk telemetry log.
+    scope: Option<String>,
+    top_queries: Option<usize>,
+}
+
+/// Latency and error figures for one tool.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolSummary {
+    tool: String,
+    calls: usize,
+    errors: usize,
+    error_rate: f64,
+    p50_ms: u64,
+    p95_ms: u64,
+    max_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryCount {
+    query: String,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TelemetryReport {
+    calls: usize,
+    errors: usize,
+    tools: Vec<ToolSummary>,
+    top_queries: Vec<QueryCount>,
+}
+
+pub fn definition() -> (ToolDefinition, ToolHandler) {
+    let definition = ToolDefinition {
+        name: "telemetry_stats".to_string(),
+        description: "Maintenance: summarize recorded tool calls (per-tool call counts, p50/p95 latency, error rate, most frequent queries) to diagnose slow or failing requests.".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {
+                "scope": {
+                    "type": "string",
+                    "enum": ["session", "all"],
+                    "description": "`session` (default) covers this server process; `all` also reads the on-disk telemetry log from earlier sessions"
+                },
+                "topQueries": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "How many of the most frequent queries to list (default 10)"
+                }
+            },
+            "additionalProperties": false
+        }),
+        input_examples: Some(vec![json!({}), json!({"scope": "all", "topQueries": 5})]),
+        allowed_callers: None,
+    };
+
+    (definition, wrap_handler(handle))
+}
+
+async fn handle(context: Arc<AppContext>, value: serde_json::Value) -> Result<ToolResponse> {
+    let args: Args = parse_args(value)?;
+    let scope = args.scope.as_deref().unwrap_or("session");
+    let entries = match scope {
+        "session" => context.telemetry_snapshot().await,
+        "all" => match &context.telemetry_log {
+            Some(log) => log.read_all().await?,
+            None => context.telemetry_snapshot().await,
+        },
+        other => bail!("unknown scope '{other}'; expected `session` or `all`"),
+    };
+
+    let report = summarize(&entries, args.top_queries.unwrap_or(DEFAULT_TOP_QUERIES));
+    let mut lines = vec![
+        "## Telemetry".to_string(),
+        String::new(),
+        format!("{} call(s), {} error(s) ({scope}).", report.calls, report.errors),
+    ];
+    if report.tools.is_empty() {
+        lines.push("No tool calls recorded yet.".to_string());
+    } else {
+        lines.push(String::new());
+        lines.push("| Tool | Calls | Error rate | p50 | p95 | Max |".to_string());
+        lines.push("|------|-------|------------|-----|-----|-----|".to_string());
+        for tool in &report.tools {
+            lines.push(format!(
+                "| {} | {} | {:.1}% | {} ms | {} ms | {} ms |",
+                tool.tool,
+                tool.calls,
+                tool.error_rate * 100.0,
+                tool.p50_ms,
+                tool.p95_ms,
+                tool.max_ms
+            ));
+        }
+    }
+    if !report.top_queries.is_empty() {
+        lines.push(String::new());
+        lines.push("## Top Queries".to_string());
+        lines.push(String::new());
+        for entry in &report.top_queries {
+            lines.push(format!("- {} ({}x)", entry.query, entry.count));
+        }
+    }
+
+    let mut metadata = serde_json::to_value(&report)?;
+    metadata["scope"] = json!(scope);
+    Ok(text_response(lines).with_metadata(metadata))
+}
+
+fn summarize(entries: &[TelemetryEntry], top_queries: usize) -> TelemetryReport {
+    let mut by_tool: BTreeMap<&str, Vec<&TelemetryEntry>> = BTreeMap::new();
+    let mut queries: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        by_tool.entry(entry.tool.as_str()).or_default().push(entry);
+        let query = entry
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("query"))
+            .and_then(|query| query.as_str())
+            .map(|query| query.trim().to_lowercase())
+            .filter(|query| !query.is_empty());
+        if let Some(query) = query {
+            *queries.entry(query).or_default() += 1;
+        }
+    }
+
+    let mut tools: Vec<ToolSummary> = by_tool
+        .into_iter()
+        .map(|(tool, calls)| {
+            let mut latencies: Vec<u64> = calls.iter().map(|entry| entry.latency_ms).collect();
+            latencies.sort_unstable();
+            let errors = calls.iter().filter(|entry| !entry.success).count();
+            ToolSummary {
+                tool: tool.to_string(),
+                calls: calls.len(),
+                errors,
+                error_rate: errors as f64 / calls.len() as f64,
+                p50_ms: percentile(&latencies, 50),
+                p95_ms: percentile(&latencies, 95),
+                max_ms: latencies.last().copied().unwrap_or_default(),
+            }
+        })
+        .collect();
+    // Busiest tools first; ties keep alphabetical order
+    tools.sort_by_key(|tool| std::cmp::Reverse(tool.calls));
+
+    let mut top: Vec<QueryCount> = queries
+        .into_iter()
+        .map(|(query, count)| QueryCount { query, count })
+        .collect();
+    top.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.query.cmp(&b.query)));
+    top.truncate(top_queries);
+
+    TelemetryReport {
+        calls: entries.len(),
+        errors: entries.iter().filter(|entry| !entry.success).count(),
+        tools,
+        top_queries: top,
+    }
+}
+
+/// Nearest-rank percentile of already sorted values.
+pub(crate) fn percentile(sorted: &[u64], percent: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (percent * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::OffsetDateTime;
+
+    fn entry(tool: &str, latency_ms: u64, success: bool, query: Option<&str>) -> TelemetryEntry {
+        TelemetryEntry {
+            tool: tool.to_string(),
+            timestamp: OffsetDateTime::now_utc(),
+            latency_ms,
+            success,
+            metadata: query.map(|query| json!({"query": query})),
+            error: (!success).then(|| "boom".to_string()),
+        }
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let latencies: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&latencies, 50), 10);
+        assert_eq!(percentile(&latencies, 95), 19);
+        assert_eq!(percentile(&[7], 95), 7);
+        assert_eq!(percentile(&[], 50), 0);
+    }
+
+    #[test]
+    fn summarizes_per_tool_and_queries() {
+        let entries = vec![
+            entry("query", 100, true, Some("SwiftUI List")),
+            entry("query", 300, false, Some("swiftui list")),
+            entry("query", 200, true, Some("tokio spawn")),
+            entry("cache_stats", 5, true, None),
+        ];
+        let report = summarize(&entries, 1);
+
+        assert_eq!(report.calls, 4);
+        assert_eq!(report.errors, 1);
+        assert_eq!(report.tools[0].tool, "query");
+        assert_eq!(report.tools[0].calls, 3);
+        assert_eq!(report.tools[0].p50_ms, 200);
+        assert_eq!(report.tools[0].max_ms, 300);
+        assert!((report.tools[0].error_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(report.tools[1].tool, "cache_stats");
+        assert_eq!(
+            report.top_queries,
+            vec![QueryCount {
+                query: "swiftui list".to_string(),
+                count: 2
+            }]
+        );
+    }
+}
@@ -0,0 +1,621 @@
+//! Streamable HTTP transport: JSON-RPC over `POST /mcp`, one session per `Mcp-Session-Id`.
+//!
+//! A deliberately small HTTP/1.1 server — requests carry a `Content-Length` body and every
+//! response is a single JSON document, so no streaming or chunked encoding is needed.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use multi_provider_client::credentials::ApiToken;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::{Mutex, Semaphore};
+use tracing::{debug, info, warn};
+
+use crate::services::health::{check_health, HealthReport, HealthStatus};
+use crate::state::AppContext;
+use crate::transport::{handle_message, SESSION_HEADER};
+
+const MCP_PATH: &str = "/mcp";
+const HEALTH_PATH: &str = "/healthz";
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+const MAX_HEADERS: usize = 100;
+/// Longest request line or single header line accepted.
+const MAX_LINE_BYTES: usize = 8 * 1024;
+/// Total size of the request line and headers accepted.
+const MAX_HEAD_BYTES: usize = 64 * 1024;
+/// Time a client gets to send a complete request, and to start the next one on a kept-alive
+/// connection.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// `/healthz` needs no token, so its report is reused this long instead of probing every
+/// provider on each hit.
+const HEALTH_CACHE_TTL: Duration = Duration::from_secs(30);
+/// Connections served at once.
+const MAX_CONNECTIONS: usize = 256;
+/// First and longest pause after `accept` fails before trying again.
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(50);
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(2);
+
+static HEALTH_REPORT: Mutex<Option<(Instant, HealthReport)>> = Mutex::const_new(None);
+
+/// Where and for whom the HTTP transport listens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpSettings {
+    pub bind: SocketAddr,
+    /// Browser origins allowed to call the server; `*` allows any. Requests without an
+    /// `Origin` header (non-browser clients) are always accepted.
+    pub allowed_origins: Vec<String>,
+    /// Required as `Authorization: Bearer <token>` on `/mcp` when set.
+    pub auth_token: Option<ApiToken>,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            bind: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            allowed_origins: Vec::new(),
+            auth_token: None,
+        }
+    }
+}
+
+/// Serve MCP over HTTP on `settings.bind` until the process exits.
+pub async fn serve_http(context: Arc<AppContext>, settings: HttpSettings) -> Result<()> {
+    let listener = TcpListener::bind(settings.bind)
+        .await
+        .with_context(|| format!("bind {}", settings.bind))?;
+    info!(
+        target: "docs_mcp_transport",
+        address = %listener.local_addr()?,
+        "Serving MCP over HTTP at {MCP_PATH}"
+    );
+    serve_listener(context, listener, Arc::new(settings), MAX_CONNECTIONS).await
+}
+
+async fn serve_listener(
+    context: Arc<AppContext>,
+    listener: TcpListener,
+    settings: Arc<HttpSettings>,
+    max_connections: usize,
+) -> Result<()> {
+    let connections = Arc::new(Semaphore::new(max_connections));
+    let mut backoff = ACCEPT_BACKOFF_MIN;
+    loop {
+        // Clients beyond the limit wait in the listen backlog until a connection closes
+        let permit = connections.clone().acquire_owned().await?;
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(error) => {
+                // Typically out of file descriptors; retry once some connections have closed
+                warn!(target: "docs_mcp_transport", error = %error, "Failed to accept HTTP connection");
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(ACCEPT_BACKOFF_MAX);
+                continue;
+            }
+        };
+        backoff = ACCEPT_BACKOFF_MIN;
+        let context = context.clone();
+        let settings = settings.clone();
+        tokio::spawn(async move {
+            if let Err(error) = serve_connection(&context, &settings, stream).await {
+                debug!(target: "docs_mcp_transport", peer = %peer, error = %error, "HTTP connection closed");
+            }
+            drop(permit);
+        });
+    }
+}
+
+async fn serve_connection(
+    context: &Arc<AppContext>,
+    settings: &HttpSettings,
+    stream: tokio::net::TcpStream,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    loop {
+        let read = tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut reader))
+            .await
+            .unwrap_or(Err(bad_request(408, "Request timed out")));
+        let (response, keep_alive) = match read {
+            Ok(Some(request)) => {
+                let keep_alive = request.keep_alive();
+                (route(context, settings, &request).await, keep_alive)
+            }
+            Ok(None) => return Ok(()),
+            Err(error) => (HttpResponse::text(error.status, error.message), false),
+        };
+        writer.write_all(&response.to_bytes(keep_alive)).await?;
+        writer.flush().await?;
+        if !keep_alive {
+            return Ok(());
+        }
+    }
+}
+
+#[derive(Debug)]
+struct HttpRequest {
+    method: String,
+    path: String,
+    http_1_0: bool,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn keep_alive(&self) -> bool {
+        match self.header("connection") {
+            Some(value) if value.eq_ignore_ascii_case("close") => false,
+            Some(value) if value.eq_ignore_ascii_case("keep-alive") => true,
+            _ => !self.http_1_0,
+        }
+    }
+}
+
+/// A request that could not be read, answered with `status` before closing the connection.
+#[derive(Debug)]
+struct BadRequest {
+    status: u16,
+    message: &'static str,
+}
+
+impl From<std::io::Error> for BadRequest {
+    fn from(_: std::io::Error) -> Self {
+        Self {
+            status: 400,
+            message: "Malformed request",
+        }
+    }
+}
+
+const fn bad_request(status: u16, message: &'static str) -> BadRequest {
+    BadRequest { status, message }
+}
+
+/// Read one request; `None` when the client closed the connection between requests.
+async fn read_request<R>(reader: &mut BufReader<R>) -> Result<Option<HttpRequest>, BadRequest>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut line = String::new();
+    let mut head_bytes = read_line_limited(reader, &mut line).await?;
+    if head_bytes == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path), Some(version)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(bad_request(400, "Malformed request line"));
+    };
+    let mut request = HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        http_1_0: version == "HTTP/1.0",
+        headers: Vec::new(),
+        body: Vec::new(),
+    };
+
+    loop {
+        line.clear();
+        let bytes = read_line_limited(reader, &mut line).await?;
+        if bytes == 0 {
+            return Err(bad_request(400, "Unexpected end of headers"));
+        }
+        head_bytes += bytes;
+        if head_bytes > MAX_HEAD_BYTES {
+            return Err(bad_request(431, "Request headers too large"));
+        }
+        let header = line.trim_end_matches(['\r', '\n']);
+        if header.is_empty() {
+            break;
+        }
+        if request.headers.len() >= MAX_HEADERS {
+            return Err(bad_request(431, "Too many headers"));
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or(bad_request(400, "Malformed header"))?;
+        request.headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    if request.header("transfer-encoding").is_some() {
+        return Err(bad_request(411, "Send the body with Content-Length"));
+    }
+    let length = match request.header("content-length") {
+        Some(value) => value
+            .parse::<usize>()
+            .map_err(|_| bad_request(400, "Invalid Content-Length"))?,
+        None => 0,
+    };
+    if length > MAX_BODY_BYTES {
+        return Err(bad_request(413, "Request body too large"));
+    }
+    request.body = vec![0; length];
+    reader.read_exact(&mut request.body).await?;
+    Ok(Some(request))
+}
+
+/// Read a line of at most [`MAX_LINE_BYTES`] including its line ending; 0 at end of stream.
+async fn read_line_limited<R>(reader: &mut BufReader<R>, line: &mut String) -> Result<usize, BadRequest>
+where
+    R: AsyncRead + Unpin,
+{
+    let bytes = (&mut *reader).take(MAX_LINE_BYTES as u64 + 1).read_line(line).await?;
+    if bytes > MAX_LINE_BYTES {
+        return Err(bad_request(431, "Request line or header too long"));
+    }
+    Ok(bytes)
+}
+
+/// The latest health report, probing the providers again once it is [`HEALTH_CACHE_TTL`] old.
+async fn cached_health(context: &AppContext) -> HealthReport {
+    let mut cached = HEALTH_REPORT.lock().await;
+    match cached.as_ref() {
+        Some((checked, report)) if checked.elapsed() < HEALTH_CACHE_TTL => report.clone(),
+        _ => {
+            let report = check_health(context, &[]).await;
+            *cached = Some((Instant::now(), report.clone()));
+            report
+        }
+    }
+}
+
+struct HttpResponse {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    fn empty(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    fn json(status: u16, body: String) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type", "application/json".to_string())],
+            body: body.into_bytes(),
+        }
+    }
+
+    fn text(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type", "text/plain; charset=utf-8".to_string())],
+            body: message.as_bytes().to_vec(),
+        }
+    }
+
+    fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    fn to_bytes(&self, keep_alive: bool) -> Vec<u8> {
+        let mut head = format!("HTTP/1.1 {} {}\r\n", self.status, reason_phrase(self.status));
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{name}: {value}\r\n"));
+        }
+        head.push_str(&format!("Content-Length: {}\r\n", self.body.len()));
+        if !keep_alive {
+            head.push_str("Connection: close\r\n");
+        }
+        head.push_str("\r\n");
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+async fn route(context: &Arc<AppContext>, settings: &HttpSettings, request: &HttpRequest) -> HttpResponse {
+    let path = request.path.split('?').next().unwrap_or_default();
+    match path {
+        HEALTH_PATH if request.method == "GET" => {
+            let report = cached_health(context).await;
+            let status = if report.status == HealthStatus::Unhealthy { 503 } else { 200 };
+            match serde_json::to_string(&report) {
+                Ok(body) => HttpResponse::json(status, body),
+                Err(_) => HttpResponse::empty(status),
+            }
+        }
+        HEALTH_PATH => HttpResponse::empty(405).with_header("Allow", "GET"),
+        MCP_PATH => {
+            let origin = request.header("origin");
+            if let Some(origin) = origin {
+                if !origin_allowed(settings, origin) {
+                    return HttpResponse::text(403, "Origin not allowed");
+                }
+            }
+            let response = route_mcp(context, settings, request).await;
+            match origin {
+                Some(origin) => response
+                    .with_header("Access-Control-Allow-Origin", origin)
+                    .with_header("Access-Control-Expose-Headers", SESSION_HEADER)
+                    .with_header("Vary", "Origin"),
+                None => response,
+            }
+        }
+        _ => HttpResponse::text(404, "Not found"),
+    }
+}
+
+async fn route_mcp(context: &Arc<AppContext>, settings: &HttpSettings, request: &HttpRequest) -> HttpResponse {
+    if request.method == "OPTIONS" {
+        return HttpResponse::empty(204)
+            .with_header("Access-Control-Allow-Methods", "POST, DELETE, OPTIONS")
+            .with_header(
+                "Access-Control-Allow-Headers",
+                format!("Content-Type, Authorization, {SESSION_HEADER}"),
+            )
+            .with_header("Access-Control-Max-Age", "600");
+    }
+    if !authorized(settings, request.header("authorization")) {
+        return HttpResponse::text(401, "Missing or invalid bearer token")
+            .with_header("WWW-Authenticate", "Bearer");
+    }
+
+    let session = request.header(SESSION_HEADER).map(str::to_string);
+    match request.method.as_str() {
+        "POST" => {
+            let Ok(payload) = std::str::from_utf8(&request.body) else {
+                return HttpResponse::text(400, "Request body must be UTF-8 JSON");
+            };
+            // Sessions start at `initialize`; every other request must name one the server issued
+            let session = match session {
+                Some(session) => session,
+                None if is_initialize(payload) => match context.start_session() {
+                    Some(session) => session,
+                    None => {
+                        return HttpResponse::text(503, "Too many active sessions; retry later")
+                            .with_header("Retry-After", "60")
+                    }
+                },
+                None => return HttpResponse::text(400, "Missing session header"),
+            };
+            let Some(session_context) = context.for_session(&session) else {
+                return HttpResponse::text(404, "Unknown session");
+            };
+            let response = match handle_message(&Arc::new(session_context), payload).await {
+                Some(body) => HttpResponse::json(200, body),
+                None => HttpResponse::empty(202),
+            };
+            response.with_header(SESSION_HEADER, session)
+        }
+        "DELETE" => match session {
+            Some(session) if context.end_session(&session) => HttpResponse::empty(204),
+            Some(_) => HttpResponse::text(404, "Unknown session"),
+            None => HttpResponse::text(400, "Missing session header"),
+        },
+        _ => HttpResponse::empty(405).with_header("Allow", "POST, DELETE, OPTIONS"),
+    }
+}
+
+fn origin_allowed(settings: &HttpSettings, origin: &str) -> bool {
+    settings
+        .allowed_origins
+        .iter()
+        .any(|allowed| allowed == "*" || allowed.trim_end_matches('/').eq_ignore_ascii_case(origin))
+}
+
+fn authorized(settings: &HttpSettings, header: Option<&str>) -> bool {
+    let Some(token) = &settings.auth_token else {
+        return true;
+    };
+    let Some(presented) = header.and_then(|value| value.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    // Compare without short-circuiting so timing doesn't reveal the matching prefix
+    let (expected, presented) = (token.expose().as_bytes(), presented.trim().as_bytes());
+    expected.len() == presented.len()
+        && expected
+            .iter()
+            .zip(presented)
+            .fold(0u8, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+/// Whether `payload` is an `initialize` request or a batch containing one.
+fn is_initialize(payload: &str) -> bool {
+    let is_initialize = |value: &serde_json::Value| {
+        value.get("method").and_then(|method| method.as_str()) == Some("initialize")
+    };
+    match serde_json::from_str::<serde_json::Value>(payload) {
+        Ok(serde_json::Value::Array(entries)) => entries.iter().any(is_initialize),
+        Ok(value) => is_initialize(&value),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::AppleDocsClient;
+
+    async fn parse(raw: &str) -> Result<Option<HttpRequest>, BadRequest> {
+        read_request(&mut BufReader::new(raw.as_bytes())).await
+    }
+
+    fn post(body: &str, headers: &[(&str, &str)]) -> HttpRequest {
+        HttpRequest {
+            method: "POST".to_string(),
+            path: MCP_PATH.to_string(),
+            http_1_0: false,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    fn header<'a>(response: &'a HttpResponse, name: &str) -> Option<&'a str> {
+        response
+            .headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[tokio::test]
+    async fn reads_requests_with_bodies() {
+        let raw = "POST /mcp HTTP/1.1\r\nHost: x\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}";
+        let request = parse(raw).await.unwrap().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.body, b"{}");
+        assert!(!request.keep_alive());
+
+        assert!(parse("").await.unwrap().is_none());
+        let chunked = "POST /mcp HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n";
+        assert_eq!(parse(chunked).await.unwrap_err().status, 411);
+        let huge = format!("POST /mcp HTTP/1.1\r\nContent-Length: {}\r\n\r\n", MAX_BODY_BYTES + 1);
+        assert_eq!(parse(&huge).await.unwrap_err().status, 413);
+
+        let long_line = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_BYTES));
+        assert_eq!(parse(&long_line).await.unwrap_err().status, 431);
+        let header = format!("X-Filler: {}\r\n", "b".repeat(1000));
+        let many_bytes = format!("GET / HTTP/1.1\r\n{}\r\n", header.repeat(MAX_HEAD_BYTES / header.len() + 1));
+        assert_eq!(parse(&many_bytes).await.unwrap_err().status, 431);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_clients_time_out() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve_listener(context, listener, Arc::new(HttpSettings::default()), MAX_CONNECTIONS));
+
+        let mut stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        stream.write_all(b"POST /mcp HTTP/1.1\r\nContent-Length: 10\r\n").await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 408 "), "{response}");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limits_concurrent_connections() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve_listener(context, listener, Arc::new(HttpSettings::default()), 1));
+
+        let idle = tokio::net::TcpStream::connect(address).await.unwrap();
+        let mut waiting = tokio::net::TcpStream::connect(address).await.unwrap();
+        waiting.write_all(b"GET /missing HTTP/1.1\r\nConnection: close\r\n\r\n").await.unwrap();
+        let mut response = String::new();
+        let read = tokio::time::timeout(Duration::from_secs(1), waiting.read_to_string(&mut response));
+        assert!(read.await.is_err(), "second connection served past the limit: {response}");
+
+        drop(idle);
+        waiting.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 404 "), "{response}");
+    }
+
+    #[tokio::test]
+    async fn initialize_starts_a_session() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let settings = HttpSettings::default();
+        let initialize = r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#;
+
+        let response = route(&context, &settings, &post(initialize, &[])).await;
+        assert_eq!(response.status, 200);
+        let session = header(&response, SESSION_HEADER).expect("session id").to_string();
+        assert_eq!(session.len(), 32);
+
+        let notification = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        let response =
+            route(&context, &settings, &post(notification, &[(SESSION_HEADER, &session)])).await;
+        assert_eq!(response.status, 202);
+
+        let mut delete = post("", &[(SESSION_HEADER, &session)]);
+        delete.method = "DELETE".to_string();
+        assert_eq!(route(&context, &settings, &delete).await.status, 204);
+        assert_eq!(route(&context, &settings, &delete).await.status, 404);
+
+        let batch = r#"[{"jsonrpc":"2.0","id":1,"method":"initialize"},{"jsonrpc":"2.0","id":2,"method":"tools/list"}]"#;
+        let response = route(&context, &settings, &post(batch, &[])).await;
+        assert_eq!(response.status, 200);
+        assert!(header(&response, SESSION_HEADER).is_some());
+    }
+
+    #[tokio::test]
+    async fn rejects_sessions_the_server_did_not_issue() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let settings = HttpSettings::default();
+        let list = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+
+        assert_eq!(route(&context, &settings, &post(list, &[])).await.status, 400);
+        let made_up = [(SESSION_HEADER, "00000000000000000000000000000000")];
+        assert_eq!(route(&context, &settings, &post(list, &made_up)).await.status, 404);
+        let initialize = r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#;
+        assert_eq!(route(&context, &settings, &post(initialize, &made_up)).await.status, 404);
+
+        let session = context.start_session().unwrap();
+        let response = route(&context, &settings, &post(list, &[(SESSION_HEADER, &session)])).await;
+        assert_eq!(response.status, 200);
+        assert_eq!(header(&response, SESSION_HEADER), Some(session.as_str()));
+    }
+
+    #[tokio::test]
+    async fn enforces_origin_and_token() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let settings = HttpSettings {
+            allowed_origins: vec!["https://app.example.com".to_string()],
+            auth_token: ApiToken::new("secret"),
+            ..Default::default()
+        };
+        let list = r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#;
+
+        let response = route(&context, &settings, &post(list, &[])).await;
+        assert_eq!(response.status, 401);
+        let response =
+            route(&context, &settings, &post(list, &[("Authorization", "Bearer wrong")])).await;
+        assert_eq!(response.status, 401);
+
+        let evil = [("Authorization", "Bearer secret"), ("Origin", "https://evil.example")];
+        assert_eq!(route(&context, &settings, &post(list, &evil)).await.status, 403);
+
+        let session = context.start_session().unwrap();
+        let good = [
+            ("Authorization", "Bearer secret"),
+            ("Origin", "https://app.example.com"),
+            (SESSION_HEADER, session.as_str()),
+        ];
+        let response = route(&context, &settings, &post(list, &good)).await;
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            header(&response, "Access-Control-Allow-Origin"),
+            Some("https://app.example.com")
+        );
+    }
+}
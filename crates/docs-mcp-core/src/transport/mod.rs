@@ -1,15 +1,20 @@
 use std::{sync::Arc, time::Instant};
 
 use anyhow::Result;
+use futures::future::join_all;
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use sha2::{Digest, Sha256};
 use tokio::io::{self, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 use tracing::{debug, info, warn};
 
-use crate::state::{AppContext, TelemetryEntry};
+use crate::state::{AppContext, RequestLogEntry, TelemetryEntry};
 use time::OffsetDateTime;
 
-const SERVER_INSTRUCTIONS: &str = r#"You are connected to a multi-provider documentation server. Use the `query` tool to retrieve official documentation for Apple platforms, Rust, Telegram Bot API, TON blockchain, Cocoon, MDN Web Docs, Web Frameworks (React, Next.js, Node.js), MLX (Apple Silicon ML), Hugging Face (Transformers), QuickNode (Solana), Claude Agent SDK, and Vertcoin (cryptocurrency).
+pub mod http;
+
+const SERVER_INSTRUCTIONS: &str = r#"You are connected to a multi-provider documentation server. Use the `query` tool to retrieve official documentation for Apple platforms, Rust, Telegram Bot API, TON blockchain, Cocoon, MDN Web Docs, Web Frameworks (React, Next.js, Node.js), MLX (Apple Silicon ML), Hugging Face (Transformers), QuickNode (Solana, Ethereum, Base, Polygon), Solana docs and cookbook, Claude Agent SDK, and bitcoind node RPC for Bitcoin, Litecoin and Vertcoin.
 
 ## How to Use
 
@@ -37,6 +42,7 @@ Codex CLI automation: use `codex exec` to call `submit_feedback` and set `client
 - "Hugging Face AutoModel" → Transformers library documentation
 - "Vertcoin getblockchaininfo" → Vertcoin RPC method documentation
 - "Verthash mining setup" → Vertcoin mining specifications
+- "bitcoin-cli getdeploymentinfo" / "Litecoin MWEB address" → Bitcoin or Litecoin RPC documentation
 
 ## What You Get
 
@@ -66,12 +72,25 @@ For top results, the tool returns:
 - **Web Frameworks**: React, Next.js, Node.js documentation with examples
 - **MLX**: Apple Silicon ML framework (Swift and Python)
 - **Hugging Face**: Transformers and swift-transformers for LLM development
-- **QuickNode**: Solana blockchain RPC documentation
+- **QuickNode**: Solana, Ethereum, Base and Polygon RPC documentation (eth_*, debug_*, trace_* namespaces)
+- **Solana**: Core concepts (accounts, rent, PDAs, CPI, tokens) and Solana Cookbook recipes
 - **Claude Agent SDK**: TypeScript and Python SDKs for AI agents
-- **Vertcoin**: GPU-mineable cryptocurrency with Verthash algorithm (80+ RPC methods)"#;
+- **Bitcoin / Litecoin / Vertcoin**: 80+ shared bitcoind RPC methods with per-coin overlays (Verthash, MWEB, recent Bitcoin Core calls)"#;
 
 const DISABLE_FEEDBACK_PROMPT_ENV: &str = "DOCSMCP_DISABLE_FEEDBACK_PROMPT";
 
+/// Header carrying the MCP session id on streamable HTTP requests.
+pub const SESSION_HEADER: &str = "Mcp-Session-Id";
+
+/// MCP protocol versions the server speaks, newest first; the last is the fallback.
+const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2024-11-05"];
+
+/// First protocol version with `resource_link` content blocks.
+const RESOURCE_LINK_PROTOCOL: &str = "2025-06-18";
+
+/// JSON-RPC error code for an unknown resource URI.
+const RESOURCE_NOT_FOUND: i32 = -32002;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TransportFraming {
     JsonLines,
@@ -97,12 +116,13 @@ pub async fn serve_stdio(context: Arc<AppContext>) -> Result<()> {
         buffer.clear();
         buffer.push_str(&payload);
         debug!(target: "docs_mcp_transport", request = buffer.trim());
-        let maybe_response = match serde_json::from_str::<RpcRequest>(&buffer) {
-            Ok(request) => {
+        let maybe_response = match parse_message(&buffer) {
+            Ok(message) => {
                 if !feedback_prompt_sent
                     && !feedback_prompt_disabled()
-                    && request.id.is_none()
-                    && request.method == "notifications/initialized"
+                    && message
+                        .requests()
+                        .any(|request| request.id.is_none() && request.method == "notifications/initialized")
                 {
                     feedback_prompt_sent = true;
                     if let Err(error) =
@@ -115,16 +135,17 @@ pub async fn serve_stdio(context: Arc<AppContext>) -> Result<()> {
                         );
                     }
                 }
-                handle_request(context.clone(), request).await
-            }
-            Err(error) => {
-                warn!(target: "docs_mcp_transport", error = %error, "Failed to parse request");
-                Some(RpcResponse::error(None, -32700, "Parse error"))
+                let has_tool_call = message.requests().any(RpcRequest::is_tool_call);
+                let response = dispatch(&context, message).await;
+                if has_tool_call {
+                    context.persist_session().await;
+                }
+                response
             }
+            Err(response) => serialize_response(&response),
         };
 
-        if let Some(response) = maybe_response {
-            let payload = serde_json::to_string(&response)?;
+        if let Some(payload) = maybe_response {
             write_response(
                 &mut writer,
                 framing.unwrap_or(TransportFraming::JsonLines),
@@ -137,6 +158,180 @@ pub async fn serve_stdio(context: Arc<AppContext>) -> Result<()> {
     Ok(())
 }
 
+/// Answer one JSON-RPC message for transports that multiplex clients.
+///
+/// Requests run against `context`'s state, normally one session's (see
+/// [`AppContext::for_session`]). Returns the serialized response, or `None` for notifications
+/// and batches of only notifications.
+pub async fn handle_message(context: &Arc<AppContext>, payload: &str) -> Option<String> {
+    match parse_message(payload) {
+        Ok(message) => dispatch(context, message).await,
+        Err(response) => serialize_response(&response),
+    }
+}
+
+/// A JSON-RPC message as received: one request, or a batch array of them.
+enum RpcMessage {
+    Single(RpcRequest),
+    /// Batch entries that are not valid requests are answered with `Invalid Request` errors.
+    Batch(Vec<Result<RpcRequest, serde_json::Error>>),
+}
+
+impl RpcMessage {
+    fn requests(&self) -> impl Iterator<Item = &RpcRequest> {
+        let (single, batch) = match self {
+            RpcMessage::Single(request) => (Some(request), &[][..]),
+            RpcMessage::Batch(entries) => (None, entries.as_slice()),
+        };
+        single.into_iter().chain(batch.iter().filter_map(|entry| entry.as_ref().ok()))
+    }
+}
+
+/// Parse a message, or the error response to send when it is not valid JSON-RPC.
+fn parse_message(payload: &str) -> Result<RpcMessage, RpcResponse> {
+    let parse_error = |error: serde_json::Error| {
+        warn!(target: "docs_mcp_transport", error = %error, "Failed to parse request");
+        RpcResponse::error(None, -32700, "Parse error")
+    };
+    if !payload.trim_start().starts_with('[') {
+        return serde_json::from_str(payload).map(RpcMessage::Single).map_err(parse_error);
+    }
+
+    let entries = serde_json::from_str::<Vec<serde_json::Value>>(payload).map_err(parse_error)?;
+    if entries.is_empty() {
+        return Err(RpcResponse::error(None, -32600, "Invalid Request"));
+    }
+    Ok(RpcMessage::Batch(entries.into_iter().map(serde_json::from_value).collect()))
+}
+
+/// Answer a message; the responses of a batch come back as one array, in request order.
+///
+/// Within a batch `initialize` runs first. Tool calls select the session's provider and
+/// technology and read them back later, so they run one after another in request order; the
+/// remaining requests only read session state and run concurrently with them.
+async fn dispatch(context: &Arc<AppContext>, message: RpcMessage) -> Option<String> {
+    let entries = match message {
+        RpcMessage::Single(request) => return answer(context.clone(), request).await,
+        RpcMessage::Batch(entries) => entries,
+    };
+
+    let mut responses: Vec<Option<String>> = vec![None; entries.len()];
+    let (mut initialize, mut tool_calls, mut readers) = (Vec::new(), Vec::new(), Vec::new());
+    for (index, entry) in entries.into_iter().enumerate() {
+        match entry {
+            Ok(request) if request.method == "initialize" => initialize.push((index, request)),
+            Ok(request) if request.is_tool_call() => tool_calls.push((index, request)),
+            Ok(request) => readers.push((index, request)),
+            Err(error) => {
+                warn!(target: "docs_mcp_transport", error = %error, "Invalid request in batch");
+                responses[index] = serialize_response(&RpcResponse::error(None, -32600, "Invalid Request"));
+            }
+        }
+    }
+
+    for (index, request) in initialize {
+        responses[index] = answer(context.clone(), request).await;
+    }
+    let in_order = async {
+        let mut answered = Vec::with_capacity(tool_calls.len());
+        for (index, request) in tool_calls {
+            answered.push((index, answer(context.clone(), request).await));
+        }
+        answered
+    };
+    let concurrent = join_all(
+        readers
+            .into_iter()
+            .map(|(index, request)| async move { (index, answer(context.clone(), request).await) }),
+    );
+    let (in_order, concurrent) = tokio::join!(in_order, concurrent);
+    for (index, payload) in in_order.into_iter().chain(concurrent) {
+        responses[index] = payload;
+    }
+
+    let responses: Vec<String> = responses.into_iter().flatten().collect();
+    (!responses.is_empty()).then(|| format!("[{}]", responses.join(",")))
+}
+
+/// Handle one request and serialize its response, recording it when request logging is on.
+async fn answer(context: Arc<AppContext>, request: RpcRequest) -> Option<String> {
+    let pending_log = context
+        .request_log
+        .is_some()
+        .then(|| (request_log_entry(&request), Instant::now()));
+    let response = handle_request(context.clone(), request).await;
+
+    let error_code = response.as_ref().and_then(|response| response.error.as_ref().map(|error| error.code));
+    let payload = response.as_ref().and_then(serialize_response);
+    if let Some((mut entry, started)) = pending_log {
+        entry.latency_ms = started.elapsed().as_millis() as u64;
+        entry.result_bytes = payload.as_ref().map_or(0, String::len);
+        entry.error_code = error_code;
+        context.record_request(&entry).await;
+    }
+    payload
+}
+
+fn serialize_response(response: &RpcResponse) -> Option<String> {
+    match serde_json::to_string(response) {
+        Ok(payload) => Some(payload),
+        Err(error) => {
+            warn!(target: "docs_mcp_transport", error = %error, "Failed to serialize response");
+            None
+        }
+    }
+}
+
+/// Start a request log entry; latency and response details are filled in once answered.
+fn request_log_entry(request: &RpcRequest) -> RequestLogEntry {
+    let (tool, args) = match request.params.as_ref() {
+        Some(params) if request.is_tool_call() => (
+            params.get("name").and_then(|name| name.as_str()).map(str::to_string),
+            params.get("arguments").map(anonymize),
+        ),
+        params => (None, params.map(anonymize)),
+    };
+    RequestLogEntry {
+        timestamp: OffsetDateTime::now_utc(),
+        method: request.method.clone(),
+        tool,
+        args,
+        latency_ms: 0,
+        result_bytes: 0,
+        error_code: None,
+    }
+}
+
+/// Key mixed into [`anonymize`] hashes; random per process so logged hashes can't be
+/// matched against a dictionary of likely queries.
+static ANONYMIZE_KEY: Lazy<[u8; 32]> = Lazy::new(|| {
+    let mut key = [0u8; 32];
+    getrandom::getrandom(&mut key).expect("operating system randomness");
+    key
+});
+
+/// Keep the shape of `value` but replace every string with its length and a keyed hash,
+/// so repeated queries within a run can be correlated without storing what was asked.
+fn anonymize(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(text) => {
+            let digest = Sha256::new()
+                .chain_update(ANONYMIZE_KEY.as_slice())
+                .chain_update(text.as_bytes())
+                .finalize();
+            let hash: String = digest[..8].iter().map(|byte| format!("{byte:02x}")).collect();
+            json!(format!("<{} chars #{hash}>", text.chars().count()))
+        }
+        serde_json::Value::Array(items) => items.iter().map(anonymize).collect(),
+        serde_json::Value::Object(fields) => fields
+            .iter()
+            .map(|(key, value)| (key.clone(), anonymize(value)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        other => other.clone(),
+    }
+}
+
 async fn read_next_message<R>(reader: &mut BufReader<R>) -> Result<Option<(String, TransportFraming)>>
 where
     R: tokio::io::AsyncRead + Unpin,
@@ -258,6 +453,12 @@ struct RpcRequest {
     pub params: Option<serde_json::Value>,
 }
 
+impl RpcRequest {
+    fn is_tool_call(&self) -> bool {
+        matches!(self.method.as_str(), "call_tool" | "tools/call")
+    }
+}
+
 #[derive(Debug, Serialize)]
 struct RpcResponse {
     jsonrpc: &'static str,
@@ -323,20 +524,63 @@ async fn handle_request(context: Arc<AppContext>, request: RpcRequest) -> Option
         .expect("id is present because notifications are handled above");
 
     match method {
-        "initialize" => Some(RpcResponse::result(
+        "initialize" => {
+            let requested = request
+                .params
+                .as_ref()
+                .and_then(|params| params.get("protocolVersion"))
+                .and_then(|version| version.as_str());
+            let version = negotiate_protocol(requested);
+            *context.state.protocol_version.write().await = Some(version.to_string());
+            Some(RpcResponse::result(
+                Some(id_value.clone()),
+                json!({
+                    "protocolVersion": version,
+                    "serverInfo": {
+                        "name": "docs-mcp",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                    "capabilities": {
+                        "tools": {},
+                        "resources": {}
+                    },
+                    "instructions": SERVER_INSTRUCTIONS,
+                }),
+            ))
+        }
+        "resources/list" => Some(RpcResponse::result(
             Some(id_value.clone()),
-            json!({
-                "protocolVersion": "2024-11-05",
-                "serverInfo": {
-                    "name": "docs-mcp",
-                    "version": env!("CARGO_PKG_VERSION"),
-                },
-                "capabilities": {
-                    "tools": {}
-                },
-                "instructions": SERVER_INSTRUCTIONS,
-            }),
+            json!({"resources": context.documents.list()}),
         )),
+        "resources/templates/list" => Some(RpcResponse::result(
+            Some(id_value.clone()),
+            json!({"resourceTemplates": []}),
+        )),
+        "resources/read" => {
+            let uri = request
+                .params
+                .as_ref()
+                .and_then(|params| params.get("uri"))
+                .and_then(|uri| uri.as_str());
+            Some(match uri.map(|uri| (uri, context.documents.get(uri))) {
+                Some((_, Some(document))) => RpcResponse::result(
+                    Some(id_value.clone()),
+                    json!({
+                        "contents": [{
+                            "uri": document.link.uri,
+                            "mimeType": document.link.mime_type,
+                            "text": document.text,
+                        }]
+                    }),
+                ),
+                Some((uri, None)) => RpcResponse::error(
+                    Some(id_value.clone()),
+                    RESOURCE_NOT_FOUND,
+                    format!("Resource not found: {uri}"),
+                ),
+                None => RpcResponse::error(Some(id_value.clone()), -32602, "Missing resource uri"),
+            })
+        }
         "list_tools" | "tools/list" => {
             let definitions = context.tools.definitions().await;
             Some(RpcResponse::result(
@@ -366,12 +610,17 @@ async fn handle_request(context: Arc<AppContext>, request: RpcRequest) -> Option
                         }
                     };
 
+                    let name = resolve_tool_name(&context, name).await;
                     match context.tools.get(&name).await {
                         Some(entry) => {
                             let handler = entry.handler.clone();
                             let started = Instant::now();
                             match handler(context.clone(), arguments).await {
-                                Ok(response) => {
+                                Ok(mut response) => {
+                                    // Clients on older protocol versions only get the text
+                                    if !supports_resource_links(&context).await {
+                                        response.content.retain(|content| content.resource.is_none());
+                                    }
                                     let latency_ms = started.elapsed().as_millis() as u64;
                                     let metadata = response.metadata.clone();
                                     let entry = TelemetryEntry {
@@ -451,3 +700,222 @@ async fn handle_request(context: Arc<AppContext>, request: RpcRequest) -> Option
         )),
     }
 }
+
+/// The protocol version to answer `initialize` with: the client's if supported, else the oldest.
+fn negotiate_protocol(requested: Option<&str>) -> &'static str {
+    requested
+        .and_then(|requested| PROTOCOL_VERSIONS.iter().find(|version| **version == requested))
+        .copied()
+        .unwrap_or(PROTOCOL_VERSIONS[PROTOCOL_VERSIONS.len() - 1])
+}
+
+/// Whether the session agreed on a protocol version with `resource_link` content blocks.
+async fn supports_resource_links(context: &AppContext) -> bool {
+    context
+        .state
+        .protocol_version
+        .read()
+        .await
+        .as_deref()
+        .is_some_and(|version| version >= RESOURCE_LINK_PROTOCOL)
+}
+
+/// The registered tool `name` refers to, following the configured aliases.
+async fn resolve_tool_name(context: &AppContext, name: String) -> String {
+    if context.tools.get(&name).await.is_some() {
+        return name;
+    }
+    match context.config.tool_aliases.get(&name) {
+        Some(target) => {
+            debug!(target: "docs_mcp_transport", alias = %name, tool = %target, "resolved tool alias");
+            target.clone()
+        }
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use docs_mcp_client::AppleDocsClient;
+    use multi_provider_client::types::ProviderType;
+
+    /// `context` scoped to a newly started session.
+    fn session(context: &Arc<AppContext>) -> Arc<AppContext> {
+        Arc::new(context.for_session(&context.start_session().unwrap()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn tool_aliases_resolve_to_registered_tools() {
+        let config = crate::ServerConfig {
+            tool_aliases: [
+                ("search".to_string(), "reset_state".to_string()),
+                ("health".to_string(), "reset_state".to_string()),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        let context = Arc::new(AppContext::with_config(AppleDocsClient::new(), config));
+        crate::tools::register_tools(context.clone()).await;
+
+        assert_eq!(resolve_tool_name(&context, "search".to_string()).await, "reset_state");
+        // Registered tools win over aliases of the same name
+        assert_eq!(resolve_tool_name(&context, "health".to_string()).await, "health");
+        assert_eq!(resolve_tool_name(&context, "missing".to_string()).await, "missing");
+
+        let call = r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"search"}}"#;
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&context, call).await.unwrap()).unwrap();
+        assert!(response["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("State Reset"));
+    }
+
+    #[tokio::test]
+    async fn handle_message_answers_requests_and_parse_errors() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let session = session(&context);
+        let ping = r#"{"jsonrpc":"2.0","id":7,"method":"tools/list"}"#;
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&session, ping).await.unwrap()).unwrap();
+        assert_eq!(response["id"], 7);
+        assert!(response["result"]["tools"].is_array());
+
+        let notification = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
+        assert!(handle_message(&session, notification).await.is_none());
+
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&context, "{").await.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], -32700);
+    }
+
+    #[tokio::test]
+    async fn batched_tool_calls_keep_their_own_provider() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let session = session(&context);
+        crate::tools::register_tools(context.clone()).await;
+        let query = |id: u32, query: &str| {
+            json!({"jsonrpc": "2.0", "id": id, "method": "tools/call",
+                   "params": {"name": "query", "arguments": {"query": query, "format": "json"}}})
+        };
+        let batch = json!([
+            query(1, "CUDA cudaMalloc device memory"),
+            query(2, "Bitcoin getblockchaininfo"),
+            query(3, "CUDA cudaMemcpy"),
+        ]);
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&session, &batch.to_string()).await.unwrap()).unwrap();
+        let metadata = |index: usize| &response[index]["result"]["metadata"];
+        assert_eq!(metadata(0)["provider"], "CUDA");
+        assert_eq!(metadata(0)["paths"][0], "cudaMalloc");
+        assert_eq!(metadata(1)["provider"], ProviderType::Bitcoind.name());
+        assert_eq!(metadata(1)["paths"][0], "bitcoin/getblockchaininfo");
+        assert_eq!(metadata(2)["provider"], "CUDA");
+        assert_eq!(metadata(2)["paths"][0], "cudaMemcpy");
+    }
+
+    #[tokio::test]
+    async fn handle_message_answers_batches() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let session = session(&context);
+        let batch = r#"[
+            {"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18"}},
+            {"jsonrpc":"2.0","method":"notifications/initialized"},
+            {"jsonrpc":"2.0","id":2,"method":"tools/list"},
+            {"id":3},
+            {"jsonrpc":"2.0","id":4,"method":"bogus"}
+        ]"#;
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&session, batch).await.unwrap()).unwrap();
+        let responses = response.as_array().expect("batch response");
+        assert_eq!(responses.len(), 4);
+        assert_eq!(responses[0]["result"]["protocolVersion"], "2025-06-18");
+        assert!(responses[1]["result"]["tools"].is_array());
+        assert_eq!(responses[2]["error"]["code"], -32600);
+        assert_eq!(responses[3]["id"], 4);
+        assert_eq!(responses[3]["error"]["code"], -32601);
+
+        let notifications = r#"[{"jsonrpc":"2.0","method":"notifications/initialized"}]"#;
+        assert!(handle_message(&session, notifications).await.is_none());
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&context, "[]").await.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], -32600);
+        let response: serde_json::Value =
+            serde_json::from_str(&handle_message(&context, "[{").await.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], -32700);
+    }
+
+    #[tokio::test]
+    async fn serves_linked_documents_as_resources() {
+        let context = Arc::new(AppContext::new(AppleDocsClient::new()));
+        let session = session(&context);
+        let call = |payload: serde_json::Value| {
+            let session = session.clone();
+            async move {
+                let response = handle_message(&session, &payload.to_string()).await.unwrap();
+                serde_json::from_str::<serde_json::Value>(&response).unwrap()
+            }
+        };
+
+        let initialize = call(json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {"protocolVersion": "2025-06-18"}})).await;
+        assert_eq!(initialize["result"]["protocolVersion"], "2025-06-18");
+        assert!(initialize["result"]["capabilities"]["resources"].is_object());
+        assert!(supports_resource_links(&session).await);
+        assert!(!supports_resource_links(&self::session(&context)).await);
+
+        let link = session.documents.insert(
+            ProviderType::Rust,
+            "std::vec::Vec",
+            "Vec",
+            "# Vec\n\nA contiguous growable array type.".to_string(),
+        );
+        let list = call(json!({"jsonrpc": "2.0", "id": 2, "method": "resources/list"})).await;
+        assert_eq!(list["result"]["resources"][0]["uri"], link.uri);
+        // Other sessions neither list nor read it
+        let other = self::session(&context);
+        let other_list = handle_message(&other, &json!({"jsonrpc": "2.0", "id": 5, "method": "resources/list"}).to_string())
+            .await
+            .unwrap();
+        assert!(!other_list.contains(&link.uri), "{other_list}");
+        assert!(other.documents.get(&link.uri).is_none());
+        assert_eq!(list["result"]["resources"][0]["mimeType"], "text/markdown");
+
+        let read = call(json!({"jsonrpc": "2.0", "id": 3, "method": "resources/read", "params": {"uri": link.uri}})).await;
+        assert!(read["result"]["contents"][0]["text"].as_str().unwrap().contains("growable array"));
+        let missing = call(json!({"jsonrpc": "2.0", "id": 4, "method": "resources/read", "params": {"uri": "docs-mcp://documents/rust/nope"}})).await;
+        assert_eq!(missing["error"]["code"], RESOURCE_NOT_FOUND);
+    }
+
+    #[test]
+    fn negotiates_supported_protocol_versions() {
+        assert_eq!(negotiate_protocol(Some("2025-06-18")), "2025-06-18");
+        assert_eq!(negotiate_protocol(Some("2024-11-05")), "2024-11-05");
+        assert_eq!(negotiate_protocol(Some("2025-03-26")), "2024-11-05");
+        assert_eq!(negotiate_protocol(None), "2024-11-05");
+    }
+
+    #[test]
+    fn request_log_anonymizes_tool_arguments() {
+        let request: RpcRequest = serde_json::from_value(json!({
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": "query",
+                "arguments": {"query": "SwiftUI List", "maxResults": 3, "tags": ["ios"]}
+            }
+        }))
+        .unwrap();
+        let entry = request_log_entry(&request);
+        assert_eq!(entry.tool.as_deref(), Some("query"));
+
+        let args = entry.args.unwrap();
+        assert_eq!(args["maxResults"], 3);
+        let query = args["query"].as_str().unwrap();
+        assert!(query.starts_with("<12 chars #"), "{query}");
+        assert_eq!(query.len(), "<12 chars #>".len() + 16, "64-bit hash: {query}");
+        assert!(!query.contains("SwiftUI"));
+        assert_eq!(args["query"], anonymize(&json!("SwiftUI List")), "hashes are stable");
+        assert!(args["tags"][0].as_str().unwrap().starts_with("<3 chars"));
+    }
+}
@@ -15,6 +15,7 @@ fn test_context() -> Arc<AppContext> {
     let client = AppleDocsClient::with_config(ClientConfig {
         cache_dir,
         memory_cache_ttl: Duration::minutes(5),
+        ..ClientConfig::default()
     });
     Arc::new(AppContext::new(client))
 }
@@ -62,6 +62,7 @@ async fn search_symbols_uses_fallback_when_index_empty() {
     let client = AppleDocsClient::with_config(ClientConfig {
         cache_dir: dir.path().to_path_buf(),
         memory_cache_ttl: Duration::minutes(10),
+        ..ClientConfig::default()
     });
     let context = Arc::new(AppContext::new(client));
 
@@ -103,6 +104,7 @@ async fn search_symbols_primary_results_exclude_fallback() {
     let client = AppleDocsClient::with_config(ClientConfig {
         cache_dir: dir.path().to_path_buf(),
         memory_cache_ttl: Duration::minutes(10),
+        ..ClientConfig::default()
     });
     let context = Arc::new(AppContext::new(client));
 
@@ -147,6 +149,7 @@ async fn search_symbols_global_scope_reads_cached_frameworks() {
     let client = AppleDocsClient::with_config(ClientConfig {
         cache_dir: dir.path().to_path_buf(),
         memory_cache_ttl: Duration::minutes(10),
+        ..ClientConfig::default()
     });
     let cache_dir = client.cache_dir().clone();
     let context = Arc::new(AppContext::new(client));
@@ -162,6 +165,7 @@ async fn search_symbols_global_scope_reads_cached_frameworks() {
         value: technologies_map,
         stored_at: now,
         last_accessed: now,
+        validators: None,
     };
     fs::write(
         cache_dir.join("technologies.json"),
@@ -174,6 +178,7 @@ async fn search_symbols_global_scope_reads_cached_frameworks() {
         value: framework.clone(),
         stored_at: now,
         last_accessed: now,
+        validators: None,
     };
     fs::write(
         cache_dir.join("SwiftUI.json"),
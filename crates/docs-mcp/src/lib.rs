@@ -1,43 +1,186 @@
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
-use docs_mcp_client::{AppleDocsClient, ClientConfig};
-use docs_mcp_core::{run, state::AppContext, ServerConfig, ServerMode};
+use docs_mcp_client::http::{NetworkSettings, ProxyMode, TlsBackend, TlsSettings};
+use docs_mcp_client::rate_limit::RateLimit;
+use docs_mcp_client::retry::RetryPolicy;
+use docs_mcp_client::timeouts::HttpTimeouts;
+use docs_mcp_core::services::bench::run_bench;
+pub use docs_mcp_core::services::bench::{parse_queries as parse_bench_queries, BenchOptions, BenchReport};
+use docs_mcp_core::services::cache_maintenance::{
+    parse_providers, prune_caches, CacheLocations, PruneOptions, PruneReport,
+};
+use docs_mcp_core::services::feedback::{
+    export_feedback as export_feedback_records, feedback_dir, FeedbackWebhook,
+};
+pub use docs_mcp_core::services::feedback::FeedbackSummary;
+pub use docs_mcp_core::services::providers::ProvidersReport;
+use docs_mcp_core::services::refresh::RefreshSettings;
+use docs_mcp_core::services::snapshot::{
+    export_snapshot, export_snapshot_with, import_snapshot, import_snapshot_with, SnapshotOptions,
+};
+use docs_mcp_core::services::index::build_indexes;
+use docs_mcp_core::services::index::IndexOptions;
+pub use docs_mcp_core::services::index::IndexReport;
+use docs_mcp_core::services::jsonl_log::LogRotation;
+pub use docs_mcp_core::services::snapshot::SnapshotManifest;
+use docs_mcp_core::services::warm::warm_caches;
+pub use docs_mcp_core::transport::http::HttpSettings;
+pub use docs_mcp_core::services::warm::{
+    WarmOptions, WarmReport, DEFAULT_MAX_DOCUMENTS as DEFAULT_WARM_DOCUMENTS,
+};
+use docs_mcp_core::{build_client, run, state::AppContext, ContentLimits, ServerConfig, ServerMode};
+pub use multi_provider_client::credentials::ApiToken;
+use multi_provider_client::types::ProviderType;
 use serde_json::json;
 
+mod output;
+
+pub use output::QueryFormat;
+
 const CACHE_DIR_ENV: &str = "DOCSMCP_CACHE_DIR";
 const HEADLESS_ENV: &str = "DOCSMCP_HEADLESS";
+const MAX_SUMMARY_LENGTH_ENV: &str = "DOCSMCP_MAX_SUMMARY_LENGTH";
+const MAX_CODE_LENGTH_ENV: &str = "DOCSMCP_MAX_CODE_LENGTH";
+const MAX_CONTENT_LENGTH_ENV: &str = "DOCSMCP_MAX_CONTENT_LENGTH";
+const CACHE_MAX_MB_ENV: &str = "DOCSMCP_CACHE_MAX_MB";
+const MEMORY_CACHE_MAX_MB_ENV: &str = "DOCSMCP_MEMORY_CACHE_MAX_MB";
+const OFFLINE_ENV: &str = "DOCSMCP_OFFLINE";
+const REFRESH_INTERVAL_MINUTES_ENV: &str = "DOCSMCP_REFRESH_INTERVAL_MINUTES";
+const REFRESH_AFTER_HOURS_ENV: &str = "DOCSMCP_REFRESH_AFTER_HOURS";
+const HTTP_RETRIES_ENV: &str = "DOCSMCP_HTTP_RETRIES";
+const HTTP_RETRY_BASE_MS_ENV: &str = "DOCSMCP_HTTP_RETRY_BASE_MS";
+const HTTP_RETRY_MAX_MS_ENV: &str = "DOCSMCP_HTTP_RETRY_MAX_MS";
+const CONNECT_TIMEOUT_SECS_ENV: &str = "DOCSMCP_CONNECT_TIMEOUT_SECS";
+const READ_TIMEOUT_SECS_ENV: &str = "DOCSMCP_READ_TIMEOUT_SECS";
+const MAX_CONCURRENT_REQUESTS_ENV: &str = "DOCSMCP_MAX_CONCURRENT_REQUESTS";
+const RATE_LIMIT_PER_SEC_ENV: &str = "DOCSMCP_RATE_LIMIT_PER_SEC";
+const RATE_LIMIT_BURST_ENV: &str = "DOCSMCP_RATE_LIMIT_BURST";
+const FEDERATED_DEADLINE_MS_ENV: &str = "DOCSMCP_FEDERATED_DEADLINE_MS";
+const PROXY_ENV: &str = "DOCSMCP_PROXY";
+const NO_PROXY_ENV: &str = "DOCSMCP_NO_PROXY";
+const HF_TOKEN_ENVS: [&str; 3] = ["DOCSMCP_HF_TOKEN", "HF_TOKEN", "HUGGING_FACE_HUB_TOKEN"];
+const GITHUB_TOKEN_ENVS: [&str; 2] = ["DOCSMCP_GITHUB_TOKEN", "GITHUB_TOKEN"];
+const TON_SPEC_REF_ENV: &str = "DOCSMCP_TON_SPEC_REF";
+const CA_CERT_ENV: &str = "DOCSMCP_CA_CERT";
+const TLS_BACKEND_ENV: &str = "DOCSMCP_TLS_BACKEND";
+const TELEMETRY_LOG_ENV: &str = "DOCSMCP_TELEMETRY_LOG";
+const TELEMETRY_LOG_MAX_MB_ENV: &str = "DOCSMCP_TELEMETRY_LOG_MAX_MB";
+const TELEMETRY_LOG_FILES_ENV: &str = "DOCSMCP_TELEMETRY_LOG_FILES";
+const REQUEST_LOG_ENV: &str = "DOCSMCP_REQUEST_LOG";
+const PERSIST_SESSION_ENV: &str = "DOCSMCP_PERSIST_SESSION";
+const FEEDBACK_WEBHOOK_ENV: &str = "DOCSMCP_FEEDBACK_WEBHOOK";
+const FEEDBACK_WEBHOOK_TOKEN_ENV: &str = "DOCSMCP_FEEDBACK_WEBHOOK_TOKEN";
+const RECIPE_DIR_ENV: &str = "DOCSMCP_RECIPE_DIR";
+const KNOWLEDGE_DIR_ENV: &str = "DOCSMCP_KNOWLEDGE_DIR";
+const KNOWLEDGE_URL_ENV: &str = "DOCSMCP_KNOWLEDGE_URL";
+const INTENT_RULES_ENV: &str = "DOCSMCP_INTENT_RULES";
+const TECHNOLOGY_TOOLS_ENV: &str = "DOCSMCP_TECHNOLOGY_TOOLS";
+const TOOLS_ENV: &str = "DOCSMCP_TOOLS";
+const DISABLED_TOOLS_ENV: &str = "DOCSMCP_DISABLED_TOOLS";
+const TOOL_ALIASES_ENV: &str = "DOCSMCP_TOOL_ALIASES";
+const HTTP_TOKEN_ENV: &str = "DOCSMCP_HTTP_TOKEN";
 
 /// Launches the MCP server using environment-informed defaults.
 ///
 /// Phase 2 provides scaffolding only; the concrete implementation lands in later phases.
 pub async fn run_server() -> Result<()> {
+    let config = resolve_config();
+
+    tracing::info!(
+        target: "docs_mcp",
+        cache_dir = ?config.cache_dir,
+        mode = ?config.mode,
+        "Starting MCP server"
+    );
+    run(config).await
+}
+
+/// Serves MCP over HTTP instead of stdio. `DOCSMCP_HTTP_TOKEN` supplies the bearer token
+/// when `settings` has none.
+pub async fn run_http_server(mut settings: HttpSettings) -> Result<()> {
+    if settings.auth_token.is_none() {
+        settings.auth_token = env_token(&[HTTP_TOKEN_ENV]);
+    }
+    if settings.auth_token.is_none() && !settings.bind.ip().is_loopback() {
+        tracing::warn!(
+            target: "docs_mcp",
+            address = %settings.bind,
+            "HTTP transport is listening beyond localhost without a bearer token"
+        );
+    }
     let config = ServerConfig {
-        cache_dir: resolve_cache_dir(),
-        mode: resolve_mode(),
-        ..Default::default()
+        mode: ServerMode::Http,
+        http: settings,
+        ..resolve_config()
     };
 
     tracing::info!(
         target: "docs_mcp",
         cache_dir = ?config.cache_dir,
-        mode = ?config.mode,
+        address = %config.http.bind,
         "Starting MCP server"
     );
     run(config).await
 }
 
-pub async fn oneshot_query(query: &str, max_results: Option<usize>) -> Result<docs_mcp_core::state::ToolResponse> {
-    let client = match resolve_cache_dir() {
-        Some(dir) => AppleDocsClient::with_config(ClientConfig {
-            cache_dir: dir,
-            ..ClientConfig::default()
-        }),
-        None => AppleDocsClient::new(),
+/// Runs one `query` call. `provider` and `technology` bypass auto-detection, like the tool's
+/// arguments of the same name.
+pub async fn oneshot_query(
+    query: &str,
+    max_results: Option<usize>,
+    provider: Option<&str>,
+    technology: Option<&str>,
+) -> Result<docs_mcp_core::state::ToolResponse> {
+    let output = QueryOutput {
+        max_results,
+        provider: provider.map(str::to_string),
+        technology: technology.map(str::to_string),
+        ..Default::default()
     };
+    call_query(query, &output, None).await
+}
+
+/// How `docs-mcp-cli query` runs and prints its response.
+#[derive(Debug, Clone, Default)]
+pub struct QueryOutput {
+    pub max_results: Option<usize>,
+    /// Provider slug that replaces auto-detection.
+    pub provider: Option<String>,
+    /// Technology (framework, crate or technology id) that replaces auto-detection.
+    pub technology: Option<String>,
+    /// Apple platform results must be available on (e.g. `visionOS`).
+    pub platform: Option<String>,
+    /// Hide beta-only Apple APIs (`includeBeta: false`).
+    pub exclude_beta: bool,
+    pub format: QueryFormat,
+    /// Result fields to print (e.g. `path`, `declaration`) instead of the full response.
+    pub fields: Vec<String>,
+}
 
-    let context = Arc::new(AppContext::new(client));
+/// Runs `query` once and renders the response as `output` asks.
+pub async fn render_query(query: &str, output: &QueryOutput) -> Result<String> {
+    if output.fields.is_empty() {
+        let response = call_query(query, output, Some(output.format.tool_format())).await?;
+        return output::render_response(&response, output.format);
+    }
+    let response = call_query(query, output, Some("json")).await?;
+    let document = response.content.first().map(|item| item.text.as_str()).unwrap_or_default();
+    output::render_fields(document, &output.fields, output.format)
+}
+
+async fn call_query(
+    query: &str,
+    output: &QueryOutput,
+    format: Option<&str>,
+) -> Result<docs_mcp_core::state::ToolResponse> {
+    let config = resolve_config();
+    let client = build_client(&config);
+
+    let context = Arc::new(AppContext::with_config(client, config));
     docs_mcp_core::tools::register_tools(context.clone()).await;
 
     let tool = context
@@ -47,21 +190,420 @@ pub async fn oneshot_query(query: &str, max_results: Option<usize>) -> Result<do
         .context("query tool not registered")?;
 
     let mut args = json!({ "query": query });
-    if let Some(max) = max_results {
+    if let Some(max) = output.max_results {
         args["maxResults"] = json!(max);
     }
+    if let Some(provider) = &output.provider {
+        args["provider"] = json!(provider);
+    }
+    if let Some(technology) = &output.technology {
+        args["technology"] = json!(technology);
+    }
+    if let Some(platform) = &output.platform {
+        args["platform"] = json!(platform);
+    }
+    if output.exclude_beta {
+        args["includeBeta"] = json!(false);
+    }
+    if let Some(format) = format {
+        args["format"] = json!(format);
+    }
 
     (tool.handler)(context, args).await
 }
 
+/// Fetches one document by path or URL, like the `get_documentation` tool, without searching.
+pub async fn get_document(
+    path: &str,
+    provider: Option<&str>,
+    technology: Option<&str>,
+) -> Result<docs_mcp_core::state::ToolResponse> {
+    let provider = provider
+        .map(|slug| ProviderType::from_slug(slug).with_context(|| format!("unknown provider '{slug}'")))
+        .transpose()?;
+    let config = resolve_config();
+    let client = build_client(&config);
+    let context = Arc::new(AppContext::with_config(client, config));
+    docs_mcp_core::tools::fetch_document(context, path, provider, technology).await
+}
+
+/// Prune on-disk caches using the same cache locations the server would use.
+pub async fn prune_cache(
+    older_than_days: Option<u64>,
+    providers: &[String],
+    dry_run: bool,
+) -> Result<PruneReport> {
+    let client = build_client(&resolve_config());
+    let options = PruneOptions {
+        older_than_days,
+        providers: parse_providers(providers)?,
+        dry_run,
+    };
+    prune_caches(&CacheLocations::for_client(&client), &options).await
+}
+
+/// Write every provider cache into a portable `.tar.gz` snapshot.
+pub async fn export_cache(archive: &Path) -> Result<SnapshotManifest> {
+    let client = build_client(&resolve_config());
+    export_snapshot(&CacheLocations::for_client(&client), archive).await
+}
+
+/// Load a snapshot produced by [`export_cache`] into the local caches.
+pub async fn import_cache(archive: &Path) -> Result<SnapshotManifest> {
+    let client = build_client(&resolve_config());
+    import_snapshot(&CacheLocations::for_client(&client), archive).await
+}
+
+/// Write an offline bundle of the selected providers (all when empty) and Apple frameworks.
+/// A `.tar.zst` name selects zstd compression; anything else is gzip.
+pub async fn export_bundle(
+    archive: &Path,
+    providers: &[String],
+    frameworks: &[String],
+) -> Result<SnapshotManifest> {
+    let client = build_client(&resolve_config());
+    let options = SnapshotOptions {
+        providers: parse_providers(providers)?,
+        frameworks: frameworks.to_vec(),
+    };
+    export_snapshot_with(&CacheLocations::for_client(&client), archive, &options).await
+}
+
+/// Load the selected providers and Apple frameworks of a bundle into the local caches.
+pub async fn import_bundle(
+    archive: &Path,
+    providers: &[String],
+    frameworks: &[String],
+) -> Result<SnapshotManifest> {
+    let client = build_client(&resolve_config());
+    let options = SnapshotOptions {
+        providers: parse_providers(providers)?,
+        frameworks: frameworks.to_vec(),
+    };
+    import_snapshot_with(&CacheLocations::for_client(&client), archive, &options).await
+}
+
+/// Feedback submitted through the `submit_feedback` tool, newest first.
+pub async fn list_feedback() -> Result<Vec<FeedbackSummary>> {
+    docs_mcp_core::services::feedback::list_feedback(&feedback_dir()).await
+}
+
+/// Write every stored feedback record to `output` as JSON Lines; returns the record count.
+pub async fn export_feedback(output: &Path) -> Result<usize> {
+    export_feedback_records(&feedback_dir(), output).await
+}
+
+/// Every provider with its technology count, cache directory and reachability.
+pub async fn providers() -> Result<ProvidersReport> {
+    let config = resolve_config();
+    let client = build_client(&config);
+    let context = AppContext::with_config(client, config);
+    Ok(docs_mcp_core::services::providers::list_providers(&context).await)
+}
+
+/// Pre-download Apple frameworks and Rust crates into the disk cache.
+///
+/// Always goes online, even when `DOCSMCP_OFFLINE` is set.
+pub async fn warm(options: WarmOptions) -> Result<WarmReport> {
+    let config = ServerConfig {
+        offline: false,
+        ..resolve_config()
+    };
+    let client = build_client(&config);
+    let context = AppContext::with_config(client, config);
+    warm_caches(&context, &options).await
+}
+
+/// Precompute search indexes for Apple frameworks and Rust crates, going online like [`warm`].
+pub async fn build_index(
+    providers: &[String],
+    frameworks: Vec<String>,
+    crates: Vec<String>,
+) -> Result<IndexReport> {
+    let options = IndexOptions {
+        providers: parse_providers(providers)?,
+        frameworks,
+        crates,
+    };
+    let config = ServerConfig {
+        offline: false,
+        ..resolve_config()
+    };
+    let client = build_client(&config);
+    let context = AppContext::with_config(client, config);
+    build_indexes(&context, &options).await
+}
+
+/// Replay queries through the `query` tool and report latency and cache hit rates.
+pub async fn bench(options: BenchOptions) -> Result<BenchReport> {
+    let config = resolve_config();
+    let client = build_client(&config);
+    let context = AppContext::with_config(client, config);
+    docs_mcp_core::tools::register_tools(Arc::new(context.clone())).await;
+    run_bench(&context, &options).await
+}
+
+fn resolve_config() -> ServerConfig {
+    ServerConfig {
+        cache_dir: resolve_cache_dir(),
+        mode: resolve_mode(),
+        limits: resolve_limits(),
+        cache_max_size_bytes: env_usize(CACHE_MAX_MB_ENV).map(|mb| mb as u64 * 1024 * 1024),
+        memory_cache_max_bytes: env_usize(MEMORY_CACHE_MAX_MB_ENV).map(|mb| mb as u64 * 1024 * 1024),
+        offline: env_flag(OFFLINE_ENV),
+        refresh: resolve_refresh(),
+        retry: resolve_retry(),
+        timeouts: resolve_timeouts(),
+        max_concurrent_requests: env_usize(MAX_CONCURRENT_REQUESTS_ENV)
+            .unwrap_or(docs_mcp_client::limiter::DEFAULT_MAX_CONCURRENT_REQUESTS),
+        rate_limit: resolve_rate_limit(),
+        federated_deadline: env_usize(FEDERATED_DEADLINE_MS_ENV).map_or(
+            multi_provider_client::timeouts::DEFAULT_FEDERATED_DEADLINE,
+            |ms| Duration::from_millis(ms as u64),
+        ),
+        network: NetworkSettings {
+            proxy: resolve_proxy(),
+            tls: resolve_tls(),
+        },
+        huggingface_token: env_token(&HF_TOKEN_ENVS),
+        github_token: env_token(&GITHUB_TOKEN_ENVS),
+        ton_spec_ref: std::env::var(TON_SPEC_REF_ENV)
+            .ok()
+            .map(|reference| reference.trim().to_string())
+            .filter(|reference| !reference.is_empty()),
+        telemetry_log: resolve_telemetry_log(),
+        request_log: env_flag(REQUEST_LOG_ENV).then(LogRotation::default),
+        feedback_webhook: resolve_feedback_webhook(),
+        persist_session: env_flag_or(PERSIST_SESSION_ENV, true),
+        recipe_dir: std::env::var_os(RECIPE_DIR_ENV).map(PathBuf::from),
+        knowledge_dir: std::env::var_os(KNOWLEDGE_DIR_ENV).map(PathBuf::from),
+        knowledge_url: std::env::var(KNOWLEDGE_URL_ENV)
+            .ok()
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty()),
+        intent_rules: std::env::var_os(INTENT_RULES_ENV).map(PathBuf::from),
+        technology_tools: env_flag(TECHNOLOGY_TOOLS_ENV),
+        enabled_tools: env_list(TOOLS_ENV),
+        disabled_tools: env_list(DISABLED_TOOLS_ENV).unwrap_or_default(),
+        tool_aliases: resolve_tool_aliases(),
+        ..Default::default()
+    }
+}
+
+fn resolve_limits() -> ContentLimits {
+    let defaults = ContentLimits::default();
+    ContentLimits {
+        summary: env_usize(MAX_SUMMARY_LENGTH_ENV).unwrap_or(defaults.summary),
+        code: env_usize(MAX_CODE_LENGTH_ENV).unwrap_or(defaults.code),
+        content: env_usize(MAX_CONTENT_LENGTH_ENV).unwrap_or(defaults.content),
+    }
+}
+
+/// Background refresh settings; an interval of `0` minutes disables the worker.
+fn resolve_refresh() -> Option<RefreshSettings> {
+    let defaults = RefreshSettings::default();
+    let interval = match env_usize(REFRESH_INTERVAL_MINUTES_ENV) {
+        Some(0) => return None,
+        Some(minutes) => Duration::from_secs(minutes as u64 * 60),
+        None => defaults.interval,
+    };
+    let refresh_after = env_usize(REFRESH_AFTER_HOURS_ENV)
+        .map_or(defaults.refresh_after, |hours| Duration::from_secs(hours as u64 * 60 * 60));
+    Some(RefreshSettings {
+        interval,
+        refresh_after,
+    })
+}
+
+/// Telemetry log rotation. The log holds raw query text, so it is only written when
+/// `DOCSMCP_TELEMETRY_LOG=1`; `DOCSMCP_TELEMETRY_LOG_MAX_MB=0` still disables it.
+fn resolve_telemetry_log() -> Option<LogRotation> {
+    if !env_flag(TELEMETRY_LOG_ENV) {
+        return None;
+    }
+    let defaults = LogRotation::default();
+    let max_file_bytes = match env_usize(TELEMETRY_LOG_MAX_MB_ENV) {
+        Some(0) => return None,
+        Some(mb) => mb as u64 * 1024 * 1024,
+        None => defaults.max_file_bytes,
+    };
+    Some(LogRotation {
+        max_file_bytes,
+        max_files: env_usize(TELEMETRY_LOG_FILES_ENV).unwrap_or(defaults.max_files),
+    })
+}
+
+/// `DOCSMCP_FEEDBACK_WEBHOOK` forwards feedback to a URL. GitHub Issues endpoints fall back to
+/// the GitHub token when `DOCSMCP_FEEDBACK_WEBHOOK_TOKEN` is unset.
+fn resolve_feedback_webhook() -> Option<FeedbackWebhook> {
+    let url = std::env::var(FEEDBACK_WEBHOOK_ENV).ok()?.trim().to_string();
+    if url.is_empty() {
+        return None;
+    }
+    let mut webhook = FeedbackWebhook {
+        url,
+        token: env_token(&[FEEDBACK_WEBHOOK_TOKEN_ENV]),
+    };
+    if webhook.token.is_none() && webhook.is_github_issues() {
+        webhook.token = env_token(&GITHUB_TOKEN_ENVS);
+    }
+    Some(webhook)
+}
+
+/// `DOCSMCP_TOOL_ALIASES` maps old tool names to registered ones, e.g. `search=query`.
+fn resolve_tool_aliases() -> HashMap<String, String> {
+    env_list(TOOL_ALIASES_ENV)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|pair| match pair.split_once('=') {
+            Some((alias, tool)) if !alias.trim().is_empty() && !tool.trim().is_empty() => {
+                Some((alias.trim().to_string(), tool.trim().to_string()))
+            }
+            _ => {
+                tracing::warn!(target: "docs_mcp", alias = %pair, "ignoring malformed tool alias; expected alias=tool");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Retry policy for outbound requests; `DOCSMCP_HTTP_RETRIES=0` disables retries.
+fn resolve_retry() -> RetryPolicy {
+    let defaults = RetryPolicy::default();
+    RetryPolicy {
+        max_retries: env_usize(HTTP_RETRIES_ENV).map_or(defaults.max_retries, |retries| retries as u32),
+        base_delay: env_usize(HTTP_RETRY_BASE_MS_ENV).map_or(defaults.base_delay, |ms| Duration::from_millis(ms as u64)),
+        max_delay: env_usize(HTTP_RETRY_MAX_MS_ENV).map_or(defaults.max_delay, |ms| Duration::from_millis(ms as u64)),
+    }
+}
+
+/// Per-host token bucket; `DOCSMCP_RATE_LIMIT_PER_SEC=0` disables client-side rate limiting.
+fn resolve_rate_limit() -> RateLimit {
+    let defaults = RateLimit::default();
+    RateLimit {
+        requests_per_second: env_f64(RATE_LIMIT_PER_SEC_ENV).unwrap_or(defaults.requests_per_second),
+        burst: env_usize(RATE_LIMIT_BURST_ENV).map_or(defaults.burst, |burst| burst as u32),
+    }
+}
+
+/// `DOCSMCP_PROXY` overrides the standard proxy variables with a proxy URL, or `off` to
+/// connect directly; `DOCSMCP_NO_PROXY` lists hosts that bypass it.
+fn resolve_proxy() -> ProxyMode {
+    let Some(url) = std::env::var(PROXY_ENV).ok().filter(|value| !value.trim().is_empty()) else {
+        return ProxyMode::System;
+    };
+    match url.trim() {
+        "off" | "none" => ProxyMode::Disabled,
+        url => ProxyMode::Explicit {
+            url: url.to_string(),
+            no_proxy: std::env::var(NO_PROXY_ENV).ok(),
+        },
+    }
+}
+
+/// `DOCSMCP_CA_CERT` lists extra PEM root certificates (separated like `PATH`), and
+/// `DOCSMCP_TLS_BACKEND` selects `rustls` (default) or `native`.
+fn resolve_tls() -> TlsSettings {
+    let backend = match std::env::var(TLS_BACKEND_ENV) {
+        Ok(name) => TlsBackend::from_name(&name).unwrap_or_else(|| {
+            tracing::warn!(target: "docs_mcp", backend = %name, "unknown TLS backend; using rustls");
+            TlsBackend::default()
+        }),
+        Err(_) => TlsBackend::default(),
+    };
+    let extra_root_certificates = std::env::var_os(CA_CERT_ENV)
+        .map(|paths| {
+            std::env::split_paths(&paths)
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    TlsSettings {
+        backend,
+        extra_root_certificates,
+    }
+}
+
+/// Timeout overrides: `DOCSMCP_{CONNECT,READ}_TIMEOUT_SECS` apply to every provider, and
+/// `DOCSMCP_<PROVIDER>_{CONNECT,READ}_TIMEOUT_SECS` (e.g. `DOCSMCP_APPLE_READ_TIMEOUT_SECS`)
+/// to a single one.
+fn resolve_timeouts() -> HashMap<ProviderType, HttpTimeouts> {
+    let secs = |key: &str| env_usize(key).map(|secs| Duration::from_secs(secs as u64));
+    let connect = secs(CONNECT_TIMEOUT_SECS_ENV);
+    let read = secs(READ_TIMEOUT_SECS_ENV);
+
+    ProviderType::ALL
+        .into_iter()
+        .filter_map(|provider| {
+            let prefix = format!("DOCSMCP_{}", provider.slug().to_ascii_uppercase());
+            let provider_connect = secs(&format!("{prefix}_CONNECT_TIMEOUT_SECS")).or(connect);
+            let provider_read = secs(&format!("{prefix}_READ_TIMEOUT_SECS")).or(read);
+            if provider_connect.is_none() && provider_read.is_none() {
+                return None;
+            }
+            let defaults = multi_provider_client::timeouts::timeouts_for(provider);
+            Some((
+                provider,
+                HttpTimeouts {
+                    connect: provider_connect.unwrap_or(defaults.connect),
+                    read: provider_read.unwrap_or(defaults.read),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn env_usize(key: &str) -> Option<usize> {
+    std::env::var(key).ok().and_then(|value| value.trim().parse().ok())
+}
+
+fn env_f64(key: &str) -> Option<f64> {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|value: &f64| value.is_finite() && *value >= 0.0)
+}
+
+/// The first non-empty token among `keys`, in order of preference.
+fn env_token(keys: &[&str]) -> Option<ApiToken> {
+    keys.iter()
+        .find_map(|key| std::env::var(key).ok().and_then(ApiToken::new))
+}
+
+/// Comma-separated names; `None` when the variable is unset or lists nothing.
+fn env_list(key: &str) -> Option<Vec<String>> {
+    let names: Vec<String> = std::env::var(key)
+        .ok()?
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    (!names.is_empty()).then_some(names)
+}
+
+fn env_flag(key: &str) -> bool {
+    env_flag_or(key, false)
+}
+
+/// `1`/`true` or `0`/`false` (any case); `default` when unset or anything else.
+fn env_flag_or(key: &str, default: bool) -> bool {
+    match std::env::var(key).map(|value| value.trim().to_ascii_lowercase()).as_deref() {
+        Ok("1" | "true") => true,
+        Ok("0" | "false") => false,
+        _ => default,
+    }
+}
+
 fn resolve_cache_dir() -> Option<PathBuf> {
     std::env::var_os(CACHE_DIR_ENV).map(PathBuf::from)
 }
 
 fn resolve_mode() -> ServerMode {
-    match std::env::var_os(HEADLESS_ENV) {
-        Some(value) if value == "1" || value.eq_ignore_ascii_case("true") => ServerMode::Headless,
-        _ => ServerMode::Stdio,
+    if env_flag(HEADLESS_ENV) {
+        ServerMode::Headless
+    } else {
+        ServerMode::Stdio
     }
 }
 
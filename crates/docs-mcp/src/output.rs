@@ -0,0 +1,188 @@
+//! Rendering of one-shot `query` responses for `docs-mcp-cli query --format/--fields`.
+
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+use docs_mcp_core::state::ToolResponse;
+use docs_mcp_core::tools::QUERY_RESULT_FIELDS;
+use serde_json::{Map, Value};
+
+/// Output style of `docs-mcp-cli query`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QueryFormat {
+    #[default]
+    Markdown,
+    Json,
+    Yaml,
+    Plain,
+}
+
+impl QueryFormat {
+    /// The `format` argument passed to the `query` tool for text output.
+    pub(crate) fn tool_format(self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            _ => "markdown",
+        }
+    }
+}
+
+impl FromStr for QueryFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "plain" | "text" => Ok(Self::Plain),
+            other => bail!("unknown format '{other}' (expected md, json, yaml or plain)"),
+        }
+    }
+}
+
+/// Text, or the whole response as a JSON/YAML document.
+pub(crate) fn render_response(response: &ToolResponse, format: QueryFormat) -> Result<String> {
+    match format {
+        QueryFormat::Json => Ok(serde_json::to_string_pretty(response)?),
+        QueryFormat::Yaml => Ok(serde_yaml::to_string(response)?),
+        QueryFormat::Markdown | QueryFormat::Plain => Ok(response
+            .content
+            .iter()
+            .map(|item| item.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")),
+    }
+}
+
+/// The selected `fields` of every result in the `query` tool's JSON document.
+///
+/// Plain output is one tab-separated line per result so it can be piped to `cut` or `xargs`;
+/// markdown output is a table.
+pub(crate) fn render_fields(document: &str, fields: &[String], format: QueryFormat) -> Result<String> {
+    // Responses without results (e.g. offline cache misses) are explanatory text
+    let Ok(document) = serde_json::from_str::<Value>(document) else {
+        bail!("query returned no results:\n{}", document.trim());
+    };
+    // Checked against the schema so every result, and an empty result list, agree
+    if let Some(unknown) = fields.iter().find(|field| !QUERY_RESULT_FIELDS.contains(&field.as_str())) {
+        bail!("unknown field '{unknown}' (available: {})", QUERY_RESULT_FIELDS.join(", "));
+    }
+    let results = document
+        .get("results")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let selected: Vec<Value> = results
+        .iter()
+        .map(|result| {
+            let object: Map<String, Value> = fields
+                .iter()
+                .map(|field| (field.clone(), result.get(field).cloned().unwrap_or(Value::Null)))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+
+    match format {
+        QueryFormat::Json => Ok(serde_json::to_string_pretty(&selected)?),
+        QueryFormat::Yaml => Ok(serde_yaml::to_string(&selected)?),
+        QueryFormat::Plain => Ok(selected
+            .iter()
+            .map(|result| {
+                fields
+                    .iter()
+                    .map(|field| plain_value(&result[field], fields.len() > 1))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        QueryFormat::Markdown => {
+            let mut lines = vec![
+                format!("| {} |", fields.join(" | ")),
+                format!("|{}", "---|".repeat(fields.len())),
+            ];
+            for result in &selected {
+                let cells: Vec<String> = fields
+                    .iter()
+                    .map(|field| plain_value(&result[field], true).replace('|', "\\|"))
+                    .collect();
+                lines.push(format!("| {} |", cells.join(" | ")));
+            }
+            Ok(lines.join("\n"))
+        }
+    }
+}
+
+/// A field as bare text; `single_line` folds multi-line values such as declarations.
+fn plain_value(value: &Value, single_line: bool) -> String {
+    let text = match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    if single_line {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = r#"{
+        "query": "tokio spawn",
+        "results": [
+            {"title": "spawn", "path": "tokio/task/fn.spawn", "declaration": "pub fn spawn<F>(future: F)\n    -> JoinHandle<F::Output>", "score": 1.5},
+            {"title": "JoinHandle", "path": "tokio/task/struct.JoinHandle", "declaration": null, "score": null}
+        ]
+    }"#;
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("md".parse::<QueryFormat>().unwrap(), QueryFormat::Markdown);
+        assert_eq!("YAML".parse::<QueryFormat>().unwrap(), QueryFormat::Yaml);
+        assert!("xml".parse::<QueryFormat>().is_err());
+    }
+
+    #[test]
+    fn selects_fields() {
+        let plain = render_fields(DOCUMENT, &fields(&["path"]), QueryFormat::Plain).unwrap();
+        assert_eq!(plain, "tokio/task/fn.spawn\ntokio/task/struct.JoinHandle");
+
+        let plain =
+            render_fields(DOCUMENT, &fields(&["title", "declaration"]), QueryFormat::Plain).unwrap();
+        assert_eq!(
+            plain.lines().next(),
+            Some("spawn\tpub fn spawn<F>(future: F) -> JoinHandle<F::Output>")
+        );
+
+        let json = render_fields(DOCUMENT, &fields(&["path", "score"]), QueryFormat::Json).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0], serde_json::json!({"path": "tokio/task/fn.spawn", "score": 1.5}));
+
+        let yaml = render_fields(DOCUMENT, &fields(&["title"]), QueryFormat::Yaml).unwrap();
+        assert_eq!(yaml, "- title: spawn\n- title: JoinHandle\n");
+
+        let markdown = render_fields(DOCUMENT, &fields(&["title"]), QueryFormat::Markdown).unwrap();
+        assert_eq!(markdown, "| title |\n|---|\n| spawn |\n| JoinHandle |");
+
+        let error = render_fields(DOCUMENT, &fields(&["signature"]), QueryFormat::Plain).unwrap_err();
+        assert!(error.to_string().contains("unknown field 'signature'"));
+
+        // Fields only some results carry are known, and missing values render empty
+        let features = render_fields(DOCUMENT, &fields(&["title", "features"]), QueryFormat::Plain).unwrap();
+        assert_eq!(features, "spawn\t\nJoinHandle\t");
+        let empty = r#"{"query": "tokio nothing", "results": []}"#;
+        assert_eq!(render_fields(empty, &fields(&["path"]), QueryFormat::Plain).unwrap(), "");
+        assert!(render_fields(empty, &fields(&["signature"]), QueryFormat::Plain).is_err());
+    }
+}
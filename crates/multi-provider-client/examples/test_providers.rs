@@ -81,14 +81,13 @@ async fn main() -> anyhow::Result<()> {
     // Test unified ProviderClients
     println!("🌐 Testing Unified ProviderClients...");
     let clients = ProviderClients::new();
-    match clients.get_all_technologies().await {
-        Ok(all_techs) => {
-            println!("   ✅ All providers loaded:");
-            for (provider, techs) in &all_techs {
-                println!("      - {}: {} technologies", provider, techs.len());
-            }
-        }
-        Err(e) => println!("   ❌ Error: {e}"),
+    let all_techs = clients.get_all_technologies().await;
+    println!("   ✅ {} providers loaded:", all_techs.technologies.len());
+    for (provider, techs) in &all_techs.technologies {
+        println!("      - {}: {} technologies", provider, techs.len());
+    }
+    for provider in all_techs.degraded_providers() {
+        println!("   ❌ {provider}: {}", all_techs.errors[&provider]);
     }
 
     println!("\n=== Test Complete ===");
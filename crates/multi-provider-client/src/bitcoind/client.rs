@@ -1,5 +1,4 @@
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
@@ -8,48 +7,52 @@ use tokio::sync::Mutex;
 use tracing::{debug, instrument, warn};
 
 use super::types::{
-    VertcoinCategory, VertcoinCategoryItem, VertcoinExample, VertcoinMethod,
-    VertcoinMethodIndex, VertcoinMethodKind, VertcoinParameter, VertcoinReturnField,
-    VertcoinReturnType, VertcoinTechnology,
-    VERTCOIN_BLOCKCHAIN_METHODS, VERTCOIN_CONTROL_METHODS, VERTCOIN_MINING_METHODS,
-    VERTCOIN_NETWORK_METHODS, VERTCOIN_RAWTRANSACTION_METHODS, VERTCOIN_SPECIFICATIONS,
-    VERTCOIN_UTIL_METHODS, VERTCOIN_WALLET_METHODS,
+    category_methods, coin_methods, method_path, parse_category_identifier, BitcoindCategory,
+    BitcoindCategoryItem, BitcoindCoin, BitcoindExample, BitcoindMethod, BitcoindMethodIndex,
+    BitcoindMethodKind, BitcoindParameter, BitcoindReturnField, BitcoindReturnType,
+    BitcoindTechnology, BITCOIND_CATEGORIES,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
 
-const VERTCOIN_CORE_DOCS_URL: &str = "https://github.com/vertcoin-project/vertcoin-core/blob/master/doc";
-const VERTCOIN_WIKI_URL: &str = "https://github.com/vertcoin-project/VertDocs";
-const VERTCOIN_MAIN_URL: &str = "https://vertcoin.org";
+use crate::credentials::github_get;
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
+
+/// Categories listed as technologies for each coin
+const LISTED_CATEGORIES: [&str; 5] = ["blockchain", "wallet", "mining", "network", "specs"];
 
 #[derive(Debug)]
-pub struct VertcoinClient {
+pub struct BitcoindClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<String>,
+    memory_cache: ProviderMemoryCache<String>,
     fetch_lock: Mutex<()>,
     cache_dir: PathBuf,
 }
 
-impl Default for VertcoinClient {
+impl Default for BitcoindClient {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl VertcoinClient {
+impl BitcoindClient {
     #[must_use]
     pub fn new() -> Self {
         let project_dirs = ProjectDirs::from("com", "RecordAndLearn", "multi-docs-mcp")
             .expect("unable to resolve project directories");
 
-        let cache_dir = project_dirs.cache_dir().join("vertcoin");
+        let cache_dir = project_dirs.cache_dir().join("bitcoind");
         if let Err(e) = std::fs::create_dir_all(&cache_dir) {
-            warn!(error = %e, "Failed to create Vertcoin cache directory");
+            warn!(error = %e, "Failed to create bitcoind cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Bitcoind))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -57,179 +60,74 @@ impl VertcoinClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(1)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Bitcoind, memory_ttl(ProviderType::Bitcoind)),
             fetch_lock: Mutex::new(()),
             cache_dir,
         }
     }
 
-    /// Get available technologies (Vertcoin categories)
-    #[instrument(name = "vertcoin_client.get_technologies", skip(self))]
-    pub async fn get_technologies(&self) -> Result<Vec<VertcoinTechnology>> {
-        let blockchain_tech = VertcoinTechnology {
-            identifier: "vertcoin:blockchain".to_string(),
-            title: "Blockchain RPC".to_string(),
-            description: format!(
-                "Vertcoin blockchain RPC methods - {} methods for querying blockchain state, blocks, and transactions",
-                VERTCOIN_BLOCKCHAIN_METHODS.len()
-            ),
-            url: format!("{VERTCOIN_CORE_DOCS_URL}/JSON-RPC-interface.md"),
-            item_count: VERTCOIN_BLOCKCHAIN_METHODS.len(),
-        };
-
-        let wallet_tech = VertcoinTechnology {
-            identifier: "vertcoin:wallet".to_string(),
-            title: "Wallet RPC".to_string(),
-            description: format!(
-                "Vertcoin wallet RPC methods - {} methods for managing wallets, addresses, and transactions",
-                VERTCOIN_WALLET_METHODS.len()
-            ),
-            url: format!("{VERTCOIN_CORE_DOCS_URL}/JSON-RPC-interface.md"),
-            item_count: VERTCOIN_WALLET_METHODS.len(),
-        };
-
-        let mining_tech = VertcoinTechnology {
-            identifier: "vertcoin:mining".to_string(),
-            title: "Mining (Verthash)".to_string(),
-            description: format!(
-                "Vertcoin Verthash mining - {} methods and specifications for GPU mining",
-                VERTCOIN_MINING_METHODS.len()
-            ),
-            url: format!("{VERTCOIN_MAIN_URL}/specs-explained/"),
-            item_count: VERTCOIN_MINING_METHODS.len(),
-        };
-
-        let network_tech = VertcoinTechnology {
-            identifier: "vertcoin:network".to_string(),
-            title: "Network RPC".to_string(),
-            description: format!(
-                "Vertcoin network RPC methods - {} methods for P2P networking and node management",
-                VERTCOIN_NETWORK_METHODS.len()
-            ),
-            url: format!("{VERTCOIN_CORE_DOCS_URL}/JSON-RPC-interface.md"),
-            item_count: VERTCOIN_NETWORK_METHODS.len(),
-        };
-
-        let specs_tech = VertcoinTechnology {
-            identifier: "vertcoin:specs".to_string(),
-            title: "Specifications".to_string(),
-            description: format!(
-                "Vertcoin specifications - {} core concepts including Verthash, block time, supply, and SegWit",
-                VERTCOIN_SPECIFICATIONS.len()
-            ),
-            url: format!("{VERTCOIN_MAIN_URL}/specs-explained/"),
-            item_count: VERTCOIN_SPECIFICATIONS.len(),
-        };
-
-        Ok(vec![blockchain_tech, wallet_tech, mining_tech, network_tech, specs_tech])
+    /// Get available technologies (blockchain, wallet, mining, network and specs for each coin)
+    #[instrument(name = "bitcoind_client.get_technologies", skip(self))]
+    pub async fn get_technologies(&self) -> Result<Vec<BitcoindTechnology>> {
+        let mut technologies = Vec::new();
+        for coin in BitcoindCoin::ALL {
+            for category in LISTED_CATEGORIES {
+                let count = category_methods(coin, category).len();
+                let (title, description) = category_summary(coin, category);
+                technologies.push(BitcoindTechnology {
+                    identifier: format!("{}:{category}", coin.slug()),
+                    title: format!("{} {title}", coin.name()),
+                    description: format!("{description} - {count} entries"),
+                    url: category_url(coin, category),
+                    item_count: count,
+                });
+            }
+        }
+        Ok(technologies)
     }
 
-    /// Get a category of methods
-    #[instrument(name = "vertcoin_client.get_category", skip(self))]
-    pub async fn get_category(&self, identifier: &str) -> Result<VertcoinCategory> {
-        let (methods, title, description): (&[VertcoinMethodIndex], &str, &str) = match identifier {
-            "vertcoin:blockchain" | "blockchain" => (
-                VERTCOIN_BLOCKCHAIN_METHODS,
-                "Blockchain RPC Methods",
-                "JSON-RPC methods for querying Vertcoin blockchain state",
-            ),
-            "vertcoin:wallet" | "wallet" => (
-                VERTCOIN_WALLET_METHODS,
-                "Wallet RPC Methods",
-                "JSON-RPC methods for Vertcoin wallet management",
-            ),
-            "vertcoin:mining" | "mining" | "verthash" => (
-                VERTCOIN_MINING_METHODS,
-                "Mining Methods (Verthash)",
-                "Mining-related RPC methods for Verthash GPU mining",
-            ),
-            "vertcoin:network" | "network" => (
-                VERTCOIN_NETWORK_METHODS,
-                "Network RPC Methods",
-                "P2P networking and node management methods",
-            ),
-            "vertcoin:rawtransactions" | "rawtransactions" | "raw" => (
-                VERTCOIN_RAWTRANSACTION_METHODS,
-                "Raw Transaction Methods",
-                "Methods for creating and signing raw transactions and PSBTs",
-            ),
-            "vertcoin:control" | "control" => (
-                VERTCOIN_CONTROL_METHODS,
-                "Control Methods",
-                "Node control and management methods",
-            ),
-            "vertcoin:util" | "util" => (
-                VERTCOIN_UTIL_METHODS,
-                "Utility Methods",
-                "Utility methods for address validation, fee estimation, and signatures",
-            ),
-            "vertcoin:specs" | "specs" | "specifications" => (
-                VERTCOIN_SPECIFICATIONS,
-                "Vertcoin Specifications",
-                "Core specifications and concepts for Vertcoin blockchain",
-            ),
-            _ => anyhow::bail!("Unknown Vertcoin category: {identifier}"),
-        };
+    /// Get a category of methods (`bitcoin:wallet`, `litecoin:mining`; bare names are Vertcoin's)
+    #[instrument(name = "bitcoind_client.get_category", skip(self))]
+    pub async fn get_category(&self, identifier: &str) -> Result<BitcoindCategory> {
+        let (coin, category) = parse_category_identifier(identifier)
+            .ok_or_else(|| anyhow::anyhow!("Unknown bitcoind category: {identifier}"))?;
+        let (title, description) = category_summary(coin, category);
 
-        let items = methods
-            .iter()
-            .map(|m| VertcoinCategoryItem {
+        let items = category_methods(coin, category)
+            .into_iter()
+            .map(|m| BitcoindCategoryItem {
                 name: m.name.to_string(),
-                description: m.description.to_string(),
+                description: coin.render(m.description),
                 kind: m.kind,
-                url: self.get_method_url(m),
+                url: method_url(coin, m),
+                path: method_path(coin, m.name),
             })
             .collect();
 
-        Ok(VertcoinCategory {
-            identifier: identifier.to_string(),
-            title: title.to_string(),
-            description: description.to_string(),
+        Ok(BitcoindCategory {
+            identifier: format!("{}:{category}", coin.slug()),
+            title: format!("{} {title}", coin.name()),
+            description,
             items,
         })
     }
 
-    /// Get URL for a method
-    fn get_method_url(&self, method: &VertcoinMethodIndex) -> String {
-        match method.kind {
-            VertcoinMethodKind::Specification => {
-                format!("{VERTCOIN_MAIN_URL}/specs-explained/")
-            }
-            VertcoinMethodKind::MiningMethod => {
-                format!("{VERTCOIN_WIKI_URL}/blob/master/docs/Mining/")
-            }
-            _ => {
-                format!("{VERTCOIN_CORE_DOCS_URL}/JSON-RPC-interface.md")
-            }
-        }
-    }
-
-    /// Get all methods as a flat list for searching
-    fn all_methods() -> impl Iterator<Item = &'static VertcoinMethodIndex> {
-        VERTCOIN_BLOCKCHAIN_METHODS
-            .iter()
-            .chain(VERTCOIN_WALLET_METHODS.iter())
-            .chain(VERTCOIN_MINING_METHODS.iter())
-            .chain(VERTCOIN_NETWORK_METHODS.iter())
-            .chain(VERTCOIN_RAWTRANSACTION_METHODS.iter())
-            .chain(VERTCOIN_CONTROL_METHODS.iter())
-            .chain(VERTCOIN_UTIL_METHODS.iter())
-            .chain(VERTCOIN_SPECIFICATIONS.iter())
-    }
-
-    /// Fetch additional documentation from GitHub (cached)
-    async fn fetch_github_doc(&self, doc_path: &str) -> Result<String> {
-        let cache_key = format!("github_{}.html", doc_path.replace('/', "_"));
+    /// Fetch additional documentation from the coin's GitHub repository (cached)
+    async fn fetch_github_doc(&self, coin: BitcoindCoin, doc_path: &str) -> Result<String> {
+        let cache_key = match coin {
+            BitcoindCoin::Vertcoin => format!("github_{}.html", doc_path.replace('/', "_")),
+            _ => format!("github_{}_{}.html", coin.slug(), doc_path.replace('/', "_")),
+        };
 
         // Check memory cache first
         if let Some(html) = self.memory_cache.get(&cache_key) {
-            debug!(path = doc_path, "Vertcoin doc served from memory cache");
+            debug!(path = doc_path, "bitcoind doc served from memory cache");
             return Ok(html);
         }
 
         // Check disk cache
         if let Ok(Some(entry)) = self.disk_cache.load::<String>(&cache_key).await {
-            debug!(path = doc_path, "Vertcoin doc served from disk cache");
+            debug!(path = doc_path, "bitcoind doc served from disk cache");
             self.memory_cache.insert(cache_key.clone(), entry.value.clone());
             return Ok(entry.value);
         }
@@ -243,19 +141,19 @@ impl VertcoinClient {
         }
 
         // Fetch from GitHub
-        let url = format!("https://raw.githubusercontent.com/vertcoin-project/vertcoin-core/master/{doc_path}");
-        debug!(url = %url, "Fetching Vertcoin documentation from GitHub");
+        let url = format!("https://raw.githubusercontent.com/{}/master/{doc_path}", coin.repository());
+        debug!(url = %url, "Fetching {} documentation from GitHub", coin.name());
 
-        let response = self
-            .http
-            .get(&url)
-            .send()
+        ensure_online(&url)?;
+        let response = github_get(&self.http, &url)
+            .send_with_retry()
             .await
-            .context("Failed to fetch Vertcoin documentation")?;
+            .with_context(|| format!("Failed to fetch {} documentation", coin.name()))?;
 
         if !response.status().is_success() {
             anyhow::bail!(
-                "Vertcoin documentation fetch failed for {}: {}",
+                "{} documentation fetch failed for {}: {}",
+                coin.name(),
                 doc_path,
                 response.status()
             );
@@ -264,81 +162,84 @@ impl VertcoinClient {
         let content = response
             .text()
             .await
-            .context("Failed to read Vertcoin response")?;
+            .with_context(|| format!("Failed to read {} response", coin.name()))?;
 
         // Store in caches
         self.memory_cache.insert(cache_key.clone(), content.clone());
         if let Err(e) = self.disk_cache.store(&cache_key, content.clone()).await {
-            warn!(error = %e, "Failed to cache Vertcoin doc to disk");
+            warn!(error = %e, "Failed to cache bitcoind doc to disk");
         }
 
         Ok(content)
     }
 
-    /// Build detailed method documentation
-    fn build_method_doc(&self, index_entry: &VertcoinMethodIndex) -> VertcoinMethod {
+    /// Build detailed method documentation, filling the coin's placeholders
+    fn build_method_doc(&self, coin: BitcoindCoin, index_entry: &BitcoindMethodIndex) -> BitcoindMethod {
         // Build examples based on method type
-        let examples = self.generate_examples(index_entry);
+        let examples = self.generate_examples(coin, index_entry);
 
         // Build parameters based on common patterns (Bitcoin RPC style)
-        let parameters = self.infer_parameters(index_entry);
+        let parameters = self
+            .infer_parameters(index_entry)
+            .into_iter()
+            .map(|p| BitcoindParameter { description: coin.render(&p.description), ..p })
+            .collect();
+
+        let returns = self.infer_return_type(index_entry).map(|r| BitcoindReturnType {
+            description: coin.render(&r.description),
+            fields: r
+                .fields
+                .into_iter()
+                .map(|f| BitcoindReturnField { description: coin.render(&f.description), ..f })
+                .collect(),
+            ..r
+        });
 
-        VertcoinMethod {
+        BitcoindMethod {
             name: index_entry.name.to_string(),
-            description: index_entry.description.to_string(),
+            coin,
+            description: coin.render(index_entry.description),
             kind: index_entry.kind,
-            url: self.get_method_url(index_entry),
+            url: method_url(coin, index_entry),
             parameters,
-            returns: self.infer_return_type(index_entry),
+            returns,
             examples,
         }
     }
 
     /// Generate example code for a method
-    fn generate_examples(&self, method: &VertcoinMethodIndex) -> Vec<VertcoinExample> {
+    fn generate_examples(&self, coin: BitcoindCoin, method: &BitcoindMethodIndex) -> Vec<BitcoindExample> {
         let mut examples = Vec::new();
+        let cli = coin.cli();
+        let address = format!("{}AddressHere", coin.name());
 
         // CLI example
         let cli_example = match method.name {
-            "getblockchaininfo" => "vertcoin-cli getblockchaininfo",
-            "getbalance" => "vertcoin-cli getbalance",
-            "getnewaddress" => "vertcoin-cli getnewaddress \"\" \"bech32\"",
-            "sendtoaddress" => "vertcoin-cli sendtoaddress \"VtcAddressHere\" 0.1",
-            "getblock" => "vertcoin-cli getblock \"blockhash\" 2",
-            "getblockcount" => "vertcoin-cli getblockcount",
-            "getdifficulty" => "vertcoin-cli getdifficulty",
-            "getmininginfo" => "vertcoin-cli getmininginfo",
-            "getnetworkinfo" => "vertcoin-cli getnetworkinfo",
-            "getpeerinfo" => "vertcoin-cli getpeerinfo",
-            "getconnectioncount" => "vertcoin-cli getconnectioncount",
-            "validateaddress" => "vertcoin-cli validateaddress \"VtcAddressHere\"",
-            "estimatesmartfee" => "vertcoin-cli estimatesmartfee 6",
-            "getblocktemplate" => "vertcoin-cli getblocktemplate '{\"rules\": [\"segwit\"]}'",
-            "listunspent" => "vertcoin-cli listunspent 1 9999999",
-            "listtransactions" => "vertcoin-cli listtransactions \"*\" 10",
-            _ => {
-                // Generic CLI example
-                return vec![VertcoinExample {
-                    language: "bash".to_string(),
-                    code: format!("vertcoin-cli {}", method.name),
-                    description: Some(format!("Call {} via vertcoin-cli", method.name)),
-                }];
-            }
+            "getnewaddress" => format!("{cli} getnewaddress \"\" \"bech32\""),
+            "sendtoaddress" => format!("{cli} sendtoaddress \"{address}\" 0.1"),
+            "getblock" => format!("{cli} getblock \"blockhash\" 2"),
+            "validateaddress" => format!("{cli} validateaddress \"{address}\""),
+            "estimatesmartfee" => format!("{cli} estimatesmartfee 6"),
+            "getblocktemplate" => format!("{cli} getblocktemplate '{{\"rules\": [\"segwit\"]}}'"),
+            "listunspent" => format!("{cli} listunspent 1 9999999"),
+            "listtransactions" => format!("{cli} listtransactions \"*\" 10"),
+            name => format!("{cli} {name}"),
         };
 
-        examples.push(VertcoinExample {
+        examples.push(BitcoindExample {
             language: "bash".to_string(),
-            code: cli_example.to_string(),
-            description: Some(format!("Call {} via vertcoin-cli", method.name)),
+            code: cli_example,
+            description: Some(format!("Call {} via {cli}", method.name)),
         });
 
         // JSON-RPC example for RPC methods
-        if matches!(method.kind, VertcoinMethodKind::RpcMethod | VertcoinMethodKind::WalletMethod) {
+        if matches!(method.kind, BitcoindMethodKind::RpcMethod | BitcoindMethodKind::WalletMethod) {
             let json_example = format!(
-                r#"curl --user myusername --data-binary '{{"jsonrpc": "1.0", "id": "curltest", "method": "{}", "params": []}}' -H 'content-type: text/plain;' http://127.0.0.1:5888/"#,
-                method.name
+                r#"curl --user myusername --data-binary '{{"jsonrpc": "1.0", "id": "curltest", "method": "{}", "params": []}}' -H 'content-type: text/plain;' http://127.0.0.1:{}/"#,
+                method.name,
+                coin.rpc_port()
             );
-            examples.push(VertcoinExample {
+            examples.push(BitcoindExample {
                 language: "bash".to_string(),
                 code: json_example,
                 description: Some("JSON-RPC call via curl".to_string()),
@@ -349,18 +250,18 @@ impl VertcoinClient {
     }
 
     /// Infer parameters for a method based on common patterns
-    fn infer_parameters(&self, method: &VertcoinMethodIndex) -> Vec<VertcoinParameter> {
+    fn infer_parameters(&self, method: &BitcoindMethodIndex) -> Vec<BitcoindParameter> {
         // Common parameter patterns for Bitcoin-derived RPC
         match method.name {
             "getblock" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "blockhash".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "The block hash (hex string)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "verbosity".to_string(),
                     param_type: "number".to_string(),
                     required: false,
@@ -369,7 +270,7 @@ impl VertcoinClient {
                 },
             ],
             "getblockhash" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "height".to_string(),
                     param_type: "number".to_string(),
                     required: true,
@@ -378,14 +279,14 @@ impl VertcoinClient {
                 },
             ],
             "getblockheader" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "blockhash".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "The block hash".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "verbose".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
@@ -394,35 +295,35 @@ impl VertcoinClient {
                 },
             ],
             "sendtoaddress" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "address".to_string(),
                     param_type: "string".to_string(),
                     required: true,
-                    description: "The Vertcoin address to send to (V... or vtc1...)".to_string(),
+                    description: "The {coin} address to send to ({legacy}... or {bech32}...)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "amount".to_string(),
                     param_type: "number".to_string(),
                     required: true,
-                    description: "The amount in VTC to send (e.g., 0.1)".to_string(),
+                    description: "The amount in {ticker} to send (e.g., 0.1)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "comment".to_string(),
                     param_type: "string".to_string(),
                     required: false,
                     description: "A comment stored in wallet for this transaction".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "comment_to".to_string(),
                     param_type: "string".to_string(),
                     required: false,
                     description: "A comment to store the recipient name".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "subtractfeefromamount".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
@@ -431,30 +332,30 @@ impl VertcoinClient {
                 },
             ],
             "getnewaddress" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "label".to_string(),
                     param_type: "string".to_string(),
                     required: false,
                     description: "The label for the address".to_string(),
                     default_value: Some("\"\"".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "address_type".to_string(),
                     param_type: "string".to_string(),
                     required: false,
-                    description: "Address type: legacy (V...), p2sh-segwit (3...), or bech32 (vtc1...)".to_string(),
+                    description: "Address type: legacy ({legacy}...), p2sh-segwit ({p2sh}...), or bech32 ({bech32}...)".to_string(),
                     default_value: Some("bech32".to_string()),
                 },
             ],
             "estimatesmartfee" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "conf_target".to_string(),
                     param_type: "number".to_string(),
                     required: true,
                     description: "Confirmation target in blocks (1-1008)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "estimate_mode".to_string(),
                     param_type: "string".to_string(),
                     required: false,
@@ -463,30 +364,30 @@ impl VertcoinClient {
                 },
             ],
             "validateaddress" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "address".to_string(),
                     param_type: "string".to_string(),
                     required: true,
-                    description: "The Vertcoin address to validate (V..., 3..., or vtc1...)".to_string(),
+                    description: "The {coin} address to validate ({legacy}..., {p2sh}..., or {bech32}...)".to_string(),
                     default_value: None,
                 },
             ],
             "getrawtransaction" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "txid".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "The transaction ID (64-character hex string)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "verbose".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
                     description: "If true, return JSON object; if false, return hex string".to_string(),
                     default_value: Some("false".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "blockhash".to_string(),
                     param_type: "string".to_string(),
                     required: false,
@@ -495,28 +396,28 @@ impl VertcoinClient {
                 },
             ],
             "createwallet" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "wallet_name".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "Name for the new wallet".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "disable_private_keys".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
                     description: "Disable private keys for watch-only wallet".to_string(),
                     default_value: Some("false".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "blank".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
                     description: "Create blank wallet without HD seed".to_string(),
                     default_value: Some("false".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "passphrase".to_string(),
                     param_type: "string".to_string(),
                     required: false,
@@ -525,28 +426,28 @@ impl VertcoinClient {
                 },
             ],
             "sendmany" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "dummy".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "Must be \"\" for backwards compatibility".to_string(),
                     default_value: Some("\"\"".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "amounts".to_string(),
                     param_type: "object".to_string(),
                     required: true,
                     description: "JSON object with addresses as keys and amounts as values".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "minconf".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Minimum confirmations for inputs".to_string(),
                     default_value: Some("1".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "comment".to_string(),
                     param_type: "string".to_string(),
                     required: false,
@@ -555,21 +456,21 @@ impl VertcoinClient {
                 },
             ],
             "listunspent" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "minconf".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Minimum confirmations to filter".to_string(),
                     default_value: Some("1".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "maxconf".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Maximum confirmations to filter".to_string(),
                     default_value: Some("9999999".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "addresses".to_string(),
                     param_type: "array".to_string(),
                     required: false,
@@ -578,28 +479,28 @@ impl VertcoinClient {
                 },
             ],
             "listtransactions" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "label".to_string(),
                     param_type: "string".to_string(),
                     required: false,
                     description: "Filter by label (use \"*\" for all)".to_string(),
                     default_value: Some("\"*\"".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "count".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Number of transactions to return".to_string(),
                     default_value: Some("10".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "skip".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Number of transactions to skip".to_string(),
                     default_value: Some("0".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "include_watchonly".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
@@ -608,7 +509,7 @@ impl VertcoinClient {
                 },
             ],
             "getblocktemplate" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "template_request".to_string(),
                     param_type: "object".to_string(),
                     required: false,
@@ -617,14 +518,14 @@ impl VertcoinClient {
                 },
             ],
             "addnode" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "node".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "Node address (IP:port or DNS)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "command".to_string(),
                     param_type: "string".to_string(),
                     required: true,
@@ -633,21 +534,21 @@ impl VertcoinClient {
                 },
             ],
             "setban" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "subnet".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "IP/Subnet with optional netmask".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "command".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "add or remove".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "bantime".to_string(),
                     param_type: "number".to_string(),
                     required: false,
@@ -656,21 +557,21 @@ impl VertcoinClient {
                 },
             ],
             "importprivkey" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "privkey".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "The private key (WIF format)".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "label".to_string(),
                     param_type: "string".to_string(),
                     required: false,
                     description: "Label for the address".to_string(),
                     default_value: Some("\"\"".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "rescan".to_string(),
                     param_type: "boolean".to_string(),
                     required: false,
@@ -679,7 +580,7 @@ impl VertcoinClient {
                 },
             ],
             "encryptwallet" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "passphrase".to_string(),
                     param_type: "string".to_string(),
                     required: true,
@@ -688,14 +589,14 @@ impl VertcoinClient {
                 },
             ],
             "walletpassphrase" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "passphrase".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "Wallet passphrase".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "timeout".to_string(),
                     param_type: "number".to_string(),
                     required: true,
@@ -704,14 +605,14 @@ impl VertcoinClient {
                 },
             ],
             "signmessage" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "address".to_string(),
                     param_type: "string".to_string(),
                     required: true,
-                    description: "Vertcoin address whose key to use".to_string(),
+                    description: "{coin} address whose key to use".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "message".to_string(),
                     param_type: "string".to_string(),
                     required: true,
@@ -720,21 +621,21 @@ impl VertcoinClient {
                 },
             ],
             "verifymessage" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "address".to_string(),
                     param_type: "string".to_string(),
                     required: true,
-                    description: "Vertcoin address that signed the message".to_string(),
+                    description: "{coin} address that signed the message".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "signature".to_string(),
                     param_type: "string".to_string(),
                     required: true,
                     description: "Base64-encoded signature".to_string(),
                     default_value: None,
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "message".to_string(),
                     param_type: "string".to_string(),
                     required: true,
@@ -743,14 +644,14 @@ impl VertcoinClient {
                 },
             ],
             "getnetworkhashps" => vec![
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "nblocks".to_string(),
                     param_type: "number".to_string(),
                     required: false,
                     description: "Blocks to average over (-1 for since last difficulty change)".to_string(),
                     default_value: Some("120".to_string()),
                 },
-                VertcoinParameter {
+                BitcoindParameter {
                     name: "height".to_string(),
                     param_type: "number".to_string(),
                     required: false,
@@ -763,172 +664,172 @@ impl VertcoinClient {
     }
 
     /// Infer return type for a method
-    fn infer_return_type(&self, method: &VertcoinMethodIndex) -> Option<VertcoinReturnType> {
+    fn infer_return_type(&self, method: &BitcoindMethodIndex) -> Option<BitcoindReturnType> {
         match method.name {
-            "getblockchaininfo" => Some(VertcoinReturnType {
+            "getblockchaininfo" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Blockchain state information".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "chain".to_string(), field_type: "string".to_string(), description: "Current network name (main, test, regtest)".to_string() },
-                    VertcoinReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Number of blocks processed".to_string() },
-                    VertcoinReturnField { name: "headers".to_string(), field_type: "number".to_string(), description: "Number of headers validated".to_string() },
-                    VertcoinReturnField { name: "bestblockhash".to_string(), field_type: "string".to_string(), description: "Hash of the best block".to_string() },
-                    VertcoinReturnField { name: "difficulty".to_string(), field_type: "number".to_string(), description: "Current Verthash mining difficulty".to_string() },
-                    VertcoinReturnField { name: "verificationprogress".to_string(), field_type: "number".to_string(), description: "Estimate of verification progress (0-1)".to_string() },
-                    VertcoinReturnField { name: "pruned".to_string(), field_type: "boolean".to_string(), description: "Whether blockchain is pruned".to_string() },
-                    VertcoinReturnField { name: "size_on_disk".to_string(), field_type: "number".to_string(), description: "Blockchain size in bytes".to_string() },
+                    BitcoindReturnField { name: "chain".to_string(), field_type: "string".to_string(), description: "Current network name (main, test, regtest)".to_string() },
+                    BitcoindReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Number of blocks processed".to_string() },
+                    BitcoindReturnField { name: "headers".to_string(), field_type: "number".to_string(), description: "Number of headers validated".to_string() },
+                    BitcoindReturnField { name: "bestblockhash".to_string(), field_type: "string".to_string(), description: "Hash of the best block".to_string() },
+                    BitcoindReturnField { name: "difficulty".to_string(), field_type: "number".to_string(), description: "Current {pow} mining difficulty".to_string() },
+                    BitcoindReturnField { name: "verificationprogress".to_string(), field_type: "number".to_string(), description: "Estimate of verification progress (0-1)".to_string() },
+                    BitcoindReturnField { name: "pruned".to_string(), field_type: "boolean".to_string(), description: "Whether blockchain is pruned".to_string() },
+                    BitcoindReturnField { name: "size_on_disk".to_string(), field_type: "number".to_string(), description: "Blockchain size in bytes".to_string() },
                 ],
             }),
-            "getbalance" => Some(VertcoinReturnType {
+            "getbalance" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
-                description: "The total available balance in VTC".to_string(),
+                description: "The total available balance in {ticker}".to_string(),
                 fields: vec![],
             }),
-            "getbalances" => Some(VertcoinReturnType {
+            "getbalances" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "All wallet balances".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "mine.trusted".to_string(), field_type: "number".to_string(), description: "Trusted balance".to_string() },
-                    VertcoinReturnField { name: "mine.untrusted_pending".to_string(), field_type: "number".to_string(), description: "Untrusted pending balance".to_string() },
-                    VertcoinReturnField { name: "mine.immature".to_string(), field_type: "number".to_string(), description: "Immature coinbase balance".to_string() },
+                    BitcoindReturnField { name: "mine.trusted".to_string(), field_type: "number".to_string(), description: "Trusted balance".to_string() },
+                    BitcoindReturnField { name: "mine.untrusted_pending".to_string(), field_type: "number".to_string(), description: "Untrusted pending balance".to_string() },
+                    BitcoindReturnField { name: "mine.immature".to_string(), field_type: "number".to_string(), description: "Immature coinbase balance".to_string() },
                 ],
             }),
-            "getblockcount" => Some(VertcoinReturnType {
+            "getblockcount" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
                 description: "The current block count".to_string(),
                 fields: vec![],
             }),
-            "getdifficulty" => Some(VertcoinReturnType {
+            "getdifficulty" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
-                description: "The current Verthash mining difficulty".to_string(),
+                description: "The current {pow} mining difficulty".to_string(),
                 fields: vec![],
             }),
-            "getconnectioncount" => Some(VertcoinReturnType {
+            "getconnectioncount" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
                 description: "The number of connections to other nodes".to_string(),
                 fields: vec![],
             }),
-            "getnewaddress" => Some(VertcoinReturnType {
+            "getnewaddress" => Some(BitcoindReturnType {
                 type_name: "string".to_string(),
-                description: "A new Vertcoin address (V... for legacy, vtc1... for bech32)".to_string(),
+                description: "A new {coin} address ({legacy}... for legacy, {bech32}... for bech32)".to_string(),
                 fields: vec![],
             }),
-            "getmininginfo" => Some(VertcoinReturnType {
+            "getmininginfo" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Mining-related information".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Current block height".to_string() },
-                    VertcoinReturnField { name: "difficulty".to_string(), field_type: "number".to_string(), description: "Current Verthash difficulty".to_string() },
-                    VertcoinReturnField { name: "networkhashps".to_string(), field_type: "number".to_string(), description: "Estimated network hash rate".to_string() },
-                    VertcoinReturnField { name: "pooledtx".to_string(), field_type: "number".to_string(), description: "Size of mempool".to_string() },
-                    VertcoinReturnField { name: "chain".to_string(), field_type: "string".to_string(), description: "Network name".to_string() },
+                    BitcoindReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Current block height".to_string() },
+                    BitcoindReturnField { name: "difficulty".to_string(), field_type: "number".to_string(), description: "Current {pow} difficulty".to_string() },
+                    BitcoindReturnField { name: "networkhashps".to_string(), field_type: "number".to_string(), description: "Estimated network hash rate".to_string() },
+                    BitcoindReturnField { name: "pooledtx".to_string(), field_type: "number".to_string(), description: "Size of mempool".to_string() },
+                    BitcoindReturnField { name: "chain".to_string(), field_type: "string".to_string(), description: "Network name".to_string() },
                 ],
             }),
-            "getnetworkinfo" => Some(VertcoinReturnType {
+            "getnetworkinfo" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "P2P networking state".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "version".to_string(), field_type: "number".to_string(), description: "Server version".to_string() },
-                    VertcoinReturnField { name: "subversion".to_string(), field_type: "string".to_string(), description: "Server subversion string".to_string() },
-                    VertcoinReturnField { name: "protocolversion".to_string(), field_type: "number".to_string(), description: "Protocol version".to_string() },
-                    VertcoinReturnField { name: "connections".to_string(), field_type: "number".to_string(), description: "Number of connections".to_string() },
-                    VertcoinReturnField { name: "connections_in".to_string(), field_type: "number".to_string(), description: "Inbound connections".to_string() },
-                    VertcoinReturnField { name: "connections_out".to_string(), field_type: "number".to_string(), description: "Outbound connections".to_string() },
-                    VertcoinReturnField { name: "networkactive".to_string(), field_type: "boolean".to_string(), description: "Network is active".to_string() },
+                    BitcoindReturnField { name: "version".to_string(), field_type: "number".to_string(), description: "Server version".to_string() },
+                    BitcoindReturnField { name: "subversion".to_string(), field_type: "string".to_string(), description: "Server subversion string".to_string() },
+                    BitcoindReturnField { name: "protocolversion".to_string(), field_type: "number".to_string(), description: "Protocol version".to_string() },
+                    BitcoindReturnField { name: "connections".to_string(), field_type: "number".to_string(), description: "Number of connections".to_string() },
+                    BitcoindReturnField { name: "connections_in".to_string(), field_type: "number".to_string(), description: "Inbound connections".to_string() },
+                    BitcoindReturnField { name: "connections_out".to_string(), field_type: "number".to_string(), description: "Outbound connections".to_string() },
+                    BitcoindReturnField { name: "networkactive".to_string(), field_type: "boolean".to_string(), description: "Network is active".to_string() },
                 ],
             }),
-            "getwalletinfo" => Some(VertcoinReturnType {
+            "getwalletinfo" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Wallet state information".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "walletname".to_string(), field_type: "string".to_string(), description: "Wallet name".to_string() },
-                    VertcoinReturnField { name: "walletversion".to_string(), field_type: "number".to_string(), description: "Wallet version".to_string() },
-                    VertcoinReturnField { name: "balance".to_string(), field_type: "number".to_string(), description: "Confirmed balance".to_string() },
-                    VertcoinReturnField { name: "unconfirmed_balance".to_string(), field_type: "number".to_string(), description: "Unconfirmed balance".to_string() },
-                    VertcoinReturnField { name: "txcount".to_string(), field_type: "number".to_string(), description: "Number of transactions".to_string() },
-                    VertcoinReturnField { name: "keypoolsize".to_string(), field_type: "number".to_string(), description: "Keypool size".to_string() },
-                    VertcoinReturnField { name: "unlocked_until".to_string(), field_type: "number".to_string(), description: "Unlock expiration timestamp".to_string() },
+                    BitcoindReturnField { name: "walletname".to_string(), field_type: "string".to_string(), description: "Wallet name".to_string() },
+                    BitcoindReturnField { name: "walletversion".to_string(), field_type: "number".to_string(), description: "Wallet version".to_string() },
+                    BitcoindReturnField { name: "balance".to_string(), field_type: "number".to_string(), description: "Confirmed balance".to_string() },
+                    BitcoindReturnField { name: "unconfirmed_balance".to_string(), field_type: "number".to_string(), description: "Unconfirmed balance".to_string() },
+                    BitcoindReturnField { name: "txcount".to_string(), field_type: "number".to_string(), description: "Number of transactions".to_string() },
+                    BitcoindReturnField { name: "keypoolsize".to_string(), field_type: "number".to_string(), description: "Keypool size".to_string() },
+                    BitcoindReturnField { name: "unlocked_until".to_string(), field_type: "number".to_string(), description: "Unlock expiration timestamp".to_string() },
                 ],
             }),
-            "getpeerinfo" => Some(VertcoinReturnType {
+            "getpeerinfo" => Some(BitcoindReturnType {
                 type_name: "array".to_string(),
                 description: "List of connected peer information".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "[].id".to_string(), field_type: "number".to_string(), description: "Peer index".to_string() },
-                    VertcoinReturnField { name: "[].addr".to_string(), field_type: "string".to_string(), description: "IP:port".to_string() },
-                    VertcoinReturnField { name: "[].subver".to_string(), field_type: "string".to_string(), description: "User agent".to_string() },
-                    VertcoinReturnField { name: "[].version".to_string(), field_type: "number".to_string(), description: "Protocol version".to_string() },
-                    VertcoinReturnField { name: "[].synced_blocks".to_string(), field_type: "number".to_string(), description: "Last synced block".to_string() },
+                    BitcoindReturnField { name: "[].id".to_string(), field_type: "number".to_string(), description: "Peer index".to_string() },
+                    BitcoindReturnField { name: "[].addr".to_string(), field_type: "string".to_string(), description: "IP:port".to_string() },
+                    BitcoindReturnField { name: "[].subver".to_string(), field_type: "string".to_string(), description: "User agent".to_string() },
+                    BitcoindReturnField { name: "[].version".to_string(), field_type: "number".to_string(), description: "Protocol version".to_string() },
+                    BitcoindReturnField { name: "[].synced_blocks".to_string(), field_type: "number".to_string(), description: "Last synced block".to_string() },
                 ],
             }),
-            "getmempoolinfo" => Some(VertcoinReturnType {
+            "getmempoolinfo" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Mempool state".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "loaded".to_string(), field_type: "boolean".to_string(), description: "Mempool is loaded".to_string() },
-                    VertcoinReturnField { name: "size".to_string(), field_type: "number".to_string(), description: "Number of transactions".to_string() },
-                    VertcoinReturnField { name: "bytes".to_string(), field_type: "number".to_string(), description: "Total size in bytes".to_string() },
-                    VertcoinReturnField { name: "usage".to_string(), field_type: "number".to_string(), description: "Memory usage".to_string() },
-                    VertcoinReturnField { name: "mempoolminfee".to_string(), field_type: "number".to_string(), description: "Minimum fee rate".to_string() },
+                    BitcoindReturnField { name: "loaded".to_string(), field_type: "boolean".to_string(), description: "Mempool is loaded".to_string() },
+                    BitcoindReturnField { name: "size".to_string(), field_type: "number".to_string(), description: "Number of transactions".to_string() },
+                    BitcoindReturnField { name: "bytes".to_string(), field_type: "number".to_string(), description: "Total size in bytes".to_string() },
+                    BitcoindReturnField { name: "usage".to_string(), field_type: "number".to_string(), description: "Memory usage".to_string() },
+                    BitcoindReturnField { name: "mempoolminfee".to_string(), field_type: "number".to_string(), description: "Minimum fee rate".to_string() },
                 ],
             }),
-            "validateaddress" => Some(VertcoinReturnType {
+            "validateaddress" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Address validation result".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "isvalid".to_string(), field_type: "boolean".to_string(), description: "Address is valid".to_string() },
-                    VertcoinReturnField { name: "address".to_string(), field_type: "string".to_string(), description: "The address".to_string() },
-                    VertcoinReturnField { name: "scriptPubKey".to_string(), field_type: "string".to_string(), description: "Script public key".to_string() },
-                    VertcoinReturnField { name: "isscript".to_string(), field_type: "boolean".to_string(), description: "Is P2SH".to_string() },
-                    VertcoinReturnField { name: "iswitness".to_string(), field_type: "boolean".to_string(), description: "Is SegWit".to_string() },
+                    BitcoindReturnField { name: "isvalid".to_string(), field_type: "boolean".to_string(), description: "Address is valid".to_string() },
+                    BitcoindReturnField { name: "address".to_string(), field_type: "string".to_string(), description: "The address".to_string() },
+                    BitcoindReturnField { name: "scriptPubKey".to_string(), field_type: "string".to_string(), description: "Script public key".to_string() },
+                    BitcoindReturnField { name: "isscript".to_string(), field_type: "boolean".to_string(), description: "Is P2SH".to_string() },
+                    BitcoindReturnField { name: "iswitness".to_string(), field_type: "boolean".to_string(), description: "Is SegWit".to_string() },
                 ],
             }),
-            "estimatesmartfee" => Some(VertcoinReturnType {
+            "estimatesmartfee" => Some(BitcoindReturnType {
                 type_name: "object".to_string(),
                 description: "Fee estimation result".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "feerate".to_string(), field_type: "number".to_string(), description: "Fee rate in VTC/kB".to_string() },
-                    VertcoinReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Blocks for estimate".to_string() },
+                    BitcoindReturnField { name: "feerate".to_string(), field_type: "number".to_string(), description: "Fee rate in {ticker}/kB".to_string() },
+                    BitcoindReturnField { name: "blocks".to_string(), field_type: "number".to_string(), description: "Blocks for estimate".to_string() },
                 ],
             }),
-            "listunspent" => Some(VertcoinReturnType {
+            "listunspent" => Some(BitcoindReturnType {
                 type_name: "array".to_string(),
                 description: "List of unspent transaction outputs".to_string(),
                 fields: vec![
-                    VertcoinReturnField { name: "[].txid".to_string(), field_type: "string".to_string(), description: "Transaction ID".to_string() },
-                    VertcoinReturnField { name: "[].vout".to_string(), field_type: "number".to_string(), description: "Output index".to_string() },
-                    VertcoinReturnField { name: "[].address".to_string(), field_type: "string".to_string(), description: "Vertcoin address".to_string() },
-                    VertcoinReturnField { name: "[].amount".to_string(), field_type: "number".to_string(), description: "Amount in VTC".to_string() },
-                    VertcoinReturnField { name: "[].confirmations".to_string(), field_type: "number".to_string(), description: "Number of confirmations".to_string() },
-                    VertcoinReturnField { name: "[].spendable".to_string(), field_type: "boolean".to_string(), description: "Is spendable".to_string() },
+                    BitcoindReturnField { name: "[].txid".to_string(), field_type: "string".to_string(), description: "Transaction ID".to_string() },
+                    BitcoindReturnField { name: "[].vout".to_string(), field_type: "number".to_string(), description: "Output index".to_string() },
+                    BitcoindReturnField { name: "[].address".to_string(), field_type: "string".to_string(), description: "{coin} address".to_string() },
+                    BitcoindReturnField { name: "[].amount".to_string(), field_type: "number".to_string(), description: "Amount in {ticker}".to_string() },
+                    BitcoindReturnField { name: "[].confirmations".to_string(), field_type: "number".to_string(), description: "Number of confirmations".to_string() },
+                    BitcoindReturnField { name: "[].spendable".to_string(), field_type: "boolean".to_string(), description: "Is spendable".to_string() },
                 ],
             }),
-            "sendtoaddress" | "sendmany" => Some(VertcoinReturnType {
+            "sendtoaddress" | "sendmany" => Some(BitcoindReturnType {
                 type_name: "string".to_string(),
                 description: "Transaction ID (txid) of the sent transaction".to_string(),
                 fields: vec![],
             }),
-            "signmessage" => Some(VertcoinReturnType {
+            "signmessage" => Some(BitcoindReturnType {
                 type_name: "string".to_string(),
                 description: "Base64-encoded signature".to_string(),
                 fields: vec![],
             }),
-            "verifymessage" => Some(VertcoinReturnType {
+            "verifymessage" => Some(BitcoindReturnType {
                 type_name: "boolean".to_string(),
                 description: "True if signature is valid".to_string(),
                 fields: vec![],
             }),
-            "getbestblockhash" | "getblockhash" => Some(VertcoinReturnType {
+            "getbestblockhash" | "getblockhash" => Some(BitcoindReturnType {
                 type_name: "string".to_string(),
                 description: "Block hash (64-character hex string)".to_string(),
                 fields: vec![],
             }),
-            "getnetworkhashps" => Some(VertcoinReturnType {
+            "getnetworkhashps" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
                 description: "Estimated network hash rate in hashes per second".to_string(),
                 fields: vec![],
             }),
-            "uptime" => Some(VertcoinReturnType {
+            "uptime" => Some(BitcoindReturnType {
                 type_name: "number".to_string(),
                 description: "Server uptime in seconds".to_string(),
                 fields: vec![],
@@ -937,21 +838,19 @@ impl VertcoinClient {
         }
     }
 
-    /// Get a specific method by name
-    #[instrument(name = "vertcoin_client.get_method", skip(self))]
-    pub async fn get_method(&self, name: &str) -> Result<VertcoinMethod> {
-        // Find method in index
-        let index_entry = Self::all_methods()
-            .find(|m| m.name.eq_ignore_ascii_case(name))
-            .ok_or_else(|| anyhow::anyhow!("Vertcoin method not found: {name}"))?;
-
-        Ok(self.build_method_doc(index_entry))
+    /// Get a method by path: `bitcoin/getblock`, `litecoin/getnewaddress`, or a bare Vertcoin name
+    #[instrument(name = "bitcoind_client.get_method", skip(self))]
+    pub async fn get_method(&self, path: &str) -> Result<BitcoindMethod> {
+        let (coin, index_entry) =
+            find_method(path).ok_or_else(|| anyhow::anyhow!("bitcoind method not found: {path}"))?;
+        Ok(self.build_method_doc(coin, index_entry))
     }
 
-    /// Search for methods matching a query
-    #[instrument(name = "vertcoin_client.search", skip(self))]
-    pub async fn search(&self, query: &str) -> Result<Vec<VertcoinMethod>> {
+    /// Search one coin's methods and specifications
+    #[instrument(name = "bitcoind_client.search", skip(self))]
+    pub async fn search(&self, query: &str, coin: BitcoindCoin) -> Result<Vec<BitcoindMethod>> {
         let query_lower = query.to_lowercase();
+        let pow = coin.pow().to_lowercase();
 
         // Split query into keywords
         let keywords: Vec<&str> = query_lower
@@ -959,12 +858,12 @@ impl VertcoinClient {
             .filter(|s| !s.is_empty() && s.len() > 1)
             .collect();
 
-        let mut scored_results: Vec<(i32, &VertcoinMethodIndex)> = Vec::new();
+        let mut scored_results: Vec<(i32, &BitcoindMethodIndex)> = Vec::new();
 
         // Search all methods
-        for method in Self::all_methods() {
+        for method in coin_methods(coin) {
             let name_lower = method.name.to_lowercase();
-            let desc_lower = method.description.to_lowercase();
+            let desc_lower = coin.render(method.description).to_lowercase();
             let category_lower = method.category.to_lowercase();
 
             let mut score = 0i32;
@@ -988,11 +887,11 @@ impl VertcoinClient {
                 }
             }
 
-            // Boost for Vertcoin-specific terms
-            if query_lower.contains("verthash") &&
-               (method.kind == VertcoinMethodKind::MiningMethod ||
+            // Boost mining entries when the query names the coin's proof-of-work algorithm
+            if query_lower.contains(&pow) &&
+               (method.kind == BitcoindMethodKind::MiningMethod ||
                 method.name.contains("mining") ||
-                method.description.to_lowercase().contains("verthash")) {
+                desc_lower.contains(&pow)) {
                 score += 15;
             }
 
@@ -1002,13 +901,13 @@ impl VertcoinClient {
         }
 
         // Sort by score (highest first)
-        scored_results.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
-        // Convert to VertcoinMethod
-        let results: Vec<VertcoinMethod> = scored_results
+        // Convert to BitcoindMethod
+        let results: Vec<BitcoindMethod> = scored_results
             .into_iter()
             .take(20)
-            .map(|(_, m)| self.build_method_doc(m))
+            .map(|(_, m)| self.build_method_doc(coin, m))
             .collect();
 
         Ok(results)
@@ -1019,18 +918,135 @@ impl VertcoinClient {
     }
 }
 
+/// Title and rendered description of a category for one coin
+fn category_summary(coin: BitcoindCoin, category: &str) -> (String, String) {
+    if category == "specs" {
+        return (
+            "Specifications".to_string(),
+            format!("Core specifications and concepts of the {} network", coin.name()),
+        );
+    }
+    BITCOIND_CATEGORIES
+        .iter()
+        .find(|(id, ..)| *id == category)
+        .map(|(_, _, title, description)| (coin.render(title), coin.render(description)))
+        .unwrap_or_default()
+}
+
+fn category_url(coin: BitcoindCoin, category: &str) -> String {
+    match category {
+        "specs" => coin.specs_url().to_string(),
+        "mining" => coin.mining_url().to_string(),
+        _ => coin.rpc_docs_url(),
+    }
+}
+
+/// Get URL for a method
+fn method_url(coin: BitcoindCoin, method: &BitcoindMethodIndex) -> String {
+    match method.kind {
+        BitcoindMethodKind::Specification => coin.specs_url().to_string(),
+        BitcoindMethodKind::MiningMethod if coin == BitcoindCoin::Vertcoin => coin.mining_url().to_string(),
+        _ if coin == BitcoindCoin::Bitcoin => {
+            format!("https://developer.bitcoin.org/reference/rpc/{}.html", method.name)
+        }
+        _ => coin.rpc_docs_url(),
+    }
+}
+
+/// Resolve `coin/name` or a bare Vertcoin method name
+fn find_method(path: &str) -> Option<(BitcoindCoin, &'static BitcoindMethodIndex)> {
+    let (coin, name) = match path.split_once('/') {
+        Some((coin, name)) => (BitcoindCoin::from_slug(coin)?, name),
+        None => (BitcoindCoin::Vertcoin, path),
+    };
+    coin_methods(coin)
+        .into_iter()
+        .find(|m| m.name.eq_ignore_ascii_case(name))
+        .map(|m| (coin, m))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_client_creation() {
-        let _client = VertcoinClient::new();
+        let _client = BitcoindClient::new();
     }
 
     #[test]
     fn test_all_methods_count() {
-        let count = VertcoinClient::all_methods().count();
-        assert!(count > 50, "Expected at least 50 methods, got {}", count);
+        for coin in BitcoindCoin::ALL {
+            let count = coin_methods(coin).len();
+            assert!(count > 50, "Expected at least 50 {} methods, got {}", coin.name(), count);
+        }
+    }
+
+    #[test]
+    fn test_overlays() {
+        let bitcoin_blockchain = category_methods(BitcoindCoin::Bitcoin, "blockchain");
+        assert!(bitcoin_blockchain.iter().any(|m| m.name == "getdeploymentinfo"));
+        assert!(!category_methods(BitcoindCoin::Vertcoin, "blockchain").iter().any(|m| m.name == "getdeploymentinfo"));
+
+        let template = category_methods(BitcoindCoin::Vertcoin, "mining")
+            .into_iter()
+            .find(|m| m.name == "getblocktemplate")
+            .unwrap();
+        assert!(template.description.contains("verthash.dat"));
+
+        // Replacements keep the shared table's size
+        assert_eq!(
+            category_methods(BitcoindCoin::Litecoin, "wallet").len(),
+            category_methods(BitcoindCoin::Vertcoin, "wallet").len()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_method_renders_coin() {
+        let client = BitcoindClient::new();
+
+        let method = client.get_method("bitcoin/sendtoaddress").await.unwrap();
+        assert_eq!(method.coin, BitcoindCoin::Bitcoin);
+        assert!(method.description.contains("BTC"));
+        assert!(method.parameters[0].description.contains("bc1"));
+        assert!(method.examples[0].code.starts_with("bitcoin-cli"));
+
+        let method = client.get_method("getdifficulty").await.unwrap();
+        assert_eq!(method.coin, BitcoindCoin::Vertcoin);
+        assert!(method.description.contains("Verthash"));
+        assert!(!method.description.contains('{'));
+
+        let method = client.get_method("litecoin/getmininginfo").await.unwrap();
+        assert!(method.returns.unwrap().fields.iter().any(|f| f.description.contains("Scrypt")));
+
+        assert!(client.get_method("vertcoin/sendall").await.is_err());
+        assert!(client.get_method("dogecoin/getblock").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_categories() {
+        let client = BitcoindClient::new();
+        let technologies = client.get_technologies().await.unwrap();
+        assert_eq!(technologies.len(), BitcoindCoin::ALL.len() * LISTED_CATEGORIES.len());
+        assert!(technologies.iter().any(|t| t.identifier == "litecoin:specs"));
+
+        let legacy = client.get_category("wallet").await.unwrap();
+        assert_eq!(legacy.identifier, "vertcoin:wallet");
+        assert_eq!(legacy.items[0].path, legacy.items[0].name);
+
+        let bitcoin = client.get_category("bitcoin:wallet").await.unwrap();
+        assert!(bitcoin.items.iter().all(|item| item.path.starts_with("bitcoin/")));
+        assert!(client.get_category("bitcoin:verthash").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_search_by_coin() {
+        let client = BitcoindClient::new();
+        let results = client.search("mweb", BitcoindCoin::Litecoin).await.unwrap();
+        assert_eq!(results[0].name, "mweb");
+        assert!(client.search("mweb", BitcoindCoin::Bitcoin).await.unwrap().is_empty());
+
+        let results = client.search("verthash mining", BitcoindCoin::Vertcoin).await.unwrap();
+        assert!(results.iter().take(3).any(|m| m.name == "verthash"));
     }
 }
@@ -1,5 +1,5 @@
 pub mod client;
 pub mod types;
 
-pub use client::VertcoinClient;
+pub use client::BitcoindClient;
 pub use types::*;
@@ -0,0 +1,593 @@
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// BITCOIND RPC DOCUMENTATION ENGINE
+// ============================================================================
+//
+// Bitcoin Core, Litecoin Core and Vertcoin Core share the bitcoind JSON-RPC
+// interface. The method tables below are written once for all three coins;
+// descriptions use `{coin}`, `{ticker}` and `{pow}` placeholders that
+// `BitcoindCoin::render` fills in. Per-coin differences are overlays:
+//
+// - Overlay methods replace a shared entry with the same name (Verthash
+//   mining for Vertcoin, MWEB addresses for Litecoin) or add RPCs only one
+//   node ships (recent Bitcoin Core calls such as `getdeploymentinfo`).
+// - Specifications describe each coin's consensus rules and network.
+//
+// Network Parameters (mainnet):
+// - Bitcoin:  SHA-256d, RPC 8332, P2P 8333, bech32 bc1
+// - Litecoin: Scrypt, RPC 9332, P2P 9333, bech32 ltc1
+// - Vertcoin: Verthash, RPC 5888, P2P 5889, bech32 vtc1
+//
+// ============================================================================
+
+/// A coin whose node implements the bitcoind RPC interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitcoindCoin {
+    Bitcoin,
+    Litecoin,
+    /// Default for bare identifiers and method names, which predate Bitcoin and Litecoin support
+    #[default]
+    Vertcoin,
+}
+
+impl BitcoindCoin {
+    pub const ALL: [Self; 3] = [Self::Bitcoin, Self::Litecoin, Self::Vertcoin];
+
+    #[must_use]
+    pub const fn slug(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bitcoin",
+            Self::Litecoin => "litecoin",
+            Self::Vertcoin => "vertcoin",
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "Bitcoin",
+            Self::Litecoin => "Litecoin",
+            Self::Vertcoin => "Vertcoin",
+        }
+    }
+
+    #[must_use]
+    pub const fn ticker(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "BTC",
+            Self::Litecoin => "LTC",
+            Self::Vertcoin => "VTC",
+        }
+    }
+
+    /// Proof-of-work algorithm
+    #[must_use]
+    pub const fn pow(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "SHA-256d",
+            Self::Litecoin => "Scrypt",
+            Self::Vertcoin => "Verthash",
+        }
+    }
+
+    /// Command-line RPC client shipped with the node
+    #[must_use]
+    pub const fn cli(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bitcoin-cli",
+            Self::Litecoin => "litecoin-cli",
+            Self::Vertcoin => "vertcoin-cli",
+        }
+    }
+
+    /// Default mainnet RPC port
+    #[must_use]
+    pub const fn rpc_port(self) -> u16 {
+        match self {
+            Self::Bitcoin => 8332,
+            Self::Litecoin => 9332,
+            Self::Vertcoin => 5888,
+        }
+    }
+
+    /// Mainnet address prefixes: legacy, P2SH and bech32
+    #[must_use]
+    pub const fn address_prefixes(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Bitcoin => ("1", "3", "bc1"),
+            Self::Litecoin => ("L", "M", "ltc1"),
+            Self::Vertcoin => ("V", "3", "vtc1"),
+        }
+    }
+
+    /// GitHub repository of the node implementation
+    #[must_use]
+    pub const fn repository(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "bitcoin/bitcoin",
+            Self::Litecoin => "litecoin-project/litecoin",
+            Self::Vertcoin => "vertcoin-project/vertcoin-core",
+        }
+    }
+
+    /// Landing page for the RPC reference
+    #[must_use]
+    pub fn rpc_docs_url(self) -> String {
+        match self {
+            Self::Bitcoin => "https://developer.bitcoin.org/reference/rpc/".to_string(),
+            Self::Litecoin | Self::Vertcoin => {
+                format!("https://github.com/{}/blob/master/doc/JSON-RPC-interface.md", self.repository())
+            }
+        }
+    }
+
+    /// Page describing consensus rules and network parameters
+    #[must_use]
+    pub const fn specs_url(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "https://developer.bitcoin.org/devguide/block_chain.html",
+            Self::Litecoin => "https://litecoin.org",
+            Self::Vertcoin => "https://vertcoin.org/specs-explained/",
+        }
+    }
+
+    /// Page describing how to mine the coin
+    #[must_use]
+    pub const fn mining_url(self) -> &'static str {
+        match self {
+            Self::Bitcoin => "https://developer.bitcoin.org/devguide/mining.html",
+            Self::Litecoin => "https://litecoin.org",
+            Self::Vertcoin => "https://github.com/vertcoin-project/VertDocs/blob/master/docs/Mining/",
+        }
+    }
+
+    #[must_use]
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        match slug.to_ascii_lowercase().as_str() {
+            "bitcoin" | "btc" => Some(Self::Bitcoin),
+            "litecoin" | "ltc" => Some(Self::Litecoin),
+            "vertcoin" | "vtc" => Some(Self::Vertcoin),
+            _ => None,
+        }
+    }
+
+    /// Methods that replace or extend the shared tables for this coin
+    #[must_use]
+    pub const fn overlay(self) -> &'static [BitcoindMethodIndex] {
+        match self {
+            Self::Bitcoin => BITCOIN_OVERLAY_METHODS,
+            Self::Litecoin => LITECOIN_OVERLAY_METHODS,
+            Self::Vertcoin => VERTCOIN_OVERLAY_METHODS,
+        }
+    }
+
+    #[must_use]
+    pub const fn specifications(self) -> &'static [BitcoindMethodIndex] {
+        match self {
+            Self::Bitcoin => BITCOIN_SPECIFICATIONS,
+            Self::Litecoin => LITECOIN_SPECIFICATIONS,
+            Self::Vertcoin => VERTCOIN_SPECIFICATIONS,
+        }
+    }
+
+    /// Fill the `{coin}`, `{ticker}`, `{pow}`, `{cli}` and address prefix placeholders of a shared description
+    #[must_use]
+    pub fn render(self, text: &str) -> String {
+        if !text.contains('{') {
+            return text.to_string();
+        }
+        let (legacy, p2sh, bech32) = self.address_prefixes();
+        text.replace("{coin}", self.name())
+            .replace("{ticker}", self.ticker())
+            .replace("{pow}", self.pow())
+            .replace("{cli}", self.cli())
+            .replace("{legacy}", legacy)
+            .replace("{p2sh}", p2sh)
+            .replace("{bech32}", bech32)
+    }
+}
+
+/// Shared RPC categories in listing order: identifier suffix, shared table, title and description
+pub const BITCOIND_CATEGORIES: &[(&str, &[BitcoindMethodIndex], &str, &str)] = &[
+    ("blockchain", BITCOIND_BLOCKCHAIN_METHODS, "Blockchain RPC", "JSON-RPC methods for querying {coin} blockchain state, blocks, and transactions"),
+    ("wallet", BITCOIND_WALLET_METHODS, "Wallet RPC", "JSON-RPC methods for {coin} wallet management"),
+    ("mining", BITCOIND_MINING_METHODS, "Mining ({pow})", "Mining-related RPC methods for {pow} mining"),
+    ("network", BITCOIND_NETWORK_METHODS, "Network RPC", "P2P networking and node management methods"),
+    ("rawtransactions", BITCOIND_RAWTRANSACTION_METHODS, "Raw Transactions", "Methods for creating and signing raw transactions and PSBTs"),
+    ("control", BITCOIND_CONTROL_METHODS, "Control", "Node control and management methods"),
+    ("util", BITCOIND_UTIL_METHODS, "Utilities", "Utility methods for address validation, fee estimation, and signatures"),
+];
+
+/// Parse a category identifier (`bitcoin:wallet`, `vertcoin:mining`) into its coin and category.
+/// Bare categories (`wallet`) are Vertcoin's.
+#[must_use]
+pub fn parse_category_identifier(identifier: &str) -> Option<(BitcoindCoin, &'static str)> {
+    let (coin, category) = match identifier.split_once(':') {
+        Some((coin, category)) => (BitcoindCoin::from_slug(coin)?, category),
+        None => (BitcoindCoin::Vertcoin, identifier),
+    };
+    let category = match category.to_ascii_lowercase().as_str() {
+        "blockchain" => "blockchain",
+        "wallet" => "wallet",
+        "mining" => "mining",
+        "verthash" if coin == BitcoindCoin::Vertcoin => "mining",
+        "network" => "network",
+        "rawtransactions" | "raw" => "rawtransactions",
+        "control" => "control",
+        "util" => "util",
+        "specs" | "specifications" => "specs",
+        _ => return None,
+    };
+    Some((coin, category))
+}
+
+/// Methods of one category for a coin: the shared table with overlay replacements applied,
+/// followed by the coin's extra methods. `specs` returns the coin's specifications.
+#[must_use]
+pub fn category_methods(coin: BitcoindCoin, category: &str) -> Vec<&'static BitcoindMethodIndex> {
+    if category == "specs" {
+        return coin.specifications().iter().collect();
+    }
+    let Some((_, shared, _, _)) = BITCOIND_CATEGORIES.iter().find(|(id, ..)| *id == category) else {
+        return Vec::new();
+    };
+    let overlay = coin.overlay();
+    let mut methods: Vec<&'static BitcoindMethodIndex> = shared
+        .iter()
+        .map(|method| overlay.iter().find(|o| o.name == method.name).unwrap_or(method))
+        .collect();
+    methods.extend(
+        overlay
+            .iter()
+            .filter(|o| o.category == category && !shared.iter().any(|method| method.name == o.name)),
+    );
+    methods
+}
+
+/// Every method and specification documented for a coin
+#[must_use]
+pub fn coin_methods(coin: BitcoindCoin) -> Vec<&'static BitcoindMethodIndex> {
+    BITCOIND_CATEGORIES
+        .iter()
+        .flat_map(|(category, ..)| category_methods(coin, category))
+        .chain(coin.specifications())
+        .collect()
+}
+
+/// Path used to fetch a method: bare names are Vertcoin's, other coins use `coin/name`
+#[must_use]
+pub fn method_path(coin: BitcoindCoin, name: &str) -> String {
+    match coin {
+        BitcoindCoin::Vertcoin => name.to_string(),
+        _ => format!("{}/{name}", coin.slug()),
+    }
+}
+
+/// A documented RPC category of one coin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindTechnology {
+    pub identifier: String,
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub item_count: usize,
+}
+
+/// Category of bitcoind documentation (RPC, Wallet, Mining) for one coin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindCategory {
+    pub identifier: String,
+    pub title: String,
+    pub description: String,
+    pub items: Vec<BitcoindCategoryItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindCategoryItem {
+    pub name: String,
+    pub description: String,
+    pub kind: BitcoindMethodKind,
+    pub url: String,
+    /// Path accepted by `BitcoindClient::get_method` (`getblock`, `bitcoin/getblock`)
+    #[serde(default)]
+    pub path: String,
+}
+
+/// Kind of bitcoind documentation item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BitcoindMethodKind {
+    /// JSON-RPC method for blockchain operations
+    RpcMethod,
+    /// Wallet-related RPC method (gRPC/legacy)
+    WalletMethod,
+    /// Mining-related documentation
+    MiningMethod,
+    /// General specification or concept
+    Specification,
+}
+
+impl std::fmt::Display for BitcoindMethodKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RpcMethod => write!(f, "RPC Method"),
+            Self::WalletMethod => write!(f, "Wallet Method"),
+            Self::MiningMethod => write!(f, "Mining"),
+            Self::Specification => write!(f, "Specification"),
+        }
+    }
+}
+
+/// Detailed method documentation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindMethod {
+    pub name: String,
+    #[serde(default)]
+    pub coin: BitcoindCoin,
+    pub description: String,
+    pub kind: BitcoindMethodKind,
+    pub url: String,
+    pub parameters: Vec<BitcoindParameter>,
+    pub returns: Option<BitcoindReturnType>,
+    pub examples: Vec<BitcoindExample>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindParameter {
+    pub name: String,
+    pub param_type: String,
+    pub required: bool,
+    pub description: String,
+    pub default_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindReturnType {
+    pub type_name: String,
+    pub description: String,
+    pub fields: Vec<BitcoindReturnField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindReturnField {
+    pub name: String,
+    pub field_type: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindExample {
+    pub language: String,
+    pub code: String,
+    pub description: Option<String>,
+}
+
+/// Static method index entry. Descriptions may contain `BitcoindCoin::render` placeholders.
+#[derive(Debug, Clone)]
+pub struct BitcoindMethodIndex {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub kind: BitcoindMethodKind,
+    pub category: &'static str,
+}
+
+// ============================================================================
+// SHARED BITCOIND RPC METHODS
+// Common to Bitcoin Core and its Litecoin and Vertcoin forks
+// ============================================================================
+
+/// Blockchain-related RPC methods
+pub const BITCOIND_BLOCKCHAIN_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getbestblockhash", description: "Returns the hash of the best (tip) block in the longest blockchain", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblock", description: "Returns block data for the specified block hash with varying verbosity", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblockchaininfo", description: "Returns blockchain state info including chain, blocks, headers, bestblockhash, difficulty, and verification progress", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblockcount", description: "Returns the number of blocks in the longest blockchain", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblockhash", description: "Returns hash of block at the specified height in the longest blockchain", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblockheader", description: "Returns information about a block header", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getchaintips", description: "Returns information about all known tips in the blockchain, including main chain and orphaned branches", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getdifficulty", description: "Returns the current {pow} mining difficulty as a multiple of minimum difficulty", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getmempoolancestors", description: "Returns all in-mempool ancestors of a transaction if it is in the mempool", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getmempooldescendants", description: "Returns all in-mempool descendants of a transaction if it is in the mempool", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getmempoolentry", description: "Returns mempool data for given transaction in the mempool", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getmempoolinfo", description: "Returns details on the active state of the TX memory pool", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getrawmempool", description: "Returns all transaction IDs in the memory pool", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "gettxout", description: "Returns details about an unspent transaction output", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "gettxoutproof", description: "Returns a hex-encoded proof that a transaction was included in a block", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "gettxoutsetinfo", description: "Returns statistics about the unspent transaction output set", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "preciousblock", description: "Treats a block as if it were received before others with the same work", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "pruneblockchain", description: "Prunes the blockchain up to the specified height or timestamp", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "verifychain", description: "Verifies blockchain database integrity", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "verifytxoutproof", description: "Verifies that a proof points to a transaction in a block", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+];
+
+/// Control RPC methods
+pub const BITCOIND_CONTROL_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getmemoryinfo", description: "Returns information about memory usage by the node", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+    BitcoindMethodIndex { name: "getrpcinfo", description: "Returns details of the RPC server", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+    BitcoindMethodIndex { name: "help", description: "Lists all commands or gets help for a specified command", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+    BitcoindMethodIndex { name: "logging", description: "Gets and sets the logging configuration", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+    BitcoindMethodIndex { name: "stop", description: "Safely stops the {coin} server", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+    BitcoindMethodIndex { name: "uptime", description: "Returns the total uptime of the server in seconds", kind: BitcoindMethodKind::RpcMethod, category: "control" },
+];
+
+/// Mining RPC methods
+pub const BITCOIND_MINING_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getblocktemplate", description: "Returns data needed to construct a block for {pow} mining", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+    BitcoindMethodIndex { name: "getmininginfo", description: "Returns mining-related information including difficulty, networkhashps, and pooledtx", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+    BitcoindMethodIndex { name: "getnetworkhashps", description: "Returns the estimated network hashes per second for {pow}", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+    BitcoindMethodIndex { name: "prioritisetransaction", description: "Accepts a transaction into the memory pool with a priority/fee delta", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+    BitcoindMethodIndex { name: "submitblock", description: "Submits a new block to the network after mining", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+    BitcoindMethodIndex { name: "submitheader", description: "Decodes and submits the given hexdata as a header to the chain", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+];
+
+/// Network RPC methods
+pub const BITCOIND_NETWORK_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "addnode", description: "Attempts to add or remove a node from the addnode list, or try a connection once", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "clearbanned", description: "Clears all banned IPs", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "disconnectnode", description: "Disconnects from a specified peer node", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getaddednodeinfo", description: "Returns information about nodes added using addnode", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getconnectioncount", description: "Returns the number of connections to other nodes", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getnettotals", description: "Returns information about network traffic, including total bytes received and sent", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getnetworkinfo", description: "Returns various state info regarding P2P networking", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getnodeaddresses", description: "Returns known addresses for potential peer connections", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "getpeerinfo", description: "Returns data about each connected network node", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "listbanned", description: "Lists all banned IPs/Subnets", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "ping", description: "Requests a ping be sent to all other nodes to measure latency", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "setban", description: "Adds or removes an IP/Subnet from the banned list", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+    BitcoindMethodIndex { name: "setnetworkactive", description: "Enables or disables all P2P network activity", kind: BitcoindMethodKind::RpcMethod, category: "network" },
+];
+
+/// Raw transaction RPC methods
+pub const BITCOIND_RAWTRANSACTION_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "combinepsbt", description: "Combines multiple partially signed {coin} transactions into one", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "combinerawtransaction", description: "Combines multiple partially signed transactions into one", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "converttopsbt", description: "Converts a network serialized transaction to a PSBT", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "createpsbt", description: "Creates a PSBT with the given inputs and outputs", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "createrawtransaction", description: "Creates a transaction spending given inputs and creating new outputs", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "decodepsbt", description: "Returns a JSON object representing the serialized, base64-encoded PSBT", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "decoderawtransaction", description: "Returns a JSON object representing the serialized, hex-encoded transaction", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "decodescript", description: "Decodes a hex-encoded script", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "finalizepsbt", description: "Finalizes the inputs of a PSBT", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "fundrawtransaction", description: "Adds inputs to a transaction until it has enough value to meet its out value", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "getrawtransaction", description: "Returns the raw transaction data for a given transaction ID", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "sendrawtransaction", description: "Submits a raw transaction to the network", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "signrawtransactionwithkey", description: "Signs inputs for a raw transaction using provided private keys", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+    BitcoindMethodIndex { name: "testmempoolaccept", description: "Tests whether raw transactions would be accepted by mempool", kind: BitcoindMethodKind::RpcMethod, category: "rawtransactions" },
+];
+
+/// Wallet RPC methods
+pub const BITCOIND_WALLET_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "abandontransaction", description: "Marks an in-wallet transaction as abandoned, allowing its inputs to be respent", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "abortrescan", description: "Stops the current wallet rescan", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "addmultisigaddress", description: "Adds a multisignature address to the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "backupwallet", description: "Safely copies the wallet file to the destination path", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "bumpfee", description: "Bumps the fee of a transaction, replacing it with a new transaction", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "createwallet", description: "Creates and loads a new wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "dumpprivkey", description: "Reveals the private key corresponding to a {coin} address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "dumpwallet", description: "Dumps all wallet keys in a human-readable format to a file", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "encryptwallet", description: "Encrypts the wallet with a passphrase", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getaddressesbylabel", description: "Returns the list of addresses assigned to a label", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getaddressinfo", description: "Returns information about a {coin} address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getbalance", description: "Returns the total available balance in the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getbalances", description: "Returns an object with all balances in {ticker}", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getnewaddress", description: "Returns a new {coin} address for receiving payments", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getrawchangeaddress", description: "Returns a new address for receiving change", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getreceivedbyaddress", description: "Returns the total amount received by an address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getreceivedbylabel", description: "Returns the total amount received by addresses with a specific label", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "gettransaction", description: "Returns detailed information about an in-wallet transaction", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getunconfirmedbalance", description: "Returns the server's total unconfirmed balance", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "getwalletinfo", description: "Returns information about the loaded wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importaddress", description: "Adds an address to watch for incoming transactions", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importmulti", description: "Imports addresses/scripts with rescan support", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importprivkey", description: "Imports a private key and optionally rescans the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importprunedfunds", description: "Imports funds without rescan", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importpubkey", description: "Adds a public key to watch for incoming transactions", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "importwallet", description: "Imports keys from a wallet dump file", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "keypoolrefill", description: "Fills the keypool with new keys", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listaddressgroupings", description: "Lists groups of addresses with common ownership", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listlabels", description: "Returns a list of all labels in the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listlockunspent", description: "Returns a list of temporarily unspendable outputs", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listreceivedbyaddress", description: "Lists balances by receiving address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listreceivedbylabel", description: "Lists received transactions grouped by label", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listsinceblock", description: "Returns all transactions since a specific block", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listtransactions", description: "Returns up to 'count' most recent transactions", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listunspent", description: "Returns array of unspent transaction outputs", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listwalletdir", description: "Returns a list of wallets in the wallet directory", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "listwallets", description: "Returns a list of currently loaded wallets", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "loadwallet", description: "Loads a wallet from a wallet file or directory", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "lockunspent", description: "Temporarily locks or unlocks specified transaction outputs", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "removeprunedfunds", description: "Deletes the specified transaction from the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "rescanblockchain", description: "Rescans the blockchain for wallet transactions", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "sendmany", description: "Sends {ticker} to multiple addresses in a single transaction", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "sendtoaddress", description: "Sends {ticker} to a given address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "sethdseed", description: "Sets the HD seed for the wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "setlabel", description: "Sets the label associated with an address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "settxfee", description: "Sets the transaction fee per kB", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "setwalletflag", description: "Changes the state of wallet flags", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "signmessage", description: "Signs a message with the private key of an address", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "signrawtransactionwithwallet", description: "Signs inputs for a raw transaction using wallet keys", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "unloadwallet", description: "Unloads a wallet", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "walletcreatefundedpsbt", description: "Creates and funds a transaction in PSBT format", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "walletlock", description: "Removes the wallet encryption key from memory", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "walletpassphrase", description: "Unlocks the wallet for the specified time", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "walletpassphrasechange", description: "Changes the wallet passphrase", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+    BitcoindMethodIndex { name: "walletprocesspsbt", description: "Updates a PSBT with input information and signs inputs", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+];
+
+/// Utility RPC methods
+pub const BITCOIND_UTIL_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "createmultisig", description: "Creates a multi-signature address with n signatures of m keys required", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "deriveaddresses", description: "Derives one or more addresses from a descriptor", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "estimatesmartfee", description: "Estimates the fee per kilobyte for a transaction to be confirmed within a target number of blocks", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "getdescriptorinfo", description: "Analyses a descriptor", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "signmessagewithprivkey", description: "Signs a message with a private key", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "validateaddress", description: "Returns information about a given {coin} address", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+    BitcoindMethodIndex { name: "verifymessage", description: "Verifies a signed message", kind: BitcoindMethodKind::RpcMethod, category: "util" },
+];
+
+// ============================================================================
+// VERTCOIN SPECIFICATIONS
+// ============================================================================
+
+/// Vertcoin network specifications and concepts
+pub const VERTCOIN_SPECIFICATIONS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "verthash", description: "Verthash is Vertcoin's GPU-optimized, ASIC-resistant mining algorithm. It requires a 1.2GB verthash.dat file and is memory-bound for fair GPU mining.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "block-time", description: "Vertcoin has a 2.5 minute block time (same as Litecoin), allowing faster confirmations than Bitcoin's 10 minutes.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "total-supply", description: "Maximum supply of 84,000,000 VTC (same as Litecoin), with a halving every 4 years.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "difficulty-adjustment", description: "Difficulty adjusts every block using Kimoto Gravity Well (KGW) algorithm, allowing rapid response to hashrate changes.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "segwit", description: "Vertcoin activated Segregated Witness (SegWit) on May 7, 2017, enabling faster transactions and Lightning Network compatibility.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "no-premine", description: "Vertcoin had no premine, no ICO, and no airdrop - all coins are distributed through fair mining.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "asic-resistance", description: "Vertcoin is committed to ASIC resistance to ensure mining remains accessible to individuals with consumer GPUs.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "one-click-miner", description: "Vertcoin provides One Click Miner (OCM), a user-friendly application for easy GPU mining setup without command-line knowledge.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "p2pool", description: "Vertcoin supports P2Pool decentralized mining pools, allowing miners to mine without trusting a central pool operator.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "lightning-network", description: "With SegWit support, Vertcoin is compatible with the Lightning Network for instant, low-fee micropayments.", kind: BitcoindMethodKind::Specification, category: "specs" },
+];
+
+// ============================================================================
+// PER-COIN OVERLAYS
+// ============================================================================
+
+/// Bitcoin Core RPCs added after the Litecoin and Vertcoin forks
+pub const BITCOIN_OVERLAY_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getdeploymentinfo", description: "Returns the state of soft fork deployments such as Taproot at the current or a given block", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "getblockfrompeer", description: "Asks a specific peer for a block the node has the header of but not the data, e.g. after pruning", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "scanblocks", description: "Returns block hashes whose compact block filters match the given descriptors", kind: BitcoindMethodKind::RpcMethod, category: "blockchain" },
+    BitcoindMethodIndex { name: "sendall", description: "Spends the whole wallet balance, or the given inputs, to one or more recipients without change", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+];
+
+/// Litecoin Core differences: MimbleWimble Extension Block (MWEB) addresses
+pub const LITECOIN_OVERLAY_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getnewaddress", description: "Returns a new Litecoin address for receiving payments; address_type \"mweb\" returns a confidential MWEB address (ltcmweb1...)", kind: BitcoindMethodKind::WalletMethod, category: "wallet" },
+];
+
+/// Vertcoin Core differences: mining uses Verthash and its verthash.dat file
+pub const VERTCOIN_OVERLAY_METHODS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "getblocktemplate", description: "Returns data needed to construct a block for Verthash mining; miners hash it against the 1.2GB verthash.dat file", kind: BitcoindMethodKind::MiningMethod, category: "mining" },
+];
+
+// ============================================================================
+// BITCOIN AND LITECOIN SPECIFICATIONS
+// ============================================================================
+
+/// Bitcoin network specifications and concepts
+pub const BITCOIN_SPECIFICATIONS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "sha256d", description: "Bitcoin is mined with double SHA-256 (SHA-256d) proof of work, dominated by ASIC hardware.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "block-time", description: "Bitcoin targets a 10 minute block time.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "total-supply", description: "Maximum supply of 21,000,000 BTC, with the block subsidy halving every 210,000 blocks (about 4 years).", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "difficulty-adjustment", description: "Difficulty retargets every 2016 blocks (about two weeks) to keep the 10 minute block time.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "segwit", description: "Bitcoin activated Segregated Witness (SegWit) on August 24, 2017, fixing transaction malleability and enabling bech32 (bc1...) addresses.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "taproot", description: "Taproot activated in November 2021, adding Schnorr signatures, Tapscript and bech32m (bc1p...) addresses.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "lightning-network", description: "The Lightning Network is a layer-2 payment channel network on Bitcoin for instant, low-fee payments.", kind: BitcoindMethodKind::Specification, category: "specs" },
+];
+
+/// Litecoin network specifications and concepts
+pub const LITECOIN_SPECIFICATIONS: &[BitcoindMethodIndex] = &[
+    BitcoindMethodIndex { name: "scrypt", description: "Litecoin is mined with Scrypt proof of work and supports merged mining with Dogecoin.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "block-time", description: "Litecoin has a 2.5 minute block time, four times faster than Bitcoin's 10 minutes.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "total-supply", description: "Maximum supply of 84,000,000 LTC, with the block subsidy halving every 840,000 blocks (about 4 years).", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "difficulty-adjustment", description: "Difficulty retargets every 2016 blocks (about 3.5 days).", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "segwit", description: "Litecoin activated Segregated Witness (SegWit) in May 2017, ahead of Bitcoin, enabling bech32 (ltc1...) addresses.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "mweb", description: "The MimbleWimble Extension Block (MWEB), activated in May 2022, adds opt-in confidential transactions with ltcmweb1... addresses.", kind: BitcoindMethodKind::Specification, category: "specs" },
+    BitcoindMethodIndex { name: "lightning-network", description: "With SegWit support, Litecoin is compatible with the Lightning Network for instant, low-fee micropayments.", kind: BitcoindMethodKind::Specification, category: "specs" },
+];
@@ -0,0 +1,283 @@
+//! Agent SDK version history from the `CHANGELOG.md` of each SDK repository.
+//!
+//! Releases are parsed from the markdown headings (`## 0.1.0` or `## [0.1.0] - 2025-09-29`);
+//! the oldest release mentioning an identifier is taken as the version that introduced it.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::AgentSdkLanguage;
+
+/// One SDK release from a changelog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSdkRelease {
+    /// Version number without a `v` prefix, e.g. `0.1.0`
+    pub version: String,
+    pub date: Option<String>,
+    pub url: String,
+    pub changes: Vec<String>,
+}
+
+/// Raw changelog for an SDK language
+#[must_use]
+pub const fn changelog_url(language: AgentSdkLanguage) -> &'static str {
+    match language {
+        AgentSdkLanguage::TypeScript => {
+            "https://raw.githubusercontent.com/anthropics/claude-agent-sdk-typescript/main/CHANGELOG.md"
+        }
+        AgentSdkLanguage::Python => {
+            "https://raw.githubusercontent.com/anthropics/claude-agent-sdk-python/main/CHANGELOG.md"
+        }
+    }
+}
+
+/// Rendered changelog on GitHub, for linking releases
+#[must_use]
+pub const fn changelog_page(language: AgentSdkLanguage) -> &'static str {
+    match language {
+        AgentSdkLanguage::TypeScript => "https://github.com/anthropics/claude-agent-sdk-typescript/blob/main/CHANGELOG.md",
+        AgentSdkLanguage::Python => "https://github.com/anthropics/claude-agent-sdk-python/blob/main/CHANGELOG.md",
+    }
+}
+
+/// `[major, minor, patch]` from "0.1.5", "v0.1.5" or "0.1.5-beta.1"; "0.1" and "0.1.x" give
+/// only the parts present, so they work as patterns
+#[must_use]
+pub fn parse_version(version: &str) -> Option<Vec<u32>> {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let core = version.split(['-', '+']).next().unwrap_or(version);
+    let mut parts = Vec::new();
+    for part in core.split('.') {
+        match part {
+            "x" | "X" | "*" => break,
+            part => parts.push(part.parse().ok()?),
+        }
+    }
+    (!parts.is_empty() && parts.len() <= 3).then_some(parts)
+}
+
+/// Whether `version` (e.g. "0.1.5") falls under `pattern` (e.g. "0.1.x" or "0.1.5")
+#[must_use]
+pub fn version_matches(version: &str, pattern: &str) -> bool {
+    match (parse_version(version), parse_version(pattern)) {
+        (Some(version), Some(pattern)) => version.starts_with(&pattern),
+        _ => false,
+    }
+}
+
+/// `(major, minor, patch)` for ordering releases, with missing parts as 0, so "0.1.x" sorts
+/// as 0.1.0 and an unparseable version as 0.0.0
+#[must_use]
+pub fn version_key(version: &str) -> (u32, u32, u32) {
+    let parts = parse_version(version).unwrap_or_default();
+    let part = |index: usize| parts.get(index).copied().unwrap_or(0);
+    (part(0), part(1), part(2))
+}
+
+/// Parse a changelog into releases, newest first as in the file
+#[must_use]
+pub fn parse_changelog(markdown: &str, page_url: &str) -> Vec<AgentSdkRelease> {
+    let mut releases: Vec<AgentSdkRelease> = Vec::new();
+    let mut in_code = false;
+    // Notes under non-release headings such as "## Unreleased" are skipped
+    let mut in_release = false;
+    // Whether the previous line continues into this one (wrapped bullet or paragraph)
+    let mut continues = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continues = false;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            if let Some((version, date)) = release_heading(heading) {
+                releases.push(AgentSdkRelease {
+                    version,
+                    date,
+                    url: format!("{page_url}#{}", github_anchor(heading)),
+                    changes: Vec::new(),
+                });
+                in_release = true;
+            } else {
+                in_release = false;
+            }
+            continues = false;
+            continue;
+        }
+        let Some(release) = releases.last_mut().filter(|_| in_release) else {
+            continue;
+        };
+
+        if trimmed.is_empty() {
+            continues = false;
+        } else if let Some(section) = trimmed.strip_prefix("### ").or_else(|| trimmed.strip_prefix("#### ")) {
+            release.changes.push(clean(section));
+            continues = false;
+        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            release.changes.push(clean(item));
+            continues = true;
+        } else if continues {
+            if let Some(last) = release.changes.last_mut() {
+                last.push(' ');
+                last.push_str(&clean(trimmed));
+            }
+        } else {
+            release.changes.push(clean(trimmed));
+            continues = true;
+        }
+    }
+
+    releases
+}
+
+/// The oldest release whose notes mention `name`, taken as the release that introduced it.
+///
+/// Only identifier-like names (`PreToolUse`, `max_turns`, `@tool`) are looked up; plain
+/// words such as "query" or "hooks" appear in too many notes to date anything.
+#[must_use]
+pub fn introduced_in<'a>(releases: &'a [AgentSdkRelease], name: &str) -> Option<&'a AgentSdkRelease> {
+    let distinctive = name.chars().any(|c| c.is_ascii_uppercase() || c == '_' || c == '@');
+    if !distinctive {
+        return None;
+    }
+    releases
+        .iter()
+        .filter(|release| release.changes.iter().any(|change| mentions(change, name)))
+        .min_by_key(|release| version_key(&release.version))
+}
+
+/// Whether `text` contains `name` as a whole identifier
+fn mentions(text: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(name).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Version and date from "0.1.0", "v0.1.0 (2025-09-29)" or "[0.1.0] - 2025-09-29"
+fn release_heading(heading: &str) -> Option<(String, Option<String>)> {
+    let mut words = heading.split_whitespace();
+    let version = words.next()?.trim_matches(['[', ']']).trim_start_matches(['v', 'V']);
+    parse_version(version)?;
+    let date = words
+        .map(|word| word.trim_matches(['(', ')', '-', '–']))
+        .find(|word| word.len() == 10 && word.chars().filter(|c| *c == '-').count() == 2)
+        .map(str::to_string);
+    Some((version.to_string(), date))
+}
+
+/// GitHub's heading anchor: lowercase, punctuation dropped, spaces as hyphens
+fn github_anchor(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+fn clean(text: &str) -> String {
+    text.replace("**", "").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "https://github.com/anthropics/claude-agent-sdk-python/blob/main/CHANGELOG.md";
+
+    const CHANGELOG: &str = r"# Changelog
+
+## Unreleased
+- Work in progress
+
+## 0.1.3
+
+### Features
+- Added `UserPromptSubmit` and `SessionStart` hook events
+- Added the `max_budget_usd` option to cap spend
+  per session
+
+## [0.1.0] - 2025-09-29
+
+Introduced Claude Agent SDK!
+
+### Breaking Changes
+- **Type rename**: `ClaudeCodeOptions` is now `ClaudeAgentOptions`
+
+```python
+options = ClaudeAgentOptions(max_turns=2)
+```
+
+## 0.0.20
+- Added `PreToolUse` hooks
+";
+
+    #[test]
+    fn test_parse_changelog() {
+        let releases = parse_changelog(CHANGELOG, PAGE);
+        assert_eq!(releases.len(), 3);
+
+        assert_eq!(releases[0].version, "0.1.3");
+        assert_eq!(releases[0].date, None);
+        assert_eq!(releases[0].url, format!("{PAGE}#013"));
+        assert_eq!(
+            releases[0].changes,
+            [
+                "Features",
+                "Added `UserPromptSubmit` and `SessionStart` hook events",
+                "Added the `max_budget_usd` option to cap spend per session",
+            ]
+        );
+
+        assert_eq!(releases[1].version, "0.1.0");
+        assert_eq!(releases[1].date.as_deref(), Some("2025-09-29"));
+        assert_eq!(releases[1].url, format!("{PAGE}#010---2025-09-29"));
+        assert_eq!(
+            releases[1].changes,
+            [
+                "Introduced Claude Agent SDK!",
+                "Breaking Changes",
+                "Type rename: `ClaudeCodeOptions` is now `ClaudeAgentOptions`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_introduced_in() {
+        let releases = parse_changelog(CHANGELOG, PAGE);
+        let version = |name| introduced_in(&releases, name).map(|release| release.version.as_str());
+
+        assert_eq!(version("PreToolUse"), Some("0.0.20"));
+        assert_eq!(version("SessionStart"), Some("0.1.3"));
+        assert_eq!(version("max_budget_usd"), Some("0.1.3"));
+        assert_eq!(version("ClaudeAgentOptions"), Some("0.1.0"));
+        // Not a whole-identifier match, and plain words are never dated
+        assert_eq!(version("PreTool"), None);
+        assert_eq!(version("hook"), None);
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v0.1.5"), Some(vec![0, 1, 5]));
+        assert_eq!(parse_version("0.2.0-beta.1"), Some(vec![0, 2, 0]));
+        assert_eq!(parse_version("0.1.x"), Some(vec![0, 1]));
+        assert_eq!(parse_version("latest"), None);
+
+        assert!(version_matches("0.1.5", "0.1.x"));
+        assert!(version_matches("0.1.5", "v0.1.5"));
+        assert!(!version_matches("0.1.15", "0.1.1"));
+        assert!(version_key("0.1.10") > version_key("0.1.9"));
+    }
+}
@@ -4,31 +4,43 @@
 //! enabling AI agents to search and retrieve SDK reference information.
 
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use reqwest::Client;
 use scraper::{Html, Selector};
+use time::{Duration, OffsetDateTime};
 use tracing::{debug, instrument, warn};
 
+use super::changelog::{changelog_page, changelog_url, parse_changelog, AgentSdkRelease};
 use super::types::{
     AgentSdkArticle, AgentSdkCategory, AgentSdkCategoryItem, AgentSdkExample,
     AgentSdkItemKind, AgentSdkLanguage, AgentSdkParameter, AgentSdkSearchResult,
     AgentSdkTechnology, COMMON_SDK_CONCEPTS, PYTHON_SDK_TOPICS, TYPESCRIPT_SDK_TOPICS,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+use docs_mcp_client::stale;
+
+use crate::credentials::github_get;
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const DOCS_BASE_URL: &str = "https://docs.anthropic.com/en/docs/agents-and-tools/claude-agent-sdk";
 const TYPESCRIPT_GITHUB: &str = "https://github.com/anthropics/claude-agent-sdk-typescript";
 const PYTHON_GITHUB: &str = "https://github.com/anthropics/claude-agent-sdk-python";
+/// Release notes are fetched again after this long so new SDK releases show up
+const CHANGELOG_MAX_AGE: Duration = Duration::hours(12);
 
 #[derive(Debug)]
 pub struct ClaudeAgentSdkClient {
     http: Client,
     disk_cache: DiskCache,
     #[allow(dead_code)]
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     cache_dir: PathBuf,
 }
 
@@ -49,9 +61,8 @@ impl ClaudeAgentSdkClient {
             warn!(error = %e, "Failed to create Claude Agent SDK cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::ClaudeAgentSdk))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -59,7 +70,7 @@ impl ClaudeAgentSdkClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(24)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::ClaudeAgentSdk, memory_ttl(ProviderType::ClaudeAgentSdk)),
             cache_dir,
         }
     }
@@ -211,7 +222,7 @@ impl ClaudeAgentSdkClient {
         }
 
         // Sort by score descending
-        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
         results.truncate(20);
 
         Ok(results)
@@ -326,7 +337,8 @@ impl ClaudeAgentSdkClient {
     async fn fetch_docs_page(&self, url: &str) -> Result<String> {
         debug!(url = %url, "Fetching Claude Agent SDK documentation");
 
-        let response = self.http.get(url).send().await;
+        ensure_online(url)?;
+        let response = github_get(&self.http, url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -909,6 +921,55 @@ async with ClaudeSDKClient(options=options) as client:
         }
     }
 
+    /// Fetch an SDK's release notes, newest first. The cached copy is used for
+    /// [`CHANGELOG_MAX_AGE`] after it was fetched, and served stale when refetching fails.
+    #[instrument(name = "agent_sdk_client.get_changelog", skip(self))]
+    pub async fn get_changelog(&self, language: AgentSdkLanguage) -> Result<Vec<AgentSdkRelease>> {
+        let cache_key = format!("changelog_{}.json", language.to_string().to_lowercase());
+        let cached = self.disk_cache.load::<Vec<AgentSdkRelease>>(&cache_key).await.ok().flatten();
+        if let Some(entry) = &cached {
+            if OffsetDateTime::now_utc() - entry.stored_at <= CHANGELOG_MAX_AGE {
+                debug!(%language, "Agent SDK changelog served from disk cache");
+                return Ok(entry.value.clone());
+            }
+        }
+
+        match self.fetch_changelog(language).await {
+            Ok(releases) => {
+                self.disk_cache.store(&cache_key, releases.clone()).await?;
+                Ok(releases)
+            }
+            Err(error) => match cached {
+                Some(entry) => {
+                    stale::record(cache_key, &error);
+                    Ok(entry.value)
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    async fn fetch_changelog(&self, language: AgentSdkLanguage) -> Result<Vec<AgentSdkRelease>> {
+        let url = changelog_url(language);
+        debug!(url, "Fetching Claude Agent SDK changelog");
+        ensure_online(url)?;
+        let response = github_get(&self.http, url)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch Claude Agent SDK changelog")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Claude Agent SDK changelog fetch failed: {}", response.status());
+        }
+
+        let markdown = response.text().await.context("Failed to read Claude Agent SDK changelog")?;
+        let releases = parse_changelog(&markdown, changelog_page(language));
+        if releases.is_empty() {
+            anyhow::bail!("Claude Agent SDK changelog contained no releases");
+        }
+        Ok(releases)
+    }
+
     pub fn cache_dir(&self) -> &PathBuf {
         &self.cache_dir
     }
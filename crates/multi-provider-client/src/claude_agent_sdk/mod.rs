@@ -1,10 +1,13 @@
 //! Claude Agent SDK documentation client module.
 //!
 //! Provides access to Claude Agent SDK documentation for building AI agents
-//! with Claude Code capabilities in TypeScript/Node.js and Python.
+//! with Claude Code capabilities in TypeScript/Node.js and Python, plus each
+//! SDK's release history for telling which version introduced an option.
 
+pub mod changelog;
 pub mod client;
 pub mod types;
 
+pub use changelog::AgentSdkRelease;
 pub use client::ClaudeAgentSdkClient;
 pub use types::*;
@@ -1,29 +1,44 @@
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use reqwest::Client;
+use serde_json::Value;
+use time::{Duration, OffsetDateTime};
 use tokio::sync::Mutex;
 use tracing::{debug, instrument};
 
 use super::types::{
     extract_markdown_summary, extract_markdown_title, CocoonDocument, CocoonDocumentSummary,
-    CocoonSection, CocoonTechnology, GitHubContent, COCOON_SECTIONS,
+    CocoonSection, CocoonSnapshot, CocoonTechnology, GitHubContent, COCOON_SECTIONS,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+use docs_mcp_client::stale;
+
+use crate::credentials::github_get;
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const GITHUB_API_BASE: &str = "https://api.github.com/repos/TelegramMessenger/cocoon/contents";
 const RAW_CONTENT_BASE: &str =
     "https://raw.githubusercontent.com/TelegramMessenger/cocoon/master";
+const DOCS_COMMITS_URL: &str =
+    "https://api.github.com/repos/TelegramMessenger/cocoon/commits?path=docs&per_page=1";
+const SNAPSHOT_CACHE_KEY: &str = "snapshot.json";
+/// How long the cached docs are served before upstream is checked for new commits
+const SYNC_INTERVAL: Duration = Duration::days(1);
 
 #[derive(Debug)]
 pub struct CocoonClient {
     http: Client,
     disk_cache: DiskCache,
     #[allow(dead_code)]
-    memory_cache: MemoryCache<Vec<u8>>,
-    #[allow(dead_code)]
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
+    /// Serialises upstream syncs so concurrent requests don't download the docs twice
     contents_lock: Mutex<()>,
     cache_dir: PathBuf,
 }
@@ -45,9 +60,8 @@ impl CocoonClient {
             tracing::warn!(error = %e, "Failed to create Cocoon cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Cocoon))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -55,7 +69,7 @@ impl CocoonClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::minutes(30)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Cocoon, memory_ttl(ProviderType::Cocoon)),
             contents_lock: Mutex::new(()),
             cache_dir,
         }
@@ -64,23 +78,24 @@ impl CocoonClient {
     /// List contents of a directory in the Cocoon repo
     #[instrument(name = "cocoon_client.list_contents", skip(self))]
     async fn list_contents(&self, path: &str) -> Result<Vec<GitHubContent>> {
-        let cache_key = format!("contents_{}.json", path.replace('/', "_"));
-
         // Check disk cache
-        if let Ok(Some(entry)) = self.disk_cache.load::<Vec<GitHubContent>>(&cache_key).await {
+        if let Ok(Some(entry)) = self.disk_cache.load::<Vec<GitHubContent>>(&contents_cache_key(path)).await {
             debug!(path, "Cocoon contents served from disk cache");
             return Ok(entry.value);
         }
 
-        // Fetch from GitHub API
+        self.download_contents(path).await
+    }
+
+    /// List a directory from the GitHub API, replacing the cached listing
+    async fn download_contents(&self, path: &str) -> Result<Vec<GitHubContent>> {
         let url = format!("{GITHUB_API_BASE}/{path}");
         debug!(url = url, "Fetching Cocoon contents");
 
-        let response = self
-            .http
-            .get(&url)
+        ensure_online(&url)?;
+        let response = github_get(&self.http, &url)
             .header("Accept", "application/vnd.github.v3+json")
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch Cocoon contents")?;
 
@@ -94,7 +109,7 @@ impl CocoonClient {
             .context("Failed to parse GitHub contents")?;
 
         // Store in cache
-        self.disk_cache.store(&cache_key, contents.clone()).await?;
+        self.disk_cache.store(&contents_cache_key(path), contents.clone()).await?;
 
         Ok(contents)
     }
@@ -102,21 +117,23 @@ impl CocoonClient {
     /// Fetch raw file content
     #[instrument(name = "cocoon_client.fetch_file", skip(self))]
     async fn fetch_file(&self, path: &str) -> Result<String> {
-        let cache_key = format!("file_{}.txt", path.replace('/', "_"));
-
         // Check disk cache
-        if let Ok(Some(entry)) = self.disk_cache.load::<String>(&cache_key).await {
+        if let Ok(Some(entry)) = self.disk_cache.load::<String>(&file_cache_key(path)).await {
             debug!(path, "Cocoon file served from disk cache");
             return Ok(entry.value);
         }
 
+        self.download_file(path).await
+    }
+
+    /// Download a raw file, replacing the cached copy
+    async fn download_file(&self, path: &str) -> Result<String> {
         let url = format!("{RAW_CONTENT_BASE}/{path}");
         debug!(url = url, "Fetching Cocoon file");
 
-        let response = self
-            .http
-            .get(&url)
-            .send()
+        ensure_online(&url)?;
+        let response = github_get(&self.http, &url)
+            .send_with_retry()
             .await
             .context("Failed to fetch Cocoon file")?;
 
@@ -130,14 +147,111 @@ impl CocoonClient {
             .context("Failed to read file content")?;
 
         // Store in cache
-        self.disk_cache.store(&cache_key, content.clone()).await?;
+        self.disk_cache.store(&file_cache_key(path), content.clone()).await?;
 
         Ok(content)
     }
 
+    /// Latest commit touching `docs/` as (sha, commit date)
+    async fn latest_docs_commit(&self) -> Result<(String, Option<String>)> {
+        ensure_online(DOCS_COMMITS_URL)?;
+        let response = github_get(&self.http, DOCS_COMMITS_URL)
+            .header("Accept", "application/vnd.github.v3+json")
+            .send_with_retry()
+            .await
+            .context("Failed to fetch Cocoon commits")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("GitHub API request failed: {}", response.status());
+        }
+
+        let commits: Value = response.json().await.context("Failed to parse Cocoon commits")?;
+        parse_latest_commit(&commits).context("Cocoon commit list was empty")
+    }
+
+    /// The snapshot the cached docs were downloaded at, without going to the network
+    pub async fn snapshot(&self) -> Result<Option<CocoonSnapshot>> {
+        Ok(self.disk_cache.load::<CocoonSnapshot>(SNAPSHOT_CACHE_KEY).await?.map(|entry| entry.value))
+    }
+
+    /// Download the docs again at the latest upstream commit, dropping cached files that were
+    /// removed upstream
+    #[instrument(name = "cocoon_client.refresh", skip(self))]
+    pub async fn refresh(&self) -> Result<CocoonSnapshot> {
+        let _lock = self.contents_lock.lock().await;
+        let (commit, committed_at) = self.latest_docs_commit().await?;
+        self.download_snapshot(commit, committed_at).await
+    }
+
+    async fn download_snapshot(&self, commit: String, committed_at: Option<String>) -> Result<CocoonSnapshot> {
+        let previous = self
+            .disk_cache
+            .load::<Vec<GitHubContent>>(&contents_cache_key("docs"))
+            .await
+            .ok()
+            .flatten()
+            .map(|entry| entry.value)
+            .unwrap_or_default();
+        let contents = self.download_contents("docs").await?;
+        let documents: Vec<&GitHubContent> = contents.iter().filter(|item| is_markdown_file(item)).collect();
+        for item in &documents {
+            self.download_file(&item.path).await?;
+        }
+        for removed in previous.iter().filter(|old| !contents.iter().any(|item| item.path == old.path)) {
+            let path = self.cache_dir.join(file_cache_key(&removed.path));
+            if let Err(e) = tokio::fs::remove_file(&path).await {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    tracing::warn!(error = %e, path = %path.display(), "Failed to drop removed Cocoon doc");
+                }
+            }
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let snapshot = CocoonSnapshot {
+            commit,
+            committed_at,
+            fetched_at: now,
+            checked_at: now,
+            document_count: documents.len(),
+        };
+        self.disk_cache.store(SNAPSHOT_CACHE_KEY, snapshot.clone()).await?;
+        debug!(commit = %snapshot.commit, documents = snapshot.document_count, "Cocoon docs refreshed");
+        Ok(snapshot)
+    }
+
+    /// Re-download the docs when upstream has moved on since the last daily check.
+    /// Failures (e.g. offline) leave the cached docs in place.
+    async fn sync_if_due(&self) {
+        let now = OffsetDateTime::now_utc();
+        if !sync_due(self.snapshot().await.ok().flatten().as_ref(), now) {
+            return;
+        }
+        let _lock = self.contents_lock.lock().await;
+        let snapshot = self.snapshot().await.ok().flatten();
+        if !sync_due(snapshot.as_ref(), now) {
+            return;
+        }
+
+        let cached = snapshot.is_some();
+        let result = match (self.latest_docs_commit().await, snapshot) {
+            (Ok((commit, _)), Some(snapshot)) if snapshot.commit == commit => {
+                self.disk_cache.store(SNAPSHOT_CACHE_KEY, CocoonSnapshot { checked_at: now, ..snapshot }).await
+            }
+            (Ok((commit, committed_at)), _) => self.download_snapshot(commit, committed_at).await.map(|_| ()),
+            (Err(e), _) => Err(e),
+        };
+        match result {
+            Err(e) if cached => stale::record(SNAPSHOT_CACHE_KEY, &e),
+            Err(e) => debug!(error = %e, "Cocoon upstream sync failed"),
+            Ok(()) => {}
+        }
+    }
+
     /// Get available technologies (documentation sections)
     #[instrument(name = "cocoon_client.get_technologies", skip(self))]
     pub async fn get_technologies(&self) -> Result<Vec<CocoonTechnology>> {
+        self.sync_if_due().await;
+
         // Try to list the docs directory to get actual counts
         let doc_counts = match self.list_contents("docs").await {
             Ok(contents) => {
@@ -166,6 +280,8 @@ impl CocoonClient {
     /// Get documents in a section
     #[instrument(name = "cocoon_client.get_section", skip(self))]
     pub async fn get_section(&self, identifier: &str) -> Result<CocoonSection> {
+        self.sync_if_due().await;
+
         // Extract section ID from identifier (e.g., "cocoon:architecture" -> "architecture")
         let section_id = identifier
             .strip_prefix("cocoon:")
@@ -214,6 +330,7 @@ impl CocoonClient {
     /// Get a specific document
     #[instrument(name = "cocoon_client.get_document", skip(self))]
     pub async fn get_document(&self, path: &str) -> Result<CocoonDocument> {
+        self.sync_if_due().await;
         let content = self.fetch_file(path).await?;
 
         let title = extract_markdown_title(&content);
@@ -242,6 +359,8 @@ impl CocoonClient {
     /// Search for documents matching a query
     #[instrument(name = "cocoon_client.search", skip(self))]
     pub async fn search(&self, query: &str) -> Result<Vec<CocoonDocumentSummary>> {
+        self.sync_if_due().await;
+
         let query_lower = query.to_lowercase();
         let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
         let mut results = Vec::new();
@@ -251,11 +370,7 @@ impl CocoonClient {
 
         for item in contents {
             // Only process markdown files
-            if item.content_type != "file"
-                || !std::path::Path::new(&item.name)
-                    .extension()
-                    .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
-            {
+            if !is_markdown_file(&item) {
                 continue;
             }
 
@@ -301,6 +416,37 @@ impl CocoonClient {
     }
 }
 
+fn contents_cache_key(path: &str) -> String {
+    format!("contents_{}.json", path.replace('/', "_"))
+}
+
+fn file_cache_key(path: &str) -> String {
+    format!("file_{}.txt", path.replace('/', "_"))
+}
+
+fn is_markdown_file(item: &GitHubContent) -> bool {
+    item.content_type == "file"
+        && std::path::Path::new(&item.name)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+}
+
+/// (sha, commit date) of the first entry of a GitHub commits listing
+fn parse_latest_commit(commits: &Value) -> Option<(String, Option<String>)> {
+    let commit = commits.as_array()?.first()?;
+    let sha = commit.get("sha")?.as_str()?.to_string();
+    let date = commit
+        .pointer("/commit/committer/date")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    Some((sha, date))
+}
+
+/// Whether upstream should be checked: never synced, or last checked over a day ago
+fn sync_due(snapshot: Option<&CocoonSnapshot>, now: OffsetDateTime) -> bool {
+    snapshot.map_or(true, |snapshot| now - snapshot.checked_at >= SYNC_INTERVAL)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +456,34 @@ mod tests {
         let _client = CocoonClient::new();
     }
 
+    #[test]
+    fn test_parse_latest_commit() {
+        let commits = serde_json::json!([{
+            "sha": "1a2b3c4d5e6f",
+            "commit": {"committer": {"date": "2025-11-28T09:15:00Z"}}
+        }]);
+        let (sha, date) = parse_latest_commit(&commits).unwrap();
+        assert_eq!(sha, "1a2b3c4d5e6f");
+        assert_eq!(date.as_deref(), Some("2025-11-28T09:15:00Z"));
+        assert!(parse_latest_commit(&serde_json::json!([])).is_none());
+    }
+
+    #[test]
+    fn test_sync_due_and_label() {
+        let now = OffsetDateTime::now_utc();
+        let snapshot = CocoonSnapshot {
+            commit: "1a2b3c4d5e6f".to_string(),
+            committed_at: Some("2025-11-28T09:15:00Z".to_string()),
+            fetched_at: now - Duration::days(3),
+            checked_at: now - Duration::hours(2),
+            document_count: 12,
+        };
+        assert!(sync_due(None, now));
+        assert!(!sync_due(Some(&snapshot), now));
+        assert!(sync_due(Some(&snapshot), now + Duration::days(1)));
+        assert_eq!(snapshot.label(), "docs as of 2025-11-28 (commit 1a2b3c4)");
+    }
+
     #[test]
     fn test_markdown_extraction() {
         let content = "# Test Title\n\nThis is the first paragraph.\n\nThis is the second.";
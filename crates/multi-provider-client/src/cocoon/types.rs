@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
 /// GitHub contents API response
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +54,33 @@ pub struct CocoonDocument {
     pub url: String,
 }
 
+/// Upstream state of the cached Cocoon docs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CocoonSnapshot {
+    /// Latest commit touching `docs/` when the docs were downloaded
+    pub commit: String,
+    /// Commit date as reported by GitHub (RFC 3339)
+    pub committed_at: Option<String>,
+    /// When the cached documents were downloaded
+    pub fetched_at: OffsetDateTime,
+    /// When upstream was last compared against `commit`
+    pub checked_at: OffsetDateTime,
+    pub document_count: usize,
+}
+
+impl CocoonSnapshot {
+    /// Short description for results, e.g. "docs as of 2025-11-28 (commit 1a2b3c4)"
+    #[must_use]
+    pub fn label(&self) -> String {
+        let short = self.commit.get(..7).unwrap_or(&self.commit);
+        match self.committed_at.as_deref().and_then(|date| date.get(..10)) {
+            Some(date) => format!("docs as of {date} (commit {short})"),
+            None => format!("docs at commit {short}"),
+        }
+    }
+}
+
 /// Known Cocoon documentation sections
 pub const COCOON_SECTIONS: &[(&str, &str, &str)] = &[
     (
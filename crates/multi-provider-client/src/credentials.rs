@@ -0,0 +1,120 @@
+//! Process-wide API tokens for providers that support authenticated requests.
+//!
+//! Like the other network settings, tokens should be registered before
+//! [`crate::ProviderClients`] is built: the Hugging Face client reads its token
+//! at construction, while [`github_get`] looks the GitHub token up per request.
+
+use std::fmt;
+use std::sync::RwLock;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::{Client, RequestBuilder, Url};
+use tracing::warn;
+
+/// Hosts that accept a GitHub token.
+const GITHUB_HOSTS: [&str; 3] = ["github.com", "api.github.com", "raw.githubusercontent.com"];
+
+/// An API token that never appears in `Debug` output or logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiToken(String);
+
+impl ApiToken {
+    /// `None` for empty or whitespace-only values.
+    pub fn new(token: impl Into<String>) -> Option<Self> {
+        let token = token.into().trim().to_string();
+        (!token.is_empty()).then_some(Self(token))
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Default headers carrying `Authorization: Bearer <token>`, marked sensitive.
+    pub fn bearer_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        match HeaderValue::from_str(&format!("Bearer {}", self.0)) {
+            Ok(mut value) => {
+                value.set_sensitive(true);
+                headers.insert(AUTHORIZATION, value);
+            }
+            Err(_) => warn!("API token contains characters not allowed in a header; ignoring it"),
+        }
+        headers
+    }
+}
+
+impl fmt::Debug for ApiToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ApiToken(***)")
+    }
+}
+
+static HUGGINGFACE: RwLock<Option<ApiToken>> = RwLock::new(None);
+static GITHUB: RwLock<Option<ApiToken>> = RwLock::new(None);
+
+/// Token sent with Hugging Face Hub and docs requests.
+pub fn set_huggingface_token(token: Option<ApiToken>) {
+    *HUGGINGFACE.write().unwrap_or_else(std::sync::PoisonError::into_inner) = token;
+}
+
+pub fn huggingface_token() -> Option<ApiToken> {
+    HUGGINGFACE
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Token sent with requests to GitHub, lifting the anonymous rate limit.
+pub fn set_github_token(token: Option<ApiToken>) {
+    *GITHUB.write().unwrap_or_else(std::sync::PoisonError::into_inner) = token;
+}
+
+pub fn github_token() -> Option<ApiToken> {
+    GITHUB.read().unwrap_or_else(std::sync::PoisonError::into_inner).clone()
+}
+
+/// `client.get(url)`, authenticated with the GitHub token when `url` points at GitHub.
+///
+/// The token is never sent to other hosts, so this is safe for any URL.
+pub fn github_get(client: &Client, url: &str) -> RequestBuilder {
+    let request = client.get(url);
+    match github_token() {
+        Some(token) if is_github_url(url) => request.headers(token.bearer_headers()),
+        _ => request,
+    }
+}
+
+fn is_github_url(url: &str) -> bool {
+    Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| GITHUB_HOSTS.contains(&host)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_are_redacted_and_sent_as_bearer() {
+        assert!(ApiToken::new("  ").is_none());
+
+        let token = ApiToken::new(" hf_secret ").unwrap();
+        assert_eq!(token.expose(), "hf_secret");
+        assert_eq!(format!("{token:?}"), "ApiToken(***)");
+
+        let headers = token.bearer_headers();
+        let value = &headers[AUTHORIZATION];
+        assert_eq!(value, "Bearer hf_secret");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    fn github_token_only_goes_to_github() {
+        assert!(is_github_url("https://raw.githubusercontent.com/org/repo/main/api.json"));
+        assert!(is_github_url("https://api.github.com/repos/org/repo/contents"));
+        assert!(!is_github_url("https://docs.rs/serde"));
+        assert!(!is_github_url("https://raw.githubusercontent.com.evil.example/x"));
+        assert!(!is_github_url("not a url"));
+    }
+}
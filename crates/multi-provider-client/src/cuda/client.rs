@@ -1,7 +1,6 @@
 #![allow(clippy::needless_raw_string_hashes)]
 
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::Result;
 use directories::ProjectDirs;
@@ -16,20 +15,28 @@ use super::types::{
     CUDA_MEMORY_METHODS, CUDA_DEVICE_METHODS, CUDA_EXECUTION_METHODS,
     CUDA_STREAM_METHODS, CUDA_EVENT_METHODS, CUDA_ERROR_METHODS,
     CUDA_KERNEL_CONSTRUCTS, CUDA_LIBRARY_METHODS, CUDA_GPU_SPECS,
-    CUDA_OPTIMIZATION_METHODS,
+    CUDA_OPTIMIZATION_METHODS, CUBLAS_METHODS, CUDNN_METHODS, CUFFT_METHODS,
+    THRUST_METHODS, CUB_METHODS,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const CUDA_DOCS_URL: &str = "https://docs.nvidia.com/cuda";
 const CUDA_RUNTIME_API_URL: &str = "https://docs.nvidia.com/cuda/cuda-runtime-api";
 const CUDA_PROGRAMMING_GUIDE_URL: &str = "https://docs.nvidia.com/cuda/cuda-c-programming-guide";
+const THRUST_DOCS_URL: &str = "https://nvidia.github.io/cccl/thrust";
+const CUB_DOCS_URL: &str = "https://nvidia.github.io/cccl/cub";
 
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct CudaClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<String>,
+    memory_cache: ProviderMemoryCache<String>,
     fetch_lock: Mutex<()>,
     cache_dir: PathBuf,
 }
@@ -51,9 +58,8 @@ impl CudaClient {
             warn!(error = %e, "Failed to create CUDA cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Cuda))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -61,7 +67,7 @@ impl CudaClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(1)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Cuda, memory_ttl(ProviderType::Cuda)),
             fetch_lock: Mutex::new(()),
             cache_dir,
         }
@@ -94,15 +100,71 @@ impl CudaClient {
             item_count: CUDA_KERNEL_CONSTRUCTS.len(),
         };
 
+        let library_count = CUBLAS_METHODS.len() + CUDNN_METHODS.len() + CUFFT_METHODS.len() + CUDA_LIBRARY_METHODS.len();
         let libraries = CudaTechnology {
             identifier: "cuda:libraries".to_string(),
             title: "CUDA Libraries".to_string(),
             description: format!(
-                "CUDA Libraries - {} functions from cuBLAS, cuDNN, cuFFT, cuRAND, and NCCL",
-                CUDA_LIBRARY_METHODS.len()
+                "CUDA Libraries - {} functions, descriptors, and enums from cuBLAS, cuDNN, cuFFT, cuRAND, and NCCL",
+                library_count
             ),
             url: format!("{}/libraries", CUDA_DOCS_URL),
-            item_count: CUDA_LIBRARY_METHODS.len(),
+            item_count: library_count,
+        };
+
+        let cublas = CudaTechnology {
+            identifier: "cuda:cublas".to_string(),
+            title: "cuBLAS".to_string(),
+            description: format!(
+                "cuBLAS - {} GEMM, BLAS Level 1-3 and cuBLASLt functions, handles, and enums",
+                CUBLAS_METHODS.len()
+            ),
+            url: "https://docs.nvidia.com/cuda/cublas/index.html".to_string(),
+            item_count: CUBLAS_METHODS.len(),
+        };
+
+        let cudnn = CudaTechnology {
+            identifier: "cuda:cudnn".to_string(),
+            title: "cuDNN".to_string(),
+            description: format!(
+                "cuDNN - {} convolution, activation, pooling, normalization and Graph API functions, descriptors, and enums",
+                CUDNN_METHODS.len()
+            ),
+            url: "https://docs.nvidia.com/deeplearning/cudnn/index.html".to_string(),
+            item_count: CUDNN_METHODS.len(),
+        };
+
+        let cufft = CudaTechnology {
+            identifier: "cuda:cufft".to_string(),
+            title: "cuFFT".to_string(),
+            description: format!(
+                "cuFFT - {} plan, execution and work area functions, types, and enums",
+                CUFFT_METHODS.len()
+            ),
+            url: "https://docs.nvidia.com/cuda/cufft/index.html".to_string(),
+            item_count: CUFFT_METHODS.len(),
+        };
+
+        let thrust = CudaTechnology {
+            identifier: "cuda:thrust".to_string(),
+            title: "Thrust".to_string(),
+            description: format!(
+                "Thrust - {} containers and parallel algorithms (device_vector, transform, reduce, scan, sort) with C++ template declarations",
+                THRUST_METHODS.len()
+            ),
+            url: format!("{}/index.html", THRUST_DOCS_URL),
+            item_count: THRUST_METHODS.len(),
+        };
+
+        let cub = CudaTechnology {
+            identifier: "cuda:cub".to_string(),
+            title: "CUB".to_string(),
+            description: format!(
+                "CUB - {} device-wide, block-level and warp-level primitives with C++ template declarations",
+                CUB_METHODS.len()
+            ),
+            url: format!("{}/index.html", CUB_DOCS_URL),
+            item_count: CUB_METHODS.len(),
         };
 
         let gpu_specs = CudaTechnology {
@@ -127,7 +189,9 @@ impl CudaClient {
             item_count: CUDA_OPTIMIZATION_METHODS.len(),
         };
 
-        Ok(vec![runtime_api, kernel_programming, libraries, gpu_specs, optimization])
+        Ok(vec![
+            runtime_api, kernel_programming, libraries, cublas, cudnn, cufft, thrust, cub, gpu_specs, optimization,
+        ])
     }
 
     /// Get a category of methods
@@ -180,25 +244,39 @@ impl CudaClient {
                 "Kernel constructs, memory qualifiers, thread indexing, and synchronization primitives",
             ),
             "cuda:libraries" | "libraries" | "libs" => (
-                CUDA_LIBRARY_METHODS.iter().collect(),
+                CUBLAS_METHODS.iter()
+                    .chain(CUDNN_METHODS.iter())
+                    .chain(CUFFT_METHODS.iter())
+                    .chain(CUDA_LIBRARY_METHODS.iter())
+                    .collect(),
                 "CUDA Libraries",
-                "cuBLAS, cuDNN, cuFFT, cuRAND, and NCCL functions",
+                "cuBLAS, cuDNN, cuFFT, cuRAND, and NCCL functions, descriptors, and enums",
             ),
             "cuda:cublas" | "cublas" | "blas" => (
-                CUDA_LIBRARY_METHODS.iter().filter(|m| m.category == "cublas").collect(),
+                CUBLAS_METHODS.iter().collect(),
                 "cuBLAS",
                 "CUDA Basic Linear Algebra Subroutines for matrix operations",
             ),
             "cuda:cudnn" | "cudnn" | "dnn" => (
-                CUDA_LIBRARY_METHODS.iter().filter(|m| m.category == "cudnn").collect(),
+                CUDNN_METHODS.iter().collect(),
                 "cuDNN",
                 "CUDA Deep Neural Network library for convolutions, activations, and more",
             ),
             "cuda:cufft" | "cufft" | "fft" => (
-                CUDA_LIBRARY_METHODS.iter().filter(|m| m.category == "cufft").collect(),
+                CUFFT_METHODS.iter().collect(),
                 "cuFFT",
                 "CUDA Fast Fourier Transform library",
             ),
+            "cuda:thrust" | "thrust" => (
+                THRUST_METHODS.iter().collect(),
+                "Thrust",
+                "C++ template library of device containers and parallel algorithms",
+            ),
+            "cuda:cub" | "cub" => (
+                CUB_METHODS.iter().collect(),
+                "CUB",
+                "Device-wide, block-level and warp-level parallel primitives",
+            ),
             "cuda:curand" | "curand" | "random" => (
                 CUDA_LIBRARY_METHODS.iter().filter(|m| m.category == "curand").collect(),
                 "cuRAND",
@@ -252,7 +330,7 @@ impl CudaClient {
             CudaMethodKind::KernelConstruct => {
                 format!("{}/index.html#programming-model", CUDA_PROGRAMMING_GUIDE_URL)
             }
-            CudaMethodKind::Library => {
+            CudaMethodKind::Library | CudaMethodKind::Descriptor | CudaMethodKind::Enum => {
                 match method.category {
                     "cublas" => "https://docs.nvidia.com/cuda/cublas/index.html".to_string(),
                     "cudnn" => "https://docs.nvidia.com/deeplearning/cudnn/index.html".to_string(),
@@ -262,6 +340,10 @@ impl CudaClient {
                     _ => format!("{}/libraries", CUDA_DOCS_URL),
                 }
             }
+            CudaMethodKind::Container | CudaMethodKind::Algorithm => {
+                let base = if method.category == "cub" { CUB_DOCS_URL } else { THRUST_DOCS_URL };
+                format!("{}/api_docs.html", base)
+            }
             CudaMethodKind::GpuSpec => {
                 "https://developer.nvidia.com/cuda-gpus".to_string()
             }
@@ -280,7 +362,12 @@ impl CudaClient {
             .chain(CUDA_EVENT_METHODS.iter())
             .chain(CUDA_ERROR_METHODS.iter())
             .chain(CUDA_KERNEL_CONSTRUCTS.iter())
+            .chain(CUBLAS_METHODS.iter())
+            .chain(CUDNN_METHODS.iter())
+            .chain(CUFFT_METHODS.iter())
             .chain(CUDA_LIBRARY_METHODS.iter())
+            .chain(THRUST_METHODS.iter())
+            .chain(CUB_METHODS.iter())
             .chain(CUDA_GPU_SPECS.iter())
             .chain(CUDA_OPTIMIZATION_METHODS.iter())
     }
@@ -298,6 +385,7 @@ impl CudaClient {
             parameters,
             returns: self.infer_return_type(index_entry),
             examples,
+            declaration: self.infer_declaration(index_entry).map(str::to_string),
         }
     }
 
@@ -550,6 +638,67 @@ cublasDestroy(handle);"#.to_string(),
                     description: Some("Matrix multiplication with cuBLAS".to_string()),
                 });
             }
+            "cudnnConvolutionForward" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"cudnnHandle_t handle;
+cudnnCreate(&handle);
+
+// Describe input (NCHW), filters and the convolution
+cudnnTensorDescriptor_t xDesc, yDesc;
+cudnnFilterDescriptor_t wDesc;
+cudnnConvolutionDescriptor_t convDesc;
+cudnnCreateTensorDescriptor(&xDesc);
+cudnnCreateTensorDescriptor(&yDesc);
+cudnnCreateFilterDescriptor(&wDesc);
+cudnnCreateConvolutionDescriptor(&convDesc);
+
+cudnnSetTensor4dDescriptor(xDesc, CUDNN_TENSOR_NCHW, CUDNN_DATA_FLOAT, n, c, h, w);
+cudnnSetFilter4dDescriptor(wDesc, CUDNN_DATA_FLOAT, CUDNN_TENSOR_NCHW, k, c, 3, 3);
+cudnnSetConvolution2dDescriptor(convDesc, 1, 1, 1, 1, 1, 1,
+                                CUDNN_CROSS_CORRELATION, CUDNN_DATA_FLOAT);
+cudnnSetConvolutionMathType(convDesc, CUDNN_TENSOR_OP_MATH);
+
+// Output shape
+int on, oc, oh, ow;
+cudnnGetConvolution2dForwardOutputDim(convDesc, xDesc, wDesc, &on, &oc, &oh, &ow);
+cudnnSetTensor4dDescriptor(yDesc, CUDNN_TENSOR_NCHW, CUDNN_DATA_FLOAT, on, oc, oh, ow);
+
+// Pick an algorithm and allocate its workspace
+cudnnConvolutionFwdAlgoPerf_t perf;
+int returned;
+cudnnGetConvolutionForwardAlgorithm_v7(handle, xDesc, wDesc, convDesc, yDesc, 1, &returned, &perf);
+size_t wsSize;
+cudnnGetConvolutionForwardWorkspaceSize(handle, xDesc, wDesc, convDesc, yDesc, perf.algo, &wsSize);
+void *d_ws;
+cudaMalloc(&d_ws, wsSize);
+
+float alpha = 1.0f, beta = 0.0f;
+cudnnConvolutionForward(handle, &alpha, xDesc, d_x, wDesc, d_w, convDesc,
+                        perf.algo, d_ws, wsSize, &beta, yDesc, d_y);"#.to_string(),
+                    description: Some("2D convolution forward pass with cuDNN descriptors".to_string()),
+                });
+            }
+            "cufftExecC2C" | "cufftPlan1d" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"cufftHandle plan;
+cufftComplex *d_signal;
+cudaMalloc(&d_signal, sizeof(cufftComplex) * NX * BATCH);
+
+// Plan BATCH 1D transforms of size NX (reuse the plan across calls)
+cufftPlan1d(&plan, NX, CUFFT_C2C, BATCH);
+
+// In-place forward then inverse transform
+cufftExecC2C(plan, d_signal, d_signal, CUFFT_FORWARD);
+cufftExecC2C(plan, d_signal, d_signal, CUFFT_INVERSE);
+// cuFFT is unnormalized: divide by NX to recover the input
+
+cufftDestroy(plan);
+cudaFree(d_signal);"#.to_string(),
+                    description: Some("Batched 1D complex FFT with cuFFT".to_string()),
+                });
+            }
 
             // Optimization Examples
             "grid_stride_loop" => {
@@ -688,6 +837,106 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
                 });
             }
 
+            // Thrust and CUB Examples
+            "thrust::device_vector" | "thrust::transform" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"#include <thrust/device_vector.h>
+#include <thrust/transform.h>
+
+struct saxpy {
+    const float a;
+    explicit saxpy(float a) : a(a) {}
+    __host__ __device__ float operator()(float x, float y) const { return a * x + y; }
+};
+
+thrust::device_vector<float> x(N, 1.0f);
+thrust::device_vector<float> y(N, 2.0f);
+
+// y = 2 * x + y, evaluated on the GPU
+thrust::transform(x.begin(), x.end(), y.begin(), y.begin(), saxpy(2.0f));
+
+// Copy back to the host in one transfer
+thrust::host_vector<float> result = y;"#.to_string(),
+                    description: Some("SAXPY with device_vector and transform".to_string()),
+                });
+            }
+            "thrust::reduce" | "thrust::transform_reduce" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"#include <thrust/device_vector.h>
+#include <thrust/reduce.h>
+#include <thrust/transform_reduce.h>
+#include <thrust/functional.h>
+
+thrust::device_vector<float> v(N, 0.5f);
+
+// Sum of all elements
+float sum = thrust::reduce(v.begin(), v.end(), 0.0f, thrust::plus<float>());
+
+// Sum of squares without a temporary array
+float sq = thrust::transform_reduce(
+    v.begin(), v.end(),
+    [] __device__ (float x) { return x * x; },  // requires nvcc --extended-lambda
+    0.0f, thrust::plus<float>());"#.to_string(),
+                    description: Some("Reductions returning a value to the host".to_string()),
+                });
+            }
+            "thrust::inclusive_scan" | "thrust::exclusive_scan" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"#include <thrust/device_vector.h>
+#include <thrust/scan.h>
+
+int data[6] = {1, 0, 2, 2, 1, 3};
+thrust::device_vector<int> d(data, data + 6);
+thrust::device_vector<int> out(6);
+
+thrust::inclusive_scan(d.begin(), d.end(), out.begin()); // {1, 1, 3, 5, 6, 9}
+thrust::exclusive_scan(d.begin(), d.end(), out.begin()); // {0, 1, 1, 3, 5, 6}"#.to_string(),
+                    description: Some("Inclusive and exclusive prefix sums".to_string()),
+                });
+            }
+            "cub::DeviceReduce::Sum" | "cub::DeviceScan::InclusiveSum" | "cub::DeviceScan::ExclusiveSum" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"#include <cub/cub.cuh>
+
+// d_in: int[num_items] on the device, d_out: int[1]
+void *d_temp_storage = nullptr;
+size_t temp_storage_bytes = 0;
+
+// First call only computes the temporary storage size
+cub::DeviceReduce::Sum(d_temp_storage, temp_storage_bytes, d_in, d_out, num_items);
+cudaMalloc(&d_temp_storage, temp_storage_bytes);
+
+// Second call runs the reduction
+cub::DeviceReduce::Sum(d_temp_storage, temp_storage_bytes, d_in, d_out, num_items);
+cudaFree(d_temp_storage);"#.to_string(),
+                    description: Some("Two-phase temporary storage pattern".to_string()),
+                });
+            }
+            "cub::BlockReduce" | "cub::BlockScan" | "cub::WarpReduce" => {
+                examples.push(CudaExample {
+                    language: "cuda".to_string(),
+                    code: r#"#include <cub/cub.cuh>
+
+__global__ void blockSum(const int *in, int *out) {
+    using BlockReduce = cub::BlockReduce<int, 128>;
+    __shared__ typename BlockReduce::TempStorage temp_storage;
+
+    int thread_data = in[blockIdx.x * 128 + threadIdx.x];
+    int aggregate = BlockReduce(temp_storage).Sum(thread_data);
+
+    // Only thread 0 holds the block aggregate
+    if (threadIdx.x == 0) out[blockIdx.x] = aggregate;
+}
+
+blockSum<<<num_blocks, 128>>>(d_in, d_out);"#.to_string(),
+                    description: Some("Block-wide reduction with shared TempStorage".to_string()),
+                });
+            }
+
             _ => {
                 // Generic example for other methods
                 match method.kind {
@@ -722,6 +971,96 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
     /// Infer parameters for a method based on common patterns
     fn infer_parameters(&self, method: &CudaMethodIndex) -> Vec<CudaParameter> {
         match method.name {
+            "cudnnSetTensor4dDescriptor" => vec![
+                CudaParameter {
+                    name: "tensorDesc".to_string(),
+                    param_type: "cudnnTensorDescriptor_t".to_string(),
+                    required: true,
+                    description: "Descriptor created with cudnnCreateTensorDescriptor".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "format".to_string(),
+                    param_type: "cudnnTensorFormat_t".to_string(),
+                    required: true,
+                    description: "Memory layout, e.g. CUDNN_TENSOR_NCHW or CUDNN_TENSOR_NHWC".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "dataType".to_string(),
+                    param_type: "cudnnDataType_t".to_string(),
+                    required: true,
+                    description: "Element type, e.g. CUDNN_DATA_FLOAT or CUDNN_DATA_HALF".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "n, c, h, w".to_string(),
+                    param_type: "int".to_string(),
+                    required: true,
+                    description: "Batch size, channels, height and width".to_string(),
+                    default_value: None,
+                },
+            ],
+            "cufftPlan1d" => vec![
+                CudaParameter {
+                    name: "plan".to_string(),
+                    param_type: "cufftHandle*".to_string(),
+                    required: true,
+                    description: "Receives the created plan".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "nx".to_string(),
+                    param_type: "int".to_string(),
+                    required: true,
+                    description: "Transform size".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "type".to_string(),
+                    param_type: "cufftType".to_string(),
+                    required: true,
+                    description: "Transform type, e.g. CUFFT_C2C or CUFFT_R2C".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "batch".to_string(),
+                    param_type: "int".to_string(),
+                    required: true,
+                    description: "Number of transforms of size nx".to_string(),
+                    default_value: None,
+                },
+            ],
+            "cufftExecC2C" => vec![
+                CudaParameter {
+                    name: "plan".to_string(),
+                    param_type: "cufftHandle".to_string(),
+                    required: true,
+                    description: "Plan created for CUFFT_C2C".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "idata".to_string(),
+                    param_type: "cufftComplex*".to_string(),
+                    required: true,
+                    description: "Input data in device memory".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "odata".to_string(),
+                    param_type: "cufftComplex*".to_string(),
+                    required: true,
+                    description: "Output data in device memory; may equal idata for in-place transforms".to_string(),
+                    default_value: None,
+                },
+                CudaParameter {
+                    name: "direction".to_string(),
+                    param_type: "int".to_string(),
+                    required: true,
+                    description: "CUFFT_FORWARD or CUFFT_INVERSE".to_string(),
+                    default_value: None,
+                },
+            ],
             "cudaMalloc" => vec![
                 CudaParameter {
                     name: "devPtr".to_string(),
@@ -948,6 +1287,41 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
         }
     }
 
+    /// C++ template declaration for Thrust and CUB APIs
+    fn infer_declaration(&self, method: &CudaMethodIndex) -> Option<&'static str> {
+        let declaration = match method.name {
+            "thrust::device_vector" => "template <typename T, typename Alloc = thrust::device_allocator<T>>\nclass device_vector;",
+            "thrust::host_vector" => "template <typename T, typename Alloc = std::allocator<T>>\nclass host_vector;",
+            "thrust::device_ptr" => "template <typename T>\nclass device_ptr;",
+            "thrust::counting_iterator" => "template <typename Incrementable, typename System = use_default,\n          typename Traversal = use_default, typename Difference = use_default>\nclass counting_iterator;",
+            "thrust::zip_iterator" => "template <typename IteratorTuple>\nclass zip_iterator;",
+            "thrust::transform" => "template <typename InputIterator1, typename InputIterator2,\n          typename OutputIterator, typename BinaryFunction>\nOutputIterator transform(InputIterator1 first1, InputIterator1 last1,\n                         InputIterator2 first2, OutputIterator result,\n                         BinaryFunction op);",
+            "thrust::reduce" => "template <typename InputIterator, typename T, typename BinaryFunction>\nT reduce(InputIterator first, InputIterator last, T init,\n         BinaryFunction binary_op);",
+            "thrust::transform_reduce" => "template <typename InputIterator, typename UnaryFunction,\n          typename OutputType, typename BinaryFunction>\nOutputType transform_reduce(InputIterator first, InputIterator last,\n                            UnaryFunction unary_op, OutputType init,\n                            BinaryFunction binary_op);",
+            "thrust::reduce_by_key" => "template <typename InputIterator1, typename InputIterator2,\n          typename OutputIterator1, typename OutputIterator2>\nthrust::pair<OutputIterator1, OutputIterator2>\nreduce_by_key(InputIterator1 keys_first, InputIterator1 keys_last,\n              InputIterator2 values_first,\n              OutputIterator1 keys_output, OutputIterator2 values_output);",
+            "thrust::inclusive_scan" => "template <typename InputIterator, typename OutputIterator,\n          typename AssociativeOperator>\nOutputIterator inclusive_scan(InputIterator first, InputIterator last,\n                              OutputIterator result,\n                              AssociativeOperator binary_op);",
+            "thrust::exclusive_scan" => "template <typename InputIterator, typename OutputIterator,\n          typename T, typename AssociativeOperator>\nOutputIterator exclusive_scan(InputIterator first, InputIterator last,\n                              OutputIterator result, T init,\n                              AssociativeOperator binary_op);",
+            "thrust::sort" => "template <typename RandomAccessIterator, typename StrictWeakOrdering>\nvoid sort(RandomAccessIterator first, RandomAccessIterator last,\n          StrictWeakOrdering comp);",
+            "thrust::sort_by_key" => "template <typename RandomAccessIterator1, typename RandomAccessIterator2>\nvoid sort_by_key(RandomAccessIterator1 keys_first,\n                 RandomAccessIterator1 keys_last,\n                 RandomAccessIterator2 values_first);",
+            "thrust::copy_if" => "template <typename InputIterator, typename OutputIterator, typename Predicate>\nOutputIterator copy_if(InputIterator first, InputIterator last,\n                       OutputIterator result, Predicate pred);",
+            "thrust::fill" => "template <typename ForwardIterator, typename T>\nvoid fill(ForwardIterator first, ForwardIterator last, const T &value);",
+            "thrust::sequence" => "template <typename ForwardIterator, typename T>\nvoid sequence(ForwardIterator first, ForwardIterator last, T init, T step);",
+            "thrust::device" => "static const thrust::detail::device_t thrust::device;",
+            "cub::DeviceReduce::Sum" => "template <typename InputIteratorT, typename OutputIteratorT, typename NumItemsT>\nstatic cudaError_t Sum(void *d_temp_storage, size_t &temp_storage_bytes,\n                       InputIteratorT d_in, OutputIteratorT d_out,\n                       NumItemsT num_items, cudaStream_t stream = 0);",
+            "cub::DeviceReduce::Reduce" => "template <typename InputIteratorT, typename OutputIteratorT,\n          typename ReductionOpT, typename T, typename NumItemsT>\nstatic cudaError_t Reduce(void *d_temp_storage, size_t &temp_storage_bytes,\n                          InputIteratorT d_in, OutputIteratorT d_out,\n                          NumItemsT num_items, ReductionOpT reduction_op,\n                          T init, cudaStream_t stream = 0);",
+            "cub::DeviceScan::InclusiveSum" => "template <typename InputIteratorT, typename OutputIteratorT, typename NumItemsT>\nstatic cudaError_t InclusiveSum(void *d_temp_storage, size_t &temp_storage_bytes,\n                                InputIteratorT d_in, OutputIteratorT d_out,\n                                NumItemsT num_items, cudaStream_t stream = 0);",
+            "cub::DeviceScan::ExclusiveSum" => "template <typename InputIteratorT, typename OutputIteratorT, typename NumItemsT>\nstatic cudaError_t ExclusiveSum(void *d_temp_storage, size_t &temp_storage_bytes,\n                                InputIteratorT d_in, OutputIteratorT d_out,\n                                NumItemsT num_items, cudaStream_t stream = 0);",
+            "cub::DeviceRadixSort::SortPairs" => "template <typename KeyT, typename ValueT, typename NumItemsT>\nstatic cudaError_t SortPairs(void *d_temp_storage, size_t &temp_storage_bytes,\n                             const KeyT *d_keys_in, KeyT *d_keys_out,\n                             const ValueT *d_values_in, ValueT *d_values_out,\n                             NumItemsT num_items, int begin_bit = 0,\n                             int end_bit = sizeof(KeyT) * 8, cudaStream_t stream = 0);",
+            "cub::DeviceSelect::If" => "template <typename InputIteratorT, typename OutputIteratorT,\n          typename NumSelectedIteratorT, typename SelectOp, typename NumItemsT>\nstatic cudaError_t If(void *d_temp_storage, size_t &temp_storage_bytes,\n                      InputIteratorT d_in, OutputIteratorT d_out,\n                      NumSelectedIteratorT d_num_selected_out,\n                      NumItemsT num_items, SelectOp select_op,\n                      cudaStream_t stream = 0);",
+            "cub::BlockReduce" => "template <typename T, int BLOCK_DIM_X,\n          BlockReduceAlgorithm ALGORITHM = BLOCK_REDUCE_WARP_REDUCTIONS,\n          int BLOCK_DIM_Y = 1, int BLOCK_DIM_Z = 1>\nclass BlockReduce;",
+            "cub::BlockScan" => "template <typename T, int BLOCK_DIM_X,\n          BlockScanAlgorithm ALGORITHM = BLOCK_SCAN_RAKING,\n          int BLOCK_DIM_Y = 1, int BLOCK_DIM_Z = 1>\nclass BlockScan;",
+            "cub::BlockLoad" => "template <typename T, int BLOCK_DIM_X, int ITEMS_PER_THREAD,\n          BlockLoadAlgorithm ALGORITHM = BLOCK_LOAD_DIRECT,\n          int BLOCK_DIM_Y = 1, int BLOCK_DIM_Z = 1>\nclass BlockLoad;",
+            "cub::WarpReduce" => "template <typename T, int LOGICAL_WARP_THREADS = 32>\nclass WarpReduce;",
+            _ => return None,
+        };
+        Some(declaration)
+    }
+
     /// Infer return type for a method
     fn infer_return_type(&self, method: &CudaMethodIndex) -> Option<CudaReturnType> {
         match method.kind {
@@ -998,6 +1372,22 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
                             },
                         ],
                     }),
+                    "cufft" => Some(CudaReturnType {
+                        type_name: "cufftResult".to_string(),
+                        description: "cuFFT status code".to_string(),
+                        fields: vec![
+                            CudaReturnField {
+                                name: "CUFFT_SUCCESS".to_string(),
+                                field_type: "0".to_string(),
+                                description: "Operation completed successfully".to_string(),
+                            },
+                            CudaReturnField {
+                                name: "CUFFT_INVALID_PLAN".to_string(),
+                                field_type: "1".to_string(),
+                                description: "The plan handle is invalid".to_string(),
+                            },
+                        ],
+                    }),
                     _ => None,
                 }
             }
@@ -1083,6 +1473,27 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
             if query_lower.contains("cudnn") && method.category == "cudnn" {
                 score += 20;
             }
+            if (query_lower.contains("cufft") || query_lower.contains("fft")) && method.category == "cufft" {
+                score += 20;
+            }
+
+            // Boost descriptors and enums when the query asks for types
+            if (query_lower.contains("descriptor") || query_lower.contains("handle"))
+                && method.kind == CudaMethodKind::Descriptor
+            {
+                score += 10;
+            }
+            if query_lower.contains("enum") && method.kind == CudaMethodKind::Enum {
+                score += 10;
+            }
+
+            // Boost Thrust and CUB entries when the library is named
+            if query_lower.contains("thrust") && method.category == "thrust" {
+                score += 20;
+            }
+            if query_lower.contains("cub") && !query_lower.contains("cublas") && method.category == "cub" {
+                score += 20;
+            }
 
             if score > 0 {
                 scored_results.push((score, method));
@@ -1090,7 +1501,7 @@ cublasGemmEx(handle, CUBLAS_OP_N, CUBLAS_OP_N,
         }
 
         // Sort by score (highest first)
-        scored_results.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         // Convert to CudaMethod
         let results: Vec<CudaMethod> = scored_results
@@ -1127,9 +1538,57 @@ mod tests {
         let count = CUDA_MEMORY_METHODS.len()
             + CUDA_DEVICE_METHODS.len()
             + CUDA_KERNEL_CONSTRUCTS.len()
+            + CUBLAS_METHODS.len()
+            + CUDNN_METHODS.len()
+            + CUFFT_METHODS.len()
             + CUDA_LIBRARY_METHODS.len()
             + CUDA_GPU_SPECS.len()
             + CUDA_OPTIMIZATION_METHODS.len();
         assert!(count > 80, "Expected comprehensive coverage, got {}", count);
     }
+
+    #[tokio::test]
+    async fn test_library_references() {
+        let client = CudaClient::new();
+
+        let cudnn = client.get_category("cuda:cudnn").await.unwrap();
+        assert!(cudnn.items.iter().any(|item| item.kind == CudaMethodKind::Descriptor));
+        assert!(cudnn.items.iter().any(|item| item.kind == CudaMethodKind::Enum));
+        assert!(cudnn.items.iter().all(|item| item.url.contains("cudnn")));
+
+        let method = client.get_method("cufftExecC2C").await.unwrap();
+        assert_eq!(method.returns.unwrap().type_name, "cufftResult");
+        assert_eq!(method.parameters.len(), 4);
+
+        let results = client.search("cudnn tensor descriptor").await.unwrap();
+        assert!(results.iter().take(3).any(|m| m.name == "cudnnTensorDescriptor_t"));
+
+        let results = client.search("cublasOperation_t").await.unwrap();
+        assert_eq!(results[0].name, "cublasOperation_t");
+    }
+
+    #[tokio::test]
+    async fn test_thrust_and_cub() {
+        let client = CudaClient::new();
+
+        let thrust = client.get_category("cuda:thrust").await.unwrap();
+        assert!(thrust.items.iter().any(|item| item.kind == CudaMethodKind::Container));
+        assert!(thrust.items.iter().all(|item| item.url.starts_with(THRUST_DOCS_URL)));
+
+        let method = client.get_method("thrust::reduce").await.unwrap();
+        let declaration = method.declaration.unwrap();
+        assert!(declaration.starts_with("template <typename InputIterator"));
+        assert!(declaration.contains("T reduce("));
+        assert!(!method.examples.is_empty());
+
+        let method = client.get_method("cub::DeviceScan::InclusiveSum").await.unwrap();
+        assert!(method.declaration.unwrap().contains("size_t &temp_storage_bytes"));
+        assert!(client.get_method("cudaMalloc").await.unwrap().declaration.is_none());
+
+        let results = client.search("thrust inclusive scan").await.unwrap();
+        assert_eq!(results[0].name, "thrust::inclusive_scan");
+
+        let results = client.search("cub block reduce").await.unwrap();
+        assert_eq!(results[0].name, "cub::BlockReduce");
+    }
 }
@@ -13,7 +13,9 @@ use serde::{Deserialize, Serialize};
 // - CUDA Runtime API: High-level API for memory management and kernel execution
 // - CUDA Driver API: Low-level API for fine-grained control
 // - Kernel Programming: __global__, __device__, __shared__ memory
-// - Libraries: cuBLAS, cuDNN, NCCL, cuFFT, cuRAND
+// - Libraries: cuBLAS, cuDNN and cuFFT functions, descriptors and enums; NCCL, cuRAND
+// - Thrust and CUB: containers, parallel algorithms and block/warp primitives with
+//   their C++ template declarations
 // - Optimization: Memory coalescing, occupancy, warp-level primitives
 //
 // GPU Specifications Covered:
@@ -65,6 +67,14 @@ pub enum CudaMethodKind {
     KernelConstruct,
     /// CUDA library function (cuBLAS, cuDNN, etc.)
     Library,
+    /// Library handle, descriptor or data type (cublasHandle_t, cudnnTensorDescriptor_t, etc.)
+    Descriptor,
+    /// Library enum (cublasOperation_t, cudnnDataType_t, cufftType, etc.)
+    Enum,
+    /// Thrust container or iterator (thrust::device_vector, thrust::device_ptr, etc.)
+    Container,
+    /// Thrust algorithm or CUB primitive (thrust::reduce, cub::DeviceScan, etc.)
+    Algorithm,
     /// GPU specification or hardware feature
     GpuSpec,
     /// Optimization technique or best practice
@@ -78,6 +88,10 @@ impl std::fmt::Display for CudaMethodKind {
             Self::DriverApi => write!(f, "Driver API"),
             Self::KernelConstruct => write!(f, "Kernel Construct"),
             Self::Library => write!(f, "Library"),
+            Self::Descriptor => write!(f, "Descriptor"),
+            Self::Enum => write!(f, "Enum"),
+            Self::Container => write!(f, "Container"),
+            Self::Algorithm => write!(f, "Algorithm"),
             Self::GpuSpec => write!(f, "GPU Specification"),
             Self::Optimization => write!(f, "Optimization"),
         }
@@ -94,6 +108,9 @@ pub struct CudaMethod {
     pub parameters: Vec<CudaParameter>,
     pub returns: Option<CudaReturnType>,
     pub examples: Vec<CudaExample>,
+    /// C++ declaration for template APIs (Thrust and CUB)
+    #[serde(default)]
+    pub declaration: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -249,38 +266,157 @@ pub const CUDA_KERNEL_CONSTRUCTS: &[CudaMethodIndex] = &[
 ];
 
 // ============================================================================
-// CUDA LIBRARIES
+// CUBLAS - DENSE LINEAR ALGEBRA
 // ============================================================================
 
-pub const CUDA_LIBRARY_METHODS: &[CudaMethodIndex] = &[
-    // cuBLAS
-    CudaMethodIndex { name: "cublasSgemm", description: "cuBLAS single-precision general matrix multiplication. C = alpha*A*B + beta*C. Highly optimized for NVIDIA GPUs.", kind: CudaMethodKind::Library, category: "cublas" },
-    CudaMethodIndex { name: "cublasDgemm", description: "cuBLAS double-precision general matrix multiplication. C = alpha*A*B + beta*C.", kind: CudaMethodKind::Library, category: "cublas" },
-    CudaMethodIndex { name: "cublasHgemm", description: "cuBLAS half-precision (FP16) matrix multiplication. Leverages Tensor Cores on Volta+ GPUs for massive speedups.", kind: CudaMethodKind::Library, category: "cublas" },
-    CudaMethodIndex { name: "cublasGemmEx", description: "cuBLAS extended GEMM with mixed precision support. Can use INT8, FP16, BF16, TF32, FP32, FP64.", kind: CudaMethodKind::Library, category: "cublas" },
-    CudaMethodIndex { name: "cublasCreate", description: "Creates a cuBLAS handle. Required before any cuBLAS operation.", kind: CudaMethodKind::Library, category: "cublas" },
+pub const CUBLAS_METHODS: &[CudaMethodIndex] = &[
+    // Functions
+    CudaMethodIndex { name: "cublasCreate", description: "Creates a cuBLAS handle. Required before any cuBLAS operation; create one per thread and device and reuse it, since creation is expensive.", kind: CudaMethodKind::Library, category: "cublas" },
     CudaMethodIndex { name: "cublasDestroy", description: "Destroys a cuBLAS handle and releases resources.", kind: CudaMethodKind::Library, category: "cublas" },
     CudaMethodIndex { name: "cublasSetStream", description: "Associates a CUDA stream with a cuBLAS handle for async execution.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasGetStream", description: "Returns the CUDA stream currently associated with a cuBLAS handle.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSetMathMode", description: "Sets the math mode of a handle (cublasMath_t), e.g. CUBLAS_TF32_TENSOR_OP_MATH to let FP32 routines use TF32 Tensor Cores.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSetPointerMode", description: "Selects whether alpha/beta scalars and scalar results live on the host or the device (cublasPointerMode_t).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSetWorkspace", description: "Provides a user-owned device workspace buffer to cuBLAS routines on a handle, avoiding internal allocations during stream capture.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSetMatrix", description: "Copies a column-major matrix from host memory to device memory.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasGetMatrix", description: "Copies a column-major matrix from device memory to host memory.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSetVector", description: "Copies a strided vector from host memory to device memory.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasGetVector", description: "Copies a strided vector from device memory to host memory.", kind: CudaMethodKind::Library, category: "cublas" },
     CudaMethodIndex { name: "cublasSaxpy", description: "cuBLAS single-precision y = alpha*x + y. Fundamental BLAS Level 1 operation.", kind: CudaMethodKind::Library, category: "cublas" },
     CudaMethodIndex { name: "cublasSdot", description: "cuBLAS single-precision dot product. Returns x·y.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSscal", description: "cuBLAS single-precision vector scaling x = alpha*x (BLAS Level 1).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSnrm2", description: "cuBLAS single-precision Euclidean norm of a vector (BLAS Level 1).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasIsamax", description: "Returns the 1-based index of the element with the largest absolute value (BLAS Level 1).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSgemv", description: "cuBLAS single-precision matrix-vector multiplication y = alpha*op(A)*x + beta*y (BLAS Level 2).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSgemm", description: "cuBLAS single-precision general matrix multiplication. C = alpha*A*B + beta*C. Highly optimized for NVIDIA GPUs.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasDgemm", description: "cuBLAS double-precision general matrix multiplication. C = alpha*A*B + beta*C.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasHgemm", description: "cuBLAS half-precision (FP16) matrix multiplication. Leverages Tensor Cores on Volta+ GPUs for massive speedups.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasGemmEx", description: "cuBLAS extended GEMM with mixed precision support. Can use INT8, FP16, BF16, TF32, FP32, FP64 via cudaDataType_t and cublasComputeType_t.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSgemmBatched", description: "Runs many independent single-precision GEMMs of the same shape given arrays of device pointers to A, B and C.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSgemmStridedBatched", description: "Batched single-precision GEMM where matrices are at a fixed stride in one allocation. Faster than pointer-array batching.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasGemmStridedBatchedEx", description: "Strided batched GEMM with mixed precision inputs and a selectable compute type. Common for attention and batched inference.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasStrsm", description: "Solves a triangular linear system op(A)*X = alpha*B or X*op(A) = alpha*B for X (BLAS Level 3).", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasSgeam", description: "cuBLAS extension computing C = alpha*op(A) + beta*op(B); also used for out-of-place matrix transpose.", kind: CudaMethodKind::Library, category: "cublas" },
+    CudaMethodIndex { name: "cublasLtMatmul", description: "cuBLASLt matrix multiplication with fused epilogues (bias, ReLU, GELU), FP8 support and algorithm heuristics via cublasLtMatmulAlgoGetHeuristic.", kind: CudaMethodKind::Library, category: "cublas" },
+
+    // Handles and descriptors
+    CudaMethodIndex { name: "cublasHandle_t", description: "Opaque cuBLAS library context holding the stream, math mode, pointer mode and workspace. Created by cublasCreate.", kind: CudaMethodKind::Descriptor, category: "cublas" },
+    CudaMethodIndex { name: "cublasLtMatmulDesc_t", description: "cuBLASLt matmul descriptor holding the compute type, transpose operations and epilogue of a cublasLtMatmul call.", kind: CudaMethodKind::Descriptor, category: "cublas" },
+    CudaMethodIndex { name: "cublasLtMatrixLayout_t", description: "cuBLASLt descriptor of a matrix's type, rows, columns, leading dimension, order and batch stride.", kind: CudaMethodKind::Descriptor, category: "cublas" },
+
+    // Enums
+    CudaMethodIndex { name: "cublasStatus_t", description: "cuBLAS status code: CUBLAS_STATUS_SUCCESS, CUBLAS_STATUS_NOT_INITIALIZED, CUBLAS_STATUS_ALLOC_FAILED, CUBLAS_STATUS_INVALID_VALUE, CUBLAS_STATUS_ARCH_MISMATCH, CUBLAS_STATUS_EXECUTION_FAILED, CUBLAS_STATUS_NOT_SUPPORTED.", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasOperation_t", description: "Transpose operation applied to an input matrix: CUBLAS_OP_N (none), CUBLAS_OP_T (transpose), CUBLAS_OP_C (conjugate transpose).", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasMath_t", description: "Handle math mode: CUBLAS_DEFAULT_MATH, CUBLAS_PEDANTIC_MATH, CUBLAS_TF32_TENSOR_OP_MATH, and the CUBLAS_MATH_DISALLOW_REDUCED_PRECISION_REDUCTION flag.", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasComputeType_t", description: "Compute precision of cublasGemmEx-style calls: CUBLAS_COMPUTE_16F, CUBLAS_COMPUTE_32F, CUBLAS_COMPUTE_32F_FAST_TF32, CUBLAS_COMPUTE_32F_FAST_16BF, CUBLAS_COMPUTE_64F, CUBLAS_COMPUTE_32I.", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasGemmAlgo_t", description: "GEMM algorithm selection for the Ex routines. CUBLAS_GEMM_DEFAULT lets cuBLAS choose; Tensor Core variants are used automatically on supported GPUs.", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasPointerMode_t", description: "Where scalars are read and written: CUBLAS_POINTER_MODE_HOST or CUBLAS_POINTER_MODE_DEVICE.", kind: CudaMethodKind::Enum, category: "cublas" },
+    CudaMethodIndex { name: "cublasFillMode_t", description: "Which triangle of a symmetric or triangular matrix is used: CUBLAS_FILL_MODE_LOWER, CUBLAS_FILL_MODE_UPPER, CUBLAS_FILL_MODE_FULL.", kind: CudaMethodKind::Enum, category: "cublas" },
+];
+
+// ============================================================================
+// CUDNN - DEEP NEURAL NETWORK PRIMITIVES
+// ============================================================================
 
-    // cuDNN
+pub const CUDNN_METHODS: &[CudaMethodIndex] = &[
+    // Functions
+    CudaMethodIndex { name: "cudnnCreate", description: "Creates a cuDNN handle. Required before any cuDNN operation.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnDestroy", description: "Destroys a cuDNN handle and releases resources.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetStream", description: "Sets the CUDA stream used by all cuDNN calls on a handle.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnGetErrorString", description: "Returns a human-readable string for a cudnnStatus_t value.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnCreateTensorDescriptor", description: "Allocates a tensor descriptor to be configured with cudnnSetTensor4dDescriptor or cudnnSetTensorNdDescriptor.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetTensor4dDescriptor", description: "Configures a 4D tensor descriptor from a layout (NCHW/NHWC), data type and n, c, h, w dimensions.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetTensorNdDescriptor", description: "Configures an N-dimensional tensor descriptor from explicit dimensions and strides.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnDestroyTensorDescriptor", description: "Destroys a tensor descriptor.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnCreateFilterDescriptor", description: "Allocates a filter (weights) descriptor for convolutions.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetFilter4dDescriptor", description: "Configures a 4D filter descriptor: data type, layout, output channels k, input channels c, and kernel height and width.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnCreateConvolutionDescriptor", description: "Allocates a convolution descriptor holding padding, stride, dilation, mode and math type.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetConvolution2dDescriptor", description: "Configures a 2D convolution: padding, stride, dilation, convolution mode and compute data type.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetConvolutionMathType", description: "Selects whether a convolution may use Tensor Core operations (cudnnMathType_t).", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnGetConvolution2dForwardOutputDim", description: "Computes the n, c, h, w dimensions of a 2D convolution's output tensor.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnGetConvolutionForwardAlgorithm_v7", description: "Returns forward convolution algorithms ranked by cuDNN's heuristics without running them. Replaces cudnnGetConvolutionForwardAlgorithm.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnGetConvolutionForwardAlgorithm", description: "Legacy heuristic algorithm query removed in cuDNN 8. Use cudnnGetConvolutionForwardAlgorithm_v7 or cudnnFindConvolutionForwardAlgorithm.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnFindConvolutionForwardAlgorithm", description: "Benchmarks forward convolution algorithms on the actual tensors and returns them sorted by measured time.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnGetConvolutionForwardWorkspaceSize", description: "Returns the device workspace size in bytes an algorithm needs for cudnnConvolutionForward.", kind: CudaMethodKind::Library, category: "cudnn" },
     CudaMethodIndex { name: "cudnnConvolutionForward", description: "cuDNN convolution forward pass. Supports multiple algorithms with auto-tuning for optimal performance.", kind: CudaMethodKind::Library, category: "cudnn" },
     CudaMethodIndex { name: "cudnnConvolutionBackwardData", description: "cuDNN convolution backward pass for input gradients.", kind: CudaMethodKind::Library, category: "cudnn" },
     CudaMethodIndex { name: "cudnnConvolutionBackwardFilter", description: "cuDNN convolution backward pass for filter gradients.", kind: CudaMethodKind::Library, category: "cudnn" },
-    CudaMethodIndex { name: "cudnnBatchNormalizationForwardTraining", description: "cuDNN batch normalization forward pass during training.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnConvolutionBiasActivationForward", description: "Fused convolution, bias add and activation: y = act(alpha1*conv(x) + alpha2*z + bias).", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnAddTensor", description: "Adds a scaled tensor to another, broadcasting the source (e.g. adding a bias per channel).", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnCreateActivationDescriptor", description: "Allocates an activation descriptor.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetActivationDescriptor", description: "Configures an activation: mode (cudnnActivationMode_t), NaN propagation and coefficient (clipping threshold or ELU alpha).", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnActivationForward", description: "Applies an activation function (ReLU, sigmoid, tanh, ...) element-wise.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSetPooling2dDescriptor", description: "Configures 2D max or average pooling: window size, padding and stride.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnPoolingForward", description: "Runs a pooling layer forward pass.", kind: CudaMethodKind::Library, category: "cudnn" },
     CudaMethodIndex { name: "cudnnSoftmaxForward", description: "cuDNN softmax activation forward pass.", kind: CudaMethodKind::Library, category: "cudnn" },
-    CudaMethodIndex { name: "cudnnCreate", description: "Creates a cuDNN handle. Required before any cuDNN operation.", kind: CudaMethodKind::Library, category: "cudnn" },
-    CudaMethodIndex { name: "cudnnDestroy", description: "Destroys a cuDNN handle and releases resources.", kind: CudaMethodKind::Library, category: "cudnn" },
-    CudaMethodIndex { name: "cudnnGetConvolutionForwardAlgorithm", description: "Selects optimal convolution algorithm based on tensor sizes and available memory.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBatchNormalizationForwardTraining", description: "cuDNN batch normalization forward pass during training.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBatchNormalizationForwardInference", description: "Batch normalization forward pass for inference using stored running mean and variance.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBackendCreateDescriptor", description: "Graph API: creates a backend descriptor (tensor, operation, graph, engine, execution plan) by cudnnBackendDescriptorType_t.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBackendFinalize", description: "Graph API: validates and finalizes a backend descriptor after its attributes are set.", kind: CudaMethodKind::Library, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBackendExecute", description: "Graph API: executes a finalized execution plan with a variant pack of device pointers and workspace.", kind: CudaMethodKind::Library, category: "cudnn" },
+
+    // Handles and descriptors
+    CudaMethodIndex { name: "cudnnHandle_t", description: "Opaque cuDNN library context bound to a device and stream. Created by cudnnCreate.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnTensorDescriptor_t", description: "Describes a tensor's data type, dimensions and strides or layout.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnFilterDescriptor_t", description: "Describes convolution weights: data type, layout and k, c, h, w dimensions.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnConvolutionDescriptor_t", description: "Describes a convolution: padding, strides, dilation, mode, compute type, math type and group count.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnActivationDescriptor_t", description: "Describes an activation function and its coefficient.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnPoolingDescriptor_t", description: "Describes a pooling window, padding, stride and mode.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnBackendDescriptor_t", description: "Graph API descriptor configured through cudnnBackendSetAttribute and finalized with cudnnBackendFinalize.", kind: CudaMethodKind::Descriptor, category: "cudnn" },
+
+    // Enums
+    CudaMethodIndex { name: "cudnnStatus_t", description: "cuDNN status code: CUDNN_STATUS_SUCCESS, CUDNN_STATUS_NOT_INITIALIZED, CUDNN_STATUS_ALLOC_FAILED, CUDNN_STATUS_BAD_PARAM, CUDNN_STATUS_NOT_SUPPORTED, CUDNN_STATUS_EXECUTION_FAILED.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnDataType_t", description: "Tensor element type: CUDNN_DATA_FLOAT, CUDNN_DATA_DOUBLE, CUDNN_DATA_HALF, CUDNN_DATA_BFLOAT16, CUDNN_DATA_INT8, CUDNN_DATA_INT32, CUDNN_DATA_FP8_E4M3.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnTensorFormat_t", description: "Tensor memory layout: CUDNN_TENSOR_NCHW, CUDNN_TENSOR_NHWC (preferred for Tensor Cores), CUDNN_TENSOR_NCHW_VECT_C.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnConvolutionMode_t", description: "CUDNN_CONVOLUTION (flips the filter) or CUDNN_CROSS_CORRELATION (what deep learning frameworks call convolution).", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnConvolutionFwdAlgo_t", description: "Forward convolution algorithm: IMPLICIT_GEMM, IMPLICIT_PRECOMP_GEMM, GEMM, DIRECT, FFT, FFT_TILING, WINOGRAD, WINOGRAD_NONFUSED (all prefixed CUDNN_CONVOLUTION_FWD_ALGO_).", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnMathType_t", description: "Tensor Core usage: CUDNN_DEFAULT_MATH, CUDNN_TENSOR_OP_MATH, CUDNN_TENSOR_OP_MATH_ALLOW_CONVERSION, CUDNN_FMA_MATH.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnActivationMode_t", description: "Activation function: CUDNN_ACTIVATION_SIGMOID, RELU, TANH, CLIPPED_RELU, ELU, SWISH, IDENTITY.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnPoolingMode_t", description: "Pooling mode: CUDNN_POOLING_MAX, CUDNN_POOLING_AVERAGE_COUNT_INCLUDE_PADDING, CUDNN_POOLING_AVERAGE_COUNT_EXCLUDE_PADDING, CUDNN_POOLING_MAX_DETERMINISTIC.", kind: CudaMethodKind::Enum, category: "cudnn" },
+    CudaMethodIndex { name: "cudnnSoftmaxAlgorithm_t", description: "Softmax variant: CUDNN_SOFTMAX_FAST, CUDNN_SOFTMAX_ACCURATE (subtracts the max), CUDNN_SOFTMAX_LOG.", kind: CudaMethodKind::Enum, category: "cudnn" },
+];
 
-    // cuFFT
-    CudaMethodIndex { name: "cufftExecC2C", description: "cuFFT complex-to-complex FFT execution. Highly optimized for power-of-2 sizes.", kind: CudaMethodKind::Library, category: "cufft" },
-    CudaMethodIndex { name: "cufftExecR2C", description: "cuFFT real-to-complex FFT execution.", kind: CudaMethodKind::Library, category: "cufft" },
-    CudaMethodIndex { name: "cufftPlan1d", description: "Creates a 1D FFT plan.", kind: CudaMethodKind::Library, category: "cufft" },
+// ============================================================================
+// CUFFT - FAST FOURIER TRANSFORMS
+// ============================================================================
+
+pub const CUFFT_METHODS: &[CudaMethodIndex] = &[
+    // Functions
+    CudaMethodIndex { name: "cufftPlan1d", description: "Creates a 1D FFT plan of a given size, transform type and batch count.", kind: CudaMethodKind::Library, category: "cufft" },
     CudaMethodIndex { name: "cufftPlan2d", description: "Creates a 2D FFT plan.", kind: CudaMethodKind::Library, category: "cufft" },
     CudaMethodIndex { name: "cufftPlan3d", description: "Creates a 3D FFT plan.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftPlanMany", description: "Creates a batched 1D, 2D or 3D plan with advanced data layout (inembed/istride/idist, onembed/ostride/odist).", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftCreate", description: "Creates an empty plan handle to configure before cufftMakePlan*; allows setting auto-allocation and streams first.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftMakePlan1d", description: "Builds a 1D plan on a handle from cufftCreate and returns the required work area size.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftMakePlanMany", description: "Builds a batched advanced-layout plan on a handle from cufftCreate and returns the work area size.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftSetStream", description: "Associates a CUDA stream with a plan so executions are asynchronous on that stream.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftSetAutoAllocation", description: "Disables or enables cuFFT's automatic work area allocation for a plan.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftSetWorkArea", description: "Provides a caller-allocated work area to a plan created with auto-allocation disabled.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftGetSize", description: "Returns the work area size in bytes a plan needs.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecC2C", description: "cuFFT complex-to-complex FFT execution. Highly optimized for power-of-2 sizes. Direction is CUFFT_FORWARD or CUFFT_INVERSE; results are unnormalized.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecR2C", description: "cuFFT real-to-complex FFT execution. Output holds N/2+1 complex values along the last dimension.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecC2R", description: "Complex-to-real inverse FFT (single precision). Overwrites its input; scale by 1/N to normalize.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecZ2Z", description: "Double-precision complex-to-complex FFT execution.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecD2Z", description: "Double-precision real-to-complex FFT execution.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftExecZ2D", description: "Double-precision complex-to-real inverse FFT execution.", kind: CudaMethodKind::Library, category: "cufft" },
+    CudaMethodIndex { name: "cufftDestroy", description: "Destroys a plan and frees its GPU resources.", kind: CudaMethodKind::Library, category: "cufft" },
+
+    // Handles and types
+    CudaMethodIndex { name: "cufftHandle", description: "Integer handle to a cuFFT plan describing size, type, batch and layout. Reuse plans; creation is expensive.", kind: CudaMethodKind::Descriptor, category: "cufft" },
+    CudaMethodIndex { name: "cufftComplex", description: "Single-precision complex type (float2 layout: x = real, y = imaginary). cufftDoubleComplex is the double2 equivalent.", kind: CudaMethodKind::Descriptor, category: "cufft" },
+    CudaMethodIndex { name: "cufftReal", description: "Single-precision real input/output type (float); cufftDoubleReal is double.", kind: CudaMethodKind::Descriptor, category: "cufft" },
+
+    // Enums
+    CudaMethodIndex { name: "cufftType", description: "Transform type: CUFFT_R2C, CUFFT_C2R, CUFFT_C2C (single precision) and CUFFT_D2Z, CUFFT_Z2D, CUFFT_Z2Z (double precision).", kind: CudaMethodKind::Enum, category: "cufft" },
+    CudaMethodIndex { name: "cufftResult", description: "cuFFT status code: CUFFT_SUCCESS, CUFFT_INVALID_PLAN, CUFFT_ALLOC_FAILED, CUFFT_INVALID_VALUE, CUFFT_INTERNAL_ERROR, CUFFT_EXEC_FAILED, CUFFT_SETUP_FAILED, CUFFT_INVALID_SIZE.", kind: CudaMethodKind::Enum, category: "cufft" },
+    CudaMethodIndex { name: "CUFFT_FORWARD", description: "Transform direction for C2C executions: CUFFT_FORWARD (-1) or CUFFT_INVERSE (1). R2C is always forward and C2R always inverse.", kind: CudaMethodKind::Enum, category: "cufft" },
+];
 
+// ============================================================================
+// OTHER CUDA LIBRARIES
+// ============================================================================
+
+pub const CUDA_LIBRARY_METHODS: &[CudaMethodIndex] = &[
     // cuRAND
     CudaMethodIndex { name: "curandCreateGenerator", description: "Creates a cuRAND random number generator. Supports multiple algorithms (XORWOW, MRG32k3a, etc.).", kind: CudaMethodKind::Library, category: "curand" },
     CudaMethodIndex { name: "curandGenerateUniform", description: "Generates uniformly distributed floats in (0, 1].", kind: CudaMethodKind::Library, category: "curand" },
@@ -295,6 +431,53 @@ pub const CUDA_LIBRARY_METHODS: &[CudaMethodIndex] = &[
     CudaMethodIndex { name: "ncclCommInitRank", description: "Initializes a single NCCL communicator for multi-process setups.", kind: CudaMethodKind::Library, category: "nccl" },
 ];
 
+// ============================================================================
+// THRUST - PARALLEL ALGORITHMS LIBRARY
+// ============================================================================
+
+pub const THRUST_METHODS: &[CudaMethodIndex] = &[
+    // Containers and iterators
+    CudaMethodIndex { name: "thrust::device_vector", description: "Vector stored in device memory. Assigning from a host_vector or std::vector copies to the GPU; element access from the host issues a cudaMemcpy per element.", kind: CudaMethodKind::Container, category: "thrust" },
+    CudaMethodIndex { name: "thrust::host_vector", description: "Vector stored in host memory with the same interface as device_vector; used to stage data for transfers.", kind: CudaMethodKind::Container, category: "thrust" },
+    CudaMethodIndex { name: "thrust::device_ptr", description: "Wraps a raw device pointer so it can be passed to Thrust algorithms; thrust::raw_pointer_cast unwraps it for kernels and CUDA libraries.", kind: CudaMethodKind::Container, category: "thrust" },
+    CudaMethodIndex { name: "thrust::counting_iterator", description: "Fancy iterator producing an increasing sequence without storing it; combine with transform to index-based computations.", kind: CudaMethodKind::Container, category: "thrust" },
+    CudaMethodIndex { name: "thrust::zip_iterator", description: "Fancy iterator that walks several ranges in lockstep and yields thrust::tuple values (structure-of-arrays access).", kind: CudaMethodKind::Container, category: "thrust" },
+
+    // Algorithms
+    CudaMethodIndex { name: "thrust::transform", description: "Applies a unary or binary functor element-wise and writes the results to an output range. Runs on the device for device iterators or with the thrust::device policy.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::reduce", description: "Reduces a range to a single value with an associative operator (default thrust::plus). Returns the result to the host and synchronizes.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::transform_reduce", description: "Fuses a transform with a reduction in a single pass, avoiding a temporary array (e.g. sum of squares, norms).", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::reduce_by_key", description: "Reduces consecutive runs of equal keys, producing one value per key segment.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::inclusive_scan", description: "Prefix sum where output[i] includes input[i]. Works in place; accepts any associative operator.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::exclusive_scan", description: "Prefix sum where output[i] excludes input[i] and starts from an initial value (default 0). Used for stream compaction offsets.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::sort", description: "Sorts a range in place; primitive keys with default comparison use a radix sort on the device.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::sort_by_key", description: "Sorts keys and applies the same permutation to a values range.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::copy_if", description: "Copies elements satisfying a predicate (stream compaction) and returns the end of the output range.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::fill", description: "Assigns a value to every element of a range.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::sequence", description: "Fills a range with init, init + step, init + 2 * step, ...", kind: CudaMethodKind::Algorithm, category: "thrust" },
+    CudaMethodIndex { name: "thrust::device", description: "Execution policy that runs an algorithm on the device; thrust::cuda::par.on(stream) selects a stream.", kind: CudaMethodKind::Algorithm, category: "thrust" },
+];
+
+// ============================================================================
+// CUB - DEVICE, BLOCK AND WARP PRIMITIVES
+// ============================================================================
+
+pub const CUB_METHODS: &[CudaMethodIndex] = &[
+    // Device-wide primitives
+    CudaMethodIndex { name: "cub::DeviceReduce::Sum", description: "Device-wide sum reduction. Call once with d_temp_storage = nullptr to query temp_storage_bytes, allocate, then call again.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::DeviceReduce::Reduce", description: "Device-wide reduction with a custom associative operator and initial value.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::DeviceScan::InclusiveSum", description: "Device-wide inclusive prefix sum using the two-phase temp storage pattern.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::DeviceScan::ExclusiveSum", description: "Device-wide exclusive prefix sum using the two-phase temp storage pattern.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::DeviceRadixSort::SortPairs", description: "Device-wide radix sort of key-value pairs; begin_bit/end_bit restrict the sorted bits.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::DeviceSelect::If", description: "Device-wide stream compaction keeping items that satisfy a predicate and writing the selected count.", kind: CudaMethodKind::Algorithm, category: "cub" },
+
+    // Block- and warp-level primitives
+    CudaMethodIndex { name: "cub::BlockReduce", description: "Cooperative reduction across a thread block. Requires a __shared__ TempStorage; the result is only valid in thread 0.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::BlockScan", description: "Cooperative prefix scan across a thread block using a __shared__ TempStorage.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::BlockLoad", description: "Loads a tile of items per thread with a selectable algorithm (direct, striped, warp transpose) for coalesced access.", kind: CudaMethodKind::Algorithm, category: "cub" },
+    CudaMethodIndex { name: "cub::WarpReduce", description: "Reduction across a warp using shuffle instructions; the result is valid in lane 0.", kind: CudaMethodKind::Algorithm, category: "cub" },
+];
+
 // ============================================================================
 // GPU SPECIFICATIONS - RTX 3070 & RTX 4090
 // ============================================================================
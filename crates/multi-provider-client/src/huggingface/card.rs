@@ -0,0 +1,274 @@
+//! Model card parsing for Hub models.
+//!
+//! Turns a model's Hub API info, `config.json` and `README.md` into the facts
+//! needed to use it: architecture, context length, required transformers
+//! version and a usage snippet.
+
+use serde_json::Value;
+
+use super::types::{HfExample, HfModelCard, HfModelInfo, LLM_MODEL_FAMILIES};
+
+/// Config keys holding the maximum context length, most specific first
+const CONTEXT_LENGTH_KEYS: &[&str] = &[
+    "max_position_embeddings",
+    "max_sequence_length",
+    "seq_length",
+    "n_positions",
+    "max_seq_len",
+    "n_ctx",
+];
+
+/// Longest summary taken from a model card
+const MAX_SUMMARY_CHARS: usize = 400;
+
+/// A Hub model named in a query: `org/name` (or a huggingface.co URL), or a bare
+/// name from a known family with a size or version such as `Qwen2.5-7B-Instruct`
+#[must_use]
+pub fn model_reference(query: &str) -> Option<String> {
+    query
+        .split_whitespace()
+        .map(|token| token.trim_matches(|c: char| matches!(c, '`' | '"' | '\'' | ',' | '?' | '(' | ')')))
+        .find_map(|token| {
+            let token = token.strip_prefix("https://").unwrap_or(token);
+            let token = token.strip_prefix("huggingface.co/").unwrap_or(token);
+            let token = token.trim_end_matches('/');
+            if let Some((org, name)) = token.split_once('/') {
+                return is_repo_id(org, name).then(|| token.to_string());
+            }
+            is_family_model(token).then(|| token.to_string())
+        })
+}
+
+fn is_repo_part(part: &str) -> bool {
+    !part.is_empty()
+        && part.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn is_repo_id(org: &str, name: &str) -> bool {
+    // Model names carry a size, version or variant ("-7B", "v0.3"); this keeps
+    // "input/output" and "async/await" from reading as repos
+    is_repo_part(org)
+        && is_repo_part(name)
+        && name.contains(['-', '.'])
+        && name.chars().any(|c| c.is_ascii_digit())
+}
+
+fn is_family_model(token: &str) -> bool {
+    let lower = token.to_lowercase();
+    is_repo_part(token)
+        && lower.contains('-')
+        && lower.chars().any(|c| c.is_ascii_digit())
+        && LLM_MODEL_FAMILIES.iter().any(|(family, _)| lower.starts_with(family))
+}
+
+/// Build a card from the Hub API info plus the repo's `config.json` and `README.md`
+#[must_use]
+pub fn build_card(info: HfModelInfo, config: Option<&Value>, readme: Option<&str>) -> HfModelCard {
+    let string_field = |key: &str| config.and_then(|config| config.get(key)).and_then(Value::as_str).map(str::to_string);
+    let architectures = config
+        .and_then(|config| config.get("architectures"))
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default();
+    let license = info
+        .tags
+        .iter()
+        .find_map(|tag| tag.strip_prefix("license:"))
+        .map(str::to_string);
+
+    HfModelCard {
+        url: format!("https://huggingface.co/{}", info.model_id),
+        summary: readme.and_then(card_summary),
+        usage: readme.and_then(usage_snippet),
+        architectures,
+        model_type: string_field("model_type"),
+        context_length: config.and_then(context_length),
+        transformers_version: string_field("transformers_version"),
+        torch_dtype: string_field("torch_dtype"),
+        license,
+        model_id: info.model_id,
+        pipeline_tag: info.pipeline_tag,
+        library_name: info.library_name,
+        downloads: info.downloads,
+        likes: info.likes,
+    }
+}
+
+/// Maximum context length from a model config, including multimodal `text_config`
+#[must_use]
+pub fn context_length(config: &Value) -> Option<u64> {
+    let from = |config: &Value| CONTEXT_LENGTH_KEYS.iter().find_map(|key| config.get(*key).and_then(Value::as_u64));
+    from(config).or_else(|| config.get("text_config").and_then(from))
+}
+
+/// Model card body without its YAML front matter
+fn card_body(readme: &str) -> &str {
+    let Some(rest) = readme.trim_start().strip_prefix("---") else {
+        return readme;
+    };
+    rest.find("\n---").map_or(readme, |end| &rest[end + 4..])
+}
+
+/// First prose paragraph of a model card
+#[must_use]
+pub fn card_summary(readme: &str) -> Option<String> {
+    let mut in_code = false;
+    let mut paragraph: Vec<&str> = Vec::new();
+    for line in card_body(readme).lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+        let is_prose = !line.is_empty()
+            && !line.starts_with(['#', '<', '!', '|', '[', '>', '-', '*'])
+            && line.chars().any(char::is_alphabetic);
+        if is_prose {
+            paragraph.push(line);
+        } else if !paragraph.is_empty() {
+            break;
+        }
+    }
+    if paragraph.is_empty() {
+        return None;
+    }
+    let summary = paragraph.join(" ");
+    if summary.chars().count() <= MAX_SUMMARY_CHARS {
+        return Some(summary);
+    }
+    let truncated: String = summary.chars().take(MAX_SUMMARY_CHARS).collect();
+    Some(format!("{}…", truncated.trim_end()))
+}
+
+/// Usage snippet from a model card: the first code block that loads the model with
+/// transformers (or another library), falling back to the first Python block
+#[must_use]
+pub fn usage_snippet(readme: &str) -> Option<HfExample> {
+    let blocks = code_blocks(card_body(readme));
+    let loads_model = |code: &str| {
+        ["from_pretrained", "pipeline(", "SentenceTransformer(", "from mlx_lm", "LLM("]
+            .iter()
+            .any(|marker| code.contains(marker))
+    };
+    let is_python = |language: &str| matches!(language, "python" | "py" | "");
+    blocks
+        .iter()
+        .find(|(language, code)| is_python(language) && loads_model(code))
+        .or_else(|| blocks.iter().find(|(language, code)| is_python(language) && code.contains("import ")))
+        .map(|(language, code)| HfExample {
+            code: code.clone(),
+            language: if language.is_empty() { "python".to_string() } else { language.clone() },
+            description: Some("Usage from the model card".to_string()),
+        })
+}
+
+/// Fenced code blocks as (language, code)
+fn code_blocks(markdown: &str) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        match (&mut current, trimmed.strip_prefix("```")) {
+            (None, Some(info)) => current = Some((info.trim().to_lowercase(), Vec::new())),
+            (Some(_), Some(_)) => {
+                if let Some((language, lines)) = current.take() {
+                    blocks.push((language, lines.join("\n")));
+                }
+            }
+            (Some((_, lines)), None) => lines.push(line),
+            (None, None) => {}
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const README: &str = r#"---
+license: apache-2.0
+pipeline_tag: text-generation
+---
+
+# Qwen2.5-7B-Instruct
+
+<a href="https://chat.qwenlm.ai/"><img src="badge.svg"/></a>
+
+Qwen2.5 is the latest series of Qwen large language models.
+It supports long contexts of up to 128K tokens.
+
+## Requirements
+
+```bash
+pip install transformers>=4.37.0
+```
+
+## Quickstart
+
+```python
+from transformers import AutoModelForCausalLM, AutoTokenizer
+
+model = AutoModelForCausalLM.from_pretrained("Qwen/Qwen2.5-7B-Instruct", torch_dtype="auto")
+```
+"#;
+
+    #[test]
+    fn test_model_reference() {
+        assert_eq!(model_reference("Qwen2.5-7B-Instruct usage").as_deref(), Some("Qwen2.5-7B-Instruct"));
+        assert_eq!(
+            model_reference("how to run meta-llama/Llama-3.1-8B-Instruct?").as_deref(),
+            Some("meta-llama/Llama-3.1-8B-Instruct")
+        );
+        assert_eq!(
+            model_reference("https://huggingface.co/google/gemma-2-9b-it").as_deref(),
+            Some("google/gemma-2-9b-it")
+        );
+        assert_eq!(model_reference("input/output streams"), None);
+        assert_eq!(model_reference("llama tokenizer"), None);
+    }
+
+    #[test]
+    fn test_build_card() {
+        let info = HfModelInfo {
+            model_id: "Qwen/Qwen2.5-7B-Instruct".to_string(),
+            author: Some("Qwen".to_string()),
+            sha: None,
+            downloads: 1_000,
+            likes: 10,
+            tags: vec!["license:apache-2.0".to_string()],
+            pipeline_tag: Some("text-generation".to_string()),
+            library_name: Some("transformers".to_string()),
+        };
+        let config = json!({
+            "architectures": ["Qwen2ForCausalLM"],
+            "model_type": "qwen2",
+            "max_position_embeddings": 32768,
+            "transformers_version": "4.43.1",
+            "torch_dtype": "bfloat16"
+        });
+
+        let card = build_card(info, Some(&config), Some(README));
+        assert_eq!(card.architectures, ["Qwen2ForCausalLM"]);
+        assert_eq!(card.context_length, Some(32768));
+        assert_eq!(card.transformers_version.as_deref(), Some("4.43.1"));
+        assert_eq!(card.license.as_deref(), Some("apache-2.0"));
+        assert!(card.summary.unwrap().starts_with("Qwen2.5 is the latest series"));
+        let usage = card.usage.unwrap();
+        assert_eq!(usage.language, "python");
+        assert!(usage.code.contains("from_pretrained"));
+    }
+
+    #[test]
+    fn test_context_length_from_text_config() {
+        let config = json!({"text_config": {"max_position_embeddings": 131_072}});
+        assert_eq!(context_length(&config), Some(131_072));
+        assert_eq!(context_length(&json!({"n_positions": 1024})), Some(1024));
+        assert_eq!(context_length(&json!({})), None);
+    }
+}
@@ -4,32 +4,44 @@
 //! swift-transformers, and model information from the Hub.
 
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use scraper::{Html, Selector};
 use tracing::{debug, instrument, warn};
 
+use super::card::build_card;
+use super::hub_api::{find_endpoint, HUB_API_DOCS_URL, HUB_API_ENDPOINTS};
 use super::types::{
-    HfArticle, HfCategory, HfCategoryItem, HfExample, HfItemKind, HfModelInfo,
+    HfArticle, HfCategory, HfCategoryItem, HfExample, HfItemKind, HfModelCard, HfModelInfo,
     HfParameter, HfSearchResult, HfTechnology, HfTechnologyKind,
     LLM_MODEL_FAMILIES, SWIFT_TRANSFORMERS_TOPICS, TRANSFORMERS_TOPICS,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::credentials::{self, ApiToken};
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const TRANSFORMERS_DOCS_BASE: &str = "https://huggingface.co/docs/transformers/main/en";
 const SWIFT_TRANSFORMERS_BASE: &str = "https://huggingface.co/docs/swift-transformers/main/en";
 const HF_HUB_API: &str = "https://huggingface.co/api";
+const HF_HUB_BASE: &str = "https://huggingface.co";
 
 #[derive(Debug)]
 pub struct HuggingFaceClient {
     http: Client,
     disk_cache: DiskCache,
     #[allow(dead_code)]
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     cache_dir: PathBuf,
+    /// Whether requests carry an API token (needed for gated models).
+    authenticated: bool,
 }
 
 impl Default for HuggingFaceClient {
@@ -39,8 +51,15 @@ impl Default for HuggingFaceClient {
 }
 
 impl HuggingFaceClient {
+    /// Client using the process-wide token from [`crate::credentials::huggingface_token`].
     #[must_use]
     pub fn new() -> Self {
+        Self::with_token(credentials::huggingface_token().as_ref())
+    }
+
+    /// Client authenticating as `token`, which raises Hub rate limits and unlocks gated models.
+    #[must_use]
+    pub fn with_token(token: Option<&ApiToken>) -> Self {
         let project_dirs = ProjectDirs::from("com", "RecordAndLearn", "multi-docs-mcp")
             .expect("unable to resolve project directories");
 
@@ -49,9 +68,9 @@ impl HuggingFaceClient {
             warn!(error = %e, "Failed to create HuggingFace cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::HuggingFace))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
+            .default_headers(token.map(ApiToken::bearer_headers).unwrap_or_default())
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -59,8 +78,9 @@ impl HuggingFaceClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(24)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::HuggingFace, memory_ttl(ProviderType::HuggingFace)),
             cache_dir,
+            authenticated: token.is_some(),
         }
     }
 
@@ -96,12 +116,39 @@ impl HuggingFaceClient {
                 url: "https://huggingface.co/docs/tokenizers".to_string(),
                 kind: HfTechnologyKind::Tokenizers,
             },
+            HfTechnology {
+                identifier: "hf:hub-api".to_string(),
+                title: "Hub HTTP API".to_string(),
+                description: "REST endpoints for model search, file download and Inference Endpoints".to_string(),
+                url: HUB_API_DOCS_URL.to_string(),
+                kind: HfTechnologyKind::HubApi,
+            },
         ])
     }
 
     /// Get category listing
     #[instrument(name = "hf_client.get_category", skip(self))]
     pub async fn get_category(&self, identifier: &str) -> Result<HfCategory> {
+        if identifier.contains("hub-api") {
+            let items = HUB_API_ENDPOINTS
+                .iter()
+                .map(|endpoint| HfCategoryItem {
+                    name: endpoint.title.to_string(),
+                    description: format!("{} — {}", endpoint.declaration(), endpoint.group.title()),
+                    kind: HfItemKind::Endpoint,
+                    path: endpoint.path(),
+                    url: endpoint.group.docs_url().to_string(),
+                })
+                .collect();
+            return Ok(HfCategory {
+                identifier: identifier.to_string(),
+                title: "Hub HTTP API".to_string(),
+                description: "Hub, Inference Providers and Inference Endpoints REST endpoints".to_string(),
+                items,
+                kind: HfTechnologyKind::HubApi,
+            });
+        }
+
         let (topics, kind, base_url, title, description) = if identifier.contains("swift") {
             (
                 SWIFT_TRANSFORMERS_TOPICS,
@@ -188,6 +235,29 @@ impl HuggingFaceClient {
             }
         }
 
+        // Search Hub HTTP API endpoints
+        if technology.is_none() || technology == Some(HfTechnologyKind::HubApi) {
+            for endpoint in HUB_API_ENDPOINTS {
+                let mut score = calculate_score(endpoint.title, endpoint.description, &query_terms);
+                for term in &query_terms {
+                    if endpoint.tags.contains(term) {
+                        score += 20;
+                    }
+                }
+                if score > 0 {
+                    results.push(HfSearchResult {
+                        name: endpoint.title.to_string(),
+                        path: endpoint.path(),
+                        url: endpoint.group.docs_url().to_string(),
+                        kind: HfItemKind::Endpoint,
+                        technology: HfTechnologyKind::HubApi,
+                        description: endpoint.description.to_string(),
+                        score,
+                    });
+                }
+            }
+        }
+
         // Search model families
         if technology.is_none() || technology == Some(HfTechnologyKind::Models) {
             for (family, desc) in LLM_MODEL_FAMILIES {
@@ -206,7 +276,7 @@ impl HuggingFaceClient {
         }
 
         // Sort by score descending
-        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
         results.truncate(20);
 
         Ok(results)
@@ -232,7 +302,8 @@ impl HuggingFaceClient {
 
         debug!(url = %url, "Searching Hugging Face models");
 
-        let response = self.http.get(&url).send().await;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -256,6 +327,13 @@ impl HuggingFaceClient {
         path: &str,
         technology: HfTechnologyKind,
     ) -> Result<HfArticle> {
+        // Hub API endpoints are embedded
+        if technology == HfTechnologyKind::HubApi {
+            return find_endpoint(path)
+                .map(|endpoint| endpoint.to_article())
+                .with_context(|| format!("Unknown Hub API endpoint: {path}"));
+        }
+
         let (base_url, topics): (&str, &[(&str, &str, &str, HfItemKind)]) =
             if technology == HfTechnologyKind::SwiftTransformers {
                 (SWIFT_TRANSFORMERS_BASE, SWIFT_TRANSFORMERS_TOPICS)
@@ -309,7 +387,8 @@ impl HuggingFaceClient {
     ) -> Result<HfArticle> {
         debug!(url = %url, "Fetching HuggingFace documentation");
 
-        let response = self.http.get(url).send().await;
+        ensure_online(url)?;
+        let response = self.http.get(url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -411,16 +490,15 @@ impl HuggingFaceClient {
         let url = format!("{}/models/{}", HF_HUB_API, model_id);
         debug!(url = %url, "Fetching model info");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch model info")?;
 
-        if !response.status().is_success() {
-            anyhow::bail!("Model not found: {}", model_id);
-        }
+        self.check_model_status(response.status(), model_id)?;
 
         let info: HfModelInfo = response.json().await?;
         let _ = self.disk_cache.store(&cache_key, info.clone()).await;
@@ -428,6 +506,81 @@ impl HuggingFaceClient {
         Ok(info)
     }
 
+    /// Get a model's card: architecture, context length, required transformers version and
+    /// a usage snippet, from the Hub API plus the repo's `config.json` and `README.md`.
+    ///
+    /// `model` is a repo id (`Qwen/Qwen2.5-7B-Instruct`) or a bare name resolved by search.
+    #[instrument(name = "hf_client.get_model_card", skip(self))]
+    pub async fn get_model_card(&self, model: &str) -> Result<HfModelCard> {
+        let model_id = self.resolve_model_id(model).await?;
+        let cache_key = format!("card_{}.json", model_id.replace('/', "_"));
+
+        if let Ok(Some(entry)) = self.disk_cache.load::<HfModelCard>(&cache_key).await {
+            return Ok(entry.value);
+        }
+
+        let info = self.get_model_info(&model_id).await?;
+        // Repos without these files (GGUF-only, gated without a token) still get a card
+        let config = self
+            .fetch_repo_file(&model_id, "config.json")
+            .await
+            .and_then(|text| serde_json::from_str::<serde_json::Value>(&text).ok());
+        let readme = self.fetch_repo_file(&model_id, "README.md").await;
+
+        let card = build_card(info, config.as_ref(), readme.as_deref());
+        let _ = self.disk_cache.store(&cache_key, card.clone()).await;
+
+        Ok(card)
+    }
+
+    /// Repo id for a model reference; bare names pick the exact (or most downloaded) match
+    async fn resolve_model_id(&self, model: &str) -> Result<String> {
+        if model.contains('/') {
+            return Ok(model.to_string());
+        }
+        let models = self.search_models(model, 10).await?;
+        let exact = models.iter().find(|info| {
+            info.model_id
+                .rsplit('/')
+                .next()
+                .is_some_and(|name| name.eq_ignore_ascii_case(model))
+        });
+        exact
+            .or_else(|| models.first())
+            .map(|info| info.model_id.clone())
+            .ok_or_else(|| anyhow::anyhow!("No Hugging Face model matches {model}"))
+    }
+
+    /// Text of a file at the head of a model repo, or `None` when it cannot be fetched
+    async fn fetch_repo_file(&self, model_id: &str, file: &str) -> Option<String> {
+        let url = format!("{}/{}/resolve/main/{}", HF_HUB_BASE, model_id, file);
+        ensure_online(&url).ok()?;
+        match self.http.get(&url).send_with_retry().await {
+            Ok(response) if response.status().is_success() => response.text().await.ok(),
+            Ok(response) => {
+                debug!(url = %url, status = %response.status(), "Model repo file unavailable");
+                None
+            }
+            Err(e) => {
+                debug!(url = %url, error = %e, "Failed to fetch model repo file");
+                None
+            }
+        }
+    }
+
+    fn check_model_status(&self, status: StatusCode, model_id: &str) -> Result<()> {
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) {
+            if self.authenticated {
+                anyhow::bail!("Access to {model_id} was denied; request access to this gated model on huggingface.co");
+            }
+            anyhow::bail!("{model_id} is gated or private; set DOCSMCP_HF_TOKEN (or HF_TOKEN) to look it up");
+        }
+        if !status.is_success() {
+            anyhow::bail!("Model not found: {}", model_id);
+        }
+        Ok(())
+    }
+
     pub fn cache_dir(&self) -> &PathBuf {
         &self.cache_dir
     }
@@ -585,6 +738,29 @@ mod tests {
         let _client = HuggingFaceClient::new();
     }
 
+    #[test]
+    fn test_client_with_token() {
+        let client = HuggingFaceClient::with_token(ApiToken::new("hf_test").as_ref());
+        assert!(client.authenticated);
+        assert!(!HuggingFaceClient::with_token(None).authenticated);
+    }
+
+    #[tokio::test]
+    async fn test_search_hub_api() {
+        let client = HuggingFaceClient::with_token(None);
+        let results = client
+            .search("download file resolve", Some(HfTechnologyKind::HubApi))
+            .await
+            .unwrap();
+        assert_eq!(results[0].path, "hub-api/download-file");
+
+        let article = client.get_article(&results[0].path, HfTechnologyKind::HubApi).await.unwrap();
+        assert!(article.declaration.unwrap().starts_with("GET https://huggingface.co/{repo_id}/resolve"));
+
+        let category = client.get_category("hf:hub-api").await.unwrap();
+        assert_eq!(category.items.len(), HUB_API_ENDPOINTS.len());
+    }
+
     #[test]
     fn test_calculate_score() {
         let terms = vec!["automodel", "llama"];
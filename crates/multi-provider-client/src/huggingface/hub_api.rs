@@ -0,0 +1,430 @@
+//! Hub HTTP API reference.
+//!
+//! The REST endpoints behind `huggingface_hub`, for scripts that call the Hub
+//! directly: repo search and metadata, file download, repo management,
+//! Inference Providers and dedicated Inference Endpoints.
+
+use super::types::{HfArticle, HfExample, HfItemKind, HfParameter, HfTechnologyKind};
+
+/// Hub API reference docs
+pub const HUB_API_DOCS_URL: &str = "https://huggingface.co/docs/hub/api";
+const INFERENCE_PROVIDERS_DOCS_URL: &str = "https://huggingface.co/docs/inference-providers/index";
+const INFERENCE_ENDPOINTS_DOCS_URL: &str = "https://huggingface.co/docs/inference-endpoints/api_reference";
+
+/// Endpoint group within the Hub API reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HubApiGroup {
+    /// Search and metadata for models, datasets and Spaces
+    Repos,
+    /// File listing and download
+    Files,
+    /// Creating, configuring and committing to repos
+    Management,
+    /// Serverless inference through Inference Providers
+    Inference,
+    /// Dedicated Inference Endpoints
+    Endpoints,
+}
+
+impl HubApiGroup {
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Repos => "Repositories",
+            Self::Files => "Files",
+            Self::Management => "Repository management",
+            Self::Inference => "Inference Providers",
+            Self::Endpoints => "Inference Endpoints",
+        }
+    }
+
+    #[must_use]
+    pub const fn docs_url(self) -> &'static str {
+        match self {
+            Self::Repos | Self::Files | Self::Management => HUB_API_DOCS_URL,
+            Self::Inference => INFERENCE_PROVIDERS_DOCS_URL,
+            Self::Endpoints => INFERENCE_ENDPOINTS_DOCS_URL,
+        }
+    }
+}
+
+/// One Hub HTTP API endpoint
+#[derive(Debug, Clone, Copy)]
+pub struct HubApiEndpoint {
+    /// Slug used in paths (`hub-api/<slug>`)
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub group: HubApiGroup,
+    pub method: &'static str,
+    /// Full URL template with `{placeholders}`
+    pub url: &'static str,
+    pub description: &'static str,
+    /// (name, type, description); path placeholders are always required
+    pub parameters: &'static [(&'static str, &'static str, &'static str)],
+    /// curl invocation
+    pub example: &'static str,
+    pub tags: &'static [&'static str],
+}
+
+impl HubApiEndpoint {
+    /// `METHOD url` as shown in declarations
+    #[must_use]
+    pub fn declaration(&self) -> String {
+        format!("{} {}", self.method, self.url)
+    }
+
+    #[must_use]
+    pub fn path(&self) -> String {
+        format!("hub-api/{}", self.slug)
+    }
+
+    #[must_use]
+    pub fn to_article(&self) -> HfArticle {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|(name, param_type, description)| HfParameter {
+                name: (*name).to_string(),
+                description: (*description).to_string(),
+                param_type: Some((*param_type).to_string()),
+                default_value: None,
+                required: self.url.contains(&format!("{{{name}}}")),
+            })
+            .collect();
+        HfArticle {
+            title: self.title.to_string(),
+            description: self.description.to_string(),
+            path: self.path(),
+            url: self.group.docs_url().to_string(),
+            kind: HfItemKind::Endpoint,
+            technology: HfTechnologyKind::HubApi,
+            declaration: Some(self.declaration()),
+            content: format!(
+                "{}\n\nAuthenticate with `Authorization: Bearer $HF_TOKEN`; public repos can be read anonymously at lower rate limits.",
+                self.description
+            ),
+            examples: vec![HfExample {
+                code: self.example.to_string(),
+                language: "bash".to_string(),
+                description: Some(format!("{} with curl", self.title)),
+            }],
+            parameters,
+            return_value: None,
+            related: HUB_API_ENDPOINTS
+                .iter()
+                .filter(|other| other.group == self.group && other.slug != self.slug)
+                .map(|other| other.title.to_string())
+                .collect(),
+            languages: vec!["bash".to_string()],
+        }
+    }
+}
+
+/// Endpoint for a `hub-api/<slug>` path (or bare slug)
+#[must_use]
+pub fn find_endpoint(path: &str) -> Option<&'static HubApiEndpoint> {
+    let slug = path.strip_prefix("hub-api/").unwrap_or(path);
+    HUB_API_ENDPOINTS.iter().find(|endpoint| endpoint.slug == slug)
+}
+
+/// Hub, Inference Providers and Inference Endpoints HTTP API
+pub static HUB_API_ENDPOINTS: &[HubApiEndpoint] = &[
+    // Repositories
+    HubApiEndpoint {
+        slug: "list-models",
+        title: "List models",
+        group: HubApiGroup::Repos,
+        method: "GET",
+        url: "https://huggingface.co/api/models",
+        description: "Search models on the Hub. Results are paginated through the `Link` response header.",
+        parameters: &[
+            ("search", "string", "Substring matched against repo ids"),
+            ("author", "string", "Only models owned by this user or organization"),
+            ("filter", "string", "Tag filter such as `text-generation`, `gguf` or `license:mit`"),
+            ("sort", "string", "Sort key: `downloads`, `likes`, `trendingScore`, `lastModified`"),
+            ("direction", "integer", "`-1` for descending order"),
+            ("limit", "integer", "Maximum number of results"),
+            ("full", "boolean", "Include file listings and last-modified dates"),
+            ("config", "boolean", "Include each model's `config.json` summary"),
+        ],
+        example: "curl -s \"https://huggingface.co/api/models?search=qwen2.5&filter=text-generation&sort=downloads&direction=-1&limit=5\"",
+        tags: &["search", "models", "list", "find", "filter"],
+    },
+    HubApiEndpoint {
+        slug: "model-info",
+        title: "Get model info",
+        group: HubApiGroup::Repos,
+        method: "GET",
+        url: "https://huggingface.co/api/models/{repo_id}",
+        description: "Metadata for one model: tags, pipeline tag, library, downloads, likes, card data and the file list (`siblings`). Append `/revision/{revision}` for a branch, tag or commit. Gated models return 403 until access is granted.",
+        parameters: &[
+            ("repo_id", "string", "Model id, e.g. `Qwen/Qwen2.5-7B-Instruct`"),
+            ("expand[]", "string", "Extra fields to include, e.g. `safetensors`, `gguf`, `inferenceProviderMapping`"),
+        ],
+        example: "curl -s https://huggingface.co/api/models/Qwen/Qwen2.5-7B-Instruct \\\n  -H \"Authorization: Bearer $HF_TOKEN\"",
+        tags: &["metadata", "model", "info", "siblings", "gated"],
+    },
+    HubApiEndpoint {
+        slug: "list-datasets",
+        title: "List datasets",
+        group: HubApiGroup::Repos,
+        method: "GET",
+        url: "https://huggingface.co/api/datasets",
+        description: "Search datasets on the Hub. Takes the same `search`, `author`, `filter`, `sort`, `direction` and `limit` parameters as model search.",
+        parameters: &[
+            ("search", "string", "Substring matched against repo ids"),
+            ("author", "string", "Only datasets owned by this user or organization"),
+            ("filter", "string", "Tag filter such as `task_categories:text-classification`"),
+            ("limit", "integer", "Maximum number of results"),
+        ],
+        example: "curl -s \"https://huggingface.co/api/datasets?search=squad&limit=5\"",
+        tags: &["search", "datasets", "list"],
+    },
+    HubApiEndpoint {
+        slug: "dataset-info",
+        title: "Get dataset info",
+        group: HubApiGroup::Repos,
+        method: "GET",
+        url: "https://huggingface.co/api/datasets/{repo_id}",
+        description: "Metadata for one dataset: tags, card data, downloads and the file list.",
+        parameters: &[("repo_id", "string", "Dataset id, e.g. `rajpurkar/squad`")],
+        example: "curl -s https://huggingface.co/api/datasets/rajpurkar/squad",
+        tags: &["metadata", "dataset", "info"],
+    },
+    HubApiEndpoint {
+        slug: "list-spaces",
+        title: "List Spaces",
+        group: HubApiGroup::Repos,
+        method: "GET",
+        url: "https://huggingface.co/api/spaces",
+        description: "Search Spaces; `GET /api/spaces/{repo_id}` returns one Space's SDK, hardware and runtime stage.",
+        parameters: &[
+            ("search", "string", "Substring matched against repo ids"),
+            ("author", "string", "Only Spaces owned by this user or organization"),
+            ("limit", "integer", "Maximum number of results"),
+        ],
+        example: "curl -s \"https://huggingface.co/api/spaces?author=huggingface&limit=5\"",
+        tags: &["search", "spaces", "list", "gradio"],
+    },
+    // Files
+    HubApiEndpoint {
+        slug: "list-files",
+        title: "List repo files",
+        group: HubApiGroup::Files,
+        method: "GET",
+        url: "https://huggingface.co/api/models/{repo_id}/tree/{revision}",
+        description: "Files and folders of a repo at a revision, with sizes and LFS pointers. Use `/api/datasets/...` or `/api/spaces/...` for other repo types.",
+        parameters: &[
+            ("repo_id", "string", "Repo id"),
+            ("revision", "string", "Branch, tag or commit sha, usually `main`"),
+            ("path", "string", "Folder inside the repo, appended after the revision; omit for the root"),
+            ("recursive", "boolean", "List subfolders too"),
+        ],
+        example: "curl -s \"https://huggingface.co/api/models/Qwen/Qwen2.5-7B-Instruct/tree/main?recursive=true\"",
+        tags: &["files", "tree", "list", "ls"],
+    },
+    HubApiEndpoint {
+        slug: "download-file",
+        title: "Download a file",
+        group: HubApiGroup::Files,
+        method: "GET",
+        url: "https://huggingface.co/{repo_id}/resolve/{revision}/{filename}",
+        description: "Raw file contents. LFS files redirect to the CDN, so follow redirects. Prefix the repo id with `datasets/` or `spaces/` for other repo types. The `X-Repo-Commit` and `ETag` headers identify the exact version.",
+        parameters: &[
+            ("repo_id", "string", "Repo id, e.g. `Qwen/Qwen2.5-7B-Instruct`"),
+            ("revision", "string", "Branch, tag or commit sha, usually `main`"),
+            ("filename", "string", "Path of the file in the repo, e.g. `config.json`"),
+        ],
+        example: "curl -L -o config.json \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  https://huggingface.co/Qwen/Qwen2.5-7B-Instruct/resolve/main/config.json",
+        tags: &["download", "file", "resolve", "weights", "safetensors", "fetch"],
+    },
+    // Repository management
+    HubApiEndpoint {
+        slug: "create-repo",
+        title: "Create a repo",
+        group: HubApiGroup::Management,
+        method: "POST",
+        url: "https://huggingface.co/api/repos/create",
+        description: "Create a model, dataset or Space repo. Needs a write token. `DELETE /api/repos/delete` takes the same body to delete one.",
+        parameters: &[
+            ("name", "string", "Repo name without the namespace"),
+            ("organization", "string", "Organization to create it under; defaults to the token's user"),
+            ("type", "string", "`model` (default), `dataset` or `space`"),
+            ("private", "boolean", "Create the repo as private"),
+            ("sdk", "string", "Space SDK (`gradio`, `docker`, `static`); Spaces only"),
+        ],
+        example: "curl -X POST https://huggingface.co/api/repos/create \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\"name\": \"my-model\", \"type\": \"model\", \"private\": true}'",
+        tags: &["create", "repo", "new", "delete"],
+    },
+    HubApiEndpoint {
+        slug: "repo-settings",
+        title: "Update repo settings",
+        group: HubApiGroup::Management,
+        method: "PUT",
+        url: "https://huggingface.co/api/{repo_type}s/{repo_id}/settings",
+        description: "Change a repo's visibility or gating.",
+        parameters: &[
+            ("repo_type", "string", "`model`, `dataset` or `space`"),
+            ("repo_id", "string", "Repo id"),
+            ("private", "boolean", "Make the repo private or public"),
+            ("gated", "string", "`auto`, `manual` or `false`"),
+        ],
+        example: "curl -X PUT https://huggingface.co/api/models/me/my-model/settings \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\"private\": false}'",
+        tags: &["settings", "visibility", "private", "gated"],
+    },
+    HubApiEndpoint {
+        slug: "commit",
+        title: "Commit files",
+        group: HubApiGroup::Management,
+        method: "POST",
+        url: "https://huggingface.co/api/{repo_type}s/{repo_id}/commit/{revision}",
+        description: "Create a commit. The body is NDJSON: a `header` line with the summary, then one `file` line per small file (base64 content) or `lfsFile` line per already-uploaded LFS object, and `deletedFile` lines for removals.",
+        parameters: &[
+            ("repo_type", "string", "`model`, `dataset` or `space`"),
+            ("repo_id", "string", "Repo id"),
+            ("revision", "string", "Branch to commit to"),
+        ],
+        example: "curl -X POST https://huggingface.co/api/models/me/my-model/commit/main \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/x-ndjson\" \\\n  --data-binary $'{\"key\":\"header\",\"value\":{\"summary\":\"Add README\"}}\\n{\"key\":\"file\",\"value\":{\"path\":\"README.md\",\"content\":\"IyBNeSBtb2RlbAo=\",\"encoding\":\"base64\"}}'",
+        tags: &["commit", "upload", "push", "write"],
+    },
+    HubApiEndpoint {
+        slug: "whoami",
+        title: "Check a token",
+        group: HubApiGroup::Management,
+        method: "GET",
+        url: "https://huggingface.co/api/whoami-v2",
+        description: "The user, organizations and token permissions behind the bearer token.",
+        parameters: &[],
+        example: "curl -s https://huggingface.co/api/whoami-v2 -H \"Authorization: Bearer $HF_TOKEN\"",
+        tags: &["token", "auth", "whoami", "user"],
+    },
+    // Inference Providers
+    HubApiEndpoint {
+        slug: "chat-completions",
+        title: "Chat completions",
+        group: HubApiGroup::Inference,
+        method: "POST",
+        url: "https://router.huggingface.co/v1/chat/completions",
+        description: "OpenAI-compatible chat completions routed to an inference provider serving the model. Append `:provider` (e.g. `:together`) or `:fastest` to the model id to pick one; OpenAI client libraries work with this base URL.",
+        parameters: &[
+            ("model", "string", "Model id, optionally with a `:provider` suffix"),
+            ("messages", "array", "Chat messages with `role` and `content`"),
+            ("max_tokens", "integer", "Maximum tokens to generate"),
+            ("stream", "boolean", "Stream server-sent events"),
+        ],
+        example: "curl https://router.huggingface.co/v1/chat/completions \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\"model\": \"meta-llama/Llama-3.1-8B-Instruct\", \"messages\": [{\"role\": \"user\", \"content\": \"Hello\"}]}'",
+        tags: &["inference", "chat", "completions", "openai", "router", "serverless"],
+    },
+    HubApiEndpoint {
+        slug: "hf-inference",
+        title: "Task inference",
+        group: HubApiGroup::Inference,
+        method: "POST",
+        url: "https://router.huggingface.co/hf-inference/models/{model_id}",
+        description: "Run a model's pipeline task (classification, embeddings, fill-mask, ...) on Hugging Face's own serverless inference. The body shape depends on the task; most take `inputs` plus optional `parameters`.",
+        parameters: &[
+            ("model_id", "string", "Model id"),
+            ("inputs", "string | array", "Task input"),
+            ("parameters", "object", "Task parameters"),
+        ],
+        example: "curl https://router.huggingface.co/hf-inference/models/distilbert/distilbert-base-uncased-finetuned-sst-2-english \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\"inputs\": \"I love this!\"}'",
+        tags: &["inference", "serverless", "pipeline", "embeddings", "classification"],
+    },
+    // Inference Endpoints
+    HubApiEndpoint {
+        slug: "list-endpoints",
+        title: "List Inference Endpoints",
+        group: HubApiGroup::Endpoints,
+        method: "GET",
+        url: "https://api.endpoints.huggingface.cloud/v2/endpoint/{namespace}",
+        description: "Dedicated Inference Endpoints owned by a user or organization, with their model, compute and status.",
+        parameters: &[("namespace", "string", "User or organization name")],
+        example: "curl -s https://api.endpoints.huggingface.cloud/v2/endpoint/my-org \\\n  -H \"Authorization: Bearer $HF_TOKEN\"",
+        tags: &["endpoints", "inference", "list", "dedicated"],
+    },
+    HubApiEndpoint {
+        slug: "create-endpoint",
+        title: "Create an Inference Endpoint",
+        group: HubApiGroup::Endpoints,
+        method: "POST",
+        url: "https://api.endpoints.huggingface.cloud/v2/endpoint/{namespace}",
+        description: "Deploy a model on dedicated hardware. The body names the endpoint and sets `model` (repository, framework, task, image), `compute` (accelerator, instance type and size, scaling) and `provider` (vendor, region).",
+        parameters: &[
+            ("namespace", "string", "User or organization name"),
+            ("name", "string", "Endpoint name"),
+            ("type", "string", "`public`, `protected` or `private`"),
+            ("model", "object", "`repository`, `framework`, `task` and serving `image`"),
+            ("compute", "object", "`accelerator`, `instanceType`, `instanceSize` and `scaling` (`minReplica`, `maxReplica`)"),
+            ("provider", "object", "`vendor` (`aws`, `gcp`, `azure`) and `region`"),
+        ],
+        example: "curl -X POST https://api.endpoints.huggingface.cloud/v2/endpoint/my-org \\\n  -H \"Authorization: Bearer $HF_TOKEN\" \\\n  -H \"Content-Type: application/json\" \\\n  -d '{\n    \"name\": \"qwen-7b\",\n    \"type\": \"protected\",\n    \"model\": {\"repository\": \"Qwen/Qwen2.5-7B-Instruct\", \"framework\": \"pytorch\", \"task\": \"text-generation\", \"image\": {\"huggingface\": {}}},\n    \"compute\": {\"accelerator\": \"gpu\", \"instanceType\": \"nvidia-l4\", \"instanceSize\": \"x1\", \"scaling\": {\"minReplica\": 0, \"maxReplica\": 1}},\n    \"provider\": {\"vendor\": \"aws\", \"region\": \"us-east-1\"}\n  }'",
+        tags: &["endpoints", "inference", "deploy", "create", "dedicated"],
+    },
+    HubApiEndpoint {
+        slug: "endpoint-status",
+        title: "Get an Inference Endpoint",
+        group: HubApiGroup::Endpoints,
+        method: "GET",
+        url: "https://api.endpoints.huggingface.cloud/v2/endpoint/{namespace}/{name}",
+        description: "One endpoint's configuration, `status.state` (`pending`, `initializing`, `running`, `paused`, `scaledToZero`, `failed`) and its `status.url` once running. `DELETE` on the same URL removes it.",
+        parameters: &[
+            ("namespace", "string", "User or organization name"),
+            ("name", "string", "Endpoint name"),
+        ],
+        example: "curl -s https://api.endpoints.huggingface.cloud/v2/endpoint/my-org/qwen-7b \\\n  -H \"Authorization: Bearer $HF_TOKEN\"",
+        tags: &["endpoints", "status", "state", "delete", "dedicated"],
+    },
+    HubApiEndpoint {
+        slug: "pause-endpoint",
+        title: "Pause or resume an Inference Endpoint",
+        group: HubApiGroup::Endpoints,
+        method: "POST",
+        url: "https://api.endpoints.huggingface.cloud/v2/endpoint/{namespace}/{name}/pause",
+        description: "Stop billing for an endpoint until it is resumed with `/resume`. `/scale-to-zero` releases replicas but wakes on the next request.",
+        parameters: &[
+            ("namespace", "string", "User or organization name"),
+            ("name", "string", "Endpoint name"),
+        ],
+        example: "curl -X POST https://api.endpoints.huggingface.cloud/v2/endpoint/my-org/qwen-7b/pause \\\n  -H \"Authorization: Bearer $HF_TOKEN\"",
+        tags: &["endpoints", "pause", "resume", "scale-to-zero", "dedicated"],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints_are_unique_and_documented() {
+        for (index, endpoint) in HUB_API_ENDPOINTS.iter().enumerate() {
+            assert!(
+                HUB_API_ENDPOINTS[index + 1..].iter().all(|other| other.slug != endpoint.slug),
+                "duplicate slug {}",
+                endpoint.slug
+            );
+            assert!(endpoint.example.contains("curl"), "{}", endpoint.slug);
+            for placeholder in endpoint.url.split('{').skip(1).filter_map(|part| part.split_once('}')) {
+                assert!(
+                    endpoint.parameters.iter().any(|(name, _, _)| *name == placeholder.0),
+                    "{} lacks parameter {}",
+                    endpoint.slug,
+                    placeholder.0
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_endpoint() {
+        let download = find_endpoint("hub-api/download-file").unwrap();
+        assert_eq!(download.declaration(), "GET https://huggingface.co/{repo_id}/resolve/{revision}/{filename}");
+
+        let article = download.to_article();
+        assert_eq!(article.path, "hub-api/download-file");
+        assert!(article.parameters.iter().all(|p| p.required));
+        assert_eq!(article.examples[0].language, "bash");
+
+        assert!(find_endpoint("list-models").is_some());
+        assert!(find_endpoint("hub-api/unknown").is_none());
+    }
+}
@@ -5,9 +5,15 @@
 //! - Swift Transformers for iOS/macOS
 //! - Model Hub documentation
 //! - Tokenizers library
+//! - Model cards (architecture, context length, usage) for Hub models
+//! - Hub HTTP API endpoints for scripting against the Hub without the Python library
 
+pub mod card;
 pub mod client;
+pub mod hub_api;
 pub mod types;
 
+pub use card::model_reference;
 pub use client::HuggingFaceClient;
+pub use hub_api::{find_endpoint, HubApiEndpoint, HubApiGroup, HUB_API_ENDPOINTS};
 pub use types::*;
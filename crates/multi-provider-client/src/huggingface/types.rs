@@ -34,6 +34,8 @@ pub enum HfTechnologyKind {
     Peft,
     /// Hub Python library
     Hub,
+    /// Hub HTTP API (REST endpoints, Inference Providers, Inference Endpoints)
+    HubApi,
 }
 
 impl std::fmt::Display for HfTechnologyKind {
@@ -47,6 +49,7 @@ impl std::fmt::Display for HfTechnologyKind {
             Self::Diffusers => write!(f, "diffusers"),
             Self::Peft => write!(f, "peft"),
             Self::Hub => write!(f, "hub"),
+            Self::HubApi => write!(f, "hub-api"),
         }
     }
 }
@@ -92,6 +95,8 @@ pub enum HfItemKind {
     Guide,
     /// Dataset
     Dataset,
+    /// HTTP API endpoint
+    Endpoint,
 }
 
 impl std::fmt::Display for HfItemKind {
@@ -106,6 +111,7 @@ impl std::fmt::Display for HfItemKind {
             Self::Pipeline => write!(f, "pipeline"),
             Self::Guide => write!(f, "guide"),
             Self::Dataset => write!(f, "dataset"),
+            Self::Endpoint => write!(f, "endpoint"),
         }
     }
 }
@@ -187,6 +193,84 @@ pub struct HfModelInfo {
     pub library_name: Option<String>,
 }
 
+/// Model card facts for a Hub model, from its card and `config.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HfModelCard {
+    pub model_id: String,
+    pub url: String,
+    /// First paragraph of the model card
+    pub summary: Option<String>,
+    pub pipeline_tag: Option<String>,
+    pub library_name: Option<String>,
+    pub license: Option<String>,
+    /// Model classes from `config.json` (e.g. `Qwen2ForCausalLM`)
+    pub architectures: Vec<String>,
+    pub model_type: Option<String>,
+    /// Maximum context length in tokens
+    pub context_length: Option<u64>,
+    /// transformers version the checkpoint was saved with
+    pub transformers_version: Option<String>,
+    pub torch_dtype: Option<String>,
+    pub downloads: i64,
+    pub likes: i64,
+    /// Usage snippet from the model card
+    pub usage: Option<HfExample>,
+}
+
+impl HfModelCard {
+    /// Key facts as markdown bullet lines
+    #[must_use]
+    pub fn facts(&self) -> Vec<String> {
+        let mut facts = Vec::new();
+        if !self.architectures.is_empty() {
+            let model_type = self.model_type.as_ref().map(|t| format!(" (`{t}`)")).unwrap_or_default();
+            facts.push(format!("- **Architecture:** {}{}", self.architectures.join(", "), model_type));
+        }
+        if let Some(length) = self.context_length {
+            facts.push(format!("- **Context length:** {length} tokens"));
+        }
+        if let Some(version) = &self.transformers_version {
+            facts.push(format!("- **Requires:** transformers >= {version}"));
+        }
+        if let Some(dtype) = &self.torch_dtype {
+            facts.push(format!("- **Weights dtype:** {dtype}"));
+        }
+        if let Some(task) = &self.pipeline_tag {
+            facts.push(format!("- **Task:** {task}"));
+        }
+        if let Some(library) = &self.library_name {
+            facts.push(format!("- **Library:** {library}"));
+        }
+        if let Some(license) = &self.license {
+            facts.push(format!("- **License:** {license}"));
+        }
+        facts.push(format!("- **Downloads:** {} · **Likes:** {}", self.downloads, self.likes));
+        facts
+    }
+
+    /// The card as a documentation article
+    #[must_use]
+    pub fn to_article(&self) -> HfArticle {
+        let mut content = self.summary.clone().map(|summary| vec![summary, String::new()]).unwrap_or_default();
+        content.extend(self.facts());
+        HfArticle {
+            title: self.model_id.clone(),
+            description: self.summary.clone().unwrap_or_else(|| format!("{} on the Hugging Face Hub", self.model_id)),
+            path: format!("models/{}", self.model_id),
+            url: self.url.clone(),
+            kind: HfItemKind::Model,
+            technology: HfTechnologyKind::Models,
+            declaration: None,
+            content: content.join("\n"),
+            examples: self.usage.iter().cloned().collect(),
+            parameters: vec![],
+            return_value: None,
+            related: self.architectures.clone(),
+            languages: vec!["python".to_string()],
+        }
+    }
+}
+
 /// Transformers library predefined topics
 pub const TRANSFORMERS_TOPICS: &[(&str, &str, &str, HfItemKind)] = &[
     // AutoClasses
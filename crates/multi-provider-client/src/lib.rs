@@ -20,25 +20,34 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::unused_self)]
 
+pub mod bitcoind;
 pub mod claude_agent_sdk;
 pub mod cocoon;
+pub mod credentials;
 pub mod cuda;
 pub mod huggingface;
 pub mod mdn;
+pub mod memory;
 pub mod mlx;
 pub mod quicknode;
 pub mod rust;
+pub mod solana;
 pub mod telegram;
+pub mod timeouts;
 pub mod ton;
 pub mod types;
-pub mod vertcoin;
 pub mod web_frameworks;
 
 use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::Result;
-use docs_mcp_client::AppleDocsClient;
+use directories::ProjectDirs;
+use docs_mcp_client::{AppleDocsClient, ClientConfig};
 
+use bitcoind::BitcoindClient;
 use claude_agent_sdk::ClaudeAgentSdkClient;
 use cocoon::CocoonClient;
 use cuda::CudaClient;
@@ -47,10 +56,10 @@ use mdn::MdnClient;
 use mlx::MlxClient;
 use quicknode::QuickNodeClient;
 use rust::RustClient;
+use solana::SolanaClient;
 use telegram::TelegramClient;
 use ton::TonClient;
 use types::{ProviderType, UnifiedFrameworkData, UnifiedSymbolData, UnifiedTechnology};
-use vertcoin::VertcoinClient;
 use web_frameworks::WebFrameworksClient;
 
 /// All provider clients for simultaneous access
@@ -67,8 +76,9 @@ pub struct ProviderClients {
     pub huggingface: HuggingFaceClient,
     pub quicknode: QuickNodeClient,
     pub claude_agent_sdk: ClaudeAgentSdkClient,
-    pub vertcoin: VertcoinClient,
+    pub bitcoind: BitcoindClient,
     pub cuda: CudaClient,
+    pub solana: SolanaClient,
 }
 
 impl Default for ProviderClients {
@@ -77,11 +87,94 @@ impl Default for ProviderClients {
     }
 }
 
+/// Technologies gathered from every provider by [`ProviderClients::get_all_technologies`].
+#[derive(Debug, Default)]
+pub struct AllTechnologies {
+    /// Technologies from each provider that loaded successfully.
+    pub technologies: HashMap<ProviderType, Vec<UnifiedTechnology>>,
+    /// Why each degraded provider failed to load, including providers that timed out.
+    pub errors: HashMap<ProviderType, anyhow::Error>,
+    /// Providers that did not answer before the federated deadline.
+    pub timed_out: Vec<ProviderType>,
+}
+
+impl AllTechnologies {
+    /// Store a provider's outcome as either technologies or an error.
+    pub fn record(&mut self, provider: ProviderType, outcome: Result<Vec<UnifiedTechnology>>) {
+        match outcome {
+            Ok(technologies) => {
+                self.technologies.insert(provider, technologies);
+            }
+            Err(error) => {
+                tracing::warn!(
+                    provider = provider.slug(),
+                    error = %error,
+                    "provider technologies unavailable"
+                );
+                self.errors.insert(provider, error);
+            }
+        }
+    }
+
+    /// Store a provider's outcome, or mark it as timed out when it missed `deadline`.
+    fn settle(
+        &mut self,
+        provider: ProviderType,
+        outcome: Option<Result<Vec<UnifiedTechnology>>>,
+        deadline: Duration,
+    ) {
+        match outcome {
+            Some(outcome) => self.record(provider, outcome),
+            None => {
+                self.timed_out.push(provider);
+                self.record(
+                    provider,
+                    Err(anyhow::anyhow!("timed out after {:.1}s", deadline.as_secs_f64())),
+                );
+            }
+        }
+    }
+
+    /// Providers that failed to load, in [`ProviderType::ALL`] order.
+    pub fn degraded_providers(&self) -> Vec<ProviderType> {
+        ProviderType::ALL
+            .into_iter()
+            .filter(|provider| self.errors.contains_key(provider))
+            .collect()
+    }
+}
+
+/// Run `future`, giving up with `None` once `deadline` has passed.
+async fn within<T>(deadline: Duration, future: impl Future<Output = T>) -> Option<T> {
+    tokio::time::timeout(deadline, future).await.ok()
+}
+
+/// Convert a provider's native technologies, keeping any error or timeout.
+fn unify<T>(
+    outcome: Option<Result<impl IntoIterator<Item = T>>>,
+    convert: impl Fn(T) -> UnifiedTechnology,
+) -> Option<Result<Vec<UnifiedTechnology>>> {
+    outcome.map(|outcome| {
+        outcome.map(|technologies| technologies.into_iter().map(convert).collect())
+    })
+}
+
+/// Root directory holding each provider's disk cache in a `ProviderType::slug()` subdirectory.
+///
+/// Apple documentation is cached separately by `docs_mcp_client::AppleDocsClient`.
+pub fn provider_cache_root() -> Option<PathBuf> {
+    ProjectDirs::from("com", "RecordAndLearn", "multi-docs-mcp")
+        .map(|dirs| dirs.cache_dir().to_path_buf())
+}
+
 impl ProviderClients {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            apple: AppleDocsClient::new(),
+            apple: AppleDocsClient::with_config(ClientConfig {
+                timeouts: timeouts::timeouts_for(ProviderType::Apple),
+                ..ClientConfig::default()
+            }),
             telegram: TelegramClient::new(),
             ton: TonClient::new(),
             cocoon: CocoonClient::new(),
@@ -92,143 +185,64 @@ impl ProviderClients {
             huggingface: HuggingFaceClient::new(),
             quicknode: QuickNodeClient::new(),
             claude_agent_sdk: ClaudeAgentSdkClient::new(),
-            vertcoin: VertcoinClient::new(),
+            bitcoind: BitcoindClient::new(),
             cuda: CudaClient::new(),
+            solana: SolanaClient::new(),
         }
     }
 
     /// Get technologies from all providers.
     ///
-    /// # Errors
-    ///
-    /// Currently this function does not return an error; individual provider failures are
-    /// omitted from the returned map.
-    #[allow(clippy::too_many_lines)]
-    pub async fn get_all_technologies(
-        &self,
-    ) -> Result<HashMap<ProviderType, Vec<UnifiedTechnology>>> {
-        let (apple, telegram, ton, cocoon, rust, mdn, webfw, mlx, hf, qn, agent_sdk, vtc, cuda) = tokio::join!(
-            self.apple.get_technologies(),
-            self.telegram.get_technologies(),
-            self.ton.get_technologies(),
-            self.cocoon.get_technologies(),
-            self.rust.get_technologies(),
-            self.mdn.get_technologies(),
-            self.web_frameworks.get_technologies(),
-            self.mlx.get_technologies(),
-            self.huggingface.get_technologies(),
-            self.quicknode.get_technologies(),
-            self.claude_agent_sdk.get_technologies(),
-            self.vertcoin.get_technologies(),
-            self.cuda.get_technologies()
-        );
-
-        let mut result = HashMap::new();
-
-        if let Ok(techs) = apple {
-            result.insert(
-                ProviderType::Apple,
-                techs
-                    .into_values()
-                    .map(UnifiedTechnology::from_apple)
-                    .collect(),
-            );
-        }
-
-        if let Ok(techs) = telegram {
-            result.insert(
-                ProviderType::Telegram,
-                techs.into_iter().map(UnifiedTechnology::from_telegram).collect(),
-            );
-        }
-
-        if let Ok(techs) = ton {
-            result.insert(
-                ProviderType::TON,
-                techs.into_iter().map(UnifiedTechnology::from_ton).collect(),
-            );
-        }
-
-        if let Ok(techs) = cocoon {
-            result.insert(
-                ProviderType::Cocoon,
-                techs.into_iter().map(UnifiedTechnology::from_cocoon).collect(),
-            );
-        }
-
-        if let Ok(techs) = rust {
-            result.insert(
-                ProviderType::Rust,
-                techs.into_iter().map(UnifiedTechnology::from_rust).collect(),
-            );
-        }
-
-        if let Ok(techs) = mdn {
-            result.insert(
-                ProviderType::Mdn,
-                techs.into_iter().map(UnifiedTechnology::from_mdn).collect(),
-            );
-        }
-
-        if let Ok(techs) = webfw {
-            result.insert(
-                ProviderType::WebFrameworks,
-                techs.into_iter().map(UnifiedTechnology::from_web_framework).collect(),
-            );
-        }
-
-        if let Ok(techs) = mlx {
-            result.insert(
-                ProviderType::Mlx,
-                techs.into_iter().map(UnifiedTechnology::from_mlx).collect(),
-            );
-        }
-
-        if let Ok(techs) = hf {
-            result.insert(
-                ProviderType::HuggingFace,
-                techs.into_iter().map(UnifiedTechnology::from_huggingface).collect(),
-            );
-        }
-
-        if let Ok(techs) = qn {
-            result.insert(
-                ProviderType::QuickNode,
-                techs.into_iter().map(UnifiedTechnology::from_quicknode).collect(),
-            );
-        }
-
-        if let Ok(techs) = agent_sdk {
-            result.insert(
-                ProviderType::ClaudeAgentSdk,
-                techs
-                    .into_iter()
-                    .map(UnifiedTechnology::from_claude_agent_sdk)
-                    .collect(),
-            );
-        }
-
-        if let Ok(techs) = vtc {
-            result.insert(
-                ProviderType::Vertcoin,
-                techs
-                    .into_iter()
-                    .map(UnifiedTechnology::from_vertcoin)
-                    .collect(),
-            );
-        }
+    /// Providers that fail to load are reported in [`AllTechnologies::errors`] rather than
+    /// failing the whole call. Providers still loading after
+    /// [`timeouts::federated_deadline`] are abandoned and reported as timed out, so one slow
+    /// provider can't hold up the others.
+    pub async fn get_all_technologies(&self) -> AllTechnologies {
+        Box::pin(self.get_all_technologies_within(timeouts::federated_deadline())).await
+    }
 
-        if let Ok(techs) = cuda {
-            result.insert(
-                ProviderType::Cuda,
-                techs
-                    .into_iter()
-                    .map(UnifiedTechnology::from_cuda)
-                    .collect(),
-            );
-        }
+    /// [`Self::get_all_technologies`] with an explicit soft deadline.
+    pub async fn get_all_technologies_within(&self, deadline: Duration) -> AllTechnologies {
+        let (apple, telegram, ton, cocoon, rust, mdn, webfw, mlx, hf, qn, agent_sdk, vtc, cuda, solana) = tokio::join!(
+            within(deadline, self.apple.get_technologies()),
+            within(deadline, self.telegram.get_technologies()),
+            within(deadline, self.ton.get_technologies()),
+            within(deadline, self.cocoon.get_technologies()),
+            within(deadline, self.rust.get_technologies()),
+            within(deadline, self.mdn.get_technologies()),
+            within(deadline, self.web_frameworks.get_technologies()),
+            within(deadline, self.mlx.get_technologies()),
+            within(deadline, self.huggingface.get_technologies()),
+            within(deadline, self.quicknode.get_technologies()),
+            within(deadline, self.claude_agent_sdk.get_technologies()),
+            within(deadline, self.bitcoind.get_technologies()),
+            within(deadline, self.cuda.get_technologies()),
+            within(deadline, self.solana.get_technologies())
+        );
 
-        Ok(result)
+        let mut all = AllTechnologies::default();
+        let mut settle = |provider, outcome| all.settle(provider, outcome, deadline);
+        settle(
+            ProviderType::Apple,
+            unify(apple.map(|outcome| outcome.map(HashMap::into_values)), UnifiedTechnology::from_apple),
+        );
+        settle(ProviderType::Telegram, unify(telegram, UnifiedTechnology::from_telegram));
+        settle(ProviderType::TON, unify(ton, UnifiedTechnology::from_ton));
+        settle(ProviderType::Cocoon, unify(cocoon, UnifiedTechnology::from_cocoon));
+        settle(ProviderType::Rust, unify(rust, UnifiedTechnology::from_rust));
+        settle(ProviderType::Mdn, unify(mdn, UnifiedTechnology::from_mdn));
+        settle(ProviderType::WebFrameworks, unify(webfw, UnifiedTechnology::from_web_framework));
+        settle(ProviderType::Mlx, unify(mlx, UnifiedTechnology::from_mlx));
+        settle(ProviderType::HuggingFace, unify(hf, UnifiedTechnology::from_huggingface));
+        settle(ProviderType::QuickNode, unify(qn, UnifiedTechnology::from_quicknode));
+        settle(
+            ProviderType::ClaudeAgentSdk,
+            unify(agent_sdk, UnifiedTechnology::from_claude_agent_sdk),
+        );
+        settle(ProviderType::Bitcoind, unify(vtc, UnifiedTechnology::from_bitcoind));
+        settle(ProviderType::Cuda, unify(cuda, UnifiedTechnology::from_cuda));
+        settle(ProviderType::Solana, unify(solana, UnifiedTechnology::from_solana));
+        all
     }
 
     /// Get technologies for a specific provider.
@@ -291,11 +305,11 @@ impl ProviderClients {
                     .map(UnifiedTechnology::from_claude_agent_sdk)
                     .collect())
             }
-            ProviderType::Vertcoin => {
-                let techs = self.vertcoin.get_technologies().await?;
+            ProviderType::Bitcoind => {
+                let techs = self.bitcoind.get_technologies().await?;
                 Ok(techs
                     .into_iter()
-                    .map(UnifiedTechnology::from_vertcoin)
+                    .map(UnifiedTechnology::from_bitcoind)
                     .collect())
             }
             ProviderType::Cuda => {
@@ -305,6 +319,13 @@ impl ProviderClients {
                     .map(UnifiedTechnology::from_cuda)
                     .collect())
             }
+            ProviderType::Solana => {
+                let techs = self.solana.get_technologies().await?;
+                Ok(techs
+                    .into_iter()
+                    .map(UnifiedTechnology::from_solana)
+                    .collect())
+            }
         }
     }
 
@@ -364,14 +385,18 @@ impl ProviderClients {
                 let data = self.claude_agent_sdk.get_category(identifier).await?;
                 Ok(UnifiedFrameworkData::from_claude_agent_sdk(data))
             }
-            ProviderType::Vertcoin => {
-                let data = self.vertcoin.get_category(identifier).await?;
-                Ok(UnifiedFrameworkData::from_vertcoin(data))
+            ProviderType::Bitcoind => {
+                let data = self.bitcoind.get_category(identifier).await?;
+                Ok(UnifiedFrameworkData::from_bitcoind(data))
             }
             ProviderType::Cuda => {
                 let data = self.cuda.get_category(identifier).await?;
                 Ok(UnifiedFrameworkData::from_cuda(data))
             }
+            ProviderType::Solana => {
+                let data = self.solana.get_category(identifier).await?;
+                Ok(UnifiedFrameworkData::from_solana(data))
+            }
         }
     }
 
@@ -420,6 +445,10 @@ impl ProviderClients {
                 Ok(UnifiedSymbolData::from_web_framework(data))
             }
             ProviderType::Mlx => {
+                // mlx-lm and mlx-examples paths ("lm/generate", "examples/mnist") are embedded
+                if let Some(topic) = mlx::find_lm_topic(path) {
+                    return Ok(UnifiedSymbolData::from_mlx(topic.to_article()));
+                }
                 // Parse the path to determine language (e.g., "swift/MLXArray" or "python/mlx.core.array")
                 let parts: Vec<&str> = path.splitn(2, '/').collect();
                 let language = if parts[0].to_lowercase().contains("python") {
@@ -432,9 +461,16 @@ impl ProviderClients {
                 Ok(UnifiedSymbolData::from_mlx(data))
             }
             ProviderType::HuggingFace => {
+                // Model cards: "models/Qwen/Qwen2.5-7B-Instruct"
+                if let Some(model) = path.strip_prefix("models/").filter(|model| model.contains('/')) {
+                    let card = self.huggingface.get_model_card(model).await?;
+                    return Ok(UnifiedSymbolData::from_huggingface(card.to_article()));
+                }
                 // Parse the path to determine technology (e.g., "transformers/AutoModel" or "swift-transformers/Hub")
                 let parts: Vec<&str> = path.splitn(2, '/').collect();
-                let technology = if parts[0].to_lowercase().contains("swift") {
+                let technology = if parts[0] == "hub-api" {
+                    huggingface::types::HfTechnologyKind::HubApi
+                } else if parts[0].to_lowercase().contains("swift") {
                     huggingface::types::HfTechnologyKind::SwiftTransformers
                 } else {
                     huggingface::types::HfTechnologyKind::Transformers
@@ -459,14 +495,18 @@ impl ProviderClients {
                 let data = self.claude_agent_sdk.get_article(slug, language).await?;
                 Ok(UnifiedSymbolData::from_claude_agent_sdk(data))
             }
-            ProviderType::Vertcoin => {
-                let data = self.vertcoin.get_method(path).await?;
-                Ok(UnifiedSymbolData::from_vertcoin(data))
+            ProviderType::Bitcoind => {
+                let data = self.bitcoind.get_method(path).await?;
+                Ok(UnifiedSymbolData::from_bitcoind(data))
             }
             ProviderType::Cuda => {
                 let data = self.cuda.get_method(path).await?;
                 Ok(UnifiedSymbolData::from_cuda(data))
             }
+            ProviderType::Solana => {
+                let data = self.solana.get_article(path).await?;
+                Ok(UnifiedSymbolData::from_solana(data))
+            }
         }
     }
 }
@@ -479,4 +519,59 @@ mod tests {
     fn test_provider_clients_creation() {
         let _clients = ProviderClients::new();
     }
+
+    #[test]
+    fn test_all_technologies_records_errors() {
+        let mut all = AllTechnologies::default();
+        all.record(ProviderType::Rust, Ok(Vec::new()));
+        all.record(ProviderType::Mdn, Err(anyhow::anyhow!("503 Service Unavailable")));
+        all.record(ProviderType::Telegram, Err(anyhow::anyhow!("timed out")));
+
+        assert!(all.technologies.contains_key(&ProviderType::Rust));
+        assert_eq!(all.degraded_providers(), vec![ProviderType::Telegram, ProviderType::Mdn]);
+        assert_eq!(all.errors[&ProviderType::Mdn].to_string(), "503 Service Unavailable");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_providers_time_out() {
+        let deadline = Duration::from_secs(1);
+        let fast = within(deadline, async { Ok(vec![1]) }).await;
+        let slow = within(deadline, async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(vec![2])
+        })
+        .await;
+
+        let mut all = AllTechnologies::default();
+        all.settle(ProviderType::Rust, unify(fast, |_| sample_technology()), deadline);
+        all.settle(ProviderType::Cuda, unify(slow, |_| sample_technology()), deadline);
+
+        assert_eq!(all.technologies[&ProviderType::Rust].len(), 1);
+        assert_eq!(all.timed_out, vec![ProviderType::Cuda]);
+        assert_eq!(all.errors[&ProviderType::Cuda].to_string(), "timed out after 1.0s");
+    }
+
+    fn sample_technology() -> UnifiedTechnology {
+        UnifiedTechnology {
+            provider: ProviderType::Rust,
+            identifier: "std".to_string(),
+            title: "std".to_string(),
+            description: String::new(),
+            url: None,
+            kind: types::TechnologyKind::RustCrate,
+        }
+    }
+
+    #[test]
+    fn test_provider_from_slug() {
+        assert_eq!(ProviderType::from_slug("web-frameworks"), Some(ProviderType::WebFrameworks));
+        assert_eq!(ProviderType::from_slug("Hugging Face"), Some(ProviderType::HuggingFace));
+        assert_eq!(ProviderType::from_slug("TON"), Some(ProviderType::TON));
+        assert_eq!(ProviderType::from_slug("bitcoind"), Some(ProviderType::Bitcoind));
+        assert_eq!(ProviderType::from_slug("vertcoin"), Some(ProviderType::Bitcoind));
+        assert_eq!(ProviderType::from_slug("unknown"), None);
+        for provider in ProviderType::ALL {
+            assert_eq!(ProviderType::from_slug(provider.slug()), Some(provider));
+        }
+    }
 }
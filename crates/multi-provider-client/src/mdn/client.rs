@@ -1,6 +1,5 @@
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
@@ -8,19 +7,41 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use reqwest::Client;
 use scraper::{Html, Selector};
+use serde_json::Value;
 use tokio::sync::RwLock;
 use tracing::{debug, instrument, warn};
 
 use super::types::{
-    MdnArticle, MdnCategory, MdnDocument, MdnDocumentResponse, MdnExample, MdnParameter,
-    MdnSearchDocument, MdnSearchEntry, MdnSearchResponse, MdnTechnology,
+    MdnArticle, MdnBrowserSupport, MdnCategory, MdnCssKind, MdnHtmlAttribute, MdnInterfaceMember,
+    MdnMemberKind, MdnDocument, MdnDocumentResponse, MdnExample, MdnParameter,
+    MdnSearchDocument, MdnSearchEntry, MdnSearchResponse, MdnSectionValue, MdnTechnology,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const MDN_SEARCH_API: &str = "https://developer.mozilla.org/api/v1/search";
 const MDN_DOCUMENT_API: &str = "https://developer.mozilla.org";
 const MDN_BASE_URL: &str = "https://developer.mozilla.org/en-US/docs";
-const ARTICLE_CACHE_VERSION: u32 = 2;
+const BCD_API: &str = "https://bcd.developer.mozilla.org/bcd/api/v0/current";
+const ARTICLE_CACHE_VERSION: u32 = 6;
+
+/// Browsers in support tables, with display names
+const BCD_BROWSERS: &[(&str, &str)] = &[
+    ("chrome", "Chrome"),
+    ("edge", "Edge"),
+    ("firefox", "Firefox"),
+    ("safari", "Safari"),
+    ("safari_ios", "Safari on iOS"),
+];
+
+/// Accessibility notes kept per element page
+const MAX_ACCESSIBILITY_NOTES: usize = 6;
 
 static PRE_BLOCK_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"(?is)<pre[^>]*>.*?</pre>").expect("pre block regex"));
@@ -29,7 +50,7 @@ static PRE_BLOCK_RE: Lazy<Regex> =
 pub struct MdnClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     /// Cached search results by query
     search_cache: RwLock<HashMap<String, Vec<MdnSearchEntry>>>,
     cache_dir: PathBuf,
@@ -52,9 +73,8 @@ impl MdnClient {
             warn!(error = %e, "Failed to create MDN cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Mdn))
             .user_agent("MultiDocsMCP/1.0 (Documentation Search Tool)")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -62,7 +82,7 @@ impl MdnClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(1)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Mdn, memory_ttl(ProviderType::Mdn)),
             search_cache: RwLock::new(HashMap::new()),
             cache_dir,
         }
@@ -103,10 +123,11 @@ impl MdnClient {
         );
         debug!(url = %url, "Searching MDN");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to search MDN")?;
 
@@ -150,10 +171,11 @@ impl MdnClient {
         let url = format!("{}/{}/index.json", MDN_DOCUMENT_API, slug);
         debug!(url = %url, "Fetching MDN article");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch MDN article")?;
 
@@ -167,7 +189,14 @@ impl MdnClient {
             .await
             .context("Failed to parse MDN document response")?;
 
-        let article = self.document_to_article(doc_response.doc, slug);
+        let mut article = self.document_to_article(doc_response.doc, slug);
+
+        if let Some(query) = article.browser_compat.clone() {
+            match self.get_browser_support(&query).await {
+                Ok(support) => article.browser_support = support,
+                Err(e) => debug!(query = %query, error = %e, "Failed to fetch browser compatibility data"),
+            }
+        }
 
         // Cache the result
         let _ = self.disk_cache.store(&cache_key, article.clone()).await;
@@ -175,15 +204,39 @@ impl MdnClient {
         Ok(article)
     }
 
+    /// Per-browser support for a browser-compat-data key such as `css.properties.display`
+    #[instrument(name = "mdn_client.get_browser_support", skip(self))]
+    pub async fn get_browser_support(&self, query: &str) -> Result<Vec<MdnBrowserSupport>> {
+        let url = format!("{BCD_API}/{query}.json");
+        ensure_online(&url)?;
+        let response = self
+            .http
+            .get(&url)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch browser compatibility data")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Browser compatibility data not found: {}", query);
+        }
+
+        let data: Value = response
+            .json()
+            .await
+            .context("Failed to parse browser compatibility data")?;
+        Ok(parse_browser_support(&data))
+    }
+
     /// Fetch article via HTML scraping (fallback)
     async fn fetch_article_html(&self, slug: &str) -> Result<MdnArticle> {
         let url = format!("{}/{}", MDN_BASE_URL, slug);
         debug!(url = %url, "Fetching MDN article via HTML");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch MDN HTML page")?;
 
@@ -209,6 +262,8 @@ impl MdnClient {
 
         // Extract syntax
         let syntax = self.extract_selector_text(&document, ".syntaxbox, pre.syntaxbox, .brush.js");
+        let formal_syntax = self.extract_selector_text(&document, "pre.css-formal-syntax, #formal_syntax ~ pre");
+        let css_kind = MdnCssKind::from_article(slug, &title);
 
         // Extract parameters
         let parameters = self.extract_parameters_from_html(&document);
@@ -225,6 +280,12 @@ impl MdnClient {
             return_value: self.extract_return_value_from_html(&document),
             browser_compat: None,
             content: self.extract_content_from_html(&document),
+            css_kind,
+            formal_syntax,
+            browser_support: Vec::new(),
+            attributes: Vec::new(),
+            members: Vec::new(),
+            accessibility_notes: Vec::new(),
         })
     }
 
@@ -376,6 +437,7 @@ impl MdnClient {
             });
 
         MdnSearchEntry {
+            css_kind: MdnCssKind::from_article(&slug, &doc.title),
             slug: slug.clone(),
             title: doc.title,
             summary: doc.summary,
@@ -388,27 +450,51 @@ impl MdnClient {
     fn document_to_article(&self, doc: MdnDocument, slug: &str) -> MdnArticle {
         let mut examples = Vec::new();
         let mut syntax = None;
+        let mut formal_syntax = None;
+        let mut browser_compat = None;
+        let mut attributes = Vec::new();
+        let mut members = Vec::new();
+        let mut accessibility_notes = Vec::new();
+        let mut aria_roles = Vec::new();
+        let css_kind = MdnCssKind::from_article(slug, &doc.title);
         let mut content_parts = Vec::new();
         let mut example_dedupe = HashSet::<String>::new();
         let pre_selector = Selector::parse("pre").ok();
 
         for section in &doc.body {
             match &section.value {
-                Some(super::types::MdnSectionValue::Code { code, language }) => {
-                    if !code.is_empty() {
-                        let lang = language.as_deref().unwrap_or("javascript");
-                        examples.push(MdnExample {
-                            code: code.clone(),
-                            language: lang.to_string(),
-                            description: None,
-                            is_runnable: code.contains("function ")
-                                || code.contains("const ")
-                                || code.contains("=>"),
-                        });
-                    }
+                Some(MdnSectionValue::Code { code, language }) if !code.is_empty() => {
+                    let lang = language.as_deref().unwrap_or("javascript");
+                    examples.push(MdnExample {
+                        code: code.clone(),
+                        language: lang.to_string(),
+                        description: None,
+                        is_runnable: code.contains("function ")
+                            || code.contains("const ")
+                            || code.contains("=>"),
+                    });
                 }
-                Some(super::types::MdnSectionValue::Prose { content }) => {
-                    if examples.len() < 5 {
+                Some(MdnSectionValue::Prose { content, id }) => {
+                    let id = id.as_deref().unwrap_or_default();
+                    if let Some(kind) = MdnMemberKind::from_section_id(id) {
+                        members.extend(parse_interface_members(content, kind));
+                    } else if id == "technical_summary" {
+                        aria_roles = parse_aria_roles(content);
+                    } else if id.contains("attributes") && !id.contains("global") {
+                        attributes.extend(parse_html_attributes(content));
+                    } else if id.starts_with("accessibility") {
+                        accessibility_notes.extend(parse_accessibility_notes(content));
+                    }
+                    // The value definition grammar is not an example
+                    if id == "formal_syntax" {
+                        if let Some(selector) = &pre_selector {
+                            formal_syntax = Html::parse_fragment(content)
+                                .select(selector)
+                                .next()
+                                .map(|pre| pre.text().collect::<String>().trim().to_string())
+                                .filter(|grammar| !grammar.is_empty());
+                        }
+                    } else if examples.len() < 5 {
                         if let Some(selector) = &pre_selector {
                             let fragment = Html::parse_fragment(content);
                             for pre in fragment.select(selector) {
@@ -424,7 +510,10 @@ impl MdnClient {
                                     || code.contains("let ")
                                     || code.contains("=>");
 
-                                if syntax.is_none() && looks_like_syntax_snippet(&code) {
+                                // CSS syntax blocks are declarations (`display: flex;`), not calls
+                                if syntax.is_none()
+                                    && (looks_like_syntax_snippet(&code) || (css_kind.is_some() && id == "syntax"))
+                                {
                                     syntax = Some(code.clone());
                                 }
 
@@ -456,10 +545,17 @@ impl MdnClient {
                         content_parts.push(text);
                     }
                 }
-                Some(super::types::MdnSectionValue::Text(text)) => {
+                Some(MdnSectionValue::Text(text)) => {
                     content_parts.push(text.clone());
                 }
-                Some(super::types::MdnSectionValue::Other(_)) | None => {}
+                // Pages with several tables (a property and its values) list the main one first
+                Some(MdnSectionValue::Other(value))
+                    if browser_compat.is_none()
+                        && section.section_type.as_deref() == Some("browser_compatibility") =>
+                {
+                    browser_compat = value.get("query").and_then(Value::as_str).map(str::to_string);
+                }
+                _ => {}
             }
         }
 
@@ -478,12 +574,23 @@ impl MdnClient {
             syntax,
             parameters,
             return_value: None,
-            browser_compat: None,
+            browser_compat,
             content: if content_parts.is_empty() {
                 None
             } else {
                 Some(content_parts.join("\n\n"))
             },
+            css_kind,
+            formal_syntax,
+            browser_support: Vec::new(),
+            attributes,
+            members,
+            // Roles first: they are short and always apply
+            accessibility_notes: aria_roles
+                .into_iter()
+                .chain(accessibility_notes)
+                .take(MAX_ACCESSIBILITY_NOTES)
+                .collect(),
         }
     }
 
@@ -517,6 +624,188 @@ fn guess_language_for_snippet(slug: &str, class: &str) -> &'static str {
     }
 }
 
+/// A term from a reference page's definition list (attributes, properties, methods, events)
+struct Definition {
+    name: String,
+    /// First paragraph of the definition
+    description: String,
+    /// The term's link, for members documented on their own page
+    href: Option<String>,
+    deprecated: bool,
+    experimental: bool,
+    read_only: bool,
+}
+
+/// Terms of the `<dl>` lists in a section. The name is the term's `code`, badges
+/// (deprecated, experimental, read only) are the term's `abbr`/`span` classes.
+fn parse_definitions(content: &str) -> Vec<Definition> {
+    let fragment = Html::parse_fragment(content);
+    let (Ok(term_selector), Ok(code_selector), Ok(link_selector), Ok(badge_selector), Ok(paragraph_selector)) = (
+        Selector::parse("dl > dt"),
+        Selector::parse("code"),
+        Selector::parse("a[href]"),
+        Selector::parse("abbr, span"),
+        Selector::parse("p"),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut definitions: Vec<Definition> = Vec::new();
+    for term in fragment.select(&term_selector) {
+        let name = term
+            .select(&code_selector)
+            .next()
+            .map_or_else(|| term.text().collect::<String>(), |code| code.text().collect());
+        let name = name.trim().to_string();
+        if name.is_empty() || definitions.iter().any(|known| known.name == name) {
+            continue;
+        }
+        let badge = |kind: &str| {
+            term.select(&badge_selector)
+                .any(|badge| badge.value().classes().any(|class| class.contains(kind)))
+        };
+        let description = term
+            .next_siblings()
+            .filter_map(scraper::ElementRef::wrap)
+            .find(|element| element.value().name() == "dd")
+            .map(|definition| {
+                let paragraph = definition.select(&paragraph_selector).next().unwrap_or(definition);
+                collapse_whitespace(&paragraph.text().collect::<String>())
+            })
+            .unwrap_or_default();
+        definitions.push(Definition {
+            href: term
+                .select(&link_selector)
+                .next()
+                .and_then(|link| link.value().attr("href"))
+                .filter(|href| !href.starts_with('#'))
+                .map(str::to_string),
+            deprecated: badge("deprecated"),
+            experimental: badge("experimental"),
+            read_only: badge("readonly"),
+            name,
+            description,
+        });
+    }
+    definitions
+}
+
+/// Attributes from an element page's attributes section
+fn parse_html_attributes(content: &str) -> Vec<MdnHtmlAttribute> {
+    parse_definitions(content)
+        .into_iter()
+        .map(|definition| MdnHtmlAttribute {
+            name: definition.name,
+            description: definition.description,
+            deprecated: definition.deprecated,
+            experimental: definition.experimental,
+        })
+        .collect()
+}
+
+/// Members listed in one section of a Web API interface page
+fn parse_interface_members(content: &str, kind: MdnMemberKind) -> Vec<MdnInterfaceMember> {
+    parse_definitions(content)
+        .into_iter()
+        .map(|definition| MdnInterfaceMember {
+            slug: definition
+                .href
+                .as_deref()
+                .and_then(|href| href.strip_prefix("/en-US/docs/"))
+                .map(str::to_string),
+            name: definition.name,
+            kind,
+            description: definition.description,
+            read_only: definition.read_only,
+            deprecated: definition.deprecated,
+            experimental: definition.experimental,
+        })
+        .collect()
+}
+
+/// ARIA roles from an element's technical summary table
+fn parse_aria_roles(content: &str) -> Vec<String> {
+    let fragment = Html::parse_fragment(content);
+    let (Ok(row_selector), Ok(header_selector), Ok(cell_selector)) =
+        (Selector::parse("tr"), Selector::parse("th"), Selector::parse("td"))
+    else {
+        return Vec::new();
+    };
+    fragment
+        .select(&row_selector)
+        .filter_map(|row| {
+            let header = collapse_whitespace(&row.select(&header_selector).next()?.text().collect::<String>());
+            let value = collapse_whitespace(&row.select(&cell_selector).next()?.text().collect::<String>());
+            (header.contains("ARIA role") && !value.is_empty()).then(|| format!("{header}: {value}"))
+        })
+        .collect()
+}
+
+/// Paragraphs and list items of an accessibility section
+fn parse_accessibility_notes(content: &str) -> Vec<String> {
+    let fragment = Html::parse_fragment(content);
+    let Ok(selector) = Selector::parse("p, li") else {
+        return Vec::new();
+    };
+    fragment
+        .select(&selector)
+        // List items' own paragraphs are covered by the item
+        .filter(|element| {
+            element.value().name() == "li"
+                || !element
+                    .ancestors()
+                    .filter_map(scraper::ElementRef::wrap)
+                    .any(|ancestor| ancestor.value().name() == "li")
+        })
+        .map(|element| collapse_whitespace(&element.text().collect::<String>()))
+        .filter(|note| !note.is_empty())
+        .collect()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Support in each of [`BCD_BROWSERS`] from a browser-compat-data response. A browser's
+/// entry is a statement or a list of them, current support first.
+fn parse_browser_support(data: &Value) -> Vec<MdnBrowserSupport> {
+    let Some(support) = data.pointer("/data/__compat/support") else {
+        return Vec::new();
+    };
+
+    let mut browsers = Vec::new();
+    for (key, name) in BCD_BROWSERS {
+        let Some(statement) = support.get(*key).map(|value| match value {
+            Value::Array(statements) => statements.first().unwrap_or(value),
+            _ => value,
+        }) else {
+            continue;
+        };
+
+        let version_added = match statement.get("version_added") {
+            Some(Value::String(version)) => Some(version.clone()),
+            Some(Value::Bool(true)) => Some("true".to_string()),
+            _ => None,
+        };
+        let note = match statement.get("notes") {
+            Some(Value::String(note)) => Some(note.as_str()),
+            Some(Value::Array(list)) => list.first().and_then(Value::as_str),
+            _ => None,
+        }
+        .map(|note| Html::parse_fragment(note).root_element().text().collect::<String>().trim().to_string());
+        browsers.push(MdnBrowserSupport {
+            browser: name.to_string(),
+            version_added,
+            prefix: statement.get("prefix").and_then(Value::as_str).map(str::to_string),
+            alternative_name: statement.get("alternative_name").and_then(Value::as_str).map(str::to_string),
+            partial: statement.get("partial_implementation").and_then(Value::as_bool) == Some(true),
+            flagged: statement.get("flags").is_some(),
+            note,
+        });
+    }
+    browsers
+}
+
 fn looks_like_syntax_snippet(code: &str) -> bool {
     let code = code.trim();
     if code.len() > 160 {
@@ -601,6 +890,7 @@ mod tests {
                     section_type: Some("prose".to_string()),
                     value: Some(MdnSectionValue::Prose {
                         content: "<p>Overview</p><pre>map(callbackFn, thisArg)</pre><pre class=\"language-js\">const xs = [1, 2, 3];</pre>".to_string(),
+                        id: None,
                     }),
                 },
                 MdnSection {
@@ -627,6 +917,196 @@ mod tests {
         assert!(!content.contains("const xs"));
     }
 
+    #[test]
+    fn test_document_to_article_reads_css_syntax_and_compat_key() {
+        let payload = serde_json::json!({
+            "doc": {
+                "mdn_url": "/en-US/docs/Web/CSS/aspect-ratio",
+                "title": "aspect-ratio",
+                "summary": "The aspect-ratio CSS property sets a preferred aspect ratio for the box.",
+                "body": [
+                    {
+                        "type": "prose",
+                        "value": {
+                            "id": "syntax",
+                            "content": "<pre class=\"brush: css notranslate\">aspect-ratio: 1 / 1;\naspect-ratio: auto 3/4;</pre>"
+                        }
+                    },
+                    {
+                        "type": "prose",
+                        "value": {
+                            "id": "formal_syntax",
+                            "content": "<pre class=\"notranslate css-formal-syntax\">aspect-ratio = \n  auto  ||\n  &lt;ratio&gt;</pre>"
+                        }
+                    },
+                    {
+                        "type": "browser_compatibility",
+                        "value": { "id": "browser_compatibility", "query": "css.properties.aspect-ratio" }
+                    }
+                ],
+                "source": {}
+            }
+        });
+
+        let doc_response: MdnDocumentResponse = serde_json::from_value(payload).unwrap();
+        let client = MdnClient::new();
+        let article = client.document_to_article(doc_response.doc, "Web/CSS/aspect-ratio");
+
+        assert_eq!(article.css_kind, Some(MdnCssKind::Property));
+        assert_eq!(article.syntax.as_deref(), Some("aspect-ratio: 1 / 1;\naspect-ratio: auto 3/4;"));
+        assert_eq!(article.formal_syntax.as_deref(), Some("aspect-ratio = \n  auto  ||\n  <ratio>"));
+        assert_eq!(article.browser_compat.as_deref(), Some("css.properties.aspect-ratio"));
+        assert!(article.examples.iter().all(|example| !example.code.contains("<ratio>")));
+    }
+
+    #[test]
+    fn test_document_to_article_reads_html_element_sections() {
+        let payload = serde_json::json!({
+            "doc": {
+                "mdn_url": "/en-US/docs/Web/HTML/Element/dialog",
+                "title": "<dialog>: The Dialog element",
+                "summary": "The <dialog> HTML element represents a modal or non-modal dialog box.",
+                "body": [
+                    {
+                        "type": "prose",
+                        "value": {
+                            "id": "attributes",
+                            "content": "<p>This element includes the global attributes.</p><dl>\
+                                <dt id=\"open\"><a href=\"#open\"><code>open</code></a></dt>\
+                                <dd><p>Indicates that the dialog box is active and is available for interaction.</p><p>More detail.</p></dd>\
+                                <dt id=\"closedby\"><code>closedby</code> <abbr class=\"icon icon-experimental\" title=\"Experimental\">Experimental</abbr></dt>\
+                                <dd><p>Specifies the types of user actions that can be used to close the element.</p></dd></dl>"
+                        }
+                    },
+                    {
+                        "type": "prose",
+                        "value": {
+                            "id": "accessibility",
+                            "content": "<p>When implementing a dialog, consider the most appropriate place to set user focus.</p>\
+                                <ul><li><p>Provide a mechanism to close the dialog.</p></li></ul>"
+                        }
+                    },
+                    {
+                        "type": "prose",
+                        "value": {
+                            "id": "technical_summary",
+                            "content": "<table class=\"properties\"><tbody>\
+                                <tr><th scope=\"row\">Permitted content</th><td>Flow content</td></tr>\
+                                <tr><th scope=\"row\">Implicit ARIA role</th><td><a href=\"#\">dialog</a></td></tr>\
+                                <tr><th scope=\"row\">Permitted ARIA roles</th><td><code>alertdialog</code></td></tr>\
+                                </tbody></table>"
+                        }
+                    }
+                ],
+                "source": {}
+            }
+        });
+
+        let doc_response: MdnDocumentResponse = serde_json::from_value(payload).unwrap();
+        let client = MdnClient::new();
+        let article = client.document_to_article(doc_response.doc, "Web/HTML/Element/dialog");
+
+        assert_eq!(article.attributes.len(), 2);
+        assert_eq!(article.attributes[0].name, "open");
+        assert_eq!(
+            article.attributes[0].description,
+            "Indicates that the dialog box is active and is available for interaction."
+        );
+        assert!(!article.attributes[0].experimental);
+        assert!(article.attributes[1].experimental);
+        assert_eq!(
+            article.accessibility_notes,
+            vec![
+                "Implicit ARIA role: dialog",
+                "Permitted ARIA roles: alertdialog",
+                "When implementing a dialog, consider the most appropriate place to set user focus.",
+                "Provide a mechanism to close the dialog.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_to_article_lists_interface_members() {
+        let section = |id: &str, content: &str| {
+            serde_json::json!({ "type": "prose", "value": { "id": id, "content": content } })
+        };
+        let payload = serde_json::json!({
+            "doc": {
+                "mdn_url": "/en-US/docs/Web/API/IntersectionObserver",
+                "title": "IntersectionObserver",
+                "summary": "The IntersectionObserver interface of the Intersection Observer API provides a way to asynchronously observe changes.",
+                "body": [
+                    section("constructor", "<dl><dt><a href=\"/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver\"><code>IntersectionObserver()</code></a></dt>\
+                        <dd><p>Creates a new <code>IntersectionObserver</code> object.</p></dd></dl>"),
+                    section("instance_properties", "<dl><dt><a href=\"/en-US/docs/Web/API/IntersectionObserver/root\"><code>IntersectionObserver.root</code></a> \
+                        <span class=\"badge inline readonly\">Read only</span></dt>\
+                        <dd><p>The Element or Document whose bounds are used as the bounding box.</p></dd></dl>"),
+                    section("instance_methods", "<dl><dt><a href=\"/en-US/docs/Web/API/IntersectionObserver/observe\"><code>IntersectionObserver.observe()</code></a></dt>\
+                        <dd><p>Tells the <code>IntersectionObserver</code> a target element to observe.</p></dd></dl>")
+                ],
+                "source": {}
+            }
+        });
+
+        let doc_response: MdnDocumentResponse = serde_json::from_value(payload).unwrap();
+        let client = MdnClient::new();
+        let article = client.document_to_article(doc_response.doc, "Web/API/IntersectionObserver");
+
+        let members: Vec<(&str, MdnMemberKind)> =
+            article.members.iter().map(|member| (member.name.as_str(), member.kind)).collect();
+        assert_eq!(
+            members,
+            vec![
+                ("IntersectionObserver()", MdnMemberKind::Constructor),
+                ("IntersectionObserver.root", MdnMemberKind::Property),
+                ("IntersectionObserver.observe()", MdnMemberKind::Method),
+            ]
+        );
+        assert_eq!(article.members[1].label(), "read-only property");
+        assert_eq!(article.members[2].slug.as_deref(), Some("Web/API/IntersectionObserver/observe"));
+        assert_eq!(
+            article.members[2].description,
+            "Tells the IntersectionObserver a target element to observe."
+        );
+    }
+
+    #[test]
+    fn test_parse_browser_support() {
+        let data = serde_json::json!({
+            "data": {
+                "__compat": {
+                    "support": {
+                        "chrome": { "version_added": "88" },
+                        "firefox": [
+                            { "version_added": "89" },
+                            { "version_added": "81", "flags": [{ "type": "preference" }] }
+                        ],
+                        "safari": { "version_added": "15", "partial_implementation": true,
+                                    "notes": "Not supported on <code>&lt;img&gt;</code> elements." },
+                        "safari_ios": { "version_added": "9", "prefix": "-webkit-" },
+                        "edge": { "version_added": false }
+                    }
+                }
+            }
+        });
+        let support = parse_browser_support(&data);
+        let labels: Vec<(&str, String)> = support
+            .iter()
+            .map(|entry| (entry.browser.as_str(), entry.version_label()))
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                ("Chrome", "88".to_string()),
+                ("Edge", "No".to_string()),
+                ("Firefox", "89".to_string()),
+                ("Safari", "15 (partial)".to_string()),
+                ("Safari on iOS", "9 (-webkit-)".to_string()),
+            ]
+        );
+        assert_eq!(support[3].note.as_deref(), Some("Not supported on <img> elements."));
+    }
+
     #[test]
     fn test_document_deserialization_tolerates_unknown_section_values() {
         let payload = serde_json::json!({
@@ -3,5 +3,7 @@ pub mod types;
 
 pub use client::MdnClient;
 pub use types::{
-    MdnArticle, MdnCategory, MdnExample, MdnParameter, MdnSearchEntry, MdnTechnology,
+    browser_support_table, is_html_element_slug, MdnArticle, MdnBrowserSupport, MdnCategory,
+    MdnCssKind, MdnExample, MdnHtmlAttribute, MdnInterfaceMember, MdnMemberKind, MdnParameter,
+    MdnSearchEntry, MdnTechnology,
 };
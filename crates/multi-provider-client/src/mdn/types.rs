@@ -46,6 +46,77 @@ impl std::fmt::Display for MdnCategory {
     }
 }
 
+/// Kind of CSS reference page, inferred from its slug and title
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MdnCssKind {
+    Property,
+    /// Type, class, attribute selectors and combinators
+    Selector,
+    PseudoClass,
+    PseudoElement,
+    AtRule,
+    Function,
+    DataType,
+}
+
+impl MdnCssKind {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Property => "CSS property",
+            Self::Selector => "CSS selector",
+            Self::PseudoClass => "CSS pseudo-class",
+            Self::PseudoElement => "CSS pseudo-element",
+            Self::AtRule => "CSS at-rule",
+            Self::Function => "CSS function",
+            Self::DataType => "CSS data type",
+        }
+    }
+
+    /// Classify a CSS reference page: `::before`, `:hover`, `@media`, `calc()`, `<length>`,
+    /// `Child_combinator`, `display`. `None` for guides and pages outside the CSS docs.
+    #[must_use]
+    pub fn from_article(slug: &str, title: &str) -> Option<Self> {
+        if MdnCategory::from_slug(slug) != MdnCategory::Css {
+            return None;
+        }
+        let last = slug.rsplit('/').next().unwrap_or_default();
+        let title = title.trim();
+        let name = if title.is_empty() { last } else { title };
+        let lower = slug.to_lowercase();
+        if name.starts_with("::") || lower.contains("/pseudo-elements/") {
+            Some(Self::PseudoElement)
+        } else if name.starts_with(':') || lower.contains("/pseudo-classes/") {
+            Some(Self::PseudoClass)
+        } else if name.starts_with('@') || lower.contains("/at-rules/") {
+            Some(Self::AtRule)
+        } else if name.ends_with("()") || (lower.contains("/values/") && name.ends_with(')')) {
+            Some(Self::Function)
+        } else if name.starts_with('<') || lower.contains("/data_types/") {
+            Some(Self::DataType)
+        } else if lower.contains("/selectors/")
+            || last.ends_with("_selectors")
+            || last.ends_with("_selector")
+            || last.ends_with("_combinator")
+        {
+            Some(Self::Selector)
+        } else if lower.contains("/properties/")
+            || (!last.contains('_') && last.chars().all(|c| c.is_ascii_lowercase() || c == '-'))
+        {
+            Some(Self::Property)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for MdnCssKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// A code example from MDN documentation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MdnExample {
@@ -76,9 +147,189 @@ pub struct MdnArticle {
     pub syntax: Option<String>,
     pub parameters: Vec<MdnParameter>,
     pub return_value: Option<String>,
+    /// Browser compatibility data key, e.g. `css.properties.display`
     pub browser_compat: Option<String>,
     /// Full markdown/HTML content
     pub content: Option<String>,
+    /// Kind of CSS reference page (property, selector, at-rule, ...)
+    #[serde(default)]
+    pub css_kind: Option<MdnCssKind>,
+    /// The CSS value definition grammar from the "Formal syntax" section
+    #[serde(default)]
+    pub formal_syntax: Option<String>,
+    /// Support in the major browsers, from browser-compat-data
+    #[serde(default)]
+    pub browser_support: Vec<MdnBrowserSupport>,
+    /// Element-specific attributes (HTML element pages)
+    #[serde(default)]
+    pub attributes: Vec<MdnHtmlAttribute>,
+    /// Constructor, properties, methods and events (Web API interface pages)
+    #[serde(default)]
+    pub members: Vec<MdnInterfaceMember>,
+    /// Implicit ARIA role and accessibility concerns (HTML element pages)
+    #[serde(default)]
+    pub accessibility_notes: Vec<String>,
+}
+
+/// An attribute from an HTML element page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MdnHtmlAttribute {
+    pub name: String,
+    pub description: String,
+    pub deprecated: bool,
+    pub experimental: bool,
+}
+
+/// Kind of Web API interface member, from the section it is listed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MdnMemberKind {
+    Constructor,
+    Property,
+    StaticProperty,
+    Method,
+    StaticMethod,
+    Event,
+}
+
+impl MdnMemberKind {
+    /// The kind listed under an interface page section, e.g. `instance_methods`
+    #[must_use]
+    pub fn from_section_id(id: &str) -> Option<Self> {
+        match id {
+            "constructor" | "constructors" => Some(Self::Constructor),
+            "properties" | "instance_properties" => Some(Self::Property),
+            "static_properties" => Some(Self::StaticProperty),
+            "methods" | "instance_methods" => Some(Self::Method),
+            "static_methods" => Some(Self::StaticMethod),
+            "events" => Some(Self::Event),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Constructor => "constructor",
+            Self::Property => "property",
+            Self::StaticProperty => "static property",
+            Self::Method => "method",
+            Self::StaticMethod => "static method",
+            Self::Event => "event",
+        }
+    }
+}
+
+/// A constructor, property, method or event of a Web API interface
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MdnInterfaceMember {
+    /// As listed, e.g. `IntersectionObserver.observe()`
+    pub name: String,
+    pub kind: MdnMemberKind,
+    pub description: String,
+    /// The member's own page, e.g. `Web/API/IntersectionObserver/observe`
+    pub slug: Option<String>,
+    pub read_only: bool,
+    pub deprecated: bool,
+    pub experimental: bool,
+}
+
+impl MdnInterfaceMember {
+    /// Kind with its qualifiers, e.g. "read-only property", "deprecated method"
+    #[must_use]
+    pub fn label(&self) -> String {
+        let mut qualifiers = Vec::new();
+        if self.deprecated {
+            qualifiers.push("deprecated");
+        } else if self.experimental {
+            qualifiers.push("experimental");
+        }
+        if self.read_only {
+            qualifiers.push("read-only");
+        }
+        qualifiers.push(self.kind.as_str());
+        qualifiers.join(" ")
+    }
+}
+
+/// Whether `slug` is an HTML element page, including `<input>` types
+/// (`Web/HTML/Element/dialog`, `Web/HTML/Reference/Elements/input/datetime-local`)
+#[must_use]
+pub fn is_html_element_slug(slug: &str) -> bool {
+    let lower = slug.to_lowercase();
+    lower.contains("/html/element/") || lower.contains("/html/reference/elements/")
+}
+
+/// Support for a feature in one browser, from the mdn/browser-compat-data dataset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MdnBrowserSupport {
+    /// Display name, e.g. "Safari on iOS"
+    pub browser: String,
+    /// Version support was added in ("88", "≤79", "preview"); `None` when unsupported
+    pub version_added: Option<String>,
+    /// Vendor prefix the feature needs, e.g. `-webkit-`
+    pub prefix: Option<String>,
+    /// Name the feature ships under instead, e.g. `webkitRequestFullscreen`
+    pub alternative_name: Option<String>,
+    pub partial: bool,
+    /// Only available behind a preference or runtime flag
+    pub flagged: bool,
+    pub note: Option<String>,
+}
+
+impl MdnBrowserSupport {
+    /// Table cell: "88", "9 (-webkit-)", "15 (partial)", "No"
+    #[must_use]
+    pub fn version_label(&self) -> String {
+        let Some(version) = &self.version_added else {
+            return "No".to_string();
+        };
+        let mut label = match version.as_str() {
+            "preview" => "Preview".to_string(),
+            "true" => "Yes".to_string(),
+            _ => version.clone(),
+        };
+        let qualifiers: Vec<&str> = [
+            self.prefix.as_deref(),
+            self.alternative_name.as_ref().map(|_| "alt. name"),
+            self.partial.then_some("partial"),
+            self.flagged.then_some("flag"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !qualifiers.is_empty() {
+            label = format!("{label} ({})", qualifiers.join(", "));
+        }
+        label
+    }
+}
+
+/// Compact Markdown support table, one column per browser, followed by any notes.
+/// `None` when there is no data.
+#[must_use]
+pub fn browser_support_table(support: &[MdnBrowserSupport]) -> Option<String> {
+    if support.is_empty() {
+        return None;
+    }
+    let header: Vec<&str> = support.iter().map(|entry| entry.browser.as_str()).collect();
+    let versions: Vec<String> = support.iter().map(MdnBrowserSupport::version_label).collect();
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", " --- |".repeat(support.len())),
+        format!("| {} |", versions.join(" | ")),
+    ];
+    for entry in support {
+        if let Some(name) = &entry.alternative_name {
+            lines.push(format!("- {}: ships as `{name}`", entry.browser));
+        }
+        if let Some(note) = &entry.note {
+            lines.push(format!("- {}: {note}", entry.browser));
+        }
+    }
+    Some(lines.join("\n"))
 }
 
 /// MDN Technology representation for unified interface
@@ -136,6 +387,8 @@ pub struct MdnSearchEntry {
     pub summary: String,
     pub category: MdnCategory,
     pub url: String,
+    #[serde(default)]
+    pub css_kind: Option<MdnCssKind>,
 }
 
 /// MDN search API response
@@ -205,7 +458,12 @@ pub struct MdnSection {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum MdnSectionValue {
-    Prose { content: String },
+    Prose {
+        content: String,
+        /// Section anchor, e.g. `formal_syntax`
+        #[serde(default)]
+        id: Option<String>,
+    },
     Code { code: String, language: Option<String> },
     Text(String),
     Other(Value),
@@ -235,6 +493,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_css_kind_from_article() {
+        let kind = |slug: &str, title: &str| MdnCssKind::from_article(slug, title);
+        assert_eq!(kind("Web/CSS/display", "display"), Some(MdnCssKind::Property));
+        assert_eq!(kind("Web/CSS/:hover", ":hover"), Some(MdnCssKind::PseudoClass));
+        assert_eq!(kind("Web/CSS/::before", "::before"), Some(MdnCssKind::PseudoElement));
+        assert_eq!(kind("Web/CSS/@media", "@media"), Some(MdnCssKind::AtRule));
+        assert_eq!(kind("Web/CSS/calc", "calc()"), Some(MdnCssKind::Function));
+        assert_eq!(kind("Web/CSS/length", "<length>"), Some(MdnCssKind::DataType));
+        assert_eq!(kind("Web/CSS/Child_combinator", "Child combinator"), Some(MdnCssKind::Selector));
+        assert_eq!(
+            kind("Web/CSS/Reference/Properties/grid-template-columns", "grid-template-columns"),
+            Some(MdnCssKind::Property)
+        );
+        assert_eq!(kind("Web/CSS/CSS_grid_layout", "CSS grid layout"), None);
+        assert_eq!(kind("Web/API/Document", "Document"), None);
+    }
+
+    #[test]
+    fn test_browser_support_table() {
+        let support = |browser: &str, version: Option<&str>| MdnBrowserSupport {
+            browser: browser.to_string(),
+            version_added: version.map(str::to_string),
+            prefix: None,
+            alternative_name: None,
+            partial: false,
+            flagged: false,
+            note: None,
+        };
+        let mut safari = support("Safari", Some("15"));
+        safari.partial = true;
+        safari.note = Some("Not supported on <img> elements.".to_string());
+        let mut ios = support("Safari on iOS", Some("9"));
+        ios.prefix = Some("-webkit-".to_string());
+
+        let table = browser_support_table(&[support("Chrome", Some("88")), support("Firefox", None), safari, ios]);
+        assert_eq!(
+            table.as_deref(),
+            Some(
+                "| Chrome | Firefox | Safari | Safari on iOS |\n\
+                 | --- | --- | --- | --- |\n\
+                 | 88 | No | 15 (partial) | 9 (-webkit-) |\n\
+                 - Safari: Not supported on <img> elements."
+            )
+        );
+        assert!(browser_support_table(&[]).is_none());
+    }
+
     #[test]
     fn test_predefined_technologies() {
         let techs = MdnTechnology::predefined();
@@ -0,0 +1,332 @@
+//! Size-bounded in-memory cache shared by every provider client.
+//!
+//! Each provider gets its own namespace and TTL through [`ProviderMemoryCache`],
+//! while a single byte budget bounds the total across all of them. When the
+//! budget is exceeded, the least recently used entries are evicted first.
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use docs_mcp_client::cache::stats::CacheStats;
+use once_cell::sync::Lazy;
+use time::{Duration, OffsetDateTime};
+
+use crate::types::ProviderType;
+
+/// Default total memory budget across all providers: 64MB
+pub const DEFAULT_MEMORY_BUDGET_BYTES: u64 = 64 * 1024 * 1024;
+
+static SHARED: Lazy<Arc<SharedMemoryCache>> =
+    Lazy::new(|| Arc::new(SharedMemoryCache::new(DEFAULT_MEMORY_BUDGET_BYTES)));
+
+/// How long `provider`'s client keeps documentation in memory before refetching it.
+///
+/// Apple's documentation client has its own cache; its TTL is listed for reporting only.
+pub fn memory_ttl(provider: ProviderType) -> Duration {
+    match provider {
+        ProviderType::Apple => Duration::minutes(10),
+        ProviderType::TON | ProviderType::Telegram | ProviderType::Cocoon | ProviderType::QuickNode
+        | ProviderType::Solana => {
+            Duration::minutes(30)
+        }
+        ProviderType::Rust | ProviderType::Mlx | ProviderType::HuggingFace | ProviderType::ClaudeAgentSdk => {
+            Duration::hours(24)
+        }
+        ProviderType::Mdn | ProviderType::WebFrameworks | ProviderType::Cuda | ProviderType::Bitcoind => {
+            Duration::hours(1)
+        }
+    }
+}
+
+/// The process-wide cache used by provider clients built with `new()`.
+pub fn shared_memory_cache() -> Arc<SharedMemoryCache> {
+    SHARED.clone()
+}
+
+#[derive(Debug)]
+struct Slot {
+    bytes: Vec<u8>,
+    stored_at: OffsetDateTime,
+    ttl: Duration,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<(ProviderType, String), Slot>,
+    total_bytes: u64,
+    clock: u64,
+}
+
+impl Inner {
+    fn remove(&mut self, key: &(ProviderType, String)) -> Option<Slot> {
+        let slot = self.entries.remove(key)?;
+        self.total_bytes -= slot.bytes.len() as u64;
+        Some(slot)
+    }
+}
+
+/// Namespaced byte cache with a global size budget.
+#[derive(Debug)]
+pub struct SharedMemoryCache {
+    inner: Mutex<Inner>,
+    budget_bytes: AtomicU64,
+    stats: CacheStats,
+}
+
+impl SharedMemoryCache {
+    pub fn new(budget_bytes: u64) -> Self {
+        Self {
+            inner: Mutex::new(Inner::default()),
+            budget_bytes: AtomicU64::new(budget_bytes),
+            stats: CacheStats::new(),
+        }
+    }
+
+    /// Change the byte budget, evicting entries if the cache is now over it.
+    pub fn set_budget(&self, budget_bytes: u64) {
+        self.budget_bytes.store(budget_bytes, Ordering::Relaxed);
+        let mut inner = self.lock();
+        self.evict_over_budget(&mut inner, budget_bytes);
+    }
+
+    pub fn budget(&self) -> u64 {
+        self.budget_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Bytes currently held across all providers.
+    pub fn total_bytes(&self) -> u64 {
+        self.lock().total_bytes
+    }
+
+    /// Bytes currently held per provider, largest first.
+    pub fn usage_by_provider(&self) -> Vec<(ProviderType, u64)> {
+        let inner = self.lock();
+        let mut usage: HashMap<ProviderType, u64> = HashMap::new();
+        for ((provider, _), slot) in &inner.entries {
+            *usage.entry(*provider).or_default() += slot.bytes.len() as u64;
+        }
+        let mut usage: Vec<_> = usage.into_iter().collect();
+        usage.sort_by_key(|(provider, bytes)| (std::cmp::Reverse(*bytes), provider.slug()));
+        usage
+    }
+
+    pub fn stats(&self) -> &CacheStats {
+        &self.stats
+    }
+
+    pub fn get(&self, provider: ProviderType, key: &str) -> Option<Vec<u8>> {
+        let mut inner = self.lock();
+        let lookup = (provider, key.to_string());
+        inner.clock += 1;
+        let clock = inner.clock;
+
+        let expired = match inner.entries.get_mut(&lookup) {
+            Some(slot) if OffsetDateTime::now_utc() - slot.stored_at <= slot.ttl => {
+                slot.last_used = clock;
+                let bytes = slot.bytes.clone();
+                self.stats.record_hit();
+                self.stats.record_bytes(bytes.len() as u64);
+                return Some(bytes);
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        if expired {
+            inner.remove(&lookup);
+            self.stats.set_entry_count(inner.entries.len());
+        }
+        self.stats.record_miss();
+        None
+    }
+
+    pub fn insert(&self, provider: ProviderType, key: impl Into<String>, bytes: Vec<u8>, ttl: Duration) {
+        let budget = self.budget();
+        let size = bytes.len() as u64;
+        let key = (provider, key.into());
+
+        let mut inner = self.lock();
+        inner.remove(&key);
+        // An entry larger than the whole budget would only evict everything else
+        if size > budget {
+            self.stats.set_entry_count(inner.entries.len());
+            return;
+        }
+
+        inner.clock += 1;
+        let slot = Slot {
+            bytes,
+            stored_at: OffsetDateTime::now_utc(),
+            ttl,
+            last_used: inner.clock,
+        };
+        inner.total_bytes += size;
+        inner.entries.insert(key, slot);
+        self.evict_over_budget(&mut inner, budget);
+        self.stats.set_entry_count(inner.entries.len());
+    }
+
+    /// Drop every entry belonging to `provider`.
+    pub fn clear_provider(&self, provider: ProviderType) {
+        let mut inner = self.lock();
+        let keys: Vec<_> = inner.entries.keys().filter(|(p, _)| *p == provider).cloned().collect();
+        for key in keys {
+            inner.remove(&key);
+        }
+        self.stats.set_entry_count(inner.entries.len());
+    }
+
+    fn evict_over_budget(&self, inner: &mut Inner, budget: u64) {
+        if inner.total_bytes <= budget {
+            return;
+        }
+        let mut by_age: Vec<_> = inner.entries.iter().map(|(key, slot)| (slot.last_used, key.clone())).collect();
+        by_age.sort_by_key(|(last_used, _)| *last_used);
+
+        let mut evicted = 0;
+        for (_, key) in by_age {
+            if inner.total_bytes <= budget {
+                break;
+            }
+            inner.remove(&key);
+            evicted += 1;
+        }
+        self.stats.record_eviction(evicted);
+        self.stats.set_entry_count(inner.entries.len());
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // A panic while holding the lock can't leave the map inconsistent enough to matter
+        self.inner.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Values that can be stored in the shared byte cache.
+pub trait MemoryValue: Sized {
+    fn into_bytes(self) -> Vec<u8>;
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self>;
+}
+
+impl MemoryValue for Vec<u8> {
+    fn into_bytes(self) -> Vec<u8> {
+        self
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        Some(bytes)
+    }
+}
+
+impl MemoryValue for String {
+    fn into_bytes(self) -> Vec<u8> {
+        String::into_bytes(self)
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        String::from_utf8(bytes).ok()
+    }
+}
+
+/// A provider's view of the shared cache: its own namespace and TTL.
+#[derive(Debug, Clone)]
+pub struct ProviderMemoryCache<T> {
+    shared: Arc<SharedMemoryCache>,
+    provider: ProviderType,
+    ttl: Duration,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: MemoryValue> ProviderMemoryCache<T> {
+    /// Namespace within the process-wide [`shared_memory_cache`].
+    pub fn new(provider: ProviderType, ttl: Duration) -> Self {
+        Self::with_shared(shared_memory_cache(), provider, ttl)
+    }
+
+    pub fn with_shared(shared: Arc<SharedMemoryCache>, provider: ProviderType, ttl: Duration) -> Self {
+        Self {
+            shared,
+            provider,
+            ttl,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<T> {
+        self.shared.get(self.provider, key).and_then(T::from_bytes)
+    }
+
+    pub fn insert(&self, key: impl Into<String>, value: T) {
+        self.shared.insert(self.provider, key, value.into_bytes(), self.ttl);
+    }
+
+    pub fn clear(&self) {
+        self.shared.clear_provider(self.provider);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespaces_are_isolated() {
+        let shared = Arc::new(SharedMemoryCache::new(1024));
+        let rust = ProviderMemoryCache::<String>::with_shared(shared.clone(), ProviderType::Rust, Duration::hours(1));
+        let mdn = ProviderMemoryCache::<String>::with_shared(shared.clone(), ProviderType::Mdn, Duration::hours(1));
+
+        rust.insert("key", "rust".to_string());
+        mdn.insert("key", "mdn".to_string());
+        assert_eq!(rust.get("key").as_deref(), Some("rust"));
+        assert_eq!(mdn.get("key").as_deref(), Some("mdn"));
+
+        rust.clear();
+        assert!(rust.get("key").is_none());
+        assert_eq!(shared.total_bytes(), 3);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_over_budget() {
+        let shared = Arc::new(SharedMemoryCache::new(10));
+        let cache = ProviderMemoryCache::<Vec<u8>>::with_shared(shared.clone(), ProviderType::TON, Duration::hours(1));
+
+        cache.insert("a", vec![0; 4]);
+        cache.insert("b", vec![0; 4]);
+        assert!(cache.get("a").is_some());
+        cache.insert("c", vec![0; 4]);
+
+        assert!(cache.get("b").is_none(), "least recently used entry is evicted");
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+        assert_eq!(shared.total_bytes(), 8);
+        assert_eq!(shared.stats().snapshot().evictions, 1);
+
+        cache.insert("huge", vec![0; 11]);
+        assert!(cache.get("huge").is_none());
+        assert_eq!(shared.total_bytes(), 8);
+    }
+
+    #[test]
+    fn expired_entries_are_dropped() {
+        let shared = Arc::new(SharedMemoryCache::new(1024));
+        let cache = ProviderMemoryCache::<String>::with_shared(shared.clone(), ProviderType::Telegram, Duration::ZERO);
+        cache.insert("key", "value".to_string());
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert!(cache.get("key").is_none());
+        assert_eq!(shared.total_bytes(), 0);
+    }
+
+    #[test]
+    fn shrinking_budget_evicts() {
+        let shared = SharedMemoryCache::new(1024);
+        shared.insert(ProviderType::Cuda, "a", vec![0; 100], Duration::hours(1));
+        shared.insert(ProviderType::Mlx, "b", vec![0; 100], Duration::hours(1));
+        assert_eq!(shared.usage_by_provider().len(), 2);
+
+        shared.set_budget(150);
+        assert_eq!(shared.total_bytes(), 100);
+        assert_eq!(shared.usage_by_provider(), vec![(ProviderType::Mlx, 100)]);
+    }
+}
@@ -1,9 +1,9 @@
 //! MLX documentation client for Apple Silicon ML framework.
 //!
-//! Provides access to MLX-Swift and MLX Python documentation.
+//! Provides access to MLX-Swift and MLX Python documentation, plus the embedded
+//! mlx-lm and mlx-examples reference.
 
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::Result;
 use directories::ProjectDirs;
@@ -11,11 +11,19 @@ use reqwest::Client;
 use scraper::{Html, Selector};
 use tracing::{debug, instrument, warn};
 
+use super::lm::{find_lm_topic, MlxLmSource, MLX_LM_TOPICS};
 use super::types::{
     MlxArticle, MlxCategory, MlxCategoryItem, MlxExample, MlxItemKind, MlxLanguage,
     MlxParameter, MlxSearchResult, MlxTechnology, MLX_PYTHON_TOPICS, MLX_SWIFT_TOPICS,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const MLX_SWIFT_BASE: &str = "https://ml-explore.github.io/mlx-swift/documentation/mlx";
 const MLX_PYTHON_BASE: &str = "https://ml-explore.github.io/mlx/build/html";
@@ -24,7 +32,7 @@ const MLX_PYTHON_BASE: &str = "https://ml-explore.github.io/mlx/build/html";
 pub struct MlxClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     cache_dir: PathBuf,
 }
 
@@ -45,9 +53,8 @@ impl MlxClient {
             warn!(error = %e, "Failed to create MLX cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Mlx))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -55,7 +62,7 @@ impl MlxClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(24)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Mlx, memory_ttl(ProviderType::Mlx)),
             cache_dir,
         }
     }
@@ -78,12 +85,35 @@ impl MlxClient {
                 url: MLX_PYTHON_BASE.to_string(),
                 language: MlxLanguage::Python,
             },
+            lm_technology(MlxLmSource::Lm),
+            lm_technology(MlxLmSource::Examples),
         ])
     }
 
     /// Get category listing
     #[instrument(name = "mlx_client.get_category", skip(self))]
     pub async fn get_category(&self, identifier: &str) -> Result<MlxCategory> {
+        if let Some(source) = MlxLmSource::from_identifier(identifier) {
+            let items = MLX_LM_TOPICS
+                .iter()
+                .filter(|topic| topic.source == source)
+                .map(|topic| MlxCategoryItem {
+                    name: topic.name.to_string(),
+                    description: topic.description.to_string(),
+                    kind: topic.kind,
+                    path: topic.path.to_string(),
+                    url: topic.url(),
+                })
+                .collect();
+            return Ok(MlxCategory {
+                identifier: source.identifier().to_string(),
+                title: source.title().to_string(),
+                description: source.description().to_string(),
+                items,
+                language: MlxLanguage::Python,
+            });
+        }
+
         let language = if identifier.contains("python") {
             MlxLanguage::Python
         } else {
@@ -176,8 +206,31 @@ impl MlxClient {
             }
         }
 
+        // Search mlx-lm and mlx-examples (Python)
+        if language.is_none() || language == Some(MlxLanguage::Python) {
+            for topic in MLX_LM_TOPICS {
+                let tag_score: i32 = query_terms
+                    .iter()
+                    .filter(|term| topic.tags.iter().any(|tag| tag == *term))
+                    .map(|_| 25)
+                    .sum();
+                let score = calculate_score(topic.name, topic.description, &query_terms) + tag_score;
+                if score > 0 {
+                    results.push(MlxSearchResult {
+                        name: topic.name.to_string(),
+                        path: topic.path.to_string(),
+                        url: topic.url(),
+                        kind: topic.kind,
+                        description: topic.description.to_string(),
+                        language: MlxLanguage::Python,
+                        score,
+                    });
+                }
+            }
+        }
+
         // Sort by score descending
-        results.sort_by(|a, b| b.score.cmp(&a.score));
+        results.sort_by_key(|r| std::cmp::Reverse(r.score));
         results.truncate(20);
 
         Ok(results)
@@ -186,6 +239,11 @@ impl MlxClient {
     /// Get detailed article documentation
     #[instrument(name = "mlx_client.get_article", skip(self))]
     pub async fn get_article(&self, path: &str, language: MlxLanguage) -> Result<MlxArticle> {
+        // mlx-lm and mlx-examples are embedded
+        if let Some(topic) = find_lm_topic(path) {
+            return Ok(topic.to_article());
+        }
+
         let (base_url, topics) = if language == MlxLanguage::Swift {
             (MLX_SWIFT_BASE, MLX_SWIFT_TOPICS)
         } else {
@@ -235,7 +293,8 @@ impl MlxClient {
     async fn fetch_swift_article(&self, url: &str, name: &str, default_desc: &str) -> Result<MlxArticle> {
         debug!(url = %url, "Fetching MLX-Swift documentation");
 
-        let response = self.http.get(url).send().await;
+        ensure_online(url)?;
+        let response = self.http.get(url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -315,7 +374,8 @@ impl MlxClient {
     async fn fetch_python_article(&self, url: &str, name: &str, default_desc: &str) -> Result<MlxArticle> {
         debug!(url = %url, "Fetching MLX Python documentation");
 
-        let response = self.http.get(url).send().await;
+        ensure_online(url)?;
+        let response = self.http.get(url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -393,6 +453,16 @@ impl MlxClient {
     }
 }
 
+fn lm_technology(source: MlxLmSource) -> MlxTechnology {
+    MlxTechnology {
+        identifier: source.identifier().to_string(),
+        title: source.title().to_string(),
+        description: source.description().to_string(),
+        url: source.base_url().to_string(),
+        language: MlxLanguage::Python,
+    }
+}
+
 /// Calculate search relevance score
 fn calculate_score(name: &str, desc: &str, query_terms: &[&str]) -> i32 {
     let name_lower = name.to_lowercase();
@@ -573,6 +643,25 @@ mod tests {
         assert_eq!(infer_item_kind("mlx.core.array"), MlxItemKind::Function);
     }
 
+    #[tokio::test]
+    async fn test_search_mlx_lm() {
+        let client = MlxClient::new();
+
+        let results = client.search("lora fine-tuning", Some(MlxLanguage::Python)).await.unwrap();
+        assert_eq!(results[0].path, "lm/lora");
+
+        let results = client.search("quantize 4-bit", None).await.unwrap();
+        assert!(results.iter().take(2).any(|r| r.path == "lm/quantization"));
+
+        let article = client.get_article("lm/stream_generate", MlxLanguage::Python).await.unwrap();
+        assert!(article.declaration.unwrap().starts_with("def stream_generate("));
+        assert_eq!(article.examples[0].language, "python");
+
+        let examples = client.get_category("mlx:examples").await.unwrap();
+        assert!(examples.items.iter().any(|item| item.path == "examples/mnist"));
+        assert_eq!(client.get_technologies().await.unwrap().len(), 4);
+    }
+
     #[test]
     fn test_calculate_score() {
         let terms = vec!["array", "mlx"];
@@ -0,0 +1,385 @@
+//! mlx-lm and mlx-examples reference.
+//!
+//! mlx-lm (generation, quantization, LoRA fine-tuning) and the mlx-examples
+//! repository are documented in GitHub READMEs rather than the Sphinx site, so
+//! their APIs and patterns are embedded here and served without a fetch.
+
+use super::types::{MlxArticle, MlxExample, MlxItemKind, MlxLanguage};
+
+const MLX_LM_REPO: &str = "https://github.com/ml-explore/mlx-lm";
+const MLX_EXAMPLES_REPO: &str = "https://github.com/ml-explore/mlx-examples";
+
+/// Where an embedded topic comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MlxLmSource {
+    /// The mlx-lm package (`pip install mlx-lm`)
+    Lm,
+    /// Patterns from the mlx-examples repository
+    Examples,
+}
+
+impl MlxLmSource {
+    #[must_use]
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Self::Lm => "mlx:lm",
+            Self::Examples => "mlx:examples",
+        }
+    }
+
+    #[must_use]
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Lm => "mlx-lm",
+            Self::Examples => "MLX Examples",
+        }
+    }
+
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Lm => "LLM text generation, quantization and LoRA fine-tuning on Apple Silicon",
+            Self::Examples => "Training loops, speech, image generation and model patterns from mlx-examples",
+        }
+    }
+
+    #[must_use]
+    pub fn base_url(self) -> &'static str {
+        match self {
+            Self::Lm => MLX_LM_REPO,
+            Self::Examples => MLX_EXAMPLES_REPO,
+        }
+    }
+
+    /// Source of a technology identifier such as `mlx:lm`
+    #[must_use]
+    pub fn from_identifier(identifier: &str) -> Option<Self> {
+        match identifier.strip_prefix("mlx:").unwrap_or(identifier) {
+            "lm" | "mlx-lm" | "mlx_lm" => Some(Self::Lm),
+            "examples" | "mlx-examples" => Some(Self::Examples),
+            _ => None,
+        }
+    }
+}
+
+/// Embedded mlx-lm API or mlx-examples pattern
+#[derive(Debug)]
+pub struct MlxLmTopic {
+    pub name: &'static str,
+    /// `lm/<slug>` or `examples/<slug>`
+    pub path: &'static str,
+    pub source: MlxLmSource,
+    pub kind: MlxItemKind,
+    pub description: &'static str,
+    /// Page relative to the source repository
+    pub page: &'static str,
+    pub declaration: Option<&'static str>,
+    pub content: &'static str,
+    /// (description, code)
+    pub example: Option<(&'static str, &'static str)>,
+    pub tags: &'static [&'static str],
+}
+
+impl MlxLmTopic {
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{}/{}", self.source.base_url(), self.page)
+    }
+
+    #[must_use]
+    pub fn to_article(&self) -> MlxArticle {
+        MlxArticle {
+            title: self.name.to_string(),
+            description: self.description.to_string(),
+            path: self.path.to_string(),
+            url: self.url(),
+            kind: self.kind,
+            language: MlxLanguage::Python,
+            declaration: self.declaration.map(str::to_string),
+            content: self.content.to_string(),
+            examples: self
+                .example
+                .iter()
+                .map(|(description, code)| MlxExample {
+                    code: (*code).to_string(),
+                    language: "python".to_string(),
+                    description: Some((*description).to_string()),
+                })
+                .collect(),
+            parameters: vec![],
+            return_value: None,
+            related: vec![],
+            platforms: vec!["macOS with Apple Silicon".to_string()],
+        }
+    }
+}
+
+/// Embedded topic by path (`lm/generate`) or name (`mlx_lm.generate`)
+#[must_use]
+pub fn find_lm_topic(path: &str) -> Option<&'static MlxLmTopic> {
+    let path = path.trim_start_matches('/');
+    MLX_LM_TOPICS
+        .iter()
+        .find(|topic| topic.path.eq_ignore_ascii_case(path) || topic.name.eq_ignore_ascii_case(path))
+}
+
+// ============================================================================
+// mlx-lm: generation
+// ============================================================================
+
+pub const MLX_LM_TOPICS: &[MlxLmTopic] = &[
+    MlxLmTopic {
+        name: "mlx_lm.load",
+        path: "lm/load",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Load a model and tokenizer from a local path or Hugging Face repo (mlx-community models load without conversion)",
+        page: "blob/main/README.md",
+        declaration: Some("def load(\n    path_or_hf_repo: str,\n    tokenizer_config: dict = {},\n    model_config: dict = {},\n    adapter_path: Optional[str] = None,\n    lazy: bool = False,\n) -> Tuple[nn.Module, TokenizerWrapper]"),
+        content: "Downloads the repo with huggingface_hub when needed, builds the model class from config.json and loads the safetensors weights. Quantized checkpoints (4-bit, 8-bit) are loaded with their quantization config. adapter_path applies LoRA adapters trained with mlx_lm.lora on top of the base weights. With lazy=True the weights are not evaluated until first use. The returned TokenizerWrapper exposes the Hugging Face tokenizer plus a streaming detokenizer.",
+        example: Some(("Load a quantized model from the Hub", "from mlx_lm import load\n\nmodel, tokenizer = load(\"mlx-community/Mistral-7B-Instruct-v0.3-4bit\")\n\n# With LoRA adapters trained by mlx_lm.lora\nmodel, tokenizer = load(\"mlx-community/Mistral-7B-Instruct-v0.3-4bit\", adapter_path=\"adapters\")")),
+        tags: &["load", "model", "tokenizer", "huggingface", "hub", "mlx-community", "adapter"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.generate",
+        path: "lm/generate",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Generate a complete response for a prompt and return it as a string",
+        page: "blob/main/README.md",
+        declaration: Some("def generate(\n    model: nn.Module,\n    tokenizer: Union[PreTrainedTokenizer, TokenizerWrapper],\n    prompt: Union[str, List[int]],\n    verbose: bool = False,\n    **kwargs,\n) -> str"),
+        content: "Runs stream_generate to completion and joins the text. Keyword arguments are forwarded: max_tokens (default 256), sampler (from make_sampler), logits_processors (from make_logits_processors), prompt_cache (from make_prompt_cache) and max_kv_size. verbose=True prints the text as it is produced along with prompt/generation tokens-per-second and peak memory. Chat models expect the prompt to be formatted with tokenizer.apply_chat_template. The command line equivalent is `mlx_lm.generate --model <repo> --prompt \"...\"`.",
+        example: Some(("Chat-formatted generation", "from mlx_lm import load, generate\n\nmodel, tokenizer = load(\"mlx-community/Llama-3.2-3B-Instruct-4bit\")\n\nmessages = [{\"role\": \"user\", \"content\": \"Write a haiku about unified memory\"}]\nprompt = tokenizer.apply_chat_template(messages, add_generation_prompt=True)\n\ntext = generate(model, tokenizer, prompt=prompt, max_tokens=128, verbose=True)")),
+        tags: &["generate", "generation", "text", "inference", "prompt", "llm", "chat"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.stream_generate",
+        path: "lm/stream_generate",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Stream generated text segment by segment with token and throughput statistics",
+        page: "blob/main/README.md",
+        declaration: Some("def stream_generate(\n    model: nn.Module,\n    tokenizer: Union[PreTrainedTokenizer, TokenizerWrapper],\n    prompt: Union[str, mx.array, List[int]],\n    max_tokens: int = 256,\n    draft_model: Optional[nn.Module] = None,\n    **kwargs,\n) -> Generator[GenerationResponse, None, None]"),
+        content: "Yields a GenerationResponse per step with text (the newly detokenized segment), token, logprobs, prompt_tokens, prompt_tps, generation_tokens, generation_tps, peak_memory and finish_reason (\"stop\" or \"length\" on the last response). Accepts the same sampler, logits_processors and prompt_cache keyword arguments as generate. A draft_model enables speculative decoding with num_draft_tokens.",
+        example: Some(("Print tokens as they arrive", "from mlx_lm import load, stream_generate\n\nmodel, tokenizer = load(\"mlx-community/Qwen2.5-7B-Instruct-4bit\")\nprompt = tokenizer.apply_chat_template(\n    [{\"role\": \"user\", \"content\": \"Explain KV caching\"}], add_generation_prompt=True\n)\n\nfor response in stream_generate(model, tokenizer, prompt, max_tokens=512):\n    print(response.text, end=\"\", flush=True)\nprint(f\"\\n{response.generation_tps:.1f} tokens/s, peak {response.peak_memory:.2f} GB\")")),
+        tags: &["stream", "streaming", "generate", "generation", "tokens per second", "speculative", "draft"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.sample_utils.make_sampler",
+        path: "lm/make_sampler",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Build a sampling function (temperature, top-p, min-p, top-k) to pass to generate",
+        page: "blob/main/mlx_lm/sample_utils.py",
+        declaration: Some("def make_sampler(\n    temp: float = 0.0,\n    top_p: float = 0.0,\n    min_p: float = 0.0,\n    min_tokens_to_keep: int = 1,\n    top_k: int = 0,\n) -> Callable[[mx.array], mx.array]"),
+        content: "temp=0.0 is greedy (argmax) decoding. Non-zero temperatures sample from the scaled distribution after the top_p, min_p and top_k filters are applied. Pass the result as sampler= to generate or stream_generate; temperature is no longer a direct keyword argument of generate. make_logits_processors(logit_bias=None, repetition_penalty=None, repetition_context_size=20) builds the matching logits_processors list.",
+        example: Some(("Sampling with a repetition penalty", "from mlx_lm import load, generate\nfrom mlx_lm.sample_utils import make_sampler, make_logits_processors\n\nmodel, tokenizer = load(\"mlx-community/Mistral-7B-Instruct-v0.3-4bit\")\nsampler = make_sampler(temp=0.7, top_p=0.9)\nprocessors = make_logits_processors(repetition_penalty=1.1)\n\ntext = generate(model, tokenizer, prompt=\"Once upon a time\", sampler=sampler,\n                logits_processors=processors, max_tokens=200)")),
+        tags: &["sampler", "sampling", "temperature", "temp", "top_p", "top-p", "min_p", "top_k", "repetition penalty"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.models.cache.make_prompt_cache",
+        path: "lm/make_prompt_cache",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Create a reusable KV cache for multi-turn chat and long shared prompts",
+        page: "blob/main/mlx_lm/models/cache.py",
+        declaration: Some("def make_prompt_cache(\n    model: nn.Module,\n    max_kv_size: Optional[int] = None,\n) -> List[Any]"),
+        content: "Returns one cache per layer (KVCache, or RotatingKVCache when max_kv_size bounds memory). Pass the same list as prompt_cache= on every turn so only new tokens are processed. save_prompt_cache(file, cache) and load_prompt_cache(file) persist a cache to .safetensors; the CLI `mlx_lm.cache_prompt` precomputes a long system prompt or document once.",
+        example: Some(("Multi-turn chat reusing the cache", "from mlx_lm import load, generate\nfrom mlx_lm.models.cache import make_prompt_cache\n\nmodel, tokenizer = load(\"mlx-community/Llama-3.2-3B-Instruct-4bit\")\ncache = make_prompt_cache(model)\n\nfor question in [\"Who wrote Dune?\", \"When was it published?\"]:\n    prompt = tokenizer.apply_chat_template(\n        [{\"role\": \"user\", \"content\": question}], add_generation_prompt=True\n    )\n    print(generate(model, tokenizer, prompt=prompt, prompt_cache=cache))")),
+        tags: &["cache", "kv cache", "kvcache", "prompt cache", "multi-turn", "chat", "rotating"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.server",
+        path: "lm/server",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Module,
+        description: "OpenAI-compatible HTTP server for chat and text completions",
+        page: "blob/main/mlx_lm/SERVER.md",
+        declaration: Some("mlx_lm.server --model <path_or_hf_repo> [--adapter-path PATH] [--host 127.0.0.1] [--port 8080]"),
+        content: "Serves POST /v1/chat/completions and /v1/completions with the OpenAI request format, including stream=true server-sent events, temperature, top_p, max_tokens, stop and repetition_penalty. The model field can name another local or Hub model to load on demand. Intended for local development, not production serving.",
+        example: Some(("Query the server with the OpenAI client", "# mlx_lm.server --model mlx-community/Mistral-7B-Instruct-v0.3-4bit --port 8080\nfrom openai import OpenAI\n\nclient = OpenAI(base_url=\"http://localhost:8080/v1\", api_key=\"not-needed\")\nreply = client.chat.completions.create(\n    model=\"default_model\",\n    messages=[{\"role\": \"user\", \"content\": \"Hello!\"}],\n)\nprint(reply.choices[0].message.content)")),
+        tags: &["server", "openai", "http", "api", "serve", "chat completions"],
+    },
+
+    // ========================================================================
+    // mlx-lm: conversion and quantization
+    // ========================================================================
+    MlxLmTopic {
+        name: "mlx_lm.convert",
+        path: "lm/convert",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Convert a Hugging Face model to MLX format, optionally quantizing and uploading it",
+        page: "blob/main/README.md",
+        declaration: Some("def convert(\n    hf_path: str,\n    mlx_path: str = \"mlx_model\",\n    quantize: bool = False,\n    q_group_size: int = 64,\n    q_bits: int = 4,\n    dtype: Optional[str] = None,\n    upload_repo: str = None,\n    revision: Optional[str] = None,\n    dequantize: bool = False,\n    quant_predicate: Optional[Union[Callable, str]] = None,\n) -> None"),
+        content: "Loads the Hugging Face checkpoint, casts to dtype (float16 by default), quantizes when quantize=True and writes safetensors shards plus config.json and tokenizer files to mlx_path. upload_repo pushes the result to the Hub (for example mlx-community/<name>-4bit). dequantize=True turns a quantized model back into full precision, e.g. before fusing adapters for export. quant_predicate selects a mixed-precision recipe such as \"mixed_3_6\". CLI: `mlx_lm.convert --hf-path mistralai/Mistral-7B-Instruct-v0.3 -q --q-bits 4`.",
+        example: Some(("Convert and quantize to 4-bit", "from mlx_lm import convert\n\nconvert(\n    \"mistralai/Mistral-7B-Instruct-v0.3\",\n    mlx_path=\"mistral-7b-4bit\",\n    quantize=True,\n    q_bits=4,\n    q_group_size=64,\n)")),
+        tags: &["convert", "conversion", "quantize", "huggingface", "upload", "safetensors", "export"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm quantization",
+        path: "lm/quantization",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Guide,
+        description: "Group-wise 2/3/4/6/8-bit weight quantization of LLMs with nn.quantize, and memory estimates",
+        page: "blob/main/README.md",
+        declaration: Some("def quantize(\n    model: nn.Module,\n    group_size: int = 64,\n    bits: int = 4,\n    class_predicate: Optional[Callable[[str, nn.Module], Union[bool, dict]]] = None,\n)  # mlx.nn.quantize"),
+        content: "MLX quantizes Linear and Embedding weights group-wise: every group_size consecutive weights share a scale and bias, and each weight is stored in bits bits. nn.quantize swaps layers for QuantizedLinear/QuantizedEmbedding in place; class_predicate can skip layers or return per-layer {\"group_size\", \"bits\"}. The quantization settings are saved in config.json and re-applied by mlx_lm.load. Rough memory: 7B parameters take ~14 GB in float16, ~7.5 GB in 8-bit and ~4 GB in 4-bit, so a 4-bit 7B model fits a 16 GB Mac. Smaller group sizes (32) improve quality at slightly higher memory; 3-bit and 2-bit trade noticeable quality for size. mlx_lm.awq, mlx_lm.dwq and mlx_lm.gptq provide calibrated quantization.",
+        example: Some(("Quantize a loaded model in memory", "import mlx.nn as nn\nfrom mlx_lm import load\n\nmodel, tokenizer = load(\"mistralai/Mistral-7B-Instruct-v0.3\")\n\n# Keep the output projection in 8-bit, everything else in 4-bit\ndef predicate(path, module):\n    if not hasattr(module, \"to_quantized\"):\n        return False\n    return {\"group_size\": 64, \"bits\": 8} if \"lm_head\" in path else True\n\nnn.quantize(model, group_size=64, bits=4, class_predicate=predicate)")),
+        tags: &["quantize", "quantization", "4-bit", "4bit", "8-bit", "8bit", "bits", "group_size", "memory", "qlora", "awq", "dwq", "gptq"],
+    },
+
+    // ========================================================================
+    // mlx-lm: fine-tuning
+    // ========================================================================
+    MlxLmTopic {
+        name: "mlx_lm.lora",
+        path: "lm/lora",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "LoRA, DoRA and full fine-tuning of LLMs; QLoRA when the base model is quantized",
+        page: "blob/main/mlx_lm/LORA.md",
+        declaration: Some("mlx_lm.lora --model <path_or_hf_repo> --train --data <dir_or_hf_dataset>\n    [--fine-tune-type lora|dora|full] [--num-layers 16] [--batch-size 4]\n    [--iters 1000] [--learning-rate 1e-5] [--adapter-path adapters]\n    [--mask-prompt] [--grad-checkpoint] [-c config.yaml]"),
+        content: "Trains low-rank adapters on the last num-layers transformer blocks (-1 for all) and saves adapters.safetensors plus adapter_config.json to adapter-path. Passing a quantized model (e.g. a 4-bit mlx-community repo) trains QLoRA automatically. --test evaluates perplexity on test.jsonl; --resume-adapter-file continues training. A YAML config (-c) sets lora_parameters {rank, scale, dropout} and the target keys. Reduce memory with a smaller --batch-size, fewer --num-layers, --grad-checkpoint, or a quantized base model. Use the adapters with load(..., adapter_path=...) or `mlx_lm.generate --adapter-path adapters`.",
+        example: Some(("QLoRA fine-tune, then generate with the adapter", "# Shell:\n#   mlx_lm.lora --model mlx-community/Mistral-7B-Instruct-v0.3-4bit \\\n#       --train --data ./data --iters 600 --batch-size 2 --num-layers 8\n\nfrom mlx_lm import load, generate\n\nmodel, tokenizer = load(\"mlx-community/Mistral-7B-Instruct-v0.3-4bit\", adapter_path=\"adapters\")\nprint(generate(model, tokenizer, prompt=\"Summarize: ...\", max_tokens=100))")),
+        tags: &["lora", "qlora", "dora", "fine-tune", "fine-tuning", "finetune", "finetuning", "training", "adapter", "adapters"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.lora data format",
+        path: "lm/lora-data",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Guide,
+        description: "train.jsonl / valid.jsonl / test.jsonl layouts (chat, completions, text) for LoRA fine-tuning",
+        page: "blob/main/mlx_lm/LORA.md#data",
+        declaration: None,
+        content: "--data points at a directory with train.jsonl and valid.jsonl (test.jsonl for --test) or at a Hugging Face dataset name. Each line is one example in one of three formats: chat {\"messages\": [{\"role\": ..., \"content\": ...}]} (optionally with \"tools\"), completions {\"prompt\": ..., \"completion\": ...}, or plain {\"text\": ...}. Chat and completions data is formatted with the tokenizer's chat template; --mask-prompt computes the loss on the completion only. Long examples are truncated to --max-seq-length (default 2048).",
+        example: Some(("Writing a chat-format dataset", "import json\n\nexamples = [\n    {\"messages\": [\n        {\"role\": \"user\", \"content\": \"What is MLX?\"},\n        {\"role\": \"assistant\", \"content\": \"An array framework for Apple silicon.\"},\n    ]},\n]\nwith open(\"data/train.jsonl\", \"w\") as f:\n    for example in examples:\n        f.write(json.dumps(example) + \"\\n\")")),
+        tags: &["lora", "dataset", "data", "jsonl", "train.jsonl", "chat", "completions", "fine-tune", "fine-tuning", "mask-prompt"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.fuse",
+        path: "lm/fuse",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Merge trained LoRA adapters into the base weights and optionally export to GGUF or the Hub",
+        page: "blob/main/mlx_lm/LORA.md#fuse",
+        declaration: Some("mlx_lm.fuse --model <path_or_hf_repo> [--adapter-path adapters]\n    [--save-path fused_model] [--dequantize] [--export-gguf] [--upload-repo REPO]"),
+        content: "Produces a standalone model directory that no longer needs adapter_path. --dequantize writes full-precision weights (needed before --export-gguf, which supports Llama and Mistral style models in fp16). --upload-repo pushes the fused model to the Hugging Face Hub.",
+        example: None,
+        tags: &["fuse", "merge", "lora", "adapter", "gguf", "export", "upload"],
+    },
+    MlxLmTopic {
+        name: "mlx_lm.tuner.linear_to_lora_layers",
+        path: "lm/linear_to_lora_layers",
+        source: MlxLmSource::Lm,
+        kind: MlxItemKind::Function,
+        description: "Python API for LoRA: replace Linear layers with LoRA layers and train with mlx_lm.tuner.train",
+        page: "blob/main/mlx_lm/tuner/utils.py",
+        declaration: Some("def linear_to_lora_layers(\n    model: nn.Module,\n    num_layers: int,\n    config: Dict,\n    use_dora: bool = False,\n) -> None"),
+        content: "config holds rank, scale, dropout and optionally keys (module paths such as self_attn.q_proj). Freeze the model first so only the adapters train, then run mlx_lm.tuner.trainer.train(model, optimizer, train_set, val_set, args=TrainingArgs(...)). Datasets come from mlx_lm.tuner.datasets.load_dataset. Save the trainable parameters with mx.save_safetensors(\"adapters.safetensors\", dict(tree_flatten(model.trainable_parameters()))).",
+        example: Some(("Programmatic LoRA setup", "import mlx.optimizers as optim\nfrom mlx.utils import tree_flatten\nfrom mlx_lm import load\nfrom mlx_lm.tuner.utils import linear_to_lora_layers\n\nmodel, tokenizer = load(\"mlx-community/Llama-3.2-1B-Instruct-4bit\")\nmodel.freeze()\nlinear_to_lora_layers(model, num_layers=8, config={\"rank\": 8, \"scale\": 20.0, \"dropout\": 0.0})\n\noptimizer = optim.Adam(learning_rate=1e-5)\ntrainable = sum(p.size for _, p in tree_flatten(model.trainable_parameters()))\nprint(f\"Trainable parameters: {trainable}\")")),
+        tags: &["lora", "tuner", "trainer", "train", "fine-tune", "fine-tuning", "python api", "adapter"],
+    },
+
+    // ========================================================================
+    // mlx-examples patterns
+    // ========================================================================
+    MlxLmTopic {
+        name: "Training loop (mnist)",
+        path: "examples/mnist",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Canonical MLX training step: nn.value_and_grad, optimizer.update and mx.eval of the model and optimizer state",
+        page: "tree/main/mnist",
+        declaration: None,
+        content: "The pattern used across mlx-examples: define the loss as a function of (model, inputs, targets), wrap it with nn.value_and_grad(model, loss_fn), call optimizer.update(model, grads), then mx.eval(model.parameters(), optimizer.state) to force the lazy graph each step. Wrapping the step in mx.compile with inputs/outputs set to the model and optimizer state speeds up small models. Batches are plain mx.array slices; no DataLoader is needed.",
+        example: Some(("Minimal training step", "import mlx.core as mx\nimport mlx.nn as nn\nimport mlx.optimizers as optim\n\nclass MLP(nn.Module):\n    def __init__(self):\n        super().__init__()\n        self.layers = [nn.Linear(784, 256), nn.Linear(256, 10)]\n\n    def __call__(self, x):\n        return self.layers[1](nn.relu(self.layers[0](x)))\n\ndef loss_fn(model, X, y):\n    return nn.losses.cross_entropy(model(X), y, reduction=\"mean\")\n\nmodel = MLP()\noptimizer = optim.SGD(learning_rate=0.1)\nloss_and_grad = nn.value_and_grad(model, loss_fn)\n\nfor X, y in batches:\n    loss, grads = loss_and_grad(model, X, y)\n    optimizer.update(model, grads)\n    mx.eval(model.parameters(), optimizer.state)")),
+        tags: &["mnist", "training", "training loop", "value_and_grad", "optimizer", "mlp", "example", "examples"],
+    },
+    MlxLmTopic {
+        name: "Transformer language model (transformer_lm)",
+        path: "examples/transformer_lm",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Train a small decoder-only transformer on PTB, WikiText or enwik8 with causal masks",
+        page: "tree/main/transformer_lm",
+        declaration: None,
+        content: "Builds the model from nn.Embedding, nn.TransformerEncoder and a Linear head, with nn.MultiHeadAttention.create_additive_causal_mask(length) as the mask. Shows gradient-based training on token windows, periodic validation perplexity and compiling the step function. A starting point for custom architectures before moving to mlx-lm models.",
+        example: Some(("Causal transformer LM", "import mlx.core as mx\nimport mlx.nn as nn\n\nclass TransformerLM(nn.Module):\n    def __init__(self, vocab_size, num_layers, dims, num_heads):\n        super().__init__()\n        self.embedding = nn.Embedding(vocab_size, dims)\n        self.pe = nn.SinusoidalPositionalEncoding(dims)\n        self.transformer = nn.TransformerEncoder(num_layers, dims, num_heads, norm_first=True)\n        self.out_proj = nn.Linear(dims, vocab_size)\n\n    def __call__(self, x):\n        L = x.shape[1]\n        mask = nn.MultiHeadAttention.create_additive_causal_mask(L)\n        x = self.embedding(x) + self.pe(mx.arange(L))\n        return self.out_proj(self.transformer(x, mask))")),
+        tags: &["transformer", "language model", "causal mask", "ptb", "wikitext", "example", "examples", "training"],
+    },
+    MlxLmTopic {
+        name: "Whisper speech recognition (mlx-whisper)",
+        path: "examples/whisper",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Transcribe and translate audio with OpenAI Whisper models via mlx_whisper.transcribe",
+        page: "tree/main/whisper",
+        declaration: Some("def transcribe(\n    audio: Union[str, np.ndarray, mx.array],\n    *,\n    path_or_hf_repo: str = \"mlx-community/whisper-tiny\",\n    verbose: Optional[bool] = None,\n    word_timestamps: bool = False,\n    **decode_options,\n) -> dict"),
+        content: "Published as the mlx-whisper package (pip install mlx-whisper); requires ffmpeg for audio decoding. Returns a dict with text, segments and language. Models from mlx-community (tiny through large-v3, including quantized and turbo variants) load directly; convert.py converts original PyTorch checkpoints. decode_options include language and task=\"translate\".",
+        example: Some(("Transcribe a file", "import mlx_whisper\n\nresult = mlx_whisper.transcribe(\n    \"speech.mp3\",\n    path_or_hf_repo=\"mlx-community/whisper-large-v3-turbo\",\n    word_timestamps=True,\n)\nprint(result[\"text\"])")),
+        tags: &["whisper", "speech", "audio", "transcribe", "transcription", "asr", "example", "examples"],
+    },
+    MlxLmTopic {
+        name: "Stable Diffusion (stable_diffusion)",
+        path: "examples/stable_diffusion",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Text-to-image and image-to-image with Stable Diffusion 2.1 and SDXL Turbo",
+        page: "tree/main/stable_diffusion",
+        declaration: None,
+        content: "StableDiffusion / StableDiffusionXL classes load weights from the Hub; generate_latents yields latents per denoising step and decode turns them into images. txt2image.py supports --quantize (4-bit text encoder and 8-bit UNet) for 8 GB machines, and image2image.py adds a strength parameter. The FLUX example (flux/) follows the same structure and adds DreamBooth-style LoRA fine-tuning.",
+        example: Some(("Generate an image", "import mlx.core as mx\nfrom stable_diffusion import StableDiffusionXL\n\nsd = StableDiffusionXL(\"stabilityai/sdxl-turbo\", float16=True)\nfor latents in sd.generate_latents(\"a red fox in the snow\", n_images=1, num_steps=2, cfg_weight=0.0):\n    mx.eval(latents)\nimage = sd.decode(latents)")),
+        tags: &["stable diffusion", "diffusion", "sdxl", "image", "text-to-image", "flux", "example", "examples"],
+    },
+    MlxLmTopic {
+        name: "CLIP embeddings (clip)",
+        path: "examples/clip",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Image and text embeddings with CLIP for zero-shot classification and retrieval",
+        page: "tree/main/clip",
+        declaration: None,
+        content: "clip.load(path) returns the model, tokenizer and image processor for a converted openai/clip-vit checkpoint. The model returns text_embeds and image_embeds that can be compared with cosine similarity. The same load/convert layout is used by the other vision examples such as llava.",
+        example: Some(("Zero-shot classification", "import clip\nfrom PIL import Image\n\nmodel, tokenizer, img_processor = clip.load(\"mlx_model\")\ninputs = {\n    \"input_ids\": tokenizer([\"a photo of a cat\", \"a photo of a dog\"]),\n    \"pixel_values\": img_processor([Image.open(\"cat.jpeg\")]),\n}\noutput = model(**inputs)\nscores = output.image_embeds @ output.text_embeds.T")),
+        tags: &["clip", "embeddings", "vision", "image", "zero-shot", "retrieval", "example", "examples"],
+    },
+    MlxLmTopic {
+        name: "LLMs in mlx-examples (llms)",
+        path: "examples/llms",
+        source: MlxLmSource::Examples,
+        kind: MlxItemKind::Guide,
+        description: "Where the LLM examples went: generation and fine-tuning moved to mlx-lm; llms/ keeps specialised demos",
+        page: "tree/main/llms",
+        declaration: None,
+        content: "The original llama, mistral, mixtral and phi2 scripts were folded into mlx-lm, which supports hundreds of Hugging Face architectures; use mlx_lm.load/generate instead of the per-model scripts. The standalone lora/ example remains as a minimal, readable LoRA/QLoRA implementation for learning, while mlx_lm.lora is the maintained tool. llms/ still holds examples such as speculative decoding and gguf_llm (loading GGUF weights).",
+        example: None,
+        tags: &["llm", "llms", "llama", "mistral", "mixtral", "phi", "lora", "gguf", "example", "examples"],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_lm_topic() {
+        assert_eq!(find_lm_topic("lm/generate").unwrap().name, "mlx_lm.generate");
+        assert_eq!(find_lm_topic("mlx_lm.lora").unwrap().path, "lm/lora");
+        assert_eq!(find_lm_topic("/examples/whisper").unwrap().source, MlxLmSource::Examples);
+        assert!(find_lm_topic("mlx.core.array").is_none());
+    }
+
+    #[test]
+    fn test_topic_paths_match_sources() {
+        for topic in MLX_LM_TOPICS {
+            let prefix = match topic.source {
+                MlxLmSource::Lm => "lm/",
+                MlxLmSource::Examples => "examples/",
+            };
+            assert!(topic.path.starts_with(prefix), "{}", topic.path);
+        }
+        assert_eq!(MlxLmSource::from_identifier("mlx:lm"), Some(MlxLmSource::Lm));
+        assert_eq!(MlxLmSource::from_identifier("mlx:python"), None);
+    }
+}
@@ -4,7 +4,9 @@
 //! enabling AI assistants to help with machine learning on Apple Silicon.
 
 pub mod client;
+pub mod lm;
 pub mod types;
 
 pub use client::MlxClient;
+pub use lm::{find_lm_topic, MlxLmSource, MlxLmTopic, MLX_LM_TOPICS};
 pub use types::*;
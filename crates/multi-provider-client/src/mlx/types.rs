@@ -2,7 +2,7 @@
 //!
 //! MLX is a machine learning framework from Apple's ML Research team,
 //! optimized for Apple Silicon. This module covers both MLX (Python)
-//! and MLX-Swift documentation.
+//! and MLX-Swift documentation; mlx-lm and mlx-examples live in `lm`.
 
 use serde::{Deserialize, Serialize};
 
@@ -268,10 +268,4 @@ pub const MLX_PYTHON_TOPICS: &[(&str, &str, &str)] = &[
     ("mlx.optimizers.SGD", "python/optimizers/_autosummary/mlx.optimizers.SGD.html", "SGD optimizer"),
     ("mlx.optimizers.Adam", "python/optimizers/_autosummary/mlx.optimizers.Adam.html", "Adam optimizer"),
     ("mlx.optimizers.AdamW", "python/optimizers/_autosummary/mlx.optimizers.AdamW.html", "AdamW optimizer"),
-
-    // LLM
-    ("mlx_lm.load", "llm/load", "Load LLM model and tokenizer"),
-    ("mlx_lm.generate", "llm/generate", "Generate text with LLM"),
-    ("mlx_lm.convert", "llm/convert", "Convert models to MLX format"),
-    ("mlx_lm.quantize", "llm/quantize", "Quantize models for efficiency"),
 ];
@@ -1,5 +1,4 @@
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
@@ -9,20 +8,24 @@ use tokio::sync::Mutex;
 use tracing::{debug, instrument, warn};
 
 use super::types::{
-    QuickNodeCategory, QuickNodeCategoryItem, QuickNodeExample, QuickNodeMethod,
-    QuickNodeMethodKind, QuickNodeParameter, QuickNodeReturnType,
-    QuickNodeTechnology, SolanaMethodIndex, SOLANA_HTTP_METHODS, SOLANA_MARKETPLACE_ADDONS,
-    SOLANA_WEBSOCKET_METHODS,
+    category_title, method_path, parse_category_identifier, QuickNodeCategory, QuickNodeCategoryItem,
+    QuickNodeChain, QuickNodeExample, QuickNodeMethod, QuickNodeMethodIndex, QuickNodeParameter,
+    QuickNodeReturnType, QuickNodeTechnology,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
 
-const BASE_URL: &str = "https://www.quicknode.com/docs/solana";
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 #[derive(Debug)]
 pub struct QuickNodeClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<String>,
+    memory_cache: ProviderMemoryCache<String>,
     fetch_lock: Mutex<()>,
     cache_dir: PathBuf,
 }
@@ -44,9 +47,8 @@ impl QuickNodeClient {
             warn!(error = %e, "Failed to create QuickNode cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::QuickNode))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -54,102 +56,72 @@ impl QuickNodeClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::minutes(30)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::QuickNode, memory_ttl(ProviderType::QuickNode)),
             fetch_lock: Mutex::new(()),
             cache_dir,
         }
     }
 
-    /// Get available technologies (Solana categories)
+    /// Get available technologies (one per chain and method category)
     #[instrument(name = "quicknode_client.get_technologies", skip(self))]
     pub async fn get_technologies(&self) -> Result<Vec<QuickNodeTechnology>> {
-        let http_tech = QuickNodeTechnology {
-            identifier: "quicknode:solana:http".to_string(),
-            title: "Solana HTTP Methods".to_string(),
-            description: format!(
-                "Solana JSON-RPC HTTP API - {} methods for interacting with Solana blockchain",
-                SOLANA_HTTP_METHODS.len()
-            ),
-            url: format!("{BASE_URL}/getAccountInfo"),
-            item_count: SOLANA_HTTP_METHODS.len(),
-        };
-
-        let ws_tech = QuickNodeTechnology {
-            identifier: "quicknode:solana:websocket".to_string(),
-            title: "Solana WebSocket Methods".to_string(),
-            description: format!(
-                "Solana WebSocket Subscriptions - {} methods for real-time blockchain data",
-                SOLANA_WEBSOCKET_METHODS.len()
-            ),
-            url: format!("{BASE_URL}/accountSubscribe"),
-            item_count: SOLANA_WEBSOCKET_METHODS.len(),
-        };
-
-        let marketplace_tech = QuickNodeTechnology {
-            identifier: "quicknode:solana:marketplace".to_string(),
-            title: "Solana Marketplace Add-ons".to_string(),
-            description: format!(
-                "QuickNode Marketplace Add-ons - {} specialized APIs for Solana",
-                SOLANA_MARKETPLACE_ADDONS.len()
-            ),
-            url: format!("{BASE_URL}/jito-bundles"),
-            item_count: SOLANA_MARKETPLACE_ADDONS.len(),
-        };
-
-        Ok(vec![http_tech, ws_tech, marketplace_tech])
+        let mut technologies = Vec::new();
+        for chain in QuickNodeChain::ALL {
+            for category in chain.categories() {
+                let methods = chain.methods(category).unwrap_or_default();
+                let Some(first) = methods.first() else {
+                    continue;
+                };
+                technologies.push(QuickNodeTechnology {
+                    identifier: format!("quicknode:{}:{category}", chain.slug()),
+                    title: category_title(chain, category),
+                    description: format!(
+                        "{} - {} methods",
+                        category_description(chain, category),
+                        methods.len()
+                    ),
+                    url: format!("{}/{}", chain.docs_url(), first.name),
+                    item_count: methods.len(),
+                });
+            }
+        }
+        Ok(technologies)
     }
 
-    /// Get a category of methods
+    /// Get a category of methods, e.g. `quicknode:ethereum:http` or `quicknode:solana:websocket`
     #[instrument(name = "quicknode_client.get_category", skip(self))]
     pub async fn get_category(&self, identifier: &str) -> Result<QuickNodeCategory> {
-        let (methods, kind, title, description): (
-            &[SolanaMethodIndex],
-            QuickNodeMethodKind,
-            &str,
-            &str,
-        ) = match identifier {
-            "quicknode:solana:http" | "http" | "solana:http" => (
-                SOLANA_HTTP_METHODS,
-                QuickNodeMethodKind::HttpMethod,
-                "Solana HTTP Methods",
-                "JSON-RPC HTTP methods for Solana blockchain interaction",
-            ),
-            "quicknode:solana:websocket" | "websocket" | "solana:websocket" | "ws" => (
-                SOLANA_WEBSOCKET_METHODS,
-                QuickNodeMethodKind::WebSocketMethod,
-                "Solana WebSocket Methods",
-                "WebSocket subscription methods for real-time Solana data",
-            ),
-            "quicknode:solana:marketplace" | "marketplace" | "solana:marketplace" | "addons" => (
-                SOLANA_MARKETPLACE_ADDONS,
-                QuickNodeMethodKind::MarketplaceAddon,
-                "Solana Marketplace Add-ons",
-                "QuickNode specialized APIs for Solana",
-            ),
-            _ => anyhow::bail!("Unknown QuickNode category: {identifier}"),
-        };
+        let (chain, category) = parse_category_identifier(identifier)
+            .ok_or_else(|| anyhow::anyhow!("Unknown QuickNode category: {identifier}"))?;
+        let methods = chain.methods(category).unwrap_or_default();
 
         let items = methods
             .iter()
             .map(|m| QuickNodeCategoryItem {
                 name: m.name.to_string(),
+                path: method_path(chain, m.name),
                 description: m.description.to_string(),
-                kind,
-                url: format!("{BASE_URL}/{}", m.name),
+                kind: m.kind,
+                url: format!("{}/{}", chain.docs_url(), m.name),
             })
             .collect();
 
         Ok(QuickNodeCategory {
             identifier: identifier.to_string(),
-            title: title.to_string(),
-            description: description.to_string(),
+            title: category_title(chain, category),
+            description: category_description(chain, category),
             items,
         })
     }
 
     /// Fetch HTML content for a method page
-    async fn fetch_method_html(&self, method_name: &str) -> Result<String> {
-        let cache_key = format!("method_{method_name}.html");
+    async fn fetch_method_html(&self, chain: QuickNodeChain, method_name: &str) -> Result<String> {
+        // Solana pages keep their original cache key so existing caches stay valid
+        let cache_key = if chain.is_evm() {
+            format!("method_{}_{method_name}.html", chain.slug())
+        } else {
+            format!("method_{method_name}.html")
+        };
 
         // Check memory cache first
         if let Some(html) = self.memory_cache.get(&cache_key) {
@@ -173,13 +145,14 @@ impl QuickNodeClient {
         }
 
         // Fetch from QuickNode
-        let url = format!("{BASE_URL}/{method_name}");
+        let url = format!("{}/{method_name}", chain.docs_url());
         debug!(url = %url, "Fetching QuickNode method documentation");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch QuickNode documentation")?;
 
@@ -208,9 +181,9 @@ impl QuickNodeClient {
     /// Parse method documentation from HTML
     fn parse_method_html(
         &self,
-        method_name: &str,
+        chain: QuickNodeChain,
         html: &str,
-        index_entry: &SolanaMethodIndex,
+        index_entry: &QuickNodeMethodIndex,
     ) -> QuickNodeMethod {
         let document = Html::parse_document(html);
 
@@ -229,10 +202,11 @@ impl QuickNodeClient {
             .unwrap_or_else(|| index_entry.description.to_string());
 
         QuickNodeMethod {
-            name: method_name.to_string(),
+            name: index_entry.name.to_string(),
+            chain,
             description,
             kind: index_entry.kind,
-            url: format!("{BASE_URL}/{method_name}"),
+            url: format!("{}/{}", chain.docs_url(), index_entry.name),
             parameters,
             returns,
             examples,
@@ -430,79 +404,94 @@ impl QuickNodeClient {
         examples
     }
 
-    /// Get a specific method by name
+    /// Get a specific method by name. EVM methods are addressed as `chain/name`
+    /// (`base/eth_call`); a bare name is looked up on Solana first, then Ethereum.
     #[instrument(name = "quicknode_client.get_method", skip(self))]
     pub async fn get_method(&self, name: &str) -> Result<QuickNodeMethod> {
-        // Find method in index
-        let index_entry = SOLANA_HTTP_METHODS
-            .iter()
-            .chain(SOLANA_WEBSOCKET_METHODS.iter())
-            .chain(SOLANA_MARKETPLACE_ADDONS.iter())
-            .find(|m| m.name.eq_ignore_ascii_case(name))
+        let (chain, index_entry) = find_method(name)
             .ok_or_else(|| anyhow::anyhow!("QuickNode method not found: {name}"))?;
 
         // Fetch and parse HTML
-        let html = self.fetch_method_html(index_entry.name).await?;
-        Ok(self.parse_method_html(index_entry.name, &html, index_entry))
+        let html = self.fetch_method_html(chain, index_entry.name).await?;
+        Ok(self.parse_method_html(chain, &html, index_entry))
     }
 
-    /// Search for methods matching a query
+    /// Search for methods matching a query; a chain named in the query narrows the search
     #[instrument(name = "quicknode_client.search", skip(self))]
     pub async fn search(&self, query: &str) -> Result<Vec<QuickNodeMethod>> {
+        self.search_in(query, chain_from_query(query)).await
+    }
+
+    /// Search the methods of one chain, or of Solana and Ethereum when `chain` is `None`.
+    /// The EVM chains share most methods, so Base and Polygon are only searched when asked for.
+    #[instrument(name = "quicknode_client.search_in", skip(self))]
+    pub async fn search_in(
+        &self,
+        query: &str,
+        chain: Option<QuickNodeChain>,
+    ) -> Result<Vec<QuickNodeMethod>> {
         let query_lower = query.to_lowercase();
 
-        // Split query into keywords
+        // Split query into keywords; chain names only select the chain
         let keywords: Vec<&str> = query_lower
             .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
             .filter(|s| !s.is_empty() && s.len() > 1)
+            .filter(|s| !CHAIN_WORDS.iter().any(|(word, _)| word == s))
             .collect();
 
-        let mut scored_results: Vec<(i32, &SolanaMethodIndex)> = Vec::new();
+        let chains = match chain {
+            Some(chain) => vec![chain],
+            None => vec![QuickNodeChain::Solana, QuickNodeChain::Ethereum],
+        };
 
-        // Search all methods
-        let all_methods = SOLANA_HTTP_METHODS
-            .iter()
-            .chain(SOLANA_WEBSOCKET_METHODS.iter())
-            .chain(SOLANA_MARKETPLACE_ADDONS.iter());
+        let mut scored_results: Vec<(i32, QuickNodeChain, &QuickNodeMethodIndex)> = Vec::new();
 
-        for method in all_methods {
-            let name_lower = method.name.to_lowercase();
-            let desc_lower = method.description.to_lowercase();
+        for chain in chains {
+            for method in chain.all_methods() {
+                let name_lower = method.name.to_lowercase();
+                let desc_lower = method.description.to_lowercase();
 
-            let mut score = 0i32;
+                let mut score = 0i32;
 
-            for keyword in &keywords {
-                // Exact name match
-                if name_lower == *keyword {
+                // Full method names such as eth_getLogs are split by the keyword tokenizer
+                if query_lower.split_whitespace().any(|word| word == name_lower) {
                     score += 50;
                 }
-                // Name contains keyword
-                else if name_lower.contains(keyword) {
-                    score += 20;
-                }
-                // Description contains keyword
-                if desc_lower.contains(keyword) {
-                    score += 5;
+
+                for keyword in &keywords {
+                    // Exact name match
+                    if name_lower == *keyword {
+                        score += 50;
+                    }
+                    // Name contains keyword
+                    else if name_lower.contains(keyword) {
+                        score += 20;
+                    }
+                    // Description contains keyword
+                    if desc_lower.contains(keyword) {
+                        score += 5;
+                    }
                 }
-            }
 
-            if score > 0 {
-                scored_results.push((score, method));
+                if score > 0 {
+                    scored_results.push((score, chain, method));
+                }
             }
         }
 
         // Sort by score (highest first)
-        scored_results.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         // Convert to QuickNodeMethod (basic info only, without fetching HTML)
         let results: Vec<QuickNodeMethod> = scored_results
             .into_iter()
             .take(20)
-            .map(|(_, m)| QuickNodeMethod {
+            .map(|(_, chain, m)| QuickNodeMethod {
                 name: m.name.to_string(),
+                chain,
                 description: m.description.to_string(),
                 kind: m.kind,
-                url: format!("{BASE_URL}/{}", m.name),
+                url: format!("{}/{}", chain.docs_url(), m.name),
                 parameters: Vec::new(),
                 returns: None,
                 examples: Vec::new(),
@@ -517,6 +506,51 @@ impl QuickNodeClient {
     }
 }
 
+/// Words in a query that name a chain
+const CHAIN_WORDS: &[(&str, QuickNodeChain)] = &[
+    ("solana", QuickNodeChain::Solana),
+    ("sol", QuickNodeChain::Solana),
+    ("ethereum", QuickNodeChain::Ethereum),
+    ("evm", QuickNodeChain::Ethereum),
+    ("base", QuickNodeChain::Base),
+    ("polygon", QuickNodeChain::Polygon),
+    ("matic", QuickNodeChain::Polygon),
+];
+
+/// Chain named in a query, if any
+fn chain_from_query(query: &str) -> Option<QuickNodeChain> {
+    query
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .find_map(|word| CHAIN_WORDS.iter().find(|(name, _)| *name == word).map(|(_, chain)| *chain))
+}
+
+/// Resolve `chain/name` or a bare method name to its index entry
+fn find_method(name: &str) -> Option<(QuickNodeChain, &'static QuickNodeMethodIndex)> {
+    let (chains, name) = match name.split_once('/') {
+        Some((chain, name)) => (vec![QuickNodeChain::from_slug(chain)?], name),
+        None => (vec![QuickNodeChain::Solana, QuickNodeChain::Ethereum], name),
+    };
+    chains.into_iter().find_map(|chain| {
+        chain
+            .all_methods()
+            .into_iter()
+            .find(|m| m.name.eq_ignore_ascii_case(name))
+            .map(|m| (chain, m))
+    })
+}
+
+fn category_description(chain: QuickNodeChain, category: &str) -> String {
+    let title = chain.title();
+    match category {
+        "websocket" => format!("{title} WebSocket subscriptions for real-time blockchain data"),
+        "marketplace" => format!("QuickNode Marketplace add-ons with specialized APIs for {title}"),
+        "debug" => format!("{title} debug_* methods for tracing transactions and inspecting raw chain data"),
+        "trace" => format!("{title} trace_* methods (OpenEthereum/Erigon style call traces)"),
+        _ => format!("{title} JSON-RPC HTTP methods for reading chain state and sending transactions"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -525,4 +559,59 @@ mod tests {
     fn test_client_creation() {
         let _client = QuickNodeClient::new();
     }
+
+    #[test]
+    fn test_find_method() {
+        let (chain, method) = find_method("getAccountInfo").unwrap();
+        assert_eq!(chain, QuickNodeChain::Solana);
+        assert_eq!(method.name, "getAccountInfo");
+
+        let (chain, method) = find_method("eth_getlogs").unwrap();
+        assert_eq!(chain, QuickNodeChain::Ethereum);
+        assert_eq!(method.name, "eth_getLogs");
+
+        let (chain, _) = find_method("polygon/bor_getAuthor").unwrap();
+        assert_eq!(chain, QuickNodeChain::Polygon);
+        assert!(find_method("base/trace_block").is_none());
+        assert!(find_method("bor_getAuthor").is_none());
+    }
+
+    #[test]
+    fn test_chain_from_query() {
+        assert_eq!(chain_from_query("polygon eth_getLogs"), Some(QuickNodeChain::Polygon));
+        assert_eq!(chain_from_query("Base debug_traceCall"), Some(QuickNodeChain::Base));
+        assert_eq!(chain_from_query("eth_getLogs"), None);
+        assert_eq!(chain_from_query("getAccountInfo"), None);
+    }
+
+    #[tokio::test]
+    async fn test_search_evm_methods() {
+        let client = QuickNodeClient::new();
+
+        let results = client.search("eth_getLogs").await.unwrap();
+        assert_eq!(results[0].name, "eth_getLogs");
+        assert_eq!(results[0].chain, QuickNodeChain::Ethereum);
+        assert_eq!(results[0].path(), "ethereum/eth_getLogs");
+
+        let results = client.search("base debug_traceTransaction").await.unwrap();
+        assert_eq!(results[0].name, "debug_traceTransaction");
+        assert_eq!(results[0].url, "https://www.quicknode.com/docs/base/debug_traceTransaction");
+
+        let results = client.search("getAccountInfo").await.unwrap();
+        assert_eq!(results[0].chain, QuickNodeChain::Solana);
+        assert_eq!(results[0].path(), "getAccountInfo");
+    }
+
+    #[tokio::test]
+    async fn test_evm_categories() {
+        let client = QuickNodeClient::new();
+        let technologies = client.get_technologies().await.unwrap();
+        assert!(technologies.iter().any(|t| t.identifier == "quicknode:ethereum:trace"));
+        assert!(technologies.iter().any(|t| t.identifier == "quicknode:solana:marketplace"));
+
+        let category = client.get_category("quicknode:polygon:http").await.unwrap();
+        assert_eq!(category.title, "Polygon HTTP Methods");
+        assert!(category.items.iter().any(|item| item.path == "polygon/bor_getAuthor"));
+        assert!(client.get_category("quicknode:base:trace").await.is_err());
+    }
 }
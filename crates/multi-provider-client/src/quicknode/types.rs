@@ -1,6 +1,129 @@
 use serde::{Deserialize, Serialize};
 
-/// QuickNode technology representation (Solana categories)
+/// Chain whose RPC methods QuickNode documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuickNodeChain {
+    #[default]
+    Solana,
+    Ethereum,
+    Base,
+    Polygon,
+}
+
+impl QuickNodeChain {
+    pub const ALL: [Self; 4] = [Self::Solana, Self::Ethereum, Self::Base, Self::Polygon];
+
+    /// Path segment of the chain's docs, e.g. `ethereum` in `/docs/ethereum/eth_call`
+    #[must_use]
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::Solana => "solana",
+            Self::Ethereum => "ethereum",
+            Self::Base => "base",
+            Self::Polygon => "polygon",
+        }
+    }
+
+    #[must_use]
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Solana => "Solana",
+            Self::Ethereum => "Ethereum",
+            Self::Base => "Base",
+            Self::Polygon => "Polygon",
+        }
+    }
+
+    #[must_use]
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|chain| chain.slug().eq_ignore_ascii_case(slug))
+    }
+
+    #[must_use]
+    pub fn is_evm(self) -> bool {
+        self != Self::Solana
+    }
+
+    #[must_use]
+    pub fn docs_url(self) -> String {
+        format!("https://www.quicknode.com/docs/{}", self.slug())
+    }
+
+    /// Method categories documented for the chain, e.g. `http` or `debug`
+    #[must_use]
+    pub fn categories(self) -> &'static [&'static str] {
+        match self {
+            Self::Solana => &["http", "websocket", "marketplace"],
+            Self::Ethereum => &["http", "websocket", "debug", "trace"],
+            Self::Base | Self::Polygon => &["http", "websocket", "debug"],
+        }
+    }
+
+    /// Methods of one category; `None` when the chain has no such category
+    #[must_use]
+    pub fn methods(self, category: &str) -> Option<Vec<&'static QuickNodeMethodIndex>> {
+        if !self.categories().contains(&category) {
+            return None;
+        }
+        let tables: &[&'static [QuickNodeMethodIndex]] = match (self, category) {
+            (Self::Solana, "http") => &[SOLANA_HTTP_METHODS],
+            (Self::Solana, "websocket") => &[SOLANA_WEBSOCKET_METHODS],
+            (Self::Solana, _) => &[SOLANA_MARKETPLACE_ADDONS],
+            (Self::Polygon, "http") => &[EVM_HTTP_METHODS, POLYGON_BOR_METHODS],
+            (_, "http") => &[EVM_HTTP_METHODS],
+            (_, "websocket") => &[EVM_WEBSOCKET_METHODS],
+            (_, "debug") => &[EVM_DEBUG_METHODS],
+            _ => &[EVM_TRACE_METHODS],
+        };
+        Some(tables.iter().flat_map(|table| table.iter()).collect())
+    }
+
+    /// Every method documented for the chain
+    #[must_use]
+    pub fn all_methods(self) -> Vec<&'static QuickNodeMethodIndex> {
+        self.categories()
+            .iter()
+            .filter_map(|category| self.methods(category))
+            .flatten()
+            .collect()
+    }
+}
+
+/// Split a category identifier such as `quicknode:base:debug` into chain and category.
+/// Identifiers without a chain (`http`, `solana:http`, `ws`) keep meaning Solana.
+#[must_use]
+pub fn parse_category_identifier(identifier: &str) -> Option<(QuickNodeChain, &'static str)> {
+    let rest = identifier.strip_prefix("quicknode:").unwrap_or(identifier);
+    let (chain, category) = match rest.split_once(':') {
+        Some((chain, category)) => (QuickNodeChain::from_slug(chain)?, category),
+        None => (QuickNodeChain::Solana, rest),
+    };
+    let category = match category {
+        "http" => "http",
+        "websocket" | "ws" => "websocket",
+        "marketplace" | "addons" => "marketplace",
+        "debug" => "debug",
+        "trace" => "trace",
+        _ => return None,
+    };
+    chain.categories().contains(&category).then_some((chain, category))
+}
+
+/// Display title of a category, e.g. "Ethereum Debug Methods"
+#[must_use]
+pub fn category_title(chain: QuickNodeChain, category: &str) -> String {
+    let category = match category {
+        "websocket" => "WebSocket Methods",
+        "marketplace" => "Marketplace Add-ons",
+        "debug" => "Debug Methods",
+        "trace" => "Trace Methods",
+        _ => "HTTP Methods",
+    };
+    format!("{} {category}", chain.title())
+}
+
+/// QuickNode technology representation (one method category of a chain)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickNodeTechnology {
     pub identifier: String,
@@ -10,7 +133,7 @@ pub struct QuickNodeTechnology {
     pub item_count: usize,
 }
 
-/// Category of QuickNode methods (HTTP, WebSocket, Marketplace)
+/// Category of QuickNode methods (HTTP, WebSocket, Marketplace, Debug, Trace)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickNodeCategory {
     pub identifier: String,
@@ -22,6 +145,9 @@ pub struct QuickNodeCategory {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickNodeCategoryItem {
     pub name: String,
+    /// Identifier accepted by `get_method`, see [`QuickNodeMethod::path`]
+    #[serde(default)]
+    pub path: String,
     pub description: String,
     pub kind: QuickNodeMethodKind,
     pub url: String,
@@ -33,6 +159,10 @@ pub enum QuickNodeMethodKind {
     HttpMethod,
     WebSocketMethod,
     MarketplaceAddon,
+    /// EVM `debug_*` namespace
+    DebugMethod,
+    /// EVM `trace_*` namespace
+    TraceMethod,
 }
 
 impl std::fmt::Display for QuickNodeMethodKind {
@@ -41,6 +171,8 @@ impl std::fmt::Display for QuickNodeMethodKind {
             Self::HttpMethod => write!(f, "HTTP Method"),
             Self::WebSocketMethod => write!(f, "WebSocket Method"),
             Self::MarketplaceAddon => write!(f, "Marketplace Add-on"),
+            Self::DebugMethod => write!(f, "Debug Method"),
+            Self::TraceMethod => write!(f, "Trace Method"),
         }
     }
 }
@@ -49,6 +181,8 @@ impl std::fmt::Display for QuickNodeMethodKind {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickNodeMethod {
     pub name: String,
+    #[serde(default)]
+    pub chain: QuickNodeChain,
     pub description: String,
     pub kind: QuickNodeMethodKind,
     pub url: String,
@@ -57,6 +191,24 @@ pub struct QuickNodeMethod {
     pub examples: Vec<QuickNodeExample>,
 }
 
+impl QuickNodeMethod {
+    /// Identifier accepted by `get_method`
+    #[must_use]
+    pub fn path(&self) -> String {
+        method_path(self.chain, &self.name)
+    }
+}
+
+/// `get_method` identifier of a method: the bare name on Solana, `chain/name` elsewhere
+#[must_use]
+pub fn method_path(chain: QuickNodeChain, name: &str) -> String {
+    if chain.is_evm() {
+        format!("{}/{name}", chain.slug())
+    } else {
+        name.to_string()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickNodeParameter {
     pub name: String,
@@ -89,95 +241,206 @@ pub struct QuickNodeExample {
 
 /// Static method index entry (pre-defined for all Solana methods)
 #[derive(Debug, Clone)]
-pub struct SolanaMethodIndex {
+pub struct QuickNodeMethodIndex {
     pub name: &'static str,
     pub description: &'static str,
     pub kind: QuickNodeMethodKind,
 }
 
 /// All known Solana HTTP RPC methods
-pub const SOLANA_HTTP_METHODS: &[SolanaMethodIndex] = &[
-    SolanaMethodIndex { name: "getAccountInfo", description: "Returns all information associated with the account of provided Pubkey", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBalance", description: "Returns the balance of the account of provided Pubkey", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlock", description: "Returns identity and transaction information about a confirmed block in the ledger", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlockCommitment", description: "Returns commitment for particular block", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlockHeight", description: "Returns the current block height of the node", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlockProduction", description: "Returns recent block production information from the current or previous epoch", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlocks", description: "Returns a list of confirmed blocks between two slots", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlocksWithLimit", description: "Returns a list of confirmed blocks starting at the given slot", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getBlockTime", description: "Returns the estimated production time of a block", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getClusterNodes", description: "Returns information about all the nodes participating in the cluster", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getEpochInfo", description: "Returns information about the current epoch", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getEpochSchedule", description: "Returns epoch schedule information from this cluster's genesis config", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getFeeForMessage", description: "Returns the fee for a message", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getFirstAvailableBlock", description: "Returns the slot of the lowest confirmed block that has not been purged from the ledger", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getGenesisHash", description: "Returns the genesis hash", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getHealth", description: "Returns the current health of the node", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getHighestSnapshotSlot", description: "Returns the highest slot information that the node has snapshots for", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getIdentity", description: "Returns the identity pubkey for the current node", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getInflationGovernor", description: "Returns the current inflation governor", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getInflationRate", description: "Returns the specific inflation values for the current epoch", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getInflationReward", description: "Returns the inflation / staking reward for a list of addresses for an epoch", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getLargestAccounts", description: "Returns the 20 largest accounts, by lamport balance", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getLatestBlockhash", description: "Returns the latest blockhash", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getLeaderSchedule", description: "Returns the leader schedule for an epoch", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getMaxRetransmitSlot", description: "Get the max slot seen from retransmit stage", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getMaxShredInsertSlot", description: "Get the max slot seen from after shred insert", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getMinimumBalanceForRentExemption", description: "Returns minimum balance required to make account rent exempt", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getMultipleAccounts", description: "Returns the account information for a list of Pubkeys", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getProgramAccounts", description: "Returns all accounts owned by the provided program Pubkey", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getRecentPerformanceSamples", description: "Returns a list of recent performance samples", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getRecentPrioritizationFees", description: "Returns a list of prioritization fees from recent blocks", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSignaturesForAddress", description: "Returns signatures for confirmed transactions that include the given address", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSignatureStatuses", description: "Returns the statuses of a list of signatures", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSlot", description: "Returns the current slot the node is processing", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSlotLeader", description: "Returns the current slot leader", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSlotLeaders", description: "Returns the slot leaders for a given slot range", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getStakeMinimumDelegation", description: "Returns the stake minimum delegation, in lamports", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getSupply", description: "Returns information about the current supply", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTokenAccountBalance", description: "Returns the token balance of an SPL Token account", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTokenAccountsByDelegate", description: "Returns all SPL Token accounts by approved Delegate", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTokenAccountsByOwner", description: "Returns all SPL Token accounts by token owner", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTokenLargestAccounts", description: "Returns the 20 largest accounts of a particular SPL Token type", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTokenSupply", description: "Returns the total supply of an SPL Token type", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTransaction", description: "Returns transaction details for a confirmed transaction", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getTransactionCount", description: "Returns the current transaction count from the ledger", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getVersion", description: "Returns the current solana version running on the node", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "getVoteAccounts", description: "Returns the account info and associated stake for all the voting accounts", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "isBlockhashValid", description: "Returns whether a blockhash is still valid or not", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "minimumLedgerSlot", description: "Returns the lowest slot that the node has information about in its ledger", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "requestAirdrop", description: "Requests an airdrop of lamports to a Pubkey", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "sendTransaction", description: "Submits a signed transaction to the cluster for processing", kind: QuickNodeMethodKind::HttpMethod },
-    SolanaMethodIndex { name: "simulateTransaction", description: "Simulate sending a transaction", kind: QuickNodeMethodKind::HttpMethod },
+pub const SOLANA_HTTP_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "getAccountInfo", description: "Returns all information associated with the account of provided Pubkey", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBalance", description: "Returns the balance of the account of provided Pubkey", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlock", description: "Returns identity and transaction information about a confirmed block in the ledger", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlockCommitment", description: "Returns commitment for particular block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlockHeight", description: "Returns the current block height of the node", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlockProduction", description: "Returns recent block production information from the current or previous epoch", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlocks", description: "Returns a list of confirmed blocks between two slots", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlocksWithLimit", description: "Returns a list of confirmed blocks starting at the given slot", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getBlockTime", description: "Returns the estimated production time of a block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getClusterNodes", description: "Returns information about all the nodes participating in the cluster", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getEpochInfo", description: "Returns information about the current epoch", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getEpochSchedule", description: "Returns epoch schedule information from this cluster's genesis config", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getFeeForMessage", description: "Returns the fee for a message", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getFirstAvailableBlock", description: "Returns the slot of the lowest confirmed block that has not been purged from the ledger", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getGenesisHash", description: "Returns the genesis hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getHealth", description: "Returns the current health of the node", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getHighestSnapshotSlot", description: "Returns the highest slot information that the node has snapshots for", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getIdentity", description: "Returns the identity pubkey for the current node", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getInflationGovernor", description: "Returns the current inflation governor", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getInflationRate", description: "Returns the specific inflation values for the current epoch", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getInflationReward", description: "Returns the inflation / staking reward for a list of addresses for an epoch", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getLargestAccounts", description: "Returns the 20 largest accounts, by lamport balance", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getLatestBlockhash", description: "Returns the latest blockhash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getLeaderSchedule", description: "Returns the leader schedule for an epoch", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getMaxRetransmitSlot", description: "Get the max slot seen from retransmit stage", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getMaxShredInsertSlot", description: "Get the max slot seen from after shred insert", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getMinimumBalanceForRentExemption", description: "Returns minimum balance required to make account rent exempt", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getMultipleAccounts", description: "Returns the account information for a list of Pubkeys", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getProgramAccounts", description: "Returns all accounts owned by the provided program Pubkey", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getRecentPerformanceSamples", description: "Returns a list of recent performance samples", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getRecentPrioritizationFees", description: "Returns a list of prioritization fees from recent blocks", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSignaturesForAddress", description: "Returns signatures for confirmed transactions that include the given address", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSignatureStatuses", description: "Returns the statuses of a list of signatures", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSlot", description: "Returns the current slot the node is processing", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSlotLeader", description: "Returns the current slot leader", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSlotLeaders", description: "Returns the slot leaders for a given slot range", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getStakeMinimumDelegation", description: "Returns the stake minimum delegation, in lamports", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getSupply", description: "Returns information about the current supply", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTokenAccountBalance", description: "Returns the token balance of an SPL Token account", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTokenAccountsByDelegate", description: "Returns all SPL Token accounts by approved Delegate", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTokenAccountsByOwner", description: "Returns all SPL Token accounts by token owner", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTokenLargestAccounts", description: "Returns the 20 largest accounts of a particular SPL Token type", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTokenSupply", description: "Returns the total supply of an SPL Token type", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTransaction", description: "Returns transaction details for a confirmed transaction", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getTransactionCount", description: "Returns the current transaction count from the ledger", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getVersion", description: "Returns the current solana version running on the node", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "getVoteAccounts", description: "Returns the account info and associated stake for all the voting accounts", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "isBlockhashValid", description: "Returns whether a blockhash is still valid or not", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "minimumLedgerSlot", description: "Returns the lowest slot that the node has information about in its ledger", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "requestAirdrop", description: "Requests an airdrop of lamports to a Pubkey", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "sendTransaction", description: "Submits a signed transaction to the cluster for processing", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "simulateTransaction", description: "Simulate sending a transaction", kind: QuickNodeMethodKind::HttpMethod },
 ];
 
 /// All known Solana WebSocket methods
-pub const SOLANA_WEBSOCKET_METHODS: &[SolanaMethodIndex] = &[
-    SolanaMethodIndex { name: "accountSubscribe", description: "Subscribe to an account to receive notifications when the lamports or data changes", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "accountUnsubscribe", description: "Unsubscribe from account change notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "blockSubscribe", description: "Subscribe to receive notification anytime a new block is confirmed or finalized", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "blockUnsubscribe", description: "Unsubscribe from block notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "logsSubscribe", description: "Subscribe to transaction logging", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "logsUnsubscribe", description: "Unsubscribe from transaction logging", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "programSubscribe", description: "Subscribe to a program to receive notifications when the lamports or data changes", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "programUnsubscribe", description: "Unsubscribe from program-owned account change notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "rootSubscribe", description: "Subscribe to receive notification anytime a new root is set by the validator", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "rootUnsubscribe", description: "Unsubscribe from root notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "signatureSubscribe", description: "Subscribe to a transaction signature to receive notification when the transaction is confirmed", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "signatureUnsubscribe", description: "Unsubscribe from signature confirmation notification", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "slotSubscribe", description: "Subscribe to receive notification anytime a slot is processed by the validator", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "slotUnsubscribe", description: "Unsubscribe from slot notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "slotsUpdatesSubscribe", description: "Subscribe to receive a notification from the validator on a variety of updates on every slot", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "slotsUpdatesUnsubscribe", description: "Unsubscribe from slot-update notifications", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "voteSubscribe", description: "Subscribe to receive notification anytime a new vote is observed in gossip", kind: QuickNodeMethodKind::WebSocketMethod },
-    SolanaMethodIndex { name: "voteUnsubscribe", description: "Unsubscribe from vote notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+pub const SOLANA_WEBSOCKET_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "accountSubscribe", description: "Subscribe to an account to receive notifications when the lamports or data changes", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "accountUnsubscribe", description: "Unsubscribe from account change notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "blockSubscribe", description: "Subscribe to receive notification anytime a new block is confirmed or finalized", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "blockUnsubscribe", description: "Unsubscribe from block notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "logsSubscribe", description: "Subscribe to transaction logging", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "logsUnsubscribe", description: "Unsubscribe from transaction logging", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "programSubscribe", description: "Subscribe to a program to receive notifications when the lamports or data changes", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "programUnsubscribe", description: "Unsubscribe from program-owned account change notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "rootSubscribe", description: "Subscribe to receive notification anytime a new root is set by the validator", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "rootUnsubscribe", description: "Unsubscribe from root notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "signatureSubscribe", description: "Subscribe to a transaction signature to receive notification when the transaction is confirmed", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "signatureUnsubscribe", description: "Unsubscribe from signature confirmation notification", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "slotSubscribe", description: "Subscribe to receive notification anytime a slot is processed by the validator", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "slotUnsubscribe", description: "Unsubscribe from slot notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "slotsUpdatesSubscribe", description: "Subscribe to receive a notification from the validator on a variety of updates on every slot", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "slotsUpdatesUnsubscribe", description: "Unsubscribe from slot-update notifications", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "voteSubscribe", description: "Subscribe to receive notification anytime a new vote is observed in gossip", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "voteUnsubscribe", description: "Unsubscribe from vote notifications", kind: QuickNodeMethodKind::WebSocketMethod },
 ];
 
 /// QuickNode Marketplace add-ons for Solana
-pub const SOLANA_MARKETPLACE_ADDONS: &[SolanaMethodIndex] = &[
-    SolanaMethodIndex { name: "jito-bundles", description: "JITO Bundles API for MEV protection and atomic transaction bundles", kind: QuickNodeMethodKind::MarketplaceAddon },
-    SolanaMethodIndex { name: "metaplex-das-api", description: "Metaplex Digital Asset Standard API for NFT and compressed NFT data", kind: QuickNodeMethodKind::MarketplaceAddon },
-    SolanaMethodIndex { name: "priority-fee-api", description: "Priority Fee API for optimal transaction fee estimation", kind: QuickNodeMethodKind::MarketplaceAddon },
-    SolanaMethodIndex { name: "metis-trading-api", description: "Metis Jupiter V6 Swap API for DEX trading", kind: QuickNodeMethodKind::MarketplaceAddon },
-    SolanaMethodIndex { name: "yellowstone-grpc", description: "Yellowstone Geyser gRPC for real-time blockchain data streaming", kind: QuickNodeMethodKind::MarketplaceAddon },
+pub const SOLANA_MARKETPLACE_ADDONS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "jito-bundles", description: "JITO Bundles API for MEV protection and atomic transaction bundles", kind: QuickNodeMethodKind::MarketplaceAddon },
+    QuickNodeMethodIndex { name: "metaplex-das-api", description: "Metaplex Digital Asset Standard API for NFT and compressed NFT data", kind: QuickNodeMethodKind::MarketplaceAddon },
+    QuickNodeMethodIndex { name: "priority-fee-api", description: "Priority Fee API for optimal transaction fee estimation", kind: QuickNodeMethodKind::MarketplaceAddon },
+    QuickNodeMethodIndex { name: "metis-trading-api", description: "Metis Jupiter V6 Swap API for DEX trading", kind: QuickNodeMethodKind::MarketplaceAddon },
+    QuickNodeMethodIndex { name: "yellowstone-grpc", description: "Yellowstone Geyser gRPC for real-time blockchain data streaming", kind: QuickNodeMethodKind::MarketplaceAddon },
+];
+
+/// JSON-RPC HTTP methods shared by every EVM chain (`eth_*`, `net_*`, `web3_*`)
+pub const EVM_HTTP_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "eth_accounts", description: "Returns a list of addresses owned by the client", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_blobBaseFee", description: "Returns the expected base fee for blobs in the next block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_blockNumber", description: "Returns the latest block number of the blockchain", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_call", description: "Executes a new message call immediately without creating a transaction on the blockchain", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_chainId", description: "Returns the chain ID used for signing replay-protected transactions", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_estimateGas", description: "Returns an estimation of the gas required for a transaction to complete", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_feeHistory", description: "Returns base fee per gas and priority fee percentiles for a range of recent blocks", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_gasPrice", description: "Returns the current gas price in wei", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBalance", description: "Returns the balance of an account at a given block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBlockByHash", description: "Returns information about a block by hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBlockByNumber", description: "Returns information about a block by block number", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBlockReceipts", description: "Returns all transaction receipts of a block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBlockTransactionCountByHash", description: "Returns the number of transactions in a block by block hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getBlockTransactionCountByNumber", description: "Returns the number of transactions in a block by block number", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getCode", description: "Returns the contract code at a given address", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getFilterChanges", description: "Polling method for a filter; returns logs or hashes that occurred since the last poll", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getFilterLogs", description: "Returns all logs matching the filter with the given ID", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getLogs", description: "Returns event logs matching a filter of block range, addresses and topics", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getProof", description: "Returns the account and storage values of an account including the Merkle proof", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getStorageAt", description: "Returns the value from a storage position at a given address", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getTransactionByBlockHashAndIndex", description: "Returns a transaction by block hash and transaction index position", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getTransactionByBlockNumberAndIndex", description: "Returns a transaction by block number and transaction index position", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getTransactionByHash", description: "Returns information about a transaction by transaction hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getTransactionCount", description: "Returns the number of transactions sent from an address (the account nonce)", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getTransactionReceipt", description: "Returns the receipt of a transaction by transaction hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getUncleCountByBlockHash", description: "Returns the number of uncles in a block by block hash", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_getUncleCountByBlockNumber", description: "Returns the number of uncles in a block by block number", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_maxPriorityFeePerGas", description: "Returns an estimate of the priority fee (tip) needed for inclusion", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_newBlockFilter", description: "Creates a filter that notifies when a new block arrives", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_newFilter", description: "Creates a filter object based on filter options to notify when the state changes (logs)", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_newPendingTransactionFilter", description: "Creates a filter that notifies when new pending transactions arrive", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_sendRawTransaction", description: "Submits a signed raw transaction to the network", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_syncing", description: "Returns an object with sync status data, or false when not syncing", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "eth_uninstallFilter", description: "Uninstalls a filter with the given ID", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "net_listening", description: "Returns true if the client is actively listening for network connections", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "net_peerCount", description: "Returns the number of peers currently connected to the client", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "net_version", description: "Returns the current network ID", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "web3_clientVersion", description: "Returns the current version of the client", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "web3_sha3", description: "Returns the Keccak-256 hash of the given data", kind: QuickNodeMethodKind::HttpMethod },
+];
+
+/// EVM WebSocket subscriptions
+pub const EVM_WEBSOCKET_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "eth_subscribe", description: "Subscribe to newHeads, logs or newPendingTransactions events over WebSocket", kind: QuickNodeMethodKind::WebSocketMethod },
+    QuickNodeMethodIndex { name: "eth_unsubscribe", description: "Cancel a subscription created with eth_subscribe", kind: QuickNodeMethodKind::WebSocketMethod },
+];
+
+/// EVM `debug_*` namespace
+pub const EVM_DEBUG_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "debug_getBadBlocks", description: "Returns a list of the last bad blocks the client has seen", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_getRawBlock", description: "Returns an RLP-encoded block", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_getRawHeader", description: "Returns an RLP-encoded block header", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_getRawReceipts", description: "Returns the consensus encoding of all receipts in a block", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_getRawTransaction", description: "Returns the bytes of a transaction", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_storageRangeAt", description: "Returns the contract storage for a given range at a transaction in a block", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_traceBlock", description: "Replays a block from its RLP encoding and returns the traces of all transactions", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_traceBlockByHash", description: "Returns the traces of all transactions in a block by block hash", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_traceBlockByNumber", description: "Returns the traces of all transactions in a block by block number", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_traceCall", description: "Runs an eth_call within the context of a block and returns its trace", kind: QuickNodeMethodKind::DebugMethod },
+    QuickNodeMethodIndex { name: "debug_traceTransaction", description: "Replays a transaction and returns its execution trace (opcodes or callTracer frames)", kind: QuickNodeMethodKind::DebugMethod },
+];
+
+/// EVM `trace_*` namespace (Ethereum)
+pub const EVM_TRACE_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "trace_block", description: "Returns traces created at a given block", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_call", description: "Executes a call and returns the requested traces (trace, vmTrace, stateDiff)", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_callMany", description: "Performs multiple call traces on top of the same block, each on the state of the previous", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_filter", description: "Returns traces matching a filter of block range and from/to addresses", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_rawTransaction", description: "Traces a call to eth_sendRawTransaction without making the call", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_replayBlockTransactions", description: "Replays all transactions in a block and returns the requested traces", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_replayTransaction", description: "Replays a transaction and returns the requested traces", kind: QuickNodeMethodKind::TraceMethod },
+    QuickNodeMethodIndex { name: "trace_transaction", description: "Returns all traces of a given transaction", kind: QuickNodeMethodKind::TraceMethod },
+];
+
+/// Polygon PoS `bor_*` methods
+pub const POLYGON_BOR_METHODS: &[QuickNodeMethodIndex] = &[
+    QuickNodeMethodIndex { name: "bor_getAuthor", description: "Returns the address of the validator that produced a block", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "bor_getCurrentProposer", description: "Returns the address of the current block proposer", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "bor_getCurrentValidators", description: "Returns the current validator set", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "bor_getRootHash", description: "Returns the root hash of a block range, used for checkpoints", kind: QuickNodeMethodKind::HttpMethod },
+    QuickNodeMethodIndex { name: "bor_getSignersAtHash", description: "Returns the validators that signed the block with the given hash", kind: QuickNodeMethodKind::HttpMethod },
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_category_identifier() {
+        assert_eq!(parse_category_identifier("quicknode:solana:http"), Some((QuickNodeChain::Solana, "http")));
+        assert_eq!(parse_category_identifier("ws"), Some((QuickNodeChain::Solana, "websocket")));
+        assert_eq!(parse_category_identifier("quicknode:ethereum:trace"), Some((QuickNodeChain::Ethereum, "trace")));
+        assert_eq!(parse_category_identifier("quicknode:base:debug"), Some((QuickNodeChain::Base, "debug")));
+        assert_eq!(parse_category_identifier("quicknode:base:trace"), None);
+        assert_eq!(parse_category_identifier("quicknode:ethereum:marketplace"), None);
+        assert_eq!(category_title(QuickNodeChain::Polygon, "http"), "Polygon HTTP Methods");
+    }
+
+    #[test]
+    fn test_chain_methods() {
+        let polygon = QuickNodeChain::Polygon.methods("http").unwrap();
+        assert!(polygon.iter().any(|method| method.name == "eth_getLogs"));
+        assert!(polygon.iter().any(|method| method.name == "bor_getAuthor"));
+        assert!(!QuickNodeChain::Ethereum.methods("http").unwrap().iter().any(|method| method.name == "bor_getAuthor"));
+        assert!(QuickNodeChain::Ethereum.all_methods().iter().any(|method| method.name == "trace_block"));
+        assert!(QuickNodeChain::Base.methods("trace").is_none());
+    }
+}
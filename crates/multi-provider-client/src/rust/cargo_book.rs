@@ -0,0 +1,403 @@
+//! The Cargo Book (doc.rust-lang.org/cargo): manifest keys, profiles, workspaces, configuration
+//! and the cargo subcommands.
+//!
+//! The book has no search index worth downloading, so topics are catalogued here with the
+//! words people use for them; the text itself is read from the book's pages.
+
+use once_cell::sync::Lazy;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use super::mdbook::{code_block, heading_level, markdown_block};
+
+const CARGO_BOOK_URL: &str = "https://doc.rust-lang.org/cargo";
+/// Blocks kept from a page without an anchor (command pages stop at their options anyway)
+const MAX_PAGE_BLOCKS: usize = 20;
+
+/// A page or section of the Cargo Book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoTopic {
+    pub title: String,
+    /// Path within the book, e.g. `reference/profiles.html#lto`
+    pub path: String,
+    #[serde(skip)]
+    keywords: Vec<&'static str>,
+}
+
+impl CargoTopic {
+    pub fn url(&self) -> String {
+        format!("{CARGO_BOOK_URL}/{}", self.path)
+    }
+
+    /// The page without its anchor
+    pub fn page(&self) -> &str {
+        self.path.split('#').next().unwrap_or(&self.path)
+    }
+
+    pub fn anchor(&self) -> Option<&str> {
+        self.path.split_once('#').map(|(_, anchor)| anchor)
+    }
+}
+
+/// The text of a Cargo Book topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CargoBookSection {
+    pub title: String,
+    pub url: String,
+    /// The first paragraph
+    pub summary: String,
+    /// The section as Markdown
+    pub content: String,
+    /// The first code block (usually a `Cargo.toml` snippet or a command line)
+    pub example: Option<String>,
+    pub example_language: Option<String>,
+}
+
+/// `(title, path, keywords)` of the manifest, configuration and guide topics
+const BOOK_TOPICS: &[(&str, &str, &[&str])] = &[
+    // Only the title mentions Cargo.toml: most questions name it as context, not as the topic
+    ("The manifest format (Cargo.toml)", "reference/manifest.html", &["manifest"]),
+    ("The [package] section", "reference/manifest.html#the-package-section", &["package", "[package]"]),
+    ("The name field", "reference/manifest.html#the-name-field", &["name"]),
+    ("The version field", "reference/manifest.html#the-version-field", &["version"]),
+    ("The edition field", "reference/manifest.html#the-edition-field", &["edition", "2021", "2024"]),
+    ("The rust-version field", "reference/manifest.html#the-rust-version-field", &["rust-version", "msrv"]),
+    ("The build field", "reference/manifest.html#the-build-field", &["build"]),
+    ("The links field", "reference/manifest.html#the-links-field", &["links"]),
+    ("The license and license-file fields", "reference/manifest.html#the-license-and-license-file-fields", &["license", "license-file"]),
+    ("The include and exclude fields", "reference/manifest.html#the-include-and-exclude-fields", &["include", "exclude"]),
+    ("The publish field", "reference/manifest.html#the-publish-field", &["publish"]),
+    ("The metadata table", "reference/manifest.html#the-metadata-table", &["metadata", "[package.metadata]"]),
+    ("The [lints] section", "reference/manifest.html#the-lints-section", &["lints", "[lints]", "lint", "clippy"]),
+    ("Specifying dependencies", "reference/specifying-dependencies.html", &["dependencies", "dependency", "[dependencies]"]),
+    ("Version requirement syntax", "reference/specifying-dependencies.html#version-requirement-syntax", &["semver", "caret", "tilde", "wildcard", "requirement", "requirements"]),
+    ("Git dependencies", "reference/specifying-dependencies.html#specifying-dependencies-from-git-repositories", &["git", "branch", "rev", "tag"]),
+    ("Path dependencies", "reference/specifying-dependencies.html#specifying-path-dependencies", &["path", "local"]),
+    ("Platform specific dependencies", "reference/specifying-dependencies.html#platform-specific-dependencies", &["platform", "cfg", "[target]", "windows", "linux"]),
+    ("Development dependencies", "reference/specifying-dependencies.html#development-dependencies", &["dev-dependencies", "[dev-dependencies]", "dev"]),
+    ("Build dependencies", "reference/specifying-dependencies.html#build-dependencies", &["build-dependencies", "[build-dependencies]"]),
+    ("Renaming dependencies", "reference/specifying-dependencies.html#renaming-dependencies-in-cargotoml", &["rename", "renaming", "package"]),
+    ("Inheriting a dependency from a workspace", "reference/specifying-dependencies.html#inheriting-a-dependency-from-a-workspace", &["inherit", "inheriting", "workspace"]),
+    ("Features", "reference/features.html", &["features", "feature", "[features]"]),
+    ("The default feature", "reference/features.html#the-default-feature", &["default", "default-features"]),
+    ("Optional dependencies", "reference/features.html#optional-dependencies", &["optional"]),
+    ("Dependency features", "reference/features.html#dependency-features", &["dependency", "enable"]),
+    ("Feature unification", "reference/features.html#feature-unification", &["unification", "unify"]),
+    ("Command-line feature options", "reference/features.html#command-line-feature-options", &["--features", "--all-features", "--no-default-features"]),
+    ("Profiles", "reference/profiles.html", &["profile", "profiles", "[profile]", "optimization", "optimize"]),
+    ("opt-level", "reference/profiles.html#opt-level", &["opt-level", "optimization", "optimize", "size"]),
+    ("debug", "reference/profiles.html#debug", &["debug", "debuginfo", "symbols"]),
+    ("strip", "reference/profiles.html#strip", &["strip", "size"]),
+    ("lto", "reference/profiles.html#lto", &["lto", "link-time", "thin", "fat"]),
+    ("panic", "reference/profiles.html#panic", &["panic", "abort", "unwind"]),
+    ("codegen-units", "reference/profiles.html#codegen-units", &["codegen-units", "codegen"]),
+    ("incremental", "reference/profiles.html#incremental", &["incremental"]),
+    ("The release profile", "reference/profiles.html#release", &["release", "[profile.release]", "profile.release"]),
+    ("The dev profile", "reference/profiles.html#dev", &["dev", "[profile.dev]", "profile.dev"]),
+    ("Custom profiles", "reference/profiles.html#custom-profiles", &["custom", "inherits"]),
+    ("Profile overrides", "reference/profiles.html#overrides", &["override", "overrides", "per-package"]),
+    ("Workspaces", "reference/workspaces.html", &["workspace", "workspaces", "[workspace]", "monorepo"]),
+    ("Workspace members and exclude", "reference/workspaces.html#the-members-and-exclude-fields", &["members", "member", "exclude"]),
+    ("Virtual workspaces", "reference/workspaces.html#virtual-workspace", &["virtual", "root"]),
+    ("The [workspace.dependencies] table", "reference/workspaces.html#the-dependencies-table", &["[workspace.dependencies]", "workspace.dependencies", "shared"]),
+    ("The [workspace.package] table", "reference/workspaces.html#the-package-table", &["[workspace.package]", "workspace.package"]),
+    ("Dependency resolver versions", "reference/resolver.html#resolver-versions", &["resolver", "resolver-2", "resolver-3"]),
+    ("Cargo targets (lib, bin, example, test, bench)", "reference/cargo-targets.html", &["target", "targets", "[lib]", "[[bin]]", "lib", "bin", "binary", "binaries"]),
+    ("Configuring a target", "reference/cargo-targets.html#configuring-a-target", &["crate-type", "cdylib", "staticlib", "proc-macro", "harness", "required-features"]),
+    ("Target auto-discovery", "reference/cargo-targets.html#target-auto-discovery", &["autobins", "autoexamples", "autotests", "discovery"]),
+    ("Build scripts", "reference/build-scripts.html", &["build.rs", "build-script", "script", "codegen"]),
+    ("Build script outputs", "reference/build-scripts.html#outputs-of-the-build-script", &["rerun-if-changed", "rustc-link-lib", "rustc-cfg", "rustc-env", "cargo:rustc", "output"]),
+    ("The links manifest key", "reference/build-scripts.html#the-links-manifest-key", &["links", "native", "sys"]),
+    ("The [patch] section", "reference/overriding-dependencies.html#the-patch-section", &["patch", "[patch]", "[patch.crates-io]", "fork"]),
+    ("Overriding dependencies", "reference/overriding-dependencies.html", &["overriding", "replace", "[replace]"]),
+    ("Configuration (.cargo/config.toml)", "reference/config.html", &["config", "config.toml", ".cargo/config", ".cargo/config.toml", "configuration"]),
+    ("Configuration environment variables", "reference/config.html#environment-variables", &["cargo_", "env"]),
+    ("Aliases", "reference/config.html#alias", &["alias", "aliases"]),
+    ("build.target", "reference/config.html#buildtarget", &["cross", "cross-compile", "triple"]),
+    ("build.rustflags", "reference/config.html#buildrustflags", &["rustflags", "flags", "target-cpu"]),
+    ("target.<triple>.linker", "reference/config.html#targettriplelinker", &["linker", "mold", "lld"]),
+    ("Environment variables Cargo sets for crates", "reference/environment-variables.html#environment-variables-cargo-sets-for-crates", &["cargo_pkg_version", "cargo_manifest_dir", "env!", "out_dir"]),
+    ("Environment variables Cargo reads", "reference/environment-variables.html#environment-variables-cargo-reads", &["cargo_home", "cargo_target_dir", "environment", "variables"]),
+    ("Publishing on crates.io", "reference/publishing.html", &["publishing", "crates.io", "release", "upload"]),
+    ("Registries", "reference/registries.html", &["registry", "registries", "private"]),
+    ("Source replacement and vendoring", "reference/source-replacement.html", &["vendor", "vendoring", "mirror", "offline"]),
+    ("SemVer compatibility", "reference/semver.html", &["semver", "breaking", "compatibility"]),
+    ("Rust version (MSRV)", "reference/rust-version.html", &["msrv", "rust-version", "minimum"]),
+    ("Cargo.toml vs Cargo.lock", "guide/cargo-toml-vs-cargo-lock.html", &["cargo.lock", "lockfile", "lock"]),
+];
+
+/// `(command, summary)` of the cargo subcommands documented in the book
+const COMMANDS: &[(&str, &str)] = &[
+    ("add", "Add dependencies to a Cargo.toml manifest file"),
+    ("bench", "Execute benchmarks of a package"),
+    ("build", "Compile the current package"),
+    ("check", "Check the current package for errors without producing binaries"),
+    ("clean", "Remove generated artifacts"),
+    ("doc", "Build a package's documentation"),
+    ("fetch", "Fetch dependencies of a package from the network"),
+    ("fix", "Automatically fix lint warnings reported by rustc"),
+    ("generate-lockfile", "Generate the lockfile for a package"),
+    ("info", "Display information about a package in the registry"),
+    ("init", "Create a new Cargo package in an existing directory"),
+    ("install", "Build and install a Rust binary"),
+    ("login", "Log in to a registry"),
+    ("metadata", "Machine-readable metadata about the current package"),
+    ("new", "Create a new Cargo package"),
+    ("owner", "Manage the owners of a crate on the registry"),
+    ("package", "Assemble the local package into a distributable tarball"),
+    ("publish", "Upload a package to the registry"),
+    ("remove", "Remove dependencies from a Cargo.toml manifest file"),
+    ("run", "Run the current package"),
+    ("rustc", "Compile the current package, and pass extra options to the compiler"),
+    ("rustdoc", "Build a package's documentation, using specified custom flags"),
+    ("search", "Search packages in the registry"),
+    ("test", "Execute unit and integration tests of a package"),
+    ("tree", "Display a tree visualization of a dependency graph"),
+    ("uninstall", "Remove a Rust binary"),
+    ("update", "Update dependencies as recorded in the local lock file"),
+    ("vendor", "Vendor all dependencies locally"),
+    ("yank", "Remove a pushed crate from the index"),
+];
+
+static TOPICS: Lazy<Vec<CargoTopic>> = Lazy::new(|| {
+    let book = BOOK_TOPICS.iter().map(|(title, path, keywords)| CargoTopic {
+        title: (*title).to_string(),
+        path: (*path).to_string(),
+        keywords: keywords.to_vec(),
+    });
+    let commands = COMMANDS.iter().map(|(command, summary)| CargoTopic {
+        title: format!("cargo {command}: {summary}"),
+        path: format!("commands/cargo-{command}.html"),
+        keywords: vec![command],
+    });
+    book.chain(commands).collect()
+});
+
+/// Words too common in build questions to tell topics apart
+const STOP_WORDS: &[&str] = &[
+    "cargo", "rust", "the", "a", "an", "in", "to", "of", "for", "and", "or", "how", "do", "i",
+    "what", "is", "my", "with", "field", "section", "table", "set", "use", "using", "key",
+];
+
+/// Whether `query` asks about Cargo itself (manifests, profiles, subcommands) rather than an API
+pub fn is_cargo_query(query: &str) -> bool {
+    let query = query.to_lowercase();
+    if ["cargo.toml", "cargo.lock", "build.rs", ".cargo/config"]
+        .iter()
+        .any(|marker| query.contains(marker))
+    {
+        return true;
+    }
+    let tables = [
+        "[package", "[dependencies", "[dev-dependencies", "[build-dependencies", "[features",
+        "[profile", "[workspace", "[patch", "[lints", "[lib]", "[[bin", "[target.",
+    ];
+    if tables.iter().any(|table| query.contains(table)) {
+        return true;
+    }
+    let words = tokens(&query);
+    let Some(cargo) = words.iter().position(|word| *word == "cargo") else {
+        return false;
+    };
+    // `cargo build --release`, or cargo together with a manifest/config concept
+    let command = words
+        .get(cargo + 1)
+        .is_some_and(|next| COMMANDS.iter().any(|(command, _)| command == next));
+    command
+        || words.iter().any(|word| {
+            BOOK_TOPICS
+                .iter()
+                .any(|(_, _, keywords)| keywords.contains(word) && !STOP_WORDS.contains(word))
+        })
+}
+
+/// Topics matching `query`, best first
+pub fn search_cargo_topics(query: &str, limit: usize) -> Vec<CargoTopic> {
+    let query = query.to_lowercase();
+    let words: Vec<&str> = tokens(&query)
+        .into_iter()
+        .filter(|word| !STOP_WORDS.contains(word))
+        .collect();
+    let command = cargo_command(&query);
+
+    let mut scored: Vec<(usize, usize, &CargoTopic)> = TOPICS
+        .iter()
+        .enumerate()
+        .filter_map(|(order, topic)| {
+            let title = topic.title.to_lowercase();
+            let mut score = 0;
+            for word in &words {
+                if topic.keywords.contains(word) {
+                    score += 3;
+                }
+                if tokens(&title).contains(word) {
+                    score += 1;
+                }
+            }
+            // Bracketed table names match as written (`[profile.release]`)
+            score += topic
+                .keywords
+                .iter()
+                .filter(|keyword| keyword.starts_with('[') && query.contains(*keyword))
+                .count()
+                * 3;
+            if command.is_some_and(|command| topic.path == format!("commands/cargo-{command}.html")) {
+                score += 10;
+            }
+            (score > 0).then_some((score, order, topic))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().take(limit).map(|(_, _, topic)| topic.clone()).collect()
+}
+
+/// The topic at `path` (`reference/profiles.html#lto`), or the best match for it as a query
+pub fn find_cargo_topic(path: &str) -> Option<CargoTopic> {
+    let path = path.trim().trim_start_matches(CARGO_BOOK_URL).trim_start_matches('/');
+    TOPICS
+        .iter()
+        .find(|topic| topic.path == path)
+        .cloned()
+        .or_else(|| search_cargo_topics(path, 1).into_iter().next())
+}
+
+/// The subcommand in `cargo <command>`, if the book documents it
+fn cargo_command(query: &str) -> Option<&'static str> {
+    let words = tokens(query);
+    words.windows(2).find_map(|pair| {
+        (pair[0] == "cargo")
+            .then(|| COMMANDS.iter().find(|(command, _)| *command == pair[1]))
+            .flatten()
+            .map(|(command, _)| *command)
+    })
+}
+
+fn tokens(text: &str) -> Vec<&str> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, ',' | '?' | '(' | ')' | '"' | '`' | ':'))
+        .map(|word| word.trim_matches(|c: char| c == '.' || c == '!'))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Cut `topic`'s section out of its page: from the anchored heading to the next heading of the
+/// same or a higher level. Without an anchor, the page's introduction is taken (for command
+/// pages, everything before the options).
+pub fn parse_cargo_section(topic: &CargoTopic, html: &str) -> Option<CargoBookSection> {
+    let document = Html::parse_document(html);
+    let main_selector = Selector::parse("main").ok()?;
+    let main = document.select(&main_selector).next()?;
+    let elements: Vec<ElementRef> = main.children().filter_map(ElementRef::wrap).collect();
+
+    let (start, level) = match topic.anchor() {
+        Some(anchor) => {
+            let index = elements.iter().position(|element| element.value().id() == Some(anchor))?;
+            (index + 1, heading_level(&elements[index]).unwrap_or(2))
+        }
+        None => {
+            let index = elements.iter().position(|element| element.value().name() == "h1");
+            // Command pages are NAME/SYNOPSIS/DESCRIPTION/OPTIONS; other pages stop at their
+            // first section
+            let level = if topic.path.starts_with("commands/") { 1 } else { 6 };
+            (index.map_or(0, |index| index + 1), level)
+        }
+    };
+
+    let mut blocks = Vec::new();
+    let mut summary = None;
+    let mut example = None;
+    for element in &elements[start..] {
+        if heading_level(element).is_some_and(|heading| heading <= level)
+            || element.value().id() == Some("options")
+        {
+            break;
+        }
+        if element.value().name() == "pre" {
+            if let Some(block) = code_block(element) {
+                blocks.push(block.to_markdown());
+                example.get_or_insert((block.code.clone(), block.language().to_string()));
+            }
+        } else if let Some(text) = markdown_block(element) {
+            if summary.is_none() && element.value().name() == "p" {
+                summary = Some(text.clone());
+            }
+            blocks.push(text);
+        }
+        if topic.anchor().is_none() && blocks.len() >= MAX_PAGE_BLOCKS {
+            break;
+        }
+    }
+    if blocks.is_empty() {
+        return None;
+    }
+
+    let (example, example_language) = example.unzip();
+    Some(CargoBookSection {
+        title: topic.title.clone(),
+        url: topic.url(),
+        summary: summary.unwrap_or_default(),
+        content: blocks.join("\n\n"),
+        example,
+        example_language,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: &str = r##"<html><body><main>
+        <h1 id="profiles"><a class="header" href="#profiles">Profiles</a></h1>
+        <p>Profiles provide a way to alter the compiler settings.</p>
+        <h2 id="profile-settings"><a class="header" href="#profile-settings">Profile settings</a></h2>
+        <h3 id="lto"><a class="header" href="#lto">lto</a></h3>
+        <p>The <code>lto</code> setting controls <code>rustc</code>'s <code>-C lto</code> flag.</p>
+        <ul><li><code>false</code>: Performs “thin local LTO”.</li><li><code>"fat"</code>: Performs “fat” LTO.</li></ul>
+        <pre><code class="language-toml">[profile.release]
+lto = true</code></pre>
+        <h3 id="panic"><a class="header" href="#panic">panic</a></h3>
+        <p>The panic setting.</p>
+    </main></body></html>"##;
+
+    #[test]
+    fn detects_cargo_questions() {
+        assert!(is_cargo_query("How do I enable LTO in Cargo.toml?"));
+        assert!(is_cargo_query("cargo build --release"));
+        assert!(is_cargo_query("[profile.release] settings"));
+        assert!(is_cargo_query("cargo workspace dependencies"));
+        assert!(!is_cargo_query("rust HashMap insert"));
+        assert!(!is_cargo_query("cargo"));
+    }
+
+    #[test]
+    fn ranks_topics() {
+        let topics = search_cargo_topics("how to enable lto in cargo.toml", 3);
+        assert_eq!(topics[0].path, "reference/profiles.html#lto");
+        let topics = search_cargo_topics("cargo add --features", 3);
+        assert_eq!(topics[0].path, "commands/cargo-add.html");
+        let topics = search_cargo_topics("[workspace.dependencies] inherit", 2);
+        assert_eq!(topics[0].path, "reference/workspaces.html#the-dependencies-table");
+        assert_eq!(
+            find_cargo_topic("https://doc.rust-lang.org/cargo/reference/profiles.html#panic")
+                .map(|topic| topic.title),
+            Some("panic".to_string())
+        );
+    }
+
+    #[test]
+    fn extracts_anchored_sections() {
+        let topic = find_cargo_topic("reference/profiles.html#lto").unwrap();
+        let section = parse_cargo_section(&topic, PROFILES).expect("section");
+        assert_eq!(section.summary, "The `lto` setting controls `rustc`'s `-C lto` flag.");
+        assert!(section.content.contains("- `false`: Performs “thin local LTO”."));
+        assert_eq!(section.example.as_deref(), Some("[profile.release]\nlto = true"));
+        assert_eq!(section.example_language.as_deref(), Some("toml"));
+        assert!(!section.content.contains("panic setting"));
+
+        let page = find_cargo_topic("reference/profiles.html").unwrap();
+        let intro = parse_cargo_section(&page, PROFILES).expect("page");
+        assert_eq!(intro.summary, "Profiles provide a way to alter the compiler settings.");
+        assert!(!intro.content.contains("lto"));
+    }
+}
@@ -1,38 +1,55 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, instrument, warn};
 
-use super::html_parser::{extract_title_from_html, parse_rustdoc_html};
+use super::cargo_book::{parse_cargo_section, CargoBookSection, CargoTopic};
+use super::error_codes::{error_code_url, find_error_code, parse_error_code_html, RustErrorCode};
+use super::html_parser::{
+    extract_title_from_html, parse_rustdoc_html, parse_rustdoc_source, parse_source_range,
+    resolve_source_url,
+};
 use super::types::{
-    DocsRsCrateData, DocsRsRelease, DocsRsReleasesResponse, RustCategory, RustCategoryItem,
-    RustCrate, RustItem, RustItemKind, RustSearchIndex, RustSearchIndexEntry, RustTechnology,
-    STD_CRATES,
+    docs_rs_base, normalize_item_path, split_crate_spec, DocsRsCrateData, DocsRsRelease, DocsRsReleasesResponse, RustCategory, RustCategoryItem,
+    RustCrate, RustItem, RustItemKind, RustSearchIndex, RustSource, RustSearchIndexEntry, RustTechnology,
+    std_path_platforms, STD_CRATES,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const STD_SEARCH_INDEX_URL: &str = "https://doc.rust-lang.org/search-index.js";
 const DOCS_RS_RELEASES_SEARCH: &str = "https://docs.rs/releases/search";
 const DOCS_RS_CRATE_DATA: &str = "https://docs.rs/crate";
 const INDEX_CACHE_VERSION: u32 = 2;
+/// Source lines returned for one item; longer items are cut off.
+const MAX_SOURCE_LINES: usize = 200;
+/// Downloads a crate needs before an unrecognized query word is taken to mean it.
+const MIN_RESOLVED_CRATE_DOWNLOADS: u64 = 100_000;
 
 #[derive(Debug)]
 pub struct RustClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     /// Lock to prevent concurrent fetches of std index
     std_lock: Mutex<()>,
     /// Cached std library search indexes
     std_indexes: RwLock<HashMap<String, RustSearchIndex>>,
     /// Cached crate search indexes (for docs.rs crates)
     crate_indexes: RwLock<HashMap<String, RustSearchIndex>>,
+    /// Names crates.io answered 404 for during this session
+    missing_crates: RwLock<HashSet<String>>,
     cache_dir: PathBuf,
 }
 
@@ -53,9 +70,8 @@ impl RustClient {
             warn!(error = %e, "Failed to create Rust cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Rust))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -63,10 +79,11 @@ impl RustClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(24)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Rust, memory_ttl(ProviderType::Rust)),
             std_lock: Mutex::new(()),
             std_indexes: RwLock::new(HashMap::new()),
             crate_indexes: RwLock::new(HashMap::new()),
+            missing_crates: RwLock::new(HashSet::new()),
             cache_dir,
         }
     }
@@ -100,8 +117,12 @@ impl RustClient {
     }
 
     /// Get crate information from docs.rs
+    ///
+    /// `spec` is a crate name, optionally pinned to a version (`tokio@1.35`); docs.rs resolves
+    /// partial versions to the newest matching release. std crates are never versioned.
     #[instrument(name = "rust_client.get_crate", skip(self))]
-    pub async fn get_crate(&self, name: &str) -> Result<RustCrate> {
+    pub async fn get_crate(&self, spec: &str) -> Result<RustCrate> {
+        let (name, version) = split_crate_spec(spec);
         // Check if it's a standard library crate
         if let Some((_, desc)) = STD_CRATES.iter().find(|(n, _)| *n == name) {
             return Ok(RustCrate {
@@ -114,85 +135,79 @@ impl RustClient {
             });
         }
 
-        // Fetch from docs.rs
-        let cache_key = format!("crate_{}.json", name);
+        let data = self
+            .fetch_crate_data(name)
+            .await?
+            .with_context(|| format!("Crate '{name}' not found on crates.io"))?;
+        let mut krate = crate_from_data(data);
+        if let Some(version) = version {
+            let valid = version
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '~' | '^' | '='));
+            if !valid {
+                anyhow::bail!("Invalid version '{version}' for crate '{name}'");
+            }
+            krate.version = version.to_string();
+            krate.documentation_url = format!("https://docs.rs/{}/{version}/", krate.name);
+        }
+        Ok(krate)
+    }
 
-        // Check disk cache first
+    /// Look up a crate that is not in the built-in list on crates.io.
+    ///
+    /// Returns `None` when no such crate is published, or when it has too few downloads to be
+    /// what a query meant (ordinary words are often taken as crate names).
+    #[instrument(name = "rust_client.resolve_crate", skip(self))]
+    pub async fn resolve_crate(&self, name: &str) -> Result<Option<RustCrate>> {
+        if STD_CRATES.iter().any(|(n, _)| *n == name) {
+            return self.get_crate(name).await.map(Some);
+        }
+        Ok(self
+            .fetch_crate_data(name)
+            .await?
+            .filter(|data| data.downloads >= MIN_RESOLVED_CRATE_DOWNLOADS)
+            .map(crate_from_data))
+    }
+
+    /// crates.io metadata of `name` (disk cached), `None` if crates.io doesn't know it.
+    async fn fetch_crate_data(&self, name: &str) -> Result<Option<DocsRsCrateData>> {
+        if self.missing_crates.read().await.contains(name) {
+            return Ok(None);
+        }
+        let cache_key = format!("crate_{}.json", name);
         if let Ok(Some(entry)) = self.disk_cache.load::<DocsRsCrateData>(&cache_key).await {
-            let data = entry.value;
-            return Ok(RustCrate {
-                name: data.name,
-                version: data.version,
-                description: data.description.unwrap_or_default(),
-                documentation_url: format!("https://docs.rs/{}/latest/", name),
-                repository_url: data.repository,
-                is_std: false,
-            });
+            return Ok(Some(entry.value));
         }
 
         // Fetch from crates.io API (docs.rs doesn't have a JSON API)
         let url = format!("https://crates.io/api/v1/crates/{}", name);
         debug!(url = %url, "Fetching crate data from crates.io");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch crate data from crates.io")?;
 
+        if response.status() == StatusCode::NOT_FOUND {
+            self.missing_crates.write().await.insert(name.to_string());
+            return Ok(None);
+        }
         if !response.status().is_success() {
-            anyhow::bail!("Crate '{}' not found on crates.io: {}", name, response.status());
+            anyhow::bail!("crates.io lookup of '{}' failed: {}", name, response.status());
         }
 
         let json: Value = response
             .json()
             .await
             .context("Failed to parse crates.io response")?;
-
-        // Extract data from crates.io API response format
-        let crate_obj = json.get("crate")
-            .context("Missing 'crate' field in crates.io response")?;
-
-        let data = DocsRsCrateData {
-            name: crate_obj.get("name")
-                .and_then(|v| v.as_str())
-                .unwrap_or(name)
-                .to_string(),
-            version: crate_obj.get("newest_version")
-                .and_then(|v| v.as_str())
-                .unwrap_or("latest")
-                .to_string(),
-            description: crate_obj.get("description")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string()),
-            repository: crate_obj.get("repository")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string()),
-            documentation: crate_obj.get("documentation")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string()),
-            keywords: crate_obj.get("keywords")
-                .and_then(|v| v.as_array())
-                .map(|arr| arr.iter()
-                    .filter_map(|v| v.as_str())
-                    .map(|s| s.to_string())
-                    .collect())
-                .unwrap_or_default(),
-            releases: Vec::new(), // We don't need releases for search functionality
-        };
+        let data = parse_crates_io_crate(&json, name)?;
 
         // Cache the result
         let _ = self.disk_cache.store(&cache_key, data.clone()).await;
-
-        Ok(RustCrate {
-            name: data.name,
-            version: data.version,
-            description: data.description.unwrap_or_default(),
-            documentation_url: format!("https://docs.rs/{}/latest/", name),
-            repository_url: data.repository,
-            is_std: false,
-        })
+        Ok(Some(data))
     }
 
     /// Get category/module listing for a crate
@@ -237,7 +252,9 @@ impl RustClient {
     /// Get a specific item by path (with detailed documentation)
     #[instrument(name = "rust_client.get_item", skip(self))]
     pub async fn get_item(&self, path: &str) -> Result<RustItem> {
-        // Parse the path (e.g., "std::collections::HashMap" or "serde::Deserialize")
+        // Parse the path (e.g., "std::collections::HashMap", "serde::Deserialize" or
+        // "tokio@1.35/sync/mpsc")
+        let path = &normalize_item_path(path);
         let parts: Vec<&str> = path.split("::").collect();
         if parts.is_empty() {
             anyhow::bail!("Invalid path: {}", path);
@@ -305,7 +322,8 @@ impl RustClient {
         for (url, guessed_kind) in urls_to_try {
             debug!(url = %url, "Trying URL");
 
-            match self.http.get(&url).send().await {
+            ensure_online(&url)?;
+            match self.http.get(&url).send_with_retry().await {
                 Ok(response) if response.status().is_success() => {
                     let html = response.text().await?;
                     let parsed = parse_rustdoc_html(&html, guessed_kind);
@@ -318,7 +336,7 @@ impl RustClient {
                         path: path.to_string(),
                         kind: guessed_kind,
                         summary: parsed.documentation.clone().unwrap_or_default(),
-                        crate_name: crate_name.to_string(),
+                        crate_name: split_crate_spec(crate_name).0.to_string(),
                         crate_version: crate_version.to_string(),
                         url: url.clone(),
                         declaration: parsed.declaration,
@@ -326,8 +344,16 @@ impl RustClient {
                         examples: parsed.examples,
                         methods: parsed.methods,
                         impl_traits: parsed.impl_traits,
+                        impl_blocks: parsed.impl_blocks,
+                        implementors: parsed.implementors,
                         associated_types: parsed.associated_types,
-                        source_url: parsed.source_url,
+                        source_url: parsed.source_url.map(|href| resolve_source_url(&url, &href)),
+                        required_features: parsed.required_features,
+                        platforms: if parsed.platforms.is_empty() {
+                            std_path_platforms(path)
+                        } else {
+                            parsed.platforms
+                        },
                         is_detailed: true,
                     });
                 }
@@ -350,11 +376,11 @@ impl RustClient {
             String::new()
         };
 
-        let is_std = STD_CRATES.iter().any(|(n, _)| *n == crate_name);
+        let is_std = STD_CRATES.iter().any(|(n, _)| *n == split_crate_spec(crate_name).0);
         let base = if is_std {
             format!("https://doc.rust-lang.org/{}", crate_name)
         } else {
-            format!("https://docs.rs/{}/{}/{}", crate_name, version, crate_name)
+            docs_rs_base(crate_name, version)
         };
 
         let module_prefix = if module_path.is_empty() {
@@ -396,7 +422,7 @@ impl RustClient {
             path: full_path.to_string(),
             kind,
             summary: summary.to_string(),
-            crate_name: crate_name.to_string(),
+            crate_name: split_crate_spec(crate_name).0.to_string(),
             crate_version: crate_version.to_string(),
             url: url.to_string(),
             declaration: None,
@@ -404,8 +430,12 @@ impl RustClient {
             examples: Vec::new(),
             methods: Vec::new(),
             impl_traits: Vec::new(),
+            impl_blocks: Vec::new(),
+            implementors: Vec::new(),
             associated_types: Vec::new(),
             source_url: None,
+            required_features: Vec::new(),
+            platforms: std_path_platforms(full_path),
             is_detailed: false,
         };
 
@@ -426,8 +456,16 @@ impl RustClient {
                     item.examples = detailed.examples;
                     item.methods = detailed.methods;
                     item.impl_traits = detailed.impl_traits;
+                    item.impl_blocks = detailed.impl_blocks;
+                    item.implementors = detailed.implementors;
                     item.associated_types = detailed.associated_types;
-                    item.source_url = detailed.source_url;
+                    item.source_url = detailed
+                        .source_url
+                        .map(|href| resolve_source_url(&item.url, &href));
+                    item.required_features = detailed.required_features;
+                    if !detailed.platforms.is_empty() {
+                        item.platforms = detailed.platforms;
+                    }
                     item.is_detailed = true;
                     break;
                 }
@@ -440,10 +478,108 @@ impl RustClient {
         Ok(item)
     }
 
+    /// Fetch the source code of an item from its `src/` page on docs.rs or doc.rust-lang.org
+    #[instrument(name = "rust_client.get_source", skip(self))]
+    pub async fn get_source(&self, path: &str) -> Result<RustSource> {
+        let item = self.get_item(path).await?;
+        let url = item
+            .source_url
+            .clone()
+            .with_context(|| format!("No source link on the documentation page of {}", item.path))?;
+        let (start, end) = parse_source_range(&url)
+            .with_context(|| format!("Source link of {} has no line range: {url}", item.path))?;
+        let page_url = url.split('#').next().unwrap_or(&url);
+
+        let cache_key = format!("src_{}.json", page_url.replace(['/', ':', '.'], "_"));
+        let lines = match self.disk_cache.load::<Vec<String>>(&cache_key).await {
+            Ok(Some(entry)) => entry.value,
+            _ => {
+                ensure_online(page_url)?;
+                let response = self
+                    .http
+                    .get(page_url)
+                    .send_with_retry()
+                    .await
+                    .with_context(|| format!("Failed to fetch source from {page_url}"))?;
+                if !response.status().is_success() {
+                    anyhow::bail!("Failed to fetch source from {page_url}: {}", response.status());
+                }
+                let lines = parse_rustdoc_source(&response.text().await?);
+                if lines.is_empty() {
+                    anyhow::bail!("No source listing found at {page_url}");
+                }
+                let _ = self.disk_cache.store(&cache_key, lines.clone()).await;
+                lines
+            }
+        };
+
+        Ok(RustSource::from_lines(&item, &url, &lines, start, end, MAX_SOURCE_LINES))
+    }
+
+    /// The text of a Cargo Book topic; pages are cached whole since topics share them
+    #[instrument(name = "rust_client.get_cargo_section", skip(self, topic), fields(path = %topic.path))]
+    pub async fn get_cargo_section(&self, topic: &CargoTopic) -> Result<CargoBookSection> {
+        let page = topic.page();
+        let cache_key = format!("cargo_book_{}.json", page.replace(['/', '.'], "_"));
+        let html = match self.disk_cache.load::<String>(&cache_key).await {
+            Ok(Some(entry)) => entry.value,
+            _ => {
+                let url = topic.url();
+                let url = url.split('#').next().unwrap_or(&url);
+                ensure_online(url)?;
+                let response = self
+                    .http
+                    .get(url)
+                    .send_with_retry()
+                    .await
+                    .with_context(|| format!("Failed to fetch {url}"))?;
+                if !response.status().is_success() {
+                    anyhow::bail!("Failed to fetch {url}: {}", response.status());
+                }
+                let html = response.text().await?;
+                let _ = self.disk_cache.store(&cache_key, html.clone()).await;
+                html
+            }
+        };
+        parse_cargo_section(topic, &html)
+            .with_context(|| format!("No section '{}' in the Cargo Book", topic.path))
+    }
+
+    /// Explanation of a compiler error code (`E0502`) from the rustc error index
+    #[instrument(name = "rust_client.get_error_code", skip(self))]
+    pub async fn get_error_code(&self, code: &str) -> Result<RustErrorCode> {
+        let code = find_error_code(code).with_context(|| format!("Not a rustc error code: {code}"))?;
+        let cache_key = format!("error_code_{code}.json");
+        if let Ok(Some(entry)) = self.disk_cache.load::<RustErrorCode>(&cache_key).await {
+            return Ok(entry.value);
+        }
+
+        let url = error_code_url(&code);
+        ensure_online(&url)?;
+        let response = self
+            .http
+            .get(&url)
+            .send_with_retry()
+            .await
+            .with_context(|| format!("Failed to fetch error code explanation from {url}"))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            anyhow::bail!("{code} is not in the rustc error index");
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch {url}: {}", response.status());
+        }
+        let html = response.text().await?;
+        let explanation = parse_error_code_html(&code, &html)
+            .with_context(|| format!("{code} has no explanation in the rustc error index"))?;
+        let _ = self.disk_cache.store(&cache_key, explanation.clone()).await;
+        Ok(explanation)
+    }
+
     /// Get a specific item by path without fetching detailed docs (for batch operations)
     #[instrument(name = "rust_client.get_item_minimal", skip(self))]
     pub async fn get_item_minimal(&self, path: &str) -> Result<RustItem> {
         // Parse the path (e.g., "std::collections::HashMap" or "serde::Deserialize")
+        let path = &normalize_item_path(path);
         let parts: Vec<&str> = path.split("::").collect();
         if parts.is_empty() {
             anyhow::bail!("Invalid path: {}", path);
@@ -482,7 +618,7 @@ impl RustClient {
             path: full_path.clone(),
             kind: entry.kind,
             summary: entry.desc.clone(),
-            crate_name: crate_name.to_string(),
+            crate_name: split_crate_spec(crate_name).0.to_string(),
             crate_version: crate_info.version.clone(),
             url: self.build_item_url(crate_name, &crate_info.version, &full_path, entry.kind),
             declaration: None,
@@ -490,8 +626,12 @@ impl RustClient {
             examples: Vec::new(),
             methods: Vec::new(),
             impl_traits: Vec::new(),
+            impl_blocks: Vec::new(),
+            implementors: Vec::new(),
             associated_types: Vec::new(),
             source_url: None,
+            required_features: Vec::new(),
+            platforms: std_path_platforms(&full_path),
             is_detailed: false,
         })
     }
@@ -504,7 +644,8 @@ impl RustClient {
         kind: RustItemKind,
     ) -> Result<super::html_parser::ParsedDocumentation> {
         // Check disk cache first
-        let cache_key = format!("html_{}.json", url.replace(['/', ':', '.'], "_"));
+        // Versioned so parses cached before a field was extracted get refreshed
+        let cache_key = format!("html_v4_{}.json", url.replace(['/', ':', '.'], "_"));
 
         if let Ok(Some(entry)) = self
             .disk_cache
@@ -517,10 +658,11 @@ impl RustClient {
 
         // Fetch the HTML page
         debug!(url = %url, "Fetching HTML documentation");
+        ensure_online(url)?;
         let response = self
             .http
             .get(url)
-            .send()
+            .send_with_retry()
             .await
             .with_context(|| format!("Failed to fetch documentation from {}", url))?;
 
@@ -543,6 +685,11 @@ impl RustClient {
         Ok(parsed)
     }
 
+    /// Fetch `crate_name`'s search index into the memory and disk caches; returns its entry count.
+    pub async fn prefetch_search_index(&self, crate_name: &str) -> Result<usize> {
+        Ok(self.get_search_index(crate_name).await?.items.len())
+    }
+
     /// Search within a crate
     #[instrument(name = "rust_client.search", skip(self))]
     pub async fn search(&self, crate_name: &str, query: &str) -> Result<Vec<RustItem>> {
@@ -614,7 +761,7 @@ impl RustClient {
             .collect();
 
         // Sort by score descending
-        results.sort_by(|a, b| b.0.cmp(&a.0));
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         Ok(results.into_iter().map(|(_, item)| item).take(50).collect())
     }
@@ -637,10 +784,11 @@ impl RustClient {
         let url = format!("{}?query={}", DOCS_RS_RELEASES_SEARCH, urlencoding::encode(query));
         debug!(url = %url, "Searching docs.rs for crates");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to search docs.rs")?;
 
@@ -661,7 +809,10 @@ impl RustClient {
 
     /// Get or fetch the search index for a crate
     async fn get_search_index(&self, crate_name: &str) -> Result<RustSearchIndex> {
-        let is_std = STD_CRATES.iter().any(|(n, _)| *n == crate_name);
+        let (name, _) = split_crate_spec(crate_name);
+        let is_std = STD_CRATES.iter().any(|(n, _)| *n == name);
+        // Pinned crates get their own index; std has one version
+        let crate_name = if is_std { name } else { crate_name };
 
         // Check in-memory cache first
         if is_std {
@@ -721,10 +872,11 @@ impl RustClient {
         let _lock = self.std_lock.lock().await;
 
         debug!("Fetching std library search index");
+        ensure_online(STD_SEARCH_INDEX_URL)?;
         let response = self
             .http
             .get(STD_SEARCH_INDEX_URL)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch std search index")?;
 
@@ -750,10 +902,11 @@ impl RustClient {
         let url = format!("https://doc.rust-lang.org/{}/all.html", crate_name);
         debug!(url = %url, "Scraping std all items page for search index");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch std all items page")?;
 
@@ -819,9 +972,10 @@ impl RustClient {
             }
         }
 
-        // Deduplicate items by name
-        items.sort_by(|a, b| a.name.cmp(&b.name));
-        items.dedup_by(|a, b| a.name == b.name && a.kind == b.kind);
+        // Deduplicate items by name, keeping same-named platform variants such as
+        // `os::unix::fs::FileExt` and `os::windows::fs::FileExt`
+        items.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path)));
+        items.dedup_by(|a, b| a.name == b.name && a.kind == b.kind && a.path == b.path);
 
         debug!(count = items.len(), "Scraped items from std all items page");
 
@@ -839,10 +993,11 @@ impl RustClient {
         let url = format!("https://doc.rust-lang.org/{}/index.html", crate_name);
         debug!(url = %url, "Scraping std index.html as fallback");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch std documentation")?;
 
@@ -890,17 +1045,18 @@ impl RustClient {
         // Try to fetch search-index.js from docs.rs
         let url = format!(
             "https://docs.rs/{}/{}/search-index.js",
-            crate_name, crate_info.version
+            crate_info.name, crate_info.version
         );
 
         debug!(url = %url, "Fetching docs.rs search index");
 
-        let response = self.http.get(&url).send().await;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await;
 
         match response {
             Ok(resp) if resp.status().is_success() => {
                 let text = resp.text().await?;
-                let mut index = parse_search_index_js(&text, crate_name)?;
+                let mut index = parse_search_index_js(&text, &crate_info.name)?;
                 index.crate_version = crate_info.version;
                 Ok(index)
             }
@@ -917,13 +1073,14 @@ impl RustClient {
         use scraper::{Html, Selector};
 
         // Fetch the main crate documentation page
-        let url = format!("https://docs.rs/{}/{}/{}/", crate_name, version, crate_name);
+        let url = format!("{}/", docs_rs_base(crate_name, version));
         debug!(url = %url, "Scraping crate documentation for search index");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch crate documentation")?;
 
@@ -983,13 +1140,14 @@ impl RustClient {
     async fn scrape_crate_all_items(&self, crate_name: &str, version: &str) -> Result<RustSearchIndex> {
         use scraper::{Html, Selector};
 
-        let url = format!("https://docs.rs/{}/{}/{}/all.html", crate_name, version, crate_name);
+        let url = format!("{}/all.html", docs_rs_base(crate_name, version));
         debug!(url = %url, "Scraping crate all.html for search index");
 
+        ensure_online(&url)?;
         let response = self
             .http
             .get(&url)
-            .send()
+            .send_with_retry()
             .await
             .context("Failed to fetch crate all.html")?;
 
@@ -1315,6 +1473,43 @@ fn rust_path_from_href(href: &str, kind: RustItemKind) -> String {
 }
 
 /// Convert docs.rs releases to RustCrate structs
+fn crate_from_data(data: DocsRsCrateData) -> RustCrate {
+    RustCrate {
+        documentation_url: format!("https://docs.rs/{}/latest/", data.name),
+        name: data.name,
+        version: data.version,
+        description: data.description.unwrap_or_default(),
+        repository_url: data.repository,
+        is_std: false,
+    }
+}
+
+/// Read the `crate` object of a crates.io `/api/v1/crates/<name>` response.
+fn parse_crates_io_crate(json: &Value, requested: &str) -> Result<DocsRsCrateData> {
+    let crate_obj = json
+        .get("crate")
+        .context("Missing 'crate' field in crates.io response")?;
+    let text = |key: &str| crate_obj.get(key).and_then(Value::as_str).map(str::to_string);
+    Ok(DocsRsCrateData {
+        name: text("name").unwrap_or_else(|| requested.to_string()),
+        // Prefer the newest stable release; docs.rs builds pre-releases too, but queries
+        // rarely mean them
+        version: text("max_stable_version")
+            .or_else(|| text("newest_version"))
+            .unwrap_or_else(|| "latest".to_string()),
+        description: text("description"),
+        repository: text("repository"),
+        documentation: text("documentation"),
+        keywords: crate_obj
+            .get("keywords")
+            .and_then(Value::as_array)
+            .map(|arr| arr.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default(),
+        releases: Vec::new(), // We don't need releases for search functionality
+        downloads: crate_obj.get("downloads").and_then(Value::as_u64).unwrap_or_default(),
+    })
+}
+
 fn releases_to_crates(releases: &[DocsRsRelease]) -> Vec<RustCrate> {
     releases
         .iter()
@@ -1451,6 +1646,40 @@ mod tests {
         assert_eq!(RustItemKind::from_type_id(255), None);
     }
 
+    #[test]
+    fn test_parse_crates_io_crate() {
+        let json = serde_json::json!({
+            "crate": {
+                "name": "async-trait",
+                "newest_version": "0.2.0-beta.1",
+                "max_stable_version": "0.1.83",
+                "description": "Type erasure for async trait methods",
+                "downloads": 250_000_000u64,
+                "keywords": ["async"]
+            }
+        });
+        let data = parse_crates_io_crate(&json, "async_trait").unwrap();
+        assert_eq!(data.name, "async-trait");
+        assert_eq!(data.version, "0.1.83");
+        assert_eq!(data.downloads, 250_000_000);
+        assert_eq!(
+            docs_rs_base(&data.name, &data.version),
+            "https://docs.rs/async-trait/0.1.83/async_trait"
+        );
+    }
+
+    #[test]
+    fn test_pinned_crate_specs() {
+        assert_eq!(split_crate_spec("tokio@1.35"), ("tokio", Some("1.35")));
+        assert_eq!(split_crate_spec("tokio"), ("tokio", None));
+        assert_eq!(normalize_item_path("tokio@1.35/sync/mpsc"), "tokio@1.35::sync::mpsc");
+        assert_eq!(normalize_item_path("std::collections::HashMap"), "std::collections::HashMap");
+        assert_eq!(
+            crate::rust::rustdoc_item_url("tokio@1.35", "1.35", "tokio@1.35::sync::mpsc", RustItemKind::Module),
+            "https://docs.rs/tokio/1.35/tokio/sync/mpsc/index.html"
+        );
+    }
+
     #[test]
     fn test_split_rust_path() {
         assert_eq!(split_rust_path("spawn"), (String::new(), "spawn".to_string()));
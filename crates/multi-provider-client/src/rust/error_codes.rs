@@ -0,0 +1,161 @@
+//! rustc error code explanations (`E0502`, `E0599`, ...) from the compiler error index
+//! at doc.rust-lang.org/error_codes.
+//!
+//! Each code has an mdBook page: a one-line summary, an erroneous example marked
+//! `compile_fail`, and the explanation with corrected code.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+
+use super::mdbook::{code_block, markdown_block};
+use serde::{Deserialize, Serialize};
+
+const ERROR_INDEX_URL: &str = "https://doc.rust-lang.org/error_codes";
+
+static ERROR_CODE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bE(0\d{3})\b").unwrap());
+
+/// Explanation of one compiler error code
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RustErrorCode {
+    /// Canonical code, e.g. "E0502"
+    pub code: String,
+    /// The first sentence of the explanation
+    pub summary: String,
+    /// The full explanation as Markdown, examples included
+    pub explanation: String,
+    pub examples: Vec<RustErrorExample>,
+    pub url: String,
+}
+
+/// A code block from an error explanation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RustErrorExample {
+    pub code: String,
+    /// Whether the block reproduces the error (`compile_fail`) rather than fixing it
+    pub erroneous: bool,
+}
+
+impl RustErrorCode {
+    /// The first example that reproduces the error
+    pub fn erroneous_example(&self) -> Option<&RustErrorExample> {
+        self.examples.iter().find(|example| example.erroneous)
+    }
+
+    /// The first example that compiles, usually the fix
+    pub fn fixed_example(&self) -> Option<&RustErrorExample> {
+        self.examples.iter().find(|example| !example.erroneous)
+    }
+}
+
+/// The first error code in `text`, e.g. `E0502` in "error[E0502]: cannot borrow ..."
+pub fn find_error_code(text: &str) -> Option<String> {
+    ERROR_CODE_RE
+        .captures(text)
+        .and_then(|captures| captures.get(1))
+        .map(|digits| format!("E{}", digits.as_str()))
+}
+
+/// Page of `code` in the error index
+pub fn error_code_url(code: &str) -> String {
+    format!("{ERROR_INDEX_URL}/{code}.html")
+}
+
+/// Parse an error index page; `None` when it has no explanation
+pub fn parse_error_code_html(code: &str, html: &str) -> Option<RustErrorCode> {
+    let document = Html::parse_document(html);
+    let main_selector = Selector::parse("main").ok()?;
+    let main = document.select(&main_selector).next()?;
+
+    let mut blocks = Vec::new();
+    let mut examples = Vec::new();
+    for element in main.children().filter_map(ElementRef::wrap) {
+        match element.value().name() {
+            "h1" => {}
+            "pre" => {
+                let Some(block) = code_block(&element) else {
+                    continue;
+                };
+                let erroneous = block.info.contains("compile_fail");
+                let label = if erroneous { "rust,compile_fail" } else { "rust" };
+                blocks.push(format!("```{label}\n{}\n```", block.code));
+                examples.push(RustErrorExample {
+                    code: block.code,
+                    erroneous,
+                });
+            }
+            _ => blocks.extend(markdown_block(&element)),
+        }
+    }
+
+    let summary = blocks
+        .iter()
+        .find(|block| !block.starts_with("```") && !block.starts_with('#'))
+        .cloned()?;
+    Some(RustErrorCode {
+        code: code.to_string(),
+        summary,
+        explanation: blocks.join("\n\n"),
+        examples,
+        url: error_code_url(code),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E0502: &str = r##"<html><body><div id="content"><main>
+        <h1 id="e0502"><a class="header" href="#e0502">E0502</a></h1>
+        <p>A variable already borrowed with a certain mutability (either mutable or
+        immutable) was borrowed again with a different mutability.</p>
+        <p>Erroneous code example:</p>
+        <pre><code class="language-compile_fail,E0502 hljs">fn bar(x: &amp;mut i32) {}
+fn foo(a: &amp;mut i32) {
+    let y = &amp;a; // a is borrowed as immutable.
+    bar(a); // error, same as above
+    println!("{}", y);
+}</code></pre>
+        <p>To fix this error, ensure that you don't have any other references to the
+        variable before trying to access it with a different mutability:</p>
+        <pre><code class="language-rust hljs"><span class="boring">#![allow(unused)]
+</span>fn bar(x: &amp;mut i32) {}
+fn foo(a: &amp;mut i32) {
+    bar(a);
+    let y = &amp;a; // ok!
+    println!("{}", y);
+}</code></pre>
+        <p>For more information on Rust's ownership system, take a look at the
+        <a href="https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html">References &amp; Borrowing</a>
+        section of the Book.</p>
+    </main></div></body></html>"##;
+
+    #[test]
+    fn finds_error_codes_in_compiler_output() {
+        assert_eq!(
+            find_error_code("error[E0502]: cannot borrow `v` as mutable").as_deref(),
+            Some("E0502")
+        );
+        assert_eq!(find_error_code("what does e0599 mean").as_deref(), Some("E0599"));
+        assert_eq!(find_error_code("E05020"), None);
+        assert_eq!(find_error_code("borrow checker"), None);
+    }
+
+    #[test]
+    fn parses_error_index_pages() {
+        let error = parse_error_code_html("E0502", E0502).expect("explanation");
+        assert!(error.summary.starts_with("A variable already borrowed with a certain mutability"));
+        assert_eq!(error.examples.len(), 2);
+        let erroneous = error.erroneous_example().unwrap();
+        assert!(erroneous.code.contains("bar(a); // error"));
+        let fixed = error.fixed_example().unwrap();
+        assert!(fixed.code.starts_with("fn bar(x: &mut i32) {}"));
+        assert!(error.explanation.contains("```rust,compile_fail\nfn bar"));
+        assert!(error.explanation.contains("References & Borrowing section of the Book."));
+        assert_eq!(error.url, "https://doc.rust-lang.org/error_codes/E0502.html");
+
+        assert!(parse_error_code_html("E9999", "<html><main><h1>E9999</h1></main></html>").is_none());
+    }
+}
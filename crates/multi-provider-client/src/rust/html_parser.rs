@@ -6,7 +6,16 @@ use regex::Regex;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 
-use super::types::{RustAssociatedType, RustExample, RustItemKind, RustMethodInfo};
+use super::types::{RustAssociatedType, RustExample, RustImplBlock, RustItemKind, RustMethodInfo};
+
+/// Page sections listing trait impls; their methods belong to the trait, not the item.
+const TRAIT_IMPL_LISTS: &[&str] = &[
+    "trait-implementations-list",
+    "synthetic-implementations-list",
+    "blanket-implementations-list",
+    "implementors-list",
+    "synthetic-implementors-list",
+];
 
 /// Parsed documentation from an HTML page
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -16,8 +25,23 @@ pub struct ParsedDocumentation {
     pub examples: Vec<RustExample>,
     pub methods: Vec<RustMethodInfo>,
     pub impl_traits: Vec<String>,
+    /// Inherent impls and trait implementations of a type
+    #[serde(default)]
+    pub impl_blocks: Vec<RustImplBlock>,
+    /// Types implementing a trait, including implementations on foreign types
+    #[serde(default)]
+    pub implementors: Vec<RustImplBlock>,
     pub associated_types: Vec<RustAssociatedType>,
     pub source_url: Option<String>,
+    /// Cargo features the item is gated behind
+    #[serde(default)]
+    pub required_features: Vec<String>,
+    /// Platforms the item is cfg-gated to, e.g. `["Unix"]` or `["Linux", "Android"]`
+    #[serde(default)]
+    pub platforms: Vec<String>,
+    /// The portability note as rendered, e.g. "Available on crate feature `fs` only."
+    #[serde(default)]
+    pub availability: Option<String>,
 }
 
 /// Parse a rustdoc HTML page and extract structured documentation
@@ -41,16 +65,36 @@ pub fn parse_rustdoc_html(html: &str, item_kind: RustItemKind) -> ParsedDocument
     ) {
         result.methods = extract_methods(&document);
         result.impl_traits = extract_impl_traits(&document);
+        if item_kind != RustItemKind::Trait {
+            result.impl_blocks = extract_impl_blocks(
+                &document,
+                "#implementations-list .impl, #trait-implementations-list .impl",
+                60,
+            );
+        }
     }
 
-    // Extract associated types for traits
+    // Extract associated types and implementors for traits
     if item_kind == RustItemKind::Trait {
         result.associated_types = extract_associated_types(&document);
+        result.implementors = extract_impl_blocks(
+            &document,
+            "#implementors-list .impl, #synthetic-implementors-list .impl, \
+             #foreign-impls ~ details .impl, #foreign-impls ~ .impl",
+            100,
+        );
     }
 
     // Extract source URL
     result.source_url = extract_source_url(&document);
 
+    // Extract feature and platform gates ("Available on crate feature X only.", "Available on Unix only.")
+    if let Some((availability, features)) = extract_availability(&document) {
+        result.platforms = availability_platforms(&availability);
+        result.availability = Some(availability);
+        result.required_features = features;
+    }
+
     result
 }
 
@@ -214,9 +258,12 @@ fn extract_methods(document: &Html) -> Vec<RustMethodInfo> {
     .ok();
 
     if let Some(selector) = method_selector {
-        for element in document.select(&selector).take(50) {
+        let candidates = document.select(&selector).filter(|element| !in_trait_impl(element));
+        for element in candidates.take(50) {
             if let Some(method) = parse_method_element(&element) {
-                methods.push(method);
+                if !methods.iter().any(|m: &RustMethodInfo| m.signature == method.signature) {
+                    methods.push(method);
+                }
             }
         }
     }
@@ -260,7 +307,8 @@ fn extract_methods(document: &Html) -> Vec<RustMethodInfo> {
 /// Parse a method element into RustMethodInfo
 fn parse_method_element(element: &scraper::ElementRef) -> Option<RustMethodInfo> {
     // Try to find method signature
-    let sig_selector = Selector::parse(".fn-signature, h4 code, .method-signature, code").ok()?;
+    let sig_selector =
+        Selector::parse(".code-header, .fn-signature, h4 code, .method-signature, code").ok()?;
 
     let signature = element
         .select(&sig_selector)
@@ -334,6 +382,132 @@ fn extract_impl_traits(document: &Html) -> Vec<String> {
     traits
 }
 
+/// Whether `element` sits inside a trait impl rather than the item's own methods.
+///
+/// Inherent impls live in `#implementations-list`; every other impl block on the page
+/// (trait implementations, implementors, implementations on foreign types) is a trait impl.
+fn in_trait_impl(element: &scraper::ElementRef) -> bool {
+    element.ancestors().filter_map(scraper::ElementRef::wrap).any(|ancestor| {
+        let value = ancestor.value();
+        if value.id().is_some_and(|id| TRAIT_IMPL_LISTS.contains(&id)) {
+            return true;
+        }
+        value.classes().any(|class| class == "implementors-toggle")
+            && ancestor
+                .parent()
+                .and_then(scraper::ElementRef::wrap)
+                .and_then(|parent| parent.value().id())
+                != Some("implementations-list")
+    })
+}
+
+/// Extract the impl blocks matched by `selector` (`.impl` headers), with their method names
+fn extract_impl_blocks(document: &Html, selector: &str, limit: usize) -> Vec<RustImplBlock> {
+    let (Ok(selector), Ok(header_selector), Ok(method_selector)) = (
+        Selector::parse(selector),
+        Selector::parse(".code-header"),
+        Selector::parse(".impl-items .method .code-header, .impl-items .method h4"),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut blocks: Vec<RustImplBlock> = Vec::new();
+    for element in document.select(&selector) {
+        let header_element = element.select(&header_selector).next().unwrap_or(element);
+        let header = clean_text(&header_element.text().collect::<String>());
+        let Some((trait_name, self_type)) = parse_impl_header(&header) else {
+            continue;
+        };
+        if blocks.iter().any(|block| block.header == header) {
+            continue;
+        }
+
+        // Items follow the header's <summary> inside the surrounding <details>
+        let mut methods = Vec::new();
+        let details = element
+            .ancestors()
+            .filter_map(scraper::ElementRef::wrap)
+            .take(2)
+            .find(|ancestor| ancestor.value().name() == "details");
+        if let Some(details) = details {
+            for method in details.select(&method_selector) {
+                let signature = clean_text(&method.text().collect::<String>());
+                if let Some(name) = extract_method_name(&signature) {
+                    if !methods.contains(&name) {
+                        methods.push(name);
+                    }
+                }
+            }
+        }
+
+        blocks.push(RustImplBlock {
+            header,
+            trait_name,
+            self_type,
+            methods,
+        });
+        if blocks.len() >= limit {
+            break;
+        }
+    }
+    blocks
+}
+
+/// Split an impl header into the implemented trait (if any) and the implementing type.
+///
+/// `impl<'de> Deserialize<'de> for bool` gives `(Some("Deserialize<'de>"), "bool")`,
+/// `impl<K, V> HashMap<K, V> where K: Eq` gives `(None, "HashMap<K, V>")`.
+fn parse_impl_header(header: &str) -> Option<(Option<String>, String)> {
+    let rest = header.trim().strip_prefix("unsafe ").unwrap_or(header.trim());
+    let mut rest = rest.strip_prefix("impl")?;
+    if rest.starts_with('<') {
+        let end = top_level_positions(rest, ">").into_iter().next()?;
+        rest = &rest[end + 1..];
+    } else if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = match top_level_positions(rest, " where ").first() {
+        Some(&index) => &rest[..index],
+        None => rest,
+    };
+    let rest = rest.trim().trim_end_matches(['{', ';']).trim();
+    match top_level_positions(rest, " for ").last() {
+        Some(&index) => {
+            let trait_name = rest[..index].trim();
+            let self_type = rest[index + " for ".len()..].trim();
+            (!self_type.is_empty()).then(|| (Some(trait_name.to_string()), self_type.to_string()))
+        }
+        None => (!rest.is_empty()).then(|| (None, rest.to_string())),
+    }
+}
+
+/// Byte offsets of `needle` outside angle brackets and parentheses.
+///
+/// A `>` needle matches the bracket closing the generics that open at the start of `text`.
+fn top_level_positions(text: &str, needle: &str) -> Vec<usize> {
+    let mut depth = 0i32;
+    let mut positions = Vec::new();
+    let bytes = text.as_bytes();
+    for (index, &byte) in bytes.iter().enumerate() {
+        match byte {
+            b'<' | b'(' | b'[' => depth += 1,
+            // `->` in `Fn(A) -> B` is not a closing bracket
+            b'>' if index > 0 && bytes[index - 1] == b'-' => {}
+            b'>' | b')' | b']' => {
+                depth -= 1;
+                if needle == ">" && depth == 0 {
+                    positions.push(index);
+                }
+            }
+            _ => {}
+        }
+        if depth == 0 && needle != ">" && bytes[index..].starts_with(needle.as_bytes()) {
+            positions.push(index);
+        }
+    }
+    positions
+}
+
 /// Extract associated types for traits
 fn extract_associated_types(document: &Html) -> Vec<RustAssociatedType> {
     let mut types = Vec::new();
@@ -365,22 +539,124 @@ fn extract_associated_types(document: &Html) -> Vec<RustAssociatedType> {
     types
 }
 
-/// Extract source code URL
+/// Extract the source code link as written; see [`resolve_source_url`]
 fn extract_source_url(document: &Html) -> Option<String> {
+    // The heading's link is the item's own; members further down link to their own lines
+    let heading_selector = Selector::parse(".main-heading a.src, h1 a.src").ok()?;
     let source_selector = Selector::parse("a.src, .src-content a, a[href*='src/']").ok()?;
 
-    document.select(&source_selector).next().and_then(|e| {
-        e.value().attr("href").map(|href| {
-            // Make absolute if relative
-            if href.starts_with("http") {
-                href.to_string()
-            } else if href.starts_with('/') {
-                format!("https://doc.rust-lang.org{}", href)
-            } else {
-                href.to_string()
-            }
+    document
+        .select(&heading_selector)
+        .next()
+        .or_else(|| document.select(&source_selector).next())
+        .and_then(|e| e.value().attr("href").map(str::to_string))
+}
+
+/// Resolve a source link (usually `../src/...`) against the URL of the page it appeared on
+pub fn resolve_source_url(page_url: &str, href: &str) -> String {
+    reqwest::Url::parse(page_url)
+        .and_then(|base| base.join(href))
+        .map_or_else(|_| href.to_string(), |url| url.to_string())
+}
+
+/// The inclusive line range a source link points at (`#213-220`, `#L213`)
+pub fn parse_source_range(url: &str) -> Option<(usize, usize)> {
+    let fragment = url.split_once('#')?.1.trim_start_matches('L');
+    let (start, end) = match fragment.split_once('-') {
+        Some((start, end)) => (start, end.trim_start_matches('L')),
+        None => (fragment, fragment),
+    };
+    let (start, end) = (start.parse().ok()?, end.parse().ok()?);
+    (start > 0 && end >= start).then_some((start, end))
+}
+
+/// The lines of a rustdoc source page (`src/.../*.rs.html`), without line numbers
+pub fn parse_rustdoc_source(html: &str) -> Vec<String> {
+    let document = Html::parse_document(html);
+    let Ok(selector) = Selector::parse("pre.rust code, pre.rust") else {
+        return Vec::new();
+    };
+    let Some(code) = document.select(&selector).next() else {
+        return Vec::new();
+    };
+
+    // Newer rustdoc puts the line numbers in the listing as `<a id="12">12</a>` anchors
+    let mut source = String::new();
+    for node in code.descendants() {
+        let Some(text) = node.value().as_text() else {
+            continue;
+        };
+        let line_number = node
+            .parent()
+            .and_then(scraper::ElementRef::wrap)
+            .is_some_and(|parent| {
+                let value = parent.value();
+                value.name() == "a"
+                    && (value.attr("data-nosnippet").is_some()
+                        || value.id().is_some_and(|id| id.parse::<usize>().is_ok()))
+            });
+        if !line_number {
+            source.push_str(text);
+        }
+    }
+    source.lines().map(str::to_string).collect()
+}
+
+/// Extract the item's own portability note and the crate features it names.
+///
+/// Members listed further down the page carry their own notes; only the first one outside the
+/// implementation blocks describes the item itself.
+fn extract_availability(document: &Html) -> Option<(String, Vec<String>)> {
+    let selector = Selector::parse(".item-info .stab.portability, .stability .stab.portability").ok()?;
+    let code_selector = Selector::parse("code").ok()?;
+    let element = document.select(&selector).find(|element| {
+        !element.ancestors().filter_map(scraper::ElementRef::wrap).any(|ancestor| {
+            let value = ancestor.value();
+            value.classes().any(|class| matches!(class, "impl-items" | "methods" | "variants"))
+                || value.id().is_some_and(|id| matches!(id, "implementations-list" | "trait-implementations-list"))
         })
-    })
+    })?;
+
+    let note = element
+        .text()
+        .collect::<String>();
+    let mut note = clean_text(&note);
+    let mut features = Vec::new();
+    if note.contains("crate feature") {
+        for code in element.select(&code_selector) {
+            let name = clean_text(&code.text().collect::<String>());
+            // cfg predicates such as `target_os="linux"` are not features
+            if !name.is_empty() && !name.contains('=') && !features.contains(&name) {
+                note = note.replacen(&name, &format!("`{name}`"), 1);
+                features.push(name);
+            }
+        }
+    }
+    Some((note, features))
+}
+
+/// Platforms named in a portability note: "Available on Unix only." is `["Unix"]`,
+/// "Available on crate feature `net` and (Linux or Android) only." is `["Linux", "Android"]`.
+/// Feature clauses and raw cfg predicates such as `target_env="gnu"` are dropped.
+pub fn availability_platforms(note: &str) -> Vec<String> {
+    let condition = note
+        .trim()
+        .trim_start_matches("Available on ")
+        .trim_end_matches('.')
+        .trim_end_matches(" only");
+    let mut platforms: Vec<String> = Vec::new();
+    for clause in condition.replace(['(', ')'], "").split(" and ") {
+        if clause.starts_with("crate feature") || clause.contains('`') || clause.contains('=') {
+            continue;
+        }
+        for platform in clause.split(" or ").flat_map(|part| part.split(", ")) {
+            let platform = platform.trim();
+            if !platform.is_empty() && !platforms.iter().any(|known| known == platform) {
+                platforms.push(platform.to_string());
+            }
+        }
+    }
+    platforms
 }
 
 /// Clean and normalize text
@@ -461,6 +737,143 @@ mod tests {
         assert_eq!(clean_text("foo\n\nbar"), "foo bar");
     }
 
+    #[test]
+    fn test_extract_availability() {
+        let html = r#"<html><body><section id="main-content">
+            <pre class="rust item-decl"><code>pub fn channel&lt;T&gt;(buffer: usize)</code></pre>
+            <span class="item-info"><div class="stab portability">Available on <strong>crate features <code>sync</code> and <code>rt</code></strong> only.</div></span>
+            <details class="toggle top-doc"><div class="docblock"><p>Creates a bounded channel.</p></div></details>
+            <div id="implementations-list"><div class="impl-items">
+                <span class="item-info"><div class="stab portability">Available on <strong>crate feature <code>time</code></strong> only.</div></span>
+            </div></div>
+        </section></body></html>"#;
+        let parsed = parse_rustdoc_html(html, RustItemKind::Function);
+        assert_eq!(parsed.required_features, vec!["sync", "rt"]);
+        assert_eq!(
+            parsed.availability.as_deref(),
+            Some("Available on crate features `sync` and `rt` only.")
+        );
+
+        let plain = parse_rustdoc_html("<div class=\"docblock\"><p>Hi</p></div>", RustItemKind::Function);
+        assert!(plain.required_features.is_empty());
+        assert!(plain.availability.is_none());
+    }
+
+    #[test]
+    fn test_availability_platforms() {
+        let html = r#"<html><body><section id="main-content">
+            <pre class="rust item-decl"><code>pub trait PermissionsExt</code></pre>
+            <span class="item-info"><div class="stab portability">Available on <strong>Unix</strong> only.</div></span>
+        </section></body></html>"#;
+        let parsed = parse_rustdoc_html(html, RustItemKind::Trait);
+        assert_eq!(parsed.platforms, vec!["Unix"]);
+        assert!(parsed.required_features.is_empty());
+
+        assert_eq!(
+            availability_platforms("Available on crate feature `net` and (Linux or Android) only."),
+            vec!["Linux", "Android"]
+        );
+        assert_eq!(
+            availability_platforms("Available on Linux and target_env=\"gnu\" only."),
+            vec!["Linux"]
+        );
+        assert!(availability_platforms("Available on crate features `sync` and `rt` only.").is_empty());
+    }
+
+    #[test]
+    fn test_extract_trait_implementors() {
+        let html = r#"<html><body><section id="main-content">
+            <h2 id="required-methods">Required Methods</h2><div class="methods">
+                <section id="tymethod.deserialize" class="method"><h4 class="code-header">fn <a class="fn">deserialize</a>&lt;D&gt;(deserializer: D) -&gt; Result&lt;Self, D::Error&gt;</h4></section>
+            </div>
+            <h2 id="foreign-impls">Implementations on Foreign Types</h2>
+            <details class="toggle implementors-toggle"><summary><section id="impl-Deserialize-for-bool" class="impl"><h3 class="code-header">impl&lt;'de&gt; Deserialize&lt;'de&gt; for bool</h3></section></summary>
+                <div class="impl-items"><section id="method.deserialize" class="method trait-impl"><h4 class="code-header">fn <a>deserialize</a>&lt;D&gt;(deserializer: D) -&gt; Result&lt;bool, D::Error&gt;</h4></section></div>
+            </details>
+            <h2 id="implementors">Implementors</h2><div id="implementors-list">
+                <section id="impl-Deserialize-for-IgnoredAny" class="impl"><h3 class="code-header">impl&lt;'de&gt; Deserialize&lt;'de&gt; for IgnoredAny</h3></section>
+                <section id="impl-Deserialize-for-Map" class="impl"><h3 class="code-header">impl&lt;'de, K, V&gt; Deserialize&lt;'de&gt; for BTreeMap&lt;K, V&gt;<span class="where"> where K: Deserialize&lt;'de&gt; + Ord</span></h3></section>
+            </div>
+        </section></body></html>"#;
+        let parsed = parse_rustdoc_html(html, RustItemKind::Trait);
+        let types: Vec<&str> = parsed.implementors.iter().map(|b| b.self_type.as_str()).collect();
+        assert_eq!(types, vec!["bool", "IgnoredAny", "BTreeMap<K, V>"]);
+        assert_eq!(parsed.implementors[0].trait_name.as_deref(), Some("Deserialize<'de>"));
+        assert_eq!(parsed.implementors[0].methods, vec!["deserialize"]);
+        // The trait's own methods, not the implementations of them
+        assert_eq!(parsed.methods.len(), 1);
+        assert!(parsed.methods[0].signature.contains("Result<Self, D::Error>"));
+    }
+
+    #[test]
+    fn test_extract_impl_blocks() {
+        let html = r#"<html><body><section id="main-content">
+            <h2 id="implementations">Implementations</h2><div id="implementations-list">
+                <details class="toggle implementors-toggle" open><summary><section id="impl-HashMap" class="impl"><h3 class="code-header">impl&lt;K, V&gt; HashMap&lt;K, V, RandomState&gt;</h3></section></summary>
+                    <div class="impl-items">
+                        <details class="toggle method-toggle" open><summary><section id="method.new" class="method"><h4 class="code-header">pub fn <a>new</a>() -&gt; HashMap&lt;K, V, RandomState&gt;</h4></section></summary></details>
+                        <section id="method.with_capacity" class="method"><h4 class="code-header">pub fn <a>with_capacity</a>(capacity: usize) -&gt; Self</h4></section>
+                    </div>
+                </details>
+            </div>
+            <h2 id="trait-implementations">Trait Implementations</h2><div id="trait-implementations-list">
+                <details class="toggle implementors-toggle"><summary><section id="impl-Clone" class="impl"><h3 class="code-header">impl&lt;K: Clone, V: Clone, S: Clone&gt; Clone for HashMap&lt;K, V, S&gt;</h3></section></summary>
+                    <div class="impl-items"><section id="method.clone" class="method trait-impl"><h4 class="code-header">fn <a>clone</a>(&amp;self) -&gt; Self</h4></section></div>
+                </details>
+            </div>
+        </section></body></html>"#;
+        let parsed = parse_rustdoc_html(html, RustItemKind::Struct);
+        assert_eq!(parsed.impl_blocks.len(), 2);
+        assert!(parsed.impl_blocks[0].trait_name.is_none());
+        assert_eq!(parsed.impl_blocks[0].self_type, "HashMap<K, V, RandomState>");
+        assert_eq!(parsed.impl_blocks[0].methods, vec!["new", "with_capacity"]);
+        assert_eq!(parsed.impl_blocks[1].trait_name.as_deref(), Some("Clone"));
+        assert_eq!(parsed.impl_blocks[1].methods, vec!["clone"]);
+        let names: Vec<&str> = parsed.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["new", "with_capacity"]);
+    }
+
+    #[test]
+    fn test_parse_impl_header() {
+        assert_eq!(
+            parse_impl_header("impl<F> Handler for F where F: Fn(Request) -> Response"),
+            Some((Some("Handler".to_string()), "F".to_string()))
+        );
+        assert_eq!(
+            parse_impl_header("unsafe impl<T: Send> Send for Arc<T>"),
+            Some((Some("Send".to_string()), "Arc<T>".to_string()))
+        );
+        assert_eq!(parse_impl_header("impl String"), Some((None, "String".to_string())));
+        assert_eq!(parse_impl_header("implementation"), None);
+    }
+
+    #[test]
+    fn test_parse_rustdoc_source() {
+        // Line numbers inline (current rustdoc) and in a separate gutter (older rustdoc)
+        let inline = r##"<main><div class="example-wrap"><pre class="rust"><code><a href="#1" id="1" data-nosnippet>1</a><span class="kw">pub fn </span>push(<span class="kw-2">&amp;mut </span><span class="self">self</span>) {
+<a href="#2" id="2" data-nosnippet>2</a>    <span class="self">self</span>.len += <span class="number">1</span>;
+<a href="#3" id="3" data-nosnippet>3</a>}</code></pre></div></main>"##;
+        let gutter = r##"<main><div class="example-wrap"><pre class="src-line-numbers"><a href="#1" id="1">1</a>
+<a href="#2" id="2">2</a></pre><pre class="rust"><code><span class="kw">fn </span>main() {
+}</code></pre></div></main>"##;
+        assert_eq!(
+            parse_rustdoc_source(inline),
+            vec!["pub fn push(&mut self) {", "    self.len += 1;", "}"]
+        );
+        assert_eq!(parse_rustdoc_source(gutter), vec!["fn main() {", "}"]);
+
+        assert_eq!(parse_source_range("https://docs.rs/x/src/lib.rs.html#12-30"), Some((12, 30)));
+        assert_eq!(parse_source_range("../src/lib.rs.html#L7"), Some((7, 7)));
+        assert_eq!(parse_source_range("../src/lib.rs.html"), None);
+        assert_eq!(
+            resolve_source_url(
+                "https://docs.rs/tokio/1.35.1/tokio/sync/mpsc/fn.channel.html",
+                "../../../src/tokio/sync/mpsc/bounded.rs.html#123-130"
+            ),
+            "https://docs.rs/tokio/1.35.1/src/tokio/sync/mpsc/bounded.rs.html#123-130"
+        );
+    }
+
     #[test]
     fn test_extract_method_name() {
         assert_eq!(
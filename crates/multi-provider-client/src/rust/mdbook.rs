@@ -0,0 +1,111 @@
+//! Text extraction for mdBook pages (the Cargo Book, the rustc error index).
+
+use scraper::{ElementRef, Selector};
+
+/// A fenced code block from an mdBook page
+pub(super) struct CodeBlock {
+    /// The fence info string, e.g. `toml` or `compile_fail,E0502`
+    pub info: String,
+    pub code: String,
+}
+
+impl CodeBlock {
+    /// Language for re-fencing the block (`toml`, `console`, ...); Rust when unspecified
+    pub fn language(&self) -> &str {
+        match self.info.split(',').next().unwrap_or_default() {
+            "" | "compile_fail" | "ignore" | "no_run" | "should_panic" | "edition2018"
+            | "edition2021" => "rust",
+            language => language,
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        format!("```{}\n{}\n```", self.language(), self.code)
+    }
+}
+
+/// The code block inside a `<pre>`, without the lines mdBook hides (`# fn main() {}`)
+pub(super) fn code_block(pre: &ElementRef) -> Option<CodeBlock> {
+    let code_selector = Selector::parse("code").ok()?;
+    let code = pre.select(&code_selector).next()?;
+    let info = code
+        .value()
+        .classes()
+        .find_map(|class| class.strip_prefix("language-"))
+        .unwrap_or_default()
+        .to_string();
+
+    // Hidden lines are wrapped in `.boring` spans
+    let mut source = String::new();
+    for node in code.descendants() {
+        let Some(text) = node.value().as_text() else {
+            continue;
+        };
+        let hidden = node
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(|ancestor| ancestor.value().classes().any(|class| class == "boring"));
+        if !hidden {
+            source.push_str(text);
+        }
+    }
+    let code = source.trim_matches('\n').to_string();
+    (!code.trim().is_empty()).then_some(CodeBlock { info, code })
+}
+
+/// A block-level element as Markdown; `None` for headings' anchors and empty elements.
+/// Code blocks are handled by [`code_block`].
+pub(super) fn markdown_block(element: &ElementRef) -> Option<String> {
+    let text = match element.value().name() {
+        "h2" | "h3" | "h4" | "h5" | "h6" => format!("### {}", element_text(element)),
+        "ul" | "ol" => element
+            .children()
+            .filter_map(ElementRef::wrap)
+            .map(|item| format!("- {}", element_text(&item)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "blockquote" => format!("> {}", element_text(element)),
+        "table" => {
+            let row_selector = Selector::parse("tr").ok()?;
+            let cell_selector = Selector::parse("th, td").ok()?;
+            element
+                .select(&row_selector)
+                .map(|row| {
+                    let cells: Vec<String> =
+                        row.select(&cell_selector).map(|cell| element_text(&cell)).collect();
+                    format!("| {} |", cells.join(" | "))
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        _ => element_text(element),
+    };
+    (!text.trim().is_empty() && text != "### ").then_some(text)
+}
+
+/// Heading level of `element` (`h2` is 2), if it is a heading
+pub(super) fn heading_level(element: &ElementRef) -> Option<u8> {
+    let name = element.value().name();
+    let level = name.strip_prefix('h')?.parse().ok()?;
+    (1..=6).contains(&level).then_some(level)
+}
+
+/// Inline text with `code` spans kept as Markdown
+pub(super) fn element_text(element: &ElementRef) -> String {
+    let mut text = String::new();
+    for node in element.children() {
+        if let Some(child) = ElementRef::wrap(node) {
+            let inner = element_text(&child);
+            if child.value().name() == "code" {
+                text.push('`');
+                text.push_str(&inner);
+                text.push('`');
+            } else {
+                text.push_str(&inner);
+            }
+        } else if let Some(value) = node.value().as_text() {
+            text.push_str(value);
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
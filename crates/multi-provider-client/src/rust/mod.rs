@@ -1,7 +1,12 @@
+pub mod cargo_book;
 pub mod client;
+pub mod error_codes;
 pub mod html_parser;
+mod mdbook;
 pub mod types;
 
+pub use cargo_book::{is_cargo_query, CargoBookSection, CargoTopic};
 pub use client::RustClient;
+pub use error_codes::{find_error_code, RustErrorCode, RustErrorExample};
 pub use html_parser::{extract_title_from_html, ParsedDocumentation};
 pub use types::*;
@@ -91,8 +91,40 @@ impl RustItemKind {
     }
 }
 
+/// Split a crate spec such as `tokio@1.35` into the crate name and the pinned version.
+#[must_use]
+pub fn split_crate_spec(spec: &str) -> (&str, Option<&str>) {
+    match spec.split_once('@') {
+        Some((name, version)) if !version.is_empty() => (name, Some(version)),
+        Some((name, _)) => (name, None),
+        None => (spec, None),
+    }
+}
+
+/// `tokio@1.35/sync/mpsc` as `tokio@1.35::sync::mpsc`; `::` paths are returned unchanged.
+#[must_use]
+pub fn normalize_item_path(path: &str) -> String {
+    let path = path.trim().trim_matches('/');
+    if path.contains("::") {
+        path.to_string()
+    } else {
+        path.replace('/', "::")
+    }
+}
+
+/// Root of a crate's rustdoc on docs.rs; the library target uses `_` where the name has `-`.
+#[must_use]
+pub fn docs_rs_base(crate_name: &str, crate_version: &str) -> String {
+    let crate_name = split_crate_spec(crate_name).0;
+    format!(
+        "https://docs.rs/{crate_name}/{crate_version}/{}",
+        crate_name.replace('-', "_")
+    )
+}
+
 #[must_use]
 pub fn rustdoc_item_url(crate_name: &str, crate_version: &str, path: &str, kind: RustItemKind) -> String {
+    let crate_name = split_crate_spec(crate_name).0;
     let path_parts: Vec<&str> = path.split("::").collect();
     let segments = if path_parts.len() > 1 {
         &path_parts[1..]
@@ -104,7 +136,7 @@ pub fn rustdoc_item_url(crate_name: &str, crate_version: &str, path: &str, kind:
     let base = if is_std {
         format!("https://doc.rust-lang.org/{crate_name}")
     } else {
-        format!("https://docs.rs/{crate_name}/{crate_version}/{crate_name}")
+        docs_rs_base(crate_name, crate_version)
     };
 
     if segments.is_empty() {
@@ -187,10 +219,22 @@ pub struct RustItem {
     pub methods: Vec<RustMethodInfo>,
     /// Trait implementations (for structs, enums)
     pub impl_traits: Vec<String>,
+    /// Inherent and trait impl blocks (for structs, enums)
+    #[serde(default)]
+    pub impl_blocks: Vec<RustImplBlock>,
+    /// Types implementing the trait (for traits)
+    #[serde(default)]
+    pub implementors: Vec<RustImplBlock>,
     /// Associated types (for traits)
     pub associated_types: Vec<RustAssociatedType>,
     /// Link to source code
     pub source_url: Option<String>,
+    /// Cargo features the item is gated behind (`Available on crate feature X only`)
+    #[serde(default)]
+    pub required_features: Vec<String>,
+    /// Platforms the item is cfg-gated to (`Available on Unix only`); empty when portable
+    #[serde(default)]
+    pub platforms: Vec<String>,
     /// Whether rich documentation has been fetched
     pub is_detailed: bool,
 }
@@ -213,6 +257,78 @@ pub struct RustMethodInfo {
     pub is_async: bool,
 }
 
+/// An `impl` block listed on a rustdoc page
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RustImplBlock {
+    /// The impl header as rendered, e.g. `impl<K, V> Clone for HashMap<K, V>`
+    pub header: String,
+    /// The implemented trait; `None` for inherent impls
+    pub trait_name: Option<String>,
+    /// The implementing type
+    pub self_type: String,
+    /// Names of the methods defined in the block
+    pub methods: Vec<String>,
+}
+
+/// Source code of an item, cut from its crate's `src/` listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RustSource {
+    /// Full item path (e.g., "std::vec::Vec::push")
+    pub path: String,
+    pub crate_name: String,
+    pub crate_version: String,
+    /// Source file as listed under `src/` (e.g., "alloc/vec/mod.rs")
+    pub file: String,
+    /// Link to the highlighted lines on docs.rs or doc.rust-lang.org
+    pub url: String,
+    /// First line of `code` (1-based)
+    pub start_line: usize,
+    /// Last line of `code`
+    pub end_line: usize,
+    pub code: String,
+    /// Whether the item is longer than the lines returned
+    pub truncated: bool,
+}
+
+impl RustSource {
+    /// Cut lines `start..=end` (1-based) for `item` out of a source file, keeping at most
+    /// `max_lines`.
+    pub fn from_lines(
+        item: &RustItem,
+        url: &str,
+        lines: &[String],
+        start: usize,
+        end: usize,
+        max_lines: usize,
+    ) -> Self {
+        let end = end.min(lines.len());
+        let start = start.clamp(1, end.max(1));
+        let last = end.min(start + max_lines.max(1) - 1);
+        let code = lines
+            .get(start - 1..last)
+            .unwrap_or_default()
+            .join("\n");
+        let file = url
+            .split('#')
+            .next()
+            .and_then(|page| page.split_once("/src/"))
+            .map(|(_, file)| file.trim_end_matches(".html").to_string())
+            .unwrap_or_default();
+        Self {
+            path: item.path.clone(),
+            crate_name: item.crate_name.clone(),
+            crate_version: item.crate_version.clone(),
+            file,
+            url: url.to_string(),
+            start_line: start,
+            end_line: last,
+            code,
+            truncated: last < end,
+        }
+    }
+}
+
 /// Associated type in a trait
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RustAssociatedType {
@@ -311,6 +427,9 @@ pub struct DocsRsCrateData {
     pub keywords: Vec<String>,
     #[serde(default)]
     pub releases: Vec<DocsRsCrateRelease>,
+    /// All-time downloads reported by crates.io.
+    #[serde(default)]
+    pub downloads: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -335,13 +454,14 @@ impl RustItem {
         };
 
         let url = rustdoc_item_url(crate_name, crate_version, &full_path, entry.kind);
+        let platforms = std_path_platforms(&full_path);
 
         Self {
             name: entry.name.clone(),
             path: full_path,
             kind: entry.kind,
             summary: entry.desc.clone(),
-            crate_name: crate_name.to_string(),
+            crate_name: split_crate_spec(crate_name).0.to_string(),
             crate_version: crate_version.to_string(),
             url,
             declaration: None,
@@ -349,12 +469,29 @@ impl RustItem {
             examples: Vec::new(),
             methods: Vec::new(),
             impl_traits: Vec::new(),
+            impl_blocks: Vec::new(),
+            implementors: Vec::new(),
             associated_types: Vec::new(),
             source_url: None,
+            required_features: Vec::new(),
+            platforms,
             is_detailed: false,
         }
     }
 
+    /// Whether the item can be used on `platform` ("linux", "windows", "macOS", ...).
+    /// Portable items match everything and Unix-gated items match every Unix target.
+    pub fn available_on(&self, platform: &str) -> bool {
+        let wanted = platform.trim().to_ascii_lowercase();
+        self.platforms.is_empty()
+            || self.platforms.iter().any(|gate| {
+                let gate = gate.to_ascii_lowercase();
+                gate == wanted
+                    || (gate == "unix" && UNIX_PLATFORMS.contains(&wanted.as_str()))
+                    || (wanted == "unix" && UNIX_PLATFORMS.contains(&gate.as_str()))
+            })
+    }
+
     /// Create an empty RustItem for error cases
     pub fn empty(name: &str, crate_name: &str) -> Self {
         Self {
@@ -370,8 +507,12 @@ impl RustItem {
             examples: Vec::new(),
             methods: Vec::new(),
             impl_traits: Vec::new(),
+            impl_blocks: Vec::new(),
+            implementors: Vec::new(),
             associated_types: Vec::new(),
             source_url: None,
+            required_features: Vec::new(),
+            platforms: Vec::new(),
             is_detailed: false,
         }
     }
@@ -402,6 +543,61 @@ pub const STD_CRATES: &[(&str, &str)] = &[
     ("alloc", "The Rust Allocation Library - heap allocation abstractions"),
 ];
 
+/// Targets covered by `cfg(unix)`, lowercased
+const UNIX_PLATFORMS: &[&str] = &[
+    "linux", "android", "macos", "ios", "tvos", "watchos", "visionos", "freebsd", "netbsd",
+    "openbsd", "dragonfly", "solaris", "illumos", "haiku", "fuchsia", "hurd", "aix", "nto",
+    "vxworks", "emscripten", "darwin",
+];
+
+/// Platform-specific modules of the standard library (`std::os::*`, `core::arch::*`)
+const STD_PLATFORM_MODULES: &[(&str, &str, &str)] = &[
+    ("os", "unix", "Unix"),
+    ("os", "windows", "Windows"),
+    ("os", "linux", "Linux"),
+    ("os", "android", "Android"),
+    ("os", "macos", "macOS"),
+    ("os", "ios", "iOS"),
+    ("os", "darwin", "Darwin"),
+    ("os", "freebsd", "FreeBSD"),
+    ("os", "netbsd", "NetBSD"),
+    ("os", "openbsd", "OpenBSD"),
+    ("os", "dragonfly", "DragonFly BSD"),
+    ("os", "solaris", "Solaris"),
+    ("os", "illumos", "illumos"),
+    ("os", "fuchsia", "Fuchsia"),
+    ("os", "wasi", "WASI"),
+    ("os", "hermit", "HermitCore"),
+    ("os", "vxworks", "VxWorks"),
+    ("os", "haiku", "Haiku"),
+    ("os", "emscripten", "Emscripten"),
+    ("os", "fortanix_sgx", "SGX"),
+    ("os", "uefi", "UEFI"),
+    ("arch", "x86", "x86"),
+    ("arch", "x86_64", "x86-64"),
+    ("arch", "aarch64", "AArch64"),
+    ("arch", "arm", "ARM"),
+    ("arch", "riscv32", "RISC-V"),
+    ("arch", "riscv64", "RISC-V"),
+    ("arch", "wasm32", "WebAssembly"),
+    ("arch", "wasm64", "WebAssembly"),
+];
+
+/// Platform a standard library path is gated to by its module, e.g. `["Unix"]` for
+/// `std::os::unix::fs::PermissionsExt`. Search index entries carry no portability
+/// notes, so this is what search results show until the page is fetched.
+pub fn std_path_platforms(path: &str) -> Vec<String> {
+    let segments: Vec<&str> = path.split("::").collect();
+    if segments.len() < 3 || !STD_CRATES.iter().any(|(name, _)| *name == segments[0]) {
+        return Vec::new();
+    }
+    STD_PLATFORM_MODULES
+        .iter()
+        .find(|(parent, module, _)| segments[1] == *parent && segments[2] == *module)
+        .map(|(_, _, platform)| vec![platform.to_string()])
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -429,4 +625,42 @@ mod tests {
         let url = rustdoc_item_url("serde", "1.0.197", "serde::Serialize", RustItemKind::Derive);
         assert_eq!(url, "https://docs.rs/serde/1.0.197/serde/derive.Serialize.html");
     }
+
+    #[test]
+    fn test_std_path_platforms() {
+        assert_eq!(std_path_platforms("std::os::unix::fs::PermissionsExt"), vec!["Unix"]);
+        assert_eq!(std_path_platforms("std::os::windows::ffi::OsStrExt"), vec!["Windows"]);
+        assert_eq!(std_path_platforms("core::arch::x86_64::_mm_add_ps"), vec!["x86-64"]);
+        assert!(std_path_platforms("std::fs::File").is_empty());
+        assert!(std_path_platforms("nix::os::unix::Thing").is_empty());
+
+        let entry = RustSearchIndexEntry {
+            name: "PermissionsExt".to_string(),
+            path: "os::unix::fs".to_string(),
+            kind: RustItemKind::Trait,
+            desc: String::new(),
+            parent: None,
+        };
+        let item = RustItem::from_search_entry(&entry, "std", "latest");
+        assert!(item.available_on("linux"));
+        assert!(item.available_on("macOS"));
+        assert!(!item.available_on("windows"));
+        assert!(RustItem::empty("File", "std").available_on("windows"));
+    }
+
+    #[test]
+    fn test_rust_source_from_lines() {
+        let item = RustItem::empty("channel", "tokio");
+        let lines: Vec<String> = (1..=10).map(|n| format!("line {n}")).collect();
+        let url = "https://docs.rs/tokio/latest/src/tokio/sync/mpsc/bounded.rs.html#3-6";
+
+        let source = RustSource::from_lines(&item, url, &lines, 3, 6, 100);
+        assert_eq!(source.file, "tokio/sync/mpsc/bounded.rs");
+        assert_eq!(source.code, "line 3\nline 4\nline 5\nline 6");
+        assert!(!source.truncated);
+
+        let source = RustSource::from_lines(&item, url, &lines, 3, 60, 2);
+        assert_eq!((source.start_line, source.end_line), (3, 4));
+        assert!(source.truncated);
+    }
 }
@@ -0,0 +1,313 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use reqwest::Client;
+use time::{Duration, OffsetDateTime};
+use tracing::{debug, instrument, warn};
+
+use super::types::{
+    SolanaArticle, SolanaArticleIndex, SolanaCategory, SolanaCategoryItem, SolanaCodeExample,
+    SolanaDocSource, SolanaTechnology, SOLANA_ARTICLES,
+};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+use docs_mcp_client::stale;
+
+use crate::timeouts::timeouts_for;
+use crate::ton::sync::parse_article_page;
+use crate::types::ProviderType;
+
+/// Articles refreshed from their page are fetched again after this long
+const ARTICLE_MAX_AGE: Duration = Duration::days(7);
+
+#[derive(Debug)]
+pub struct SolanaClient {
+    http: Client,
+    disk_cache: DiskCache,
+    cache_dir: PathBuf,
+}
+
+impl Default for SolanaClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SolanaClient {
+    #[must_use]
+    pub fn new() -> Self {
+        let project_dirs = ProjectDirs::from("com", "RecordAndLearn", "multi-docs-mcp")
+            .expect("unable to resolve project directories");
+
+        let cache_dir = project_dirs.cache_dir().join("solana");
+        if let Err(e) = std::fs::create_dir_all(&cache_dir) {
+            warn!(error = %e, "Failed to create Solana cache directory");
+        }
+
+        let http = client_builder(timeouts_for(ProviderType::Solana))
+            .user_agent("MultiDocsMCP/1.0")
+            .gzip(true)
+            .build()
+            .expect("failed to build reqwest client");
+
+        Self {
+            http,
+            disk_cache: DiskCache::new(&cache_dir),
+            cache_dir,
+        }
+    }
+
+    /// Get available technologies (Core Concepts and Cookbook)
+    #[instrument(name = "solana_client.get_technologies", skip(self))]
+    pub async fn get_technologies(&self) -> Result<Vec<SolanaTechnology>> {
+        Ok([SolanaDocSource::Docs, SolanaDocSource::Cookbook]
+            .into_iter()
+            .map(|source| {
+                let count = SOLANA_ARTICLES.iter().filter(|article| article.source == source).count();
+                let (title, description) = source_summary(source);
+                SolanaTechnology {
+                    identifier: source.identifier().to_string(),
+                    title: title.to_string(),
+                    description: format!("{description} - {count} articles"),
+                    url: source.base_url().to_string(),
+                    item_count: count,
+                }
+            })
+            .collect())
+    }
+
+    /// Get the articles of one source (`solana:core` or `solana:cookbook`)
+    #[instrument(name = "solana_client.get_category", skip(self))]
+    pub async fn get_category(&self, identifier: &str) -> Result<SolanaCategory> {
+        let source = SolanaDocSource::from_identifier(identifier)
+            .ok_or_else(|| anyhow::anyhow!("Unknown Solana category: {identifier}"))?;
+        let (title, description) = source_summary(source);
+
+        let items = SOLANA_ARTICLES
+            .iter()
+            .filter(|article| article.source == source)
+            .map(|article| SolanaCategoryItem {
+                id: article.id.to_string(),
+                title: article.title.to_string(),
+                description: article.description.to_string(),
+                topic: article.topic.to_string(),
+                url: article.url(),
+            })
+            .collect();
+
+        Ok(SolanaCategory {
+            identifier: source.identifier().to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            items,
+        })
+    }
+
+    /// Get an article by id, refreshed from its page when online. The embedded copy is
+    /// returned when the page cannot be fetched.
+    #[instrument(name = "solana_client.get_article", skip(self))]
+    pub async fn get_article(&self, id: &str) -> Result<SolanaArticle> {
+        let index = find_article(id).ok_or_else(|| anyhow::anyhow!("Solana article not found: {id}"))?;
+        let cache_key = format!("article_{}.json", index.id);
+
+        let cached = self.disk_cache.load::<SolanaArticle>(&cache_key).await.ok().flatten();
+        if let Some(entry) = &cached {
+            if OffsetDateTime::now_utc() - entry.stored_at <= ARTICLE_MAX_AGE {
+                return Ok(entry.value.clone());
+            }
+        }
+
+        match self.fetch_article(index).await {
+            Ok(article) => {
+                if let Err(e) = self.disk_cache.store(&cache_key, article.clone()).await {
+                    warn!(error = %e, "Failed to cache Solana article to disk");
+                }
+                Ok(article)
+            }
+            Err(e) => match cached {
+                Some(entry) => {
+                    stale::record(cache_key, &e);
+                    Ok(entry.value)
+                }
+                None => {
+                    debug!(id, error = %e, "Solana article fetch failed, using embedded copy");
+                    Ok(index.to_article())
+                }
+            },
+        }
+    }
+
+    /// Fetch an article's page and merge its text into the embedded copy
+    async fn fetch_article(&self, index: &SolanaArticleIndex) -> Result<SolanaArticle> {
+        let url = index.url();
+        ensure_online(&url)?;
+        let response = self
+            .http
+            .get(&url)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch Solana documentation")?;
+        if !response.status().is_success() {
+            anyhow::bail!("Solana documentation fetch failed for {}: {}", index.id, response.status());
+        }
+        let html = response.text().await.context("Failed to read Solana documentation")?;
+        let page = parse_article_page(&html).context("Solana documentation page has no content")?;
+
+        let mut article = index.to_article();
+        if !page.description.is_empty() {
+            article.description = page.description;
+        }
+        article.content = page.content;
+        if !page.code_examples.is_empty() {
+            article.code_examples = page
+                .code_examples
+                .into_iter()
+                .map(|example| SolanaCodeExample {
+                    language: example.language,
+                    code: example.code,
+                    description: example.description,
+                })
+                .collect();
+        }
+        Ok(article)
+    }
+
+    /// Search the embedded articles; `source` limits the search to the docs or the cookbook
+    #[instrument(name = "solana_client.search", skip(self))]
+    pub async fn search(&self, query: &str, source: Option<SolanaDocSource>) -> Result<Vec<SolanaArticle>> {
+        let terms = tokenize_query(query);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut scored: Vec<(f32, &SolanaArticleIndex)> = SOLANA_ARTICLES
+            .iter()
+            .filter(|article| source.map_or(true, |source| article.source == source))
+            .filter_map(|article| {
+                let score = score_article(article, &terms);
+                (score > 0.0).then_some((score, article))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        Ok(scored.into_iter().map(|(_, article)| article.to_article()).collect())
+    }
+
+    pub fn cache_dir(&self) -> &PathBuf {
+        &self.cache_dir
+    }
+}
+
+fn source_summary(source: SolanaDocSource) -> (&'static str, &'static str) {
+    match source {
+        SolanaDocSource::Docs => (
+            "Solana Core Concepts",
+            "Accounts, rent, transactions, fees, programs, PDAs, CPI and tokens from solana.com/docs",
+        ),
+        SolanaDocSource::Cookbook => (
+            "Solana Cookbook",
+            "Task-focused recipes for wallets, accounts, transactions and tokens",
+        ),
+    }
+}
+
+fn find_article(id: &str) -> Option<&'static SolanaArticleIndex> {
+    let id = id.strip_prefix("solana/").unwrap_or(id);
+    SOLANA_ARTICLES.iter().find(|article| article.id.eq_ignore_ascii_case(id))
+}
+
+/// Lowercase terms of two or more characters, ignoring words that only name Solana
+fn tokenize_query(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|term| term.len() > 1)
+        .map(str::to_lowercase)
+        .filter(|term| !matches!(term.as_str(), "solana" | "sol" | "docs" | "cookbook" | "how" | "to" | "the"))
+        .collect();
+    terms.sort();
+    terms.dedup();
+    terms
+}
+
+fn score_article(article: &SolanaArticleIndex, terms: &[String]) -> f32 {
+    let title = article.title.to_lowercase();
+    let topic = article.topic.to_lowercase();
+    let description = article.description.to_lowercase();
+    let content = article.content.to_lowercase();
+    let id = article.id.to_lowercase();
+
+    let mut score = 0.0;
+    for term in terms {
+        if id == *term {
+            score += 6.0;
+        }
+        if title.contains(term.as_str()) {
+            score += 4.0;
+        }
+        if article.tags.iter().any(|tag| tag.contains(term.as_str())) {
+            score += 3.0;
+        }
+        if topic.contains(term.as_str()) {
+            score += 2.5;
+        }
+        if description.contains(term.as_str()) {
+            score += 1.5;
+        }
+        if content.contains(term.as_str()) {
+            score += 0.75;
+        }
+        if article.examples.iter().any(|(_, _, code)| code.to_lowercase().contains(term.as_str())) {
+            score += 1.0;
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_search_core_concepts() {
+        let client = SolanaClient::new();
+
+        let results = client.search("program derived address seeds", None).await.unwrap();
+        assert_eq!(results[0].id, "pda");
+
+        let results = client.search("cpi invoke_signed", Some(SolanaDocSource::Docs)).await.unwrap();
+        assert_eq!(results[0].id, "cpi");
+
+        let results = client.search("rent exemption", None).await.unwrap();
+        assert_eq!(results[0].id, "rent");
+    }
+
+    #[tokio::test]
+    async fn test_search_cookbook() {
+        let client = SolanaClient::new();
+        let results = client.search("how to send SOL", Some(SolanaDocSource::Cookbook)).await.unwrap();
+        assert_eq!(results[0].id, "send-sol");
+        assert!(results.iter().all(|article| article.source == SolanaDocSource::Cookbook));
+        assert!(client.search("solana", None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_categories() {
+        let client = SolanaClient::new();
+        let technologies = client.get_technologies().await.unwrap();
+        assert_eq!(technologies.len(), 2);
+
+        let cookbook = client.get_category("solana:cookbook").await.unwrap();
+        assert!(cookbook.items.iter().any(|item| item.id == "create-pda-account"));
+        assert!(client.get_category("solana:nfts").await.is_err());
+    }
+
+    #[test]
+    fn test_find_article() {
+        assert_eq!(find_article("PDA").unwrap().id, "pda");
+        assert_eq!(find_article("solana/accounts").unwrap().id, "accounts");
+        assert!(find_article("eth_call").is_none());
+    }
+}
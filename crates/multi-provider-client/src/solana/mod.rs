@@ -0,0 +1,5 @@
+pub mod client;
+pub mod types;
+
+pub use client::SolanaClient;
+pub use types::*;
@@ -0,0 +1,536 @@
+use serde::{Deserialize, Serialize};
+
+/// Solana documentation technology (Core Concepts or Cookbook)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaTechnology {
+    pub identifier: String,
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub item_count: usize,
+}
+
+/// Where an article comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolanaDocSource {
+    /// Core concepts at solana.com/docs (formerly docs.solana.com)
+    Docs,
+    /// Recipes from the Solana Cookbook at solana.com/developers/cookbook
+    Cookbook,
+}
+
+impl SolanaDocSource {
+    #[must_use]
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Self::Docs => "solana:core",
+            Self::Cookbook => "solana:cookbook",
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Docs => "Solana Docs",
+            Self::Cookbook => "Solana Cookbook",
+        }
+    }
+
+    #[must_use]
+    pub fn base_url(self) -> &'static str {
+        match self {
+            Self::Docs => "https://solana.com/docs",
+            Self::Cookbook => "https://solana.com/developers/cookbook",
+        }
+    }
+
+    /// Source of a technology identifier such as `solana:cookbook`
+    #[must_use]
+    pub fn from_identifier(identifier: &str) -> Option<Self> {
+        match identifier.strip_prefix("solana:").unwrap_or(identifier) {
+            "core" | "docs" | "concepts" => Some(Self::Docs),
+            "cookbook" | "recipes" => Some(Self::Cookbook),
+            _ => None,
+        }
+    }
+}
+
+/// Category listing of one source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaCategory {
+    pub identifier: String,
+    pub title: String,
+    pub description: String,
+    pub items: Vec<SolanaCategoryItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaCategoryItem {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub topic: String,
+    pub url: String,
+}
+
+/// A documentation article or cookbook recipe
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaArticle {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub content: String,
+    pub source: SolanaDocSource,
+    /// Topic within the source, e.g. "Accounts" or "Tokens"
+    pub topic: String,
+    pub url: String,
+    #[serde(default)]
+    pub code_examples: Vec<SolanaCodeExample>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolanaCodeExample {
+    /// typescript or rust
+    pub language: String,
+    pub code: String,
+    pub description: Option<String>,
+}
+
+/// Embedded article, see [`SOLANA_ARTICLES`]
+#[derive(Debug, Clone, Copy)]
+pub struct SolanaArticleIndex {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub content: &'static str,
+    pub source: SolanaDocSource,
+    pub topic: &'static str,
+    /// Path below the source's base URL
+    pub path: &'static str,
+    pub tags: &'static [&'static str],
+    /// (language, description, code)
+    pub examples: &'static [(&'static str, &'static str, &'static str)],
+}
+
+impl SolanaArticleIndex {
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{}/{}", self.source.base_url(), self.path)
+    }
+
+    #[must_use]
+    pub fn to_article(&self) -> SolanaArticle {
+        SolanaArticle {
+            id: self.id.to_string(),
+            title: self.title.to_string(),
+            description: self.description.to_string(),
+            content: self.content.to_string(),
+            source: self.source,
+            topic: self.topic.to_string(),
+            url: self.url(),
+            code_examples: self
+                .examples
+                .iter()
+                .map(|(language, description, code)| SolanaCodeExample {
+                    language: (*language).to_string(),
+                    code: (*code).to_string(),
+                    description: Some((*description).to_string()),
+                })
+                .collect(),
+            tags: self.tags.iter().map(|tag| (*tag).to_string()).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Core concepts (solana.com/docs/core)
+// ============================================================================
+
+pub const SOLANA_ARTICLES: &[SolanaArticleIndex] = &[
+    SolanaArticleIndex {
+        id: "accounts",
+        title: "Solana Account Model",
+        description: "All data on Solana is stored in accounts: a key-value store addressed by 32-byte public keys.",
+        content: "Every account has the same fields:\n\
+• lamports: the account balance (1 SOL = 1,000,000,000 lamports)\n\
+• data: a byte array holding state or, for programs, executable code\n\
+• owner: the program id that may modify data and debit lamports\n\
+• executable: whether the account is a program\n\
+• rent_epoch: legacy field, no longer used\n\n\
+Only the owner program can change an account's data or subtract its lamports; anyone can credit lamports. \
+New accounts are owned by the System Program, which creates accounts, allocates space and assigns ownership to other programs. \
+Programs are stateless: state lives in separate data accounts owned by the program, often at Program Derived Addresses. \
+Sysvar accounts (Clock, Rent, EpochSchedule) expose cluster state at fixed addresses.\n\n\
+Every account must hold a rent-exempt minimum balance proportional to its size; see Rent.",
+        source: SolanaDocSource::Docs,
+        topic: "Accounts",
+        path: "core/accounts",
+        tags: &["account", "accounts", "account model", "lamports", "owner", "system program", "sysvar", "data"],
+        examples: &[(
+            "typescript",
+            "Read an account's fields",
+            "import { Connection, PublicKey, clusterApiUrl } from \"@solana/web3.js\";\n\n\
+const connection = new Connection(clusterApiUrl(\"devnet\"), \"confirmed\");\n\
+const info = await connection.getAccountInfo(new PublicKey(\"11111111111111111111111111111111\"));\n\
+console.log(info?.lamports, info?.owner.toBase58(), info?.executable, info?.data.length);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "rent",
+        title: "Rent and Rent Exemption",
+        description: "Accounts must hold a minimum lamport balance proportional to their data size to stay on-chain.",
+        content: "Storing data on-chain requires a deposit. An account is rent exempt when its balance is at least \
+(128 + data length) bytes × 3,480 lamports per byte-year × 2 years, i.e. 6,960 lamports per byte. \
+An empty account therefore needs 890,880 lamports (0.00089088 SOL).\n\n\
+Rent collection from non-exempt accounts has been removed: transactions that would leave an account below the \
+rent-exempt minimum fail instead. The deposit is fully recovered when the account is closed by transferring its lamports out.\n\n\
+Use getMinimumBalanceForRentExemption over RPC, or Rent::get()?.minimum_balance(space) inside a program, to compute the amount.",
+        source: SolanaDocSource::Docs,
+        topic: "Accounts",
+        path: "core/accounts#rent",
+        tags: &["rent", "rent exempt", "rent exemption", "minimum balance", "close account", "space"],
+        examples: &[
+            (
+                "typescript",
+                "Rent-exempt minimum for 165 bytes (an SPL token account)",
+                "const lamports = await connection.getMinimumBalanceForRentExemption(165);",
+            ),
+            (
+                "rust",
+                "Rent-exempt minimum inside a program",
+                "use solana_program::{rent::Rent, sysvar::Sysvar};\n\nlet lamports = Rent::get()?.minimum_balance(space);",
+            ),
+        ],
+    },
+    SolanaArticleIndex {
+        id: "transactions",
+        title: "Transactions and Instructions",
+        description: "Transactions bundle one or more instructions that execute atomically in order.",
+        content: "An instruction names a program id, the accounts it reads or writes (each flagged is_signer and is_writable) \
+and an opaque data buffer. A transaction holds a message (account keys, a recent blockhash and the instructions) and the \
+signatures of every required signer.\n\n\
+• Atomic: if any instruction fails, the whole transaction is rolled back\n\
+• Size limit: 1,232 bytes serialized; address lookup tables (versioned v0 transactions) fit more accounts\n\
+• Expiry: the recent blockhash is valid for 150 blocks (about a minute); rebuild and re-sign after it expires\n\
+• Declaring writable accounts up front lets the runtime execute non-overlapping transactions in parallel",
+        source: SolanaDocSource::Docs,
+        topic: "Transactions",
+        path: "core/transactions",
+        tags: &["transaction", "transactions", "instruction", "instructions", "blockhash", "signer", "versioned transaction", "lookup table", "atomic"],
+        examples: &[(
+            "typescript",
+            "Build, sign and send a transaction",
+            "import { Transaction, sendAndConfirmTransaction } from \"@solana/web3.js\";\n\n\
+const tx = new Transaction().add(instruction);\n\
+const signature = await sendAndConfirmTransaction(connection, tx, [payer]);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "fees",
+        title: "Transaction Fees and Compute Budget",
+        description: "Base fees per signature, optional priority fees, and compute unit limits.",
+        content: "Every transaction pays a base fee of 5,000 lamports per signature, charged to the fee payer (the first signer). \
+A priority fee can be added to improve scheduling: compute unit price (in micro-lamports) × compute unit limit.\n\n\
+• Default limit: 200,000 compute units per instruction, at most 1,400,000 per transaction\n\
+• Set the limit and price with the Compute Budget program's SetComputeUnitLimit and SetComputeUnitPrice instructions\n\
+• Requesting a tight limit (from simulation) lowers the priority fee paid and improves scheduling\n\
+• Half of the base fee is burned; priority fees go to the validator",
+        source: SolanaDocSource::Docs,
+        topic: "Fees",
+        path: "core/fees",
+        tags: &["fee", "fees", "priority fee", "compute units", "compute budget", "lamports", "micro-lamports"],
+        examples: &[(
+            "typescript",
+            "Set compute unit limit and price",
+            "import { ComputeBudgetProgram } from \"@solana/web3.js\";\n\n\
+tx.add(\n  ComputeBudgetProgram.setComputeUnitLimit({ units: 300_000 }),\n  ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 10_000 }),\n);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "programs",
+        title: "Programs",
+        description: "Solana smart contracts are stateless programs compiled to sBPF and stored in executable accounts.",
+        content: "Programs are usually written in Rust, either with the Anchor framework or natively with solana-program, and \
+compiled to Solana BPF (sBPF). They are deployed by a loader program; the upgradeable loader keeps the code in a separate \
+program data account and lets an upgrade authority replace it until the authority is removed, making the program immutable.\n\n\
+Programs hold no state of their own: they read and write data accounts they own, which are passed in by each instruction. \
+Built-in programs include the System Program, Compute Budget program and the loaders; the Token and Associated Token Account \
+programs are part of the Solana Program Library (SPL).",
+        source: SolanaDocSource::Docs,
+        topic: "Programs",
+        path: "core/programs",
+        tags: &["program", "programs", "smart contract", "anchor", "sbpf", "bpf", "deploy", "upgrade authority", "loader"],
+        examples: &[(
+            "rust",
+            "Minimal native program entrypoint",
+            "use solana_program::{account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, msg, pubkey::Pubkey};\n\n\
+entrypoint!(process_instruction);\n\n\
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {\n    msg!(\"program {} received {} accounts\", program_id, accounts.len());\n    Ok(())\n}",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "pda",
+        title: "Program Derived Addresses (PDAs)",
+        description: "Deterministic addresses derived from seeds and a program id that only that program can sign for.",
+        content: "A PDA is found by hashing optional seeds, a bump seed and the program id, searching for an address that is \
+not on the ed25519 curve and therefore has no private key. find_program_address starts the bump at 255 and counts down; \
+the first valid bump is the canonical bump, which programs should store and reuse.\n\n\
+• Up to 16 seeds, each at most 32 bytes\n\
+• Use them as a hashmap: seeds such as [b\"vault\", user] give every user a predictable account\n\
+• Deriving a PDA does not create the account; the program creates it with a CPI to the System Program signed via invoke_signed\n\
+• Only the deriving program can sign for the PDA, which lets PDAs act as program-controlled authorities",
+        source: SolanaDocSource::Docs,
+        topic: "PDAs",
+        path: "core/pda",
+        tags: &["pda", "pdas", "program derived address", "seeds", "bump", "canonical bump", "find_program_address", "invoke_signed"],
+        examples: &[
+            (
+                "rust",
+                "Derive a PDA in a program",
+                "let (vault, bump) = Pubkey::find_program_address(&[b\"vault\", user.key.as_ref()], program_id);",
+            ),
+            (
+                "typescript",
+                "Derive the same PDA on the client",
+                "const [vault, bump] = PublicKey.findProgramAddressSync(\n  [Buffer.from(\"vault\"), user.toBuffer()],\n  programId,\n);",
+            ),
+        ],
+    },
+    SolanaArticleIndex {
+        id: "cpi",
+        title: "Cross Program Invocation (CPI)",
+        description: "How one program calls instructions of another program.",
+        content: "A CPI lets a program invoke an instruction of another program, passing along accounts from its own instruction. \
+Signer and writable privileges extend from the caller to the callee. Use invoke when the required signers already signed the \
+transaction, and invoke_signed to sign for PDAs owned by the calling program by supplying their seeds and bump.\n\n\
+• The callee can itself make CPIs up to a maximum invocation depth of 4\n\
+• Every account the callee touches, including the callee program, must be passed to the CPI\n\
+• Anchor wraps CPIs in CpiContext and CpiContext::new_with_signer",
+        source: SolanaDocSource::Docs,
+        topic: "CPI",
+        path: "core/cpi",
+        tags: &["cpi", "cross program invocation", "invoke", "invoke_signed", "cpicontext", "composability"],
+        examples: &[
+            (
+                "rust",
+                "Transfer SOL from a PDA with invoke_signed",
+                "use solana_program::{program::invoke_signed, system_instruction};\n\n\
+invoke_signed(\n    &system_instruction::transfer(vault.key, recipient.key, lamports),\n    &[vault.clone(), recipient.clone(), system_program.clone()],\n    &[&[b\"vault\", user.key.as_ref(), &[bump]]],\n)?;",
+            ),
+            (
+                "rust",
+                "The same transfer with Anchor",
+                "let seeds: &[&[&[u8]]] = &[&[b\"vault\", user.key.as_ref(), &[bump]]];\n\
+let cpi_ctx = CpiContext::new_with_signer(\n    ctx.accounts.system_program.to_account_info(),\n    system_program::Transfer { from: ctx.accounts.vault.to_account_info(), to: ctx.accounts.recipient.to_account_info() },\n    seeds,\n);\n\
+system_program::transfer(cpi_ctx, lamports)?;",
+            ),
+        ],
+    },
+    SolanaArticleIndex {
+        id: "tokens",
+        title: "Tokens on Solana",
+        description: "SPL tokens: mint accounts, token accounts and associated token accounts.",
+        content: "Fungible and non-fungible tokens are handled by the Token Program (TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA) \
+and its successor Token-2022 (TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb), which adds extensions such as transfer fees and metadata.\n\n\
+• Mint account: supply, decimals, mint authority and optional freeze authority\n\
+• Token account: holds a balance of one mint for one owner\n\
+• Associated token account (ATA): the token account at a PDA derived from owner, token program and mint, so wallets can find it\n\
+• NFTs are mints with supply 1 and 0 decimals",
+        source: SolanaDocSource::Docs,
+        topic: "Tokens",
+        path: "core/tokens",
+        tags: &["token", "tokens", "spl", "spl token", "mint", "token account", "ata", "associated token account", "token-2022", "nft"],
+        examples: &[(
+            "typescript",
+            "Find a wallet's associated token account",
+            "import { getAssociatedTokenAddressSync } from \"@solana/spl-token\";\n\nconst ata = getAssociatedTokenAddressSync(mint, owner);",
+        )],
+    },
+    // ========================================================================
+    // Cookbook recipes (solana.com/developers/cookbook)
+    // ========================================================================
+    SolanaArticleIndex {
+        id: "create-keypair",
+        title: "How to Create a Keypair",
+        description: "Generate a new keypair or load one from a secret key.",
+        content: "A keypair holds an ed25519 public key (the address) and its secret key. Keypair.generate() creates a random \
+keypair; Keypair.fromSecretKey loads one from the 64-byte secret key, e.g. the JSON array in ~/.config/solana/id.json.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Wallets",
+        path: "wallets/create-keypair",
+        tags: &["keypair", "wallet", "secret key", "public key", "generate"],
+        examples: &[(
+            "typescript",
+            "Generate and restore a keypair",
+            "import { Keypair } from \"@solana/web3.js\";\n\n\
+const keypair = Keypair.generate();\n\
+const restored = Keypair.fromSecretKey(keypair.secretKey);\n\
+console.log(restored.publicKey.toBase58());",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "get-account-balance",
+        title: "How to Get Account Balance",
+        description: "Read the SOL balance of an address.",
+        content: "getBalance returns the balance in lamports; divide by LAMPORTS_PER_SOL (1,000,000,000) for SOL.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Accounts",
+        path: "accounts/get-account-balance",
+        tags: &["balance", "get balance", "lamports", "sol"],
+        examples: &[(
+            "typescript",
+            "Balance in SOL",
+            "import { LAMPORTS_PER_SOL } from \"@solana/web3.js\";\n\n\
+const lamports = await connection.getBalance(address);\n\
+console.log(lamports / LAMPORTS_PER_SOL);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "create-account",
+        title: "How to Create an Account",
+        description: "Create a system account with space and a rent-exempt balance, owned by a program.",
+        content: "SystemProgram.createAccount allocates space, funds the account with lamports and assigns its owner in one \
+instruction. Both the payer and the new account must sign; fund it with the rent-exempt minimum for the requested space.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Accounts",
+        path: "accounts/create-account",
+        tags: &["create account", "createaccount", "system program", "space", "allocate", "rent"],
+        examples: &[(
+            "typescript",
+            "Create an account owned by a program",
+            "import { Keypair, SystemProgram, Transaction, sendAndConfirmTransaction } from \"@solana/web3.js\";\n\n\
+const account = Keypair.generate();\n\
+const space = 64;\n\
+const lamports = await connection.getMinimumBalanceForRentExemption(space);\n\
+const tx = new Transaction().add(\n  SystemProgram.createAccount({\n    fromPubkey: payer.publicKey,\n    newAccountPubkey: account.publicKey,\n    lamports,\n    space,\n    programId,\n  }),\n);\n\
+await sendAndConfirmTransaction(connection, tx, [payer, account]);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "calculate-rent",
+        title: "How to Calculate Account Creation Cost",
+        description: "Compute the rent-exempt deposit for an account of a given size.",
+        content: "Ask the cluster for the rent-exempt minimum of the account's data length. The result already includes the \
+128 bytes of account metadata.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Accounts",
+        path: "accounts/calculate-rent",
+        tags: &["rent", "account cost", "rent exempt", "minimum balance"],
+        examples: &[(
+            "typescript",
+            "Rent for 1,500 bytes",
+            "const rent = await connection.getMinimumBalanceForRentExemption(1500);\nconsole.log(`${rent / LAMPORTS_PER_SOL} SOL`);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "create-pda-account",
+        title: "How to Create a PDA Account",
+        description: "Create an account at a Program Derived Address from inside a program.",
+        content: "A PDA has no private key, so its account can only be created by the owning program: call the System \
+Program's create_account through invoke_signed with the PDA's seeds and bump. The client derives the same PDA and passes it \
+in the instruction's accounts as writable.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Accounts",
+        path: "accounts/create-pda-account",
+        tags: &["pda", "create pda", "invoke_signed", "create account", "seeds", "bump"],
+        examples: &[(
+            "rust",
+            "Create a PDA account in a native program",
+            "let rent = Rent::get()?.minimum_balance(space);\n\
+invoke_signed(\n    &system_instruction::create_account(payer.key, pda.key, rent, space as u64, program_id),\n    &[payer.clone(), pda.clone(), system_program.clone()],\n    &[&[b\"data\", payer.key.as_ref(), &[bump]]],\n)?;",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "send-sol",
+        title: "How to Send SOL",
+        description: "Transfer SOL between accounts with the System Program.",
+        content: "SystemProgram.transfer moves lamports from a system-owned account that signs the transaction to any address.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Transactions",
+        path: "transactions/send-sol",
+        tags: &["send sol", "transfer", "system program", "lamports"],
+        examples: &[(
+            "typescript",
+            "Send 0.1 SOL",
+            "import { LAMPORTS_PER_SOL, SystemProgram, Transaction, sendAndConfirmTransaction } from \"@solana/web3.js\";\n\n\
+const tx = new Transaction().add(\n  SystemProgram.transfer({ fromPubkey: sender.publicKey, toPubkey: recipient, lamports: 0.1 * LAMPORTS_PER_SOL }),\n);\n\
+await sendAndConfirmTransaction(connection, tx, [sender]);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "add-priority-fees",
+        title: "How to Add Priority Fees to a Transaction",
+        description: "Raise a transaction's priority with compute budget instructions.",
+        content: "Prepend SetComputeUnitLimit and SetComputeUnitPrice instructions. getRecentPrioritizationFees reports what \
+recent transactions touching the same accounts paid, which helps pick a price.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Transactions",
+        path: "transactions/add-priority-fees",
+        tags: &["priority fee", "priority fees", "compute budget", "compute units", "getrecentprioritizationfees"],
+        examples: &[(
+            "typescript",
+            "Transfer with a priority fee",
+            "const tx = new Transaction().add(\n  ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000 }),\n  ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 50_000 }),\n  SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: recipient, lamports: 10_000 }),\n);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "create-mint-account",
+        title: "How to Create a Token",
+        description: "Create a new SPL token mint.",
+        content: "createMint creates and initializes a mint account with the given decimals, mint authority and optional freeze \
+authority. Pass TOKEN_2022_PROGRAM_ID as the program id to create a Token-2022 mint.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Tokens",
+        path: "tokens/create-mint-account",
+        tags: &["create token", "mint", "create mint", "spl token", "decimals", "mint authority"],
+        examples: &[(
+            "typescript",
+            "Create a mint with 9 decimals",
+            "import { createMint } from \"@solana/spl-token\";\n\n\
+const mint = await createMint(connection, payer, mintAuthority.publicKey, freezeAuthority.publicKey, 9);",
+        )],
+    },
+    SolanaArticleIndex {
+        id: "create-token-account",
+        title: "How to Create a Token Account",
+        description: "Create the associated token account that holds a wallet's balance of a mint.",
+        content: "getOrCreateAssociatedTokenAccount returns the owner's ATA for the mint, creating it (paid by the payer) when it \
+does not exist yet. Mint to it with mintTo and move tokens with transfer or transferChecked.",
+        source: SolanaDocSource::Cookbook,
+        topic: "Tokens",
+        path: "tokens/create-token-account",
+        tags: &["token account", "ata", "associated token account", "getorcreateassociatedtokenaccount", "mintto"],
+        examples: &[(
+            "typescript",
+            "Create an ATA and mint to it",
+            "import { getOrCreateAssociatedTokenAccount, mintTo } from \"@solana/spl-token\";\n\n\
+const ata = await getOrCreateAssociatedTokenAccount(connection, payer, mint, owner);\n\
+await mintTo(connection, payer, mint, ata.address, mintAuthority, 1_000_000_000n);",
+        )],
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_ids_are_unique() {
+        let mut ids: Vec<_> = SOLANA_ARTICLES.iter().map(|article| article.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SOLANA_ARTICLES.len());
+    }
+
+    #[test]
+    fn test_article_urls() {
+        let pda = SOLANA_ARTICLES.iter().find(|article| article.id == "pda").unwrap();
+        assert_eq!(pda.url(), "https://solana.com/docs/core/pda");
+        let recipe = SOLANA_ARTICLES.iter().find(|article| article.id == "send-sol").unwrap();
+        assert_eq!(recipe.url(), "https://solana.com/developers/cookbook/transactions/send-sol");
+        assert_eq!(SolanaDocSource::from_identifier("solana:cookbook"), Some(SolanaDocSource::Cookbook));
+        assert_eq!(SolanaDocSource::from_identifier("solana:nfts"), None);
+    }
+}
@@ -0,0 +1,326 @@
+//! Bot API version history: the release notes from core.telegram.org and field-level
+//! diffs between two tracked snapshots of the API spec.
+//!
+//! The spec source only publishes the latest version, so snapshots accumulate locally as
+//! new versions are fetched; the release notes cover every version.
+
+use std::collections::BTreeSet;
+
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use super::types::{TelegramApiSpec, TelegramFieldSpec};
+
+pub(crate) const CHANGELOG_URL: &str = "https://core.telegram.org/bots/api-changelog";
+
+/// One Bot API release from the changelog page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramRelease {
+    /// Version number without the "Bot API" prefix, e.g. `7.10`
+    pub version: String,
+    pub date: String,
+    pub url: String,
+    pub changes: Vec<String>,
+}
+
+/// Methods, types and fields that differ between two spec versions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelegramSpecDiff {
+    pub from: String,
+    pub to: String,
+    pub added_methods: Vec<String>,
+    pub removed_methods: Vec<String>,
+    pub changed_methods: Vec<TelegramItemChange>,
+    pub added_types: Vec<String>,
+    pub removed_types: Vec<String>,
+    pub changed_types: Vec<TelegramItemChange>,
+}
+
+impl TelegramSpecDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_methods.is_empty()
+            && self.removed_methods.is_empty()
+            && self.changed_methods.is_empty()
+            && self.added_types.is_empty()
+            && self.removed_types.is_empty()
+            && self.changed_types.is_empty()
+    }
+}
+
+/// Fields added to or removed from a method or type present in both versions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramItemChange {
+    pub name: String,
+    pub added_fields: Vec<String>,
+    pub removed_fields: Vec<String>,
+}
+
+/// `(major, minor)` from "7.10", "Bot API 7.10" or "v7.1"; a bare major such as "7" or
+/// "7.x" has no minor.
+#[must_use]
+pub fn parse_version(version: &str) -> Option<(u32, Option<u32>)> {
+    let version = version.trim();
+    let version = version
+        .strip_prefix("Bot API")
+        .or_else(|| version.strip_prefix("bot api"))
+        .unwrap_or(version)
+        .trim()
+        .trim_start_matches(['v', 'V']);
+    let (major, minor) = version.split_once('.').unwrap_or((version, ""));
+    let major = major.parse().ok()?;
+    match minor {
+        "" | "x" | "X" | "*" => Some((major, None)),
+        minor => Some((major, Some(minor.parse().ok()?))),
+    }
+}
+
+/// Whether `version` (e.g. "7.10") falls under `pattern` (e.g. "7.x" or "7.10")
+#[must_use]
+pub fn version_matches(version: &str, pattern: &str) -> bool {
+    match (parse_version(version), parse_version(pattern)) {
+        (Some((major, minor)), Some((pattern_major, pattern_minor))) => {
+            major == pattern_major && (pattern_minor.is_none() || minor == pattern_minor)
+        }
+        _ => false,
+    }
+}
+
+/// Sort key for a version; unparseable versions sort first
+#[must_use]
+pub fn version_key(version: &str) -> (u32, u32) {
+    parse_version(version).map_or((0, 0), |(major, minor)| (major, minor.unwrap_or(0)))
+}
+
+/// Parse the changelog page into releases, newest first as on the page
+#[must_use]
+pub fn parse_changelog(html: &str) -> Vec<TelegramRelease> {
+    let document = Html::parse_document(html);
+    let content_selector = Selector::parse("#dev_page_content").unwrap();
+    let anchor_selector = Selector::parse("a.anchor").unwrap();
+    let strong_selector = Selector::parse("strong").unwrap();
+
+    let Some(content) = document.select(&content_selector).next() else {
+        return Vec::new();
+    };
+
+    let mut releases: Vec<TelegramRelease> = Vec::new();
+    let mut date = String::new();
+    let mut anchor = String::new();
+    // Notes before the first "Bot API" heading belong to pre-versioned releases
+    let mut in_release = false;
+
+    for element in content.children().filter_map(ElementRef::wrap) {
+        match element.value().name() {
+            "h3" => in_release = false,
+            "h4" => {
+                date = element_text(&element);
+                anchor = element
+                    .select(&anchor_selector)
+                    .next()
+                    .and_then(|a| a.value().attr("name"))
+                    .unwrap_or_default()
+                    .to_string();
+                in_release = false;
+            }
+            "p" => {
+                let heading = element.select(&strong_selector).next().map(|strong| element_text(&strong));
+                if let Some(version) = heading.as_deref().and_then(|text| text.strip_prefix("Bot API ")) {
+                    releases.push(TelegramRelease {
+                        version: version.trim().to_string(),
+                        date: date.clone(),
+                        url: format!("{CHANGELOG_URL}#{anchor}"),
+                        changes: Vec::new(),
+                    });
+                    in_release = true;
+                } else if in_release {
+                    if let Some(release) = releases.last_mut() {
+                        release.changes.push(element_text(&element));
+                    }
+                }
+            }
+            "ul" if in_release => {
+                if let Some(release) = releases.last_mut() {
+                    release.changes.extend(
+                        element
+                            .children()
+                            .filter_map(ElementRef::wrap)
+                            .filter(|child| child.value().name() == "li")
+                            .map(|li| element_text(&li))
+                            .filter(|text| !text.is_empty()),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    releases
+}
+
+/// Compare two spec snapshots
+#[must_use]
+pub fn diff_specs(old: &TelegramApiSpec, new: &TelegramApiSpec) -> TelegramSpecDiff {
+    let (added_methods, removed_methods, changed_methods) = diff_items(
+        old.methods.iter().map(|(name, method)| (name.as_str(), method.fields.as_slice())),
+        new.methods.iter().map(|(name, method)| (name.as_str(), method.fields.as_slice())),
+    );
+    let (added_types, removed_types, changed_types) = diff_items(
+        old.types.iter().map(|(name, t)| (name.as_str(), t.fields.as_slice())),
+        new.types.iter().map(|(name, t)| (name.as_str(), t.fields.as_slice())),
+    );
+
+    TelegramSpecDiff {
+        from: old.version.clone(),
+        to: new.version.clone(),
+        added_methods,
+        removed_methods,
+        changed_methods,
+        added_types,
+        removed_types,
+        changed_types,
+    }
+}
+
+type ItemDiff = (Vec<String>, Vec<String>, Vec<TelegramItemChange>);
+
+fn diff_items<'a>(
+    old: impl Iterator<Item = (&'a str, &'a [TelegramFieldSpec])>,
+    new: impl Iterator<Item = (&'a str, &'a [TelegramFieldSpec])>,
+) -> ItemDiff {
+    let old: Vec<_> = old.collect();
+    let new: Vec<_> = new.collect();
+    let old_names: BTreeSet<&str> = old.iter().map(|(name, _)| *name).collect();
+    let new_names: BTreeSet<&str> = new.iter().map(|(name, _)| *name).collect();
+
+    let added = new_names.difference(&old_names).map(ToString::to_string).collect();
+    let removed = old_names.difference(&new_names).map(ToString::to_string).collect();
+
+    let mut changed: Vec<TelegramItemChange> = new
+        .iter()
+        .filter_map(|(name, new_fields)| {
+            let (_, old_fields) = old.iter().find(|(old_name, _)| old_name == name)?;
+            let old_fields: BTreeSet<&str> = old_fields.iter().map(|field| field.name.as_str()).collect();
+            let new_fields: BTreeSet<&str> = new_fields.iter().map(|field| field.name.as_str()).collect();
+            let added_fields: Vec<String> = new_fields.difference(&old_fields).map(ToString::to_string).collect();
+            let removed_fields: Vec<String> = old_fields.difference(&new_fields).map(ToString::to_string).collect();
+            (!added_fields.is_empty() || !removed_fields.is_empty()).then(|| TelegramItemChange {
+                name: (*name).to_string(),
+                added_fields,
+                removed_fields,
+            })
+        })
+        .collect();
+    changed.sort_by(|a, b| a.name.cmp(&b.name));
+
+    (added, removed, changed)
+}
+
+pub(super) fn element_text(element: &ElementRef) -> String {
+    element.text().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::telegram::types::TelegramMethodSpec;
+
+    const CHANGELOG: &str = r##"<html><body><div id="dev_page_content">
+<p>Older release notes are below.</p>
+<h3><a class="anchor" name="2024" href="#2024"></a>2024</h3>
+<h4><a class="anchor" name="september-6-2024" href="#september-6-2024"></a>September 6, 2024</h4>
+<p><strong>Bot API 7.10</strong></p>
+<ul>
+<li>Added updates about purchased paid media, represented by the class <a href="/bots/api#paidmediapurchased">PaidMediaPurchased</a>.</li>
+<li>Added the parameter <em>payload</em> to the method <a href="/bots/api#sendpaidmedia">sendPaidMedia</a>.</li>
+</ul>
+<h4><a class="anchor" name="december-29-2023" href="#december-29-2023"></a>December 29, 2023</h4>
+<p><strong>Bot API 7.0</strong></p>
+<p><strong>Reactions</strong></p>
+<ul><li>Added the class <a href="/bots/api#reactiontype">ReactionType</a>.</li></ul>
+<h3><a class="anchor" name="2015" href="#2015"></a>2015</h3>
+<h4><a class="anchor" name="june-24-2015" href="#june-24-2015"></a>June 24, 2015</h4>
+<ul><li>The bot platform was officially launched.</li></ul>
+</div></body></html>"##;
+
+    fn spec(version: &str, methods: &[(&str, &[&str])]) -> TelegramApiSpec {
+        TelegramApiSpec {
+            version: version.to_string(),
+            release_date: String::new(),
+            changelog: String::new(),
+            methods: methods
+                .iter()
+                .map(|(name, fields)| {
+                    let method = TelegramMethodSpec {
+                        name: (*name).to_string(),
+                        href: String::new(),
+                        description: Vec::new(),
+                        returns: Vec::new(),
+                        fields: fields
+                            .iter()
+                            .map(|field| TelegramFieldSpec {
+                                name: (*field).to_string(),
+                                types: vec!["String".to_string()],
+                                required: true,
+                                description: String::new(),
+                            })
+                            .collect(),
+                    };
+                    ((*name).to_string(), method)
+                })
+                .collect(),
+            types: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_changelog() {
+        let releases = parse_changelog(CHANGELOG);
+        assert_eq!(releases.len(), 2);
+
+        assert_eq!(releases[0].version, "7.10");
+        assert_eq!(releases[0].date, "September 6, 2024");
+        assert_eq!(releases[0].url, "https://core.telegram.org/bots/api-changelog#september-6-2024");
+        assert_eq!(releases[0].changes.len(), 2);
+        assert!(releases[0].changes[1].starts_with("Added the parameter payload to the method sendPaidMedia"));
+
+        assert_eq!(releases[1].version, "7.0");
+        assert_eq!(releases[1].changes, ["Reactions", "Added the class ReactionType."]);
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("Bot API 7.10"), Some((7, Some(10))));
+        assert_eq!(parse_version("v6.9"), Some((6, Some(9))));
+        assert_eq!(parse_version("7.x"), Some((7, None)));
+        assert_eq!(parse_version("8"), Some((8, None)));
+        assert_eq!(parse_version("latest"), None);
+
+        assert!(version_matches("7.10", "7.x"));
+        assert!(version_matches("Bot API 7.1", "7.1"));
+        assert!(!version_matches("7.10", "7.1"));
+        assert!(version_key("7.10") > version_key("7.9"));
+    }
+
+    #[test]
+    fn test_diff_specs() {
+        let old = spec("Bot API 7.9", &[("sendMessage", &["chat_id", "text"]), ("getMe", &[])]);
+        let new = spec(
+            "Bot API 7.10",
+            &[("sendMessage", &["chat_id", "text", "message_effect_id"]), ("sendPaidMedia", &["chat_id"])],
+        );
+
+        let diff = diff_specs(&old, &new);
+        assert_eq!(diff.added_methods, ["sendPaidMedia"]);
+        assert_eq!(diff.removed_methods, ["getMe"]);
+        assert_eq!(diff.changed_methods.len(), 1);
+        assert_eq!(diff.changed_methods[0].name, "sendMessage");
+        assert_eq!(diff.changed_methods[0].added_fields, ["message_effect_id"]);
+        assert!(diff.changed_methods[0].removed_fields.is_empty());
+        assert!(diff.added_types.is_empty());
+
+        assert!(diff_specs(&new, &new).is_empty());
+    }
+}
@@ -1,27 +1,52 @@
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use reqwest::Client;
+use time::{Duration, OffsetDateTime};
 use tokio::sync::Mutex;
 use tracing::{debug, instrument};
 
+use super::changelog::{
+    diff_specs, parse_changelog, version_key, version_matches, TelegramRelease, TelegramSpecDiff, CHANGELOG_URL,
+};
+use super::mini_apps::{parse_mini_apps, MiniAppSection, MINI_APPS_TECHNOLOGY, MINI_APPS_URL};
+use super::tdlib::{parse_td_api, TdlibSpec, TDLIB_TECHNOLOGY, TD_API_URL};
 use super::types::{
     TelegramApiSpec, TelegramCategory, TelegramCategoryItem, TelegramItem, TelegramTechnology,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::disk::STATE_DIR;
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+use docs_mcp_client::stale;
+
+use crate::credentials::github_get;
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 const SPEC_URL: &str =
     "https://raw.githubusercontent.com/PaulSonOfLars/telegram-bot-api-spec/main/api.json";
 const CACHE_KEY: &str = "telegram_api_spec";
+const VERSIONS_CACHE_KEY: &str = "telegram_api_versions.json";
+const CHANGELOG_CACHE_KEY: &str = "telegram_api_changelog.json";
+const MINI_APPS_CACHE_KEY: &str = "telegram_webapps.json";
+const TDLIB_CACHE_KEY: &str = "tdlib_td_api";
+const TDLIB_DOCS_URL: &str = "https://core.telegram.org/tdlib/docs/td__api_8h.html";
+/// The Bot API spec and changelog are fetched again after this long, so new versions show up
+const SPEC_MAX_AGE: Duration = Duration::days(1);
 
 #[derive(Debug)]
 pub struct TelegramClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    /// Spec snapshots of every version seen, kept out of reach of pruning and eviction
+    snapshots: DiskCache,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     spec_lock: Mutex<()>,
+    tdlib_lock: Mutex<()>,
     cache_dir: PathBuf,
 }
 
@@ -42,9 +67,8 @@ impl TelegramClient {
             tracing::warn!(error = %e, "Failed to create Telegram cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::Telegram))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -52,38 +76,61 @@ impl TelegramClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::minutes(30)),
+            snapshots: DiskCache::new(cache_dir.join(STATE_DIR)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::Telegram, memory_ttl(ProviderType::Telegram)),
             spec_lock: Mutex::new(()),
+            tdlib_lock: Mutex::new(()),
             cache_dir,
         }
     }
 
-    /// Fetch the Telegram Bot API specification
+    /// Fetch the Telegram Bot API specification, refetched once the cached copy is older than
+    /// [`SPEC_MAX_AGE`]; the cached copy is served stale when that fails
     #[instrument(name = "telegram_client.get_spec", skip(self))]
     async fn get_spec(&self) -> Result<TelegramApiSpec> {
         let cache_key = format!("{CACHE_KEY}.json");
 
         // Check disk cache
-        if let Ok(Some(entry)) = self.disk_cache.load::<TelegramApiSpec>(&cache_key).await {
-            debug!("Telegram API spec served from disk cache");
-            return Ok(entry.value);
+        if let Some(entry) = self.disk_cache.load::<TelegramApiSpec>(&cache_key).await.ok().flatten() {
+            if OffsetDateTime::now_utc() - entry.stored_at <= SPEC_MAX_AGE {
+                debug!("Telegram API spec served from disk cache");
+                return Ok(entry.value);
+            }
         }
 
         // Lock to prevent concurrent fetches
         let _lock = self.spec_lock.lock().await;
 
         // Double-check after acquiring lock
-        if let Ok(Some(entry)) = self.disk_cache.load::<TelegramApiSpec>(&cache_key).await {
-            debug!("Telegram API spec served from disk cache (after lock)");
-            return Ok(entry.value);
+        let cached = self.disk_cache.load::<TelegramApiSpec>(&cache_key).await.ok().flatten();
+        if let Some(entry) = &cached {
+            if OffsetDateTime::now_utc() - entry.stored_at <= SPEC_MAX_AGE {
+                debug!("Telegram API spec served from disk cache (after lock)");
+                return Ok(entry.value.clone());
+            }
         }
 
-        // Fetch from remote
+        match self.fetch_spec().await {
+            Ok(spec) => {
+                self.disk_cache.store(&cache_key, spec.clone()).await?;
+                self.record_snapshot(&spec).await?;
+                Ok(spec)
+            }
+            Err(error) => match cached {
+                Some(entry) => {
+                    stale::record(cache_key, &error);
+                    Ok(entry.value)
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    async fn fetch_spec(&self) -> Result<TelegramApiSpec> {
         debug!(url = SPEC_URL, "Fetching Telegram API spec");
-        let response = self
-            .http
-            .get(SPEC_URL)
-            .send()
+        ensure_online(SPEC_URL)?;
+        let response = github_get(&self.http, SPEC_URL)
+            .send_with_retry()
             .await
             .context("Failed to fetch Telegram API spec")?;
 
@@ -91,12 +138,185 @@ impl TelegramClient {
             anyhow::bail!("Telegram API spec fetch failed: {}", response.status());
         }
 
-        let spec: TelegramApiSpec = response
+        response
             .json()
             .await
-            .context("Failed to parse Telegram API spec")?;
+            .context("Failed to parse Telegram API spec")
+    }
+
+    /// Keep a copy of every spec version seen so later versions can be diffed against it
+    async fn record_snapshot(&self, spec: &TelegramApiSpec) -> Result<()> {
+        let mut versions = self
+            .snapshots
+            .load::<Vec<String>>(VERSIONS_CACHE_KEY)
+            .await
+            .ok()
+            .flatten()
+            .map(|entry| entry.value)
+            .unwrap_or_default();
+        if versions.contains(&spec.version) {
+            return Ok(());
+        }
+
+        self.snapshots.store(&snapshot_key(&spec.version), spec.clone()).await?;
+        versions.push(spec.version.clone());
+        versions.sort_by_key(|version| version_key(version));
+        self.snapshots.store(VERSIONS_CACHE_KEY, versions).await
+    }
+
+    /// Bot API versions with a local spec snapshot, oldest first
+    #[instrument(name = "telegram_client.tracked_versions", skip(self))]
+    pub async fn tracked_versions(&self) -> Result<Vec<String>> {
+        // Specs cached before snapshots were kept still count as tracked
+        let spec = self.get_spec().await?;
+        self.record_snapshot(&spec).await?;
+
+        Ok(self
+            .snapshots
+            .load::<Vec<String>>(VERSIONS_CACHE_KEY)
+            .await?
+            .map(|entry| entry.value)
+            .unwrap_or_default())
+    }
+
+    /// Diff the tracked snapshots matching `from` and `to` (default: the current spec).
+    ///
+    /// Returns `None` when either version has no local snapshot.
+    #[instrument(name = "telegram_client.diff_versions", skip(self))]
+    pub async fn diff_versions(&self, from: &str, to: Option<&str>) -> Result<Option<TelegramSpecDiff>> {
+        let versions = self.tracked_versions().await?;
+        let resolve = |pattern: &str| versions.iter().rev().find(|version| version_matches(version, pattern)).cloned();
+
+        let Some(from) = resolve(from) else {
+            return Ok(None);
+        };
+        let to = match to {
+            Some(pattern) => match resolve(pattern) {
+                Some(version) => version,
+                None => return Ok(None),
+            },
+            None => versions.last().cloned().unwrap_or_default(),
+        };
+
+        let old = self.snapshots.load::<TelegramApiSpec>(&snapshot_key(&from)).await?;
+        let new = self.snapshots.load::<TelegramApiSpec>(&snapshot_key(&to)).await?;
+        Ok(old.zip(new).map(|(old, new)| diff_specs(&old.value, &new.value)))
+    }
+
+    /// Fetch the Bot API release notes, newest first; refetched like the spec
+    #[instrument(name = "telegram_client.get_changelog", skip(self))]
+    pub async fn get_changelog(&self) -> Result<Vec<TelegramRelease>> {
+        let cached = self.disk_cache.load::<Vec<TelegramRelease>>(CHANGELOG_CACHE_KEY).await.ok().flatten();
+        if let Some(entry) = &cached {
+            if OffsetDateTime::now_utc() - entry.stored_at <= SPEC_MAX_AGE {
+                debug!("Telegram changelog served from disk cache");
+                return Ok(entry.value.clone());
+            }
+        }
+
+        match self.fetch_changelog().await {
+            Ok(releases) => {
+                self.disk_cache.store(CHANGELOG_CACHE_KEY, releases.clone()).await?;
+                Ok(releases)
+            }
+            Err(error) => match cached {
+                Some(entry) => {
+                    stale::record(CHANGELOG_CACHE_KEY, &error);
+                    Ok(entry.value)
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    async fn fetch_changelog(&self) -> Result<Vec<TelegramRelease>> {
+        debug!(url = CHANGELOG_URL, "Fetching Telegram Bot API changelog");
+        ensure_online(CHANGELOG_URL)?;
+        let response = self
+            .http
+            .get(CHANGELOG_URL)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch Telegram Bot API changelog")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Telegram changelog fetch failed: {}", response.status());
+        }
+
+        let html = response.text().await.context("Failed to read Telegram changelog")?;
+        let releases = parse_changelog(&html);
+        if releases.is_empty() {
+            anyhow::bail!("Telegram changelog contained no Bot API releases");
+        }
+        Ok(releases)
+    }
+
+    /// Fetch and parse the Mini Apps platform page
+    #[instrument(name = "telegram_client.get_mini_apps", skip(self))]
+    async fn get_mini_apps(&self) -> Result<Vec<MiniAppSection>> {
+        if let Ok(Some(entry)) = self.disk_cache.load::<Vec<MiniAppSection>>(MINI_APPS_CACHE_KEY).await {
+            debug!("Telegram Mini Apps docs served from disk cache");
+            return Ok(entry.value);
+        }
+
+        debug!(url = MINI_APPS_URL, "Fetching Telegram Mini Apps docs");
+        ensure_online(MINI_APPS_URL)?;
+        let response = self
+            .http
+            .get(MINI_APPS_URL)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch Telegram Mini Apps docs")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Telegram Mini Apps docs fetch failed: {}", response.status());
+        }
+
+        let html = response.text().await.context("Failed to read Telegram Mini Apps docs")?;
+        let sections = parse_mini_apps(&html);
+        if sections.is_empty() {
+            anyhow::bail!("Telegram Mini Apps page contained no sections");
+        }
+
+        self.disk_cache.store(MINI_APPS_CACHE_KEY, sections.clone()).await?;
+
+        Ok(sections)
+    }
+
+    /// Fetch and parse the TDLib schema (`td_api.tl`)
+    #[instrument(name = "telegram_client.get_tdlib_spec", skip(self))]
+    async fn get_tdlib_spec(&self) -> Result<TdlibSpec> {
+        let cache_key = format!("{TDLIB_CACHE_KEY}.json");
+
+        if let Ok(Some(entry)) = self.disk_cache.load::<TdlibSpec>(&cache_key).await {
+            debug!("TDLib schema served from disk cache");
+            return Ok(entry.value);
+        }
+
+        let _lock = self.tdlib_lock.lock().await;
+
+        if let Ok(Some(entry)) = self.disk_cache.load::<TdlibSpec>(&cache_key).await {
+            debug!("TDLib schema served from disk cache (after lock)");
+            return Ok(entry.value);
+        }
+
+        debug!(url = TD_API_URL, "Fetching TDLib schema");
+        ensure_online(TD_API_URL)?;
+        let response = github_get(&self.http, TD_API_URL)
+            .send_with_retry()
+            .await
+            .context("Failed to fetch TDLib schema")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("TDLib schema fetch failed: {}", response.status());
+        }
+
+        let schema = response.text().await.context("Failed to read TDLib schema")?;
+        let spec = parse_td_api(&schema);
+        if spec.entries.is_empty() {
+            anyhow::bail!("TDLib schema contained no documented entries");
+        }
 
-        // Store in cache
         self.disk_cache.store(&cache_key, spec.clone()).await?;
 
         Ok(spec)
@@ -127,12 +347,84 @@ impl TelegramClient {
             item_count: spec.types.len(),
         };
 
-        Ok(vec![methods_tech, types_tech])
+        let mut technologies = vec![methods_tech, types_tech];
+
+        match self.get_mini_apps().await {
+            Ok(sections) => technologies.push(TelegramTechnology {
+                identifier: MINI_APPS_TECHNOLOGY.to_string(),
+                title: "Telegram Mini Apps".to_string(),
+                description: format!(
+                    "Mini Apps (WebApp) - JavaScript API, themes and init data validation in {} sections",
+                    sections.len()
+                ),
+                url: MINI_APPS_URL.to_string(),
+                item_count: sections.len(),
+            }),
+            Err(e) => debug!(error = %e, "Telegram Mini Apps docs unavailable"),
+        }
+
+        // TDLib is a separate schema; the Bot API stays usable when it cannot be fetched
+        match self.get_tdlib_spec().await {
+            Ok(tdlib) => technologies.push(TelegramTechnology {
+                identifier: TDLIB_TECHNOLOGY.to_string(),
+                title: "TDLib".to_string(),
+                description: format!(
+                    "TDLib - {} classes, objects and functions for building full Telegram clients",
+                    tdlib.entries.len()
+                ),
+                url: TDLIB_DOCS_URL.to_string(),
+                item_count: tdlib.entries.len(),
+            }),
+            Err(e) => debug!(error = %e, "TDLib schema unavailable"),
+        }
+
+        Ok(technologies)
     }
 
     /// Get a category (methods or types)
     #[instrument(name = "telegram_client.get_category", skip(self))]
     pub async fn get_category(&self, identifier: &str) -> Result<TelegramCategory> {
+        if matches!(identifier, TDLIB_TECHNOLOGY | "tdlib") {
+            let tdlib = self.get_tdlib_spec().await?;
+            let items = tdlib
+                .entries
+                .iter()
+                .map(|entry| TelegramCategoryItem {
+                    name: entry.name.clone(),
+                    description: entry.description.clone(),
+                    kind: entry.kind.as_str().to_string(),
+                    href: entry.url(),
+                })
+                .collect();
+
+            return Ok(TelegramCategory {
+                identifier: TDLIB_TECHNOLOGY.to_string(),
+                title: "TDLib".to_string(),
+                description: "TDLib API - classes, objects and functions from td_api.tl".to_string(),
+                items,
+            });
+        }
+
+        if matches!(identifier, MINI_APPS_TECHNOLOGY | "webapps") {
+            let sections = self.get_mini_apps().await?;
+            let items = sections
+                .iter()
+                .map(|section| TelegramCategoryItem {
+                    name: section.title.clone(),
+                    description: section.summary.clone(),
+                    kind: section.kind().to_string(),
+                    href: section.url(),
+                })
+                .collect();
+
+            return Ok(TelegramCategory {
+                identifier: MINI_APPS_TECHNOLOGY.to_string(),
+                title: "Telegram Mini Apps".to_string(),
+                description: "Mini Apps platform - WebApp JavaScript API, themes and init data".to_string(),
+                items,
+            });
+        }
+
         let spec = self.get_spec().await?;
 
         match identifier {
@@ -275,12 +567,121 @@ impl TelegramClient {
         }
 
         // Sort by score (highest first) and return items
-        scored_results.sort_by(|a, b| b.0.cmp(&a.0));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
         let results = scored_results.into_iter().map(|(_, item)| item).collect();
 
         Ok(results)
     }
 
+    /// Get a Mini Apps section by heading, object name or anchor
+    #[instrument(name = "telegram_client.get_mini_app_item", skip(self))]
+    pub async fn get_mini_app_item(&self, name: &str) -> Result<TelegramItem> {
+        let sections = self.get_mini_apps().await?;
+        let name = name.trim().trim_start_matches('#');
+        sections
+            .iter()
+            .find(|section| section.title.eq_ignore_ascii_case(name) || section.anchor.eq_ignore_ascii_case(name))
+            .map(MiniAppSection::to_item)
+            .with_context(|| format!("Telegram Mini Apps section not found: {name}"))
+    }
+
+    /// Search Mini Apps sections and the fields of their object tables
+    #[instrument(name = "telegram_client.search_mini_apps", skip(self))]
+    pub async fn search_mini_apps(&self, query: &str) -> Result<Vec<MiniAppSection>> {
+        let sections = self.get_mini_apps().await?;
+        let query_lower = query.to_lowercase();
+
+        let keywords: Vec<&str> = query_lower
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .filter(|s| !s.is_empty() && s.len() > 1)
+            .collect();
+
+        let mut scored_results: Vec<(i32, MiniAppSection)> = Vec::new();
+        for section in sections {
+            let title_lower = section.title.to_lowercase();
+            let content_lower = section.content.to_lowercase();
+
+            let mut score = 0i32;
+            for keyword in &keywords {
+                if title_lower == *keyword {
+                    score += 50;
+                } else if title_lower.contains(keyword) {
+                    score += 20;
+                }
+                if content_lower.contains(keyword) {
+                    score += 5;
+                }
+                for field in &section.fields {
+                    let field_lower = field.name.to_lowercase();
+                    if field_lower == *keyword {
+                        score += 15;
+                    } else if field_lower.contains(keyword) {
+                        score += 3;
+                    }
+                }
+            }
+
+            if score > 0 {
+                scored_results.push((score, section));
+            }
+        }
+
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
+        Ok(scored_results.into_iter().map(|(_, section)| section).collect())
+    }
+
+    /// Get a TDLib class, object or function by name
+    #[instrument(name = "telegram_client.get_tdlib_item", skip(self))]
+    pub async fn get_tdlib_item(&self, name: &str) -> Result<TelegramItem> {
+        let tdlib = self.get_tdlib_spec().await?;
+        tdlib
+            .get(name)
+            .map(|entry| tdlib.item(entry))
+            .with_context(|| format!("TDLib entry not found: {name}"))
+    }
+
+    /// Search TDLib classes, objects and functions
+    #[instrument(name = "telegram_client.search_tdlib", skip(self))]
+    pub async fn search_tdlib(&self, query: &str) -> Result<Vec<TelegramItem>> {
+        let tdlib = self.get_tdlib_spec().await?;
+        let query_lower = query.to_lowercase();
+
+        let keywords: Vec<&str> = query_lower
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .filter(|s| !s.is_empty() && s.len() > 1)
+            .collect();
+
+        let mut scored_results: Vec<(i32, &_)> = Vec::new();
+        for entry in &tdlib.entries {
+            let name_lower = entry.name.to_lowercase();
+            let description_text = entry.description.to_lowercase();
+
+            let mut score = 0i32;
+            for keyword in &keywords {
+                if name_lower == *keyword {
+                    score += 50;
+                } else if name_lower.contains(keyword) {
+                    score += 20;
+                }
+                if description_text.contains(keyword) {
+                    score += 5;
+                }
+                for field in &entry.fields {
+                    if field.name.contains(keyword) {
+                        score += 3;
+                    }
+                }
+            }
+
+            if score > 0 {
+                scored_results.push((score, entry));
+            }
+        }
+
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.0));
+        Ok(scored_results.into_iter().map(|(_, entry)| tdlib.item(entry)).collect())
+    }
+
     /// Get the API version
     pub async fn get_version(&self) -> Result<String> {
         let spec = self.get_spec().await?;
@@ -292,6 +693,12 @@ impl TelegramClient {
     }
 }
 
+/// Cache file for the snapshot of one spec version, e.g. `telegram_api_spec_7.10.json`
+fn snapshot_key(version: &str) -> String {
+    let version = version.trim();
+    format!("{CACHE_KEY}_{}.json", version.strip_prefix("Bot API").unwrap_or(version).trim())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,4 +707,9 @@ mod tests {
     fn test_client_creation() {
         let _client = TelegramClient::new();
     }
+
+    #[test]
+    fn test_snapshot_key() {
+        assert_eq!(snapshot_key("Bot API 7.10"), "telegram_api_spec_7.10.json");
+    }
 }
@@ -0,0 +1,223 @@
+//! Telegram Mini Apps (the `window.Telegram.WebApp` JavaScript API) parsed from the
+//! platform page on core.telegram.org.
+//!
+//! The page is one long article: every `h3`/`h4` heading starts a section, and sections
+//! describing an object (`WebAppInitData`, `ThemeParams`, ...) carry a Field/Type/Description
+//! table like the Bot API page.
+
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use super::changelog::element_text;
+use super::types::{TelegramItem, TelegramItemField};
+
+/// Technology identifier for Mini Apps, alongside `telegram:methods` and `telegram:types`
+pub const MINI_APPS_TECHNOLOGY: &str = "telegram:webapps";
+
+pub(crate) const MINI_APPS_URL: &str = "https://core.telegram.org/bots/webapps";
+
+/// One heading of the Mini Apps page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiniAppSection {
+    /// Heading text; object sections are headed by the object name, e.g. `ThemeParams`
+    pub title: String,
+    pub anchor: String,
+    /// First paragraph
+    pub summary: String,
+    /// Prose, lists and code of the section as plain text
+    pub content: String,
+    #[serde(default)]
+    pub fields: Vec<MiniAppField>,
+    #[serde(default)]
+    pub code_sample: Option<String>,
+}
+
+/// A row of an object table; `WebApp` methods have the type `Function`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiniAppField {
+    pub name: String,
+    pub type_name: String,
+    pub description: String,
+}
+
+impl MiniAppSection {
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{MINI_APPS_URL}#{}", self.anchor)
+    }
+
+    /// Sections with a field table document an object; the rest are guides
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        if self.fields.is_empty() {
+            "guide"
+        } else {
+            "object"
+        }
+    }
+
+    #[must_use]
+    pub fn to_item(&self) -> TelegramItem {
+        TelegramItem {
+            name: self.title.clone(),
+            description: self.content.clone(),
+            kind: self.kind().to_string(),
+            href: self.url(),
+            fields: self
+                .fields
+                .iter()
+                .map(|field| TelegramItemField {
+                    name: field.name.clone(),
+                    types: vec![field.type_name.clone()],
+                    required: !field.description.starts_with("Optional"),
+                    description: field.description.clone(),
+                })
+                .collect(),
+            returns: None,
+            subtypes: Vec::new(),
+            subtype_of: Vec::new(),
+        }
+    }
+}
+
+/// Parse the Mini Apps page into sections
+#[must_use]
+pub fn parse_mini_apps(html: &str) -> Vec<MiniAppSection> {
+    let document = Html::parse_document(html);
+    let content_selector = Selector::parse("#dev_page_content").unwrap();
+    let anchor_selector = Selector::parse("a.anchor").unwrap();
+    let row_selector = Selector::parse("tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    let Some(content) = document.select(&content_selector).next() else {
+        return Vec::new();
+    };
+
+    let mut sections: Vec<MiniAppSection> = Vec::new();
+    for element in content.children().filter_map(ElementRef::wrap) {
+        let name = element.value().name();
+        if matches!(name, "h3" | "h4") {
+            let anchor = element
+                .select(&anchor_selector)
+                .next()
+                .and_then(|a| a.value().attr("name"))
+                .unwrap_or_default()
+                .to_string();
+            sections.push(MiniAppSection {
+                title: element_text(&element),
+                anchor,
+                summary: String::new(),
+                content: String::new(),
+                fields: Vec::new(),
+                code_sample: None,
+            });
+            continue;
+        }
+
+        let Some(section) = sections.last_mut() else {
+            continue;
+        };
+        match name {
+            "table" if section.fields.is_empty() => {
+                section.fields = element
+                    .select(&row_selector)
+                    .filter_map(|row| {
+                        let cells: Vec<String> = row.select(&cell_selector).map(|cell| element_text(&cell)).collect();
+                        match cells.as_slice() {
+                            [name, type_name, description, ..] if name != "Field" => Some(MiniAppField {
+                                name: name.clone(),
+                                type_name: type_name.clone(),
+                                description: description.clone(),
+                            }),
+                            _ => None,
+                        }
+                    })
+                    .collect();
+            }
+            "pre" => {
+                let code = element.text().collect::<String>().trim().to_string();
+                push_paragraph(&mut section.content, &code);
+                section.code_sample.get_or_insert(code);
+            }
+            "p" | "blockquote" => {
+                let text = element_text(&element);
+                if section.summary.is_empty() {
+                    section.summary.clone_from(&text);
+                }
+                push_paragraph(&mut section.content, &text);
+            }
+            "ul" | "ol" => {
+                let items: Vec<String> = element
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .map(|li| format!("• {}", element_text(&li)))
+                    .collect();
+                push_paragraph(&mut section.content, &items.join("\n"));
+            }
+            _ => {}
+        }
+    }
+
+    // Headings that only group the sections below them
+    sections.retain(|section| !section.title.is_empty() && (!section.content.is_empty() || !section.fields.is_empty()));
+    sections
+}
+
+fn push_paragraph(content: &mut String, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    if !content.is_empty() {
+        content.push_str("\n\n");
+    }
+    content.push_str(text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r##"<html><body><div id="dev_page_content">
+<p>Telegram Bots can completely replace any website.</p>
+<h3><a class="anchor" name="initializing-mini-apps" href="#initializing-mini-apps"></a>Initializing Mini Apps</h3>
+<p>To connect your Mini App to the Telegram client, place the script in the head tag:</p>
+<pre><code>&lt;script src="https://telegram.org/js/telegram-web-app.js"&gt;&lt;/script&gt;</code></pre>
+<table class="table">
+<tr><td><strong>Field</strong></td><td><strong>Type</strong></td><td><strong>Description</strong></td></tr>
+<tr><td>initData</td><td>String</td><td>A string with raw data transferred to the Mini App.</td></tr>
+<tr><td>isVersionAtLeast(version)</td><td>Function</td><td>Returns true if the user's app supports a version of the Bot API.</td></tr>
+</table>
+<h4><a class="anchor" name="themeparams" href="#themeparams"></a>ThemeParams</h4>
+<p>Mini Apps can adjust the appearance of the interface to match the Telegram user's app.</p>
+<table class="table">
+<tr><td><strong>Field</strong></td><td><strong>Type</strong></td><td><strong>Description</strong></td></tr>
+<tr><td>bg_color</td><td>String</td><td><em>Optional</em>. Background color in the #RRGGBB format.</td></tr>
+</table>
+<h4><a class="anchor" name="validating-data-received-via-the-mini-app" href="#validating-data-received-via-the-mini-app"></a>Validating data received via the Mini App</h4>
+<p>To validate data received via the Mini App, one should send the data from the Telegram.WebApp.initData field to the bot's backend.</p>
+<ul><li>Sort the fields alphabetically.</li><li>Compute the HMAC-SHA-256 signature.</li></ul>
+</div></body></html>"##;
+
+    #[test]
+    fn test_parse_mini_apps() {
+        let sections = parse_mini_apps(PAGE);
+        let titles: Vec<&str> = sections.iter().map(|section| section.title.as_str()).collect();
+        assert_eq!(titles, ["Initializing Mini Apps", "ThemeParams", "Validating data received via the Mini App"]);
+
+        let init = &sections[0];
+        assert_eq!(init.fields.len(), 2);
+        assert_eq!(init.fields[1].name, "isVersionAtLeast(version)");
+        assert_eq!(init.fields[1].type_name, "Function");
+        assert!(init.code_sample.as_deref().unwrap().contains("telegram-web-app.js"));
+        assert_eq!(init.url(), "https://core.telegram.org/bots/webapps#initializing-mini-apps");
+
+        let theme = sections[1].to_item();
+        assert_eq!(theme.kind, "object");
+        assert!(!theme.fields[0].required);
+
+        let validating = &sections[2];
+        assert_eq!(validating.kind(), "guide");
+        assert!(validating.summary.starts_with("To validate data"));
+        assert!(validating.content.contains("• Compute the HMAC-SHA-256 signature."));
+    }
+}
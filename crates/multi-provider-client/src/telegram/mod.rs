@@ -1,4 +1,10 @@
+pub mod changelog;
 mod client;
+pub mod mini_apps;
+pub mod tdlib;
 pub mod types;
 
+pub use changelog::{TelegramRelease, TelegramSpecDiff};
 pub use client::TelegramClient;
+pub use mini_apps::{MiniAppSection, MINI_APPS_TECHNOLOGY};
+pub use tdlib::{parse_td_api, TdlibEntry, TdlibKind, TdlibSpec, TDLIB_TECHNOLOGY};
@@ -0,0 +1,356 @@
+//! TDLib API reference parsed from `td_api.tl`, the TL schema TDLib generates its
+//! bindings from.
+//!
+//! Every object and function is preceded by a `//@description` comment with one
+//! `@field` tag per parameter; abstract classes are declared by a bare
+//! `//@class Name @description ...` comment. Functions follow the `---functions---` line.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::types::{TelegramItem, TelegramItemField};
+
+/// Technology identifier for TDLib, alongside `telegram:methods` and `telegram:types`
+pub const TDLIB_TECHNOLOGY: &str = "telegram:tdlib";
+
+pub(crate) const TD_API_URL: &str =
+    "https://raw.githubusercontent.com/tdlib/td/master/td/generate/scheme/td_api.tl";
+
+const TDLIB_DOCS_BASE: &str = "https://core.telegram.org/tdlib/docs";
+
+/// A tag starting a section of a doc comment, e.g. `@description` or `@chat_id`
+static TAG_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|\s)@([a-z_][a-z0-9_]*)").unwrap());
+
+/// The parsed TDLib schema
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TdlibSpec {
+    pub entries: Vec<TdlibEntry>,
+}
+
+/// What a schema entry declares
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TdlibKind {
+    /// Abstract class whose constructors are objects
+    Class,
+    /// Constructor of a class, e.g. `messageText`
+    Object,
+    /// Request sent to TDLib, e.g. `sendMessage`
+    Function,
+}
+
+impl TdlibKind {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Class => "class",
+            Self::Object => "object",
+            Self::Function => "function",
+        }
+    }
+}
+
+/// One class, object or function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TdlibEntry {
+    pub name: String,
+    pub kind: TdlibKind,
+    pub description: String,
+    #[serde(default)]
+    pub fields: Vec<TdlibField>,
+    /// The class an object belongs to or the type a function returns; empty for classes
+    #[serde(default)]
+    pub result: String,
+}
+
+/// A parameter of an object or function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TdlibField {
+    pub name: String,
+    pub type_name: String,
+    pub description: String,
+}
+
+impl TdlibField {
+    /// TDLib marks optional fields in prose ("may be null", "pass null to ...")
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        let description = self.description.to_lowercase();
+        description.contains("may be null") || description.contains("pass null")
+    }
+}
+
+impl TdlibEntry {
+    /// Reference page for the generated C++ class, e.g. `classtd_1_1td__api_1_1send__message.html`
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!(
+            "{TDLIB_DOCS_BASE}/classtd_1_1td__api_1_1{}.html",
+            snake_case(&self.name).replace('_', "__")
+        )
+    }
+}
+
+impl TdlibSpec {
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&TdlibEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .or_else(|| self.entries.iter().find(|entry| entry.name.eq_ignore_ascii_case(name)))
+    }
+
+    /// Objects that construct `class`
+    pub fn constructors<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a TdlibEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.kind == TdlibKind::Object && entry.result == class)
+    }
+
+    /// The entry as a Telegram item: functions return their result type, objects are
+    /// subtypes of their class and classes list their constructors
+    #[must_use]
+    pub fn item(&self, entry: &TdlibEntry) -> TelegramItem {
+        let subtype_of = match entry.kind {
+            TdlibKind::Object if !entry.result.eq_ignore_ascii_case(&entry.name) => {
+                vec![entry.result.clone()]
+            }
+            _ => Vec::new(),
+        };
+        let subtypes = match entry.kind {
+            TdlibKind::Class => self.constructors(&entry.name).map(|object| object.name.clone()).collect(),
+            _ => Vec::new(),
+        };
+        TelegramItem {
+            name: entry.name.clone(),
+            description: entry.description.clone(),
+            kind: entry.kind.as_str().to_string(),
+            href: entry.url(),
+            fields: entry
+                .fields
+                .iter()
+                .map(|field| TelegramItemField {
+                    name: field.name.clone(),
+                    types: vec![field.type_name.clone()],
+                    required: !field.is_optional(),
+                    description: field.description.clone(),
+                })
+                .collect(),
+            returns: (entry.kind == TdlibKind::Function).then(|| vec![entry.result.clone()]),
+            subtypes,
+            subtype_of,
+        }
+    }
+}
+
+/// Parse `td_api.tl` into classes, objects and functions. Built-in types without a
+/// description (`int32`, `vector`, ...) are skipped.
+#[must_use]
+pub fn parse_td_api(schema: &str) -> TdlibSpec {
+    let mut entries = Vec::new();
+    let mut comment = String::new();
+    let mut functions = false;
+
+    for line in schema.lines().map(str::trim) {
+        if line == "---functions---" {
+            functions = true;
+            comment.clear();
+        } else if let Some(text) = line.strip_prefix("//") {
+            // A class declaration directly followed by the next entry's comment
+            if text.starts_with("@description") {
+                if let Some(class) = parse_class(&comment) {
+                    entries.push(class);
+                    comment.clear();
+                }
+            }
+            let text = text.strip_prefix('-').unwrap_or(text);
+            comment.push(' ');
+            comment.push_str(text.trim());
+        } else if line.is_empty() {
+            if let Some(class) = parse_class(&comment) {
+                entries.push(class);
+            }
+            comment.clear();
+        } else {
+            if let Some(entry) = parse_definition(line, &comment, functions) {
+                entries.push(entry);
+            }
+            comment.clear();
+        }
+    }
+    if let Some(class) = parse_class(&comment) {
+        entries.push(class);
+    }
+
+    TdlibSpec { entries }
+}
+
+/// `//@class Name @description ...` blocks that declare an abstract class
+fn parse_class(comment: &str) -> Option<TdlibEntry> {
+    let tags = split_tags(comment, &[]);
+    let name = tags.iter().find(|(tag, _)| tag == "class")?.1.clone();
+    let description = tags
+        .into_iter()
+        .find(|(tag, _)| tag == "description")
+        .map(|(_, text)| text)
+        .unwrap_or_default();
+    Some(TdlibEntry { name, kind: TdlibKind::Class, description, fields: Vec::new(), result: String::new() })
+}
+
+/// `name field:type ... = Result;` with its doc comment
+fn parse_definition(line: &str, comment: &str, function: bool) -> Option<TdlibEntry> {
+    let (lhs, result) = line.trim_end_matches(';').split_once('=')?;
+    let mut tokens = lhs.split_whitespace();
+    let name = tokens.next()?.to_string();
+    let params: Vec<(&str, &str)> = tokens.filter_map(|token| token.split_once(':')).collect();
+
+    let field_names: Vec<&str> = params.iter().map(|(field, _)| *field).collect();
+    let tags = split_tags(comment, &field_names);
+    let description = tags
+        .iter()
+        .find(|(tag, _)| tag == "description")
+        .map(|(_, text)| text.clone())?;
+
+    let fields = params
+        .iter()
+        .map(|(field, type_name)| {
+            // `@description` is taken by the entry itself, so a field of that name is `@param_description`
+            let tag_name = if *field == "description" { "param_description" } else { field };
+            TdlibField {
+                name: (*field).to_string(),
+                type_name: (*type_name).to_string(),
+                description: tags
+                    .iter()
+                    .find(|(tag, _)| tag == tag_name)
+                    .map(|(_, text)| text.clone())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    Some(TdlibEntry {
+        name,
+        kind: if function { TdlibKind::Function } else { TdlibKind::Object },
+        description,
+        fields,
+        result: result.trim().to_string(),
+    })
+}
+
+/// Split a doc comment into `(tag, text)` pairs. Only `class`, `description` and the
+/// given field names start a new tag, so an `@` inside prose stays part of the text.
+fn split_tags(comment: &str, fields: &[&str]) -> Vec<(String, String)> {
+    let starts: Vec<(usize, usize, &str)> = TAG_RE
+        .captures_iter(comment)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            let tag = caps.get(1)?.as_str();
+            let known = matches!(tag, "class" | "description" | "param_description") || fields.contains(&tag);
+            known.then(|| (whole.start(), whole.end(), tag))
+        })
+        .collect();
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(index, (_, end, tag))| {
+            let stop = starts.get(index + 1).map_or(comment.len(), |(start, _, _)| *start);
+            ((*tag).to_string(), comment[*end..stop].trim().to_string())
+        })
+        .collect()
+}
+
+/// `sendMessage` -> `send_message`, the name of the generated C++ class
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r"
+double ? = Double;
+int53 = Int53;
+vector {t:Type} # [ t ] = Vector t;
+
+//@description An object of this type can be returned on every function call, in case of an error
+//@code Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way
+//@message Error message; subject to future changes
+error code:int32 message:string = Error;
+
+//@class MessageContent @description Contains the content of a message
+//@description A text message @text Text of the message
+//@link_preview A link preview attached to the message; may be null
+messageText text:formattedText link_preview:linkPreview = MessageContent;
+
+//@description Describes a chat invite link @invite_link Chat invite link
+//@param_description Description of the link, e.g. for @username mentions
+chatInviteLink invite_link:string description:string = ChatInviteLink;
+
+---functions---
+
+//@description Sends a message. Returns the sent message
+//@chat_id Target chat
+//@input_message_content The content of the message to be sent
+sendMessage chat_id:int53 input_message_content:InputMessageContent = Message;
+";
+
+    #[test]
+    fn test_parse_td_api() {
+        let spec = parse_td_api(SCHEMA);
+        let names: Vec<&str> = spec.entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["error", "MessageContent", "messageText", "chatInviteLink", "sendMessage"]);
+
+        let send = spec.get("sendMessage").unwrap();
+        assert_eq!(send.kind, TdlibKind::Function);
+        assert_eq!(send.result, "Message");
+        assert_eq!(send.description, "Sends a message. Returns the sent message");
+        assert_eq!(send.fields[0].name, "chat_id");
+        assert_eq!(send.fields[0].type_name, "int53");
+        assert_eq!(send.fields[0].description, "Target chat");
+
+        let error = spec.get("error").unwrap();
+        assert!(error.fields[0].description.contains("406"));
+
+        let link = spec.get("chatInviteLink").unwrap();
+        assert_eq!(link.fields[1].description, "Description of the link, e.g. for @username mentions");
+
+        let class = spec.get("MessageContent").unwrap();
+        assert_eq!(class.kind, TdlibKind::Class);
+        assert_eq!(class.description, "Contains the content of a message");
+    }
+
+    #[test]
+    fn test_tdlib_item() {
+        let spec = parse_td_api(SCHEMA);
+
+        let class = spec.item(spec.get("MessageContent").unwrap());
+        assert_eq!(class.kind, "class");
+        assert_eq!(class.subtypes, ["messageText"]);
+
+        let text = spec.item(spec.get("messageText").unwrap());
+        assert_eq!(text.subtype_of, ["MessageContent"]);
+        assert!(text.fields[0].required);
+        assert!(!text.fields[1].required);
+
+        let error = spec.item(spec.get("error").unwrap());
+        assert!(error.subtype_of.is_empty());
+
+        let send = spec.item(spec.get("sendMessage").unwrap());
+        assert_eq!(send.returns, Some(vec!["Message".to_string()]));
+        assert_eq!(send.href, "https://core.telegram.org/tdlib/docs/classtd_1_1td__api_1_1send__message.html");
+    }
+}
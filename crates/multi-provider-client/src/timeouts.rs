@@ -0,0 +1,72 @@
+//! Process-wide HTTP timeouts per provider, and the soft deadline for federated calls.
+//!
+//! Provider clients read their timeouts when they are constructed, so overrides
+//! must be registered with [`set_timeouts`] before building [`crate::ProviderClients`].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use docs_mcp_client::timeouts::HttpTimeouts;
+use once_cell::sync::Lazy;
+
+use crate::types::ProviderType;
+
+/// Default time federated calls wait before returning the providers that have answered.
+pub const DEFAULT_FEDERATED_DEADLINE: Duration = Duration::from_millis(DEFAULT_FEDERATED_DEADLINE_MS);
+const DEFAULT_FEDERATED_DEADLINE_MS: u64 = 8_000;
+
+static FEDERATED_DEADLINE_MS: AtomicU64 = AtomicU64::new(DEFAULT_FEDERATED_DEADLINE_MS);
+
+static OVERRIDES: Lazy<RwLock<HashMap<ProviderType, HttpTimeouts>>> = Lazy::new(RwLock::default);
+
+/// Override the timeouts used by `provider`'s client.
+pub fn set_timeouts(provider: ProviderType, timeouts: HttpTimeouts) {
+    OVERRIDES
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(provider, timeouts);
+}
+
+/// Timeouts for `provider`: its override, or the built-in default.
+pub fn timeouts_for(provider: ProviderType) -> HttpTimeouts {
+    let overrides = OVERRIDES.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+    overrides.get(&provider).copied().unwrap_or_else(|| default_timeouts(provider))
+}
+
+/// Change how long federated calls wait for slow providers.
+pub fn set_federated_deadline(deadline: Duration) {
+    let millis = u64::try_from(deadline.as_millis()).unwrap_or(u64::MAX);
+    FEDERATED_DEADLINE_MS.store(millis, Ordering::Relaxed);
+}
+
+/// How long federated calls wait before reporting the remaining providers as timed out.
+pub fn federated_deadline() -> Duration {
+    Duration::from_millis(FEDERATED_DEADLINE_MS.load(Ordering::Relaxed))
+}
+
+fn default_timeouts(provider: ProviderType) -> HttpTimeouts {
+    match provider {
+        ProviderType::Apple => HttpTimeouts::APPLE,
+        _ => HttpTimeouts::PROVIDER,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_apply_per_provider() {
+        assert_eq!(timeouts_for(ProviderType::Apple), HttpTimeouts::APPLE);
+
+        let slow = HttpTimeouts {
+            connect: Duration::from_secs(20),
+            read: Duration::from_secs(90),
+        };
+        set_timeouts(ProviderType::Bitcoind, slow);
+        assert_eq!(timeouts_for(ProviderType::Bitcoind), slow);
+        assert_eq!(timeouts_for(ProviderType::Cuda), HttpTimeouts::PROVIDER);
+    }
+}
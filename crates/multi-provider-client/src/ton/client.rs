@@ -1,24 +1,81 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
+use std::sync::{Arc, RwLock};
 
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use reqwest::Client;
+use time::OffsetDateTime;
 use tokio::sync::Mutex;
+use tokio::task::JoinSet;
 use tracing::{debug, instrument};
 
+use super::exit_codes::{exit_codes, parse_exit_code, EXIT_CODES_TECHNOLOGY};
+use super::sync::{
+    merge_article, parse_article_page, ArticlePage, ARTICLE_SYNC_MAX_AGE, ARTICLE_SYNC_RETRY, SYNCED_ARTICLES_KEY,
+};
+use super::tlb::{tlb_schemas, TLB_TECHNOLOGY};
 use super::types::{
     OpenApiSpec, TonCategory, TonCodeExample, TonDocArticle, TonDocSource, TonEndpoint,
     TonEndpointSummary, TonResultType, TonSearchResult, TonSecurityCategory, TonSecurityPattern,
-    TonTechnology,
+    TonExitCode, TonSpecStatus, TonTechnology, TonTlbSchema,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
-
-const OPENAPI_URL: &str =
-    "https://raw.githubusercontent.com/tonkeeper/opentonapi/master/api/openapi.yml";
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::credentials::github_get;
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
+
+const OPENAPI_REPO_URL: &str = "https://raw.githubusercontent.com/tonkeeper/opentonapi";
+const DEFAULT_SPEC_REF: &str = "master";
 const CACHE_KEY: &str = "ton_openapi_spec";
 
+static SPEC_REF: RwLock<Option<String>> = RwLock::new(None);
+
+/// Pin the tonkeeper OpenAPI spec to a git commit, tag or branch; `None` follows `master`.
+///
+/// Like the other network settings this is process-wide. Invalid references are ignored
+/// with a warning.
+pub fn set_spec_ref(reference: Option<String>) {
+    let reference = reference.map(|reference| reference.trim().to_string()).filter(|reference| {
+        let valid = is_valid_spec_ref(reference);
+        if !valid {
+            tracing::warn!(reference = %reference, "ignoring invalid TON spec reference");
+        }
+        valid
+    });
+    *SPEC_REF.write().unwrap_or_else(std::sync::PoisonError::into_inner) = reference;
+}
+
+/// The pinned spec reference, if any
+pub fn pinned_spec_ref() -> Option<String> {
+    SPEC_REF.read().unwrap_or_else(std::sync::PoisonError::into_inner).clone()
+}
+
+fn is_valid_spec_ref(reference: &str) -> bool {
+    !reference.is_empty()
+        && reference.len() <= 100
+        && !reference.contains("..")
+        && reference.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '/'))
+}
+
+fn spec_url(reference: &str) -> String {
+    format!("{OPENAPI_REPO_URL}/{reference}/api/openapi.yml")
+}
+
+/// The default branch keeps the original key so existing caches stay valid
+fn spec_cache_key(reference: &str) -> String {
+    if reference == DEFAULT_SPEC_REF {
+        format!("{CACHE_KEY}.json")
+    } else {
+        format!("{CACHE_KEY}@{}.json", reference.replace('/', "_"))
+    }
+}
+
 fn tokenize_query(query: &str) -> Vec<String> {
     let mut terms: Vec<String> = query
         .split(|c: char| !c.is_alphanumeric())
@@ -30,12 +87,24 @@ fn tokenize_query(query: &str) -> Vec<String> {
     terms
 }
 
+/// Documentation articles kept in memory between searches
+#[derive(Debug, Default)]
+struct ArticleState {
+    /// Articles and when they were synced; `None` until first searched
+    articles: Option<(OffsetDateTime, Arc<Vec<TonDocArticle>>)>,
+    /// Time of the last sync attempt
+    last_attempt: Option<OffsetDateTime>,
+    /// Whether a background sync is running
+    syncing: bool,
+}
+
 #[derive(Debug)]
 pub struct TonClient {
     http: Client,
-    disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    disk_cache: Arc<DiskCache>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     spec_lock: Mutex<()>,
+    articles: Arc<Mutex<ArticleState>>,
     cache_dir: PathBuf,
 }
 
@@ -56,18 +125,18 @@ impl TonClient {
             tracing::warn!(error = %e, "Failed to create TON cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::TON))
             .user_agent("MultiDocsMCP/1.0")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
 
         Self {
             http,
-            disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::minutes(30)),
+            disk_cache: Arc::new(DiskCache::new(&cache_dir)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::TON, memory_ttl(ProviderType::TON)),
             spec_lock: Mutex::new(()),
+            articles: Arc::new(Mutex::new(ArticleState::default())),
             cache_dir,
         }
     }
@@ -75,7 +144,8 @@ impl TonClient {
     /// Fetch the TON API OpenAPI specification
     #[instrument(name = "ton_client.get_spec", skip(self))]
     async fn get_spec(&self) -> Result<OpenApiSpec> {
-        let cache_key = format!("{CACHE_KEY}.json");
+        let reference = pinned_spec_ref().unwrap_or_else(|| DEFAULT_SPEC_REF.to_string());
+        let cache_key = spec_cache_key(&reference);
 
         // Check disk cache (we store as JSON after parsing YAML)
         if let Ok(Some(entry)) = self.disk_cache.load::<OpenApiSpec>(&cache_key).await {
@@ -92,15 +162,23 @@ impl TonClient {
             return Ok(entry.value);
         }
 
+        self.fetch_spec(&reference, &cache_key).await
+    }
+
+    /// Download the spec at `reference` and cache it under `cache_key`
+    async fn fetch_spec(&self, reference: &str, cache_key: &str) -> Result<OpenApiSpec> {
         // Fetch from remote (YAML format)
-        debug!(url = OPENAPI_URL, "Fetching TON OpenAPI spec (YAML)");
-        let response = self
-            .http
-            .get(OPENAPI_URL)
-            .send()
+        let url = spec_url(reference);
+        debug!(url = %url, "Fetching TON OpenAPI spec (YAML)");
+        ensure_online(&url)?;
+        let response = github_get(&self.http, &url)
+            .send_with_retry()
             .await
             .context("Failed to fetch TON OpenAPI spec")?;
 
+        if response.status() == reqwest::StatusCode::NOT_FOUND && reference != DEFAULT_SPEC_REF {
+            anyhow::bail!("TON OpenAPI spec not found at '{reference}'; check DOCSMCP_TON_SPEC_REF");
+        }
         if !response.status().is_success() {
             anyhow::bail!("TON OpenAPI spec fetch failed: {}", response.status());
         }
@@ -117,11 +195,33 @@ impl TonClient {
         })?;
 
         // Store in cache (as JSON for faster subsequent loads)
-        self.disk_cache.store(&cache_key, spec.clone()).await?;
+        self.disk_cache.store(cache_key, spec.clone()).await?;
 
         Ok(spec)
     }
 
+    /// Re-download the spec at the configured reference, replacing the cached copy
+    #[instrument(name = "ton_client.refresh_spec", skip(self))]
+    pub async fn refresh_spec(&self) -> Result<TonSpecStatus> {
+        let reference = pinned_spec_ref().unwrap_or_else(|| DEFAULT_SPEC_REF.to_string());
+        let cache_key = spec_cache_key(&reference);
+
+        let _lock = self.spec_lock.lock().await;
+        let spec = self.fetch_spec(&reference, &cache_key).await?;
+        Ok(spec_status(&spec, reference, OffsetDateTime::now_utc()))
+    }
+
+    /// The cached spec for the configured reference, without going to the network
+    pub async fn spec_status(&self) -> Result<Option<TonSpecStatus>> {
+        let reference = pinned_spec_ref().unwrap_or_else(|| DEFAULT_SPEC_REF.to_string());
+        let cache_key = spec_cache_key(&reference);
+        Ok(self
+            .disk_cache
+            .load::<OpenApiSpec>(&cache_key)
+            .await?
+            .map(|entry| spec_status(&entry.value, reference, entry.stored_at)))
+    }
+
     /// Get available technologies (API categories by tag + additional documentation sections)
     #[instrument(name = "ton_client.get_technologies", skip(self))]
     pub async fn get_technologies(&self) -> Result<Vec<TonTechnology>> {
@@ -238,6 +338,22 @@ impl TonClient {
                 endpoint_count: 10,
                 source: TonDocSource::TonDocs,
             },
+            TonTechnology {
+                identifier: TLB_TECHNOLOGY.to_string(),
+                title: "TL-B Schemas".to_string(),
+                description: "Serialization layouts of jetton, NFT and wallet messages".to_string(),
+                url: TonDocSource::Tlb.base_url().to_string(),
+                endpoint_count: self.get_tlb_schemas().len(),
+                source: TonDocSource::Tlb,
+            },
+            TonTechnology {
+                identifier: EXIT_CODES_TECHNOLOGY.to_string(),
+                title: "Exit Codes".to_string(),
+                description: "TVM compute and action phase exit codes and common contract error codes".to_string(),
+                url: "https://docs.ton.org/v3/documentation/tvm/tvm-exit-codes".to_string(),
+                endpoint_count: self.get_exit_codes().len(),
+                source: TonDocSource::Tvm,
+            },
             TonTechnology {
                 identifier: "ton:wallets".to_string(),
                 title: "TON Wallets".to_string(),
@@ -407,9 +523,16 @@ impl TonClient {
         results.extend(security_results);
 
         // Search documentation articles
-        let doc_results = self.search_documentation(&query_lower);
+        let articles = self.get_synced_articles().await;
+        let doc_results = self.search_documentation(&query_lower, &articles);
         results.extend(doc_results);
 
+        // Search TL-B schemas
+        results.extend(self.search_tlb_schemas(&query_lower));
+
+        // Search exit codes
+        results.extend(self.search_exit_codes(&query_lower));
+
         // Sort by score
         results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
 
@@ -609,10 +732,9 @@ impl TonClient {
         results
     }
 
-    /// Search embedded documentation articles
-    fn search_documentation(&self, query: &str) -> Vec<TonSearchResult> {
+    /// Search documentation articles
+    fn search_documentation(&self, query: &str, articles: &[TonDocArticle]) -> Vec<TonSearchResult> {
         let terms = tokenize_query(query);
-        let articles = self.get_documentation_articles();
         let mut results = Vec::new();
 
         if terms.is_empty() {
@@ -720,6 +842,185 @@ impl TonClient {
         results
     }
 
+    /// Search TL-B schemas by name, op code, standard and field names
+    pub fn search_tlb_schemas(&self, query: &str) -> Vec<TonSearchResult> {
+        let query = query.to_lowercase();
+        let terms = tokenize_query(&query);
+        let mut results = Vec::new();
+
+        if terms.is_empty() {
+            return results;
+        }
+
+        for schema in self.get_tlb_schemas() {
+            let id_lower = schema.id.replace('-', " ");
+            let standard_lower = schema.standard.to_lowercase();
+            let description_lower = schema.description.to_lowercase();
+            let op_code = schema.op_code.as_deref().unwrap_or_default();
+            let tags_lower: Vec<String> = schema.tags.iter().map(|tag| tag.to_lowercase()).collect();
+
+            let mut score = 0.0;
+            let mut matched_terms = 0u8;
+
+            for term in &terms {
+                let mut matched = false;
+
+                // Op code match identifies the message exactly
+                if !op_code.is_empty() && (term == op_code || op_code.trim_start_matches("0x") == term) {
+                    score += 10.0;
+                    matched = true;
+                }
+
+                // Identifier/name match (highest weight)
+                if id_lower.split(' ').any(|word| word == term) || schema.name == *term {
+                    score += 4.0;
+                    matched = true;
+                }
+
+                // Tag match
+                if tags_lower.iter().any(|tag| tag.contains(term.as_str())) {
+                    score += 3.0;
+                    matched = true;
+                }
+
+                // Standard match (e.g., "tep74", "wallet v4")
+                if standard_lower.replace('-', "").contains(term.as_str()) {
+                    score += 2.0;
+                    matched = true;
+                }
+
+                // Field names
+                if schema.fields.iter().any(|field| field.name == *term) {
+                    score += 1.5;
+                    matched = true;
+                }
+
+                if description_lower.contains(term.as_str()) {
+                    score += 0.75;
+                    matched = true;
+                }
+
+                if matched {
+                    matched_terms = matched_terms.saturating_add(1);
+                }
+            }
+
+            let matched_terms_usize = usize::from(matched_terms);
+
+            if matched_terms_usize == terms.len() && matched_terms > 1 {
+                score += 1.5;
+            } else if matched_terms > 1 {
+                score += f32::from(matched_terms) * 0.5;
+            }
+
+            if score > 0.0 {
+                results.push(TonSearchResult {
+                    id: schema.id.clone(),
+                    title: format!("{} ({})", schema.name, schema.standard),
+                    description: schema.description.clone(),
+                    source: TonDocSource::Tlb,
+                    url: schema.url.clone(),
+                    result_type: TonResultType::TlbSchema,
+                    score,
+                    code_examples: vec![TonCodeExample {
+                        language: "tlb".to_string(),
+                        code: schema.tlb.clone(),
+                        description: Some(format!("{} TL-B", schema.name)),
+                        is_complete: true,
+                    }],
+                });
+            }
+        }
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        results
+    }
+
+    /// Get embedded TL-B schemas of standard messages
+    pub fn get_tlb_schemas(&self) -> Vec<TonTlbSchema> {
+        tlb_schemas()
+    }
+
+    /// Get a TL-B schema by ID (e.g., "jetton-transfer") or op code (e.g., "0x0f8a7ea5")
+    pub fn get_tlb_schema(&self, id: &str) -> Option<TonTlbSchema> {
+        let id = id.trim().to_lowercase();
+        let op_code = format!("0x{}", id.trim_start_matches("0x"));
+        let schemas = self.get_tlb_schemas();
+        let position = schemas
+            .iter()
+            .position(|schema| schema.id == id)
+            .or_else(|| schemas.iter().position(|schema| schema.op_code.as_deref() == Some(op_code.as_str())))?;
+        schemas.into_iter().nth(position)
+    }
+
+    /// Search exit codes by number (decimal, negative or hex) and by name, meaning and causes
+    pub fn search_exit_codes(&self, query: &str) -> Vec<TonSearchResult> {
+        let query = query.to_lowercase();
+        let numbers: Vec<i32> = query
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .filter_map(parse_exit_code)
+            .collect();
+        // Words every exit code query contains say nothing about which code is meant
+        let terms: Vec<String> = tokenize_query(&query)
+            .into_iter()
+            .filter(|term| {
+                term.parse::<i64>().is_err()
+                    && !matches!(term.as_str(), "exit" | "code" | "codes" | "ton" | "tvm" | "error" | "errors")
+            })
+            .collect();
+        let mut results = Vec::new();
+
+        for exit_code in self.get_exit_codes() {
+            let name_lower = exit_code.name.to_lowercase();
+            let description_lower = exit_code.description.to_lowercase();
+            let causes_lower = exit_code.causes.join(" ").to_lowercase();
+
+            let mut score = 0.0;
+
+            // A number in the query identifies the code; -14 and 14 are easily confused
+            if numbers.contains(&exit_code.code) {
+                score += 10.0;
+            } else if numbers.iter().any(|number| number.abs() == exit_code.code.abs()) {
+                score += 5.0;
+            }
+
+            for term in &terms {
+                if name_lower.split_whitespace().any(|word| word == term) {
+                    score += 3.0;
+                } else if name_lower.contains(term.as_str()) {
+                    score += 1.5;
+                }
+                if exit_code.phase.to_lowercase().contains(term.as_str()) {
+                    score += 1.0;
+                }
+                if description_lower.contains(term.as_str()) {
+                    score += 0.75;
+                }
+                if causes_lower.contains(term.as_str()) {
+                    score += 0.5;
+                }
+            }
+
+            if score > 0.0 {
+                results.push(exit_code_result(&exit_code, score));
+            }
+        }
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        results
+    }
+
+    /// Get embedded exit code reference
+    pub fn get_exit_codes(&self) -> Vec<TonExitCode> {
+        exit_codes()
+    }
+
+    /// Get an exit code by number, e.g. "37", "-14" or "0xffff"
+    pub fn get_exit_code(&self, code: &str) -> Option<TonExitCode> {
+        let code = parse_exit_code(code.trim().trim_start_matches("exit-code-"))?;
+        self.get_exit_codes().into_iter().find(|exit_code| exit_code.code == code)
+    }
+
     /// Get embedded security patterns (built-in knowledge base)
     pub fn get_security_patterns(&self) -> Vec<TonSecurityPattern> {
         vec![
@@ -1056,6 +1357,42 @@ impl TonClient {
         ]
     }
 
+    /// Documentation articles refreshed from their pages, falling back to the embedded
+    /// copies until a sync succeeds
+    ///
+    /// Never waits on the network: once the in-memory copy is a week old a background task
+    /// syncs the pages and the current copy is served until it finishes.
+    pub async fn get_synced_articles(&self) -> Arc<Vec<TonDocArticle>> {
+        let mut state = self.articles.lock().await;
+        let (synced_at, articles) = match &state.articles {
+            Some(loaded) => loaded.clone(),
+            None => {
+                // First search: start from the last sync on disk
+                let loaded = match self.disk_cache.load::<Vec<TonDocArticle>>(SYNCED_ARTICLES_KEY).await {
+                    Ok(Some(entry)) => (entry.stored_at, Arc::new(entry.value)),
+                    _ => (OffsetDateTime::UNIX_EPOCH, Arc::new(self.get_documentation_articles())),
+                };
+                state.articles = Some(loaded.clone());
+                loaded
+            }
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let expired = now - synced_at > ARTICLE_SYNC_MAX_AGE;
+        let retry_due = !state.last_attempt.is_some_and(|attempt| now - attempt < ARTICLE_SYNC_RETRY);
+        if expired && retry_due && !state.syncing {
+            state.syncing = true;
+            state.last_attempt = Some(now);
+            tokio::spawn(sync_articles(
+                self.http.clone(),
+                Arc::clone(&self.disk_cache),
+                Arc::clone(&self.articles),
+                self.get_documentation_articles(),
+            ));
+        }
+        articles
+    }
+
     /// Get embedded documentation articles
     fn get_documentation_articles(&self) -> Vec<TonDocArticle> {
         vec![
@@ -1876,6 +2213,99 @@ impl TonClient {
     }
 }
 
+fn spec_status(spec: &OpenApiSpec, reference: String, fetched_at: OffsetDateTime) -> TonSpecStatus {
+    TonSpecStatus {
+        url: spec_url(&reference),
+        pinned: reference != DEFAULT_SPEC_REF,
+        reference,
+        version: spec.info.version.clone(),
+        endpoint_count: spec.paths.values().map(|item| item.operations().len()).sum(),
+        fetched_at,
+    }
+}
+
+/// Sync the articles in the background and publish them to `state`
+async fn sync_articles(
+    http: Client,
+    disk_cache: Arc<DiskCache>,
+    state: Arc<Mutex<ArticleState>>,
+    embedded: Vec<TonDocArticle>,
+) {
+    let result = fetch_articles(&http, &disk_cache, embedded).await;
+    let mut state = state.lock().await;
+    state.syncing = false;
+    match result {
+        Ok(articles) => state.articles = Some((OffsetDateTime::now_utc(), Arc::new(articles))),
+        Err(e) => debug!(error = %e, "TON article sync failed, keeping cached or embedded articles"),
+    }
+}
+
+/// Fetch every article page and store the merged articles
+#[instrument(name = "ton_client.sync_articles", skip_all)]
+async fn fetch_articles(http: &Client, disk_cache: &DiskCache, embedded: Vec<TonDocArticle>) -> Result<Vec<TonDocArticle>> {
+    ensure_online(TonDocSource::TonDocs.base_url())?;
+
+    let mut tasks = JoinSet::new();
+    for (index, article) in embedded.iter().enumerate() {
+        let http = http.clone();
+        let url = article.url.clone();
+        tasks.spawn(async move { (index, fetch_article_page(&http, &url).await) });
+    }
+
+    let mut articles = embedded.clone();
+    let mut synced = 0usize;
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, page)) = joined else {
+            continue;
+        };
+        match page {
+            Ok(Some(page)) => {
+                articles[index] = merge_article(&embedded[index], page);
+                synced += 1;
+            }
+            Ok(None) => debug!(url = %embedded[index].url, "TON article page has no content"),
+            Err(e) => debug!(url = %embedded[index].url, error = %e, "TON article fetch failed"),
+        }
+    }
+
+    if synced == 0 {
+        anyhow::bail!("No TON documentation articles could be fetched");
+    }
+    debug!(synced, total = articles.len(), "TON documentation articles synced");
+    disk_cache.store(SYNCED_ARTICLES_KEY, articles.clone()).await?;
+    Ok(articles)
+}
+
+/// Fetch and parse one article page
+async fn fetch_article_page(http: &Client, url: &str) -> Result<Option<ArticlePage>> {
+    ensure_online(url)?;
+    let response = http.get(url).send_with_retry().await.context("Failed to fetch TON article")?;
+    if !response.status().is_success() {
+        anyhow::bail!("TON article fetch failed: {}", response.status());
+    }
+    let html = response.text().await.context("Failed to read TON article")?;
+    Ok(parse_article_page(&html))
+}
+
+/// An exit code as a search result; causes are folded into the description
+fn exit_code_result(exit_code: &TonExitCode, score: f32) -> TonSearchResult {
+    let mut description = format!("{} ({}) {}", exit_code.name, exit_code.phase, exit_code.description);
+    for cause in &exit_code.causes {
+        description.push_str("\n• ");
+        description.push_str(cause);
+    }
+    TonSearchResult {
+        id: format!("exit-code-{}", exit_code.code),
+        title: format!("Exit code {}: {}", exit_code.code, exit_code.name),
+        description,
+        source: TonDocSource::Tvm,
+        url: exit_code.url.clone(),
+        result_type: TonResultType::ExitCode,
+        score,
+        code_examples: Vec::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1903,6 +2333,71 @@ mod tests {
         assert!(!articles.is_empty(), "Documentation articles should exist");
     }
 
+    #[tokio::test]
+    async fn stale_articles_are_served_without_waiting_for_a_sync() {
+        let client = TonClient::new();
+        let old_copy = Arc::new(vec![client.get_documentation_articles().remove(0)]);
+        {
+            let mut state = client.articles.lock().await;
+            state.articles = Some((OffsetDateTime::UNIX_EPOCH, Arc::clone(&old_copy)));
+            state.syncing = true;
+        }
+
+        let articles = client.get_synced_articles().await;
+        assert!(Arc::ptr_eq(&articles, &old_copy));
+        assert!(client.articles.lock().await.last_attempt.is_none(), "a running sync is not started again");
+    }
+
+    #[test]
+    fn test_tlb_schema_lookup() {
+        let client = TonClient::new();
+        assert_eq!(client.get_tlb_schema("nft-transfer").unwrap().op_code.as_deref(), Some("0x5fcc3d14"));
+        assert_eq!(client.get_tlb_schema("0x0F8A7EA5").unwrap().id, "jetton-transfer");
+        assert!(client.get_tlb_schema("unknown").is_none());
+
+        let results = client.search_tlb_schemas("jetton transfer notification");
+        assert_eq!(results[0].id, "jetton-transfer-notification");
+        assert_eq!(results[0].code_examples[0].language, "tlb");
+
+        let results = client.search_tlb_schemas("0x5fcc3d14");
+        assert_eq!(results[0].id, "nft-transfer");
+    }
+
+    #[test]
+    fn test_spec_ref_cache_keys() {
+        assert_eq!(spec_cache_key("master"), "ton_openapi_spec.json");
+        assert_eq!(spec_cache_key("v2.1.0"), "ton_openapi_spec@v2.1.0.json");
+        assert_eq!(spec_cache_key("release/v2"), "ton_openapi_spec@release_v2.json");
+        assert_eq!(
+            spec_url("3f2c1e9"),
+            "https://raw.githubusercontent.com/tonkeeper/opentonapi/3f2c1e9/api/openapi.yml"
+        );
+
+        assert!(is_valid_spec_ref("3f2c1e9a"));
+        assert!(is_valid_spec_ref("release/v2"));
+        assert!(!is_valid_spec_ref(""));
+        assert!(!is_valid_spec_ref("../master"));
+        assert!(!is_valid_spec_ref("master?token=x"));
+    }
+
+    #[test]
+    fn test_exit_code_lookup() {
+        let client = TonClient::new();
+        assert_eq!(client.get_exit_code("37").unwrap().name, "Not enough Toncoin");
+        assert_eq!(client.get_exit_code("exit-code-0xffff").unwrap().code, 65535);
+        assert!(client.get_exit_code("999").is_none());
+
+        let results = client.search_exit_codes("ton exit code 37");
+        assert_eq!(results[0].id, "exit-code-37");
+        assert!(results[0].description.contains("mode 64"));
+
+        let results = client.search_exit_codes("exit code 0xffff");
+        assert_eq!(results[0].id, "exit-code-65535");
+
+        let results = client.search_exit_codes("tvm out of gas");
+        assert!(results.iter().take(2).any(|result| result.id == "exit-code--14"));
+    }
+
     #[test]
     fn test_additional_technologies() {
         let client = TonClient::new();
@@ -0,0 +1,452 @@
+//! Exit codes reported in a transaction's compute and action phases, plus the codes
+//! standard contracts and Tact reserve for their own errors.
+//!
+//! Contracts may throw any code they like, so one number can mean different things; the
+//! TVM meaning comes first and contract-specific uses are listed as causes.
+
+use super::types::TonExitCode;
+
+/// Technology identifier for exit codes
+pub const EXIT_CODES_TECHNOLOGY: &str = "ton:exit-codes";
+
+const TVM_EXIT_CODES_URL: &str = "https://docs.ton.org/v3/documentation/tvm/tvm-exit-codes";
+const TACT_EXIT_CODES_URL: &str = "https://docs.tact-lang.org/book/exit-codes";
+const JETTON_WALLET_URL: &str = "https://github.com/ton-blockchain/token-contract/blob/main/ft/jetton-wallet.fc";
+
+const COMPUTE: &str = "Compute phase";
+const ACTION: &str = "Action phase";
+const TACT: &str = "Tact runtime";
+const CONVENTION: &str = "Contract convention";
+
+/// Built-in exit code reference, ordered by code
+#[must_use]
+pub fn exit_codes() -> Vec<TonExitCode> {
+    vec![
+        // Compute phase (TVM)
+        exit_code(0, "Success", COMPUTE, "Standard successful execution.", &[], TVM_EXIT_CODES_URL),
+        exit_code(
+            1,
+            "Alternative success",
+            COMPUTE,
+            "Alternative successful execution code; the transaction is still successful.",
+            &["The contract ended with `RET` after a `ONE` left on the stack, or threw 1 itself"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            2,
+            "Stack underflow",
+            COMPUTE,
+            "An instruction needed more values than the stack held.",
+            &[
+                "Hand-written `asm` functions that pop more arguments than they are given",
+                "Calling a get-method with fewer arguments than it expects",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            3,
+            "Stack overflow",
+            COMPUTE,
+            "More than 255 values were pushed onto the stack, or a continuation received more arguments than it accepts.",
+            &["Deep recursion or tuples built on the stack without bound"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            4,
+            "Integer overflow",
+            COMPUTE,
+            "An arithmetic result does not fit into a 257-bit signed integer, or a division by zero.",
+            &["Dividing by a zero amount or supply", "Multiplying two large amounts before dividing"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            5,
+            "Integer out of expected range",
+            COMPUTE,
+            "A value does not fit the bit width it is stored in or an argument is outside the allowed range.",
+            &[
+                "Storing a negative number with `store_uint` or a value wider than the given bit length",
+                "Storing more than 2^120 nanotons with `store_coins`",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            6,
+            "Invalid opcode",
+            COMPUTE,
+            "The instruction is unknown to the TVM version executing the code.",
+            &["Code compiled for a newer TVM than the network runs", "Executing a cell that is data, not code"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            7,
+            "Type check error",
+            COMPUTE,
+            "An instruction received a value of the wrong type, e.g. a cell where an integer was expected.",
+            &["`null` passed where a value is required", "Wrong argument types in a get-method call"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            8,
+            "Cell overflow",
+            COMPUTE,
+            "A builder would hold more than 1023 bits or 4 references.",
+            &["Storing a long string or payload inline instead of in a reference cell"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            9,
+            "Cell underflow",
+            COMPUTE,
+            "A slice was read past its end: more bits or references were loaded than it contains.",
+            &[
+                "Parsing an incoming message body with the wrong layout or missing fields",
+                "Reading persistent storage with a different layout than it was saved with",
+                "Empty message bodies parsed as if they carried an op code",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            10,
+            "Dictionary error",
+            COMPUTE,
+            "A dictionary (hashmap) operation failed, usually because the cell is not a valid dictionary.",
+            &["Key length differs from the one the dictionary was built with"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            11,
+            "Unknown error",
+            COMPUTE,
+            "Reserved for user programs; in practice raised for problems queueing an outgoing message or in get-methods.",
+            &["Sending a message whose mode or layout TVM rejects while building the action list"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            12,
+            "Fatal error",
+            COMPUTE,
+            "Thrown by TVM in situations considered impossible.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            13,
+            "Out of gas",
+            COMPUTE,
+            "The computation ran out of gas. The reported code is -14 so it cannot be faked by `throw(13)`.",
+            &["See -14"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            -14,
+            "Out of gas",
+            COMPUTE,
+            "The compute phase consumed its gas limit. The gas limit is derived from the incoming value, or from \
+             `accept_message` for external messages.",
+            &[
+                "Attaching too little TON to an internal message",
+                "Unbounded loops over dictionaries or message lists",
+                "An external message that did not call `accept_message` within the 10k gas credit",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            14,
+            "Virtualization error",
+            COMPUTE,
+            "Reserved for prunned-branch cells; never thrown in practice.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        // Action phase
+        exit_code(
+            32,
+            "Action list is invalid",
+            ACTION,
+            "The action list in register c5 is malformed.",
+            &["Setting c5 by hand with a wrong layout"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            33,
+            "Action list is too long",
+            ACTION,
+            "The action list has more than 255 actions.",
+            &[
+                "Sending more than 255 messages in one transaction",
+                "Wallet v3/v4: the external message's seqno does not match the stored seqno (compute phase)",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            34,
+            "Action is invalid or not supported",
+            ACTION,
+            "An action could not be applied during the action phase.",
+            &[
+                "Sending a message with an invalid send mode",
+                "Wallet v3/v4: the external message's subwallet_id does not match the wallet (compute phase)",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            35,
+            "Invalid source address in outbound message",
+            ACTION,
+            "The source address of an outgoing message is neither empty nor the contract's own address.",
+            &[
+                "Wallet v3/v4: the external message expired (valid_until in the past) or its signature is invalid \
+                 (compute phase)",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            36,
+            "Invalid destination address in outbound message",
+            ACTION,
+            "The destination address of an outgoing message is invalid or in a workchain that does not exist.",
+            &["Serializing the destination with the wrong layout", "Sending to a non-existent workchain id"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            37,
+            "Not enough Toncoin",
+            ACTION,
+            "The contract does not have enough balance to send the value of an outgoing message plus its fees.",
+            &[
+                "Sending more than the balance with mode 0 instead of mode 64 (carry remaining value) or 128 (carry \
+                 all balance)",
+                "Forwarding an amount that ignores the fees already paid by the compute phase",
+                "Storage fees drained the balance before the message was sent",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            38,
+            "Not enough extra currencies",
+            ACTION,
+            "The contract does not hold enough of an extra currency to send it.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            39,
+            "Outbound message does not fit into a cell",
+            ACTION,
+            "After rewriting (e.g. filling in the source address and fees) the message no longer fits into a cell.",
+            &["Message body stored inline while close to 1023 bits; store it in a reference instead"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            40,
+            "Cannot process a message",
+            ACTION,
+            "Not enough funds to process the message, or the message exceeds size or Merkle depth limits.",
+            &["Very large state_init or body attached to an outgoing message"],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            41,
+            "Library reference is null",
+            ACTION,
+            "A library change action referenced a library that is null.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            42,
+            "Library change action error",
+            ACTION,
+            "A library change action could not be applied.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            43,
+            "Library limits exceeded",
+            ACTION,
+            "The library exceeds the maximum number of cells or the maximum Merkle depth.",
+            &[],
+            TVM_EXIT_CODES_URL,
+        ),
+        exit_code(
+            50,
+            "Account state size exceeded limits",
+            ACTION,
+            "The account state (code and data) would exceed the size limits after the transaction.",
+            &["Growing a dictionary in storage without bound; shard the data into child contracts instead"],
+            TVM_EXIT_CODES_URL,
+        ),
+        // Tact reserves 128-255
+        exit_code(
+            128,
+            "Null reference exception",
+            TACT,
+            "A non-null assertion (`!!`) was applied to a null value.",
+            &[],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            129,
+            "Invalid serialization prefix",
+            TACT,
+            "The prefix (op code) of a message or struct does not match the expected one while parsing.",
+            &["Parsing a message body as the wrong message type"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            130,
+            "Invalid incoming message",
+            TACT,
+            "No receiver handles the incoming message's op code.",
+            &["Sending a message the contract has no `receive` for", "A comment with no matching text receiver"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            131,
+            "Constraints error",
+            TACT,
+            "Reserved by Tact; not thrown by current versions.",
+            &[],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            132,
+            "Access denied",
+            TACT,
+            "`requireOwner()` failed: the sender is not the owner.",
+            &["Wallet v5: signature authentication is disabled"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            133,
+            "Contract stopped",
+            TACT,
+            "A message was sent to a contract stopped with the `Resumable` trait.",
+            &["Wallet v5: the external message's seqno does not match the stored seqno"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            134,
+            "Invalid argument",
+            TACT,
+            "An argument to a standard library function is invalid, e.g. a malformed address string.",
+            &["Wallet v5: the wallet_id in the message does not match the wallet"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            135,
+            "Code of a contract was not found",
+            TACT,
+            "The code of a contract referenced with `initOf` could not be found.",
+            &["Wallet v5: the message signature is invalid"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            136,
+            "Invalid standard address",
+            TACT,
+            "An address is not a valid 267-bit standard address.",
+            &["Wallet v5: the external message expired (valid_until in the past)"],
+            TACT_EXIT_CODES_URL,
+        ),
+        exit_code(
+            138,
+            "Not a basechain address",
+            TACT,
+            "An address is not in the basechain (workchain 0).",
+            &[],
+            TACT_EXIT_CODES_URL,
+        ),
+        // Reference jetton wallet (token-contract)
+        exit_code(
+            705,
+            "Unauthorized transfer",
+            CONVENTION,
+            "Reference jetton wallet: a transfer or burn was not sent by the wallet's owner.",
+            &["Sending the jetton transfer to someone else's jetton wallet instead of your own"],
+            JETTON_WALLET_URL,
+        ),
+        exit_code(
+            706,
+            "Not enough jettons",
+            CONVENTION,
+            "Reference jetton wallet: the balance is lower than the amount being transferred or burned.",
+            &["Amount given in whole jettons instead of the smallest units (decimals)"],
+            JETTON_WALLET_URL,
+        ),
+        exit_code(
+            707,
+            "Invalid internal transfer sender",
+            CONVENTION,
+            "Reference jetton wallet: internal_transfer came from neither the minter nor a jetton wallet of the same \
+             minter.",
+            &[],
+            JETTON_WALLET_URL,
+        ),
+        exit_code(
+            709,
+            "Not enough TON for forwarding",
+            CONVENTION,
+            "Reference jetton wallet: the attached TON does not cover forward_ton_amount plus the forwarding fees.",
+            &["Attaching exactly forward_ton_amount without extra TON for gas and fees"],
+            JETTON_WALLET_URL,
+        ),
+        exit_code(
+            0xffff,
+            "Unknown op code",
+            CONVENTION,
+            "Standard FunC contracts (jettons, NFTs) throw 0xffff when the message's op code is not handled.",
+            &[
+                "Sending a message with an op code the contract does not implement",
+                "Wrong byte order or a missing op code in a hand-built message body",
+            ],
+            TVM_EXIT_CODES_URL,
+        ),
+    ]
+}
+
+fn exit_code(code: i32, name: &str, phase: &str, description: &str, causes: &[&str], url: &str) -> TonExitCode {
+    TonExitCode {
+        code,
+        name: name.to_string(),
+        phase: phase.to_string(),
+        description: description.to_string(),
+        causes: causes.iter().map(ToString::to_string).collect(),
+        url: url.to_string(),
+    }
+}
+
+/// Parse an exit code as written in explorers and code: `37`, `-14`, `0xffff`
+#[must_use]
+pub fn parse_exit_code(value: &str) -> Option<i32> {
+    let value = value.trim();
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex) => i32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes_are_unique_and_sorted() {
+        let codes: Vec<i32> = exit_codes().iter().map(|code| code.code).collect();
+        let mut sorted = codes.clone();
+        sorted.sort_by_key(|code| (code.abs(), *code));
+        sorted.dedup();
+        assert_eq!(codes, sorted);
+    }
+
+    #[test]
+    fn test_parse_exit_code() {
+        assert_eq!(parse_exit_code("37"), Some(37));
+        assert_eq!(parse_exit_code("-14"), Some(-14));
+        assert_eq!(parse_exit_code("0xffff"), Some(65535));
+        assert_eq!(parse_exit_code("0xFFFF"), Some(65535));
+        assert_eq!(parse_exit_code("jetton"), None);
+    }
+}
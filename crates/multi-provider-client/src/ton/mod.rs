@@ -1,4 +1,9 @@
 mod client;
+pub mod exit_codes;
+pub mod sync;
+pub mod tlb;
 pub mod types;
 
-pub use client::TonClient;
+pub use client::{pinned_spec_ref, set_spec_ref, TonClient};
+pub use exit_codes::EXIT_CODES_TECHNOLOGY;
+pub use tlb::TLB_TECHNOLOGY;
@@ -0,0 +1,203 @@
+//! Refreshing the embedded documentation articles from their pages on docs.ton.org and
+//! docs.tact-lang.org.
+//!
+//! The embedded copies keep their id, title, category and tags so search ranking stays
+//! stable; only the description, content and code examples are replaced by what the page
+//! currently says. Security patterns are not synced: their vulnerable/secure code pairs
+//! are curated and have no page to mirror.
+
+use scraper::{ElementRef, Html, Selector};
+use time::Duration;
+
+use super::types::{TonCodeExample, TonDocArticle};
+
+/// Disk cache key of the synced articles
+pub(crate) const SYNCED_ARTICLES_KEY: &str = "ton_articles_synced.json";
+
+/// Synced articles older than this are fetched again
+pub(crate) const ARTICLE_SYNC_MAX_AGE: Duration = Duration::days(7);
+
+/// Failed syncs are not retried sooner than this
+pub(crate) const ARTICLE_SYNC_RETRY: Duration = Duration::minutes(10);
+
+const MAX_CONTENT_CHARS: usize = 6000;
+const MAX_CODE_EXAMPLES: usize = 5;
+
+/// Text and code taken from an article page
+#[derive(Debug, Clone, Default)]
+pub struct ArticlePage {
+    /// First substantial paragraph
+    pub description: String,
+    /// Headings, paragraphs and list items as plain text
+    pub content: String,
+    pub code_examples: Vec<TonCodeExample>,
+}
+
+/// Extract the article body of a docs page; `None` when the page has no recognisable content
+#[must_use]
+pub fn parse_article_page(html: &str) -> Option<ArticlePage> {
+    let document = Html::parse_document(html);
+    let root_selector = Selector::parse("article, main, #content-area, .markdown").unwrap();
+    let block_selector = Selector::parse("h2, h3, p, li, pre").unwrap();
+    let code_selector = Selector::parse("code").unwrap();
+
+    let root = document.select(&root_selector).next()?;
+    let mut page = ArticlePage::default();
+
+    for element in root.select(&block_selector) {
+        if in_chrome(&element) {
+            continue;
+        }
+        let name = element.value().name();
+        if name == "pre" {
+            if page.code_examples.len() < MAX_CODE_EXAMPLES {
+                let code = element.text().collect::<String>().trim().to_string();
+                if !code.is_empty() {
+                    let language = element
+                        .select(&code_selector)
+                        .next()
+                        .and_then(|code| code_language(&code))
+                        .or_else(|| code_language(&element))
+                        .unwrap_or_else(|| "text".to_string());
+                    page.code_examples.push(TonCodeExample { language, code, description: None, is_complete: false });
+                }
+            }
+            continue;
+        }
+        // Paragraphs inside list items are part of the item's text
+        if name == "p" && has_ancestor(&element, &["li"]) {
+            continue;
+        }
+
+        let text = normalize_whitespace(&element.text().collect::<String>());
+        if text.is_empty() {
+            continue;
+        }
+        if name == "p" && page.description.is_empty() && text.len() >= 40 {
+            page.description.clone_from(&text);
+        }
+        if page.content.len() < MAX_CONTENT_CHARS {
+            let line = match name {
+                "h2" | "h3" => format!("\n{text}"),
+                "li" => format!("• {text}"),
+                _ => text,
+            };
+            if !page.content.is_empty() {
+                page.content.push('\n');
+            }
+            page.content.push_str(&line);
+        }
+    }
+
+    page.content = page.content.trim().to_string();
+    (!page.content.is_empty()).then_some(page)
+}
+
+/// The embedded article with the page's text and code; embedded code examples are kept
+/// when the page has none
+#[must_use]
+pub fn merge_article(embedded: &TonDocArticle, page: ArticlePage) -> TonDocArticle {
+    let mut article = embedded.clone();
+    if !page.description.is_empty() {
+        article.description = page.description;
+    }
+    article.content = page.content;
+    if !page.code_examples.is_empty() {
+        article.code_examples = page.code_examples;
+    }
+    article
+}
+
+/// Navigation, sidebars and footers repeat on every page
+fn in_chrome(element: &ElementRef<'_>) -> bool {
+    has_ancestor(element, &["nav", "aside", "footer", "header"])
+}
+
+fn has_ancestor(element: &ElementRef<'_>, names: &[&str]) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| names.contains(&ancestor.value().name()))
+}
+
+/// Language from `class="language-tact"` or `data-language="tact"`
+fn code_language(element: &ElementRef<'_>) -> Option<String> {
+    let value = element.value();
+    value
+        .attr("data-language")
+        .map(str::to_string)
+        .or_else(|| value.classes().find_map(|class| class.strip_prefix("language-").map(str::to_string)))
+        .filter(|language| !language.is_empty())
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ton::types::TonDocSource;
+
+    const PAGE: &str = r#"<html><body>
+<nav><ul><li>Overview</li><li>Smart contracts</li></ul></nav>
+<main><article>
+<h1>Smart contracts</h1>
+<p>Short intro.</p>
+<p>TON smart contracts are programs that process messages and keep persistent data in cells.</p>
+<h2>Messages</h2>
+<ul><li><p>Internal messages</p> are sent between contracts.</li></ul>
+<pre><code class="language-tact">contract Counter {}</code></pre>
+</article></main>
+<footer><p>Copyright TON Foundation and contributors, all rights reserved.</p></footer>
+</body></html>"#;
+
+    fn embedded() -> TonDocArticle {
+        TonDocArticle {
+            id: "smart-contracts-overview".to_string(),
+            title: "Smart Contracts on TON".to_string(),
+            description: "Overview".to_string(),
+            content: "Embedded content".to_string(),
+            source: TonDocSource::TonDocs,
+            url: "https://docs.ton.org/v3/documentation/smart-contracts/overview".to_string(),
+            category: "Smart Contracts".to_string(),
+            code_examples: vec![TonCodeExample {
+                language: "func".to_string(),
+                code: "() recv_internal() impure {}".to_string(),
+                description: None,
+                is_complete: true,
+            }],
+            related: Vec::new(),
+            tags: vec!["overview".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_parse_article_page() {
+        let page = parse_article_page(PAGE).unwrap();
+        assert!(page.description.starts_with("TON smart contracts are programs"));
+        assert!(page.content.contains("\nMessages"));
+        assert!(page.content.contains("• Internal messages are sent between contracts."));
+        assert!(!page.content.contains("Overview"));
+        assert!(!page.content.contains("Copyright"));
+        assert_eq!(page.code_examples.len(), 1);
+        assert_eq!(page.code_examples[0].language, "tact");
+
+        assert!(parse_article_page("<html><body><nav>menu</nav></body></html>").is_none());
+    }
+
+    #[test]
+    fn test_merge_article_keeps_identity() {
+        let page = parse_article_page(PAGE).unwrap();
+        let merged = merge_article(&embedded(), page);
+        assert_eq!(merged.id, "smart-contracts-overview");
+        assert_eq!(merged.tags, ["overview"]);
+        assert!(merged.content.contains("process messages"));
+        assert_eq!(merged.code_examples[0].language, "tact");
+
+        let page = ArticlePage { content: "Text only".to_string(), ..ArticlePage::default() };
+        let merged = merge_article(&embedded(), page);
+        assert_eq!(merged.description, "Overview");
+        assert_eq!(merged.code_examples[0].language, "func");
+    }
+}
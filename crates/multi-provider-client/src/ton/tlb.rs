@@ -0,0 +1,406 @@
+//! TL-B schemas of standard TON messages: jetton and NFT transfers, wallet payloads and
+//! the block.tlb structures they are wrapped in.
+//!
+//! Schemas are kept as TL-B source; op codes and the field layout are derived from it so
+//! the two never disagree.
+
+use super::types::{TonMethodParam, TonTlbSchema};
+
+/// Technology identifier for TL-B schemas
+pub const TLB_TECHNOLOGY: &str = "ton:tlb";
+
+const TEP_74_URL: &str = "https://github.com/ton-blockchain/TEPs/blob/master/text/0074-jettons-standard.md";
+const TEP_62_URL: &str = "https://github.com/ton-blockchain/TEPs/blob/master/text/0062-nft-standard.md";
+const TEP_89_URL: &str = "https://github.com/ton-blockchain/TEPs/blob/master/text/0089-jetton-wallet-discovery.md";
+const BLOCK_TLB_URL: &str = "https://github.com/ton-blockchain/ton/blob/master/crypto/block/block.tlb";
+const WALLETS_URL: &str = "https://docs.ton.org/v3/documentation/smart-contracts/contracts-specs/wallet-contracts";
+const JETTON_MINTER_URL: &str = "https://github.com/ton-blockchain/token-contract/blob/main/ft/op-codes.fc";
+
+/// Built-in TL-B schemas
+#[must_use]
+pub fn tlb_schemas() -> Vec<TonTlbSchema> {
+    vec![
+        // Jettons (TEP-74)
+        schema(
+            "jetton-transfer",
+            "TEP-74 Jettons",
+            "Sent by the owner to their jetton wallet to move jettons to another owner. The wallet deploys or credits the \
+             destination's jetton wallet and, if forward_ton_amount > 0, notifies the new owner with transfer_notification.",
+            TEP_74_URL,
+            &["jetton", "transfer", "send jettons", "tep74"],
+            "transfer#0f8a7ea5 query_id:uint64 amount:(VarUInteger 16) destination:MsgAddress\n\
+             \x20                 response_destination:MsgAddress custom_payload:(Maybe ^Cell)\n\
+             \x20                 forward_ton_amount:(VarUInteger 16) forward_payload:(Either Cell ^Cell)\n\
+             \x20                 = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-transfer-notification",
+            "TEP-74 Jettons",
+            "Sent by the receiving jetton wallet to its owner after an incoming transfer when forward_ton_amount > 0. \
+             Check that the sender is the expected jetton wallet before trusting amount.",
+            TEP_74_URL,
+            &["jetton", "notification", "incoming transfer", "deposit", "tep74"],
+            "transfer_notification#7362d09c query_id:uint64 amount:(VarUInteger 16)\n\
+             \x20                              sender:MsgAddress forward_payload:(Either Cell ^Cell)\n\
+             \x20                              = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-excesses",
+            "TEP-74 Jettons",
+            "Returns the remaining TON to response_destination after a transfer or burn.",
+            TEP_74_URL,
+            &["jetton", "excesses", "refund", "tep74"],
+            "excesses#d53276db query_id:uint64 = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-burn",
+            "TEP-74 Jettons",
+            "Sent by the owner to their jetton wallet to destroy jettons; the wallet reports to the minter with burn_notification.",
+            TEP_74_URL,
+            &["jetton", "burn", "tep74"],
+            "burn#595f07bc query_id:uint64 amount:(VarUInteger 16)\n\
+             \x20             response_destination:MsgAddress custom_payload:(Maybe ^Cell)\n\
+             \x20             = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-internal-transfer",
+            "TEP-74 Jettons (reference implementation)",
+            "Wallet-to-wallet message of the reference jetton implementation. Not fixed by TEP-74, but used by most jettons.",
+            JETTON_MINTER_URL,
+            &["jetton", "internal transfer", "wallet to wallet", "mint"],
+            "internal_transfer#178d4519 query_id:uint64 amount:(VarUInteger 16) from:MsgAddress\n\
+             \x20                          response_address:MsgAddress forward_ton_amount:(VarUInteger 16)\n\
+             \x20                          forward_payload:(Either Cell ^Cell)\n\
+             \x20                          = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-burn-notification",
+            "TEP-74 Jettons (reference implementation)",
+            "Sent by a jetton wallet to the minter after a burn so total supply can be decreased.",
+            JETTON_MINTER_URL,
+            &["jetton", "burn", "notification", "minter", "total supply"],
+            "burn_notification#7bdd97de query_id:uint64 amount:(VarUInteger 16)\n\
+             \x20                          sender:MsgAddress response_destination:MsgAddress\n\
+             \x20                          = InternalMsgBody;",
+        ),
+        // Jetton wallet discovery (TEP-89)
+        schema(
+            "jetton-provide-wallet-address",
+            "TEP-89 Jetton Wallet Discovery",
+            "Asks a jetton minter for the jetton wallet address of owner_address.",
+            TEP_89_URL,
+            &["jetton", "wallet address", "discovery", "tep89"],
+            "provide_wallet_address#2c76b973 query_id:uint64 owner_address:MsgAddress include_address:Bool\n\
+             \x20                               = InternalMsgBody;",
+        ),
+        schema(
+            "jetton-take-wallet-address",
+            "TEP-89 Jetton Wallet Discovery",
+            "Minter's reply to provide_wallet_address; wallet_address is addr_none if the owner is not in the jetton's workchain.",
+            TEP_89_URL,
+            &["jetton", "wallet address", "discovery", "tep89"],
+            "take_wallet_address#d1735400 query_id:uint64 wallet_address:MsgAddress\n\
+             \x20                            owner_address:(Maybe ^MsgAddress)\n\
+             \x20                            = InternalMsgBody;",
+        ),
+        // NFT (TEP-62)
+        schema(
+            "nft-transfer",
+            "TEP-62 NFT",
+            "Sent by the owner to the NFT item to change its owner. If forward_amount > 0 the new owner receives ownership_assigned.",
+            TEP_62_URL,
+            &["nft", "transfer", "ownership", "tep62"],
+            "transfer#5fcc3d14 query_id:uint64 new_owner:MsgAddress response_destination:MsgAddress\n\
+             \x20                 custom_payload:(Maybe ^Cell) forward_amount:(VarUInteger 16)\n\
+             \x20                 forward_payload:(Either Cell ^Cell)\n\
+             \x20                 = InternalMsgBody;",
+        ),
+        schema(
+            "nft-ownership-assigned",
+            "TEP-62 NFT",
+            "Sent by the NFT item to the new owner after a transfer with forward_amount > 0.",
+            TEP_62_URL,
+            &["nft", "ownership", "notification", "tep62"],
+            "ownership_assigned#05138d91 query_id:uint64 prev_owner:MsgAddress\n\
+             \x20                           forward_payload:(Either Cell ^Cell)\n\
+             \x20                           = InternalMsgBody;",
+        ),
+        schema(
+            "nft-get-static-data",
+            "TEP-62 NFT",
+            "Asks an NFT item for its index and collection; answered with report_static_data.",
+            TEP_62_URL,
+            &["nft", "static data", "index", "collection", "tep62"],
+            "get_static_data#2fcb26a2 query_id:uint64 = InternalMsgBody;",
+        ),
+        schema(
+            "nft-report-static-data",
+            "TEP-62 NFT",
+            "NFT item's reply to get_static_data.",
+            TEP_62_URL,
+            &["nft", "static data", "index", "collection", "tep62"],
+            "report_static_data#8b771735 query_id:uint64 index:uint256 collection:MsgAddress\n\
+             \x20                           = InternalMsgBody;",
+        ),
+        // Simple payloads
+        schema(
+            "text-comment",
+            "Simple transfers",
+            "Plain-text comment attached to a TON transfer: a zero op followed by UTF-8 text, continued in references \
+             (snake format) when it does not fit in one cell.",
+            "https://docs.ton.org/v3/guidelines/dapps/asset-processing/payments-processing",
+            &["comment", "memo", "text", "payload", "deposit"],
+            "text_comment#00000000 text:SnakeData = InternalMsgBody;",
+        ),
+        // Wallet payloads
+        schema(
+            "wallet-v3-external",
+            "Wallet v3",
+            "External message body accepted by wallet v3r2. The signature covers the rest of the body. After seqno come up \
+             to 4 (mode:uint8, ^MessageRelaxed) pairs, one per outgoing message.",
+            WALLETS_URL,
+            &["wallet", "v3r2", "v3", "external message", "signature", "seqno", "subwallet"],
+            "wallet_v3_body$_ signature:bits512 subwallet_id:uint32 valid_until:uint32 seqno:uint32\n\
+             \x20                // repeated up to 4 times: mode:uint8 out_msg:^(MessageRelaxed Any)\n\
+             \x20                = ExternalMsgBody;",
+        ),
+        schema(
+            "wallet-v4-external",
+            "Wallet v4",
+            "External message body accepted by wallet v4r2. Like v3 with an op after seqno: 0 sends the (mode, \
+             ^MessageRelaxed) pairs that follow, 1 deploys and installs a plugin, 2 installs and 3 removes one.",
+            WALLETS_URL,
+            &["wallet", "v4r2", "v4", "external message", "signature", "seqno", "plugin"],
+            "wallet_v4_body$_ signature:bits512 subwallet_id:uint32 valid_until:uint32 seqno:uint32 op:uint8\n\
+             \x20                // op = 0: repeated up to 4 times: mode:uint8 out_msg:^(MessageRelaxed Any)\n\
+             \x20                = ExternalMsgBody;",
+        ),
+        schema(
+            "wallet-v5-external",
+            "Wallet v5 (W5)",
+            "Signed external request of wallet v5. Unlike v3/v4 the signature comes last; outgoing messages are an \
+             OutList of action_send_msg actions in the inner request. The same SignedRequest can arrive internally \
+             with op internal_signed (0x73696e74).",
+            WALLETS_URL,
+            &["wallet", "w5", "v5", "walletv5", "external message", "signature", "seqno", "gasless"],
+            "external_signed#7369676e wallet_id:uint32 valid_until:uint32 seqno:uint32\n\
+             \x20                        inner:InnerRequest signature:bits512\n\
+             \x20                        = ExternalMsgBody;\n\
+             actions$_ out_actions:(Maybe ^OutList) has_other_actions:(## 1)\n\
+             \x20         {m:#} {n:#} other_actions:(ActionList n m) = InnerRequest;",
+        ),
+        schema(
+            "out-action-send-msg",
+            "block.tlb",
+            "Output action created by SENDRAWMSG; wallets build an OutList of these for their outgoing messages.",
+            BLOCK_TLB_URL,
+            &["action", "send mode", "out list", "sendrawmsg", "wallet"],
+            "action_send_msg#0ec3c86d mode:(## 8) out_msg:^(MessageRelaxed Any) = OutAction;",
+        ),
+        // Message envelope (block.tlb)
+        schema(
+            "internal-message-info",
+            "block.tlb",
+            "Header of an internal message as built by a contract (relaxed: src may be addr_none and is filled in by the \
+             validator). Fees and lt/at are overwritten when the message is sent.",
+            BLOCK_TLB_URL,
+            &["internal message", "header", "bounce", "message layout", "common msg info"],
+            "int_msg_info$0 ihr_disabled:Bool bounce:Bool bounced:Bool\n\
+             \x20              src:MsgAddress dest:MsgAddressInt\n\
+             \x20              value:CurrencyCollection ihr_fee:Grams fwd_fee:Grams\n\
+             \x20              created_lt:uint64 created_at:uint32 = CommonMsgInfoRelaxed;",
+        ),
+        schema(
+            "message-relaxed",
+            "block.tlb",
+            "A full outgoing message: header, optional StateInit for deployment, and the body inline or in a reference.",
+            BLOCK_TLB_URL,
+            &["message", "message layout", "state init", "body", "deploy"],
+            "message$_ {X:Type} info:CommonMsgInfoRelaxed\n\
+             \x20         init:(Maybe (Either StateInit ^StateInit))\n\
+             \x20         body:(Either X ^X) = MessageRelaxed X;",
+        ),
+        schema(
+            "state-init",
+            "block.tlb",
+            "Code and data used to deploy a contract; the contract address is the hash of this cell.",
+            BLOCK_TLB_URL,
+            &["state init", "deploy", "contract address", "code", "data"],
+            "_ split_depth:(Maybe (## 5)) special:(Maybe TickTock)\n\
+             \x20 code:(Maybe ^Cell) data:(Maybe ^Cell)\n\
+             \x20 library:(HashmapE 256 SimpleLib) = StateInit;",
+        ),
+        schema(
+            "msg-address-int",
+            "block.tlb",
+            "Standard internal address: 267 bits for addr_std without anycast.",
+            BLOCK_TLB_URL,
+            &["address", "workchain", "addr_std", "msgaddress"],
+            "addr_std$10 anycast:(Maybe Anycast) workchain_id:int8 address:bits256 = MsgAddressInt;",
+        ),
+    ]
+}
+
+fn schema(id: &str, standard: &str, description: &str, url: &str, tags: &[&str], tlb: &str) -> TonTlbSchema {
+    TonTlbSchema {
+        id: id.to_string(),
+        name: constructor_name(tlb).to_string(),
+        standard: standard.to_string(),
+        op_code: tlb_op_code(tlb),
+        tlb: tlb.to_string(),
+        description: description.to_string(),
+        fields: parse_tlb_fields(tlb),
+        url: url.to_string(),
+        tags: tags.iter().map(ToString::to_string).collect(),
+    }
+}
+
+/// Name of the first constructor, e.g. `transfer` for `transfer#0f8a7ea5 ...`
+fn constructor_name(tlb: &str) -> &str {
+    let head = tlb.split_whitespace().next().unwrap_or_default();
+    head.split(['#', '$']).next().unwrap_or(head)
+}
+
+/// Op code of the first constructor as `0x`-prefixed hex, e.g. `0x0f8a7ea5`
+#[must_use]
+pub fn tlb_op_code(tlb: &str) -> Option<String> {
+    let head = tlb.split_whitespace().next()?;
+    let (_, tag) = head.split_once('#')?;
+    (tag.len() == 8 && tag.chars().all(|c| c.is_ascii_hexdigit())).then(|| format!("0x{tag}"))
+}
+
+/// Fields of the first constructor in serialization order. Implicit parameters (`{X:Type}`)
+/// and comments are skipped; each field's description is its bit layout when known.
+#[must_use]
+pub fn parse_tlb_fields(tlb: &str) -> Vec<TonMethodParam> {
+    let source: String = tlb
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let constructor = source.split(';').next().unwrap_or_default();
+    let body = constructor.split_once('=').map_or(constructor, |(body, _)| body);
+
+    split_top_level(body)
+        .into_iter()
+        .skip(1)
+        .filter(|token| !token.starts_with('{'))
+        .filter_map(|token| {
+            let (name, field_type) = token.split_once(':')?;
+            let field_type = strip_parens(field_type);
+            Some(TonMethodParam {
+                name: name.to_string(),
+                param_type: field_type.to_string(),
+                description: type_layout(field_type),
+            })
+        })
+        .collect()
+}
+
+/// Whitespace-separated tokens, keeping parenthesised types such as `(Maybe ^Cell)` whole
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(begin) = start.take() {
+                    tokens.push(&text[begin..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
+    }
+    if let Some(begin) = start {
+        tokens.push(&text[begin..]);
+    }
+    tokens
+}
+
+fn strip_parens(field_type: &str) -> &str {
+    field_type
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(field_type)
+}
+
+/// How a field type is laid out in the cell
+fn type_layout(field_type: &str) -> Option<String> {
+    let bits = |prefix: &str| field_type.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok());
+    if let Some(n) = bits("uint").or_else(|| bits("int")).or_else(|| bits("bits")).or_else(|| bits("## ")) {
+        return Some(format!("{n} bits"));
+    }
+
+    let layout = match field_type {
+        "Bool" => "1 bit",
+        "#" => "32 bits",
+        "MsgAddress" => "267 bits for a standard address (2-bit tag, 1-bit anycast, int8 workchain, 256-bit hash); 2 bits for addr_none",
+        "MsgAddressInt" => "267 bits: 2-bit tag, 1-bit anycast, int8 workchain, 256-bit hash",
+        "VarUInteger 16" | "Grams" | "Coins" => "4-bit byte length followed by up to 120 bits of value (nanotons for TON amounts)",
+        "CurrencyCollection" => "Grams followed by a 1-bit flag for the extra currencies dictionary",
+        "Either Cell ^Cell" => "1-bit flag: 0 = inline in the rest of this cell, 1 = in the next reference",
+        "Maybe ^Cell" | "Maybe ^MsgAddress" | "Maybe ^OutList" => "1-bit flag, then a reference when set",
+        "SnakeData" => "Bytes in this cell, continued in a chain of single references",
+        _ if field_type.starts_with('^') => "Reference to a child cell",
+        _ if field_type.starts_with("Maybe ") => "1-bit flag, then the value when set",
+        _ => return None,
+    };
+    Some(layout.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_jetton_transfer() {
+        let schemas = tlb_schemas();
+        let transfer = schemas.iter().find(|schema| schema.id == "jetton-transfer").unwrap();
+        assert_eq!(transfer.name, "transfer");
+        assert_eq!(transfer.op_code.as_deref(), Some("0x0f8a7ea5"));
+
+        let fields: Vec<(&str, &str)> = transfer
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.param_type.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("query_id", "uint64"),
+                ("amount", "VarUInteger 16"),
+                ("destination", "MsgAddress"),
+                ("response_destination", "MsgAddress"),
+                ("custom_payload", "Maybe ^Cell"),
+                ("forward_ton_amount", "VarUInteger 16"),
+                ("forward_payload", "Either Cell ^Cell"),
+            ]
+        );
+        assert_eq!(transfer.fields[0].description.as_deref(), Some("64 bits"));
+    }
+
+    #[test]
+    fn test_parse_fields_skips_comments_and_implicit_params() {
+        let fields = parse_tlb_fields(
+            "message$_ {X:Type} info:CommonMsgInfoRelaxed\n  // comment: not:afield\n  body:(Either X ^X) = MessageRelaxed X;",
+        );
+        let names: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+        assert_eq!(names, ["info", "body"]);
+        assert_eq!(tlb_op_code("message$_ info:CommonMsgInfoRelaxed = MessageRelaxed X;"), None);
+    }
+
+    #[test]
+    fn test_tlb_schema_ids_are_unique() {
+        let schemas = tlb_schemas();
+        let mut ids: Vec<&str> = schemas.iter().map(|schema| schema.id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), schemas.len());
+        assert!(schemas.iter().all(|schema| !schema.fields.is_empty()));
+    }
+}
@@ -2,11 +2,28 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use time::OffsetDateTime;
 
 // ============================================================================
 // OpenAPI Types (for tonapi.io REST API)
 // ============================================================================
 
+/// Which tonkeeper OpenAPI spec is cached and where it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TonSpecStatus {
+    /// Git commit, tag or branch of tonkeeper/opentonapi the spec was fetched from
+    pub reference: String,
+    /// Whether `reference` was pinned instead of following the default branch
+    pub pinned: bool,
+    pub url: String,
+    /// `info.version` of the spec
+    pub version: String,
+    pub endpoint_count: usize,
+    /// When the cached copy was downloaded
+    pub fetched_at: OffsetDateTime,
+}
+
 /// OpenAPI specification structure (simplified)
 /// Uses flatten to capture any extra fields we don't explicitly handle
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -172,6 +189,8 @@ pub enum TonDocSource {
     FunC,
     /// Tolk language documentation (new)
     Tolk,
+    /// TL-B schemas of standard messages (TEPs and block.tlb)
+    Tlb,
 }
 
 impl TonDocSource {
@@ -184,6 +203,7 @@ impl TonDocSource {
             TonDocSource::Tvm => "TVM",
             TonDocSource::FunC => "FunC",
             TonDocSource::Tolk => "Tolk",
+            TonDocSource::Tlb => "TL-B Schemas",
         }
     }
 
@@ -196,6 +216,7 @@ impl TonDocSource {
             TonDocSource::Tvm => "https://docs.ton.org/v3/documentation/tvm",
             TonDocSource::FunC => "https://docs.ton.org/v3/documentation/smart-contracts/func",
             TonDocSource::Tolk => "https://docs.ton.org/v3/documentation/smart-contracts/tolk",
+            TonDocSource::Tlb => "https://github.com/ton-blockchain/TEPs",
         }
     }
 }
@@ -633,6 +654,56 @@ pub struct TonMethodParam {
     pub description: Option<String>,
 }
 
+// ============================================================================
+// TL-B Schema Types
+// ============================================================================
+
+/// TL-B definition of a standard message body or structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonTlbSchema {
+    /// Unique identifier (e.g., "jetton-transfer")
+    pub id: String,
+    /// Constructor or structure name
+    pub name: String,
+    /// Standard defining the layout (e.g., "TEP-74 Jettons")
+    pub standard: String,
+    /// Op code from the constructor tag (e.g., "0x0f8a7ea5")
+    pub op_code: Option<String>,
+    /// TL-B source
+    pub tlb: String,
+    /// Description
+    pub description: String,
+    /// Fields in serialization order, with their bit layout as description
+    pub fields: Vec<TonMethodParam>,
+    /// URL to the defining specification
+    pub url: String,
+    /// Tags for searchability
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// ============================================================================
+// Exit Code Types
+// ============================================================================
+
+/// A TVM or contract exit code with what it means and what usually triggers it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TonExitCode {
+    /// Exit code as reported in the transaction (e.g., 37, -14, 65535)
+    pub code: i32,
+    /// Short name (e.g., "Not enough Toncoin")
+    pub name: String,
+    /// Where the code is raised: "Compute phase", "Action phase" or a contract convention
+    pub phase: String,
+    /// Meaning of the code
+    pub description: String,
+    /// Common causes, including contracts that reuse the code for their own errors
+    #[serde(default)]
+    pub causes: Vec<String>,
+    /// URL to the reference
+    pub url: String,
+}
+
 // ============================================================================
 // Wallet Types
 // ============================================================================
@@ -695,6 +766,10 @@ pub enum TonResultType {
     TokenStandard,
     /// Wallet documentation
     Wallet,
+    /// TL-B message layout
+    TlbSchema,
+    /// TVM or contract exit code
+    ExitCode,
 }
 
 impl TonResultType {
@@ -707,6 +782,8 @@ impl TonResultType {
             TonResultType::ContractExample => "Contract Example",
             TonResultType::TokenStandard => "Token Standard",
             TonResultType::Wallet => "Wallet",
+            TonResultType::TlbSchema => "TL-B Schema",
+            TonResultType::ExitCode => "Exit Code",
         }
     }
 }
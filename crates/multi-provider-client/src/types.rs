@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::bitcoind::types::{BitcoindCategory, BitcoindMethod, BitcoindTechnology};
 use crate::claude_agent_sdk::types::{
     AgentSdkArticle, AgentSdkCategory, AgentSdkTechnology,
 };
@@ -12,9 +13,9 @@ use crate::mdn::types::{MdnArticle, MdnTechnology};
 use crate::mlx::types::{MlxArticle, MlxCategory, MlxTechnology};
 use crate::quicknode::types::{QuickNodeCategory, QuickNodeMethod, QuickNodeTechnology};
 use crate::rust::types::{RustCategory, RustItem, RustTechnology};
+use crate::solana::types::{SolanaArticle, SolanaCategory, SolanaTechnology};
 use crate::telegram::types::{TelegramCategory, TelegramItem, TelegramTechnology};
 use crate::ton::types::{TonCategory, TonEndpoint, TonTechnology};
-use crate::vertcoin::types::{VertcoinCategory, VertcoinMethod, VertcoinTechnology};
 use crate::web_frameworks::types::{
     WebFrameworkArticle, WebFrameworkTechnology,
 };
@@ -38,13 +39,77 @@ pub enum ProviderType {
     QuickNode,
     /// Claude Agent SDK - TypeScript and Python SDKs for building AI agents
     ClaudeAgentSdk,
-    /// Vertcoin - GPU-mineable cryptocurrency with Verthash algorithm
-    Vertcoin,
+    /// bitcoind - node RPC documentation for Bitcoin, Litecoin and Vertcoin (Verthash)
+    #[serde(alias = "Vertcoin")]
+    Bitcoind,
     /// CUDA - NVIDIA GPU programming and kernel development
     Cuda,
+    /// Solana - core concepts from solana.com/docs and the Solana Cookbook
+    Solana,
 }
 
 impl ProviderType {
+    /// Every provider, in display order.
+    pub const ALL: [ProviderType; 14] = [
+        Self::Apple,
+        Self::Telegram,
+        Self::TON,
+        Self::Cocoon,
+        Self::Rust,
+        Self::Mdn,
+        Self::WebFrameworks,
+        Self::Mlx,
+        Self::HuggingFace,
+        Self::QuickNode,
+        Self::ClaudeAgentSdk,
+        Self::Bitcoind,
+        Self::Cuda,
+        Self::Solana,
+    ];
+
+    /// Stable machine-friendly identifier; also the provider's cache subdirectory.
+    #[must_use]
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Self::Apple => "apple",
+            Self::Telegram => "telegram",
+            Self::TON => "ton",
+            Self::Cocoon => "cocoon",
+            Self::Rust => "rust",
+            Self::Mdn => "mdn",
+            Self::WebFrameworks => "web_frameworks",
+            Self::Mlx => "mlx",
+            Self::HuggingFace => "huggingface",
+            Self::QuickNode => "quicknode",
+            Self::ClaudeAgentSdk => "claude_agent_sdk",
+            Self::Bitcoind => "bitcoind",
+            Self::Cuda => "cuda",
+            Self::Solana => "solana",
+        }
+    }
+
+    /// Parse a provider from its slug or display name, ignoring case and punctuation
+    /// (`"web-frameworks"`, `"Hugging Face"`, and `"claude_agent_sdk"` all resolve).
+    #[must_use]
+    pub fn from_slug(value: &str) -> Option<Self> {
+        fn normalize(value: &str) -> String {
+            value
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+                .collect()
+        }
+
+        let wanted = normalize(value);
+        // The bitcoind provider was named after Vertcoin before it covered Bitcoin and Litecoin
+        if wanted == "vertcoin" {
+            return Some(Self::Bitcoind);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|provider| normalize(provider.slug()) == wanted || normalize(provider.name()) == wanted)
+    }
+
     #[must_use]
     pub fn name(&self) -> &'static str {
         match self {
@@ -59,8 +124,30 @@ impl ProviderType {
             Self::HuggingFace => "Hugging Face",
             Self::QuickNode => "QuickNode",
             Self::ClaudeAgentSdk => "Claude Agent SDK",
-            Self::Vertcoin => "Vertcoin",
+            Self::Bitcoind => "bitcoind",
             Self::Cuda => "CUDA",
+            Self::Solana => "Solana",
+        }
+    }
+
+    /// Landing page of the provider's documentation, used for reachability checks.
+    #[must_use]
+    pub fn homepage(&self) -> &'static str {
+        match self {
+            Self::Apple => "https://developer.apple.com/documentation",
+            Self::Telegram => "https://core.telegram.org/bots/api",
+            Self::TON => "https://docs.ton.org",
+            Self::Cocoon => "https://github.com/TelegramMessenger/cocoon",
+            Self::Rust => "https://docs.rs",
+            Self::Mdn => "https://developer.mozilla.org/en-US/docs/Web",
+            Self::WebFrameworks => "https://react.dev",
+            Self::Mlx => "https://ml-explore.github.io/mlx/build/html/index.html",
+            Self::HuggingFace => "https://huggingface.co/docs",
+            Self::QuickNode => "https://www.quicknode.com/docs/solana",
+            Self::ClaudeAgentSdk => "https://docs.anthropic.com/en/docs/agents-and-tools/claude-agent-sdk",
+            Self::Bitcoind => "https://developer.bitcoin.org/reference/rpc/",
+            Self::Cuda => "https://docs.nvidia.com/cuda",
+            Self::Solana => "https://solana.com/docs",
         }
     }
 
@@ -76,10 +163,11 @@ impl ProviderType {
             Self::WebFrameworks => "React, Next.js, and Node.js Documentation",
             Self::Mlx => "MLX Machine Learning Framework for Apple Silicon",
             Self::HuggingFace => "Hugging Face Transformers and Model Documentation",
-            Self::QuickNode => "QuickNode Solana RPC Documentation",
+            Self::QuickNode => "QuickNode RPC Documentation",
             Self::ClaudeAgentSdk => "Claude Agent SDK for TypeScript and Python",
-            Self::Vertcoin => "Vertcoin Blockchain and Verthash Mining Documentation",
+            Self::Bitcoind => "Bitcoin, Litecoin and Vertcoin Node RPC Documentation",
             Self::Cuda => "CUDA GPU Programming and Kernel Development (RTX 3070/4090)",
+            Self::Solana => "Solana Core Concepts and Cookbook",
         }
     }
 }
@@ -121,14 +209,17 @@ pub enum TechnologyKind {
     MlxFramework,
     /// Hugging Face library (Transformers, Hub, etc.)
     HfLibrary,
-    /// QuickNode Solana API (HTTP, WebSocket, Marketplace)
+    /// QuickNode RPC APIs (Solana, Ethereum, Base, Polygon)
     QuickNodeApi,
     /// Claude Agent SDK library (TypeScript or Python)
     AgentSdkLibrary,
-    /// Vertcoin blockchain API (RPC, Wallet, Mining)
-    VertcoinApi,
+    /// bitcoind RPC API for Bitcoin, Litecoin and Vertcoin (RPC, Wallet, Mining)
+    #[serde(alias = "VertcoinApi")]
+    BitcoindApi,
     /// CUDA GPU programming (Runtime API, Kernels, Libraries)
     CudaApi,
+    /// Solana documentation (Core Concepts, Cookbook)
+    SolanaDocs,
 }
 
 impl UnifiedTechnology {
@@ -260,14 +351,14 @@ impl UnifiedTechnology {
         }
     }
 
-    pub fn from_vertcoin(tech: VertcoinTechnology) -> Self {
+    pub fn from_bitcoind(tech: BitcoindTechnology) -> Self {
         Self {
-            provider: ProviderType::Vertcoin,
+            provider: ProviderType::Bitcoind,
             identifier: tech.identifier,
             title: tech.title,
             description: tech.description,
             url: Some(tech.url),
-            kind: TechnologyKind::VertcoinApi,
+            kind: TechnologyKind::BitcoindApi,
         }
     }
 
@@ -281,6 +372,17 @@ impl UnifiedTechnology {
             kind: TechnologyKind::CudaApi,
         }
     }
+
+    pub fn from_solana(tech: SolanaTechnology) -> Self {
+        Self {
+            provider: ProviderType::Solana,
+            identifier: tech.identifier,
+            title: tech.title,
+            description: tech.description,
+            url: Some(tech.url),
+            kind: TechnologyKind::SolanaDocs,
+        }
+    }
 }
 
 /// Unified framework/category data
@@ -495,7 +597,7 @@ impl UnifiedFrameworkData {
             .items
             .into_iter()
             .map(|item| UnifiedReference {
-                identifier: item.name.clone(),
+                identifier: if item.path.is_empty() { item.name.clone() } else { item.path },
                 title: item.name,
                 description: Some(item.description),
                 kind: Some(item.kind.to_string()),
@@ -534,12 +636,12 @@ impl UnifiedFrameworkData {
         }
     }
 
-    pub fn from_vertcoin(data: VertcoinCategory) -> Self {
+    pub fn from_bitcoind(data: BitcoindCategory) -> Self {
         let items = data
             .items
             .into_iter()
             .map(|item| UnifiedReference {
-                identifier: item.name.clone(),
+                identifier: if item.path.is_empty() { item.name.clone() } else { item.path },
                 title: item.name,
                 description: Some(item.description),
                 kind: Some(item.kind.to_string()),
@@ -548,7 +650,7 @@ impl UnifiedFrameworkData {
             .collect();
 
         Self {
-            provider: ProviderType::Vertcoin,
+            provider: ProviderType::Bitcoind,
             title: data.title,
             description: data.description,
             items,
@@ -577,6 +679,28 @@ impl UnifiedFrameworkData {
             sections: vec![],
         }
     }
+
+    pub fn from_solana(data: SolanaCategory) -> Self {
+        let items = data
+            .items
+            .into_iter()
+            .map(|item| UnifiedReference {
+                identifier: item.id,
+                title: item.title,
+                description: Some(item.description),
+                kind: Some(item.topic),
+                url: Some(item.url),
+            })
+            .collect();
+
+        Self {
+            provider: ProviderType::Solana,
+            title: data.title,
+            description: data.description,
+            items,
+            sections: vec![],
+        }
+    }
 }
 
 /// Unified symbol/item data
@@ -652,7 +776,7 @@ pub enum SymbolContent {
         examples: Vec<HfExampleInfo>,
         parameters: Vec<HfParamInfo>,
     },
-    /// QuickNode Solana RPC documentation
+    /// QuickNode Solana and EVM RPC documentation
     QuickNode {
         method_kind: String,
         parameters: Vec<QuickNodeParamInfo>,
@@ -667,24 +791,39 @@ pub enum SymbolContent {
         examples: Vec<AgentSdkExampleInfo>,
         parameters: Vec<AgentSdkParamInfo>,
     },
-    /// Vertcoin blockchain documentation
-    Vertcoin {
+    /// bitcoind RPC documentation (Bitcoin, Litecoin, Vertcoin)
+    Bitcoind {
         method_kind: String,
-        parameters: Vec<VertcoinParamInfo>,
-        returns: Option<VertcoinReturnInfo>,
-        examples: Vec<VertcoinExampleInfo>,
+        parameters: Vec<BitcoindParamInfo>,
+        returns: Option<BitcoindReturnInfo>,
+        examples: Vec<BitcoindExampleInfo>,
     },
     /// CUDA GPU programming documentation
     Cuda {
         method_kind: String,
+        declaration: Option<String>,
         parameters: Vec<CudaParamInfo>,
         returns: Option<CudaReturnInfo>,
         examples: Vec<CudaExampleInfo>,
     },
+    /// Solana documentation article or cookbook recipe
+    Solana {
+        source: String,
+        topic: String,
+        content: String,
+        examples: Vec<SolanaExampleInfo>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VertcoinParamInfo {
+pub struct SolanaExampleInfo {
+    pub code: String,
+    pub language: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoindParamInfo {
     pub name: String,
     pub description: String,
     pub param_type: String,
@@ -693,21 +832,21 @@ pub struct VertcoinParamInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VertcoinReturnInfo {
+pub struct BitcoindReturnInfo {
     pub type_name: String,
     pub description: String,
-    pub fields: Vec<VertcoinFieldInfo>,
+    pub fields: Vec<BitcoindFieldInfo>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VertcoinFieldInfo {
+pub struct BitcoindFieldInfo {
     pub name: String,
     pub field_type: String,
     pub description: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VertcoinExampleInfo {
+pub struct BitcoindExampleInfo {
     pub code: String,
     pub language: String,
     pub description: Option<String>,
@@ -1248,11 +1387,11 @@ impl UnifiedSymbolData {
         }
     }
 
-    pub fn from_vertcoin(data: VertcoinMethod) -> Self {
+    pub fn from_bitcoind(data: BitcoindMethod) -> Self {
         let parameters = data
             .parameters
             .into_iter()
-            .map(|p| VertcoinParamInfo {
+            .map(|p| BitcoindParamInfo {
                 name: p.name,
                 description: p.description,
                 param_type: p.param_type,
@@ -1261,13 +1400,13 @@ impl UnifiedSymbolData {
             })
             .collect();
 
-        let returns = data.returns.map(|r| VertcoinReturnInfo {
+        let returns = data.returns.map(|r| BitcoindReturnInfo {
             type_name: r.type_name,
             description: r.description,
             fields: r
                 .fields
                 .into_iter()
-                .map(|f| VertcoinFieldInfo {
+                .map(|f| BitcoindFieldInfo {
                     name: f.name,
                     field_type: f.field_type,
                     description: f.description,
@@ -1278,7 +1417,7 @@ impl UnifiedSymbolData {
         let examples = data
             .examples
             .into_iter()
-            .map(|e| VertcoinExampleInfo {
+            .map(|e| BitcoindExampleInfo {
                 code: e.code,
                 language: e.language,
                 description: e.description,
@@ -1286,11 +1425,11 @@ impl UnifiedSymbolData {
             .collect();
 
         Self {
-            provider: ProviderType::Vertcoin,
+            provider: ProviderType::Bitcoind,
             title: data.name,
             description: data.description,
             kind: Some(data.kind.to_string()),
-            content: SymbolContent::Vertcoin {
+            content: SymbolContent::Bitcoind {
                 method_kind: data.kind.to_string(),
                 parameters,
                 returns,
@@ -1344,6 +1483,7 @@ impl UnifiedSymbolData {
             kind: Some(data.kind.to_string()),
             content: SymbolContent::Cuda {
                 method_kind: data.kind.to_string(),
+                declaration: data.declaration,
                 parameters,
                 returns,
                 examples,
@@ -1351,4 +1491,30 @@ impl UnifiedSymbolData {
             related: vec![],
         }
     }
+
+    pub fn from_solana(data: SolanaArticle) -> Self {
+        let examples = data
+            .code_examples
+            .into_iter()
+            .map(|e| SolanaExampleInfo {
+                code: e.code,
+                language: e.language,
+                description: e.description,
+            })
+            .collect();
+
+        Self {
+            provider: ProviderType::Solana,
+            title: data.title,
+            description: data.description,
+            kind: Some(data.topic.clone()),
+            content: SymbolContent::Solana {
+                source: data.source.name().to_string(),
+                topic: data.topic,
+                content: data.content,
+                examples,
+            },
+            related: vec![],
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::time::Duration as StdDuration;
 
 use anyhow::Result;
 use directories::ProjectDirs;
@@ -11,27 +11,44 @@ use tokio::sync::RwLock;
 use tracing::{debug, instrument, warn};
 
 use super::types::{
-    CodeExample, NodeApiModule, WebFramework, WebFrameworkArticle, WebFrameworkSearchEntry,
-    WebFrameworkTechnology,
+    node_docs_base, node_module_slug, parse_node_slug, CodeExample, NodeApiModule, WebFramework,
+    WebFrameworkArticle, WebFrameworkSearchEntry, WebFrameworkTechnology,
 };
-use docs_mcp_client::cache::{DiskCache, MemoryCache};
+use docs_mcp_client::cache::DiskCache;
+use docs_mcp_client::http::client_builder;
+use docs_mcp_client::offline::ensure_online;
+use docs_mcp_client::retry::SendWithRetry;
+
+use crate::memory::{memory_ttl, ProviderMemoryCache};
+use crate::timeouts::timeouts_for;
+use crate::types::ProviderType;
 
 // API endpoints
-const NODEJS_API_JSON: &str = "https://nodejs.org/api/all.json";
 const REACT_DEV_BASE: &str = "https://react.dev";
 const NEXTJS_BASE: &str = "https://nextjs.org";
 const BUN_BASE: &str = "https://bun.sh";
+const EXPRESS_BASE: &str = "https://expressjs.com";
+const ASTRO_BASE: &str = "https://docs.astro.build";
+const REACT_ROUTER_BASE: &str = "https://reactrouter.com";
+const REACT_NATIVE_BASE: &str = "https://reactnative.dev";
+const TYPESCRIPT_BASE: &str = "https://www.typescriptlang.org";
 
 #[derive(Debug)]
 pub struct WebFrameworksClient {
     http: Client,
     disk_cache: DiskCache,
-    memory_cache: MemoryCache<Vec<u8>>,
+    memory_cache: ProviderMemoryCache<Vec<u8>>,
     /// Search indexes per framework
     react_index: RwLock<Vec<WebFrameworkSearchEntry>>,
     nextjs_index: RwLock<Vec<WebFrameworkSearchEntry>>,
-    nodejs_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    /// Node.js indexes keyed by pinned release line (`None` is the current release)
+    nodejs_indexes: RwLock<HashMap<Option<u32>, Vec<WebFrameworkSearchEntry>>>,
     bun_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    express_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    astro_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    react_router_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    react_native_index: RwLock<Vec<WebFrameworkSearchEntry>>,
+    typescript_index: RwLock<Vec<WebFrameworkSearchEntry>>,
     cache_dir: PathBuf,
 }
 
@@ -52,9 +69,8 @@ impl WebFrameworksClient {
             warn!(error = %e, "Failed to create web_frameworks cache directory");
         }
 
-        let http = Client::builder()
+        let http = client_builder(timeouts_for(ProviderType::WebFrameworks))
             .user_agent("MultiDocsMCP/1.0 (Documentation Search Tool)")
-            .timeout(StdDuration::from_secs(30))
             .gzip(true)
             .build()
             .expect("failed to build reqwest client");
@@ -62,11 +78,16 @@ impl WebFrameworksClient {
         Self {
             http,
             disk_cache: DiskCache::new(&cache_dir),
-            memory_cache: MemoryCache::new(time::Duration::hours(1)),
+            memory_cache: ProviderMemoryCache::new(ProviderType::WebFrameworks, memory_ttl(ProviderType::WebFrameworks)),
             react_index: RwLock::new(Vec::new()),
             nextjs_index: RwLock::new(Vec::new()),
-            nodejs_index: RwLock::new(Vec::new()),
+            nodejs_indexes: RwLock::new(HashMap::new()),
             bun_index: RwLock::new(Vec::new()),
+            express_index: RwLock::new(Vec::new()),
+            astro_index: RwLock::new(Vec::new()),
+            react_router_index: RwLock::new(Vec::new()),
+            react_native_index: RwLock::new(Vec::new()),
+            typescript_index: RwLock::new(Vec::new()),
             cache_dir,
         }
     }
@@ -89,6 +110,11 @@ impl WebFrameworksClient {
             WebFramework::NextJs => self.search_nextjs(query).await,
             WebFramework::NodeJs => self.search_nodejs(query).await,
             WebFramework::Bun => self.search_bun(query).await,
+            WebFramework::Express => self.search_express(query).await,
+            WebFramework::Astro => self.search_astro(query).await,
+            WebFramework::ReactRouter => self.search_react_router(query).await,
+            WebFramework::ReactNative => self.search_react_native(query).await,
+            WebFramework::TypeScript => self.search_typescript(query).await,
         }
     }
 
@@ -104,6 +130,11 @@ impl WebFrameworksClient {
             WebFramework::NextJs => self.fetch_nextjs_article(slug).await,
             WebFramework::NodeJs => self.fetch_nodejs_article(slug).await,
             WebFramework::Bun => self.fetch_bun_article(slug).await,
+            WebFramework::Express => self.fetch_express_article(slug).await,
+            WebFramework::Astro => self.fetch_astro_article(slug).await,
+            WebFramework::ReactRouter => self.fetch_react_router_article(slug).await,
+            WebFramework::ReactNative => self.fetch_react_native_article(slug).await,
+            WebFramework::TypeScript => self.fetch_typescript_article(slug).await,
         }
     }
 
@@ -142,7 +173,7 @@ impl WebFrameworksClient {
             })
             .collect();
 
-        results.sort_by(|a, b| b.0.cmp(&a.0));
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         Ok(results
             .into_iter()
@@ -260,7 +291,8 @@ impl WebFrameworksClient {
         let url = format!("{}/{}", REACT_DEV_BASE, slug);
         debug!(url = %url, "Fetching React article");
 
-        let response = self.http.get(&url).send().await?;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
         if !response.status().is_success() {
             anyhow::bail!("React page not found: {}", slug);
         }
@@ -339,7 +371,7 @@ impl WebFrameworksClient {
             })
             .collect();
 
-        results.sort_by(|a, b| b.0.cmp(&a.0));
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         Ok(results
             .into_iter()
@@ -448,7 +480,8 @@ impl WebFrameworksClient {
         let url = format!("{}/{}", NEXTJS_BASE, slug);
         debug!(url = %url, "Fetching Next.js article");
 
-        let response = self.http.get(&url).send().await?;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
         if !response.status().is_success() {
             anyhow::bail!("Next.js page not found: {}", slug);
         }
@@ -495,12 +528,26 @@ impl WebFrameworksClient {
     // ==================== NODE.JS ====================
 
     async fn search_nodejs(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
-        self.ensure_nodejs_index().await?;
+        self.search_nodejs_release(None, query).await
+    }
+
+    /// Search the docs of one Node.js release line (see `NODE_RELEASE_LINES`); `None` searches
+    /// the current release. Pinned results carry `nodejs@<line>/<module>` slugs.
+    #[instrument(name = "webfw_client.search_nodejs_release", skip(self))]
+    pub async fn search_nodejs_release(
+        &self,
+        release: Option<u32>,
+        query: &str,
+    ) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_nodejs_index(release).await?;
 
         let query_lower = query.to_lowercase();
         let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
 
-        let index = self.nodejs_index.read().await;
+        let indexes = self.nodejs_indexes.read().await;
+        let Some(index) = indexes.get(&release) else {
+            return Ok(Vec::new());
+        };
         let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
             .iter()
             .filter_map(|entry| {
@@ -525,7 +572,7 @@ impl WebFrameworksClient {
             })
             .collect();
 
-        results.sort_by(|a, b| b.0.cmp(&a.0));
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         Ok(results
             .into_iter()
@@ -534,76 +581,85 @@ impl WebFrameworksClient {
             .collect())
     }
 
-    async fn ensure_nodejs_index(&self) -> Result<()> {
-        if !self.nodejs_index.read().await.is_empty() {
+    async fn ensure_nodejs_index(&self, release: Option<u32>) -> Result<()> {
+        if self.nodejs_indexes.read().await.contains_key(&release) {
             return Ok(());
         }
 
+        let cache_key = release.map_or_else(
+            || "nodejs_latest_index.json".to_string(),
+            |line| format!("nodejs_v{line}_index.json"),
+        );
         if let Ok(Some(entry)) = self
             .disk_cache
-            .load::<Vec<WebFrameworkSearchEntry>>("nodejs_index.json")
+            .load::<Vec<WebFrameworkSearchEntry>>(&cache_key)
             .await
         {
-            *self.nodejs_index.write().await = entry.value;
+            self.nodejs_indexes.write().await.insert(release, entry.value);
             return Ok(());
         }
 
-        let index = self.build_nodejs_index().await;
+        let index = self.build_nodejs_index(release).await;
         let _ = self
             .disk_cache
-            .store("nodejs_index.json", index.clone())
+            .store(&cache_key, index.clone())
             .await;
-        *self.nodejs_index.write().await = index;
+        self.nodejs_indexes.write().await.insert(release, index);
 
         Ok(())
     }
 
-    async fn build_nodejs_index(&self) -> Vec<WebFrameworkSearchEntry> {
-        // Try to fetch from Node.js API JSON
-        if let Ok(modules) = self.fetch_nodejs_api_json().await {
+    async fn build_nodejs_index(&self, release: Option<u32>) -> Vec<WebFrameworkSearchEntry> {
+        // Try to fetch from the release line's Node.js API JSON
+        if let Ok(modules) = self.fetch_nodejs_api_json(release).await {
+            let base = node_docs_base(release);
             return modules
                 .iter()
                 .map(|m| WebFrameworkSearchEntry {
                     framework: WebFramework::NodeJs,
-                    slug: format!("api/{}", m.name),
+                    slug: node_module_slug(release, &m.name),
                     title: m.display_name.clone().unwrap_or_else(|| m.name.clone()),
                     description: m.desc.clone().unwrap_or_default(),
-                    url: format!("https://nodejs.org/api/{}.html", m.name),
-                    category: Some("Module".to_string()),
+                    url: format!("{base}/{}.html", m.name),
+                    category: Some(node_module_category(m.stability).to_string()),
                 })
                 .collect();
         }
 
         // Fallback to predefined entries
         vec![
-            self.nodejs_entry("fs", "File System (fs)", "File system operations"),
-            self.nodejs_entry("path", "Path", "File path utilities"),
-            self.nodejs_entry("http", "HTTP", "HTTP server and client"),
-            self.nodejs_entry("https", "HTTPS", "HTTPS server and client"),
-            self.nodejs_entry("stream", "Stream", "Streaming data handling"),
-            self.nodejs_entry("buffer", "Buffer", "Binary data handling"),
-            self.nodejs_entry("events", "Events", "Event emitter pattern"),
-            self.nodejs_entry("child_process", "Child Process", "Spawn child processes"),
-            self.nodejs_entry("crypto", "Crypto", "Cryptographic functions"),
-            self.nodejs_entry("os", "OS", "Operating system utilities"),
-            self.nodejs_entry("url", "URL", "URL parsing and formatting"),
-            self.nodejs_entry("querystring", "Query Strings", "Parse and format URL query strings"),
-            self.nodejs_entry("util", "Util", "Utility functions"),
-            self.nodejs_entry("assert", "Assert", "Assertion testing"),
-            self.nodejs_entry("process", "Process", "Process information and control"),
-            self.nodejs_entry("net", "Net", "TCP/IPC networking"),
-            self.nodejs_entry("dns", "DNS", "DNS lookups"),
-            self.nodejs_entry("readline", "Readline", "Read lines from stream"),
-            self.nodejs_entry("zlib", "Zlib", "Compression utilities"),
-            self.nodejs_entry("cluster", "Cluster", "Multi-process Node.js"),
-            self.nodejs_entry("worker_threads", "Worker Threads", "Multi-threaded JavaScript"),
-            self.nodejs_entry("async_hooks", "Async Hooks", "Track async resources"),
-            self.nodejs_entry("timers", "Timers", "setTimeout, setInterval, etc."),
+            self.nodejs_entry(release, "fs", "File System (fs)", "File system operations"),
+            self.nodejs_entry(release, "path", "Path", "File path utilities"),
+            self.nodejs_entry(release, "http", "HTTP", "HTTP server and client"),
+            self.nodejs_entry(release, "https", "HTTPS", "HTTPS server and client"),
+            self.nodejs_entry(release, "stream", "Stream", "Streaming data handling"),
+            self.nodejs_entry(release, "buffer", "Buffer", "Binary data handling"),
+            self.nodejs_entry(release, "events", "Events", "Event emitter pattern"),
+            self.nodejs_entry(release, "child_process", "Child Process", "Spawn child processes"),
+            self.nodejs_entry(release, "crypto", "Crypto", "Cryptographic functions"),
+            self.nodejs_entry(release, "os", "OS", "Operating system utilities"),
+            self.nodejs_entry(release, "url", "URL", "URL parsing and formatting"),
+            self.nodejs_entry(release, "querystring", "Query Strings", "Parse and format URL query strings"),
+            self.nodejs_entry(release, "util", "Util", "Utility functions"),
+            self.nodejs_entry(release, "assert", "Assert", "Assertion testing"),
+            self.nodejs_entry(release, "process", "Process", "Process information and control"),
+            self.nodejs_entry(release, "net", "Net", "TCP/IPC networking"),
+            self.nodejs_entry(release, "dns", "DNS", "DNS lookups"),
+            self.nodejs_entry(release, "readline", "Readline", "Read lines from stream"),
+            self.nodejs_entry(release, "zlib", "Zlib", "Compression utilities"),
+            self.nodejs_entry(release, "cluster", "Cluster", "Multi-process Node.js"),
+            self.nodejs_entry(release, "worker_threads", "Worker Threads", "Multi-threaded JavaScript"),
+            self.nodejs_entry(release, "async_hooks", "Async Hooks", "Track async resources"),
+            self.nodejs_entry(release, "timers", "Timers", "setTimeout, setInterval, etc."),
+            self.nodejs_entry(release, "test", "Test runner", "Built-in test runner (node:test)"),
+            self.nodejs_entry(release, "globals", "Globals", "Global objects such as fetch, AbortController and structuredClone"),
         ]
     }
 
-    async fn fetch_nodejs_api_json(&self) -> Result<Vec<NodeApiModule>> {
-        let response = self.http.get(NODEJS_API_JSON).send().await?;
+    async fn fetch_nodejs_api_json(&self, release: Option<u32>) -> Result<Vec<NodeApiModule>> {
+        let url = format!("{}/all.json", node_docs_base(release));
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
         if !response.status().is_success() {
             anyhow::bail!("Failed to fetch Node.js API JSON");
         }
@@ -622,13 +678,13 @@ impl WebFrameworksClient {
         anyhow::bail!("Invalid Node.js API JSON structure")
     }
 
-    fn nodejs_entry(&self, name: &str, title: &str, description: &str) -> WebFrameworkSearchEntry {
+    fn nodejs_entry(&self, release: Option<u32>, name: &str, title: &str, description: &str) -> WebFrameworkSearchEntry {
         WebFrameworkSearchEntry {
             framework: WebFramework::NodeJs,
-            slug: format!("api/{}", name),
+            slug: node_module_slug(release, name),
             title: title.to_string(),
             description: description.to_string(),
-            url: format!("https://nodejs.org/api/{}.html", name),
+            url: format!("{}/{}.html", node_docs_base(release), name),
             category: Some("Module".to_string()),
         }
     }
@@ -644,12 +700,13 @@ impl WebFrameworksClient {
             return Ok(entry.value);
         }
 
-        // Extract module name from slug (e.g., "api/fs" -> "fs")
-        let module_name = slug.strip_prefix("api/").unwrap_or(slug);
-        let url = format!("https://nodejs.org/api/{}.html", module_name);
+        // Split the release pin and module name from the slug (e.g., "nodejs@20/fs" -> 20, "fs")
+        let (release, module_name) = parse_node_slug(slug);
+        let url = format!("{}/{}.html", node_docs_base(release), module_name);
         debug!(url = %url, "Fetching Node.js article");
 
-        let response = self.http.get(&url).send().await?;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
         if !response.status().is_success() {
             anyhow::bail!("Node.js page not found: {}", slug);
         }
@@ -727,7 +784,7 @@ impl WebFrameworksClient {
             })
             .collect();
 
-        results.sort_by(|a, b| b.0.cmp(&a.0));
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
 
         Ok(results
             .into_iter()
@@ -1115,7 +1172,8 @@ impl WebFrameworksClient {
         let url = format!("{}/{}", BUN_BASE, fetch_slug);
         debug!(url = %url, "Fetching Bun article");
 
-        let response = self.http.get(&url).send().await?;
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
         if !response.status().is_success() {
             anyhow::bail!("Bun page not found: {}", slug);
         }
@@ -1273,115 +1331,1421 @@ impl WebFrameworksClient {
         examples
     }
 
-    // ==================== HELPERS ====================
+    // ==================== EXPRESS ====================
 
-    #[allow(clippy::unused_self)]
-    fn extract_text(&self, document: &Html, selector_str: &str) -> Option<String> {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            document
-                .select(&selector)
-                .next()
-                .map(|e| e.text().collect::<String>().trim().to_string())
-                .filter(|s| !s.is_empty())
-        } else {
-            None
+    /// Search Express documentation
+    async fn search_express(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_express_index().await?;
+
+        let query_lower = query.to_lowercase();
+        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let index = self.express_index.read().await;
+        let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
+            .iter()
+            .filter_map(|entry| {
+                let title_lower = entry.title.to_lowercase();
+                let desc_lower = entry.description.to_lowercase();
+
+                let mut score = 0i32;
+                for term in &query_terms {
+                    if title_lower.contains(term) {
+                        score += 15;
+                    }
+                    if desc_lower.contains(term) {
+                        score += 5;
+                    }
+                }
+
+                if score > 0 {
+                    Some((score, entry))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
+
+        Ok(results
+            .into_iter()
+            .take(20)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn ensure_express_index(&self) -> Result<()> {
+        if !self.express_index.read().await.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<Vec<WebFrameworkSearchEntry>>("express_index.json")
+            .await
+        {
+            *self.express_index.write().await = entry.value;
+            return Ok(());
         }
+
+        let index = self.build_express_index();
+        let _ = self
+            .disk_cache
+            .store("express_index.json", index.clone())
+            .await;
+        *self.express_index.write().await = index;
+
+        Ok(())
     }
 
-    #[allow(clippy::unused_self)]
-    fn extract_code_examples(&self, document: &Html, default_lang: &str) -> Vec<CodeExample> {
-        let mut examples = Vec::new();
+    /// Build Express search index from the 5.x API reference and guides
+    fn build_express_index(&self) -> Vec<WebFrameworkSearchEntry> {
+        vec![
+            // ==================== express() ====================
+            self.express_entry("en/5x/api.html#express", "express()", "Create an Express application", "express"),
+            self.express_entry("en/5x/api.html#express.json", "express.json", "Middleware that parses JSON request bodies", "express"),
+            self.express_entry("en/5x/api.html#express.urlencoded", "express.urlencoded", "Middleware that parses URL-encoded form bodies", "express"),
+            self.express_entry("en/5x/api.html#express.raw", "express.raw", "Middleware that parses bodies into a Buffer", "express"),
+            self.express_entry("en/5x/api.html#express.text", "express.text", "Middleware that parses bodies into a string", "express"),
+            self.express_entry("en/5x/api.html#express.static", "express.static", "Middleware that serves static files from a directory", "express"),
+            self.express_entry("en/5x/api.html#express.router", "express.Router", "Create a modular router object", "express"),
+
+            // ==================== Application ====================
+            self.express_entry("en/5x/api.html#app", "app", "The application object returned by express()", "Application"),
+            self.express_entry("en/5x/api.html#app.use", "app.use", "Mount middleware functions at a path", "Application"),
+            self.express_entry("en/5x/api.html#app.METHOD", "app.METHOD", "Route GET, POST, PUT and other HTTP requests", "Application"),
+            self.express_entry("en/5x/api.html#app.get.method", "app.get", "Route HTTP GET requests to a handler", "Application"),
+            self.express_entry("en/5x/api.html#app.post.method", "app.post", "Route HTTP POST requests to a handler", "Application"),
+            self.express_entry("en/5x/api.html#app.put.method", "app.put", "Route HTTP PUT requests to a handler", "Application"),
+            self.express_entry("en/5x/api.html#app.delete.method", "app.delete", "Route HTTP DELETE requests to a handler", "Application"),
+            self.express_entry("en/5x/api.html#app.all", "app.all", "Route every HTTP method at a path", "Application"),
+            self.express_entry("en/5x/api.html#app.route", "app.route", "Chainable route handlers for a single path", "Application"),
+            self.express_entry("en/5x/api.html#app.param", "app.param", "Run a callback for a route parameter", "Application"),
+            self.express_entry("en/5x/api.html#app.listen", "app.listen", "Start an HTTP server on a port", "Application"),
+            self.express_entry("en/5x/api.html#app.set", "app.set", "Assign an application setting", "Application"),
+            self.express_entry("en/5x/api.html#app.get", "app.get (setting)", "Read an application setting", "Application"),
+            self.express_entry("en/5x/api.html#app.settings.table", "Application settings", "trust proxy, view engine, env and other settings", "Application"),
+            self.express_entry("en/5x/api.html#app.engine", "app.engine", "Register a template engine", "Application"),
+            self.express_entry("en/5x/api.html#app.render", "app.render", "Render a view without sending it", "Application"),
+            self.express_entry("en/5x/api.html#app.locals", "app.locals", "Variables available to every rendered view", "Application"),
+            self.express_entry("en/5x/api.html#app.mountpath", "app.mountpath", "Path patterns a sub-app was mounted on", "Application"),
+            self.express_entry("en/5x/api.html#app.path", "app.path", "Canonical path of the application", "Application"),
+
+            // ==================== Request ====================
+            self.express_entry("en/5x/api.html#req", "req", "The request object passed to route handlers", "Request"),
+            self.express_entry("en/5x/api.html#req.params", "req.params", "Named route parameters", "Request"),
+            self.express_entry("en/5x/api.html#req.query", "req.query", "Parsed query string parameters", "Request"),
+            self.express_entry("en/5x/api.html#req.body", "req.body", "Request body populated by body-parsing middleware", "Request"),
+            self.express_entry("en/5x/api.html#req.cookies", "req.cookies", "Cookies sent by the client (cookie-parser)", "Request"),
+            self.express_entry("en/5x/api.html#req.signedCookies", "req.signedCookies", "Signed cookies sent by the client", "Request"),
+            self.express_entry("en/5x/api.html#req.get", "req.get", "Read a request header", "Request"),
+            self.express_entry("en/5x/api.html#req.is", "req.is", "Check the request Content-Type", "Request"),
+            self.express_entry("en/5x/api.html#req.accepts", "req.accepts", "Content negotiation on the Accept header", "Request"),
+            self.express_entry("en/5x/api.html#req.ip", "req.ip", "Remote IP address of the request", "Request"),
+            self.express_entry("en/5x/api.html#req.hostname", "req.hostname", "Host name from the Host header", "Request"),
+            self.express_entry("en/5x/api.html#req.path", "req.path", "Path part of the request URL", "Request"),
+            self.express_entry("en/5x/api.html#req.originalUrl", "req.originalUrl", "Original request URL before routing rewrites", "Request"),
+            self.express_entry("en/5x/api.html#req.baseUrl", "req.baseUrl", "URL path a router instance was mounted on", "Request"),
+            self.express_entry("en/5x/api.html#req.method", "req.method", "HTTP method of the request", "Request"),
+            self.express_entry("en/5x/api.html#req.route", "req.route", "The currently matched route", "Request"),
+            self.express_entry("en/5x/api.html#req.app", "req.app", "Reference to the application handling the request", "Request"),
+
+            // ==================== Response ====================
+            self.express_entry("en/5x/api.html#res", "res", "The response object sent back to the client", "Response"),
+            self.express_entry("en/5x/api.html#res.send", "res.send", "Send a response body", "Response"),
+            self.express_entry("en/5x/api.html#res.json", "res.json", "Send a JSON response", "Response"),
+            self.express_entry("en/5x/api.html#res.status", "res.status", "Set the HTTP status code", "Response"),
+            self.express_entry("en/5x/api.html#res.sendStatus", "res.sendStatus", "Set the status code and send its text", "Response"),
+            self.express_entry("en/5x/api.html#res.redirect", "res.redirect", "Redirect to another URL", "Response"),
+            self.express_entry("en/5x/api.html#res.render", "res.render", "Render a view template and send it", "Response"),
+            self.express_entry("en/5x/api.html#res.sendFile", "res.sendFile", "Send a file as the response", "Response"),
+            self.express_entry("en/5x/api.html#res.download", "res.download", "Send a file as an attachment", "Response"),
+            self.express_entry("en/5x/api.html#res.set", "res.set", "Set response headers", "Response"),
+            self.express_entry("en/5x/api.html#res.cookie", "res.cookie", "Set a cookie", "Response"),
+            self.express_entry("en/5x/api.html#res.clearCookie", "res.clearCookie", "Clear a cookie", "Response"),
+            self.express_entry("en/5x/api.html#res.type", "res.type", "Set the Content-Type header", "Response"),
+            self.express_entry("en/5x/api.html#res.format", "res.format", "Respond based on the Accept header", "Response"),
+            self.express_entry("en/5x/api.html#res.locals", "res.locals", "Variables scoped to the current request's views", "Response"),
+            self.express_entry("en/5x/api.html#res.end", "res.end", "End the response without data", "Response"),
+            self.express_entry("en/5x/api.html#res.headersSent", "res.headersSent", "Whether response headers were already sent", "Response"),
+
+            // ==================== Router ====================
+            self.express_entry("en/5x/api.html#router", "Router", "Isolated instance of middleware and routes", "Router"),
+            self.express_entry("en/5x/api.html#router.use", "router.use", "Mount middleware on a router", "Router"),
+            self.express_entry("en/5x/api.html#router.METHOD", "router.METHOD", "Route GET, POST and other HTTP requests on a router", "Router"),
+            self.express_entry("en/5x/api.html#router.all", "router.all", "Route every HTTP method on a router path", "Router"),
+            self.express_entry("en/5x/api.html#router.route", "router.route", "Chainable route handlers on a router path", "Router"),
+            self.express_entry("en/5x/api.html#router.param", "router.param", "Run a callback for a router parameter", "Router"),
+
+            // ==================== Guides ====================
+            self.express_entry("en/starter/hello-world.html", "Hello world", "Minimal Express application", "Guide"),
+            self.express_entry("en/starter/basic-routing.html", "Basic routing", "Respond to requests for a path and method", "Guide"),
+            self.express_entry("en/starter/static-files.html", "Serving static files", "Serve images, CSS and JavaScript with express.static", "Guide"),
+            self.express_entry("en/guide/routing.html", "Routing", "Route paths, route parameters, route handlers and express.Router", "Guide"),
+            self.express_entry("en/guide/using-template-engines.html", "Template engines", "Render views with Pug, EJS and other engines", "Guide"),
+            self.express_entry("en/guide/error-handling.html", "Error handling", "Catch errors and write error-handling middleware", "Guide"),
+            self.express_entry("en/guide/debugging.html", "Debugging", "Enable Express debug logging", "Guide"),
+            self.express_entry("en/guide/behind-proxies.html", "Behind proxies", "Configure trust proxy for reverse proxies", "Guide"),
+            self.express_entry("en/guide/database-integration.html", "Database integration", "Connect Express apps to databases", "Guide"),
+            self.express_entry("en/guide/migrating-5.html", "Migrating to Express 5", "Breaking changes from Express 4", "Guide"),
+            self.express_entry("en/advanced/best-practice-security.html", "Security best practices", "Helmet, TLS, cookies and input validation", "Guide"),
+            self.express_entry("en/advanced/best-practice-performance.html", "Performance best practices", "Compression, logging and process management", "Guide"),
+
+            // ==================== Middleware ====================
+            self.express_entry("en/guide/using-middleware.html", "Using middleware", "Application-level, router-level, error-handling and third-party middleware", "Middleware"),
+            self.express_entry("en/guide/writing-middleware.html", "Writing middleware", "Write middleware functions that call next()", "Middleware"),
+            self.express_entry("en/resources/middleware.html", "Middleware modules", "Middleware maintained by the Express team", "Middleware"),
+            self.express_entry("en/resources/middleware/body-parser.html", "body-parser", "Parse request bodies middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/cookie-parser.html", "cookie-parser", "Parse Cookie header middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/cors.html", "cors", "Enable CORS middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/morgan.html", "morgan", "HTTP request logger middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/multer.html", "multer", "Handle multipart/form-data uploads middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/session.html", "express-session", "Session middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/compression.html", "compression", "Response compression middleware", "Middleware"),
+            self.express_entry("en/resources/middleware/serve-static.html", "serve-static", "Serve static files middleware", "Middleware"),
+        ]
+    }
 
-        let selectors = [
-            "pre code",
-            "pre.language-js",
-            "pre.language-jsx",
-            "pre.language-tsx",
-            "pre.language-typescript",
-            ".code-block pre",
-            ".highlight pre",
-        ];
+    fn express_entry(&self, slug: &str, title: &str, description: &str, category: &str) -> WebFrameworkSearchEntry {
+        WebFrameworkSearchEntry {
+            framework: WebFramework::Express,
+            slug: slug.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            url: format!("{}/{}", EXPRESS_BASE, slug),
+            category: Some(category.to_string()),
+        }
+    }
 
-        for selector_str in selectors {
-            if let Ok(selector) = Selector::parse(selector_str) {
-                for element in document.select(&selector) {
-                    let code = element.text().collect::<String>().trim().to_string();
-                    if code.is_empty() || code.len() < 20 {
-                        continue;
-                    }
+    async fn fetch_express_article(&self, slug: &str) -> Result<WebFrameworkArticle> {
+        let cache_key = format!("express_{}.json", slug.replace('/', "_").replace('#', "_"));
 
-                    // Determine language from class
-                    let class = element.value().attr("class").unwrap_or("");
-                    let language = if class.contains("tsx") || class.contains("typescript") {
-                        "typescript"
-                    } else if class.contains("jsx") {
-                        "jsx"
-                    } else if class.contains("js") || class.contains("javascript") {
-                        "javascript"
-                    } else {
-                        default_lang
-                    };
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<WebFrameworkArticle>(&cache_key)
+            .await
+        {
+            return Ok(entry.value);
+        }
 
-                    // Get description from preceding element
-                    let description = element
-                        .prev_siblings()
-                        .filter_map(scraper::ElementRef::wrap)
-                        .find(|e| e.value().name() == "p")
-                        .map(|e| e.text().collect::<String>().trim().to_string());
+        let fetch_slug = slug.split('#').next().unwrap_or(slug);
+        let url = format!("{}/{}", EXPRESS_BASE, fetch_slug);
+        debug!(url = %url, "Fetching Express article");
 
-                    // Get filename if present
-                    let filename = element
-                        .prev_siblings()
-                        .filter_map(scraper::ElementRef::wrap)
-                        .find(|e| {
-                                    e.value().name() == "div"
-                                        && e.value()
-                                            .attr("class")
-                                            .is_some_and(|c| c.contains("filename"))
-                        })
-                        .map(|e| e.text().collect::<String>().trim().to_string());
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("Express page not found: {}", slug);
+        }
 
-                    let is_complete = code.contains("import ") || code.contains("export ");
-                    let has_output =
-                        code.contains("console.log") || code.contains("// Output:");
+        let html = response.text().await?;
+        let article = self.parse_express_html(&html, slug, &format!("{}/{}", EXPRESS_BASE, slug));
 
-                    examples.push(CodeExample {
-                        code,
-                        language: language.to_string(),
-                        filename,
-                        description,
-                        is_complete,
-                        has_output,
-                    });
+        let _ = self.disk_cache.store(&cache_key, article.clone()).await;
 
-                    if examples.len() >= 5 {
-                        break;
+        Ok(article)
+    }
+
+    fn parse_express_html(&self, html: &str, slug: &str, url: &str) -> WebFrameworkArticle {
+        let document = Html::parse_document(html);
+
+        // The API reference is a single page; an anchored slug keeps only that entry's section
+        if let Some(article) = slug
+            .split_once('#')
+            .and_then(|(_, anchor)| {
+                self.parse_anchored_section(&document, WebFramework::Express, anchor, slug, url, "javascript")
+            })
+        {
+            return article;
+        }
+
+        let title = self
+            .extract_text(&document, "h1")
+            .unwrap_or_else(|| slug.split('/').next_back().unwrap_or("Express").replace(".html", "").replace('-', " "));
+
+        let description = self
+            .extract_text(&document, "#page-doc > p:first-of-type, main p:first-of-type")
+            .unwrap_or_else(|| format!("Express documentation for {title}"));
+
+        let examples = self.extract_code_examples(&document, "javascript");
+
+        let content = self
+            .extract_text(&document, "#page-doc, article, main")
+            .map(|s| truncate_chars(s, 4000))
+            .unwrap_or_default();
+
+        WebFrameworkArticle {
+            framework: WebFramework::Express,
+            slug: slug.to_string(),
+            title,
+            description,
+            content,
+            examples,
+            api_signature: None,
+            related: Vec::new(),
+            url: url.to_string(),
+        }
+    }
+
+    // ==================== ASTRO ====================
+
+    /// Search Astro documentation
+    async fn search_astro(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_astro_index().await?;
+
+        let query_lower = query.to_lowercase();
+        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let index = self.astro_index.read().await;
+        let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
+            .iter()
+            .filter_map(|entry| {
+                let title_lower = entry.title.to_lowercase();
+                let desc_lower = entry.description.to_lowercase();
+
+                let mut score = 0i32;
+                for term in &query_terms {
+                    if title_lower.contains(term) {
+                        score += 15;
+                    }
+                    if desc_lower.contains(term) {
+                        score += 5;
                     }
                 }
-            }
 
-            if examples.len() >= 5 {
-                break;
-            }
+                if score > 0 {
+                    Some((score, entry))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
+
+        Ok(results
+            .into_iter()
+            .take(20)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn ensure_astro_index(&self) -> Result<()> {
+        if !self.astro_index.read().await.is_empty() {
+            return Ok(());
         }
 
-        // Sort by quality score
-        examples.sort_by_key(|example| Reverse(example.quality_score()));
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<Vec<WebFrameworkSearchEntry>>("astro_index.json")
+            .await
+        {
+            *self.astro_index.write().await = entry.value;
+            return Ok(());
+        }
 
-        examples
+        let index = self.build_astro_index();
+        let _ = self
+            .disk_cache
+            .store("astro_index.json", index.clone())
+            .await;
+        *self.astro_index.write().await = index;
+
+        Ok(())
     }
 
-    pub fn cache_dir(&self) -> &PathBuf {
-        &self.cache_dir
+    /// Build Astro search index covering routing, data loading and content collections
+    fn build_astro_index(&self) -> Vec<WebFrameworkSearchEntry> {
+        vec![
+            // ==================== Components & Pages ====================
+            self.astro_entry("en/basics/astro-components/", "Astro components", "Component script, template and props in .astro files", "Components"),
+            self.astro_entry("en/basics/astro-pages/", "Pages", "Files in src/pages become routes", "Components"),
+            self.astro_entry("en/basics/layouts/", "Layouts", "Reusable page shells with <slot />", "Components"),
+            self.astro_entry("en/guides/framework-components/", "Framework components", "Use React, Vue, Svelte and Solid components in Astro", "Components"),
+            self.astro_entry("en/reference/directives-reference/#client-directives", "client:* directives", "client:load, client:idle, client:visible and client:only hydration", "Components"),
+            self.astro_entry("en/concepts/islands/", "Islands", "Interactive islands in otherwise static HTML", "Components"),
+            self.astro_entry("en/guides/server-islands/", "Server islands", "Defer dynamic server-rendered components with server:defer", "Components"),
+
+            // ==================== Routing ====================
+            self.astro_entry("en/guides/routing/", "Routing", "File-based routing, dynamic [param] routes and rest parameters", "Routing"),
+            self.astro_entry("en/reference/routing-reference/#getstaticpaths", "getStaticPaths", "Generate the pages of a dynamic route at build time", "Routing"),
+            self.astro_entry("en/reference/routing-reference/#paginate", "paginate", "Split a collection into numbered pages", "Routing"),
+            self.astro_entry("en/reference/routing-reference/#prerender", "prerender", "Opt a route in or out of prerendering", "Routing"),
+            self.astro_entry("en/guides/endpoints/", "Endpoints", "API routes that return JSON or files from GET and POST handlers", "Routing"),
+            self.astro_entry("en/guides/middleware/", "Middleware", "onRequest handlers, context.locals and sequence()", "Routing"),
+            self.astro_entry("en/guides/on-demand-rendering/", "On-demand rendering", "Server-render routes per request with an adapter", "Routing"),
+            self.astro_entry("en/guides/view-transitions/", "View transitions", "Animated client-side navigation with <ClientRouter />", "Routing"),
+            self.astro_entry("en/guides/prefetch/", "Prefetch", "Prefetch links before they are visited", "Routing"),
+
+            // ==================== Astro global ====================
+            self.astro_entry("en/reference/api-reference/", "Astro global", "Astro.props, Astro.params, Astro.request and the render context", "API"),
+            self.astro_entry("en/reference/api-reference/#props", "Astro.props", "Props passed to a component or page", "API"),
+            self.astro_entry("en/reference/api-reference/#params", "Astro.params", "Dynamic route segment values", "API"),
+            self.astro_entry("en/reference/api-reference/#request", "Astro.request", "The incoming Request object", "API"),
+            self.astro_entry("en/reference/api-reference/#cookies", "Astro.cookies", "Read and set cookies in on-demand routes", "API"),
+            self.astro_entry("en/reference/api-reference/#redirect", "Astro.redirect", "Redirect to another page", "API"),
+            self.astro_entry("en/reference/api-reference/#url", "Astro.url", "URL of the current request", "API"),
+            self.astro_entry("en/reference/api-reference/#locals", "Astro.locals", "Values set by middleware for this request", "API"),
+            self.astro_entry("en/reference/api-reference/#slots", "Astro.slots", "Check for and render named slots", "API"),
+
+            // ==================== Data loading ====================
+            self.astro_entry("en/guides/data-fetching/", "Data fetching", "Top-level await fetch() in component scripts", "Data"),
+            self.astro_entry("en/guides/content-collections/", "Content collections", "Type-safe Markdown, MDX and JSON collections with loaders", "Data"),
+            self.astro_entry("en/reference/modules/astro-content/#getcollection", "getCollection", "Query every entry in a content collection", "Data"),
+            self.astro_entry("en/reference/modules/astro-content/#getentry", "getEntry", "Fetch one content collection entry", "Data"),
+            self.astro_entry("en/reference/modules/astro-content/#definecollection", "defineCollection", "Declare a collection with a loader and schema", "Data"),
+            self.astro_entry("en/reference/modules/astro-content/#render", "render", "Render a collection entry to a <Content /> component", "Data"),
+            self.astro_entry("en/reference/content-loader-reference/", "Content loaders", "glob(), file() and custom content loaders", "Data"),
+            self.astro_entry("en/guides/actions/", "Actions", "Type-safe server functions called from forms and client code", "Data"),
+            self.astro_entry("en/reference/modules/astro-actions/#defineaction", "defineAction", "Define a server action with input validation", "Data"),
+            self.astro_entry("en/guides/sessions/", "Sessions", "Server-side session storage with Astro.session", "Data"),
+            self.astro_entry("en/guides/environment-variables/", "Environment variables", "import.meta.env and astro:env schema", "Data"),
+
+            // ==================== Content & Assets ====================
+            self.astro_entry("en/guides/markdown-content/", "Markdown", "Markdown pages, frontmatter and remark plugins", "Content"),
+            self.astro_entry("en/guides/images/", "Images", "<Image /> and <Picture /> from astro:assets", "Content"),
+            self.astro_entry("en/guides/styling/", "Styling", "Scoped styles, global CSS and Tailwind", "Content"),
+
+            // ==================== Configuration ====================
+            self.astro_entry("en/reference/configuration-reference/", "astro.config", "Configuration reference for astro.config.mjs", "Config"),
+            self.astro_entry("en/guides/integrations-guide/", "Integrations", "Add frameworks, adapters and tools with astro add", "Config"),
+            self.astro_entry("en/guides/deploy/", "Deploy", "Deploy static and server-rendered sites", "Config"),
+        ]
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn astro_entry(&self, slug: &str, title: &str, description: &str, category: &str) -> WebFrameworkSearchEntry {
+        WebFrameworkSearchEntry {
+            framework: WebFramework::Astro,
+            slug: slug.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            url: format!("{}/{}", ASTRO_BASE, slug),
+            category: Some(category.to_string()),
+        }
+    }
 
-    #[test]
-    fn test_client_creation() {
-        let _client = WebFrameworksClient::new();
+    async fn fetch_astro_article(&self, slug: &str) -> Result<WebFrameworkArticle> {
+        let cache_key = format!("astro_{}.json", slug.replace('/', "_").replace('#', "_"));
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<WebFrameworkArticle>(&cache_key)
+            .await
+        {
+            return Ok(entry.value);
+        }
+
+        let fetch_slug = slug.split('#').next().unwrap_or(slug);
+        let url = format!("{}/{}", ASTRO_BASE, fetch_slug);
+        debug!(url = %url, "Fetching Astro article");
+
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("Astro page not found: {}", slug);
+        }
+
+        let html = response.text().await?;
+        let article = self.parse_astro_html(&html, slug, &url);
+
+        let _ = self.disk_cache.store(&cache_key, article.clone()).await;
+
+        Ok(article)
+    }
+
+    fn parse_astro_html(&self, html: &str, slug: &str, url: &str) -> WebFrameworkArticle {
+        let document = Html::parse_document(html);
+
+        let title = self
+            .extract_text(&document, "h1")
+            .unwrap_or_else(|| slug.trim_end_matches('/').split('/').next_back().unwrap_or("Astro").replace('-', " "));
+
+        let description = self
+            .extract_text(&document, ".sl-markdown-content > p:first-of-type, main p:first-of-type")
+            .unwrap_or_else(|| format!("Astro documentation for {title}"));
+
+        let examples = self.extract_code_examples(&document, "astro");
+
+        let content = self
+            .extract_text(&document, ".sl-markdown-content, main")
+            .map(|s| truncate_chars(s, 4000))
+            .unwrap_or_default();
+
+        WebFrameworkArticle {
+            framework: WebFramework::Astro,
+            slug: slug.to_string(),
+            title,
+            description,
+            content,
+            examples,
+            api_signature: None,
+            related: Vec::new(),
+            url: url.to_string(),
+        }
+    }
+
+    // ==================== REACT ROUTER ====================
+
+    /// Search React Router documentation
+    async fn search_react_router(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_react_router_index().await?;
+
+        let query_lower = query.to_lowercase();
+        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let index = self.react_router_index.read().await;
+        let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
+            .iter()
+            .filter_map(|entry| {
+                let title_lower = entry.title.to_lowercase();
+                let desc_lower = entry.description.to_lowercase();
+
+                let mut score = 0i32;
+                for term in &query_terms {
+                    if title_lower.contains(term) {
+                        score += 15;
+                    }
+                    if desc_lower.contains(term) {
+                        score += 5;
+                    }
+                }
+
+                if score > 0 {
+                    Some((score, entry))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
+
+        Ok(results
+            .into_iter()
+            .take(20)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn ensure_react_router_index(&self) -> Result<()> {
+        if !self.react_router_index.read().await.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<Vec<WebFrameworkSearchEntry>>("react_router_index.json")
+            .await
+        {
+            *self.react_router_index.write().await = entry.value;
+            return Ok(());
+        }
+
+        let index = self.build_react_router_index();
+        let _ = self
+            .disk_cache
+            .store("react_router_index.json", index.clone())
+            .await;
+        *self.react_router_index.write().await = index;
+
+        Ok(())
+    }
+
+    /// Build React Router v7 search index covering framework-mode routing and data APIs
+    fn build_react_router_index(&self) -> Vec<WebFrameworkSearchEntry> {
+        vec![
+            // ==================== Routing ====================
+            self.react_router_entry("start/framework/routing", "Routing", "Configure routes in app/routes.ts with route(), index() and layout()", "Routing"),
+            self.react_router_entry("api/framework-conventions/routes.ts", "routes.ts", "Route configuration file for framework mode", "Routing"),
+            self.react_router_entry("how-to/file-route-conventions", "File route conventions", "flatRoutes() file-based routing (the Remix convention)", "Routing"),
+            self.react_router_entry("start/framework/route-module", "Route module", "Exports a route file can define: loader, action, meta and more", "Routing"),
+            self.react_router_entry("start/framework/navigating", "Navigating", "Link, NavLink, Form, redirect and useNavigate", "Routing"),
+            self.react_router_entry("start/framework/pending-ui", "Pending UI", "Show pending states with useNavigation and fetchers", "Routing"),
+            self.react_router_entry("how-to/resource-routes", "Resource routes", "Routes that return data or files instead of UI", "Routing"),
+            self.react_router_entry("how-to/middleware", "Middleware", "Route middleware that runs before loaders and actions", "Routing"),
+            self.react_router_entry("how-to/error-boundary", "Error boundaries", "Render errors thrown from loaders, actions and components", "Routing"),
+            self.react_router_entry("start/data/routing", "Data mode routing", "createBrowserRouter route objects", "Routing"),
+            self.react_router_entry("start/declarative/routing", "Declarative routing", "<Routes> and <Route> without data APIs", "Routing"),
+
+            // ==================== Data loading ====================
+            self.react_router_entry("start/framework/data-loading", "Data loading", "Load route data with loader and clientLoader", "Data"),
+            self.react_router_entry("start/framework/route-module#loader", "loader", "Server data loader for a route", "Data"),
+            self.react_router_entry("start/framework/route-module#clientloader", "clientLoader", "Browser-only data loader for a route", "Data"),
+            self.react_router_entry("start/framework/actions", "Actions", "Handle form submissions with action and clientAction", "Data"),
+            self.react_router_entry("start/framework/route-module#action", "action", "Server mutation handler for a route", "Data"),
+            self.react_router_entry("start/framework/route-module#clientaction", "clientAction", "Browser-only mutation handler for a route", "Data"),
+            self.react_router_entry("start/framework/route-module#shouldrevalidate", "shouldRevalidate", "Opt out of revalidating loader data", "Data"),
+            self.react_router_entry("start/framework/route-module#headers", "headers", "Set HTTP headers for a route", "Data"),
+            self.react_router_entry("start/framework/route-module#meta", "meta", "Route title and meta tags", "Data"),
+            self.react_router_entry("how-to/fetchers", "Fetchers", "Load and mutate data without navigating", "Data"),
+            self.react_router_entry("how-to/form-validation", "Form validation", "Return validation errors from actions", "Data"),
+            self.react_router_entry("api/utils/redirect", "redirect", "Redirect from a loader or action", "Data"),
+            self.react_router_entry("api/utils/data", "data", "Return data with a status code and headers", "Data"),
+
+            // ==================== Hooks ====================
+            self.react_router_entry("api/hooks/useLoaderData", "useLoaderData", "Read data returned by the route loader", "Hooks"),
+            self.react_router_entry("api/hooks/useActionData", "useActionData", "Read data returned by the last action", "Hooks"),
+            self.react_router_entry("api/hooks/useFetcher", "useFetcher", "Call loaders and actions without navigating", "Hooks"),
+            self.react_router_entry("api/hooks/useNavigation", "useNavigation", "Current navigation state for pending UI", "Hooks"),
+            self.react_router_entry("api/hooks/useNavigate", "useNavigate", "Navigate programmatically", "Hooks"),
+            self.react_router_entry("api/hooks/useParams", "useParams", "Dynamic params of the matched route", "Hooks"),
+            self.react_router_entry("api/hooks/useSearchParams", "useSearchParams", "Read and update URL search params", "Hooks"),
+            self.react_router_entry("api/hooks/useSubmit", "useSubmit", "Submit a form programmatically", "Hooks"),
+            self.react_router_entry("api/hooks/useRouteError", "useRouteError", "Error thrown while rendering or loading a route", "Hooks"),
+            self.react_router_entry("api/hooks/useRevalidator", "useRevalidator", "Revalidate loader data manually", "Hooks"),
+            self.react_router_entry("api/hooks/useMatches", "useMatches", "Matched routes with their data", "Hooks"),
+            self.react_router_entry("api/hooks/useLocation", "useLocation", "Current location object", "Hooks"),
+
+            // ==================== Components ====================
+            self.react_router_entry("api/components/Form", "Form", "Progressively enhanced form that submits to an action", "Components"),
+            self.react_router_entry("api/components/Link", "Link", "Client-side navigation link", "Components"),
+            self.react_router_entry("api/components/NavLink", "NavLink", "Link that knows whether it is active", "Components"),
+            self.react_router_entry("api/components/Outlet", "Outlet", "Render the matched child route", "Components"),
+            self.react_router_entry("api/components/Await", "Await", "Render a deferred promise from a loader", "Components"),
+            self.react_router_entry("api/components/Meta", "Meta", "Render route meta tags in the root", "Components"),
+            self.react_router_entry("api/components/Links", "Links", "Render route link tags in the root", "Components"),
+            self.react_router_entry("api/components/Scripts", "Scripts", "Render the client scripts in the root", "Components"),
+            self.react_router_entry("api/data-routers/createBrowserRouter", "createBrowserRouter", "Create a data router for the browser", "Components"),
+            self.react_router_entry("api/data-routers/RouterProvider", "RouterProvider", "Render a data router", "Components"),
+
+            // ==================== Setup ====================
+            self.react_router_entry("start/framework/installation", "Installation", "Create a framework-mode app", "Guide"),
+            self.react_router_entry("start/framework/rendering", "Rendering strategies", "SSR, client rendering and pre-rendering", "Guide"),
+            self.react_router_entry("how-to/pre-rendering", "Pre-rendering", "Pre-render routes at build time", "Guide"),
+            self.react_router_entry("how-to/spa", "SPA mode", "Ship a single-page app without a server", "Guide"),
+            self.react_router_entry("api/framework-conventions/react-router.config.ts", "react-router.config.ts", "Framework configuration file", "Guide"),
+            self.react_router_entry("upgrading/remix", "Upgrading from Remix", "Move a Remix v2 app to React Router v7", "Guide"),
+        ]
+    }
+
+    fn react_router_entry(&self, slug: &str, title: &str, description: &str, category: &str) -> WebFrameworkSearchEntry {
+        WebFrameworkSearchEntry {
+            framework: WebFramework::ReactRouter,
+            slug: slug.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            url: format!("{}/{}", REACT_ROUTER_BASE, slug),
+            category: Some(category.to_string()),
+        }
+    }
+
+    async fn fetch_react_router_article(&self, slug: &str) -> Result<WebFrameworkArticle> {
+        let cache_key = format!("react_router_{}.json", slug.replace('/', "_").replace('#', "_"));
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<WebFrameworkArticle>(&cache_key)
+            .await
+        {
+            return Ok(entry.value);
+        }
+
+        let fetch_slug = slug.split('#').next().unwrap_or(slug);
+        let url = format!("{}/{}", REACT_ROUTER_BASE, fetch_slug);
+        debug!(url = %url, "Fetching React Router article");
+
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("React Router page not found: {}", slug);
+        }
+
+        let html = response.text().await?;
+        let article = self.parse_react_router_html(&html, slug, &url);
+
+        let _ = self.disk_cache.store(&cache_key, article.clone()).await;
+
+        Ok(article)
+    }
+
+    fn parse_react_router_html(&self, html: &str, slug: &str, url: &str) -> WebFrameworkArticle {
+        let document = Html::parse_document(html);
+
+        let title = self
+            .extract_text(&document, "h1")
+            .unwrap_or_else(|| slug.split('/').next_back().unwrap_or("React Router").to_string());
+
+        let description = self
+            .extract_text(&document, "main p:first-of-type")
+            .unwrap_or_else(|| format!("React Router documentation for {title}"));
+
+        let examples = self.extract_code_examples(&document, "tsx");
+
+        let content = self
+            .extract_text(&document, "main, article")
+            .map(|s| truncate_chars(s, 4000))
+            .unwrap_or_default();
+
+        WebFrameworkArticle {
+            framework: WebFramework::ReactRouter,
+            slug: slug.to_string(),
+            title,
+            description,
+            content,
+            examples,
+            api_signature: None,
+            related: Vec::new(),
+            url: url.to_string(),
+        }
+    }
+
+    // ==================== REACT NATIVE ====================
+
+    /// Search React Native documentation
+    async fn search_react_native(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_react_native_index().await?;
+
+        let query_lower = query.to_lowercase();
+        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let index = self.react_native_index.read().await;
+        let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
+            .iter()
+            .filter_map(|entry| {
+                let title_lower = entry.title.to_lowercase();
+                let desc_lower = entry.description.to_lowercase();
+
+                let mut score = 0i32;
+                for term in &query_terms {
+                    if title_lower.contains(term) {
+                        score += 15;
+                    }
+                    if desc_lower.contains(term) {
+                        score += 5;
+                    }
+                }
+
+                if score > 0 {
+                    Some((score, entry))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
+
+        Ok(results
+            .into_iter()
+            .take(20)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn ensure_react_native_index(&self) -> Result<()> {
+        if !self.react_native_index.read().await.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<Vec<WebFrameworkSearchEntry>>("react_native_index.json")
+            .await
+        {
+            *self.react_native_index.write().await = entry.value;
+            return Ok(());
+        }
+
+        let index = self.build_react_native_index();
+        let _ = self
+            .disk_cache
+            .store("react_native_index.json", index.clone())
+            .await;
+        *self.react_native_index.write().await = index;
+
+        Ok(())
+    }
+
+    /// Build React Native search index covering core components, APIs and the New Architecture
+    fn build_react_native_index(&self) -> Vec<WebFrameworkSearchEntry> {
+        vec![
+            // ==================== Core Components ====================
+            self.react_native_entry("docs/view", "View", "Container that supports layout with flexbox, style and touch handling", "Components"),
+            self.react_native_entry("docs/text", "Text", "Display, style and nest text", "Components"),
+            self.react_native_entry("docs/image", "Image", "Display network, static and local images", "Components"),
+            self.react_native_entry("docs/imagebackground", "ImageBackground", "Image rendered behind child views", "Components"),
+            self.react_native_entry("docs/textinput", "TextInput", "Text entry through the keyboard", "Components"),
+            self.react_native_entry("docs/scrollview", "ScrollView", "Scrolling container that renders all children", "Components"),
+            self.react_native_entry("docs/flatlist", "FlatList", "Performant virtualized list for flat data", "Components"),
+            self.react_native_entry("docs/sectionlist", "SectionList", "Virtualized list with section headers", "Components"),
+            self.react_native_entry("docs/virtualizedlist", "VirtualizedList", "Base implementation behind FlatList and SectionList", "Components"),
+            self.react_native_entry("docs/pressable", "Pressable", "Detect press interactions on any child", "Components"),
+            self.react_native_entry("docs/touchableopacity", "TouchableOpacity", "Dim a view while it is pressed (legacy; prefer Pressable)", "Components"),
+            self.react_native_entry("docs/button", "Button", "Basic platform button", "Components"),
+            self.react_native_entry("docs/switch", "Switch", "Boolean on/off input", "Components"),
+            self.react_native_entry("docs/modal", "Modal", "Present content above an enclosing view", "Components"),
+            self.react_native_entry("docs/activityindicator", "ActivityIndicator", "Circular loading indicator", "Components"),
+            self.react_native_entry("docs/refreshcontrol", "RefreshControl", "Pull-to-refresh for ScrollView and lists", "Components"),
+            self.react_native_entry("docs/safeareaview", "SafeAreaView", "Render content inside device safe area insets", "Components"),
+            self.react_native_entry("docs/keyboardavoidingview", "KeyboardAvoidingView", "Move content out of the way of the keyboard", "Components"),
+            self.react_native_entry("docs/statusbar", "StatusBar", "Control the app status bar", "Components"),
+
+            // ==================== APIs ====================
+            self.react_native_entry("docs/stylesheet", "StyleSheet", "StyleSheet.create and style composition", "APIs"),
+            self.react_native_entry("docs/dimensions", "Dimensions", "Window and screen dimensions", "APIs"),
+            self.react_native_entry("docs/usewindowdimensions", "useWindowDimensions", "Hook that updates on window size changes", "APIs"),
+            self.react_native_entry("docs/usecolorscheme", "useColorScheme", "Hook for the light or dark color scheme", "APIs"),
+            self.react_native_entry("docs/platform", "Platform", "Platform.OS, Platform.select and version checks", "APIs"),
+            self.react_native_entry("docs/appstate", "AppState", "Foreground and background app state", "APIs"),
+            self.react_native_entry("docs/alert", "Alert", "Native alert dialogs", "APIs"),
+            self.react_native_entry("docs/animated", "Animated", "Declarative animations with Animated.Value", "APIs"),
+            self.react_native_entry("docs/easing", "Easing", "Easing functions for animations", "APIs"),
+            self.react_native_entry("docs/layoutanimation", "LayoutAnimation", "Animate the next layout change", "APIs"),
+            self.react_native_entry("docs/linking", "Linking", "Open URLs and handle deep links", "APIs"),
+            self.react_native_entry("docs/appearance", "Appearance", "Color scheme preferences", "APIs"),
+            self.react_native_entry("docs/pixelratio", "PixelRatio", "Device pixel density", "APIs"),
+            self.react_native_entry("docs/keyboard", "Keyboard", "Listen to and dismiss the keyboard", "APIs"),
+            self.react_native_entry("docs/panresponder", "PanResponder", "Reconcile multi-touch gestures", "APIs"),
+            self.react_native_entry("docs/share", "Share", "Open the native share sheet", "APIs"),
+            self.react_native_entry("docs/vibration", "Vibration", "Vibrate the device", "APIs"),
+            self.react_native_entry("docs/accessibilityinfo", "AccessibilityInfo", "Query screen reader and reduce motion state", "APIs"),
+            self.react_native_entry("docs/permissionsandroid", "PermissionsAndroid", "Request Android runtime permissions", "APIs"),
+            self.react_native_entry("docs/backhandler", "BackHandler", "Handle the Android back button", "APIs"),
+            self.react_native_entry("docs/i18nmanager", "I18nManager", "Right-to-left layout support", "APIs"),
+
+            // ==================== New Architecture ====================
+            self.react_native_entry("architecture/landing-page", "New Architecture", "Fabric renderer, Turbo Native Modules, JSI and bridgeless mode", "New Architecture"),
+            self.react_native_entry("docs/turbo-native-modules-introduction", "Turbo Native Modules", "Call platform code from JavaScript through typed specs", "New Architecture"),
+            self.react_native_entry("docs/fabric-native-components-introduction", "Fabric Native Components", "Render host views with the Fabric renderer", "New Architecture"),
+            self.react_native_entry("docs/the-new-architecture/pure-cxx-modules", "Pure C++ Turbo Modules", "Cross-platform C++ native modules", "New Architecture"),
+            self.react_native_entry("docs/the-new-architecture/using-codegen", "Codegen", "Generate native interfaces from TypeScript specs", "New Architecture"),
+            self.react_native_entry("docs/the-new-architecture/create-module-library", "Native module libraries", "Publish Turbo Modules as a library", "New Architecture"),
+            self.react_native_entry("architecture/render-pipeline", "Render pipeline", "How Fabric renders, commits and mounts", "New Architecture"),
+            self.react_native_entry("architecture/threading-model", "Threading model", "JS, UI and background threads", "New Architecture"),
+
+            // ==================== Guides ====================
+            self.react_native_entry("docs/environment-setup", "Get started", "Create a new React Native app", "Guide"),
+            self.react_native_entry("docs/intro-react-native-components", "Core components and native components", "How React components map to native views", "Guide"),
+            self.react_native_entry("docs/style", "Style", "Style props and StyleSheet", "Guide"),
+            self.react_native_entry("docs/flexbox", "Layout with Flexbox", "flexDirection, justifyContent and alignItems", "Guide"),
+            self.react_native_entry("docs/handling-text-input", "Handling text input", "Read and react to TextInput changes", "Guide"),
+            self.react_native_entry("docs/using-a-listview", "Using list views", "FlatList and SectionList basics", "Guide"),
+            self.react_native_entry("docs/optimizing-flatlist-configuration", "Optimizing FlatList", "windowSize, getItemLayout and other list performance props", "Guide"),
+            self.react_native_entry("docs/navigation", "Navigation", "Navigating between screens with React Navigation", "Guide"),
+            self.react_native_entry("docs/network", "Networking", "fetch, WebSocket and network security", "Guide"),
+            self.react_native_entry("docs/animations", "Animations", "Animated and LayoutAnimation guide", "Guide"),
+            self.react_native_entry("docs/platform-specific-code", "Platform-specific code", "Platform module and .ios.js / .android.js files", "Guide"),
+            self.react_native_entry("docs/accessibility", "Accessibility", "Accessibility props and screen readers", "Guide"),
+            self.react_native_entry("docs/performance", "Performance", "Frame rates, JS thread and UI thread work", "Guide"),
+            self.react_native_entry("docs/hermes", "Hermes", "The Hermes JavaScript engine", "Guide"),
+            self.react_native_entry("docs/debugging", "Debugging", "React Native DevTools and the dev menu", "Guide"),
+            self.react_native_entry("docs/typescript", "TypeScript", "Using TypeScript with React Native", "Guide"),
+            self.react_native_entry("docs/testing-overview", "Testing", "Unit, component and end-to-end tests", "Guide"),
+        ]
+    }
+
+    fn react_native_entry(&self, slug: &str, title: &str, description: &str, category: &str) -> WebFrameworkSearchEntry {
+        WebFrameworkSearchEntry {
+            framework: WebFramework::ReactNative,
+            slug: slug.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            url: format!("{}/{}", REACT_NATIVE_BASE, slug),
+            category: Some(category.to_string()),
+        }
+    }
+
+    async fn fetch_react_native_article(&self, slug: &str) -> Result<WebFrameworkArticle> {
+        let cache_key = format!("react_native_{}.json", slug.replace('/', "_").replace('#', "_"));
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<WebFrameworkArticle>(&cache_key)
+            .await
+        {
+            return Ok(entry.value);
+        }
+
+        let fetch_slug = slug.split('#').next().unwrap_or(slug);
+        let url = format!("{}/{}", REACT_NATIVE_BASE, fetch_slug);
+        debug!(url = %url, "Fetching React Native article");
+
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("React Native page not found: {}", slug);
+        }
+
+        let html = response.text().await?;
+        let article = self.parse_react_native_html(&html, slug, &url);
+
+        let _ = self.disk_cache.store(&cache_key, article.clone()).await;
+
+        Ok(article)
+    }
+
+    fn parse_react_native_html(&self, html: &str, slug: &str, url: &str) -> WebFrameworkArticle {
+        let document = Html::parse_document(html);
+
+        let title = self
+            .extract_text(&document, "h1")
+            .unwrap_or_else(|| slug.split('/').next_back().unwrap_or("React Native").to_string());
+
+        let description = self
+            .extract_text(&document, ".theme-doc-markdown > p:first-of-type, article p:first-of-type")
+            .unwrap_or_else(|| format!("React Native documentation for {title}"));
+
+        let examples = self.extract_code_examples(&document, "tsx");
+
+        let content = self
+            .extract_text(&document, ".theme-doc-markdown, article, main")
+            .map(|s| truncate_chars(s, 4000))
+            .unwrap_or_default();
+
+        WebFrameworkArticle {
+            framework: WebFramework::ReactNative,
+            slug: slug.to_string(),
+            title,
+            description,
+            content,
+            examples,
+            api_signature: None,
+            related: Vec::new(),
+            url: url.to_string(),
+        }
+    }
+
+    // ==================== TYPESCRIPT ====================
+
+    /// Search TypeScript documentation
+    async fn search_typescript(&self, query: &str) -> Result<Vec<WebFrameworkSearchEntry>> {
+        self.ensure_typescript_index().await?;
+
+        let query_lower = query.to_lowercase();
+        let query_terms: Vec<&str> = query_lower.split_whitespace().collect();
+
+        let index = self.typescript_index.read().await;
+        let mut results: Vec<(i32, &WebFrameworkSearchEntry)> = index
+            .iter()
+            .filter_map(|entry| {
+                let title_lower = entry.title.to_lowercase();
+                let desc_lower = entry.description.to_lowercase();
+
+                let mut score = 0i32;
+                for term in &query_terms {
+                    if title_lower.contains(term) {
+                        score += 15;
+                    }
+                    if desc_lower.contains(term) {
+                        score += 5;
+                    }
+                }
+
+                if score > 0 {
+                    Some((score, entry))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        results.sort_by_key(|r| std::cmp::Reverse(r.0));
+
+        Ok(results
+            .into_iter()
+            .take(20)
+            .map(|(_, e)| e.clone())
+            .collect())
+    }
+
+    async fn ensure_typescript_index(&self) -> Result<()> {
+        if !self.typescript_index.read().await.is_empty() {
+            return Ok(());
+        }
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<Vec<WebFrameworkSearchEntry>>("typescript_index.json")
+            .await
+        {
+            *self.typescript_index.write().await = entry.value;
+            return Ok(());
+        }
+
+        let index = self.build_typescript_index();
+        let _ = self
+            .disk_cache
+            .store("typescript_index.json", index.clone())
+            .await;
+        *self.typescript_index.write().await = index;
+
+        Ok(())
+    }
+
+    /// Build TypeScript search index: Handbook chapters, utility types and tsconfig options
+    fn build_typescript_index(&self) -> Vec<WebFrameworkSearchEntry> {
+        vec![
+            // ==================== Handbook ====================
+            self.typescript_entry("docs/handbook/2/basic-types.html", "The Basics", "Static type checking, tsc and strictness flags", "Handbook"),
+            self.typescript_entry("docs/handbook/2/everyday-types.html", "Everyday Types", "Primitives, arrays, any, unions, type aliases and interfaces", "Handbook"),
+            self.typescript_entry("docs/handbook/2/everyday-types.html#differences-between-type-aliases-and-interfaces", "Type aliases vs interfaces", "Differences between type and interface declarations", "Handbook"),
+            self.typescript_entry("docs/handbook/2/narrowing.html", "Narrowing", "typeof, instanceof, in and truthiness narrowing", "Handbook"),
+            self.typescript_entry("docs/handbook/2/narrowing.html#using-type-predicates", "Type predicates", "User-defined type guards with `x is T`", "Handbook"),
+            self.typescript_entry("docs/handbook/2/narrowing.html#discriminated-unions", "Discriminated unions", "Narrow unions on a shared literal property", "Handbook"),
+            self.typescript_entry("docs/handbook/2/narrowing.html#exhaustiveness-checking", "Exhaustiveness checking", "never in switch statements", "Handbook"),
+            self.typescript_entry("docs/handbook/2/functions.html", "More on Functions", "Function types, overloads, generics and this", "Handbook"),
+            self.typescript_entry("docs/handbook/2/objects.html", "Object Types", "Optional, readonly and index signature properties", "Handbook"),
+            self.typescript_entry("docs/handbook/2/generics.html", "Generics", "Generic functions, types, classes and constraints", "Handbook"),
+            self.typescript_entry("docs/handbook/2/keyof-types.html", "Keyof Type Operator", "keyof for property name unions", "Handbook"),
+            self.typescript_entry("docs/handbook/2/typeof-types.html", "Typeof Type Operator", "typeof in type positions", "Handbook"),
+            self.typescript_entry("docs/handbook/2/indexed-access-types.html", "Indexed Access Types", "Look up property types with T[K]", "Handbook"),
+            self.typescript_entry("docs/handbook/2/conditional-types.html", "Conditional Types", "T extends U ? X : Y and infer", "Handbook"),
+            self.typescript_entry("docs/handbook/2/mapped-types.html", "Mapped Types", "Transform properties with { [K in keyof T]: ... }", "Handbook"),
+            self.typescript_entry("docs/handbook/2/template-literal-types.html", "Template Literal Types", "String literal types built from templates", "Handbook"),
+            self.typescript_entry("docs/handbook/2/classes.html", "Classes", "Class members, access modifiers, abstract classes and implements", "Handbook"),
+            self.typescript_entry("docs/handbook/2/modules.html", "Modules", "ES module and CommonJS interop in TypeScript", "Handbook"),
+            self.typescript_entry("docs/handbook/enums.html", "Enums", "Numeric, string and const enums", "Handbook"),
+            self.typescript_entry("docs/handbook/decorators.html", "Decorators", "Class, method, accessor and property decorators", "Handbook"),
+            self.typescript_entry("docs/handbook/declaration-merging.html", "Declaration Merging", "Merging interfaces, namespaces and module augmentation", "Handbook"),
+            self.typescript_entry("docs/handbook/type-compatibility.html", "Type Compatibility", "Structural typing and assignability rules", "Handbook"),
+            self.typescript_entry("docs/handbook/type-inference.html", "Type Inference", "How types are inferred from values and context", "Handbook"),
+            self.typescript_entry("docs/handbook/release-notes/typescript-4-9.html#the-satisfies-operator", "satisfies", "Check a value against a type without widening it", "Handbook"),
+            self.typescript_entry("docs/handbook/jsx.html", "JSX", "Type checking JSX and TSX files", "Handbook"),
+            self.typescript_entry("docs/handbook/triple-slash-directives.html", "Triple-Slash Directives", "/// <reference> directives", "Handbook"),
+
+            // ==================== Utility Types ====================
+            self.typescript_entry("docs/handbook/utility-types.html", "Utility Types", "Built-in generic types for common type transformations", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#partialtype", "Partial<Type>", "Make every property optional", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#requiredtype", "Required<Type>", "Make every property required", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#readonlytype", "Readonly<Type>", "Make every property readonly", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#recordkeys-type", "Record<Keys, Type>", "Object type with keys Keys and values Type", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#picktype-keys", "Pick<Type, Keys>", "Keep only the listed properties", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#omittype-keys", "Omit<Type, Keys>", "Remove the listed properties", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#excludeuniontype-excludedmembers", "Exclude<UnionType, ExcludedMembers>", "Remove union members assignable to ExcludedMembers", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#extracttype-union", "Extract<Type, Union>", "Keep union members assignable to Union", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#nonnullabletype", "NonNullable<Type>", "Remove null and undefined", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#parameterstype", "Parameters<Type>", "Tuple of a function type's parameters", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#constructorparameterstype", "ConstructorParameters<Type>", "Tuple of a constructor's parameters", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#returntypetype", "ReturnType<Type>", "Return type of a function type", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#instancetypetype", "InstanceType<Type>", "Instance type of a constructor", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#awaitedtype", "Awaited<Type>", "Type a Promise resolves to, recursively unwrapped", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#noinfertype", "NoInfer<Type>", "Block inference from a type parameter position", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#thisparametertypetype", "ThisParameterType<Type>", "Type of a function's this parameter", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#omitthisparametertype", "OmitThisParameter<Type>", "Remove the this parameter from a function type", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#thistypetype", "ThisType<Type>", "Marker for the contextual this type", "Utility Types"),
+            self.typescript_entry("docs/handbook/utility-types.html#intrinsic-string-manipulation-types", "Uppercase / Lowercase / Capitalize / Uncapitalize", "Intrinsic string manipulation types", "Utility Types"),
+
+            // ==================== TSConfig ====================
+            self.typescript_entry("tsconfig", "TSConfig Reference", "Every tsconfig.json compiler option", "TSConfig"),
+            self.typescript_entry("tsconfig#strict", "strict", "Enable all strict type-checking options", "TSConfig"),
+            self.typescript_entry("tsconfig#strictNullChecks", "strictNullChecks", "Treat null and undefined as distinct types", "TSConfig"),
+            self.typescript_entry("tsconfig#noImplicitAny", "noImplicitAny", "Error on expressions implicitly typed any", "TSConfig"),
+            self.typescript_entry("tsconfig#noUncheckedIndexedAccess", "noUncheckedIndexedAccess", "Add undefined to index signature reads", "TSConfig"),
+            self.typescript_entry("tsconfig#exactOptionalPropertyTypes", "exactOptionalPropertyTypes", "Distinguish missing properties from undefined", "TSConfig"),
+            self.typescript_entry("tsconfig#noUnusedLocals", "noUnusedLocals", "Report unused local variables", "TSConfig"),
+            self.typescript_entry("tsconfig#target", "target", "JavaScript language version to emit", "TSConfig"),
+            self.typescript_entry("tsconfig#lib", "lib", "Bundled library declarations such as DOM and ES2022", "TSConfig"),
+            self.typescript_entry("tsconfig#module", "module", "Module system for emitted code (commonjs, esnext, nodenext)", "TSConfig"),
+            self.typescript_entry("tsconfig#moduleResolution", "moduleResolution", "How imports are resolved (node16, nodenext, bundler)", "TSConfig"),
+            self.typescript_entry("tsconfig#paths", "paths", "Remap import specifiers to file locations", "TSConfig"),
+            self.typescript_entry("tsconfig#baseUrl", "baseUrl", "Base directory for non-relative module names", "TSConfig"),
+            self.typescript_entry("tsconfig#esModuleInterop", "esModuleInterop", "Interop helpers for importing CommonJS modules", "TSConfig"),
+            self.typescript_entry("tsconfig#verbatimModuleSyntax", "verbatimModuleSyntax", "Keep or drop imports exactly as written", "TSConfig"),
+            self.typescript_entry("tsconfig#isolatedModules", "isolatedModules", "Ensure each file can be transpiled on its own", "TSConfig"),
+            self.typescript_entry("tsconfig#resolveJsonModule", "resolveJsonModule", "Allow importing .json files", "TSConfig"),
+            self.typescript_entry("tsconfig#allowImportingTsExtensions", "allowImportingTsExtensions", "Allow imports ending in .ts", "TSConfig"),
+            self.typescript_entry("tsconfig#jsx", "jsx", "How JSX is emitted (react-jsx, preserve)", "TSConfig"),
+            self.typescript_entry("tsconfig#allowJs", "allowJs", "Compile JavaScript files alongside TypeScript", "TSConfig"),
+            self.typescript_entry("tsconfig#checkJs", "checkJs", "Type check JavaScript files", "TSConfig"),
+            self.typescript_entry("tsconfig#declaration", "declaration", "Emit .d.ts declaration files", "TSConfig"),
+            self.typescript_entry("tsconfig#sourceMap", "sourceMap", "Emit source map files", "TSConfig"),
+            self.typescript_entry("tsconfig#outDir", "outDir", "Output directory for emitted files", "TSConfig"),
+            self.typescript_entry("tsconfig#rootDir", "rootDir", "Root directory of input files", "TSConfig"),
+            self.typescript_entry("tsconfig#noEmit", "noEmit", "Type check without emitting files", "TSConfig"),
+            self.typescript_entry("tsconfig#skipLibCheck", "skipLibCheck", "Skip type checking declaration files", "TSConfig"),
+            self.typescript_entry("tsconfig#types", "types", "Limit which @types packages are included", "TSConfig"),
+            self.typescript_entry("tsconfig#incremental", "incremental", "Save build info for faster rebuilds", "TSConfig"),
+            self.typescript_entry("tsconfig#composite", "composite", "Enable project references for this project", "TSConfig"),
+            self.typescript_entry("tsconfig#experimentalDecorators", "experimentalDecorators", "Legacy decorator support", "TSConfig"),
+            self.typescript_entry("tsconfig#extends", "extends", "Inherit settings from another tsconfig", "TSConfig"),
+            self.typescript_entry("tsconfig#include", "include", "Files and globs to include in the program", "TSConfig"),
+            self.typescript_entry("tsconfig#exclude", "exclude", "Files and globs to skip when resolving include", "TSConfig"),
+            self.typescript_entry("tsconfig#references", "references", "Project references to other tsconfig projects", "TSConfig"),
+
+            // ==================== Reference ====================
+            self.typescript_entry("docs/handbook/declaration-files/introduction.html", "Declaration Files", "Write .d.ts files for JavaScript libraries", "Reference"),
+            self.typescript_entry("docs/handbook/declaration-files/by-example.html", "Declaration files by example", "Declaring globals, functions, classes and namespaces", "Reference"),
+            self.typescript_entry("docs/handbook/declaration-files/consumption.html", "Consuming declaration files", "Install @types packages", "Reference"),
+            self.typescript_entry("docs/handbook/project-references.html", "Project References", "Split a codebase into buildable projects with tsc --build", "Reference"),
+            self.typescript_entry("docs/handbook/compiler-options.html", "tsc CLI Options", "Command-line flags for the TypeScript compiler", "Reference"),
+            self.typescript_entry("docs/handbook/modules/theory.html", "Module resolution theory", "How TypeScript models module formats and resolution", "Reference"),
+        ]
+    }
+
+    fn typescript_entry(&self, slug: &str, title: &str, description: &str, category: &str) -> WebFrameworkSearchEntry {
+        WebFrameworkSearchEntry {
+            framework: WebFramework::TypeScript,
+            slug: slug.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            url: format!("{}/{}", TYPESCRIPT_BASE, slug),
+            category: Some(category.to_string()),
+        }
+    }
+
+    async fn fetch_typescript_article(&self, slug: &str) -> Result<WebFrameworkArticle> {
+        let cache_key = format!("typescript_{}.json", slug.replace('/', "_").replace('#', "_"));
+
+        if let Ok(Some(entry)) = self
+            .disk_cache
+            .load::<WebFrameworkArticle>(&cache_key)
+            .await
+        {
+            return Ok(entry.value);
+        }
+
+        let fetch_slug = slug.split('#').next().unwrap_or(slug);
+        let url = format!("{}/{}", TYPESCRIPT_BASE, fetch_slug);
+        debug!(url = %url, "Fetching TypeScript article");
+
+        ensure_online(&url)?;
+        let response = self.http.get(&url).send_with_retry().await?;
+        if !response.status().is_success() {
+            anyhow::bail!("TypeScript page not found: {}", slug);
+        }
+
+        let html = response.text().await?;
+        let article = self.parse_typescript_html(&html, slug, &format!("{}/{}", TYPESCRIPT_BASE, slug));
+
+        let _ = self.disk_cache.store(&cache_key, article.clone()).await;
+
+        Ok(article)
+    }
+
+    fn parse_typescript_html(&self, html: &str, slug: &str, url: &str) -> WebFrameworkArticle {
+        let document = Html::parse_document(html);
+
+        // The tsconfig reference and utility types are single pages; keep just the anchored entry
+        if let Some(article) = slug.split_once('#').and_then(|(_, anchor)| {
+            self.parse_anchored_section(&document, WebFramework::TypeScript, anchor, slug, url, "typescript")
+        }) {
+            return article;
+        }
+
+        let title = self
+            .extract_text(&document, "h1")
+            .unwrap_or_else(|| slug.split('/').next_back().unwrap_or("TypeScript").replace(".html", "").replace('-', " "));
+
+        let description = self
+            .extract_text(&document, "#handbook-content > p:first-of-type, article p:first-of-type")
+            .unwrap_or_else(|| format!("TypeScript documentation for {title}"));
+
+        let examples = self.extract_code_examples(&document, "typescript");
+
+        let content = self
+            .extract_text(&document, "#handbook-content, article, main")
+            .map(|s| truncate_chars(s, 4000))
+            .unwrap_or_default();
+
+        WebFrameworkArticle {
+            framework: WebFramework::TypeScript,
+            slug: slug.to_string(),
+            title,
+            description,
+            content,
+            examples,
+            api_signature: None,
+            related: Vec::new(),
+            url: url.to_string(),
+        }
+    }
+
+    // ==================== HELPERS ====================
+
+    /// Extract one entry of a single-page reference: the heading carrying `anchor` and every
+    /// sibling up to the next heading
+    #[allow(clippy::unused_self)]
+    fn parse_anchored_section(
+        &self,
+        document: &Html,
+        framework: WebFramework,
+        anchor: &str,
+        slug: &str,
+        url: &str,
+        language: &str,
+    ) -> Option<WebFrameworkArticle> {
+        let selector = Selector::parse(&format!("[id=\"{anchor}\"]")).ok()?;
+        let heading = document.select(&selector).next()?;
+        let signature = heading.text().collect::<String>().trim().to_string();
+
+        let mut paragraphs: Vec<String> = Vec::new();
+        let mut examples = Vec::new();
+        for sibling in heading.next_siblings().filter_map(scraper::ElementRef::wrap) {
+            let name = sibling.value().name();
+            if matches!(name, "h1" | "h2" | "h3") {
+                break;
+            }
+            let text = sibling.text().collect::<String>().trim().to_string();
+            if text.is_empty() {
+                continue;
+            }
+            if name == "pre" {
+                let is_complete = text.contains("require(") || text.contains("import ");
+                let has_output = text.contains("console.log") || text.contains("// =>");
+                examples.push(CodeExample {
+                    code: text,
+                    language: language.to_string(),
+                    filename: None,
+                    description: paragraphs.last().cloned(),
+                    is_complete,
+                    has_output,
+                });
+            } else {
+                paragraphs.push(text);
+            }
+        }
+
+        examples.sort_by_key(|example| Reverse(example.quality_score()));
+        examples.truncate(5);
+
+        let title = signature
+            .split('(')
+            .next()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(anchor)
+            .to_string();
+        let description = paragraphs
+            .first()
+            .cloned()
+            .unwrap_or_else(|| format!("{} reference for {title}", framework.display_name()));
+
+        Some(WebFrameworkArticle {
+            framework,
+            slug: slug.to_string(),
+            title,
+            description,
+            content: truncate_chars(paragraphs.join("\n\n"), 4000),
+            examples,
+            api_signature: Some(signature).filter(|s| s.contains('(')),
+            related: Vec::new(),
+            url: url.to_string(),
+        })
+    }
+
+
+    #[allow(clippy::unused_self)]
+    fn extract_text(&self, document: &Html, selector_str: &str) -> Option<String> {
+        if let Ok(selector) = Selector::parse(selector_str) {
+            document
+                .select(&selector)
+                .next()
+                .map(|e| e.text().collect::<String>().trim().to_string())
+                .filter(|s| !s.is_empty())
+        } else {
+            None
+        }
+    }
+
+    #[allow(clippy::unused_self)]
+    fn extract_code_examples(&self, document: &Html, default_lang: &str) -> Vec<CodeExample> {
+        let mut examples = Vec::new();
+
+        let selectors = [
+            "pre code",
+            "pre.language-js",
+            "pre.language-jsx",
+            "pre.language-tsx",
+            "pre.language-typescript",
+            ".code-block pre",
+            ".highlight pre",
+        ];
+
+        for selector_str in selectors {
+            if let Ok(selector) = Selector::parse(selector_str) {
+                for element in document.select(&selector) {
+                    let code = element.text().collect::<String>().trim().to_string();
+                    if code.is_empty() || code.len() < 20 {
+                        continue;
+                    }
+
+                    // Determine language from class
+                    let class = element.value().attr("class").unwrap_or("");
+                    let language = if class.contains("tsx") || class.contains("typescript") {
+                        "typescript"
+                    } else if class.contains("jsx") {
+                        "jsx"
+                    } else if class.contains("js") || class.contains("javascript") {
+                        "javascript"
+                    } else {
+                        default_lang
+                    };
+
+                    // Get description from preceding element
+                    let description = element
+                        .prev_siblings()
+                        .filter_map(scraper::ElementRef::wrap)
+                        .find(|e| e.value().name() == "p")
+                        .map(|e| e.text().collect::<String>().trim().to_string());
+
+                    // Get filename if present
+                    let filename = element
+                        .prev_siblings()
+                        .filter_map(scraper::ElementRef::wrap)
+                        .find(|e| {
+                                    e.value().name() == "div"
+                                        && e.value()
+                                            .attr("class")
+                                            .is_some_and(|c| c.contains("filename"))
+                        })
+                        .map(|e| e.text().collect::<String>().trim().to_string());
+
+                    let is_complete = code.contains("import ") || code.contains("export ");
+                    let has_output =
+                        code.contains("console.log") || code.contains("// Output:");
+
+                    examples.push(CodeExample {
+                        code,
+                        language: language.to_string(),
+                        filename,
+                        description,
+                        is_complete,
+                        has_output,
+                    });
+
+                    if examples.len() >= 5 {
+                        break;
+                    }
+                }
+            }
+
+            if examples.len() >= 5 {
+                break;
+            }
+        }
+
+        // Sort by quality score
+        examples.sort_by_key(|example| Reverse(example.quality_score()));
+
+        examples
+    }
+
+    pub fn cache_dir(&self) -> &PathBuf {
+        &self.cache_dir
+    }
+}
+
+/// Index category for a Node.js module, flagging modules that are not yet (or no longer) stable
+fn node_module_category(stability: Option<i32>) -> &'static str {
+    match stability {
+        Some(0) => "Module (deprecated)",
+        Some(1) => "Module (experimental)",
+        _ => "Module",
+    }
+}
+
+/// Cut `text` to at most `max` characters without splitting a UTF-8 sequence
+fn truncate_chars(text: String, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((end, _)) => text[..end].to_string(),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_creation() {
+        let _client = WebFrameworksClient::new();
+    }
+
+    #[test]
+    fn test_parse_express_api_section() {
+        let html = r#"<html><body><div id="page-doc">
+            <h1>Express 5.x - API Reference</h1>
+            <section>
+              <h3 id="app.use">app.use([path,] callback [, callback...])</h3>
+              <p>Mounts the specified middleware function or functions at the specified path.</p>
+              <pre><code>app.use(function (req, res, next) {
+  console.log('Time: %d', Date.now())
+  next()
+})</code></pre>
+            </section>
+            <section>
+              <h3 id="req.params">req.params</h3>
+              <p>An object containing properties mapped to the named route parameters.</p>
+            </section>
+        </div></body></html>"#;
+
+        let client = WebFrameworksClient::new();
+        let slug = "en/5x/api.html#app.use";
+        let article = client.parse_express_html(html, slug, "https://expressjs.com/en/5x/api.html#app.use");
+        assert_eq!(article.framework, WebFramework::Express);
+        assert_eq!(article.title, "app.use");
+        assert_eq!(article.api_signature.as_deref(), Some("app.use([path,] callback [, callback...])"));
+        assert!(article.description.starts_with("Mounts the specified middleware"));
+        assert!(!article.content.contains("req.params"));
+        assert_eq!(article.examples.len(), 1);
+        assert!(article.examples[0].code.contains("next()"));
+
+        let article = client.parse_express_html(html, "en/5x/api.html#req.params", "https://expressjs.com");
+        assert_eq!(article.title, "req.params");
+        assert_eq!(article.api_signature, None);
+
+        let article = client.parse_express_html(html, "en/5x/api.html#missing", "https://expressjs.com");
+        assert_eq!(article.title, "Express 5.x - API Reference");
+    }
+
+    #[test]
+    fn test_parse_astro_html() {
+        let html = r#"<html><body><main>
+            <h1 id="_top">Routing</h1>
+            <div class="sl-markdown-content">
+              <p>Astro uses file-based routing to generate your build URLs.</p>
+              <pre class="language-astro"><code>---
+export function getStaticPaths() {
+  return [{ params: { dog: 'clifford' } }];
+}
+const { dog } = Astro.params;
+---</code></pre>
+            </div>
+        </main></body></html>"#;
+
+        let client = WebFrameworksClient::new();
+        let article = client.parse_astro_html(html, "en/guides/routing/", "https://docs.astro.build/en/guides/routing/");
+        assert_eq!(article.framework, WebFramework::Astro);
+        assert_eq!(article.title, "Routing");
+        assert!(article.description.starts_with("Astro uses file-based routing"));
+        assert_eq!(article.examples.len(), 1);
+        assert_eq!(article.examples[0].language, "astro");
+    }
+
+    #[test]
+    fn test_parse_typescript_tsconfig_option() {
+        let html = r#"<html><body><main>
+            <h1>TSConfig Reference</h1>
+            <section class="compiler-option">
+              <h3 id="strict">Strict - <code>strict</code></h3>
+              <p>The strict flag enables a wide range of type checking behavior.</p>
+            </section>
+            <section class="compiler-option">
+              <h3 id="target">Target - <code>target</code></h3>
+              <p>Modern browsers support all ES6 features.</p>
+            </section>
+        </main></body></html>"#;
+
+        let client = WebFrameworksClient::new();
+        let article = client.parse_typescript_html(html, "tsconfig#strict", "https://www.typescriptlang.org/tsconfig#strict");
+        assert_eq!(article.framework, WebFramework::TypeScript);
+        assert!(article.description.starts_with("The strict flag"));
+        assert!(!article.content.contains("ES6"));
+
+        let article = client.parse_typescript_html(html, "tsconfig", "https://www.typescriptlang.org/tsconfig");
+        assert_eq!(article.title, "TSConfig Reference");
+    }
+
+    #[test]
+    fn test_node_module_category() {
+        assert_eq!(node_module_category(Some(1)), "Module (experimental)");
+        assert_eq!(node_module_category(Some(2)), "Module");
+        assert_eq!(node_module_category(None), "Module");
+    }
+
+    #[test]
+    fn test_truncate_chars_keeps_char_boundaries() {
+        assert_eq!(truncate_chars("héllo".to_string(), 2), "hé");
+        assert_eq!(truncate_chars("hi".to_string(), 4000), "hi");
     }
 }
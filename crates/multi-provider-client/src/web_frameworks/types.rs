@@ -8,6 +8,12 @@ pub enum WebFramework {
     NextJs,
     NodeJs,
     Bun,
+    Express,
+    Astro,
+    /// React Router v7, which Remix merged into
+    ReactRouter,
+    ReactNative,
+    TypeScript,
 }
 
 impl WebFramework {
@@ -18,6 +24,11 @@ impl WebFramework {
             Self::NextJs => "nextjs",
             Self::NodeJs => "nodejs",
             Self::Bun => "bun",
+            Self::Express => "express",
+            Self::Astro => "astro",
+            Self::ReactRouter => "react-router",
+            Self::ReactNative => "react-native",
+            Self::TypeScript => "typescript",
         }
     }
 
@@ -28,6 +39,11 @@ impl WebFramework {
             Self::NextJs => "Next.js",
             Self::NodeJs => "Node.js",
             Self::Bun => "Bun",
+            Self::Express => "Express",
+            Self::Astro => "Astro",
+            Self::ReactRouter => "React Router",
+            Self::ReactNative => "React Native",
+            Self::TypeScript => "TypeScript",
         }
     }
 
@@ -38,6 +54,11 @@ impl WebFramework {
             Self::NextJs => "https://nextjs.org",
             Self::NodeJs => "https://nodejs.org",
             Self::Bun => "https://bun.sh",
+            Self::Express => "https://expressjs.com",
+            Self::Astro => "https://docs.astro.build",
+            Self::ReactRouter => "https://reactrouter.com",
+            Self::ReactNative => "https://reactnative.dev",
+            Self::TypeScript => "https://www.typescriptlang.org",
         }
     }
 
@@ -45,7 +66,21 @@ impl WebFramework {
     #[must_use]
     pub fn from_str_opt(s: &str) -> Option<Self> {
         let lower = s.to_lowercase();
-        if lower.contains("react") {
+        // Express first: "express on node" is an Express question
+        if lower.contains("express") {
+            Some(Self::Express)
+        } else if lower.contains("astro") {
+            Some(Self::Astro)
+        } else if lower.contains("remix")
+            || lower.contains("react router")
+            || lower.contains("react-router")
+        {
+            Some(Self::ReactRouter)
+        } else if lower.contains("react native") || lower.contains("react-native") {
+            Some(Self::ReactNative)
+        } else if lower.contains("typescript") || lower.contains("tsconfig") {
+            Some(Self::TypeScript)
+        } else if lower.contains("react") {
             Some(Self::React)
         } else if lower.contains("next") {
             Some(Self::NextJs)
@@ -65,6 +100,38 @@ impl std::fmt::Display for WebFramework {
     }
 }
 
+/// Node.js release lines whose docs can be pinned with `nodejs@<line>`
+pub const NODE_RELEASE_LINES: [u32; 3] = [18, 20, 22];
+
+/// API docs root for a Node.js release line; `None` is the current release
+#[must_use]
+pub fn node_docs_base(release: Option<u32>) -> String {
+    release.map_or_else(
+        || "https://nodejs.org/api".to_string(),
+        |line| format!("https://nodejs.org/docs/latest-v{line}.x/api"),
+    )
+}
+
+/// Search slug for a Node.js module: `api/fs` for the current release, `nodejs@20/fs` when pinned
+#[must_use]
+pub fn node_module_slug(release: Option<u32>, module: &str) -> String {
+    release.map_or_else(|| format!("api/{module}"), |line| format!("nodejs@{line}/{module}"))
+}
+
+/// Split a Node.js slug into its pinned release line and module name
+#[must_use]
+pub fn parse_node_slug(slug: &str) -> (Option<u32>, &str) {
+    if let Some((line, module)) = slug
+        .strip_prefix("nodejs@")
+        .and_then(|rest| rest.split_once('/'))
+    {
+        if let Ok(line) = line.parse() {
+            return (Some(line), module);
+        }
+    }
+    (None, slug.strip_prefix("api/").unwrap_or(slug))
+}
+
 /// A code example with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeExample {
@@ -191,6 +258,46 @@ impl WebFrameworkTechnology {
                 url: "https://bun.sh".to_string(),
                 version: "1.1".to_string(),
             },
+            Self {
+                identifier: "webfw:express".to_string(),
+                framework: WebFramework::Express,
+                title: "Express".to_string(),
+                description: "Minimal web framework for Node.js with routing and middleware".to_string(),
+                url: "https://expressjs.com".to_string(),
+                version: "5".to_string(),
+            },
+            Self {
+                identifier: "webfw:astro".to_string(),
+                framework: WebFramework::Astro,
+                title: "Astro".to_string(),
+                description: "Content-driven web framework with islands and content collections".to_string(),
+                url: "https://docs.astro.build".to_string(),
+                version: "5".to_string(),
+            },
+            Self {
+                identifier: "webfw:react-router".to_string(),
+                framework: WebFramework::ReactRouter,
+                title: "React Router".to_string(),
+                description: "Routing and data loading for React; the successor to Remix".to_string(),
+                url: "https://reactrouter.com".to_string(),
+                version: "7".to_string(),
+            },
+            Self {
+                identifier: "webfw:react-native".to_string(),
+                framework: WebFramework::ReactNative,
+                title: "React Native".to_string(),
+                description: "Native iOS and Android apps with React: core components, APIs and the New Architecture".to_string(),
+                url: "https://reactnative.dev".to_string(),
+                version: "0.81".to_string(),
+            },
+            Self {
+                identifier: "webfw:typescript".to_string(),
+                framework: WebFramework::TypeScript,
+                title: "TypeScript".to_string(),
+                description: "The TypeScript Handbook, utility types and tsconfig compiler options".to_string(),
+                url: "https://www.typescriptlang.org/docs".to_string(),
+                version: "5.9".to_string(),
+            },
         ]
     }
 }
@@ -290,9 +397,27 @@ mod tests {
         assert_eq!(WebFramework::from_str_opt("Node.js"), Some(WebFramework::NodeJs));
         assert_eq!(WebFramework::from_str_opt("bun"), Some(WebFramework::Bun));
         assert_eq!(WebFramework::from_str_opt("Bun runtime"), Some(WebFramework::Bun));
+        assert_eq!(WebFramework::from_str_opt("Express.js"), Some(WebFramework::Express));
+        assert_eq!(WebFramework::from_str_opt("node express"), Some(WebFramework::Express));
+        assert_eq!(WebFramework::from_str_opt("Astro"), Some(WebFramework::Astro));
+        assert_eq!(WebFramework::from_str_opt("Remix"), Some(WebFramework::ReactRouter));
+        assert_eq!(WebFramework::from_str_opt("react-router v7"), Some(WebFramework::ReactRouter));
+        assert_eq!(WebFramework::from_str_opt("React Native"), Some(WebFramework::ReactNative));
+        assert_eq!(WebFramework::from_str_opt("TypeScript"), Some(WebFramework::TypeScript));
         assert_eq!(WebFramework::from_str_opt("python"), None);
     }
 
+    #[test]
+    fn test_node_release_slugs() {
+        assert_eq!(node_module_slug(None, "fs"), "api/fs");
+        assert_eq!(node_module_slug(Some(20), "fs"), "nodejs@20/fs");
+        assert_eq!(parse_node_slug("nodejs@20/fs"), (Some(20), "fs"));
+        assert_eq!(parse_node_slug("api/test"), (None, "test"));
+        assert_eq!(parse_node_slug("fs"), (None, "fs"));
+        assert_eq!(node_docs_base(Some(18)), "https://nodejs.org/docs/latest-v18.x/api");
+        assert_eq!(node_docs_base(None), "https://nodejs.org/api");
+    }
+
     #[test]
     fn test_code_example_score() {
         let complete_example = CodeExample {
@@ -319,10 +444,15 @@ mod tests {
     #[test]
     fn test_predefined_technologies() {
         let techs = WebFrameworkTechnology::predefined();
-        assert_eq!(techs.len(), 4);
+        assert_eq!(techs.len(), 9);
         assert!(techs.iter().any(|t| t.framework == WebFramework::React));
         assert!(techs.iter().any(|t| t.framework == WebFramework::NextJs));
         assert!(techs.iter().any(|t| t.framework == WebFramework::NodeJs));
         assert!(techs.iter().any(|t| t.framework == WebFramework::Bun));
+        assert!(techs.iter().any(|t| t.framework == WebFramework::Express));
+        assert!(techs.iter().any(|t| t.framework == WebFramework::Astro));
+        assert!(techs.iter().any(|t| t.framework == WebFramework::ReactRouter));
+        assert!(techs.iter().any(|t| t.framework == WebFramework::ReactNative));
+        assert!(techs.iter().any(|t| t.framework == WebFramework::TypeScript));
     }
 }